@@ -5,15 +5,83 @@ CREATE TABLE IF NOT EXISTS user_details (
     email VARCHAR(50),
     username VARCHAR(200),
     password VARCHAR(200),
+    -- Encrypted with the server-side MFA_SECRET_ENCRYPTION_KEY, so a copy of the table alone cannot generate codes
+    mfa_secret VARCHAR(255) NULL,
+    mfa_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    -- The time step of the last accepted TOTP code, so that no code is accepted twice
+    mfa_last_time_step BIGINT UNSIGNED NULL,
+    mfa_failed_attempts INT NOT NULL DEFAULT 0,
+    mfa_locked_until TIMESTAMP NULL,
+    is_disabled BOOLEAN NOT NULL DEFAULT FALSE,
     UNIQUE (email, username),
     PRIMARY KEY (id)
 );
 
+CREATE TABLE IF NOT EXISTS user_recovery_codes (
+    id INT NOT NULL AUTO_INCREMENT,
+    username VARCHAR(200) NOT NULL,
+    code_hash CHAR(64) NOT NULL,
+    date_used TIMESTAMP NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS password_history (
+    id INT NOT NULL AUTO_INCREMENT,
+    username VARCHAR(200) NOT NULL,
+    password_hash VARCHAR(200) NOT NULL,
+    date_created TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX (username),
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS password_reset_tokens (
+    id INT NOT NULL AUTO_INCREMENT,
+    username VARCHAR(200) NOT NULL,
+    token_hash CHAR(64) NOT NULL,
+    expiry_date TIMESTAMP NOT NULL,
+    date_used TIMESTAMP NULL,
+    UNIQUE (token_hash),
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS mfa_policy (
+    role VARCHAR(50) NOT NULL,
+    is_mfa_required BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (role)
+);
+
 CREATE TABLE IF NOT EXISTS prayer_times (
     data LONGBLOB NOT NULL,
     hash CHAR(64) NOT NULL
 );
 
+CREATE TABLE IF NOT EXISTS rooms (
+    id INT NOT NULL AUTO_INCREMENT,
+    name VARCHAR(50) NOT NULL,
+    -- The room's capacity is unknown when NULL
+    capacity SMALLINT UNSIGNED NULL,
+    PRIMARY KEY (id),
+    UNIQUE (name)
+);
+
+CREATE TABLE IF NOT EXISTS speakers (
+    id INT NOT NULL AUTO_INCREMENT,
+    full_name VARCHAR(100) NOT NULL,
+    title VARCHAR(50) NULL,
+    bio VARCHAR(1000) NULL,
+    photo_url VARCHAR(2083) NULL,
+    -- JSON array of the languages the speaker teaches in
+    languages JSON NOT NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS event_categories (
+    id INT NOT NULL AUTO_INCREMENT,
+    name VARCHAR(50) NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE (name)
+);
+
 CREATE TABLE IF NOT EXISTS events (
     id INT NOT NULL AUTO_INCREMENT,
     title VARCHAR(50) NOT NULL,
@@ -22,15 +90,114 @@ CREATE TABLE IF NOT EXISTS events (
     -- Event Details
     type VARCHAR(10),
     recurrence VARCHAR(15),
+    recurrence_end_date TIMESTAMP NULL,
+    recurrence_count SMALLINT UNSIGNED NULL,
     status VARCHAR(15),
+    -- When a scheduled event is published automatically
+    publish_at TIMESTAMP NULL,
     minimum_age TINYINT UNSIGNED,
     maximum_age TINYINT UNSIGNED,
+    -- Registrations are unlimited when NULL
+    capacity SMALLINT UNSIGNED NULL,
+    -- Events without a room are not booked, and booked events always have a duration
+    room_id INT NULL,
+    duration_minutes SMALLINT UNSIGNED NULL,
+    -- JSON array of the ids of the speakers leading the event
+    speaker_ids JSON NOT NULL,
+    -- Events are not listed under a category when NULL
+    category_id INT NULL,
+    -- JSON array of lowercase free-form tags
+    tags JSON NOT NULL,
     image_url VARCHAR(2083),
     -- Contact Details
     full_name VARCHAR(100),
     phone_number VARCHAR(15),
     email VARCHAR(50),
-    PRIMARY KEY (id)
+    -- Which contact details are shown on the public API, where only the name is shown by default
+    show_full_name BOOLEAN NOT NULL DEFAULT TRUE,
+    show_phone_number BOOLEAN NOT NULL DEFAULT FALSE,
+    show_email BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Lets the public message the organiser by email without the address being shown
+    contact_form_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Incremented on every change, so edits made from a stale copy can be rejected
+    version INT UNSIGNED NOT NULL DEFAULT 1,
+    PRIMARY KEY (id),
+    -- Supports the duplicate check made when an event is saved
+    INDEX idx_events_title_date (title, date),
+    FULLTEXT INDEX ft_events_search (title, description)
+);
+
+CREATE TABLE IF NOT EXISTS event_exceptions (
+    id INT NOT NULL AUTO_INCREMENT,
+    event_id INT NOT NULL,
+    -- The scheduled date of the occurrence being overridden
+    occurrence_date TIMESTAMP NOT NULL,
+    -- Cancels the occurrence when NULL, otherwise moves it
+    replacement_date TIMESTAMP NULL,
+    PRIMARY KEY (id),
+    UNIQUE (event_id, occurrence_date)
+);
+
+CREATE TABLE IF NOT EXISTS event_registrations (
+    id INT NOT NULL AUTO_INCREMENT,
+    event_id INT NOT NULL,
+    full_name VARCHAR(100) NOT NULL,
+    email VARCHAR(50) NULL,
+    phone_number VARCHAR(15) NULL,
+    party_size TINYINT UNSIGNED NOT NULL,
+    status VARCHAR(10) NOT NULL CHECK (status IN ('confirmed', 'waitlisted')),
+    registered_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id),
+    INDEX (event_id, status)
+);
+
+CREATE TABLE IF NOT EXISTS event_check_ins (
+    registration_id INT NOT NULL,
+    -- The scheduled date of the occurrence being attended, as recurring events share registrations
+    occurrence_date TIMESTAMP NOT NULL,
+    checked_in_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (registration_id, occurrence_date)
+);
+
+CREATE TABLE IF NOT EXISTS event_reminder_subscriptions (
+    id INT NOT NULL AUTO_INCREMENT,
+    -- Reminders are either for a single event or for every event of a type
+    event_id INT NULL,
+    event_type VARCHAR(10) NULL,
+    channel VARCHAR(5) NOT NULL CHECK (channel IN ('push', 'email')),
+    -- The device token for push reminders, otherwise the email address
+    recipient VARCHAR(255) NOT NULL,
+    hours_before SMALLINT UNSIGNED NOT NULL,
+    subscribed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    -- Email subscriptions are only sent reminders once the recipient has confirmed the address
+    -- is theirs, so nobody can be subscribed to reminders they did not ask for
+    confirmed_at TIMESTAMP NULL,
+    PRIMARY KEY (id),
+    INDEX (event_id),
+    CONSTRAINT chk_reminder_is_for_event_or_type CHECK (
+        (event_id IS NOT NULL AND event_type IS NULL) OR
+        (event_id IS NULL AND event_type IS NOT NULL)
+    )
+);
+
+CREATE TABLE IF NOT EXISTS event_reminder_deliveries (
+    subscription_id INT NOT NULL,
+    -- The date of the occurrence being reminded about, so each occurrence is only reminded once
+    occurrence_date TIMESTAMP NOT NULL,
+    delivered_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (subscription_id, occurrence_date)
+);
+
+-- Messages sent to organisers through the contact form, only kept for as long as they count
+-- towards how many messages an event's organiser may be sent. Senders are told apart by their IP
+-- address, as the email address they give is not verified
+CREATE TABLE IF NOT EXISTS organiser_messages (
+    id INT NOT NULL AUTO_INCREMENT,
+    event_id INT NOT NULL,
+    sender_ip VARCHAR(45) NOT NULL,
+    sent_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id),
+    INDEX (event_id, sender_ip, sent_at)
 );
 
 CREATE TABLE IF NOT EXISTS imam_question (
@@ -43,9 +210,41 @@ CREATE TABLE IF NOT EXISTS imam_question (
     imam_name VARCHAR(50) NULL,
     answer VARCHAR(250) NULL,
     date_answered TIMESTAMP NULL,
+    -- Incremented on every change, so answers written from a stale copy can be rejected
+    version INT UNSIGNED NOT NULL DEFAULT 1,
     PRIMARY KEY (id),
+    FULLTEXT INDEX ft_imam_question_search (title, description, answer),
     CONSTRAINT chk_question_is_answered_or_not CHECK (
         (imam_name IS NULL AND answer IS NULL AND date_answered IS NULL) OR
         (imam_name IS NOT NULL AND answer IS NOT NULL AND date_answered IS NOT NULL)
     )
-);
\ No newline at end of file
+);
+
+-- Append only, so that every change made through the admin API can be traced back to who made it
+CREATE TABLE IF NOT EXISTS audit_log (
+    id BIGINT NOT NULL AUTO_INCREMENT,
+    -- The username of whoever made the change
+    actor VARCHAR(200) NOT NULL,
+    action VARCHAR(10) NOT NULL CHECK (action IN ('create', 'update', 'delete')),
+    entity_type VARCHAR(20) NOT NULL CHECK (entity_type IN ('event', 
+                                                           'event_exceptions', 
+                                                           'room', 
+                                                           'event_category', 
+                                                           'speaker', 
+                                                           'event_registration', 
+                                                           'prayer_times', 
+                                                           'imam_question', 
+                                                           'user', 
+                                                           'mfa_policy')),
+    -- NULL for the prayer times and the MFA policy, which are never identified. Event exceptions
+    -- are identified by the event they belong to
+    entity_id INT NULL,
+    before_snapshot JSON NULL,
+    after_snapshot JSON NULL,
+    request_id VARCHAR(64) NOT NULL,
+    occurred_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id),
+    INDEX (actor),
+    INDEX (entity_type, entity_id),
+    INDEX (occurred_at)
+);
@@ -8,7 +8,15 @@ END //
 
 CREATE PROCEDURE IF NOT EXISTS get_user_credentials(IN p_username VARCHAR(200))
 BEGIN
-    SELECT username, password, role FROM user_details WHERE username = p_username;
+    SELECT user_details.username, 
+    user_details.password, 
+    user_details.role, 
+    user_details.mfa_enabled, 
+    COALESCE(mfa_policy.is_mfa_required, FALSE), 
+    user_details.is_disabled
+    FROM user_details 
+    LEFT JOIN mfa_policy ON mfa_policy.role = user_details.role
+    WHERE user_details.username = p_username;
 END //
 
 CREATE PROCEDURE IF NOT EXISTS register_user(IN p_full_name VARCHAR(100), 
@@ -19,16 +27,292 @@ CREATE PROCEDURE IF NOT EXISTS register_user(IN p_full_name VARCHAR(100),
 BEGIN
     INSERT INTO user_details (full_name, role, email, username, password) 
     VALUES (p_full_name, p_role, p_email, p_username, p_password);
+    INSERT INTO password_history (username, password_hash) VALUES (p_username, p_password);
 END //
 
 CREATE PROCEDURE IF NOT EXISTS reset_user_password(IN p_username VARCHAR(200), 
                                                     IN p_password VARCHAR(200))
 BEGIN
+    INSERT INTO password_history (username, password_hash) 
+    SELECT username, p_password FROM user_details WHERE username = p_username;
+    -- Kept as the last statement so that the affected row count reflects whether the user exists
     UPDATE user_details 
     SET password = p_password 
     WHERE username = p_username;
 END //
 
+-- Replaces the hash of an unchanged password, so the password history is left as it is
+CREATE PROCEDURE IF NOT EXISTS update_user_password_hash(IN p_username VARCHAR(200), 
+                                                          IN p_password VARCHAR(200))
+BEGIN
+    UPDATE user_details 
+    SET password = p_password 
+    WHERE username = p_username;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_username_by_email(IN p_email VARCHAR(50))
+BEGIN
+    SELECT username FROM user_details WHERE email = p_email AND is_disabled = FALSE;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_user_email(IN p_username VARCHAR(200))
+BEGIN
+    SELECT email FROM user_details WHERE username = p_username;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_user_mfa_details(IN p_username VARCHAR(200))
+BEGIN
+    SELECT mfa_secret, mfa_enabled, mfa_last_time_step, mfa_locked_until 
+    FROM user_details 
+    WHERE username = p_username;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS set_user_mfa_secret(IN p_username VARCHAR(200), 
+                                                    IN p_mfa_secret VARCHAR(255))
+BEGIN
+    UPDATE user_details 
+    SET mfa_secret = p_mfa_secret, mfa_enabled = FALSE 
+    WHERE username = p_username AND mfa_enabled = FALSE;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS enable_user_mfa(IN p_username VARCHAR(200), 
+                                                IN p_time_step BIGINT UNSIGNED)
+BEGIN
+    DELETE FROM user_recovery_codes WHERE username = p_username;
+    -- The code used to confirm enrolment cannot then be used to log in
+    UPDATE user_details 
+    SET mfa_enabled = TRUE, mfa_last_time_step = p_time_step 
+    WHERE username = p_username AND mfa_secret IS NOT NULL;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS disable_user_mfa(IN p_username VARCHAR(200))
+BEGIN
+    DELETE FROM user_recovery_codes WHERE username = p_username;
+    UPDATE user_details 
+    SET mfa_secret = NULL, 
+        mfa_enabled = FALSE, 
+        mfa_last_time_step = NULL, 
+        mfa_failed_attempts = 0, 
+        mfa_locked_until = NULL 
+    WHERE username = p_username;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS accept_totp_time_step(IN p_username VARCHAR(200), 
+                                                      IN p_time_step BIGINT UNSIGNED)
+BEGIN
+    -- Conditional, so that two requests racing with the same code cannot both be accepted
+    UPDATE user_details 
+    SET mfa_last_time_step = p_time_step, mfa_failed_attempts = 0 
+    WHERE username = p_username 
+      AND (mfa_last_time_step IS NULL OR mfa_last_time_step < p_time_step);
+END //
+
+CREATE PROCEDURE IF NOT EXISTS record_failed_mfa_attempt(IN p_username VARCHAR(200), 
+                                                          IN p_max_attempts INT, 
+                                                          IN p_lockout_minutes INT)
+BEGIN
+    -- Assignments are applied left to right, so the lock is set before the count is reset
+    UPDATE user_details 
+    SET mfa_locked_until = IF(mfa_failed_attempts + 1 >= p_max_attempts, 
+                              TIMESTAMPADD(MINUTE, p_lockout_minutes, CURRENT_TIMESTAMP), 
+                              mfa_locked_until), 
+        mfa_failed_attempts = IF(mfa_failed_attempts + 1 >= p_max_attempts, 
+                                 0, 
+                                 mfa_failed_attempts + 1) 
+    WHERE username = p_username;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_users(IN p_role VARCHAR(50))
+BEGIN
+    SELECT id, full_name, email, username, role, is_disabled, mfa_enabled 
+    FROM user_details 
+    WHERE p_role IS NULL OR role = p_role 
+    ORDER BY id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_user_by_id(IN p_id INT)
+BEGIN
+    SELECT id, full_name, email, username, role, is_disabled, mfa_enabled 
+    FROM user_details 
+    WHERE id = p_id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS update_user_details(IN p_id INT, 
+                                                    IN p_full_name VARCHAR(100), 
+                                                    IN p_email VARCHAR(50), 
+                                                    IN p_role VARCHAR(50))
+BEGIN
+    DECLARE v_role VARCHAR(50);
+    DECLARE v_is_disabled BOOLEAN;
+    DECLARE v_other_admins INT;
+
+    START TRANSACTION;
+    SELECT role, is_disabled INTO v_role, v_is_disabled FROM user_details WHERE id = p_id FOR UPDATE;
+    IF v_is_disabled IS NULL THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'User not found';
+    END IF;
+
+    SELECT COUNT(*) INTO v_other_admins 
+    FROM user_details 
+    WHERE role = 'Admin' AND is_disabled = FALSE AND id <> p_id 
+    FOR UPDATE;
+    IF v_role = 'Admin' AND v_is_disabled = FALSE AND p_role <> 'Admin' AND v_other_admins = 0 THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Cannot remove the last Admin';
+    END IF;
+
+    UPDATE user_details 
+    SET full_name = p_full_name, email = p_email, role = p_role 
+    WHERE id = p_id;
+    COMMIT;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS set_user_disabled(IN p_id INT, IN p_is_disabled BOOLEAN)
+BEGIN
+    DECLARE v_role VARCHAR(50);
+    DECLARE v_is_disabled BOOLEAN;
+    DECLARE v_other_admins INT;
+
+    START TRANSACTION;
+    SELECT role, is_disabled INTO v_role, v_is_disabled FROM user_details WHERE id = p_id FOR UPDATE;
+    IF v_is_disabled IS NULL THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'User not found';
+    END IF;
+
+    SELECT COUNT(*) INTO v_other_admins 
+    FROM user_details 
+    WHERE role = 'Admin' AND is_disabled = FALSE AND id <> p_id 
+    FOR UPDATE;
+    IF v_role = 'Admin' AND p_is_disabled = TRUE AND v_other_admins = 0 THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Cannot remove the last Admin';
+    END IF;
+
+    UPDATE user_details SET is_disabled = p_is_disabled WHERE id = p_id;
+    COMMIT;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS delete_user(IN p_id INT)
+BEGIN
+    DECLARE v_username VARCHAR(200);
+    DECLARE v_role VARCHAR(50);
+    DECLARE v_is_disabled BOOLEAN;
+    DECLARE v_other_admins INT;
+
+    START TRANSACTION;
+    SELECT username, role, is_disabled INTO v_username, v_role, v_is_disabled 
+    FROM user_details 
+    WHERE id = p_id 
+    FOR UPDATE;
+    IF v_is_disabled IS NULL THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'User not found';
+    END IF;
+
+    SELECT COUNT(*) INTO v_other_admins 
+    FROM user_details 
+    WHERE role = 'Admin' AND is_disabled = FALSE AND id <> p_id 
+    FOR UPDATE;
+    IF v_role = 'Admin' AND v_is_disabled = FALSE AND v_other_admins = 0 THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Cannot remove the last Admin';
+    END IF;
+
+    DELETE FROM user_recovery_codes WHERE username = v_username;
+    DELETE FROM password_reset_tokens WHERE username = v_username;
+    DELETE FROM password_history WHERE username = v_username;
+    DELETE FROM user_details WHERE id = p_id;
+    COMMIT;
+END //
+
+-- user_recovery_codes stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS insert_user_recovery_code(IN p_username VARCHAR(200), 
+                                                          IN p_code_hash CHAR(64))
+BEGIN
+    INSERT INTO user_recovery_codes (username, code_hash) VALUES (p_username, p_code_hash);
+END //
+
+CREATE PROCEDURE IF NOT EXISTS consume_user_recovery_code(IN p_username VARCHAR(200), 
+                                                           IN p_code_hash CHAR(64))
+BEGIN
+    UPDATE user_details 
+    SET mfa_failed_attempts = 0 
+    WHERE username = p_username 
+      AND EXISTS (SELECT 1 
+                  FROM user_recovery_codes 
+                  WHERE username = p_username AND code_hash = p_code_hash AND date_used IS NULL);
+    -- Kept as the last statement so that the affected row count reflects whether the code was used
+    UPDATE user_recovery_codes 
+    SET date_used = CURRENT_TIMESTAMP 
+    WHERE username = p_username AND code_hash = p_code_hash AND date_used IS NULL;
+END //
+
+-- password_history stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS get_password_history(IN p_username VARCHAR(200), IN p_limit INT)
+BEGIN
+    SELECT password_hash 
+    FROM password_history 
+    WHERE username = p_username 
+    ORDER BY date_created DESC, id DESC 
+    LIMIT p_limit;
+END //
+
+-- password_reset_tokens stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS get_password_reset_token_username(IN p_token_hash CHAR(64))
+BEGIN
+    SELECT username 
+    FROM password_reset_tokens 
+    WHERE token_hash = p_token_hash AND date_used IS NULL AND expiry_date > CURRENT_TIMESTAMP;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS insert_password_reset_token(IN p_username VARCHAR(200), 
+                                                            IN p_token_hash CHAR(64), 
+                                                            IN p_expiry_date TIMESTAMP)
+BEGIN
+    -- Only the most recently requested token can be used
+    DELETE FROM password_reset_tokens WHERE username = p_username AND date_used IS NULL;
+    INSERT INTO password_reset_tokens (username, token_hash, expiry_date) 
+    VALUES (p_username, p_token_hash, p_expiry_date);
+END //
+
+CREATE PROCEDURE IF NOT EXISTS reset_user_password_with_token(IN p_token_hash CHAR(64), 
+                                                               IN p_password VARCHAR(200))
+BEGIN
+    DECLARE v_username VARCHAR(200);
+
+    START TRANSACTION;
+    SELECT username INTO v_username 
+    FROM password_reset_tokens 
+    WHERE token_hash = p_token_hash AND date_used IS NULL AND expiry_date > CURRENT_TIMESTAMP
+    FOR UPDATE;
+
+    IF v_username IS NULL THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Invalid or expired token';
+    END IF;
+
+    UPDATE password_reset_tokens SET date_used = CURRENT_TIMESTAMP WHERE token_hash = p_token_hash;
+    UPDATE user_details SET password = p_password WHERE username = v_username;
+    INSERT INTO password_history (username, password_hash) VALUES (v_username, p_password);
+    COMMIT;
+    SELECT v_username;
+END //
+
+-- mfa_policy stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS upsert_mfa_policy(IN p_role VARCHAR(50), 
+                                                  IN p_is_mfa_required BOOLEAN)
+BEGIN
+    INSERT INTO mfa_policy (role, is_mfa_required) 
+    VALUES (p_role, p_is_mfa_required)
+    ON DUPLICATE KEY UPDATE is_mfa_required = p_is_mfa_required;
+END //
+
 -- prayer_times stored procedures
 
 CREATE PROCEDURE IF NOT EXISTS get_prayer_times()
@@ -64,7 +348,9 @@ END //
 
 -- events stored procedures
 
-CREATE PROCEDURE IF NOT EXISTS get_events()
+-- Only confirmed, postponed and cancelled events have been published, so the rest are withheld
+-- from the public
+CREATE PROCEDURE IF NOT EXISTS get_events(IN p_published_only BOOLEAN)
 BEGIN
     SELECT id, 
     title, 
@@ -72,14 +358,193 @@ BEGIN
     date, 
     type, 
     recurrence, 
+    recurrence_end_date,
+    recurrence_count,
     status, 
     minimum_age,
     maximum_age,
     image_url, 
     full_name, 
     phone_number, 
-    email
-    FROM events;
+    email,
+    capacity,
+    publish_at,
+    room_id,
+    duration_minutes,
+    CAST(speaker_ids AS CHAR) AS speaker_ids,
+    version,
+    show_full_name,
+    show_phone_number,
+    show_email,
+    contact_form_enabled,
+    category_id,
+    CAST(tags AS CHAR) AS tags
+    FROM events
+    WHERE NOT p_published_only OR status IN ('confirmed', 'postponed', 'cancelled');
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_filtered_events(IN p_from TIMESTAMP, 
+                                                   IN p_to TIMESTAMP, 
+                                                   IN p_type VARCHAR(10), 
+                                                   IN p_status VARCHAR(15), 
+                                                   IN p_age_min TINYINT UNSIGNED, 
+                                                   IN p_age_max TINYINT UNSIGNED, 
+                                                   IN p_category_id INT, 
+                                                   IN p_tag VARCHAR(30), 
+                                                   IN p_published_only BOOLEAN, 
+                                                   IN p_limit INT UNSIGNED, 
+                                                   IN p_offset BIGINT UNSIGNED)
+BEGIN
+    SELECT id, 
+    title, 
+    description, 
+    date, 
+    type, 
+    recurrence, 
+    recurrence_end_date,
+    recurrence_count,
+    status, 
+    minimum_age,
+    maximum_age,
+    image_url, 
+    full_name, 
+    phone_number, 
+    email,
+    capacity,
+    publish_at,
+    room_id,
+    duration_minutes,
+    CAST(speaker_ids AS CHAR) AS speaker_ids,
+    version,
+    show_full_name,
+    show_phone_number,
+    show_email,
+    contact_form_enabled,
+    category_id,
+    CAST(tags AS CHAR) AS tags
+    FROM events
+    WHERE (p_to IS NULL OR date < p_to) 
+    -- Recurring series anchored before the window are included while they are still running
+    AND (p_from IS NULL 
+        OR date >= p_from 
+        OR (recurrence <> 'one-off' AND (recurrence_end_date IS NULL OR recurrence_end_date >= p_from))) 
+    AND (p_type IS NULL OR type = p_type) 
+    AND (p_status IS NULL OR status = p_status) 
+    -- Events without an age range are suitable for everyone
+    AND (p_age_min IS NULL OR maximum_age IS NULL OR maximum_age >= p_age_min) 
+    AND (p_age_max IS NULL OR minimum_age IS NULL OR minimum_age <= p_age_max)
+    AND (p_category_id IS NULL OR category_id = p_category_id)
+    -- Tags are stored in lowercase, so the tag being filtered by must be too
+    AND (p_tag IS NULL OR p_tag MEMBER OF (tags))
+    AND (NOT p_published_only OR status IN ('confirmed', 'postponed', 'cancelled'))
+    ORDER BY date, id
+    LIMIT p_limit OFFSET p_offset;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS count_filtered_events(IN p_from TIMESTAMP, 
+                                                     IN p_to TIMESTAMP, 
+                                                     IN p_type VARCHAR(10), 
+                                                     IN p_status VARCHAR(15), 
+                                                     IN p_age_min TINYINT UNSIGNED, 
+                                                     IN p_age_max TINYINT UNSIGNED, 
+                                                     IN p_category_id INT, 
+                                                     IN p_tag VARCHAR(30), 
+                                                     IN p_published_only BOOLEAN)
+BEGIN
+    SELECT COUNT(*) 
+    FROM events
+    WHERE (p_to IS NULL OR date < p_to) 
+    -- Recurring series anchored before the window are included while they are still running
+    AND (p_from IS NULL 
+        OR date >= p_from 
+        OR (recurrence <> 'one-off' AND (recurrence_end_date IS NULL OR recurrence_end_date >= p_from))) 
+    AND (p_type IS NULL OR type = p_type) 
+    AND (p_status IS NULL OR status = p_status) 
+    -- Events without an age range are suitable for everyone
+    AND (p_age_min IS NULL OR maximum_age IS NULL OR maximum_age >= p_age_min) 
+    AND (p_age_max IS NULL OR minimum_age IS NULL OR minimum_age <= p_age_max)
+    AND (p_category_id IS NULL OR category_id = p_category_id)
+    -- Tags are stored in lowercase, so the tag being filtered by must be too
+    AND (p_tag IS NULL OR p_tag MEMBER OF (tags))
+    AND (NOT p_published_only OR status IN ('confirmed', 'postponed', 'cancelled'));
+END //
+
+-- Only published events are searchable, ranked by how relevant their title and description are
+CREATE PROCEDURE IF NOT EXISTS search_events(IN p_query VARCHAR(100), IN p_limit INT UNSIGNED)
+BEGIN
+    SELECT id,
+    title,
+    description,
+    date,
+    type,
+    recurrence,
+    recurrence_end_date,
+    recurrence_count,
+    status,
+    minimum_age,
+    maximum_age,
+    image_url,
+    full_name,
+    phone_number,
+    email,
+    capacity,
+    publish_at,
+    room_id,
+    duration_minutes,
+    CAST(speaker_ids AS CHAR) AS speaker_ids,
+    version,
+    show_full_name,
+    show_phone_number,
+    show_email,
+    contact_form_enabled,
+    category_id,
+    CAST(tags AS CHAR) AS tags,
+    MATCH (title, description) AGAINST (p_query IN NATURAL LANGUAGE MODE) AS relevance
+    FROM events
+    WHERE MATCH (title, description) AGAINST (p_query IN NATURAL LANGUAGE MODE)
+    AND status IN ('confirmed', 'postponed', 'cancelled')
+    ORDER BY relevance DESC, id
+    LIMIT p_limit;
+END //
+
+-- The organiser's email address is only given out to forward messages sent through the contact
+-- form, so nothing is returned unless the event is published and has the form enabled
+CREATE PROCEDURE IF NOT EXISTS get_event_organiser_contact(IN p_event_id INT)
+BEGIN
+    SELECT title, 
+    email 
+    FROM events 
+    WHERE id = p_event_id 
+    AND contact_form_enabled 
+    AND email IS NOT NULL 
+    AND status IN ('confirmed', 'postponed', 'cancelled');
+END //
+
+-- Nothing is recorded once the sender has already sent p_max_messages_per_sender about the event
+-- within the window, or its organiser has already been sent p_max_messages_per_event, which stops
+-- the contact form from being used to flood organisers
+CREATE PROCEDURE IF NOT EXISTS record_organiser_message(IN p_event_id INT, 
+                                                        IN p_sender_ip VARCHAR(45), 
+                                                        IN p_max_messages_per_sender INT, 
+                                                        IN p_max_messages_per_event INT, 
+                                                        IN p_window_minutes INT)
+BEGIN
+    DECLARE v_event_id INT;
+    -- Held until the calling transaction ends, so that concurrent messages about the same event
+    -- are counted one at a time rather than all passing the limit together
+    SELECT id INTO v_event_id FROM events WHERE id = p_event_id FOR UPDATE;
+    DELETE FROM organiser_messages 
+    WHERE event_id = p_event_id 
+    AND sent_at <= TIMESTAMPADD(MINUTE, -p_window_minutes, CURRENT_TIMESTAMP);
+    -- Kept as the last statement so that the affected row count reflects whether the message was
+    -- allowed
+    INSERT INTO organiser_messages (event_id, sender_ip) 
+    SELECT p_event_id, p_sender_ip 
+    FROM DUAL 
+    WHERE (SELECT COUNT(*) 
+           FROM organiser_messages 
+           WHERE event_id = p_event_id AND sender_ip = p_sender_ip) < p_max_messages_per_sender 
+    AND (SELECT COUNT(*) FROM organiser_messages WHERE event_id = p_event_id) < p_max_messages_per_event;
 END //
 
 CREATE PROCEDURE IF NOT EXISTS upsert_event(IN p_id INT,
@@ -88,70 +553,570 @@ CREATE PROCEDURE IF NOT EXISTS upsert_event(IN p_id INT,
                                             IN p_date TIMESTAMP, 
                                             IN p_type VARCHAR(10), 
                                             IN p_recurrence VARCHAR(15),
+                                            IN p_recurrence_end_date TIMESTAMP,
+                                            IN p_recurrence_count SMALLINT UNSIGNED,
                                             IN p_status VARCHAR(15),
+                                            IN p_publish_at TIMESTAMP,
                                             IN p_minimum_age TINYINT UNSIGNED,
                                             IN p_maximum_age TINYINT UNSIGNED,
+                                            IN p_capacity SMALLINT UNSIGNED,
+                                            IN p_room_id INT,
+                                            IN p_duration_minutes SMALLINT UNSIGNED,
+                                            IN p_speaker_ids JSON,
+                                            IN p_category_id INT,
+                                            IN p_tags JSON,
                                             IN p_image_url VARCHAR(2083),
                                             IN p_full_name VARCHAR (100),
                                             IN p_phone_number VARCHAR(15),
-                                            IN p_email VARCHAR(50))
+                                            IN p_email VARCHAR(50),
+                                            IN p_show_full_name BOOLEAN,
+                                            IN p_show_phone_number BOOLEAN,
+                                            IN p_show_email BOOLEAN,
+                                            IN p_contact_form_enabled BOOLEAN,
+                                            IN p_version INT UNSIGNED)
 BEGIN
+    DECLARE v_saved_rows INT;
+    DECLARE v_previous_capacity SMALLINT UNSIGNED;
+    IF p_room_id IS NOT NULL AND NOT EXISTS(SELECT 1 FROM rooms WHERE id = p_room_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Room not found';
+    END IF;
+    IF EXISTS(SELECT 1 
+              FROM JSON_TABLE(p_speaker_ids, '$[*]' COLUMNS (speaker_id INT PATH '$')) ids 
+              WHERE ids.speaker_id NOT IN (SELECT id FROM speakers)) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Speaker not found';
+    END IF;
+    IF p_category_id IS NOT NULL AND NOT EXISTS(SELECT 1 FROM event_categories WHERE id = p_category_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Category not found';
+    END IF;
+    -- The version is what the change was made against, so a newer version means someone else has
+    -- saved the event since
+    IF p_id <> 0 AND EXISTS(SELECT 1 FROM events WHERE id = p_id AND version <> p_version) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Version mismatch';
+    END IF;
+    -- An event is only a duplicate when it shares its title, start date and room with another, so
+    -- that a title such as a weekly circle can be used again
+    IF EXISTS(SELECT 1 
+              FROM events 
+              WHERE title = p_title 
+              AND DATE(date) = DATE(p_date) 
+              AND room_id <=> p_room_id 
+              AND id <> p_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Event already exists';
+    END IF;
     IF p_id = 0 THEN
-        IF EXISTS(SELECT 1 FROM events WHERE title = p_title) THEN
-            SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Event already exists';
-        ELSE
-            INSERT INTO events (title, 
-                description, 
-                date, 
-                type, 
-                recurrence,
-                status, 
-                minimum_age, 
-                maximum_age, 
-                image_url, 
-                full_name, 
-                phone_number, 
-                email)
-            VALUES (p_title,
-                p_description,
-                p_date,
-                p_type,
-                p_recurrence,
-                p_status,
-                p_minimum_age,
-                p_maximum_age,
-                p_image_url,
-                p_full_name,
-                p_phone_number,
-                p_email);
-        END IF;
+        INSERT INTO events (title, 
+            description, 
+            date, 
+            type, 
+            recurrence,
+            recurrence_end_date,
+            recurrence_count,
+            status, 
+            publish_at,
+            minimum_age, 
+            maximum_age, 
+            capacity,
+            room_id,
+            duration_minutes,
+            speaker_ids,
+            category_id,
+            tags,
+            image_url, 
+            full_name, 
+            phone_number, 
+            email,
+            show_full_name,
+            show_phone_number,
+            show_email,
+            contact_form_enabled)
+        VALUES (p_title,
+            p_description,
+            p_date,
+            p_type,
+            p_recurrence,
+            p_recurrence_end_date,
+            p_recurrence_count,
+            p_status,
+            p_publish_at,
+            p_minimum_age,
+            p_maximum_age,
+            p_capacity,
+            p_room_id,
+            p_duration_minutes,
+            p_speaker_ids,
+            p_category_id,
+            p_tags,
+            p_image_url,
+            p_full_name,
+            p_phone_number,
+            p_email,
+            p_show_full_name,
+            p_show_phone_number,
+            p_show_email,
+            p_contact_form_enabled);
+        SET v_saved_rows = ROW_COUNT();
+        SET p_id = LAST_INSERT_ID();
     ELSE 
+        -- Locking the event serialises this with registrations, so the waitlist is promoted against
+        -- the places actually taken
+        SELECT capacity INTO v_previous_capacity FROM events WHERE id = p_id FOR UPDATE;
         UPDATE events SET title = p_title, 
             description = p_description, 
             date = p_date,
             type = p_type,
             recurrence = p_recurrence,
+            recurrence_end_date = p_recurrence_end_date,
+            recurrence_count = p_recurrence_count,
             status = p_status,
+            publish_at = p_publish_at,
             minimum_age = p_minimum_age,
             maximum_age = p_maximum_age,
+            capacity = p_capacity,
+            room_id = p_room_id,
+            duration_minutes = p_duration_minutes,
+            speaker_ids = p_speaker_ids,
+            category_id = p_category_id,
+            tags = p_tags,
             image_url = p_image_url,
             full_name = p_full_name,
             phone_number = p_phone_number,
-            email = p_email
-        WHERE id = p_id;
+            email = p_email,
+            show_full_name = p_show_full_name,
+            show_phone_number = p_show_phone_number,
+            show_email = p_show_email,
+            contact_form_enabled = p_contact_form_enabled,
+            version = version + 1
+        WHERE id = p_id AND version = p_version;
+        SET v_saved_rows = ROW_COUNT();
+        -- Raising the capacity, or removing it altogether, can make room for those on the waitlist
+        IF v_saved_rows > 0 AND NOT (v_previous_capacity <=> p_capacity) THEN
+            CALL promote_waitlisted_event_registrations(p_id);
+        END IF;
+    END IF;
+    -- Returned so that the saved event can be cached without reading every event back
+    IF v_saved_rows > 0 THEN
+        SELECT id, version FROM events WHERE id = p_id;
     END IF;
 END //
 
+-- Uploads are content addressed, so events and speakers with identical images share one object.
+-- The url is withheld while anything else still references it, which stops the shared image being
+-- deleted
 CREATE PROCEDURE IF NOT EXISTS retrieve_image_url_by_event_id(IN p_id INT)
 BEGIN
-    SELECT image_url FROM events WHERE id = p_id;
+    SELECT CASE
+               WHEN EXISTS(SELECT 1 FROM events other WHERE other.image_url = e.image_url AND other.id <> p_id)
+                   OR EXISTS(SELECT 1 FROM speakers s WHERE s.photo_url = e.image_url)
+                   THEN NULL
+               ELSE e.image_url
+           END AS image_url
+    FROM events e
+    WHERE e.id = p_id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_event_status_by_id(IN p_id INT)
+BEGIN
+    SELECT status FROM events WHERE id = p_id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS update_event_status(IN p_id INT, IN p_status VARCHAR(15), IN p_version INT UNSIGNED)
+BEGIN
+    IF EXISTS(SELECT 1 FROM events WHERE id = p_id AND version <> p_version) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Version mismatch';
+    END IF;
+    UPDATE events SET status = p_status, version = version + 1 WHERE id = p_id AND version = p_version;
+    IF ROW_COUNT() > 0 THEN
+        SELECT version FROM events WHERE id = p_id;
+    END IF;
 END //
 
 CREATE PROCEDURE IF NOT EXISTS delete_event_by_id(IN p_id INT)
 BEGIN
+    DELETE FROM event_exceptions WHERE event_id = p_id;
+    DELETE c FROM event_check_ins c 
+    JOIN event_registrations r ON c.registration_id = r.id 
+    WHERE r.event_id = p_id;
+    DELETE FROM event_registrations WHERE event_id = p_id;
+    DELETE d FROM event_reminder_deliveries d 
+    JOIN event_reminder_subscriptions s ON d.subscription_id = s.id 
+    WHERE s.event_id = p_id;
+    DELETE FROM event_reminder_subscriptions WHERE event_id = p_id;
+    DELETE FROM organiser_messages WHERE event_id = p_id;
     DELETE FROM events WHERE id = p_id;
 END //
 
+-- rooms stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS get_rooms()
+BEGIN
+    SELECT id, name, capacity FROM rooms ORDER BY name;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS upsert_room(IN p_id INT, IN p_name VARCHAR(50), IN p_capacity SMALLINT UNSIGNED)
+BEGIN
+    IF EXISTS(SELECT 1 FROM rooms WHERE name = p_name AND id <> p_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Room already exists';
+    END IF;
+    IF p_id = 0 THEN
+        INSERT INTO rooms (name, capacity) VALUES (p_name, p_capacity);
+        SET p_id = LAST_INSERT_ID();
+    ELSEIF EXISTS(SELECT 1 FROM rooms WHERE id = p_id) THEN
+        UPDATE rooms SET name = p_name, capacity = p_capacity WHERE id = p_id;
+    ELSE
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Room not found';
+    END IF;
+    SELECT p_id;
+END //
+
+-- Held until the booking transaction ends, so that two events cannot both find the room free and
+-- then both be saved into it
+CREATE PROCEDURE IF NOT EXISTS lock_room_by_id(IN p_id INT)
+BEGIN
+    SELECT id FROM rooms WHERE id = p_id FOR UPDATE;
+END //
+
+-- Rooms still booked by an event are kept, so the event is not silently left without a venue
+CREATE PROCEDURE IF NOT EXISTS delete_room_by_id(IN p_id INT)
+BEGIN
+    IF EXISTS(SELECT 1 FROM events WHERE room_id = p_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Room in use';
+    END IF;
+    DELETE FROM rooms WHERE id = p_id;
+END //
+
+-- speakers stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS get_speakers()
+BEGIN
+    SELECT id, full_name, title, bio, photo_url, CAST(languages AS CHAR) AS languages 
+    FROM speakers 
+    ORDER BY full_name;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS upsert_speaker(IN p_id INT, 
+                                              IN p_full_name VARCHAR(100), 
+                                              IN p_title VARCHAR(50), 
+                                              IN p_bio VARCHAR(1000), 
+                                              IN p_photo_url VARCHAR(2083), 
+                                              IN p_languages JSON)
+BEGIN
+    IF p_id = 0 THEN
+        INSERT INTO speakers (full_name, title, bio, photo_url, languages) 
+        VALUES (p_full_name, p_title, p_bio, p_photo_url, p_languages);
+        SET p_id = LAST_INSERT_ID();
+    ELSEIF EXISTS(SELECT 1 FROM speakers WHERE id = p_id) THEN
+        UPDATE speakers SET full_name = p_full_name, 
+            title = p_title, 
+            bio = p_bio, 
+            photo_url = p_photo_url, 
+            languages = p_languages 
+        WHERE id = p_id;
+    ELSE
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Speaker not found';
+    END IF;
+    SELECT p_id;
+END //
+
+-- As with event images, the url is withheld while an event or another speaker shares the photo
+CREATE PROCEDURE IF NOT EXISTS retrieve_photo_url_by_speaker_id(IN p_id INT)
+BEGIN
+    SELECT CASE
+               WHEN EXISTS(SELECT 1 FROM speakers other WHERE other.photo_url = s.photo_url AND other.id <> p_id)
+                   OR EXISTS(SELECT 1 FROM events e WHERE e.image_url = s.photo_url)
+                   THEN NULL
+               ELSE s.photo_url
+           END AS photo_url
+    FROM speakers s
+    WHERE s.id = p_id;
+END //
+
+-- Checked before any orphaned upload is deleted, since the object may have been shared with an
+-- event or speaker saved in the meantime
+CREATE PROCEDURE IF NOT EXISTS is_image_url_referenced(IN p_url VARCHAR(2083))
+BEGIN
+    SELECT EXISTS(SELECT 1 FROM events WHERE image_url = p_url)
+        OR EXISTS(SELECT 1 FROM speakers WHERE photo_url = p_url);
+END //
+
+-- Speakers still leading an event are kept, so the event does not silently lose them
+CREATE PROCEDURE IF NOT EXISTS delete_speaker_by_id(IN p_id INT)
+BEGIN
+    IF EXISTS(SELECT 1 FROM events WHERE p_id MEMBER OF (speaker_ids)) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Speaker in use';
+    END IF;
+    DELETE FROM speakers WHERE id = p_id;
+END //
+
+-- event_categories stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS get_event_categories()
+BEGIN
+    SELECT id, name FROM event_categories ORDER BY name;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS upsert_event_category(IN p_id INT, IN p_name VARCHAR(50))
+BEGIN
+    IF EXISTS(SELECT 1 FROM event_categories WHERE name = p_name AND id <> p_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Category already exists';
+    END IF;
+    IF p_id = 0 THEN
+        INSERT INTO event_categories (name) VALUES (p_name);
+        SET p_id = LAST_INSERT_ID();
+    ELSEIF EXISTS(SELECT 1 FROM event_categories WHERE id = p_id) THEN
+        UPDATE event_categories SET name = p_name WHERE id = p_id;
+    ELSE
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Category not found';
+    END IF;
+    SELECT p_id;
+END //
+
+-- Categories still used by an event are kept, so the event is not silently left uncategorised
+CREATE PROCEDURE IF NOT EXISTS delete_event_category_by_id(IN p_id INT)
+BEGIN
+    IF EXISTS(SELECT 1 FROM events WHERE category_id = p_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Category in use';
+    END IF;
+    DELETE FROM event_categories WHERE id = p_id;
+END //
+
+-- Tags only exist on the events using them, so unpublished events' tags are withheld from the
+-- public along with the events
+CREATE PROCEDURE IF NOT EXISTS get_event_tags(IN p_published_only BOOLEAN)
+BEGIN
+    SELECT DISTINCT event_tags.tag 
+    FROM events, 
+    JSON_TABLE(events.tags, '$[*]' COLUMNS (tag VARCHAR(30) PATH '$')) event_tags 
+    WHERE NOT p_published_only OR events.status IN ('confirmed', 'postponed', 'cancelled') 
+    ORDER BY event_tags.tag;
+END //
+
+-- event_exceptions stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS get_event_exceptions()
+BEGIN
+    SELECT event_id, occurrence_date, replacement_date 
+    FROM event_exceptions;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS upsert_event_exception(IN p_event_id INT, 
+                                                      IN p_occurrence_date TIMESTAMP, 
+                                                      IN p_replacement_date TIMESTAMP)
+BEGIN
+    IF NOT EXISTS(SELECT 1 FROM events WHERE id = p_event_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Event not found';
+    END IF;
+    INSERT INTO event_exceptions (event_id, occurrence_date, replacement_date) 
+    VALUES (p_event_id, p_occurrence_date, p_replacement_date) 
+    ON DUPLICATE KEY UPDATE replacement_date = p_replacement_date;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS delete_event_exception(IN p_event_id INT, IN p_occurrence_date TIMESTAMP)
+BEGIN
+    DELETE FROM event_exceptions WHERE event_id = p_event_id AND occurrence_date = p_occurrence_date;
+END //
+
+-- event_registrations stored procedures
+
+-- Confirms waitlisted registrations in the order they were made, for as long as each party fits
+-- into the remaining places. Stopping at the first party that does not fit keeps the waitlist fair
+CREATE PROCEDURE IF NOT EXISTS promote_waitlisted_event_registrations(IN p_event_id INT)
+BEGIN
+    DECLARE v_capacity SMALLINT UNSIGNED;
+    DECLARE v_places_taken INT;
+    SELECT capacity INTO v_capacity FROM events WHERE id = p_event_id;
+    SELECT COALESCE(SUM(party_size), 0) INTO v_places_taken 
+    FROM event_registrations 
+    WHERE event_id = p_event_id AND status = 'confirmed';
+    UPDATE event_registrations r
+    JOIN (SELECT id, SUM(party_size) OVER (ORDER BY registered_at, id) AS places_needed
+          FROM event_registrations
+          WHERE event_id = p_event_id AND status = 'waitlisted') waitlist ON r.id = waitlist.id
+    SET r.status = 'confirmed'
+    WHERE v_capacity IS NULL OR v_places_taken + waitlist.places_needed <= v_capacity;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS insert_event_registration(IN p_event_id INT, 
+                                                         IN p_full_name VARCHAR(100), 
+                                                         IN p_email VARCHAR(50), 
+                                                         IN p_phone_number VARCHAR(15), 
+                                                         IN p_party_size TINYINT UNSIGNED)
+BEGIN
+    DECLARE v_capacity SMALLINT UNSIGNED;
+    DECLARE v_places_taken INT;
+    DECLARE v_status VARCHAR(10) DEFAULT 'confirmed';
+    -- Registrations are only open while the event is published and going ahead
+    IF NOT EXISTS(SELECT 1 FROM events WHERE id = p_event_id AND status IN ('confirmed', 'postponed')) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Event not found';
+    END IF;
+    START TRANSACTION;
+    -- Locking the event serialises registrations, so concurrent requests cannot overfill it
+    SELECT capacity INTO v_capacity FROM events WHERE id = p_event_id FOR UPDATE;
+    IF v_capacity IS NOT NULL AND p_party_size > v_capacity THEN
+        ROLLBACK;
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Party size exceeds capacity';
+    END IF;
+    SELECT COALESCE(SUM(party_size), 0) INTO v_places_taken 
+    FROM event_registrations 
+    WHERE event_id = p_event_id AND status = 'confirmed';
+    -- Nobody is confirmed ahead of those already waiting
+    IF v_capacity IS NOT NULL 
+        AND (v_places_taken + p_party_size > v_capacity 
+            OR EXISTS(SELECT 1 FROM event_registrations WHERE event_id = p_event_id AND status = 'waitlisted')) THEN
+        SET v_status = 'waitlisted';
+    END IF;
+    INSERT INTO event_registrations (event_id, full_name, email, phone_number, party_size, status) 
+    VALUES (p_event_id, p_full_name, p_email, p_phone_number, p_party_size, v_status);
+    SELECT LAST_INSERT_ID() AS id, v_status AS status;
+    COMMIT;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS cancel_event_registration(IN p_id INT)
+BEGIN
+    DECLARE v_event_id INT;
+    SELECT event_id INTO v_event_id FROM event_registrations WHERE id = p_id;
+    IF v_event_id IS NULL THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Registration not found';
+    END IF;
+    START TRANSACTION;
+    SELECT id INTO v_event_id FROM events WHERE id = v_event_id FOR UPDATE;
+    DELETE FROM event_check_ins WHERE registration_id = p_id;
+    DELETE FROM event_registrations WHERE id = p_id;
+    CALL promote_waitlisted_event_registrations(v_event_id);
+    COMMIT;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_event_registrations_by_event_id(IN p_event_id INT)
+BEGIN
+    SELECT id, 
+    event_id, 
+    full_name, 
+    email, 
+    phone_number, 
+    party_size, 
+    status, 
+    registered_at
+    FROM event_registrations 
+    WHERE event_id = p_event_id
+    ORDER BY registered_at, id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_event_id_by_registration_id(IN p_registration_id INT)
+BEGIN
+    SELECT event_id FROM event_registrations WHERE id = p_registration_id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS insert_event_check_in(IN p_registration_id INT, 
+                                                     IN p_occurrence_date TIMESTAMP)
+BEGIN
+    DECLARE v_status VARCHAR(10);
+    SELECT status INTO v_status FROM event_registrations WHERE id = p_registration_id;
+    IF v_status IS NULL THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Registration not found';
+    ELSEIF v_status <> 'confirmed' THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Registration not confirmed';
+    ELSEIF EXISTS(SELECT 1 FROM event_check_ins 
+                  WHERE registration_id = p_registration_id AND occurrence_date = p_occurrence_date) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Already checked in';
+    END IF;
+    INSERT INTO event_check_ins (registration_id, occurrence_date) 
+    VALUES (p_registration_id, p_occurrence_date);
+    SELECT r.event_id, 
+    r.full_name, 
+    r.party_size, 
+    c.occurrence_date, 
+    c.checked_in_at
+    FROM event_check_ins c 
+    JOIN event_registrations r ON c.registration_id = r.id
+    WHERE c.registration_id = p_registration_id AND c.occurrence_date = p_occurrence_date;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS get_event_attendance(IN p_event_id INT)
+BEGIN
+    DECLARE v_expected_attendees BIGINT;
+    SELECT COALESCE(SUM(party_size), 0) INTO v_expected_attendees 
+    FROM event_registrations 
+    WHERE event_id = p_event_id AND status = 'confirmed';
+    SELECT c.occurrence_date, 
+    v_expected_attendees AS expected_attendees, 
+    COUNT(*) AS checked_in_registrations, 
+    CAST(SUM(r.party_size) AS SIGNED) AS checked_in_attendees
+    FROM event_check_ins c 
+    JOIN event_registrations r ON c.registration_id = r.id
+    WHERE r.event_id = p_event_id
+    GROUP BY c.occurrence_date
+    ORDER BY c.occurrence_date;
+END //
+
+-- event_reminder stored procedures
+
+CREATE PROCEDURE IF NOT EXISTS insert_event_reminder_subscription(IN p_event_id INT, 
+                                                                  IN p_event_type VARCHAR(10), 
+                                                                  IN p_channel VARCHAR(5), 
+                                                                  IN p_recipient VARCHAR(255), 
+                                                                  IN p_hours_before SMALLINT UNSIGNED)
+BEGIN
+    IF p_event_id IS NOT NULL 
+        AND NOT EXISTS(SELECT 1 FROM events WHERE id = p_event_id AND status IN ('confirmed', 'postponed')) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Event not found';
+    END IF;
+    -- Push subscriptions come from the device itself, so only email subscriptions need confirming
+    INSERT INTO event_reminder_subscriptions (event_id, event_type, channel, recipient, hours_before, confirmed_at) 
+    VALUES (p_event_id, p_event_type, p_channel, p_recipient, p_hours_before, 
+            IF(p_channel = 'push', CURRENT_TIMESTAMP, NULL));
+    SELECT LAST_INSERT_ID() AS id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS delete_event_reminder_subscription(IN p_id INT)
+BEGIN
+    IF NOT EXISTS(SELECT 1 FROM event_reminder_subscriptions WHERE id = p_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Subscription not found';
+    END IF;
+    DELETE FROM event_reminder_deliveries WHERE subscription_id = p_id;
+    DELETE FROM event_reminder_subscriptions WHERE id = p_id;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS confirm_event_reminder_subscription(IN p_id INT)
+BEGIN
+    IF NOT EXISTS(SELECT 1 FROM event_reminder_subscriptions WHERE id = p_id) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Subscription not found';
+    END IF;
+    UPDATE event_reminder_subscriptions 
+    SET confirmed_at = COALESCE(confirmed_at, CURRENT_TIMESTAMP) 
+    WHERE id = p_id;
+END //
+
+-- Only confirmed subscriptions are sent reminders
+CREATE PROCEDURE IF NOT EXISTS get_event_reminder_subscriptions()
+BEGIN
+    SELECT id, 
+    event_id, 
+    event_type, 
+    channel, 
+    recipient, 
+    hours_before
+    FROM event_reminder_subscriptions 
+    WHERE confirmed_at IS NOT NULL
+    ORDER BY id;
+END //
+
+-- Claims the reminder for the occurrence, returning whether it had not already been claimed. Claiming
+-- before sending means a reminder is never sent twice, even when several schedulers are running
+CREATE PROCEDURE IF NOT EXISTS claim_event_reminder_delivery(IN p_subscription_id INT, 
+                                                             IN p_occurrence_date TIMESTAMP)
+BEGIN
+    INSERT IGNORE INTO event_reminder_deliveries (subscription_id, occurrence_date) 
+    VALUES (p_subscription_id, p_occurrence_date);
+    SELECT ROW_COUNT() > 0 AS claimed;
+END //
+
+-- Gives up a claim when the reminder could not be sent, so that it is tried again
+CREATE PROCEDURE IF NOT EXISTS release_event_reminder_delivery(IN p_subscription_id INT, 
+                                                               IN p_occurrence_date TIMESTAMP)
+BEGIN
+    DELETE FROM event_reminder_deliveries 
+    WHERE subscription_id = p_subscription_id AND occurrence_date = p_occurrence_date;
+END //
+
 -- imam_question stored procedures
 
 CREATE PROCEDURE IF NOT EXISTS get_all_imam_questions()
@@ -164,7 +1129,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered
+    date_answered,
+    version
     FROM imam_question;
 END //
 
@@ -178,7 +1144,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered
+    date_answered,
+    version
     FROM imam_question WHERE answer IS NULL;
 END //
 
@@ -192,7 +1159,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered
+    date_answered,
+    version
     FROM imam_question WHERE answer IS NULL AND topic = p_topic;
 END //
 
@@ -206,7 +1174,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered
+    date_answered,
+    version
     FROM imam_question WHERE answer IS NULL AND (school_of_thought = p_school_of_thought OR school_of_thought IS NULL);
 END //
 
@@ -221,7 +1190,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered
+    date_answered,
+    version
     FROM imam_question WHERE answer IS NULL AND topic = p_topic AND (school_of_thought = p_school_of_thought OR school_of_thought IS NULL);
 END //
 
@@ -235,13 +1205,34 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered
+    date_answered,
+    version
     FROM imam_question WHERE answer IS NOT NULL;
 END //
 
+-- Unanswered questions are never searchable, as they are not public
+CREATE PROCEDURE IF NOT EXISTS search_answered_imam_questions(IN p_query VARCHAR(100), IN p_limit INT UNSIGNED)
+BEGIN
+    SELECT id,
+    title,
+    topic,
+    school_of_thought,
+    description,
+    date,
+    imam_name,
+    answer,
+    date_answered,
+    version,
+    MATCH (title, description, answer) AGAINST (p_query IN NATURAL LANGUAGE MODE) AS relevance
+    FROM imam_question
+    WHERE answer IS NOT NULL
+    AND MATCH (title, description, answer) AGAINST (p_query IN NATURAL LANGUAGE MODE)
+    ORDER BY relevance DESC, id
+    LIMIT p_limit;
+END //
+
 CREATE PROCEDURE IF NOT EXISTS get_answered_imam_questions_by_topic(IN p_topic VARCHAR(20))
 BEGIN
-    SELECT 
     SELECT id,
     title,
     topic,
@@ -250,7 +1241,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered FROM imam_question WHERE answer IS NOT NULL AND topic = p_topic;
+    date_answered,
+    version FROM imam_question WHERE answer IS NOT NULL AND topic = p_topic;
 END //
 
 CREATE PROCEDURE IF NOT EXISTS get_answered_imam_questions_by_school_of_thought(IN p_school_of_thought VARCHAR(7))
@@ -263,7 +1255,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered FROM imam_question WHERE answer IS NOT NULL AND (school_of_thought = p_school_of_thought OR school_of_thought IS NULL);
+    date_answered,
+    version FROM imam_question WHERE answer IS NOT NULL AND (school_of_thought = p_school_of_thought OR school_of_thought IS NULL);
 END //
 
 CREATE PROCEDURE IF NOT EXISTS get_answered_imam_questions_by_topic_and_school_of_thought(IN p_topic VARCHAR(20), 
@@ -277,7 +1270,8 @@ BEGIN
     date,
     imam_name,
     answer,
-    date_answered FROM imam_question WHERE answer IS NOT NULL AND topic = p_topic AND (school_of_thought = p_school_of_thought OR school_of_thought IS NULL);
+    date_answered,
+    version FROM imam_question WHERE answer IS NOT NULL AND topic = p_topic AND (school_of_thought = p_school_of_thought OR school_of_thought IS NULL);
 END //
 
 CREATE PROCEDURE IF NOT EXISTS insert_question_for_imam(IN p_title VARCHAR(50),
@@ -302,13 +1296,18 @@ END //
 CREATE PROCEDURE IF NOT EXISTS upsert_imam_answer_to_question(IN p_imam_name VARCHAR(50), 
                                                               IN p_answer VARCHAR(250),
                                                               IN p_date_answered TIMESTAMP,
-                                                              IN p_id INT)
+                                                              IN p_id INT,
+                                                              IN p_version INT UNSIGNED)
 BEGIN
+    IF EXISTS(SELECT 1 FROM imam_question WHERE id = p_id AND version <> p_version) THEN
+        SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Version mismatch';
+    END IF;
     UPDATE imam_question 
     SET imam_name = p_imam_name, 
         answer = p_answer, 
-        date_answered = p_date_answered
-    WHERE id = p_id;
+        date_answered = p_date_answered,
+        version = version + 1
+    WHERE id = p_id AND version = p_version;
 END //
 
 CREATE PROCEDURE IF NOT EXISTS delete_imam_question_by_id(IN p_id INT)
@@ -316,4 +1315,206 @@ BEGIN
     DELETE FROM imam_question WHERE id = p_id;
 END //
 
+-- audit_log stored procedures
+
+-- Entries can never be changed or removed, even by users able to write to the table directly
+CREATE TRIGGER IF NOT EXISTS prevent_audit_log_update BEFORE UPDATE ON audit_log
+FOR EACH ROW
+BEGIN
+    SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Audit log is append only';
+END //
+
+CREATE TRIGGER IF NOT EXISTS prevent_audit_log_delete BEFORE DELETE ON audit_log
+FOR EACH ROW
+BEGIN
+    SIGNAL SQLSTATE '45000' SET MESSAGE_TEXT = 'Audit log is append only';
+END //
+
+CREATE PROCEDURE IF NOT EXISTS insert_audit_log_entry(IN p_actor VARCHAR(200), 
+                                                      IN p_action VARCHAR(10), 
+                                                      IN p_entity_type VARCHAR(20), 
+                                                      IN p_entity_id INT, 
+                                                      IN p_before_snapshot JSON, 
+                                                      IN p_after_snapshot JSON, 
+                                                      IN p_request_id VARCHAR(64), 
+                                                      IN p_occurred_at TIMESTAMP)
+BEGIN
+    INSERT INTO audit_log (actor, 
+        action, 
+        entity_type, 
+        entity_id, 
+        before_snapshot, 
+        after_snapshot, 
+        request_id, 
+        occurred_at)
+    VALUES (p_actor, 
+        p_action, 
+        p_entity_type, 
+        p_entity_id, 
+        p_before_snapshot, 
+        p_after_snapshot, 
+        p_request_id, 
+        p_occurred_at);
+END //
+
+-- Newest entries first, so that recent changes are on the first page
+CREATE PROCEDURE IF NOT EXISTS get_audit_log_entries(IN p_actor VARCHAR(200), 
+                                                     IN p_action VARCHAR(10), 
+                                                     IN p_entity_type VARCHAR(20), 
+                                                     IN p_entity_id INT, 
+                                                     IN p_from TIMESTAMP, 
+                                                     IN p_to TIMESTAMP, 
+                                                     IN p_limit INT UNSIGNED, 
+                                                     IN p_offset BIGINT UNSIGNED)
+BEGIN
+    SELECT id, 
+    actor, 
+    action, 
+    entity_type, 
+    entity_id, 
+    CAST(before_snapshot AS CHAR) AS before_snapshot, 
+    CAST(after_snapshot AS CHAR) AS after_snapshot, 
+    request_id, 
+    occurred_at
+    FROM audit_log
+    WHERE (p_actor IS NULL OR actor = p_actor)
+    AND (p_action IS NULL OR action = p_action)
+    AND (p_entity_type IS NULL OR entity_type = p_entity_type)
+    AND (p_entity_id IS NULL OR entity_id = p_entity_id)
+    AND (p_from IS NULL OR occurred_at >= p_from)
+    AND (p_to IS NULL OR occurred_at < p_to)
+    ORDER BY occurred_at DESC, id DESC
+    LIMIT p_limit OFFSET p_offset;
+END //
+
+CREATE PROCEDURE IF NOT EXISTS count_audit_log_entries(IN p_actor VARCHAR(200), 
+                                                       IN p_action VARCHAR(10), 
+                                                       IN p_entity_type VARCHAR(20), 
+                                                       IN p_entity_id INT, 
+                                                       IN p_from TIMESTAMP, 
+                                                       IN p_to TIMESTAMP)
+BEGIN
+    SELECT COUNT(*)
+    FROM audit_log
+    WHERE (p_actor IS NULL OR actor = p_actor)
+    AND (p_action IS NULL OR action = p_action)
+    AND (p_entity_type IS NULL OR entity_type = p_entity_type)
+    AND (p_entity_id IS NULL OR entity_id = p_entity_id)
+    AND (p_from IS NULL OR occurred_at >= p_from)
+    AND (p_to IS NULL OR occurred_at < p_to);
+END //
+
+-- Snapshots are read as they are stored rather than through each feature, so that the audit user
+-- can take them without being able to change anything. Prayer times are summarised by their hash,
+-- as the timetable itself is too large to keep a copy of on every change
+CREATE PROCEDURE IF NOT EXISTS get_audit_snapshot(IN p_entity_type VARCHAR(20), IN p_entity_id INT)
+BEGIN
+    IF p_entity_type = 'event' THEN
+        SELECT CAST(JSON_OBJECT('id', id, 
+            'title', title, 
+            'description', description, 
+            'date', date, 
+            'type', type, 
+            'recurrence', recurrence, 
+            'recurrenceEndDate', recurrence_end_date, 
+            'recurrenceCount', recurrence_count, 
+            'status', status, 
+            'publishAt', publish_at, 
+            'minimumAge', minimum_age, 
+            'maximumAge', maximum_age, 
+            'capacity', capacity, 
+            'roomId', room_id, 
+            'durationMinutes', duration_minutes, 
+            'speakerIds', speaker_ids, 
+            'categoryId', category_id, 
+            'tags', tags, 
+            'imageUrl', image_url, 
+            'fullName', full_name, 
+            'phoneNumber', phone_number, 
+            'email', email, 
+            'showFullName', show_full_name IS TRUE, 
+            'showPhoneNumber', show_phone_number IS TRUE, 
+            'showEmail', show_email IS TRUE, 
+            'contactFormEnabled', contact_form_enabled IS TRUE, 
+            'version', version) AS CHAR)
+        FROM events 
+        WHERE id = p_entity_id;
+    ELSEIF p_entity_type = 'imam_question' THEN
+        SELECT CAST(JSON_OBJECT('id', id, 
+            'title', title, 
+            'topic', topic, 
+            'schoolOfThought', school_of_thought, 
+            'description', description, 
+            'date', date, 
+            'imamName', imam_name, 
+            'answer', answer, 
+            'dateAnswered', date_answered, 
+            'version', version) AS CHAR)
+        FROM imam_question 
+        WHERE id = p_entity_id;
+    ELSEIF p_entity_type = 'event_exceptions' THEN
+        SELECT CAST(COALESCE(JSON_ARRAYAGG(JSON_OBJECT('occurrenceDate', occurrence_date, 
+            'replacementDate', replacement_date)), JSON_ARRAY()) AS CHAR)
+        FROM event_exceptions 
+        WHERE event_id = p_entity_id;
+    ELSEIF p_entity_type = 'room' THEN
+        SELECT CAST(JSON_OBJECT('id', id, 'name', name, 'capacity', capacity) AS CHAR)
+        FROM rooms 
+        WHERE id = p_entity_id;
+    ELSEIF p_entity_type = 'event_category' THEN
+        SELECT CAST(JSON_OBJECT('id', id, 'name', name) AS CHAR)
+        FROM event_categories 
+        WHERE id = p_entity_id;
+    ELSEIF p_entity_type = 'speaker' THEN
+        SELECT CAST(JSON_OBJECT('id', id, 
+            'fullName', full_name, 
+            'title', title, 
+            'bio', bio, 
+            'photoUrl', photo_url, 
+            'languages', languages) AS CHAR)
+        FROM speakers 
+        WHERE id = p_entity_id;
+    ELSEIF p_entity_type = 'event_registration' THEN
+        SELECT CAST(JSON_OBJECT('id', r.id, 
+            'eventId', r.event_id, 
+            'fullName', r.full_name, 
+            'partySize', r.party_size, 
+            'status', r.status, 
+            'checkIns', (SELECT COALESCE(JSON_ARRAYAGG(JSON_OBJECT('occurrenceDate', c.occurrence_date, 
+                                                                   'checkedInAt', c.checked_in_at)), JSON_ARRAY())
+                         FROM event_check_ins c 
+                         WHERE c.registration_id = r.id)) AS CHAR)
+        FROM event_registrations r 
+        WHERE r.id = p_entity_id;
+    -- Neither the password nor the MFA secret are copied, only when the password was last changed
+    ELSEIF p_entity_type = 'user' THEN
+        SELECT CAST(JSON_OBJECT('id', u.id, 
+            'fullName', u.full_name, 
+            'role', u.role, 
+            'email', u.email, 
+            'username', u.username, 
+            'mfaEnabled', u.mfa_enabled IS TRUE, 
+            'isDisabled', u.is_disabled IS TRUE, 
+            'passwordLastChanged', (SELECT MAX(h.date_created) 
+                                    FROM password_history h 
+                                    WHERE h.username = u.username)) AS CHAR)
+        FROM user_details u 
+        WHERE u.id = p_entity_id;
+    ELSEIF p_entity_type = 'mfa_policy' THEN
+        SELECT CAST(COALESCE(JSON_ARRAYAGG(JSON_OBJECT('role', role, 
+            'isMfaRequired', is_mfa_required IS TRUE)), JSON_ARRAY()) AS CHAR)
+        FROM mfa_policy;
+    ELSEIF p_entity_type = 'prayer_times' THEN
+        SELECT CAST(JSON_OBJECT('hash', hash, 'size', LENGTH(data)) AS CHAR)
+        FROM prayer_times 
+        LIMIT 1;
+    END IF;
+END //
+
+-- Users are audited by their id, whereas password resets only know them by their username
+CREATE PROCEDURE IF NOT EXISTS get_audit_user_id(IN p_username VARCHAR(200))
+BEGIN
+    SELECT id FROM user_details WHERE username = p_username;
+END //
+
 DELIMITER ;
\ No newline at end of file
@@ -3,6 +3,7 @@ CREATE USER IF NOT EXISTS 'authenticationuser'@'%' IDENTIFIED BY 'BL6FxKu!237GvP
 CREATE USER IF NOT EXISTS 'prayertimesadmin'@'%' IDENTIFIED BY 'HR0o8NRkwvuMaIBh7yaf';
 CREATE USER IF NOT EXISTS 'eventsadmin'@'%' IDENTIFIED BY 'changeme';
 CREATE USER IF NOT EXISTS 'askimamadmin'@'%' IDENTIFIED BY 'changeme';
+CREATE USER IF NOT EXISTS 'auditlogadmin'@'%' IDENTIFIED BY 'changeme';
 
 -- Adjust user permissions
 REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'authenticationuser'@'%';
@@ -10,6 +11,27 @@ GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_username TO 'authenticationuser
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_user_credentials TO 'authenticationuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.register_user TO 'authenticationuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.reset_user_password TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.update_user_password_hash TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_username_by_email TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_user_email TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_password_history TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_password_reset_token_username TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_password_reset_token TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.reset_user_password_with_token TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_user_mfa_details TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.set_user_mfa_secret TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.enable_user_mfa TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.disable_user_mfa TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.accept_totp_time_step TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.record_failed_mfa_attempt TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_user_recovery_code TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.consume_user_recovery_code TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_mfa_policy TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_users TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_user_by_id TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.update_user_details TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.set_user_disabled TO 'authenticationuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_user TO 'authenticationuser'@'%';
 
 REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'prayertimesadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_prayer_times TO 'prayertimesadmin'@'%';
@@ -18,9 +40,37 @@ GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_prayer_times TO 'prayertimes
 
 REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'eventsadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_events TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_filtered_events TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.count_filtered_events TO 'eventsadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_event TO 'eventsadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.retrieve_image_url_by_event_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_status_by_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.update_event_status TO 'eventsadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_event_by_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_exceptions TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_event_exception TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_event_exception TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_rooms TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_room TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.lock_room_by_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_room_by_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_speakers TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_speaker TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.retrieve_photo_url_by_speaker_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.is_image_url_referenced TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_speaker_by_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_categories TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_event_category TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_event_category_by_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_tags TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_registrations_by_event_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_id_by_registration_id TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_event_check_in TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_attendance TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_reminder_subscriptions TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.claim_event_reminder_delivery TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.release_event_reminder_delivery TO 'eventsadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_event_reminder_subscription TO 'eventsadmin'@'%';
 
 REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'askimamadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_all_imam_questions TO 'askimamadmin'@'%';
@@ -35,3 +85,10 @@ GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_answered_imam_questions_by_topi
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_question_for_imam TO 'askimamadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.upsert_imam_answer_to_question TO 'askimamadmin'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_imam_question_by_id TO 'askimamadmin'@'%';
+
+REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'auditlogadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_audit_log_entry TO 'auditlogadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_audit_log_entries TO 'auditlogadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.count_audit_log_entries TO 'auditlogadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_audit_snapshot TO 'auditlogadmin'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_audit_user_id TO 'auditlogadmin'@'%';
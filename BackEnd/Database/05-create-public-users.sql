@@ -10,11 +10,27 @@ GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_updated_prayer_times TO 'prayer
 
 REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'eventsuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_events TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_filtered_events TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.count_filtered_events TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.search_events TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_organiser_contact TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.record_organiser_message TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_exceptions TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_rooms TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_speakers TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_categories TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_event_tags TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_event_registration TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.cancel_event_registration TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_event_reminder_subscription TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.delete_event_reminder_subscription TO 'eventsuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.confirm_event_reminder_subscription TO 'eventsuser'@'%';
 
 REVOKE ALL PRIVILEGES, GRANT OPTION FROM 'askimamuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_answered_imam_questions TO 'askimamuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_answered_imam_questions_by_topic TO 'askimamuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_answered_imam_questions_by_school_of_thought TO 'askimamuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.get_answered_imam_questions_by_topic_and_school_of_thought TO 'askimamuser'@'%';
+GRANT EXECUTE ON PROCEDURE masjidappdatabase.search_answered_imam_questions TO 'askimamuser'@'%';
 GRANT EXECUTE ON PROCEDURE masjidappdatabase.insert_question_for_imam TO 'askimamuser'@'%';
 
@@ -1,22 +1,45 @@
 use crate::features::ask_imam::errors::delete_question_error::DeleteQuestionError;
 use crate::features::ask_imam::services::AskImamAdminService;
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::shared::audited_app_state::AuditedServiceAppState;
 use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
-use masjid_app_api_library::shared::types::app_state::ServiceAppState;
 use std::sync::Arc;
 
 pub async fn delete_imam_question(
-    State(state): State<ServiceAppState<Arc<dyn AskImamAdminService>>>,
-    _claims: Claims,
+    State(state): State<AuditedServiceAppState<Arc<dyn AskImamAdminService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
     Path(questions_id): Path<i32>,
 ) -> Response {
     if questions_id == 0 {
         return (StatusCode::BAD_REQUEST, "question ids cannot be 0").into_response();
     }
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::ImamQuestion, Some(questions_id))
+        .await;
     match state.service.delete_question(questions_id).await {
-        Ok(()) => StatusCode::OK.into_response(),
+        Ok(()) => {
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Delete,
+                    entity_type: AuditEntityType::ImamQuestion,
+                    entity_id: Some(questions_id),
+                    before,
+                    after: None,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
         Err(DeleteQuestionError::QuestionNotFound) => StatusCode::NOT_FOUND.into_response(),
         Err(DeleteQuestionError::UnableToDeleteQuestion) => {
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
@@ -27,6 +50,8 @@ pub async fn delete_imam_question(
 mod test {
     use super::*;
     use crate::features::ask_imam::services::MockAskImamAdminService;
+    use crate::features::audit_log::services::{MockAuditLogService, new_test_audit_log_service};
+    use serde_json::json;
     #[tokio::test]
     async fn test_delete_imam_question() {
         struct TestCase {
@@ -70,16 +95,60 @@ mod test {
                     .returning(move |_| expected_db_response);
             }
             let arc_respository: Arc<dyn AskImamAdminService> = Arc::new(mock_service);
-            let app_state = ServiceAppState {
+            let app_state = AuditedServiceAppState {
                 service: arc_respository,
+                audit_log: new_test_audit_log_service(),
             };
             let actual_response = delete_imam_question(
                 State(app_state),
                 Claims::default(),
+                RequestId::default(),
                 Path(test_case.question_id),
             )
             .await;
             assert_eq!(test_case.expected_status_code, actual_response.status());
         }
     }
+
+    #[tokio::test]
+    async fn test_delete_imam_question_is_audited() {
+        let mut mock_service = MockAskImamAdminService::new();
+        mock_service.expect_delete_question().returning(|_| Ok(()));
+        let mut mock_audit_log = MockAuditLogService::new();
+        mock_audit_log
+            .expect_take_snapshot()
+            .withf(|entity_type, entity_id| {
+                *entity_type == AuditEntityType::ImamQuestion && *entity_id == Some(4)
+            })
+            .times(1)
+            .returning(|_, _| Some(json!({"id": 4, "version": 2})));
+        mock_audit_log
+            .expect_record_change()
+            .withf(|change| {
+                *change
+                    == AuditedChange {
+                        actor: "admin".to_owned(),
+                        action: AuditAction::Delete,
+                        entity_type: AuditEntityType::ImamQuestion,
+                        entity_id: Some(4),
+                        before: Some(json!({"id": 4, "version": 2})),
+                        after: None,
+                        request_id: "request".to_owned(),
+                    }
+            })
+            .times(1)
+            .returning(|_| ());
+        let app_state = AuditedServiceAppState::<Arc<dyn AskImamAdminService>> {
+            service: Arc::new(mock_service),
+            audit_log: Arc::new(mock_audit_log),
+        };
+        let actual_response = delete_imam_question(
+            State(app_state),
+            Claims::generate("admin", "Admin"),
+            RequestId("request".to_owned()),
+            Path(4),
+        )
+        .await;
+        assert_eq!(StatusCode::OK, actual_response.status());
+    }
 }
@@ -1,31 +1,67 @@
 use crate::features::ask_imam::errors::upsert_answer_to_question_error::UpsertAnswerToQuestionError;
 use crate::features::ask_imam::models::provide_answer_for_imam_question_request::ProvideAnswerForImamQuestionRequest;
 use crate::features::ask_imam::services::AskImamAdminService;
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::if_match::{IfMatch, entity_tag};
 use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
 use axum::Json;
 use axum::extract::State;
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
 use axum::response::{IntoResponse, Response};
-use masjid_app_api_library::shared::types::app_state::ServiceAppState;
 use std::sync::Arc;
 use validator::Validate;
 
 pub async fn provide_answer_for_imam_question(
-    State(state): State<ServiceAppState<Arc<dyn AskImamAdminService>>>,
+    State(state): State<AuditedServiceAppState<Arc<dyn AskImamAdminService>>>,
     claims: Claims,
+    IfMatch(version): IfMatch,
+    RequestId(request_id): RequestId,
     Json(request): Json<ProvideAnswerForImamQuestionRequest>,
 ) -> Response {
     if request.validate().is_err() {
         return StatusCode::BAD_REQUEST.into_response();
     }
+    let Some(version) = version else {
+        return StatusCode::PRECONDITION_REQUIRED.into_response();
+    };
 
+    let question_id = request.question_id;
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::ImamQuestion, Some(question_id))
+        .await;
     match state
         .service
-        .provide_answer_to_question(request.question_id, request.into())
+        .provide_answer_to_question(question_id, version, request.into())
         .await
     {
-        Ok(()) => StatusCode::OK.into_response(),
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::ImamQuestion, Some(question_id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::ImamQuestion,
+                    entity_id: Some(question_id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            (StatusCode::OK, [(header::ETAG, entity_tag(version + 1))]).into_response()
+        }
         Err(UpsertAnswerToQuestionError::QuestionNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpsertAnswerToQuestionError::VersionMismatch) => {
+            StatusCode::PRECONDITION_FAILED.into_response()
+        }
         Err(UpsertAnswerToQuestionError::UnableToUpsertAnswerToQuestion) => {
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
         }
@@ -37,12 +73,14 @@ mod tests {
     use super::*;
     use crate::features::ask_imam::errors::upsert_answer_to_question_error::UpsertAnswerToQuestionError;
     use crate::features::ask_imam::services::MockAskImamAdminService;
+    use crate::features::audit_log::services::new_test_audit_log_service;
 
     #[tokio::test]
     async fn test_provide_answer_for_imam_question() {
         struct TestCase {
             description: &'static str,
             request: ProvideAnswerForImamQuestionRequest,
+            if_match: IfMatch,
             expected_db_response: Option<Result<(), UpsertAnswerToQuestionError>>,
             expected_status_code: StatusCode,
         }
@@ -59,18 +97,35 @@ mod tests {
                     imam_name: "".to_owned(),
                     text: "".to_owned(),
                 },
+                if_match: IfMatch(Some(1)),
                 expected_db_response: None,
                 expected_status_code: StatusCode::BAD_REQUEST,
             },
+            TestCase {
+                description: "When the version being answered is not given, I should get a PRECONDITION_REQUIRED response",
+                request: valid_request.clone(),
+                if_match: IfMatch(None),
+                expected_db_response: None,
+                expected_status_code: StatusCode::PRECONDITION_REQUIRED,
+            },
             TestCase {
                 description: "When upserting an answer to a non-existent question, I should get a NOT_FOUND response",
                 request: valid_request.clone(),
+                if_match: IfMatch(Some(1)),
                 expected_db_response: Some(Err(UpsertAnswerToQuestionError::QuestionNotFound)),
                 expected_status_code: StatusCode::NOT_FOUND,
             },
+            TestCase {
+                description: "When the question has changed since the version being answered, I should get a PRECONDITION_FAILED response",
+                request: valid_request.clone(),
+                if_match: IfMatch(Some(1)),
+                expected_db_response: Some(Err(UpsertAnswerToQuestionError::VersionMismatch)),
+                expected_status_code: StatusCode::PRECONDITION_FAILED,
+            },
             TestCase {
                 description: "When upsertion fails, I should get an INTERNAL_SERVER_ERROR response",
                 request: valid_request.clone(),
+                if_match: IfMatch(Some(1)),
                 expected_db_response: Some(Err(
                     UpsertAnswerToQuestionError::UnableToUpsertAnswerToQuestion,
                 )),
@@ -79,6 +134,7 @@ mod tests {
             TestCase {
                 description: "When upsertion succeeds, I should get an OK response",
                 request: valid_request,
+                if_match: IfMatch(Some(1)),
                 expected_db_response: Some(Ok(())),
                 expected_status_code: StatusCode::OK,
             },
@@ -89,15 +145,19 @@ mod tests {
             if let Some(expected_db_response) = test_case.expected_db_response {
                 mock_service
                     .expect_provide_answer_to_question()
-                    .returning(move |_, _| expected_db_response);
+                    .withf(|_, version, _| *version == 1)
+                    .returning(move |_, _, _| expected_db_response);
             }
             let arc_service: Arc<dyn AskImamAdminService> = Arc::new(mock_service);
-            let app_state = ServiceAppState {
+            let app_state = AuditedServiceAppState {
                 service: arc_service,
+                audit_log: new_test_audit_log_service(),
             };
             let actual_response = provide_answer_for_imam_question(
                 State(app_state),
                 Claims::default(),
+                test_case.if_match,
+                RequestId::default(),
                 Json(test_case.request),
             )
             .await;
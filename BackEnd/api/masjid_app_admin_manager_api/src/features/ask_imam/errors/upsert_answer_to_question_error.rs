@@ -1,5 +1,7 @@
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum UpsertAnswerToQuestionError {
     QuestionNotFound,
+    /// The question has been changed since the version the answer was written against
+    VersionMismatch,
     UnableToUpsertAnswerToQuestion,
 }
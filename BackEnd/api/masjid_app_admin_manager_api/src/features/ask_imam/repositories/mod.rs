@@ -40,6 +40,7 @@ pub trait ImamQuestionsAdminRepository: ImamQuestionsRepository {
     async fn upsert_imam_answer_to_question(
         &self,
         question_id: &i32,
+        version: &u32,
         answer: &Answer,
     ) -> Result<(), UpsertAnswerToQuestionError>;
     async fn delete_imam_question_by_id(&self, id: &i32) -> Result<(), DeleteQuestionError>;
@@ -76,6 +76,7 @@ impl ImamQuestionsAdminRepository for MySqlRepository {
     async fn upsert_imam_answer_to_question(
         &self,
         question_id: &i32,
+        version: &u32,
         answer: &Answer,
     ) -> Result<(), UpsertAnswerToQuestionError> {
         tracing::debug!(
@@ -83,14 +84,20 @@ impl ImamQuestionsAdminRepository for MySqlRepository {
             "upserting imam's answer to question in database"
         );
         let db_connection = self.db_connection.clone();
-        let query_result = sqlx::query("CALL upsert_imam_answer_to_question(?, ?, ?, ?)")
+        let query_result = sqlx::query("CALL upsert_imam_answer_to_question(?, ?, ?, ?, ?)")
             .bind(&answer.imam_name)
             .bind(&answer.text)
             .bind(&answer.date_answered)
             .bind(question_id)
+            .bind(version)
             .execute(&*db_connection)
             .await
             .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Version mismatch" {
+                        return UpsertAnswerToQuestionError::VersionMismatch;
+                    }
+                }
                 tracing::error!(
                     stored_procedure = "upsert_imam_answer_to_question",
                     error = err.to_string(),
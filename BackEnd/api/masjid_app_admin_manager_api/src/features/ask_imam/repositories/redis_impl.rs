@@ -56,6 +56,7 @@ impl ImamQuestionsAdminRepository for InMemoryRepository {
     async fn upsert_imam_answer_to_question(
         &self,
         question_id: &i32,
+        version: &u32,
         answer: &Answer,
     ) -> Result<(), UpsertAnswerToQuestionError> {
         tracing::warn!("in-memory database not implemented for upsert_imam_answer_to_question");
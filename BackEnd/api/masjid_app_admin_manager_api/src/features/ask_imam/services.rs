@@ -20,9 +20,11 @@ pub trait AskImamAdminService: Send + Sync {
         topic: Option<String>,
         school_of_thought: Option<SchoolOfThought>,
     ) -> Result<Vec<ImamQuestionDTO>, GetQuestionsError>;
+    /// The answer is only saved when the question is still at the version it was written against
     async fn provide_answer_to_question(
         &self,
         question_id: i32,
+        version: u32,
         answer: Answer,
     ) -> Result<(), UpsertAnswerToQuestionError>;
     async fn delete_question(&self, question_id: i32) -> Result<(), DeleteQuestionError>;
@@ -125,11 +127,12 @@ impl AskImamAdminService for AskImamServiceImpl<dyn ImamQuestionsAdminRepository
     async fn provide_answer_to_question(
         &self,
         question_id: i32,
+        version: u32,
         answer: Answer,
     ) -> Result<(), UpsertAnswerToQuestionError> {
         if self
             .in_memory_repository
-            .upsert_imam_answer_to_question(&question_id, &answer)
+            .upsert_imam_answer_to_question(&question_id, &version, &answer)
             .await
             .is_err()
         {
@@ -139,7 +142,7 @@ impl AskImamAdminService for AskImamServiceImpl<dyn ImamQuestionsAdminRepository
             );
         }
         self.repository
-            .upsert_imam_answer_to_question(&question_id, &answer)
+            .upsert_imam_answer_to_question(&question_id, &version, &answer)
             .await
     }
 
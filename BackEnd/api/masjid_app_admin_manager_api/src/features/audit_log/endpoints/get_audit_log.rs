@@ -0,0 +1,139 @@
+use crate::features::audit_log::errors::get_audit_log_error::GetAuditLogError;
+use crate::features::audit_log::models::audit_log_filter::AuditLogFilter;
+use crate::features::audit_log::models::get_audit_log_request::GetAuditLogRequest;
+use crate::features::audit_log::services::AuditLogService;
+use crate::shared::jwt::Claims;
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::features::events::endpoints::get_events::{
+    PAGE_HEADER, PAGE_SIZE_HEADER, TOTAL_COUNT_HEADER,
+};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn get_audit_log(
+    State(state): State<ServiceAppState<Arc<dyn AuditLogService>>>,
+    claims: Claims,
+    Query(request): Query<GetAuditLogRequest>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let filter = AuditLogFilter::from(request);
+    let (page, page_size) = (filter.page, filter.page_size);
+    match state.service.get_entries(filter).await {
+        Ok(audit_log_page) => (
+            StatusCode::OK,
+            [
+                (TOTAL_COUNT_HEADER, audit_log_page.total_count.to_string()),
+                (PAGE_HEADER, page.to_string()),
+                (PAGE_SIZE_HEADER, page_size.to_string()),
+            ],
+            Json(audit_log_page.entries),
+        )
+            .into_response(),
+        Err(GetAuditLogError::UnableToGetAuditLog) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::audit_log::models::audit_action::AuditAction;
+    use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+    use crate::features::audit_log::models::audit_log_page::AuditLogPage;
+    use crate::features::audit_log::services::MockAuditLogService;
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    async fn test_get_audit_log() {
+        let now = Utc::now();
+        struct TestCase {
+            description: &'static str,
+            claims: Claims,
+            request: GetAuditLogRequest,
+            expected_service_response: Option<Result<AuditLogPage, GetAuditLogError>>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the user is not an Admin, I should get a FORBIDDEN response",
+                claims: Claims::generate("imam", "Imam"),
+                request: GetAuditLogRequest::default(),
+                expected_service_response: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            TestCase {
+                description: "When the page size is larger than the maximum, I should get a BAD_REQUEST response",
+                claims: Claims::generate("admin", "Admin"),
+                request: GetAuditLogRequest {
+                    page_size: Some(1000),
+                    ..Default::default()
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the from date is not before the to date, I should get a BAD_REQUEST response",
+                claims: Claims::generate("admin", "Admin"),
+                request: GetAuditLogRequest {
+                    from: Some(now),
+                    to: Some(now - Duration::days(1)),
+                    ..Default::default()
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the audit log cannot be retrieved, I should get an INTERNAL_SERVER_ERROR response",
+                claims: Claims::generate("admin", "Admin"),
+                request: GetAuditLogRequest::default(),
+                expected_service_response: Some(Err(GetAuditLogError::UnableToGetAuditLog)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When the audit log is retrieved, I should get an OK response",
+                claims: Claims::generate("admin", "Admin"),
+                request: GetAuditLogRequest {
+                    action: Some(AuditAction::Update),
+                    entity_type: Some(AuditEntityType::Event),
+                    page: Some(2),
+                    ..Default::default()
+                },
+                expected_service_response: Some(Ok(AuditLogPage {
+                    entries: Vec::new(),
+                    total_count: 26,
+                })),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockAuditLogService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_get_entries()
+                    .return_once(move |_| expected_service_response);
+            }
+            let app_state = ServiceAppState::<Arc<dyn AuditLogService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response =
+                get_audit_log(State(app_state), test_case.claims, Query(test_case.request)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+            if actual_response.status() == StatusCode::OK {
+                assert_eq!(actual_response.headers()[TOTAL_COUNT_HEADER], "26");
+                assert_eq!(actual_response.headers()[PAGE_HEADER], "2");
+                assert_eq!(actual_response.headers()[PAGE_SIZE_HEADER], "25");
+            }
+        }
+    }
+}
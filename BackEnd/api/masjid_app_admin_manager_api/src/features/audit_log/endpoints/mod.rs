@@ -0,0 +1 @@
+pub(crate) mod get_audit_log;
@@ -0,0 +1,3 @@
+pub mod get_audit_log_error;
+pub mod get_audit_snapshot_error;
+pub mod record_audit_entry_error;
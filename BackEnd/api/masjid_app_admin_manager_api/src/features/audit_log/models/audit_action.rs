@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+}
+impl ToString for AuditAction {
+    fn to_string(&self) -> String {
+        match self {
+            AuditAction::Create => "create".to_owned(),
+            AuditAction::Update => "update".to_owned(),
+            AuditAction::Delete => "delete".to_owned(),
+        }
+    }
+}
+impl FromStr for AuditAction {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(AuditAction::Create),
+            "update" => Ok(AuditAction::Update),
+            "delete" => Ok(AuditAction::Delete),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The kinds of entity whose changes are recorded in the audit log
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditEntityType {
+    Event,
+    /// Every exception of an event, identified by the event they belong to
+    EventExceptions,
+    Room,
+    EventCategory,
+    Speaker,
+    /// A registration along with its check-ins
+    EventRegistration,
+    PrayerTimes,
+    ImamQuestion,
+    User,
+    MfaPolicy,
+}
+impl ToString for AuditEntityType {
+    fn to_string(&self) -> String {
+        match self {
+            AuditEntityType::Event => "event".to_owned(),
+            AuditEntityType::EventExceptions => "event_exceptions".to_owned(),
+            AuditEntityType::Room => "room".to_owned(),
+            AuditEntityType::EventCategory => "event_category".to_owned(),
+            AuditEntityType::Speaker => "speaker".to_owned(),
+            AuditEntityType::EventRegistration => "event_registration".to_owned(),
+            AuditEntityType::PrayerTimes => "prayer_times".to_owned(),
+            AuditEntityType::ImamQuestion => "imam_question".to_owned(),
+            AuditEntityType::User => "user".to_owned(),
+            AuditEntityType::MfaPolicy => "mfa_policy".to_owned(),
+        }
+    }
+}
+impl FromStr for AuditEntityType {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "event" => Ok(AuditEntityType::Event),
+            "event_exceptions" => Ok(AuditEntityType::EventExceptions),
+            "room" => Ok(AuditEntityType::Room),
+            "event_category" => Ok(AuditEntityType::EventCategory),
+            "speaker" => Ok(AuditEntityType::Speaker),
+            "event_registration" => Ok(AuditEntityType::EventRegistration),
+            "prayer_times" => Ok(AuditEntityType::PrayerTimes),
+            "imam_question" => Ok(AuditEntityType::ImamQuestion),
+            "user" => Ok(AuditEntityType::User),
+            "mfa_policy" => Ok(AuditEntityType::MfaPolicy),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_entity_type_round_trips_through_database_value() {
+        for entity_type in [
+            AuditEntityType::Event,
+            AuditEntityType::EventExceptions,
+            AuditEntityType::Room,
+            AuditEntityType::EventCategory,
+            AuditEntityType::Speaker,
+            AuditEntityType::EventRegistration,
+            AuditEntityType::PrayerTimes,
+            AuditEntityType::ImamQuestion,
+            AuditEntityType::User,
+            AuditEntityType::MfaPolicy,
+        ] {
+            assert_eq!(
+                Ok(entity_type),
+                AuditEntityType::from_str(&entity_type.to_string())
+            );
+        }
+    }
+}
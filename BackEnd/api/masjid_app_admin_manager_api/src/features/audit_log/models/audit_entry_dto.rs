@@ -0,0 +1,22 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct AuditEntryDTO {
+    pub id: i64,
+    pub actor: String,
+    pub action: AuditAction,
+    #[serde(rename(serialize = "entityType"))]
+    pub entity_type: AuditEntityType,
+    #[serde(rename(serialize = "entityId"))]
+    pub entity_id: Option<i32>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    #[serde(rename(serialize = "requestId"))]
+    pub request_id: String,
+    #[serde(rename(serialize = "occurredAt"))]
+    pub occurred_at: DateTime<Utc>,
+}
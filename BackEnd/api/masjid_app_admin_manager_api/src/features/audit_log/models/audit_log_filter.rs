@@ -0,0 +1,39 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::get_audit_log_request::GetAuditLogRequest;
+use chrono::{DateTime, Utc};
+
+pub const DEFAULT_PAGE_SIZE: u32 = 25;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditLogFilter {
+    pub actor: Option<String>,
+    pub action: Option<AuditAction>,
+    pub entity_type: Option<AuditEntityType>,
+    pub entity_id: Option<i32>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl AuditLogFilter {
+    pub fn offset(&self) -> u64 {
+        (self.page.saturating_sub(1) as u64) * self.page_size as u64
+    }
+}
+
+impl From<GetAuditLogRequest> for AuditLogFilter {
+    fn from(request: GetAuditLogRequest) -> Self {
+        Self {
+            actor: request.actor,
+            action: request.action,
+            entity_type: request.entity_type,
+            entity_id: request.entity_id,
+            from: request.from,
+            to: request.to,
+            page: request.page.unwrap_or(1),
+            page_size: request.page_size.unwrap_or(DEFAULT_PAGE_SIZE),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::features::audit_log::models::audit_entry_dto::AuditEntryDTO;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditLogPage {
+    pub entries: Vec<AuditEntryDTO>,
+    /// Number of entries matching the filter across every page
+    pub total_count: u64,
+}
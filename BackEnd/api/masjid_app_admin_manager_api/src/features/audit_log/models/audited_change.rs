@@ -0,0 +1,18 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use serde_json::Value;
+
+/// A change made through the admin API, which is recorded in the audit log once it has been saved
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditedChange {
+    /// The username of whoever made the change, taken from the `sub` of their token
+    pub actor: String,
+    pub action: AuditAction,
+    pub entity_type: AuditEntityType,
+    pub entity_id: Option<i32>,
+    /// The entity as it was before the change, which is `None` for entities being created
+    pub before: Option<Value>,
+    /// The entity as it was saved, which is `None` for entities being deleted
+    pub after: Option<Value>,
+    pub request_id: String,
+}
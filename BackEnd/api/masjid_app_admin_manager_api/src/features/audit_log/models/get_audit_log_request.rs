@@ -0,0 +1,43 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+pub const MAXIMUM_PAGE_SIZE: u32 = 100;
+
+#[derive(Deserialize, Validate, Clone, Debug, Default)]
+#[validate(schema(function = "validate_range"))]
+pub struct GetAuditLogRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub actor: Option<String>,
+
+    pub action: Option<AuditAction>,
+
+    #[serde(rename = "entityType")]
+    pub entity_type: Option<AuditEntityType>,
+
+    #[validate(range(min = 1))]
+    #[serde(rename = "entityId")]
+    pub entity_id: Option<i32>,
+
+    pub from: Option<DateTime<Utc>>,
+
+    pub to: Option<DateTime<Utc>>,
+
+    #[validate(range(min = 1))]
+    pub page: Option<u32>,
+
+    #[validate(range(min = 1, max = MAXIMUM_PAGE_SIZE))]
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<u32>,
+}
+
+fn validate_range(request: &GetAuditLogRequest) -> Result<(), ValidationError> {
+    if let (Some(from), Some(to)) = (request.from, request.to)
+        && from >= to
+    {
+        return Err(ValidationError::new("from must be before to"));
+    }
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod audit_action;
+pub mod audit_entity_type;
+pub mod audit_entry_dto;
+pub mod audit_log_filter;
+pub mod audit_log_page;
+pub mod audited_change;
+pub mod get_audit_log_request;
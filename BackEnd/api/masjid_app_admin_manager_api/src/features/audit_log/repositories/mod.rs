@@ -0,0 +1,39 @@
+use crate::features::audit_log::errors::get_audit_log_error::GetAuditLogError;
+use crate::features::audit_log::errors::get_audit_snapshot_error::GetAuditSnapshotError;
+use crate::features::audit_log::errors::record_audit_entry_error::RecordAuditEntryError;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audit_log_filter::AuditLogFilter;
+use crate::features::audit_log::models::audit_log_page::AuditLogPage;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use serde_json::Value;
+use std::sync::Arc;
+
+mod mysql_impl;
+
+#[automock]
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    async fn insert_entry(
+        &self,
+        change: AuditedChange,
+        occurred_at: DateTime<Utc>,
+    ) -> Result<(), RecordAuditEntryError>;
+    async fn get_entries(&self, filter: AuditLogFilter) -> Result<AuditLogPage, GetAuditLogError>;
+    /// Returns `None` when the entity does not exist
+    async fn get_snapshot(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Option<i32>,
+    ) -> Result<Option<Value>, GetAuditSnapshotError>;
+    /// Returns `None` when the user does not exist
+    async fn get_user_id(&self, username: &str) -> Result<Option<i32>, GetAuditSnapshotError>;
+}
+
+pub async fn new_audit_log_repository() -> Arc<dyn AuditLogRepository> {
+    Arc::new(MySqlRepository::new(RepositoryType::AuditLog).await)
+}
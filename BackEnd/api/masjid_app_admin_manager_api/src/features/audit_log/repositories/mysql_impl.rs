@@ -0,0 +1,163 @@
+use crate::features::audit_log::errors::get_audit_log_error::GetAuditLogError;
+use crate::features::audit_log::errors::get_audit_snapshot_error::GetAuditSnapshotError;
+use crate::features::audit_log::errors::record_audit_entry_error::RecordAuditEntryError;
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audit_entry_dto::AuditEntryDTO;
+use crate::features::audit_log::models::audit_log_filter::AuditLogFilter;
+use crate::features::audit_log::models::audit_log_page::AuditLogPage;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::audit_log::repositories::AuditLogRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use serde_json::Value;
+use sqlx::Row;
+use std::str::FromStr;
+
+// Snapshots are stored as JSON columns, which are read back as text
+fn parse_snapshot(snapshot: Option<String>) -> Option<Value> {
+    snapshot.and_then(|snapshot| serde_json::from_str(&snapshot).ok())
+}
+
+fn map_audit_entry(row: sqlx::mysql::MySqlRow) -> Result<AuditEntryDTO, sqlx::Error> {
+    let action: String = row.try_get(2)?;
+    let entity_type: String = row.try_get(3)?;
+    Ok(AuditEntryDTO {
+        id: row.try_get(0)?,
+        actor: row.try_get(1)?,
+        action: AuditAction::from_str(&action)
+            .map_err(|_| sqlx::Error::Decode(format!("unknown audit action {action}").into()))?,
+        entity_type: AuditEntityType::from_str(&entity_type).map_err(|_| {
+            sqlx::Error::Decode(format!("unknown audit entity type {entity_type}").into())
+        })?,
+        entity_id: row.try_get(4)?,
+        before: parse_snapshot(row.try_get(5)?),
+        after: parse_snapshot(row.try_get(6)?),
+        request_id: row.try_get(7)?,
+        occurred_at: row.try_get(8)?,
+    })
+}
+
+#[async_trait]
+impl AuditLogRepository for MySqlRepository {
+    async fn insert_entry(
+        &self,
+        change: AuditedChange,
+        occurred_at: DateTime<Utc>,
+    ) -> Result<(), RecordAuditEntryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL insert_audit_log_entry(?, ?, ?, ?, ?, ?, ?, ?);")
+            .bind(&change.actor)
+            .bind(change.action.to_string())
+            .bind(change.entity_type.to_string())
+            .bind(change.entity_id)
+            .bind(change.before.as_ref().map(Value::to_string))
+            .bind(change.after.as_ref().map(Value::to_string))
+            .bind(&change.request_id)
+            .bind(occurred_at)
+            .execute(&*db_connection)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                tracing::error!(
+                    actor = %change.actor,
+                    action = change.action.to_string(),
+                    entity_type = change.entity_type.to_string(),
+                    entity_id = ?change.entity_id,
+                    request_id = %change.request_id,
+                    error = err.to_string(),
+                    "an error occurred whilst recording an audit log entry",
+                );
+                RecordAuditEntryError::UnableToRecordAuditEntry
+            })
+    }
+
+    async fn get_entries(&self, filter: AuditLogFilter) -> Result<AuditLogPage, GetAuditLogError> {
+        let db_connection = self.db_connection.clone();
+        let action = filter.action.map(|action| action.to_string());
+        let entity_type = filter
+            .entity_type
+            .map(|entity_type| entity_type.to_string());
+        let log_error = |err: sqlx::Error| {
+            tracing::error!(
+                actor = ?filter.actor,
+                action = ?action,
+                entity_type = ?entity_type,
+                entity_id = ?filter.entity_id,
+                error = err.to_string(),
+                "an error occurred whilst retrieving audit log entries",
+            );
+            GetAuditLogError::UnableToGetAuditLog
+        };
+        let entries = sqlx::query("CALL get_audit_log_entries(?, ?, ?, ?, ?, ?, ?, ?);")
+            .bind(&filter.actor)
+            .bind(&action)
+            .bind(&entity_type)
+            .bind(filter.entity_id)
+            .bind(filter.from)
+            .bind(filter.to)
+            .bind(filter.page_size)
+            .bind(filter.offset())
+            .try_map(map_audit_entry)
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(log_error)?;
+        let total_count: i64 = sqlx::query("CALL count_audit_log_entries(?, ?, ?, ?, ?, ?);")
+            .bind(&filter.actor)
+            .bind(&action)
+            .bind(&entity_type)
+            .bind(filter.entity_id)
+            .bind(filter.from)
+            .bind(filter.to)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(log_error)?;
+        Ok(AuditLogPage {
+            entries,
+            total_count: total_count as u64,
+        })
+    }
+
+    async fn get_snapshot(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Option<i32>,
+    ) -> Result<Option<Value>, GetAuditSnapshotError> {
+        let db_connection = self.db_connection.clone();
+        let snapshot: Option<Option<String>> = sqlx::query("CALL get_audit_snapshot(?, ?);")
+            .bind(entity_type.to_string())
+            .bind(entity_id)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_optional(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    entity_type = entity_type.to_string(),
+                    entity_id = ?entity_id,
+                    error = err.to_string(),
+                    "an error occurred whilst taking an audit snapshot",
+                );
+                GetAuditSnapshotError::UnableToGetAuditSnapshot
+            })?;
+        Ok(parse_snapshot(snapshot.flatten()))
+    }
+
+    async fn get_user_id(&self, username: &str) -> Result<Option<i32>, GetAuditSnapshotError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_audit_user_id(?);")
+            .bind(username)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_optional(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = %username,
+                    error = err.to_string(),
+                    "an error occurred whilst identifying an audited user",
+                );
+                GetAuditSnapshotError::UnableToGetAuditSnapshot
+            })
+    }
+}
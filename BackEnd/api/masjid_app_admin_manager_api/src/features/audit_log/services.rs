@@ -0,0 +1,167 @@
+use crate::features::audit_log::errors::get_audit_log_error::GetAuditLogError;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audit_log_filter::AuditLogFilter;
+use crate::features::audit_log::models::audit_log_page::AuditLogPage;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::audit_log::repositories::AuditLogRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use mockall::automock;
+use serde_json::Value;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait AuditLogService: Send + Sync {
+    /// Takes a copy of the entity as it is stored, which is `None` when it does not exist or
+    /// cannot be read, so that auditing never stops a change from being made
+    async fn take_snapshot(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Option<i32>,
+    ) -> Option<Value>;
+    /// Finds the id a user's changes are recorded against, which is `None` when they do not exist
+    /// or cannot be read
+    async fn find_user_id(&self, username: &str) -> Option<i32>;
+    /// Records a change which has already been saved, so failures are logged rather than returned
+    async fn record_change(&self, change: AuditedChange);
+    async fn get_entries(&self, filter: AuditLogFilter) -> Result<AuditLogPage, GetAuditLogError>;
+}
+
+struct AuditLogServiceImpl {
+    repository: Arc<dyn AuditLogRepository>,
+}
+
+pub fn new_audit_log_service(repository: Arc<dyn AuditLogRepository>) -> Arc<dyn AuditLogService> {
+    Arc::new(AuditLogServiceImpl { repository })
+}
+
+#[async_trait]
+impl AuditLogService for AuditLogServiceImpl {
+    async fn take_snapshot(
+        &self,
+        entity_type: AuditEntityType,
+        entity_id: Option<i32>,
+    ) -> Option<Value> {
+        self.repository
+            .get_snapshot(entity_type, entity_id)
+            .await
+            .unwrap_or_default()
+    }
+
+    async fn find_user_id(&self, username: &str) -> Option<i32> {
+        self.repository
+            .get_user_id(username)
+            .await
+            .unwrap_or_default()
+    }
+
+    async fn record_change(&self, change: AuditedChange) {
+        // The repository logs the details of the failure
+        let _ = self.repository.insert_entry(change, Utc::now()).await;
+    }
+
+    async fn get_entries(&self, filter: AuditLogFilter) -> Result<AuditLogPage, GetAuditLogError> {
+        self.repository.get_entries(filter).await
+    }
+}
+
+/// An audit log for the tests of audited endpoints, which takes no snapshots and records nothing
+#[cfg(test)]
+pub(crate) fn new_test_audit_log_service() -> Arc<dyn AuditLogService> {
+    let mut audit_log = MockAuditLogService::new();
+    audit_log.expect_take_snapshot().returning(|_, _| None);
+    audit_log.expect_find_user_id().returning(|_| None);
+    audit_log.expect_record_change().returning(|_| ());
+    Arc::new(audit_log)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::errors::get_audit_snapshot_error::GetAuditSnapshotError;
+    use crate::features::audit_log::errors::record_audit_entry_error::RecordAuditEntryError;
+    use crate::features::audit_log::models::audit_action::AuditAction;
+    use crate::features::audit_log::repositories::MockAuditLogRepository;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_take_snapshot() {
+        struct TestCase {
+            description: &'static str,
+            repository_response: Result<Option<Value>, GetAuditSnapshotError>,
+            expected_snapshot: Option<Value>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the entity exists, I should get its snapshot",
+                repository_response: Ok(Some(json!({"id": 1, "version": 2}))),
+                expected_snapshot: Some(json!({"id": 1, "version": 2})),
+            },
+            TestCase {
+                description: "When the entity does not exist, I should get no snapshot",
+                repository_response: Ok(None),
+                expected_snapshot: None,
+            },
+            TestCase {
+                description: "When the snapshot cannot be taken, I should get no snapshot",
+                repository_response: Err(GetAuditSnapshotError::UnableToGetAuditSnapshot),
+                expected_snapshot: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockAuditLogRepository::new();
+            let repository_response = test_case.repository_response;
+            mock_repository
+                .expect_get_snapshot()
+                .withf(|entity_type, entity_id| {
+                    *entity_type == AuditEntityType::Event && *entity_id == Some(1)
+                })
+                .return_once(move |_, _| repository_response);
+            let service = new_audit_log_service(Arc::new(mock_repository));
+            let actual_snapshot = service.take_snapshot(AuditEntityType::Event, Some(1)).await;
+            assert_eq!(test_case.expected_snapshot, actual_snapshot);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_change() {
+        struct TestCase {
+            description: &'static str,
+            repository_response: Result<(), RecordAuditEntryError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the entry is recorded, the change should be passed on unaltered",
+                repository_response: Ok(()),
+            },
+            TestCase {
+                description: "When the entry cannot be recorded, the failure should not be returned",
+                repository_response: Err(RecordAuditEntryError::UnableToRecordAuditEntry),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let change = AuditedChange {
+                actor: "admin".to_owned(),
+                action: AuditAction::Delete,
+                entity_type: AuditEntityType::ImamQuestion,
+                entity_id: Some(3),
+                before: Some(json!({"id": 3})),
+                after: None,
+                request_id: "request".to_owned(),
+            };
+            let expected_change = change.clone();
+            let repository_response = test_case.repository_response;
+            let mut mock_repository = MockAuditLogRepository::new();
+            mock_repository
+                .expect_insert_entry()
+                .withf(move |change, _| *change == expected_change)
+                .times(1)
+                .return_once(move |_, _| repository_response);
+            let service = new_audit_log_service(Arc::new(mock_repository));
+            service.record_change(change).await;
+        }
+    }
+}
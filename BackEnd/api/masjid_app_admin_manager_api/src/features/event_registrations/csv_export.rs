@@ -0,0 +1,71 @@
+use crate::shared::csv::escape_csv_field;
+use masjid_app_api_library::features::events::models::event_registration_dto::EventRegistrationDTO;
+
+const CSV_HEADER: &str = "Name,Email,Phone Number,Party Size,Status,Registered At";
+
+pub fn event_registrations_to_csv(registrations: &[EventRegistrationDTO]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+    for registration in registrations {
+        let fields = [
+            escape_csv_field(&registration.full_name),
+            escape_csv_field(registration.email.as_deref().unwrap_or_default()),
+            escape_csv_field(registration.phone_number.as_deref().unwrap_or_default()),
+            registration.party_size.to_string(),
+            registration.status.to_string(),
+            registration.registered_at.to_rfc3339(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use masjid_app_api_library::features::events::models::registration_status::RegistrationStatus;
+
+    #[test]
+    fn test_event_registrations_to_csv() {
+        struct TestCase {
+            description: &'static str,
+            full_name: &'static str,
+            expected_row: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the name has nothing to escape, it should be written as is",
+                full_name: "John Smith",
+                expected_row: "John Smith,johnsmith@test.com,,2,waitlisted,2025-01-01T18:00:00+00:00",
+            },
+            TestCase {
+                description: "When the name contains a comma or quotes, it should be quoted",
+                full_name: "Smith, \"John\"",
+                expected_row: "\"Smith, \"\"John\"\"\",johnsmith@test.com,,2,waitlisted,2025-01-01T18:00:00+00:00",
+            },
+            TestCase {
+                description: "When the name would be evaluated as a formula, it should be prefixed so that it is treated as text",
+                full_name: "=HYPERLINK(\"http://test.com\")",
+                expected_row: "\"'=HYPERLINK(\"\"http://test.com\"\")\",johnsmith@test.com,,2,waitlisted,2025-01-01T18:00:00+00:00",
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let registration = EventRegistrationDTO {
+                id: 1,
+                full_name: test_case.full_name.to_owned(),
+                email: Some("johnsmith@test.com".to_owned()),
+                phone_number: None,
+                party_size: 2,
+                status: RegistrationStatus::Waitlisted,
+                registered_at: Utc.with_ymd_and_hms(2025, 1, 1, 18, 0, 0).unwrap(),
+            };
+            assert_eq!(
+                format!("{}\r\n{}\r\n", CSV_HEADER, test_case.expected_row),
+                event_registrations_to_csv(&[registration])
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::event_registrations::errors::check_in_error::CheckInError;
+use crate::features::event_registrations::models::check_in_request::CheckInRequest;
+use crate::features::event_registrations::services::EventRegistrationAdminService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn check_in_attendee(
+    State(state): State<AuditedServiceAppState<Arc<dyn EventRegistrationAdminService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Json(request): Json<CheckInRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let registration_id = state.service.find_registration_id(&request.check_in_code);
+    let before = match registration_id {
+        Some(registration_id) => {
+            state
+                .audit_log
+                .take_snapshot(AuditEntityType::EventRegistration, Some(registration_id))
+                .await
+        }
+        None => None,
+    };
+    match state.service.check_in(request).await {
+        Ok(attendee) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::EventRegistration, registration_id)
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::EventRegistration,
+                    entity_id: registration_id,
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            (StatusCode::CREATED, Json(attendee)).into_response()
+        }
+        Err(CheckInError::InvalidCheckInCode) => {
+            (StatusCode::BAD_REQUEST, "check-in code is not valid").into_response()
+        }
+        Err(CheckInError::RegistrationNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(CheckInError::RegistrationNotConfirmed) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "registration is still on the waitlist",
+        )
+            .into_response(),
+        Err(CheckInError::NotAnOccurrence) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "occurrence date is not an occurrence of the event which is going ahead",
+        )
+            .into_response(),
+        Err(CheckInError::AlreadyCheckedIn) => StatusCode::CONFLICT.into_response(),
+        Err(CheckInError::UnableToCheckIn) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::event_registrations::services::MockEventRegistrationAdminService;
+
+    #[tokio::test]
+    async fn test_check_in_attendee() {
+        struct TestCase {
+            description: &'static str,
+            check_in_code: &'static str,
+            expected_service_error: Option<CheckInError>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When no check-in code is given, I should get a BAD_REQUEST response",
+                check_in_code: "",
+                expected_service_error: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the check-in code is not genuine, I should get a BAD_REQUEST response",
+                check_in_code: "code",
+                expected_service_error: Some(CheckInError::InvalidCheckInCode),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the registration has been cancelled, I should get a NOT_FOUND response",
+                check_in_code: "code",
+                expected_service_error: Some(CheckInError::RegistrationNotFound),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When the registration is on the waitlist, I should get an UNPROCESSABLE_ENTITY response",
+                check_in_code: "code",
+                expected_service_error: Some(CheckInError::RegistrationNotConfirmed),
+                expected_status_code: StatusCode::UNPROCESSABLE_ENTITY,
+            },
+            TestCase {
+                description: "When the occurrence is not going ahead, I should get an UNPROCESSABLE_ENTITY response",
+                check_in_code: "code",
+                expected_service_error: Some(CheckInError::NotAnOccurrence),
+                expected_status_code: StatusCode::UNPROCESSABLE_ENTITY,
+            },
+            TestCase {
+                description: "When the party has already been checked in, I should get a CONFLICT response",
+                check_in_code: "code",
+                expected_service_error: Some(CheckInError::AlreadyCheckedIn),
+                expected_status_code: StatusCode::CONFLICT,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRegistrationAdminService::new();
+            mock_service
+                .expect_find_registration_id()
+                .returning(|_| None);
+            if let Some(expected_service_error) = test_case.expected_service_error {
+                mock_service
+                    .expect_check_in()
+                    .return_once(move |_| Err(expected_service_error));
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn EventRegistrationAdminService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = check_in_attendee(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                Json(CheckInRequest {
+                    check_in_code: test_case.check_in_code.to_owned(),
+                    occurrence_date: Default::default(),
+                }),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::features::event_registrations::csv_export::event_registrations_to_csv;
+use crate::features::event_registrations::errors::get_event_registrations_error::GetEventRegistrationsError;
+use crate::features::event_registrations::services::EventRegistrationAdminService;
+use crate::shared::jwt::Claims;
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn export_event_registrations(
+    State(state): State<ServiceAppState<Arc<dyn EventRegistrationAdminService>>>,
+    _claims: Claims,
+    Path(event_id): Path<i32>,
+) -> Response {
+    match state.service.get_event_registrations(event_id).await {
+        Ok(registrations) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_owned()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"event-{}-registrations.csv\"",
+                        event_id
+                    ),
+                ),
+            ],
+            event_registrations_to_csv(&registrations),
+        )
+            .into_response(),
+        Err(GetEventRegistrationsError::UnableToGetRegistrations) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_registrations::services::MockEventRegistrationAdminService;
+    use masjid_app_api_library::features::events::models::event_registration_dto::EventRegistrationDTO;
+
+    #[tokio::test]
+    async fn test_export_event_registrations() {
+        struct TestCase {
+            description: &'static str,
+            service_result: Result<Vec<EventRegistrationDTO>, GetEventRegistrationsError>,
+            expected_status_code: StatusCode,
+            expected_content_disposition: Option<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the registrations cannot be retrieved, I should get an INTERNAL_SERVER_ERROR response",
+                service_result: Err(GetEventRegistrationsError::UnableToGetRegistrations),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                expected_content_disposition: None,
+            },
+            TestCase {
+                description: "When the registrations are retrieved, I should get them as a CSV attachment",
+                service_result: Ok(vec![]),
+                expected_status_code: StatusCode::OK,
+                expected_content_disposition: Some(
+                    "attachment; filename=\"event-1-registrations.csv\"",
+                ),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRegistrationAdminService::new();
+            let service_result = test_case.service_result;
+            mock_service
+                .expect_get_event_registrations()
+                .withf(|event_id| *event_id == 1)
+                .return_once(move |_| service_result);
+            let app_state = ServiceAppState::<Arc<dyn EventRegistrationAdminService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response =
+                export_event_registrations(State(app_state), Claims::default(), Path(1)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+            assert_eq!(
+                test_case.expected_content_disposition,
+                actual_response
+                    .headers()
+                    .get(header::CONTENT_DISPOSITION)
+                    .map(|value| value.to_str().unwrap())
+            );
+        }
+    }
+}
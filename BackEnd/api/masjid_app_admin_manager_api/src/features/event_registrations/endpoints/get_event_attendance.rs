@@ -0,0 +1,22 @@
+use crate::features::event_registrations::errors::get_event_attendance_error::GetEventAttendanceError;
+use crate::features::event_registrations::services::EventRegistrationAdminService;
+use crate::shared::jwt::Claims;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn get_event_attendance(
+    State(state): State<ServiceAppState<Arc<dyn EventRegistrationAdminService>>>,
+    _claims: Claims,
+    Path(event_id): Path<i32>,
+) -> Response {
+    match state.service.get_event_attendance(event_id).await {
+        Ok(attendance) => (StatusCode::OK, Json(attendance)).into_response(),
+        Err(GetEventAttendanceError::UnableToGetAttendance) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
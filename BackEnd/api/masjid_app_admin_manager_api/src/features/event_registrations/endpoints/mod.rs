@@ -0,0 +1,4 @@
+pub mod check_in_attendee;
+pub mod export_event_registrations;
+pub mod get_event_attendance;
+pub mod get_event_registrations;
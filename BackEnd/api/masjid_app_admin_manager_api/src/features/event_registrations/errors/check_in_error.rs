@@ -0,0 +1,10 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CheckInError {
+    InvalidCheckInCode,
+    RegistrationNotFound,
+    RegistrationNotConfirmed,
+    /// The date is not one of the event's occurrences, or that occurrence has been cancelled
+    NotAnOccurrence,
+    AlreadyCheckedIn,
+    UnableToCheckIn,
+}
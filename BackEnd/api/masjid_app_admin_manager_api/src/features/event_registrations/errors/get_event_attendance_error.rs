@@ -0,0 +1,4 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GetEventAttendanceError {
+    UnableToGetAttendance,
+}
@@ -0,0 +1,3 @@
+pub mod check_in_error;
+pub mod get_event_attendance_error;
+pub mod get_event_registrations_error;
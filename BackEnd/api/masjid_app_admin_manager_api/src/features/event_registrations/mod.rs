@@ -0,0 +1,6 @@
+pub mod csv_export;
+pub mod endpoints;
+pub mod errors;
+pub mod models;
+pub mod repositories;
+pub mod services;
@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Validate, Deserialize, Clone, Debug, PartialEq)]
+pub struct CheckInRequest {
+    #[validate(length(min = 1))]
+    #[serde(rename = "checkInCode")]
+    pub check_in_code: String,
+
+    /// The scheduled date of the occurrence being attended
+    #[serde(rename = "occurrenceDate")]
+    pub occurrence_date: DateTime<Utc>,
+}
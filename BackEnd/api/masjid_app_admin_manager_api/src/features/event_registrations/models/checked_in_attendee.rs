@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Returned once a party is checked in, so the volunteer at the door can confirm who they have
+/// let in
+#[derive(Serialize, sqlx::FromRow, Clone, Debug, PartialEq, Eq)]
+pub struct CheckedInAttendee {
+    #[serde(rename = "eventId")]
+    pub event_id: i32,
+
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+
+    #[serde(rename = "partySize")]
+    pub party_size: u8,
+
+    #[serde(rename = "occurrenceDate")]
+    pub occurrence_date: DateTime<Utc>,
+
+    #[serde(rename = "checkedInAt")]
+    pub checked_in_at: DateTime<Utc>,
+}
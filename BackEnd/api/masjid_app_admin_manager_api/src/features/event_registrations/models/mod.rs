@@ -0,0 +1,3 @@
+pub mod check_in_request;
+pub mod checked_in_attendee;
+pub mod occurrence_attendance;
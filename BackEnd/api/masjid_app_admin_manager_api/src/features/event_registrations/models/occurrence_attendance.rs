@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize, sqlx::FromRow, Clone, Debug, PartialEq, Eq)]
+pub struct OccurrenceAttendance {
+    #[serde(rename = "occurrenceDate")]
+    pub occurrence_date: DateTime<Utc>,
+
+    /// The number of people with a confirmed registration for the event
+    #[serde(rename = "expectedAttendees")]
+    pub expected_attendees: i64,
+
+    #[serde(rename = "checkedInRegistrations")]
+    pub checked_in_registrations: i64,
+
+    /// The number of people checked in, counting every member of each party
+    #[serde(rename = "checkedInAttendees")]
+    pub checked_in_attendees: i64,
+}
@@ -0,0 +1,49 @@
+use crate::features::event_registrations::errors::check_in_error::CheckInError;
+use crate::features::event_registrations::errors::get_event_attendance_error::GetEventAttendanceError;
+use crate::features::event_registrations::errors::get_event_registrations_error::GetEventRegistrationsError;
+use crate::features::event_registrations::models::checked_in_attendee::CheckedInAttendee;
+use crate::features::event_registrations::models::occurrence_attendance::OccurrenceAttendance;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::event_registration::EventRegistration;
+use masjid_app_api_library::new_repository;
+use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use std::sync::Arc;
+
+mod mysql_impl;
+mod redis_impl;
+
+#[automock]
+#[async_trait]
+pub trait EventRegistrationsAdminRepository: Send + Sync {
+    /// Returns every registration for the event in the order they were made, including those on
+    /// the waitlist
+    async fn get_event_registrations(
+        &self,
+        event_id: &i32,
+    ) -> Result<Vec<EventRegistration>, GetEventRegistrationsError>;
+    /// Finds the event the registration was made for
+    async fn get_registration_event_id(&self, registration_id: &i32) -> Result<i32, CheckInError>;
+    async fn insert_event_check_in(
+        &self,
+        registration_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<CheckedInAttendee, CheckInError>;
+    /// Returns the attendance of each occurrence that anybody has been checked in to
+    async fn get_event_attendance(
+        &self,
+        event_id: &i32,
+    ) -> Result<Vec<OccurrenceAttendance>, GetEventAttendanceError>;
+}
+
+pub async fn new_event_registrations_admin_repository(
+    repository_mode: RepositoryMode,
+) -> Arc<dyn EventRegistrationsAdminRepository> {
+    new_repository!(repository_mode, RepositoryType::Events)
+}
@@ -0,0 +1,105 @@
+use crate::features::event_registrations::errors::check_in_error::CheckInError;
+use crate::features::event_registrations::errors::get_event_attendance_error::GetEventAttendanceError;
+use crate::features::event_registrations::errors::get_event_registrations_error::GetEventRegistrationsError;
+use crate::features::event_registrations::models::checked_in_attendee::CheckedInAttendee;
+use crate::features::event_registrations::models::occurrence_attendance::OccurrenceAttendance;
+use crate::features::event_registrations::repositories::EventRegistrationsAdminRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::event_registration::EventRegistration;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use sqlx::Row;
+
+#[async_trait]
+impl EventRegistrationsAdminRepository for MySqlRepository {
+    async fn get_event_registrations(
+        &self,
+        event_id: &i32,
+    ) -> Result<Vec<EventRegistration>, GetEventRegistrationsError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query_as::<_, EventRegistration>("CALL get_event_registrations_by_event_id(?);")
+            .bind(event_id)
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    event_id = event_id,
+                    error = err.to_string(),
+                    "unable to fetch event registrations from database"
+                );
+                GetEventRegistrationsError::UnableToGetRegistrations
+            })
+    }
+
+    async fn get_registration_event_id(&self, registration_id: &i32) -> Result<i32, CheckInError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_event_id_by_registration_id(?);")
+            .bind(registration_id)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_optional(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    registration_id = registration_id,
+                    error = err.to_string(),
+                    "unable to fetch the event of the registration from database"
+                );
+                CheckInError::UnableToCheckIn
+            })?
+            .ok_or(CheckInError::RegistrationNotFound)
+    }
+
+    async fn insert_event_check_in(
+        &self,
+        registration_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<CheckedInAttendee, CheckInError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query_as::<_, CheckedInAttendee>("CALL insert_event_check_in(?, ?);")
+            .bind(registration_id)
+            .bind(occurrence_date)
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    match database_error.message() {
+                        "Registration not found" => return CheckInError::RegistrationNotFound,
+                        "Registration not confirmed" => {
+                            return CheckInError::RegistrationNotConfirmed;
+                        }
+                        "Already checked in" => return CheckInError::AlreadyCheckedIn,
+                        // Two volunteers scanning the same code at once both pass the check above
+                        _ if database_error.is_unique_violation() => {
+                            return CheckInError::AlreadyCheckedIn;
+                        }
+                        _ => {}
+                    }
+                }
+                tracing::error!(
+                    registration_id = registration_id,
+                    error = err.to_string(),
+                    "unable to check in event registration"
+                );
+                CheckInError::UnableToCheckIn
+            })
+    }
+
+    async fn get_event_attendance(
+        &self,
+        event_id: &i32,
+    ) -> Result<Vec<OccurrenceAttendance>, GetEventAttendanceError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query_as::<_, OccurrenceAttendance>("CALL get_event_attendance(?);")
+            .bind(event_id)
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    event_id = event_id,
+                    error = err.to_string(),
+                    "unable to fetch event attendance from database"
+                );
+                GetEventAttendanceError::UnableToGetAttendance
+            })
+    }
+}
@@ -0,0 +1,43 @@
+use crate::features::event_registrations::errors::check_in_error::CheckInError;
+use crate::features::event_registrations::errors::get_event_attendance_error::GetEventAttendanceError;
+use crate::features::event_registrations::errors::get_event_registrations_error::GetEventRegistrationsError;
+use crate::features::event_registrations::models::checked_in_attendee::CheckedInAttendee;
+use crate::features::event_registrations::models::occurrence_attendance::OccurrenceAttendance;
+use crate::features::event_registrations::repositories::EventRegistrationsAdminRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::event_registration::EventRegistration;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+
+#[async_trait]
+impl EventRegistrationsAdminRepository for InMemoryRepository {
+    async fn get_event_registrations(
+        &self,
+        event_id: &i32,
+    ) -> Result<Vec<EventRegistration>, GetEventRegistrationsError> {
+        tracing::warn!("in-memory database not implemented for get_event_registrations");
+        Err(GetEventRegistrationsError::UnableToGetRegistrations)
+    }
+
+    async fn get_registration_event_id(&self, registration_id: &i32) -> Result<i32, CheckInError> {
+        tracing::warn!("in-memory database not implemented for get_registration_event_id");
+        Err(CheckInError::UnableToCheckIn)
+    }
+
+    async fn insert_event_check_in(
+        &self,
+        registration_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<CheckedInAttendee, CheckInError> {
+        tracing::warn!("in-memory database not implemented for insert_event_check_in");
+        Err(CheckInError::UnableToCheckIn)
+    }
+
+    async fn get_event_attendance(
+        &self,
+        event_id: &i32,
+    ) -> Result<Vec<OccurrenceAttendance>, GetEventAttendanceError> {
+        tracing::warn!("in-memory database not implemented for get_event_attendance");
+        Err(GetEventAttendanceError::UnableToGetAttendance)
+    }
+}
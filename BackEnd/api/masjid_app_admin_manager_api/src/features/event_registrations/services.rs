@@ -0,0 +1,336 @@
+use crate::features::event_registrations::errors::check_in_error::CheckInError;
+use crate::features::event_registrations::errors::get_event_attendance_error::GetEventAttendanceError;
+use crate::features::event_registrations::errors::get_event_registrations_error::GetEventRegistrationsError;
+use crate::features::event_registrations::models::check_in_request::CheckInRequest;
+use crate::features::event_registrations::models::checked_in_attendee::CheckedInAttendee;
+use crate::features::event_registrations::models::occurrence_attendance::OccurrenceAttendance;
+use crate::features::event_registrations::repositories::EventRegistrationsAdminRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::event_registration_dto::EventRegistrationDTO;
+use masjid_app_api_library::features::events::models::get_events_calendar_request::GetEventsCalendarRequest;
+use masjid_app_api_library::features::events::services::event_retrieval_service::EventRetrievalService;
+use masjid_app_api_library::features::events::services::occurrence_expansion::is_scheduled_occurrence;
+use masjid_app_api_library::features::events::services::registration_tokens::{
+    RegistrationTokenPurpose, registration_id_from_token,
+};
+use masjid_app_api_library::shared::services::token_signing::r#trait::TokenSigner;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventRegistrationAdminService: Send + Sync {
+    async fn get_event_registrations(
+        &self,
+        event_id: i32,
+    ) -> Result<Vec<EventRegistrationDTO>, GetEventRegistrationsError>;
+    /// Finds the registration a check-in code was issued for, which is `None` when the code is
+    /// not genuine
+    fn find_registration_id(&self, check_in_code: &str) -> Option<i32>;
+    /// The occurrence date is the date the occurrence was scheduled for, even if it has since
+    /// been moved
+    async fn check_in(&self, request: CheckInRequest) -> Result<CheckedInAttendee, CheckInError>;
+    async fn get_event_attendance(
+        &self,
+        event_id: i32,
+    ) -> Result<Vec<OccurrenceAttendance>, GetEventAttendanceError>;
+}
+
+pub fn new_event_registration_admin_service(
+    repository: Arc<dyn EventRegistrationsAdminRepository>,
+    event_retrieval_service: Arc<dyn EventRetrievalService>,
+    token_signer: Arc<dyn TokenSigner>,
+) -> Arc<dyn EventRegistrationAdminService> {
+    Arc::new(EventRegistrationAdminServiceImpl {
+        repository,
+        event_retrieval_service,
+        token_signer,
+    })
+}
+
+struct EventRegistrationAdminServiceImpl {
+    repository: Arc<dyn EventRegistrationsAdminRepository>,
+    event_retrieval_service: Arc<dyn EventRetrievalService>,
+    token_signer: Arc<dyn TokenSigner>,
+}
+
+impl EventRegistrationAdminServiceImpl {
+    /// Whether the date is a scheduled occurrence of the event which has not been cancelled
+    async fn is_occurrence_going_ahead(
+        &self,
+        event_id: i32,
+        occurrence_date: DateTime<Utc>,
+    ) -> Result<bool, CheckInError> {
+        let calendar = self
+            .event_retrieval_service
+            .get_events_calendar(GetEventsCalendarRequest::default())
+            .await
+            .map_err(|_| {
+                tracing::error!(
+                    event_id = event_id,
+                    "unable to retrieve the event being checked in to"
+                );
+                CheckInError::UnableToCheckIn
+            })?;
+        let event = calendar
+            .events
+            .iter()
+            .find(|event| event.id == event_id)
+            .ok_or(CheckInError::RegistrationNotFound)?;
+        let is_cancelled = calendar.exceptions.iter().any(|exception| {
+            exception.event_id == event_id
+                && exception.occurrence_date == occurrence_date
+                && exception.replacement_date.is_none()
+        });
+        Ok(is_scheduled_occurrence(event, occurrence_date) && !is_cancelled)
+    }
+}
+
+#[async_trait]
+impl EventRegistrationAdminService for EventRegistrationAdminServiceImpl {
+    async fn get_event_registrations(
+        &self,
+        event_id: i32,
+    ) -> Result<Vec<EventRegistrationDTO>, GetEventRegistrationsError> {
+        let registrations = self.repository.get_event_registrations(&event_id).await?;
+        Ok(registrations
+            .into_iter()
+            .map(EventRegistrationDTO::from)
+            .collect())
+    }
+
+    fn find_registration_id(&self, check_in_code: &str) -> Option<i32> {
+        registration_id_from_token(
+            &*self.token_signer,
+            RegistrationTokenPurpose::CheckIn,
+            check_in_code,
+        )
+    }
+
+    async fn check_in(&self, request: CheckInRequest) -> Result<CheckedInAttendee, CheckInError> {
+        let registration_id = self
+            .find_registration_id(&request.check_in_code)
+            .ok_or(CheckInError::InvalidCheckInCode)?;
+        let event_id = self
+            .repository
+            .get_registration_event_id(&registration_id)
+            .await?;
+        if !self
+            .is_occurrence_going_ahead(event_id, request.occurrence_date)
+            .await?
+        {
+            return Err(CheckInError::NotAnOccurrence);
+        }
+        self.repository
+            .insert_event_check_in(&registration_id, &request.occurrence_date)
+            .await
+    }
+
+    async fn get_event_attendance(
+        &self,
+        event_id: i32,
+    ) -> Result<Vec<OccurrenceAttendance>, GetEventAttendanceError> {
+        self.repository.get_event_attendance(&event_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_registrations::repositories::MockEventRegistrationsAdminRepository;
+    use chrono::{DateTime, TimeZone, Utc};
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::models::events_calendar::EventsCalendar;
+    use masjid_app_api_library::features::events::services::event_retrieval_service::MockEventRetrievalService;
+    use masjid_app_api_library::features::events::services::registration_tokens::issue_registration_token;
+    use masjid_app_api_library::shared::services::token_signing::r#trait::new_token_signer;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+
+    const SECRET: &[u8] = b"secret";
+
+    #[tokio::test]
+    async fn test_check_in() {
+        struct TestCase {
+            description: &'static str,
+            check_in_code: String,
+            occurrence_date: DateTime<Utc>,
+            mock_get_event_id_result: Option<Result<i32, CheckInError>>,
+            exceptions: Vec<EventExceptionDTO>,
+            expected_registration_id: Option<i32>,
+            expected_result: Result<(), CheckInError>,
+        }
+        let token_signer = new_token_signer(SECRET);
+        let check_in_code =
+            issue_registration_token(&*token_signer, RegistrationTokenPurpose::CheckIn, 7);
+        let occurrence_date: DateTime<Utc> = Utc.with_ymd_and_hms(2025, 1, 3, 18, 0, 0).unwrap();
+        let event = EventDTO {
+            id: 1,
+            title: "weekly halaqa".to_owned(),
+            description: None,
+            date: Utc.with_ymd_and_hms(2024, 12, 27, 18, 0, 0).unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::Weekly,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        };
+        let cancellation = EventExceptionDTO {
+            event_id: 1,
+            occurrence_date,
+            replacement_date: None,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the code was not signed by us, I should get an invalid check-in code error",
+                check_in_code: issue_registration_token(
+                    &*new_token_signer(b"another secret"),
+                    RegistrationTokenPurpose::CheckIn,
+                    1,
+                ),
+                occurrence_date,
+                mock_get_event_id_result: None,
+                exceptions: Vec::new(),
+                expected_registration_id: None,
+                expected_result: Err(CheckInError::InvalidCheckInCode),
+            },
+            TestCase {
+                description: "When the code is a cancellation token, I should get an invalid check-in code error",
+                check_in_code: issue_registration_token(
+                    &*token_signer,
+                    RegistrationTokenPurpose::Cancellation,
+                    1,
+                ),
+                occurrence_date,
+                mock_get_event_id_result: None,
+                exceptions: Vec::new(),
+                expected_registration_id: None,
+                expected_result: Err(CheckInError::InvalidCheckInCode),
+            },
+            TestCase {
+                description: "When the registration has been cancelled, I should get a registration not found error",
+                check_in_code: check_in_code.clone(),
+                occurrence_date,
+                mock_get_event_id_result: Some(Err(CheckInError::RegistrationNotFound)),
+                exceptions: Vec::new(),
+                expected_registration_id: None,
+                expected_result: Err(CheckInError::RegistrationNotFound),
+            },
+            TestCase {
+                description: "When the date is not an occurrence of the event, I should get a not an occurrence error",
+                check_in_code: check_in_code.clone(),
+                occurrence_date: Utc.with_ymd_and_hms(2025, 1, 4, 18, 0, 0).unwrap(),
+                mock_get_event_id_result: Some(Ok(1)),
+                exceptions: Vec::new(),
+                expected_registration_id: None,
+                expected_result: Err(CheckInError::NotAnOccurrence),
+            },
+            TestCase {
+                description: "When the occurrence has been cancelled, I should get a not an occurrence error",
+                check_in_code: check_in_code.clone(),
+                occurrence_date,
+                mock_get_event_id_result: Some(Ok(1)),
+                exceptions: vec![cancellation.clone()],
+                expected_registration_id: None,
+                expected_result: Err(CheckInError::NotAnOccurrence),
+            },
+            TestCase {
+                description: "When the occurrence has been moved, the registration should be checked in to the date it was scheduled for",
+                check_in_code: check_in_code.clone(),
+                occurrence_date,
+                mock_get_event_id_result: Some(Ok(1)),
+                exceptions: vec![EventExceptionDTO {
+                    replacement_date: Some(Utc.with_ymd_and_hms(2025, 1, 4, 18, 0, 0).unwrap()),
+                    ..cancellation
+                }],
+                expected_registration_id: Some(7),
+                expected_result: Ok(()),
+            },
+            TestCase {
+                description: "When the code is genuine, the registration it was issued for should be checked in",
+                check_in_code,
+                occurrence_date,
+                mock_get_event_id_result: Some(Ok(1)),
+                exceptions: Vec::new(),
+                expected_registration_id: Some(7),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventRegistrationsAdminRepository::new();
+            let mut mock_event_retrieval_service = MockEventRetrievalService::new();
+            if let Some(mock_get_event_id_result) = test_case.mock_get_event_id_result {
+                let is_found = mock_get_event_id_result.is_ok();
+                mock_repository
+                    .expect_get_registration_event_id()
+                    .withf(|registration_id| *registration_id == 7)
+                    .return_once(move |_| mock_get_event_id_result);
+                if is_found {
+                    let calendar = EventsCalendar {
+                        events: vec![event.clone()],
+                        exceptions: test_case.exceptions,
+                    };
+                    mock_event_retrieval_service
+                        .expect_get_events_calendar()
+                        .return_once(move |_| Ok(calendar));
+                }
+            }
+            let expected_occurrence_date = test_case.occurrence_date;
+            if let Some(expected_registration_id) = test_case.expected_registration_id {
+                mock_repository
+                    .expect_insert_event_check_in()
+                    .withf(move |registration_id, actual_occurrence_date| {
+                        *registration_id == expected_registration_id
+                            && *actual_occurrence_date == expected_occurrence_date
+                    })
+                    .return_once(move |_, _| {
+                        Ok(CheckedInAttendee {
+                            event_id: 1,
+                            full_name: "John Smith".to_owned(),
+                            party_size: 2,
+                            occurrence_date: expected_occurrence_date,
+                            checked_in_at: expected_occurrence_date,
+                        })
+                    });
+            }
+            let service = new_event_registration_admin_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_event_retrieval_service),
+                new_token_signer(SECRET),
+            );
+            let actual_result = service
+                .check_in(CheckInRequest {
+                    check_in_code: test_case.check_in_code,
+                    occurrence_date: test_case.occurrence_date,
+                })
+                .await;
+            assert_eq!(test_case.expected_result, actual_result.map(|_| ()));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod delete_reminder_subscription_error;
+pub mod get_reminder_subscriptions_error;
+pub mod reminder_delivery_error;
+pub mod send_reminders_error;
@@ -0,0 +1,14 @@
+use crate::features::event_reminders::errors::get_reminder_subscriptions_error::GetReminderSubscriptionsError;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SendRemindersError {
+    UnableToGetSubscriptions,
+    UnableToGetEvents,
+}
+
+impl From<GetReminderSubscriptionsError> for SendRemindersError {
+    #[inline]
+    fn from(_: GetReminderSubscriptionsError) -> Self {
+        SendRemindersError::UnableToGetSubscriptions
+    }
+}
@@ -0,0 +1,48 @@
+use crate::features::event_reminders::errors::delete_reminder_subscription_error::DeleteReminderSubscriptionError;
+use crate::features::event_reminders::errors::get_reminder_subscriptions_error::GetReminderSubscriptionsError;
+use crate::features::event_reminders::errors::reminder_delivery_error::ReminderDeliveryError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::reminder_subscription::ReminderSubscription;
+use masjid_app_api_library::new_repository;
+use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use std::sync::Arc;
+
+mod mysql_impl;
+mod redis_impl;
+
+#[automock]
+#[async_trait]
+pub trait EventRemindersAdminRepository: Send + Sync {
+    async fn get_reminder_subscriptions(
+        &self,
+    ) -> Result<Vec<ReminderSubscription>, GetReminderSubscriptionsError>;
+    /// Records that the reminder for the occurrence is being sent, returning false when it already
+    /// has been
+    async fn claim_reminder_delivery(
+        &self,
+        subscription_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<bool, ReminderDeliveryError>;
+    async fn release_reminder_delivery(
+        &self,
+        subscription_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<(), ReminderDeliveryError>;
+    async fn delete_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), DeleteReminderSubscriptionError>;
+}
+
+pub async fn new_event_reminders_admin_repository(
+    repository_mode: RepositoryMode,
+) -> Arc<dyn EventRemindersAdminRepository> {
+    new_repository!(repository_mode, RepositoryType::Events)
+}
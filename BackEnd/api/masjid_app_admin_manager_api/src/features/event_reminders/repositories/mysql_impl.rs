@@ -0,0 +1,139 @@
+use crate::features::event_reminders::errors::delete_reminder_subscription_error::DeleteReminderSubscriptionError;
+use crate::features::event_reminders::errors::get_reminder_subscriptions_error::GetReminderSubscriptionsError;
+use crate::features::event_reminders::errors::reminder_delivery_error::ReminderDeliveryError;
+use crate::features::event_reminders::repositories::EventRemindersAdminRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::reminder_subscription::ReminderSubscription;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use sqlx::Row;
+
+#[derive(sqlx::FromRow)]
+struct ReminderSubscriptionRow {
+    id: i32,
+    event_id: Option<i32>,
+    event_type: Option<String>,
+    channel: String,
+    recipient: String,
+    hours_before: u16,
+}
+
+impl TryFrom<ReminderSubscriptionRow> for ReminderSubscription {
+    type Error = ();
+    fn try_from(row: ReminderSubscriptionRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            event_id: row.event_id,
+            event_type: row
+                .event_type
+                .map(|event_type| event_type.parse())
+                .transpose()?,
+            channel: row.channel.parse()?,
+            recipient: row.recipient,
+            hours_before: row.hours_before,
+        })
+    }
+}
+
+#[async_trait]
+impl EventRemindersAdminRepository for MySqlRepository {
+    async fn get_reminder_subscriptions(
+        &self,
+    ) -> Result<Vec<ReminderSubscription>, GetReminderSubscriptionsError> {
+        let db_connection = self.db_connection.clone();
+        let rows = sqlx::query_as::<_, ReminderSubscriptionRow>(
+            "CALL get_event_reminder_subscriptions();",
+        )
+        .fetch_all(&*db_connection)
+        .await
+        .map_err(|err| {
+            tracing::error!(
+                error = err.to_string(),
+                "unable to fetch reminder subscriptions from database"
+            );
+            GetReminderSubscriptionsError::UnableToGetSubscriptions
+        })?;
+        rows.into_iter()
+            .map(|row| {
+                let subscription_id = row.id;
+                ReminderSubscription::try_from(row).map_err(|_| {
+                    tracing::error!(
+                        subscription_id = subscription_id,
+                        "reminder subscription has an unknown event type or channel"
+                    );
+                    GetReminderSubscriptionsError::UnableToGetSubscriptions
+                })
+            })
+            .collect()
+    }
+
+    async fn claim_reminder_delivery(
+        &self,
+        subscription_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<bool, ReminderDeliveryError> {
+        let db_connection = self.db_connection.clone();
+        let row = sqlx::query("CALL claim_event_reminder_delivery(?, ?);")
+            .bind(subscription_id)
+            .bind(occurrence_date)
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    subscription_id = subscription_id,
+                    error = err.to_string(),
+                    "unable to claim reminder delivery"
+                );
+                ReminderDeliveryError::UnableToClaimDelivery
+            })?;
+        let claimed: i64 = row.get(0);
+        Ok(claimed > 0)
+    }
+
+    async fn release_reminder_delivery(
+        &self,
+        subscription_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<(), ReminderDeliveryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL release_event_reminder_delivery(?, ?);")
+            .bind(subscription_id)
+            .bind(occurrence_date)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    subscription_id = subscription_id,
+                    error = err.to_string(),
+                    "unable to release reminder delivery"
+                );
+                ReminderDeliveryError::UnableToReleaseDelivery
+            })?;
+        Ok(())
+    }
+
+    async fn delete_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), DeleteReminderSubscriptionError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL delete_event_reminder_subscription(?);")
+            .bind(subscription_id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Subscription not found" {
+                        return DeleteReminderSubscriptionError::SubscriptionNotFound;
+                    }
+                }
+                tracing::error!(
+                    subscription_id = subscription_id,
+                    error = err.to_string(),
+                    "unable to delete reminder subscription"
+                );
+                DeleteReminderSubscriptionError::UnableToDeleteSubscription
+            })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::features::event_reminders::errors::delete_reminder_subscription_error::DeleteReminderSubscriptionError;
+use crate::features::event_reminders::errors::get_reminder_subscriptions_error::GetReminderSubscriptionsError;
+use crate::features::event_reminders::errors::reminder_delivery_error::ReminderDeliveryError;
+use crate::features::event_reminders::repositories::EventRemindersAdminRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::reminder_subscription::ReminderSubscription;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+
+#[async_trait]
+impl EventRemindersAdminRepository for InMemoryRepository {
+    async fn get_reminder_subscriptions(
+        &self,
+    ) -> Result<Vec<ReminderSubscription>, GetReminderSubscriptionsError> {
+        tracing::warn!("in-memory database not implemented for get_reminder_subscriptions");
+        Err(GetReminderSubscriptionsError::UnableToGetSubscriptions)
+    }
+
+    async fn claim_reminder_delivery(
+        &self,
+        subscription_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<bool, ReminderDeliveryError> {
+        tracing::warn!("in-memory database not implemented for claim_reminder_delivery");
+        Err(ReminderDeliveryError::UnableToClaimDelivery)
+    }
+
+    async fn release_reminder_delivery(
+        &self,
+        subscription_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<(), ReminderDeliveryError> {
+        tracing::warn!("in-memory database not implemented for release_reminder_delivery");
+        Err(ReminderDeliveryError::UnableToReleaseDelivery)
+    }
+
+    async fn delete_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), DeleteReminderSubscriptionError> {
+        tracing::warn!("in-memory database not implemented for delete_reminder_subscription");
+        Err(DeleteReminderSubscriptionError::UnableToDeleteSubscription)
+    }
+}
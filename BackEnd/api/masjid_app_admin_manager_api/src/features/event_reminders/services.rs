@@ -0,0 +1,537 @@
+use crate::features::event_reminders::errors::send_reminders_error::SendRemindersError;
+use crate::features::event_reminders::repositories::EventRemindersAdminRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use masjid_app_api_library::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use masjid_app_api_library::features::events::models::event_status::EventStatus;
+use masjid_app_api_library::features::events::models::reminder_channel::ReminderChannel;
+use masjid_app_api_library::features::events::models::reminder_subscription::ReminderSubscription;
+use masjid_app_api_library::features::events::services::event_retrieval_service::EventRetrievalService;
+use masjid_app_api_library::features::events::services::reminder_tokens::{
+    ReminderTokenPurpose, issue_reminder_token,
+};
+use masjid_app_api_library::shared::services::notifications::errors::NotifyError;
+use masjid_app_api_library::shared::services::notifications::notification::Notification;
+use masjid_app_api_library::shared::services::notifications::r#trait::Notifier;
+use masjid_app_api_library::shared::services::token_signing::r#trait::TokenSigner;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventReminderScheduler: Send + Sync {
+    /// Sends every reminder which has become due and has not been sent yet, returning how many
+    /// were sent
+    async fn send_due_reminders(&self, now: DateTime<Utc>) -> Result<usize, SendRemindersError>;
+}
+
+struct EventReminderSchedulerImpl {
+    repository: Arc<dyn EventRemindersAdminRepository>,
+    event_retrieval_service: Arc<dyn EventRetrievalService>,
+    push_notifier: Arc<dyn Notifier>,
+    email_notifier: Arc<dyn Notifier>,
+    token_signer: Arc<dyn TokenSigner>,
+    unsubscribe_url: String,
+}
+
+pub fn new_event_reminder_scheduler(
+    repository: Arc<dyn EventRemindersAdminRepository>,
+    event_retrieval_service: Arc<dyn EventRetrievalService>,
+    push_notifier: Arc<dyn Notifier>,
+    email_notifier: Arc<dyn Notifier>,
+    token_signer: Arc<dyn TokenSigner>,
+    unsubscribe_url: String,
+) -> Arc<dyn EventReminderScheduler> {
+    Arc::new(EventReminderSchedulerImpl {
+        repository,
+        event_retrieval_service,
+        push_notifier,
+        email_notifier,
+        token_signer,
+        unsubscribe_url,
+    })
+}
+
+fn is_subscribed_to(subscription: &ReminderSubscription, occurrence: &EventOccurrenceDTO) -> bool {
+    subscription.event_id == Some(occurrence.event.id)
+        || subscription.event_type.as_ref() == Some(&occurrence.event.event_details.event_type)
+}
+
+/// Pairs each subscription with the occurrences it is due a reminder for. A reminder is due from
+/// `hours_before` the occurrence until the occurrence starts, so reminders missed while the
+/// scheduler was stopped are still sent late rather than not at all
+fn due_reminders<'a>(
+    subscriptions: &'a [ReminderSubscription],
+    occurrences: &'a [EventOccurrenceDTO],
+    now: DateTime<Utc>,
+) -> Vec<(&'a ReminderSubscription, &'a EventOccurrenceDTO)> {
+    occurrences
+        .iter()
+        // Postponed and cancelled occurrences are not going ahead on their dates
+        .filter(|occurrence| occurrence.event.event_details.event_status == EventStatus::Confirmed)
+        .filter(|occurrence| now < occurrence.occurrence_date)
+        .flat_map(|occurrence| {
+            subscriptions
+                .iter()
+                .filter(move |subscription| {
+                    is_subscribed_to(subscription, occurrence)
+                        && occurrence.occurrence_date
+                            - TimeDelta::hours(subscription.hours_before.into())
+                            <= now
+                })
+                .map(move |subscription| (subscription, occurrence))
+        })
+        .collect()
+}
+
+impl EventReminderSchedulerImpl {
+    /// Every reminder links to where it can be stopped, so recipients never need to have kept
+    /// the token they were given when subscribing
+    fn reminder_notification(
+        &self,
+        subscription: &ReminderSubscription,
+        occurrence: &EventOccurrenceDTO,
+    ) -> Notification {
+        let unsubscribe_token = issue_reminder_token(
+            &*self.token_signer,
+            ReminderTokenPurpose::Unsubscribe,
+            subscription.id,
+        );
+        Notification {
+            recipient: subscription.recipient.clone(),
+            title: occurrence.event.title.clone(),
+            body: format!(
+                "Starts on {} (UTC)\n\nTo stop these reminders, visit {}?token={unsubscribe_token}",
+                occurrence.occurrence_date.format("%A %-d %B at %H:%M"),
+                self.unsubscribe_url
+            ),
+        }
+    }
+
+    /// Sends a claimed reminder, returning whether it was sent. Reminders which could not be sent
+    /// are released to be tried again, unless the recipient can never be reached, in which case
+    /// the subscription is removed
+    async fn send_reminder(
+        &self,
+        subscription: &ReminderSubscription,
+        occurrence: &EventOccurrenceDTO,
+    ) -> bool {
+        let notifier = match subscription.channel {
+            ReminderChannel::Push => &self.push_notifier,
+            ReminderChannel::Email => &self.email_notifier,
+        };
+        match notifier
+            .notify(self.reminder_notification(subscription, occurrence))
+            .await
+        {
+            Ok(()) => true,
+            Err(NotifyError::InvalidRecipient) => {
+                tracing::warn!(
+                    subscription_id = subscription.id,
+                    "reminder recipient is no longer reachable, removing subscription"
+                );
+                if let Err(err) = self
+                    .repository
+                    .delete_reminder_subscription(&subscription.id)
+                    .await
+                {
+                    tracing::error!(error = ?err, subscription_id = subscription.id, "unable to remove reminder subscription");
+                }
+                false
+            }
+            Err(NotifyError::UnableToNotify) => {
+                if let Err(err) = self
+                    .repository
+                    .release_reminder_delivery(&subscription.id, &occurrence.occurrence_date)
+                    .await
+                {
+                    tracing::error!(error = ?err, subscription_id = subscription.id, "unable to release reminder delivery");
+                }
+                false
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl EventReminderScheduler for EventReminderSchedulerImpl {
+    async fn send_due_reminders(&self, now: DateTime<Utc>) -> Result<usize, SendRemindersError> {
+        let subscriptions = self.repository.get_reminder_subscriptions().await?;
+        let Some(furthest_hours_before) = subscriptions
+            .iter()
+            .map(|subscription| subscription.hours_before)
+            .max()
+        else {
+            return Ok(0);
+        };
+        // The window ends an hour late, as its end is exclusive and an occurrence exactly the
+        // furthest hours before away is already due
+        let occurrences = self
+            .event_retrieval_service
+            .get_event_occurrences(
+                now,
+                now + TimeDelta::hours(furthest_hours_before as i64 + 1),
+            )
+            .await
+            .map_err(|_| SendRemindersError::UnableToGetEvents)?;
+
+        let mut sent_reminders = 0;
+        for (subscription, occurrence) in due_reminders(&subscriptions, &occurrences, now) {
+            // A failure is retried on the next run, so it does not stop the remaining reminders
+            match self
+                .repository
+                .claim_reminder_delivery(&subscription.id, &occurrence.occurrence_date)
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    tracing::error!(error = ?err, subscription_id = subscription.id, "unable to claim reminder delivery");
+                    continue;
+                }
+            }
+            if self.send_reminder(subscription, occurrence).await {
+                tracing::info!(
+                    subscription_id = subscription.id,
+                    event_id = occurrence.event.id,
+                    "event reminder sent"
+                );
+                sent_reminders += 1;
+            }
+        }
+        Ok(sent_reminders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_reminders::errors::get_reminder_subscriptions_error::GetReminderSubscriptionsError;
+    use crate::features::event_reminders::repositories::MockEventRemindersAdminRepository;
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::services::event_retrieval_service::MockEventRetrievalService;
+    use masjid_app_api_library::shared::services::notifications::r#trait::MockNotifier;
+    use masjid_app_api_library::shared::services::token_signing::r#trait::new_token_signer;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+
+    const NOW: &str = "2026-03-04T12:00:00Z";
+    const SECRET: &[u8] = b"secret";
+    const UNSUBSCRIBE_URL: &str = "https://masjidapp.com/reminders/unsubscribe";
+
+    fn occurrence(id: i32, occurrence_date: &str, event_status: EventStatus) -> EventOccurrenceDTO {
+        EventOccurrenceDTO {
+            occurrence_date: occurrence_date.parse().unwrap(),
+            original_date: None,
+            event: EventDTO {
+                id,
+                title: "Weekly Tafsir Circle".to_owned(),
+                description: None,
+                date: "2026-01-07T19:00:00Z".parse().unwrap(),
+                event_details: EventDetails {
+                    event_type: EventType::Class,
+                    event_recurrence: EventRecurrence::Weekly,
+                    recurrence_end: None,
+                    event_status,
+                    publish_at: None,
+                    age_range: None,
+                    capacity: None,
+                    room_id: None,
+                    duration_minutes: None,
+                    speaker_ids: Vec::new(),
+                    category_id: None,
+                    tags: Vec::new(),
+                    image_url: None,
+                    image_variants: None,
+                    contact_details: ContactDetails {
+                        full_name: "Zayd McArdle".to_owned(),
+                        title: None,
+                        phone_number: "07123456789".to_owned(),
+                        email: None,
+                        visibility: ContactVisibility::default(),
+                    },
+                },
+                version: 1,
+            },
+        }
+    }
+
+    fn subscription(
+        id: i32,
+        event_id: Option<i32>,
+        event_type: Option<EventType>,
+        channel: ReminderChannel,
+        hours_before: u16,
+    ) -> ReminderSubscription {
+        ReminderSubscription {
+            id,
+            event_id,
+            event_type,
+            channel,
+            recipient: "johnsmith@test.com".to_owned(),
+            hours_before,
+        }
+    }
+
+    #[test]
+    fn test_due_reminders() {
+        struct TestCase {
+            description: &'static str,
+            subscriptions: Vec<ReminderSubscription>,
+            occurrences: Vec<EventOccurrenceDTO>,
+            expected_reminders: Vec<(i32, &'static str)>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When an occurrence is within the hours before of a subscription to its event, a reminder should be due",
+                subscriptions: vec![subscription(1, Some(1), None, ReminderChannel::Email, 24)],
+                occurrences: vec![occurrence(
+                    1,
+                    "2026-03-04T19:00:00Z",
+                    EventStatus::Confirmed,
+                )],
+                expected_reminders: vec![(1, "2026-03-04T19:00:00Z")],
+            },
+            TestCase {
+                description: "When an occurrence is of a subscribed event type, a reminder should be due",
+                subscriptions: vec![subscription(
+                    1,
+                    None,
+                    Some(EventType::Class),
+                    ReminderChannel::Push,
+                    24,
+                )],
+                occurrences: vec![occurrence(
+                    2,
+                    "2026-03-04T19:00:00Z",
+                    EventStatus::Confirmed,
+                )],
+                expected_reminders: vec![(1, "2026-03-04T19:00:00Z")],
+            },
+            TestCase {
+                description: "When an occurrence is further away than the hours before, no reminder should be due yet",
+                subscriptions: vec![subscription(1, Some(1), None, ReminderChannel::Email, 2)],
+                occurrences: vec![occurrence(
+                    1,
+                    "2026-03-04T19:00:00Z",
+                    EventStatus::Confirmed,
+                )],
+                expected_reminders: Vec::new(),
+            },
+            TestCase {
+                description: "When an occurrence has already started, no reminder should be due",
+                subscriptions: vec![subscription(1, Some(1), None, ReminderChannel::Email, 24)],
+                occurrences: vec![occurrence(1, NOW, EventStatus::Confirmed)],
+                expected_reminders: Vec::new(),
+            },
+            TestCase {
+                description: "When an event has been postponed, no reminder should be due",
+                subscriptions: vec![subscription(1, Some(1), None, ReminderChannel::Email, 24)],
+                occurrences: vec![occurrence(
+                    1,
+                    "2026-03-04T19:00:00Z",
+                    EventStatus::Postponed,
+                )],
+                expected_reminders: Vec::new(),
+            },
+            TestCase {
+                description: "When the subscription is for another event, no reminder should be due",
+                subscriptions: vec![
+                    subscription(1, Some(2), None, ReminderChannel::Email, 24),
+                    subscription(2, None, Some(EventType::Talk), ReminderChannel::Email, 24),
+                ],
+                occurrences: vec![occurrence(
+                    1,
+                    "2026-03-04T19:00:00Z",
+                    EventStatus::Confirmed,
+                )],
+                expected_reminders: Vec::new(),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let actual_reminders: Vec<(i32, DateTime<Utc>)> = due_reminders(
+                &test_case.subscriptions,
+                &test_case.occurrences,
+                NOW.parse().unwrap(),
+            )
+            .into_iter()
+            .map(|(subscription, occurrence)| (subscription.id, occurrence.occurrence_date))
+            .collect();
+            let expected_reminders: Vec<(i32, DateTime<Utc>)> = test_case
+                .expected_reminders
+                .into_iter()
+                .map(|(subscription_id, occurrence_date)| {
+                    (subscription_id, occurrence_date.parse().unwrap())
+                })
+                .collect();
+            assert_eq!(expected_reminders, actual_reminders);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_due_reminders() {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Outcome {
+            AlreadyClaimed,
+            Sent,
+            InvalidRecipient,
+            UnableToNotify,
+        }
+        struct TestCase {
+            description: &'static str,
+            subscriptions: Result<Vec<ReminderSubscription>, GetReminderSubscriptionsError>,
+            outcome: Option<Outcome>,
+            expected_result: Result<usize, SendRemindersError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the subscriptions cannot be retrieved, I should receive an error",
+                subscriptions: Err(GetReminderSubscriptionsError::UnableToGetSubscriptions),
+                outcome: None,
+                expected_result: Err(SendRemindersError::UnableToGetSubscriptions),
+            },
+            TestCase {
+                description: "When there are no subscriptions, nothing should be sent",
+                subscriptions: Ok(Vec::new()),
+                outcome: None,
+                expected_result: Ok(0),
+            },
+            TestCase {
+                description: "When the reminder has already been claimed, it should not be sent again",
+                subscriptions: Ok(vec![subscription(
+                    1,
+                    Some(1),
+                    None,
+                    ReminderChannel::Push,
+                    24,
+                )]),
+                outcome: Some(Outcome::AlreadyClaimed),
+                expected_result: Ok(0),
+            },
+            TestCase {
+                description: "When the reminder is due, it should be sent through the subscription's channel",
+                subscriptions: Ok(vec![subscription(
+                    1,
+                    Some(1),
+                    None,
+                    ReminderChannel::Push,
+                    24,
+                )]),
+                outcome: Some(Outcome::Sent),
+                expected_result: Ok(1),
+            },
+            TestCase {
+                description: "When the recipient can never be reached, the subscription should be removed",
+                subscriptions: Ok(vec![subscription(
+                    1,
+                    Some(1),
+                    None,
+                    ReminderChannel::Push,
+                    24,
+                )]),
+                outcome: Some(Outcome::InvalidRecipient),
+                expected_result: Ok(0),
+            },
+            TestCase {
+                description: "When the reminder cannot be sent, it should be released to be tried again",
+                subscriptions: Ok(vec![subscription(
+                    1,
+                    Some(1),
+                    None,
+                    ReminderChannel::Push,
+                    24,
+                )]),
+                outcome: Some(Outcome::UnableToNotify),
+                expected_result: Ok(0),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventRemindersAdminRepository::new();
+            let mut mock_event_retrieval_service = MockEventRetrievalService::new();
+            let mut mock_push_notifier = MockNotifier::new();
+            let has_subscriptions = test_case
+                .subscriptions
+                .as_ref()
+                .is_ok_and(|subscriptions| !subscriptions.is_empty());
+            mock_repository
+                .expect_get_reminder_subscriptions()
+                .return_once(move || test_case.subscriptions);
+            if has_subscriptions {
+                mock_event_retrieval_service
+                    .expect_get_event_occurrences()
+                    .withf(|from, to| {
+                        *from == NOW.parse::<DateTime<Utc>>().unwrap()
+                            && *to == "2026-03-05T13:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                    })
+                    .return_once(|_, _| {
+                        Ok(vec![occurrence(
+                            1,
+                            "2026-03-04T19:00:00Z",
+                            EventStatus::Confirmed,
+                        )])
+                    });
+            }
+            if let Some(outcome) = test_case.outcome {
+                mock_repository
+                    .expect_claim_reminder_delivery()
+                    .withf(|subscription_id, occurrence_date| {
+                        *subscription_id == 1
+                            && *occurrence_date
+                                == "2026-03-04T19:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                    })
+                    .return_once(move |_, _| Ok(outcome != Outcome::AlreadyClaimed));
+                if outcome != Outcome::AlreadyClaimed {
+                    let expected_body = format!(
+                        "Starts on Wednesday 4 March at 19:00 (UTC)\n\n\
+                        To stop these reminders, visit {UNSUBSCRIBE_URL}?token={}",
+                        issue_reminder_token(
+                            &*new_token_signer(SECRET),
+                            ReminderTokenPurpose::Unsubscribe,
+                            1,
+                        )
+                    );
+                    mock_push_notifier
+                        .expect_notify()
+                        .withf(move |notification| {
+                            notification.recipient == "johnsmith@test.com"
+                                && notification.title == "Weekly Tafsir Circle"
+                                && notification.body == expected_body
+                        })
+                        .return_once(move |_| match outcome {
+                            Outcome::InvalidRecipient => Err(NotifyError::InvalidRecipient),
+                            Outcome::UnableToNotify => Err(NotifyError::UnableToNotify),
+                            _ => Ok(()),
+                        });
+                }
+                if outcome == Outcome::InvalidRecipient {
+                    mock_repository
+                        .expect_delete_reminder_subscription()
+                        .withf(|subscription_id| *subscription_id == 1)
+                        .return_once(|_| Ok(()));
+                }
+                if outcome == Outcome::UnableToNotify {
+                    mock_repository
+                        .expect_release_reminder_delivery()
+                        .return_once(|_, _| Ok(()));
+                }
+            }
+            let scheduler = new_event_reminder_scheduler(
+                Arc::new(mock_repository),
+                Arc::new(mock_event_retrieval_service),
+                Arc::new(mock_push_notifier),
+                Arc::new(MockNotifier::new()),
+                new_token_signer(SECRET),
+                UNSUBSCRIBE_URL.to_owned(),
+            );
+            assert_eq!(
+                test_case.expected_result,
+                scheduler.send_due_reminders(NOW.parse().unwrap()).await
+            );
+        }
+    }
+}
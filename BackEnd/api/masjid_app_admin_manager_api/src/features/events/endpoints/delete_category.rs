@@ -0,0 +1,119 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::category_management_service::CategoryManagementService;
+use crate::features::events::services::errors::category_management_error::CategoryManagementError;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+pub async fn delete_category(
+    State(state): State<AuditedServiceAppState<Arc<dyn CategoryManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path(category_id): Path<i32>,
+) -> Response {
+    if category_id == 0 {
+        return (StatusCode::BAD_REQUEST, "category ids cannot be 0").into_response();
+    }
+
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::EventCategory, Some(category_id))
+        .await;
+    match state.service.remove_category(category_id).await {
+        Ok(()) => {
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Delete,
+                    entity_type: AuditEntityType::EventCategory,
+                    entity_id: Some(category_id),
+                    before,
+                    after: None,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(CategoryManagementError::CategoryNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(CategoryManagementError::CategoryInUse) => (
+            StatusCode::CONFLICT,
+            "the category still has one or more events listed under it",
+        )
+            .into_response(),
+        Err(CategoryManagementError::CategoryAlreadyExists)
+        | Err(CategoryManagementError::UnableToUpdateCategories) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::services::category_management_service::MockCategoryManagementService;
+
+    #[tokio::test]
+    async fn test_delete_category() {
+        struct TestCase {
+            description: &'static str,
+            category_id: i32,
+            expected_service_response: Option<Result<(), CategoryManagementError>>,
+            expected_status: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When I use an invalid category ID, I should get a bad request",
+                category_id: 0,
+                expected_service_response: None,
+                expected_status: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When an event is still listed under the category, I should get a conflict",
+                category_id: 1,
+                expected_service_response: Some(Err(CategoryManagementError::CategoryInUse)),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When the category does not exist, I should get a not found",
+                category_id: 1,
+                expected_service_response: Some(Err(CategoryManagementError::CategoryNotFound)),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When deleting the category succeeds, I should get an ok response",
+                category_id: 1,
+                expected_service_response: Some(Ok(())),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockCategoryManagementService::new();
+            if let Some(mock_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_remove_category()
+                    .return_once(move |_| mock_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn CategoryManagementService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = delete_category(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                Path(test_case.category_id),
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+    }
+}
@@ -1,74 +1,52 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
 use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
 use crate::features::events::services::errors::event_deletion_error::EventDeletionError;
 use crate::features::events::services::event_deletion_service::EventDeletionService;
+use crate::shared::audited_app_state::AuditedFileHandlingServiceAppState;
 use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use masjid_app_api_library::shared::extractors::file_handler::FileHandler;
-use masjid_app_api_library::shared::types::app_state::ServiceAppState;
 use std::sync::Arc;
 
 pub async fn delete_event(
-    State(state): State<ServiceAppState<Arc<dyn EventDeletionService>>>,
-    file_deleter: FileHandler,
+    State(state): State<AuditedFileHandlingServiceAppState<Arc<dyn EventDeletionService>>>,
+    file_handler: FileHandler,
     claims: Claims,
+    RequestId(request_id): RequestId,
     Path(event_id): Path<i32>,
 ) -> Response {
     if event_id == 0 {
         return (StatusCode::BAD_REQUEST, "event ids cannot be 0").into_response();
     }
 
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::Event, Some(event_id))
+        .await;
     match state.service.delete_event(event_id).await {
-        Ok(()) => {
-            // TODO - implement file handling in a separate api
-            /*if let Some(url) = image_url {
-                let file_directory = url
-                    .splitn(2, "//") // Split on double slash
-                    .nth(1) // Take the part after protocol
-                    .and_then(|s| s.splitn(2, '/').nth(1)); // Take the part after first single slash
-
-                if let Some(file_directory) = file_directory {
-                    if let Err(delete_file_err) = file_deleter.delete_file(file_directory).await {
-                        return match delete_file_err {
-                            DeleteError::FileNotFound => {
-                                (StatusCode::NOT_FOUND, "file path in request uri not found")
-                                    .into_response()
-                            }
-                            DeleteError::DirectoryNotFound => {
-                                (StatusCode::NOT_FOUND, "endpoint in request not found")
-                                    .into_response()
-                            }
-                            DeleteError::PathIsTraversal => {
-                                (StatusCode::FORBIDDEN, "invalid url").into_response()
-                            }
-                            DeleteError::PermissionDenied => (
-                                StatusCode::FORBIDDEN,
-                                "requested file to be deleted cannot be done",
-                            )
-                                .into_response(),
-                            DeleteError::DirectoryMistookForFile => (
-                                StatusCode::UNPROCESSABLE_ENTITY,
-                                "filename in request is a directory",
-                            )
-                                .into_response(),
-                            DeleteError::UnableToDeleteFileDueToReadOnlyAccess => (
-                                StatusCode::FORBIDDEN,
-                                "requested file to be deleted cannot be done, as it is read only",
-                            )
-                                .into_response(),
-                            DeleteError::UnableToDeleteFileDueToBeingInUse
-                            | DeleteError::IOError(_) => {
-                                StatusCode::INTERNAL_SERVER_ERROR.into_response()
-                            }
-                            DeleteError::EmptyPath => StatusCode::BAD_REQUEST.into_response(),
-                        };
-                    }
-                } else {
-                    return (StatusCode::BAD_REQUEST, format!("invalid path {url}"))
-                        .into_response();
-                }
-            }*/
+        Ok(image_url) => {
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Delete,
+                    entity_type: AuditEntityType::Event,
+                    entity_id: Some(event_id),
+                    before,
+                    after: None,
+                    request_id,
+                })
+                .await;
+            if let Some(image_url) = image_url
+                && let Err(err) = file_handler.delete_image_by_url(&image_url).await
+            {
+                tracing::error!(error = ?err, url = %image_url, "unable to delete image of deleted event");
+            }
             StatusCode::OK.into_response()
         }
         Err(EventDeletionError::RepositoryError(DeleteEventError::EventNotFound)) => {
@@ -83,28 +61,41 @@ pub async fn delete_event(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
     use crate::features::events::services::event_deletion_service::MockEventDeletionService;
+    use masjid_app_api_library::shared::extractors::file_handler::UPLOADS_ENDPOINT;
+    use masjid_app_api_library::shared::services::file_storage::providers::FileStorageProvider;
+    use masjid_app_api_library::shared::services::file_storage::settings::UploadLimits;
+    use masjid_app_api_library::shared::services::file_storage::r#trait::new_file_storage;
     #[tokio::test]
     async fn test_delete_event() {
         struct TestCase {
             description: &'static str,
             delete_event_request_id: i32,
-            file_deleter: FileHandler,
-            expected_service_response: Option<Result<(), EventDeletionError>>,
+            expected_service_response: Option<Result<Option<String>, EventDeletionError>>,
             expected_status: StatusCode,
         }
+        let upload_directory = std::env::temp_dir().join("delete_event_tests");
+        let file_handler = FileHandler::new(
+            new_file_storage(FileStorageProvider::Local {
+                directory: upload_directory.clone(),
+            }),
+            UploadLimits::default(),
+            UPLOADS_ENDPOINT.to_owned(),
+        );
+        std::fs::create_dir_all(&upload_directory).unwrap();
+        let image_path = upload_directory.join("poster.png");
+        std::fs::write(&image_path, "test data").unwrap();
         let test_cases = [
             TestCase {
                 description: "When I use an invalid event ID, I should get a bad request",
                 delete_event_request_id: 0,
-                file_deleter: FileHandler::default(),
                 expected_service_response: None,
                 expected_status: StatusCode::BAD_REQUEST,
             },
             TestCase {
                 description: "When I delete an event using a non-existent ID, I should get a not found",
                 delete_event_request_id: 1,
-                file_deleter: FileHandler::default(),
                 expected_service_response: Some(Err(EventDeletionError::RepositoryError(
                     DeleteEventError::EventNotFound,
                 ))),
@@ -113,15 +104,21 @@ mod tests {
             TestCase {
                 description: "When deleting an event fails, I should get an internal server error",
                 delete_event_request_id: 2,
-                file_deleter: FileHandler::default(),
-                expected_service_response: None,
+                expected_service_response: Some(Err(EventDeletionError::RepositoryError(
+                    DeleteEventError::UnableToDeleteEvent,
+                ))),
                 expected_status: StatusCode::INTERNAL_SERVER_ERROR,
             },
             TestCase {
                 description: "When deleting an event succeeds, I should get an ok response",
                 delete_event_request_id: 2,
-                file_deleter: FileHandler::default(),
-                expected_service_response: None,
+                expected_service_response: Some(Ok(None)),
+                expected_status: StatusCode::OK,
+            },
+            TestCase {
+                description: "When deleting an event with an image succeeds, I should get an ok response",
+                delete_event_request_id: 3,
+                expected_service_response: Some(Ok(Some("/uploads/poster.png".to_owned()))),
                 expected_status: StatusCode::OK,
             },
         ];
@@ -134,17 +131,21 @@ mod tests {
                     .return_once(move |_| mock_response);
             }
 
-            let app_state = ServiceAppState::<Arc<dyn EventDeletionService>> {
+            let app_state = AuditedFileHandlingServiceAppState::<Arc<dyn EventDeletionService>> {
                 service: Arc::new(mock_service),
+                file_handler: file_handler.clone(),
+                audit_log: new_test_audit_log_service(),
             };
             let actual_response = delete_event(
                 State(app_state),
-                test_case.file_deleter,
+                file_handler.clone(),
                 Claims::default(),
+                RequestId::default(),
                 axum::extract::Path(test_case.delete_event_request_id),
             )
             .await;
             assert_eq!(test_case.expected_status, actual_response.status());
         }
+        assert!(!image_path.exists());
     }
 }
@@ -0,0 +1,120 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::errors::event_exception_error::EventExceptionError;
+use crate::features::events::services::event_exception_service::EventExceptionService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+pub async fn delete_event_exception(
+    State(state): State<AuditedServiceAppState<Arc<dyn EventExceptionService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path((event_id, occurrence_date)): Path<(i32, DateTime<Utc>)>,
+) -> Response {
+    if event_id == 0 {
+        return (StatusCode::BAD_REQUEST, "event ids cannot be 0").into_response();
+    }
+
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::EventExceptions, Some(event_id))
+        .await;
+    match state
+        .service
+        .remove_event_exception(event_id, occurrence_date)
+        .await
+    {
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::EventExceptions, Some(event_id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::EventExceptions,
+                    entity_id: Some(event_id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(EventExceptionError::EventExceptionNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(EventExceptionError::EventNotFound)
+        | Err(EventExceptionError::NotAnOccurrence)
+        | Err(EventExceptionError::RoomConflict(_))
+        | Err(EventExceptionError::UnableToCheckRoomAvailability)
+        | Err(EventExceptionError::UnableToUpdateEventExceptions) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::services::event_exception_service::MockEventExceptionService;
+
+    #[tokio::test]
+    async fn test_delete_event_exception() {
+        struct TestCase {
+            description: &'static str,
+            event_id: i32,
+            expected_service_response: Option<Result<(), EventExceptionError>>,
+            expected_status: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When I use an invalid event ID, I should get a bad request",
+                event_id: 0,
+                expected_service_response: None,
+                expected_status: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the exception does not exist, I should get a not found",
+                event_id: 1,
+                expected_service_response: Some(Err(EventExceptionError::EventExceptionNotFound)),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When removing the exception succeeds, I should get an ok response",
+                event_id: 1,
+                expected_service_response: Some(Ok(())),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventExceptionService::new();
+            if let Some(mock_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_remove_event_exception()
+                    .return_once(move |_, _| mock_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn EventExceptionService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = delete_event_exception(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                Path((test_case.event_id, "2025-01-13T19:00:00Z".parse().unwrap())),
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+    }
+}
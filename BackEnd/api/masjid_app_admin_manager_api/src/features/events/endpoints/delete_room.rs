@@ -0,0 +1,119 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::errors::room_management_error::RoomManagementError;
+use crate::features::events::services::room_management_service::RoomManagementService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+pub async fn delete_room(
+    State(state): State<AuditedServiceAppState<Arc<dyn RoomManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path(room_id): Path<i32>,
+) -> Response {
+    if room_id == 0 {
+        return (StatusCode::BAD_REQUEST, "room ids cannot be 0").into_response();
+    }
+
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::Room, Some(room_id))
+        .await;
+    match state.service.remove_room(room_id).await {
+        Ok(()) => {
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Delete,
+                    entity_type: AuditEntityType::Room,
+                    entity_id: Some(room_id),
+                    before,
+                    after: None,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(RoomManagementError::RoomNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(RoomManagementError::RoomInUse) => (
+            StatusCode::CONFLICT,
+            "the room is still booked by one or more events",
+        )
+            .into_response(),
+        Err(RoomManagementError::RoomAlreadyExists)
+        | Err(RoomManagementError::UnableToUpdateRooms) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::services::room_management_service::MockRoomManagementService;
+
+    #[tokio::test]
+    async fn test_delete_room() {
+        struct TestCase {
+            description: &'static str,
+            room_id: i32,
+            expected_service_response: Option<Result<(), RoomManagementError>>,
+            expected_status: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When I use an invalid room ID, I should get a bad request",
+                room_id: 0,
+                expected_service_response: None,
+                expected_status: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the room is still booked by an event, I should get a conflict",
+                room_id: 1,
+                expected_service_response: Some(Err(RoomManagementError::RoomInUse)),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When the room does not exist, I should get a not found",
+                room_id: 1,
+                expected_service_response: Some(Err(RoomManagementError::RoomNotFound)),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When deleting the room succeeds, I should get an ok response",
+                room_id: 1,
+                expected_service_response: Some(Ok(())),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockRoomManagementService::new();
+            if let Some(mock_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_remove_room()
+                    .return_once(move |_| mock_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn RoomManagementService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = delete_room(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                Path(test_case.room_id),
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::errors::speaker_management_error::SpeakerManagementError;
+use crate::features::events::services::speaker_management_service::SpeakerManagementService;
+use crate::shared::audited_app_state::AuditedFileHandlingServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::extractors::file_handler::FileHandler;
+use std::sync::Arc;
+
+pub async fn delete_speaker(
+    State(state): State<AuditedFileHandlingServiceAppState<Arc<dyn SpeakerManagementService>>>,
+    file_handler: FileHandler,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path(speaker_id): Path<i32>,
+) -> Response {
+    if speaker_id == 0 {
+        return (StatusCode::BAD_REQUEST, "speaker ids cannot be 0").into_response();
+    }
+
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::Speaker, Some(speaker_id))
+        .await;
+    match state.service.remove_speaker(speaker_id).await {
+        Ok(photo_url) => {
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Delete,
+                    entity_type: AuditEntityType::Speaker,
+                    entity_id: Some(speaker_id),
+                    before,
+                    after: None,
+                    request_id,
+                })
+                .await;
+            if let Some(photo_url) = photo_url
+                && let Err(err) = file_handler.delete_image_by_url(&photo_url).await
+            {
+                tracing::error!(error = ?err, url = %photo_url, "unable to delete photo of deleted speaker");
+            }
+            StatusCode::OK.into_response()
+        }
+        Err(SpeakerManagementError::SpeakerNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(SpeakerManagementError::SpeakerInUse) => (
+            StatusCode::CONFLICT,
+            "the speaker is still listed on one or more events",
+        )
+            .into_response(),
+        Err(SpeakerManagementError::UnableToUpdateSpeakers) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::services::speaker_management_service::MockSpeakerManagementService;
+    use masjid_app_api_library::shared::extractors::file_handler::UPLOADS_ENDPOINT;
+    use masjid_app_api_library::shared::services::file_storage::providers::FileStorageProvider;
+    use masjid_app_api_library::shared::services::file_storage::settings::UploadLimits;
+    use masjid_app_api_library::shared::services::file_storage::r#trait::new_file_storage;
+
+    #[tokio::test]
+    async fn test_delete_speaker() {
+        struct TestCase {
+            description: &'static str,
+            speaker_id: i32,
+            expected_service_response: Option<Result<Option<String>, SpeakerManagementError>>,
+            expected_status: StatusCode,
+        }
+        let upload_directory = std::env::temp_dir().join("delete_speaker_tests");
+        let file_handler = FileHandler::new(
+            new_file_storage(FileStorageProvider::Local {
+                directory: upload_directory.clone(),
+            }),
+            UploadLimits::default(),
+            UPLOADS_ENDPOINT.to_owned(),
+        );
+        std::fs::create_dir_all(&upload_directory).unwrap();
+        let photo_path = upload_directory.join("speaker.png");
+        std::fs::write(&photo_path, "test data").unwrap();
+        let test_cases = [
+            TestCase {
+                description: "When I use an invalid speaker ID, I should get a bad request",
+                speaker_id: 0,
+                expected_service_response: None,
+                expected_status: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the speaker does not exist, I should get a not found",
+                speaker_id: 1,
+                expected_service_response: Some(Err(SpeakerManagementError::SpeakerNotFound)),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When the speaker is still listed on an event, I should get a conflict",
+                speaker_id: 1,
+                expected_service_response: Some(Err(SpeakerManagementError::SpeakerInUse)),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When deleting the speaker fails, I should get an internal server error",
+                speaker_id: 1,
+                expected_service_response: Some(Err(
+                    SpeakerManagementError::UnableToUpdateSpeakers,
+                )),
+                expected_status: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When deleting a speaker with a photo succeeds, I should get an ok response",
+                speaker_id: 1,
+                expected_service_response: Some(Ok(Some("/uploads/speaker.png".to_owned()))),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockSpeakerManagementService::new();
+            if let Some(mock_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_remove_speaker()
+                    .return_once(move |_| mock_response);
+            }
+
+            let app_state = AuditedFileHandlingServiceAppState::<Arc<dyn SpeakerManagementService>> {
+                service: Arc::new(mock_service),
+                file_handler: file_handler.clone(),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = delete_speaker(
+                State(app_state),
+                file_handler.clone(),
+                Claims::default(),
+                RequestId::default(),
+                Path(test_case.speaker_id),
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+        assert!(!photo_path.exists());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::features::events::events_csv::events_to_csv;
+use crate::features::events::models::events_file_format::EventsFileFormat;
+use crate::features::events::models::export_events_request::ExportEventsRequest;
+use crate::shared::jwt::Claims;
+use axum::extract::{Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use masjid_app_api_library::features::events::services::errors::get_events_service_error::GetEventsServiceError;
+use masjid_app_api_library::features::events::services::event_retrieval_service::EventRetrievalService;
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn export_events(
+    State(state): State<ServiceAppState<Arc<dyn EventRetrievalService>>>,
+    _claims: Claims,
+    Query(request): Query<ExportEventsRequest>,
+) -> Response {
+    let events = match state.service.get_events().await {
+        Ok(events) => events,
+        Err(GetEventsServiceError::UnableToGetEventsFromRepository(
+            GetEventsRepositoryError::EventsNotFound,
+        )) => Vec::new(),
+        Err(GetEventsServiceError::UnableToGetEventsFromRepository(
+            GetEventsRepositoryError::UnableToGetEvents,
+        )) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    let file = match request.format {
+        EventsFileFormat::Csv => events_to_csv(&events),
+        EventsFileFormat::Json => match serde_json::to_string(&events) {
+            Ok(file) => file,
+            Err(err) => {
+                tracing::error!(
+                    error = err.to_string(),
+                    "unable to serialise events for export"
+                );
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        },
+    };
+    (
+        StatusCode::OK,
+        [
+            (
+                header::CONTENT_TYPE,
+                request.format.content_type().to_owned(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"events.{}\"",
+                    request.format.extension()
+                ),
+            ),
+        ],
+        file,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::services::event_retrieval_service::MockEventRetrievalService;
+
+    #[tokio::test]
+    async fn test_export_events() {
+        struct TestCase {
+            description: &'static str,
+            format: EventsFileFormat,
+            service_result: Result<Vec<EventDTO>, GetEventsRepositoryError>,
+            expected_status_code: StatusCode,
+            expected_content_type: Option<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the events cannot be retrieved, I should get an INTERNAL_SERVER_ERROR response",
+                format: EventsFileFormat::Json,
+                service_result: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                expected_content_type: None,
+            },
+            TestCase {
+                description: "When there are no events, I should still get an empty export",
+                format: EventsFileFormat::Json,
+                service_result: Err(GetEventsRepositoryError::EventsNotFound),
+                expected_status_code: StatusCode::OK,
+                expected_content_type: Some("application/json"),
+            },
+            TestCase {
+                description: "When CSV is requested, I should get the events as a CSV attachment",
+                format: EventsFileFormat::Csv,
+                service_result: Ok(Vec::new()),
+                expected_status_code: StatusCode::OK,
+                expected_content_type: Some("text/csv; charset=utf-8"),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRetrievalService::new();
+            let service_result = test_case
+                .service_result
+                .map_err(GetEventsServiceError::UnableToGetEventsFromRepository);
+            mock_service
+                .expect_get_events()
+                .return_once(move || service_result);
+            let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response = export_events(
+                State(app_state),
+                Claims::default(),
+                Query(ExportEventsRequest {
+                    format: test_case.format,
+                }),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+            assert_eq!(
+                test_case.expected_content_type,
+                actual_response
+                    .headers()
+                    .get(header::CONTENT_TYPE)
+                    .map(|value| value.to_str().unwrap())
+            );
+        }
+    }
+}
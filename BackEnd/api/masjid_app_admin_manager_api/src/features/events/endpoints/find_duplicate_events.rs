@@ -0,0 +1,106 @@
+use crate::features::events::models::find_duplicate_events_request::FindDuplicateEventsRequest;
+use crate::features::events::services::errors::event_duplicate_detection_error::EventDuplicateDetectionError;
+use crate::features::events::services::event_duplicate_detection_service::EventDuplicateDetectionService;
+use crate::shared::jwt::Claims;
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+/// Lists existing events similar to one about to be published, so that administrators can check
+/// for duplicates beforehand
+pub async fn find_duplicate_events(
+    State(state): State<ServiceAppState<Arc<dyn EventDuplicateDetectionService>>>,
+    _claims: Claims,
+    Query(request): Query<FindDuplicateEventsRequest>,
+) -> Response {
+    if request.title.trim().is_empty() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.service.find_similar_events(request).await {
+        Ok(similar_events) => (StatusCode::OK, Json(similar_events)).into_response(),
+        Err(EventDuplicateDetectionError::UnableToGetEvents) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::similar_event::SimilarEvent;
+    use crate::features::events::services::event_duplicate_detection_service::MockEventDuplicateDetectionService;
+
+    fn request(title: &str) -> FindDuplicateEventsRequest {
+        FindDuplicateEventsRequest {
+            id: 0,
+            title: title.to_owned(),
+            date: "2026-03-04T19:00:00Z".parse().unwrap(),
+            room_id: Some(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_duplicate_events() {
+        struct TestCase {
+            description: &'static str,
+            request: FindDuplicateEventsRequest,
+            service_result: Option<Result<Vec<SimilarEvent>, EventDuplicateDetectionError>>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the title is blank, I should get a BAD_REQUEST response",
+                request: request("  "),
+                service_result: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the events cannot be retrieved, I should get an INTERNAL_SERVER_ERROR response",
+                request: request("Weekly Tafsir Circle"),
+                service_result: Some(Err(EventDuplicateDetectionError::UnableToGetEvents)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When there are no similar events, I should get an OK response",
+                request: request("Weekly Tafsir Circle"),
+                service_result: Some(Ok(Vec::new())),
+                expected_status_code: StatusCode::OK,
+            },
+            TestCase {
+                description: "When there are similar events, I should get an OK response",
+                request: request("Weekly Tafsir Circle"),
+                service_result: Some(Ok(vec![SimilarEvent {
+                    event_id: 2,
+                    title: "Weekly Tafsir Circle".to_owned(),
+                    room_id: Some(1),
+                    occurrence_date: "2026-03-04T19:00:00Z".parse().unwrap(),
+                    title_similarity: 1.0,
+                    is_duplicate: true,
+                }])),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventDuplicateDetectionService::new();
+            if let Some(service_result) = test_case.service_result {
+                mock_service
+                    .expect_find_similar_events()
+                    .return_once(move |_| service_result);
+            }
+            let app_state = ServiceAppState::<Arc<dyn EventDuplicateDetectionService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response = find_duplicate_events(
+                State(app_state),
+                Claims::default(),
+                Query(test_case.request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
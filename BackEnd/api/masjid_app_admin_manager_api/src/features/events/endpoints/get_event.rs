@@ -0,0 +1,153 @@
+use crate::shared::if_match::entity_tag;
+use crate::shared::jwt::Claims;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use masjid_app_api_library::features::events::services::errors::get_events_service_error::GetEventsServiceError;
+use masjid_app_api_library::features::events::services::event_retrieval_service::EventRetrievalService;
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+/// Returns the event with its version as the ETag, which must be sent back as `If-Match` when the
+/// event is updated
+pub async fn get_event(
+    State(state): State<ServiceAppState<Arc<dyn EventRetrievalService>>>,
+    _claims: Claims,
+    Path(event_id): Path<i32>,
+) -> Response {
+    let events = match state.service.get_events().await {
+        Ok(events) => events,
+        Err(GetEventsServiceError::UnableToGetEventsFromRepository(
+            GetEventsRepositoryError::EventsNotFound,
+        )) => return StatusCode::NOT_FOUND.into_response(),
+        Err(GetEventsServiceError::UnableToGetEventsFromRepository(
+            GetEventsRepositoryError::UnableToGetEvents,
+        )) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+    match events.into_iter().find(|event| event.id == event_id) {
+        Some(event) => (
+            StatusCode::OK,
+            [(header::ETAG, entity_tag(event.version))],
+            Json(event),
+        )
+            .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::services::event_retrieval_service::MockEventRetrievalService;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+
+    fn event(id: i32, version: u32) -> EventDTO {
+        EventDTO {
+            id,
+            title: "Tajweed class".to_owned(),
+            description: None,
+            date: Utc.with_ymd_and_hms(2026, 1, 5, 17, 30, 0).unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "John Smith".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_event() {
+        struct TestCase {
+            description: &'static str,
+            event_id: i32,
+            service_result: Result<Vec<EventDTO>, GetEventsRepositoryError>,
+            expected_status_code: StatusCode,
+            expected_etag: Option<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the events cannot be retrieved, I should get an INTERNAL_SERVER_ERROR response",
+                event_id: 1,
+                service_result: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                expected_etag: None,
+            },
+            TestCase {
+                description: "When there are no events, I should get a NOT_FOUND response",
+                event_id: 1,
+                service_result: Err(GetEventsRepositoryError::EventsNotFound),
+                expected_status_code: StatusCode::NOT_FOUND,
+                expected_etag: None,
+            },
+            TestCase {
+                description: "When the event does not exist, I should get a NOT_FOUND response",
+                event_id: 1,
+                service_result: Ok(vec![event(2, 1)]),
+                expected_status_code: StatusCode::NOT_FOUND,
+                expected_etag: None,
+            },
+            TestCase {
+                description: "When the event exists, I should get it with its version as the etag",
+                event_id: 2,
+                service_result: Ok(vec![event(1, 1), event(2, 4)]),
+                expected_status_code: StatusCode::OK,
+                expected_etag: Some("\"4\""),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRetrievalService::new();
+            let service_result = test_case
+                .service_result
+                .map_err(GetEventsServiceError::UnableToGetEventsFromRepository);
+            mock_service
+                .expect_get_events()
+                .return_once(move || service_result);
+            let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response = get_event(
+                State(app_state),
+                Claims::default(),
+                Path(test_case.event_id),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+            assert_eq!(
+                test_case.expected_etag,
+                actual_response
+                    .headers()
+                    .get(header::ETAG)
+                    .map(|value| value.to_str().unwrap())
+            );
+        }
+    }
+}
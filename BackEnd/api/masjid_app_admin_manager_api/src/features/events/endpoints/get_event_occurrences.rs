@@ -0,0 +1,15 @@
+use axum::extract::{Query, State};
+use axum::response::Response;
+use masjid_app_api_library::features::events::endpoints::get_event_occurrences::get_event_occurrences_common;
+use masjid_app_api_library::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use masjid_app_api_library::features::events::models::get_event_occurrences_request::GetEventOccurrencesRequest;
+use masjid_app_api_library::features::events::services::event_retrieval_service::EventRetrievalService;
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn get_event_occurrences(
+    State(state): State<ServiceAppState<Arc<dyn EventRetrievalService>>>,
+    query: Query<GetEventOccurrencesRequest>,
+) -> Response {
+    get_event_occurrences_common::<_, EventOccurrenceDTO>(State(state), query).await
+}
@@ -0,0 +1,250 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::models::events_file_format::EventsFileFormat;
+use crate::features::events::models::import_events_request::ImportEventsRequest;
+use crate::features::events::services::errors::event_import_error::EventImportError;
+use crate::features::events::services::event_import_service::EventImportService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub async fn import_events(
+    State(state): State<AuditedServiceAppState<Arc<dyn EventImportService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Query(request): Query<ImportEventsRequest>,
+    headers: HeaderMap,
+    file: String,
+) -> Response {
+    let Some(format) = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(EventsFileFormat::from_content_type)
+    else {
+        return StatusCode::UNSUPPORTED_MEDIA_TYPE.into_response();
+    };
+
+    let mut before_snapshots = HashMap::new();
+    if !request.dry_run {
+        for event_id in state.service.find_updated_event_ids(format, &file).await {
+            let before = state
+                .audit_log
+                .take_snapshot(AuditEntityType::Event, Some(event_id))
+                .await;
+            before_snapshots.insert(event_id, before);
+        }
+    }
+    match state
+        .service
+        .import_events(format, file, request.dry_run)
+        .await
+    {
+        Ok(report) if !report.errors.is_empty() => {
+            (StatusCode::UNPROCESSABLE_ENTITY, Json(report)).into_response()
+        }
+        Ok(report) => {
+            for event_id in &report.event_ids {
+                let before = before_snapshots.remove(event_id);
+                let after = state
+                    .audit_log
+                    .take_snapshot(AuditEntityType::Event, Some(*event_id))
+                    .await;
+                state
+                    .audit_log
+                    .record_change(AuditedChange {
+                        actor: claims.sub.clone(),
+                        action: if before.is_some() {
+                            AuditAction::Update
+                        } else {
+                            AuditAction::Create
+                        },
+                        entity_type: AuditEntityType::Event,
+                        entity_id: Some(*event_id),
+                        before: before.flatten(),
+                        after,
+                        request_id: request_id.clone(),
+                    })
+                    .await;
+            }
+            (StatusCode::OK, Json(report)).into_response()
+        }
+        Err(EventImportError::InvalidFile(message)) => {
+            (StatusCode::BAD_REQUEST, message).into_response()
+        }
+        Err(EventImportError::UnableToValidateEvents)
+        | Err(EventImportError::UnableToImportEvents) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::{MockAuditLogService, new_test_audit_log_service};
+    use crate::features::events::models::event_import_report::EventImportReport;
+    use crate::features::events::models::event_import_row_error::EventImportRowError;
+    use crate::features::events::services::event_import_service::MockEventImportService;
+    use axum::http::HeaderValue;
+
+    #[tokio::test]
+    async fn test_import_events() {
+        struct TestCase {
+            description: &'static str,
+            content_type: &'static str,
+            expected_format: EventsFileFormat,
+            expected_service_result: Option<Result<EventImportReport, EventImportError>>,
+            expected_status_code: StatusCode,
+        }
+        let report = EventImportReport {
+            dry_run: false,
+            imported: 0,
+            event_ids: Vec::new(),
+            errors: Vec::new(),
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the file is neither CSV nor JSON, I should get an UNSUPPORTED_MEDIA_TYPE response",
+                content_type: "application/xml",
+                expected_format: EventsFileFormat::Json,
+                expected_service_result: None,
+                expected_status_code: StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            },
+            TestCase {
+                description: "When the file cannot be read, I should get a BAD_REQUEST response",
+                content_type: "text/csv",
+                expected_format: EventsFileFormat::Csv,
+                expected_service_result: Some(Err(EventImportError::InvalidFile(
+                    "the file is empty".to_owned(),
+                ))),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When some of the events have errors, I should get an UNPROCESSABLE_ENTITY response",
+                content_type: "application/json",
+                expected_format: EventsFileFormat::Json,
+                expected_service_result: Some(Ok(EventImportReport {
+                    errors: vec![EventImportRowError {
+                        row: 1,
+                        errors: vec!["event 7 does not exist".to_owned()],
+                    }],
+                    ..report.clone()
+                })),
+                expected_status_code: StatusCode::UNPROCESSABLE_ENTITY,
+            },
+            TestCase {
+                description: "When importing fails, I should get an INTERNAL_SERVER_ERROR response",
+                content_type: "application/json",
+                expected_format: EventsFileFormat::Json,
+                expected_service_result: Some(Err(EventImportError::UnableToImportEvents)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When every event is imported, I should get an OK response",
+                content_type: "text/csv; charset=utf-8",
+                expected_format: EventsFileFormat::Csv,
+                expected_service_result: Some(Ok(EventImportReport {
+                    imported: 2,
+                    event_ids: vec![1, 2],
+                    ..report
+                })),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventImportService::new();
+            if let Some(expected_service_result) = test_case.expected_service_result {
+                let expected_format = test_case.expected_format;
+                mock_service
+                    .expect_import_events()
+                    .withf(move |format, _, dry_run| *format == expected_format && !*dry_run)
+                    .return_once(move |_, _, _| expected_service_result);
+            }
+            mock_service
+                .expect_find_updated_event_ids()
+                .returning(|_, _| Vec::new());
+            let app_state = AuditedServiceAppState::<Arc<dyn EventImportService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(test_case.content_type),
+            );
+            let actual_response = import_events(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                Query(ImportEventsRequest::default()),
+                headers,
+                String::new(),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_events_audits_each_saved_event() {
+        let mut mock_service = MockEventImportService::new();
+        mock_service
+            .expect_find_updated_event_ids()
+            .return_once(|_, _| vec![1]);
+        mock_service.expect_import_events().return_once(|_, _, _| {
+            Ok(EventImportReport {
+                dry_run: false,
+                imported: 2,
+                event_ids: vec![1, 2],
+                errors: Vec::new(),
+            })
+        });
+        let mut mock_audit_log = MockAuditLogService::new();
+        mock_audit_log
+            .expect_take_snapshot()
+            .returning(|_, event_id| Some(serde_json::json!({ "id": event_id })));
+        mock_audit_log
+            .expect_record_change()
+            .withf(|change| {
+                change.action == AuditAction::Update
+                    && change.entity_id == Some(1)
+                    && change.before == Some(serde_json::json!({ "id": 1 }))
+            })
+            .times(1)
+            .returning(|_| ());
+        mock_audit_log
+            .expect_record_change()
+            .withf(|change| {
+                change.action == AuditAction::Create
+                    && change.entity_id == Some(2)
+                    && change.before.is_none()
+                    && change.after == Some(serde_json::json!({ "id": 2 }))
+            })
+            .times(1)
+            .returning(|_| ());
+        let app_state = AuditedServiceAppState::<Arc<dyn EventImportService>> {
+            service: Arc::new(mock_service),
+            audit_log: Arc::new(mock_audit_log),
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/csv"));
+        let actual_response = import_events(
+            State(app_state),
+            Claims::default(),
+            RequestId::default(),
+            Query(ImportEventsRequest::default()),
+            headers,
+            String::new(),
+        )
+        .await;
+        assert_eq!(StatusCode::OK, actual_response.status());
+    }
+}
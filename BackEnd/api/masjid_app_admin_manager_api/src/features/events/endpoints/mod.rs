@@ -1,3 +1,20 @@
+pub mod delete_category;
 pub mod delete_event;
+pub mod delete_event_exception;
+pub mod delete_room;
+pub mod delete_speaker;
+pub mod export_events;
+pub mod find_duplicate_events;
+pub mod get_categories;
+pub mod get_event;
+pub mod get_event_occurrences;
 pub mod get_events;
+pub mod get_rooms;
+pub mod get_speakers;
+pub mod get_tags;
+pub mod import_events;
 pub mod publish_event;
+pub mod upsert_category;
+pub mod upsert_event_exception;
+pub mod upsert_room;
+pub mod upsert_speaker;
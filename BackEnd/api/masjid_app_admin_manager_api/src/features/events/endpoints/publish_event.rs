@@ -1,63 +1,151 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::models::published_event::PublishedEvent;
 use crate::features::events::repositories::errors::insert_event_error::InsertEventError;
 use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
 use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
 use crate::features::events::services::errors::event_publishing_error::EventPublishingError;
 use crate::features::events::services::event_publishing_service::EventPublishingService;
+use crate::shared::audited_app_state::AuditedFileHandlingServiceAppState;
+use crate::shared::if_match::{IfMatch, entity_tag};
 use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::Json;
 use axum::extract::State;
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
 use axum::response::{IntoResponse, Response};
 use masjid_app_api_library::features::events::models::event_dto::EventDTO;
 use masjid_app_api_library::shared::extractors::file_handler::FileHandler;
+use masjid_app_api_library::shared::extractors::file_handler::image_uploader::ImageUploadError;
 use masjid_app_api_library::shared::extractors::request_validator::multipart::ValidatedMultipartRequest;
-use masjid_app_api_library::shared::types::app_state::ServiceAppState;
 use std::sync::Arc;
 use validator::Validate;
 
 pub async fn upsert_events(
-    State(state): State<ServiceAppState<Arc<dyn EventPublishingService>>>,
-    file_uploader: FileHandler,
+    State(state): State<AuditedFileHandlingServiceAppState<Arc<dyn EventPublishingService>>>,
+    file_handler: FileHandler,
     claims: Claims,
+    IfMatch(version): IfMatch,
+    RequestId(request_id): RequestId,
     mut request: ValidatedMultipartRequest<EventDTO>,
 ) -> Response {
     if request.json.validate().is_err() {
         return StatusCode::BAD_REQUEST.into_response();
     }
+    // Updates must say which version they change, whereas new events have no version yet
+    if request.json.id != 0 {
+        let Some(version) = version else {
+            return StatusCode::PRECONDITION_REQUIRED.into_response();
+        };
+        request.json.version = version;
+    }
 
-    /* match file_uploader
-        .save_file(&request.file_data, request.filename)
-        .await
+    let mut uploaded_image_url: Option<String> = None;
+    if let (Some(file_data), Some(filename)) = (&request.file_data, &request.filename)
+        && !filename.is_empty()
     {
-        Ok(url) => {
-            request.json.event_details.image_url = Some(url);
-        }
-        Err(err) => {
-            match err {
-                //If no file was uploaded, ignore
-                UploadError::NoFileName => {}
-                UploadError::EmptyFile | UploadError::InvalidFileName => {
-                    return (StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response();
-                }
-                UploadError::UnsupportedFileType(file_type) => {
-                    return (StatusCode::UNSUPPORTED_MEDIA_TYPE, file_type).into_response();
-                }
-                UploadError::SystemIOError => {
-                    return StatusCode::INTERNAL_SERVER_ERROR.into_response();
-                }
-            };
+        match file_handler.save_image(file_data).await {
+            Ok(url) => {
+                request.json.event_details.image_url = Some(url.clone());
+                uploaded_image_url = Some(url);
+            }
+            Err(err) => {
+                return match err {
+                    ImageUploadError::EmptyFile | ImageUploadError::UndecodableImage => {
+                        (StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response()
+                    }
+                    ImageUploadError::UnsupportedImageFormat => {
+                        (StatusCode::UNSUPPORTED_MEDIA_TYPE, err.to_string()).into_response()
+                    }
+                    ImageUploadError::FileTooLarge => {
+                        (StatusCode::PAYLOAD_TOO_LARGE, err.to_string()).into_response()
+                    }
+                    ImageUploadError::StorageQuotaExceeded => {
+                        (StatusCode::INSUFFICIENT_STORAGE, err.to_string()).into_response()
+                    }
+                    ImageUploadError::SystemIOError => {
+                        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+                    }
+                };
+            }
         }
-    };*/
+    }
 
-    match state.service.publish_event(request.json).await {
-        Ok(()) => StatusCode::OK.into_response(),
-        Err(EventPublishingError::RepositoryError(UpsertEventError::InsertError(
-            InsertEventError::EventAlreadyExists,
-        ))) => StatusCode::CONFLICT.into_response(),
+    let event_id = request.json.id;
+    let before = if event_id == 0 {
+        None
+    } else {
+        state
+            .audit_log
+            .take_snapshot(AuditEntityType::Event, Some(event_id))
+            .await
+    };
+    let publish_result = state.service.publish_event(request.json).await;
+    // Whichever image the event no longer references is now orphaned
+    let orphaned_image_url = match &publish_result {
+        Ok(published_event) => published_event.replaced_image_url.clone(),
+        Err(_) => uploaded_image_url,
+    };
+    if let Some(orphaned_image_url) = orphaned_image_url
+        && !state.service.is_image_referenced(&orphaned_image_url).await
+        && let Err(err) = file_handler.delete_image_by_url(&orphaned_image_url).await
+    {
+        tracing::error!(error = ?err, url = %orphaned_image_url, "unable to delete orphaned event image");
+    }
 
+    match publish_result {
+        Ok(PublishedEvent { saved_event, .. }) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::Event, Some(saved_event.id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: if event_id == 0 {
+                        AuditAction::Create
+                    } else {
+                        AuditAction::Update
+                    },
+                    entity_type: AuditEntityType::Event,
+                    entity_id: Some(saved_event.id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            (
+                StatusCode::OK,
+                [(header::ETAG, entity_tag(saved_event.version))],
+            )
+                .into_response()
+        }
+        Err(EventPublishingError::RepositoryError(UpsertEventError::EventAlreadyExists)) => {
+            StatusCode::CONFLICT.into_response()
+        }
         Err(EventPublishingError::RepositoryError(UpsertEventError::UpdateError(
             UpdateEventError::EventNotFound,
         ))) => StatusCode::NOT_FOUND.into_response(),
+        Err(EventPublishingError::RepositoryError(UpsertEventError::UpdateError(
+            UpdateEventError::VersionMismatch,
+        ))) => StatusCode::PRECONDITION_FAILED.into_response(),
+        Err(EventPublishingError::RoomConflict(conflict))
+        | Err(EventPublishingError::RepositoryError(UpsertEventError::RoomConflict(conflict))) => {
+            (StatusCode::CONFLICT, Json(conflict)).into_response()
+        }
+        Err(EventPublishingError::InvalidStatusTransition)
+        | Err(EventPublishingError::RepositoryError(UpsertEventError::RoomNotFound))
+        | Err(EventPublishingError::RepositoryError(UpsertEventError::CategoryNotFound))
+        | Err(EventPublishingError::RepositoryError(UpsertEventError::SpeakerNotFound)) => {
+            StatusCode::UNPROCESSABLE_ENTITY.into_response()
+        }
         Err(EventPublishingError::UnableToSaveImage)
+        | Err(EventPublishingError::UnableToCheckRoomAvailability)
+        | Err(EventPublishingError::RepositoryError(
+            UpsertEventError::UnableToCheckRoomAvailability,
+        ))
         | Err(EventPublishingError::RepositoryError(UpsertEventError::InsertError(
             InsertEventError::UnableToInsertEvent,
         )))
@@ -73,21 +161,36 @@ pub async fn upsert_events(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::audit_log::services::MockAuditLogService;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::models::booking_conflict::BookingConflict;
+    use crate::features::events::models::saved_event::SavedEvent;
     use crate::features::events::services::event_publishing_service::MockEventPublishingService;
     use axum::body::Bytes;
     use masjid_app_api_library::features::events::models::event_details::EventDetails;
     use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
     use masjid_app_api_library::features::events::models::event_status::EventStatus;
     use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::shared::extractors::file_handler::UPLOADS_ENDPOINT;
+    use masjid_app_api_library::shared::services::file_storage::providers::FileStorageProvider;
+    use masjid_app_api_library::shared::services::file_storage::settings::UploadLimits;
+    use masjid_app_api_library::shared::services::file_storage::r#trait::new_file_storage;
     use masjid_app_api_library::shared::types::age_range::AgeRange;
     use masjid_app_api_library::shared::types::contact_details::ContactDetails;
-    fn get_valid_upsert_request(include_file: bool) -> ValidatedMultipartRequest<EventDTO> {
-        let mut file_data = Bytes::default();
-        let mut filename = String::default();
-        if include_file {
-            file_data = Bytes::from("test data");
-            filename = "test_file_name.txt".to_owned();
-        }
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+    // A white 1x1 pixel png
+    const TEST_PNG: [u8; 69] = [
+        0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00, 0x90,
+        0x77, 0x53, 0xDE, 0x00, 0x00, 0x00, 0x0C, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0xF8,
+        0xFF, 0xFF, 0x3F, 0x00, 0x05, 0xFE, 0x02, 0xFE, 0x0D, 0xEF, 0x46, 0xB8, 0x00, 0x00, 0x00,
+        0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+    ];
+    fn get_valid_upsert_request(
+        filename: &str,
+        file_data: &'static [u8],
+    ) -> ValidatedMultipartRequest<EventDTO> {
+        let file_data = Bytes::from_static(file_data);
         ValidatedMultipartRequest {
             json: EventDTO {
                 id: 0,
@@ -97,34 +200,72 @@ mod tests {
                 event_details: EventDetails {
                     event_type: EventType::Talk,
                     event_recurrence: EventRecurrence::OneOff,
+                    recurrence_end: None,
                     event_status: EventStatus::Confirmed,
+                    publish_at: None,
                     age_range: Some(AgeRange {
                         minimum_age: 13,
                         maximum_age: 16,
                     }),
+                    capacity: None,
+                    room_id: None,
+                    duration_minutes: None,
+                    speaker_ids: Vec::new(),
+                    category_id: None,
+                    tags: Vec::new(),
                     image_url: None,
+                    image_variants: None,
                     contact_details: ContactDetails {
                         full_name: "John Smith".to_owned(),
                         title: None,
                         phone_number: "07787395729".to_owned(),
                         email: Some("johnsmith@test.com".to_owned()),
+                        visibility: ContactVisibility::default(),
                     },
                 },
+                version: 0,
             },
             file_data: Some(file_data),
-            filename: Some(filename),
+            filename: Some(filename.to_owned()),
         }
     }
+    fn get_published_event(
+        id: i32,
+        version: u32,
+        replaced_image_url: Option<&str>,
+    ) -> PublishedEvent {
+        PublishedEvent {
+            saved_event: SavedEvent { id, version },
+            replaced_image_url: replaced_image_url.map(str::to_owned),
+        }
+    }
+    fn get_test_upload_directory() -> std::path::PathBuf {
+        std::env::temp_dir().join("publish_event_tests")
+    }
+    fn get_test_file_handler() -> FileHandler {
+        FileHandler::new(
+            new_file_storage(FileStorageProvider::Local {
+                directory: get_test_upload_directory(),
+            }),
+            UploadLimits {
+                maximum_image_size: 1024,
+                ..UploadLimits::default()
+            },
+            UPLOADS_ENDPOINT.to_owned(),
+        )
+    }
 
     #[tokio::test]
     async fn test_upsert_event() {
         struct TestCase {
             description: &'static str,
             request: ValidatedMultipartRequest<EventDTO>,
-            file_uploader: FileHandler,
-            expected_service_response: Option<Result<(), EventPublishingError>>,
+            expected_service_response: Option<Result<PublishedEvent, EventPublishingError>>,
             expected_status: StatusCode,
         }
+        let replaced_image_path = get_test_upload_directory().join("replaced.png");
+        std::fs::create_dir_all(get_test_upload_directory()).unwrap();
+        std::fs::write(&replaced_image_path, "test data").unwrap();
         let test_cases = [
             TestCase {
                 description: "Given the request json is invalid, I should get a bad request",
@@ -137,38 +278,124 @@ mod tests {
                         event_details: EventDetails {
                             event_type: EventType::Talk,
                             event_recurrence: EventRecurrence::OneOff,
+                            recurrence_end: None,
                             event_status: EventStatus::Cancelled,
+                            publish_at: None,
                             age_range: None,
+                            capacity: None,
+                            room_id: None,
+                            duration_minutes: None,
+                            speaker_ids: Vec::new(),
+                            category_id: None,
+                            tags: Vec::new(),
                             image_url: None,
+                            image_variants: None,
                             contact_details: ContactDetails {
                                 full_name: "".to_owned(),
                                 title: None,
                                 phone_number: "".to_owned(),
                                 email: None,
+                                visibility: ContactVisibility::default(),
                             },
                         },
+                        version: 0,
                     },
                     file_data: None,
                     filename: None,
                 },
-                file_uploader: FileHandler::default(),
                 expected_service_response: None,
                 expected_status: StatusCode::BAD_REQUEST,
             },
             TestCase {
                 description: "Given the json is valid, but event publishing fails, I should get an internal server error",
-                request: get_valid_upsert_request(false),
-                file_uploader: FileHandler::default(),
+                request: get_valid_upsert_request("", &[]),
                 expected_service_response: Some(Err(EventPublishingError::RepositoryError(
                     UpsertEventError::UnableToUpsertEvent,
                 ))),
                 expected_status: StatusCode::INTERNAL_SERVER_ERROR,
             },
+            TestCase {
+                description: "Given the event cannot move to the requested status, I should get an unprocessable entity",
+                request: get_valid_upsert_request("", &[]),
+                expected_service_response: Some(Err(EventPublishingError::InvalidStatusTransition)),
+                expected_status: StatusCode::UNPROCESSABLE_ENTITY,
+            },
+            TestCase {
+                description: "Given an event with the same title already starts on that day in the same room, I should get a conflict",
+                request: get_valid_upsert_request("", &[]),
+                expected_service_response: Some(Err(EventPublishingError::RepositoryError(
+                    UpsertEventError::EventAlreadyExists,
+                ))),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "Given the room is already booked at the same time, I should get a conflict",
+                request: get_valid_upsert_request("", &[]),
+                expected_service_response: Some(Err(EventPublishingError::RoomConflict(
+                    BookingConflict {
+                        event_id: 2,
+                        title: "Weekly halaqa".to_owned(),
+                        room_id: 1,
+                        starts_at: "2025-01-13T19:00:00Z".parse().unwrap(),
+                        ends_at: "2025-01-13T20:30:00Z".parse().unwrap(),
+                    },
+                ))),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "Given the repository finds the room already booked at the same time, I should get a conflict",
+                request: get_valid_upsert_request("", &[]),
+                expected_service_response: Some(Err(EventPublishingError::RepositoryError(
+                    UpsertEventError::RoomConflict(BookingConflict {
+                        event_id: 2,
+                        title: "Weekly halaqa".to_owned(),
+                        room_id: 1,
+                        starts_at: "2025-01-13T19:00:00Z".parse().unwrap(),
+                        ends_at: "2025-01-13T20:30:00Z".parse().unwrap(),
+                    }),
+                ))),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "Given the event references a speaker that does not exist, I should get an unprocessable entity",
+                request: get_valid_upsert_request("", &[]),
+                expected_service_response: Some(Err(EventPublishingError::RepositoryError(
+                    UpsertEventError::SpeakerNotFound,
+                ))),
+                expected_status: StatusCode::UNPROCESSABLE_ENTITY,
+            },
             TestCase {
                 description: "Given the json is valid and upsertion succeeds, I should get an ok response",
-                request: get_valid_upsert_request(false),
-                file_uploader: FileHandler::default(),
-                expected_service_response: Some(Ok(())),
+                request: get_valid_upsert_request("", &[]),
+                expected_service_response: Some(Ok(get_published_event(3, 1, None))),
+                expected_status: StatusCode::OK,
+            },
+            TestCase {
+                description: "Given the uploaded file is not an image, I should get an unsupported media type",
+                request: get_valid_upsert_request("poster.png", b"name,age"),
+                expected_service_response: None,
+                expected_status: StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            },
+            TestCase {
+                description: "Given the uploaded image exceeds the size limit, I should get a payload too large",
+                request: get_valid_upsert_request("poster.png", &[0xFF; 1025]),
+                expected_service_response: None,
+                expected_status: StatusCode::PAYLOAD_TOO_LARGE,
+            },
+            TestCase {
+                description: "Given the uploaded file is valid and upsertion succeeds, I should get an ok response",
+                request: get_valid_upsert_request("poster.png", &TEST_PNG),
+                expected_service_response: Some(Ok(get_published_event(3, 1, None))),
+                expected_status: StatusCode::OK,
+            },
+            TestCase {
+                description: "Given the event image is replaced, I should get an ok response",
+                request: get_valid_upsert_request("poster.png", &TEST_PNG),
+                expected_service_response: Some(Ok(get_published_event(
+                    1,
+                    2,
+                    Some("/uploads/replaced.png"),
+                ))),
                 expected_status: StatusCode::OK,
             },
         ];
@@ -180,17 +407,201 @@ mod tests {
                     .expect_publish_event()
                     .return_once(move |_| mock_response);
             }
-            let app_state = ServiceAppState::<Arc<dyn EventPublishingService>> {
+            mock_service
+                .expect_is_image_referenced()
+                .returning(|_| false);
+            let app_state = AuditedFileHandlingServiceAppState::<Arc<dyn EventPublishingService>> {
                 service: Arc::new(mock_service),
+                file_handler: get_test_file_handler(),
+                audit_log: new_test_audit_log_service(),
             };
             let actual_response = upsert_events(
                 State(app_state),
-                test_case.file_uploader,
+                get_test_file_handler(),
                 Claims::default(),
+                IfMatch::default(),
+                RequestId::default(),
                 test_case.request,
             )
             .await;
             assert_eq!(test_case.expected_status, actual_response.status());
         }
+        assert!(!replaced_image_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_upsert_event_versioning() {
+        struct TestCase {
+            description: &'static str,
+            id: i32,
+            if_match: IfMatch,
+            expected_service_response: Option<Result<PublishedEvent, EventPublishingError>>,
+            expected_version: Option<u32>,
+            expected_status: StatusCode,
+            expected_etag: Option<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "Given a new event is created, I should get the first version as its etag",
+                id: 0,
+                if_match: IfMatch::default(),
+                expected_service_response: Some(Ok(get_published_event(3, 1, None))),
+                expected_version: Some(0),
+                expected_status: StatusCode::OK,
+                expected_etag: Some("\"1\""),
+            },
+            TestCase {
+                description: "Given an event is updated without saying which version it changes, I should get a precondition required",
+                id: 5,
+                if_match: IfMatch::default(),
+                expected_service_response: None,
+                expected_version: None,
+                expected_status: StatusCode::PRECONDITION_REQUIRED,
+                expected_etag: None,
+            },
+            TestCase {
+                description: "Given the event has been changed since the version being updated, I should get a precondition failed",
+                id: 5,
+                if_match: IfMatch(Some(3)),
+                expected_service_response: Some(Err(EventPublishingError::RepositoryError(
+                    UpsertEventError::UpdateError(UpdateEventError::VersionMismatch),
+                ))),
+                expected_version: Some(3),
+                expected_status: StatusCode::PRECONDITION_FAILED,
+                expected_etag: None,
+            },
+            TestCase {
+                description: "Given the event is updated from its current version, I should get the next version as its etag",
+                id: 5,
+                if_match: IfMatch(Some(3)),
+                expected_service_response: Some(Ok(get_published_event(5, 4, None))),
+                expected_version: Some(3),
+                expected_status: StatusCode::OK,
+                expected_etag: Some("\"4\""),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventPublishingService::new();
+            if let (Some(mock_response), Some(expected_version)) = (
+                test_case.expected_service_response,
+                test_case.expected_version,
+            ) {
+                mock_service
+                    .expect_publish_event()
+                    .withf(move |event| event.version == expected_version)
+                    .return_once(move |_| mock_response);
+            }
+            mock_service
+                .expect_is_image_referenced()
+                .returning(|_| false);
+            let app_state = AuditedFileHandlingServiceAppState::<Arc<dyn EventPublishingService>> {
+                service: Arc::new(mock_service),
+                file_handler: get_test_file_handler(),
+                audit_log: new_test_audit_log_service(),
+            };
+            let mut request = get_valid_upsert_request("", &[]);
+            request.json.id = test_case.id;
+            let actual_response = upsert_events(
+                State(app_state),
+                get_test_file_handler(),
+                Claims::default(),
+                test_case.if_match,
+                RequestId::default(),
+                request,
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+            assert_eq!(
+                test_case.expected_etag,
+                actual_response
+                    .headers()
+                    .get(header::ETAG)
+                    .and_then(|etag| etag.to_str().ok())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upsert_event_keeps_shared_image_when_publishing_fails() {
+        let file_handler = FileHandler::new(
+            new_file_storage(FileStorageProvider::Local {
+                directory: std::env::temp_dir().join("publish_event_shared_image_tests"),
+            }),
+            UploadLimits::default(),
+            UPLOADS_ENDPOINT.to_owned(),
+        );
+        // Another event already uses the same image, which the upload is stored alongside
+        let shared_image_url = file_handler.save_image(&TEST_PNG).await.unwrap();
+        let mut mock_service = MockEventPublishingService::new();
+        mock_service.expect_publish_event().return_once(|_| {
+            Err(EventPublishingError::RepositoryError(
+                UpsertEventError::EventAlreadyExists,
+            ))
+        });
+        let expected_image_url = shared_image_url.clone();
+        mock_service
+            .expect_is_image_referenced()
+            .withf(move |image_url| image_url == expected_image_url)
+            .times(1)
+            .returning(|_| true);
+        let app_state = AuditedFileHandlingServiceAppState::<Arc<dyn EventPublishingService>> {
+            service: Arc::new(mock_service),
+            file_handler: file_handler.clone(),
+            audit_log: new_test_audit_log_service(),
+        };
+        let actual_response = upsert_events(
+            State(app_state),
+            file_handler.clone(),
+            Claims::default(),
+            IfMatch::default(),
+            RequestId::default(),
+            get_valid_upsert_request("poster.png", &TEST_PNG),
+        )
+        .await;
+        assert_eq!(StatusCode::CONFLICT, actual_response.status());
+        let shared_image_key = shared_image_url.rsplit('/').next().unwrap();
+        assert!(file_handler.get_file(shared_image_key).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_upsert_event_audits_created_event_by_its_saved_id() {
+        let mut mock_service = MockEventPublishingService::new();
+        mock_service
+            .expect_publish_event()
+            .return_once(|_| Ok(get_published_event(3, 1, None)));
+        let mut mock_audit_log = MockAuditLogService::new();
+        mock_audit_log
+            .expect_take_snapshot()
+            .withf(|entity_type, entity_id| {
+                *entity_type == AuditEntityType::Event && *entity_id == Some(3)
+            })
+            .times(1)
+            .returning(|_, _| Some(serde_json::json!({"id": 3, "version": 1})));
+        mock_audit_log
+            .expect_record_change()
+            .withf(|change| {
+                change.action == AuditAction::Create
+                    && change.entity_id == Some(3)
+                    && change.before.is_none()
+                    && change.after == Some(serde_json::json!({"id": 3, "version": 1}))
+            })
+            .times(1)
+            .returning(|_| ());
+        let app_state = AuditedFileHandlingServiceAppState::<Arc<dyn EventPublishingService>> {
+            service: Arc::new(mock_service),
+            file_handler: get_test_file_handler(),
+            audit_log: Arc::new(mock_audit_log),
+        };
+        let actual_response = upsert_events(
+            State(app_state),
+            get_test_file_handler(),
+            Claims::default(),
+            IfMatch::default(),
+            RequestId::default(),
+            get_valid_upsert_request("", &[]),
+        )
+        .await;
+        assert_eq!(StatusCode::OK, actual_response.status());
     }
 }
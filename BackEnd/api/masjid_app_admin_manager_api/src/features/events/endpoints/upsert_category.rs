@@ -0,0 +1,127 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::category_management_service::CategoryManagementService;
+use crate::features::events::services::errors::category_management_error::CategoryManagementError;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::features::events::models::event_category::EventCategory;
+use masjid_app_api_library::shared::extractors::request_validator::json::ValidatedJsonRequest;
+use std::sync::Arc;
+
+pub async fn upsert_category(
+    State(state): State<AuditedServiceAppState<Arc<dyn CategoryManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    ValidatedJsonRequest(request): ValidatedJsonRequest<EventCategory>,
+) -> Response {
+    let category_id = request.id;
+    let before = if category_id == 0 {
+        None
+    } else {
+        state
+            .audit_log
+            .take_snapshot(AuditEntityType::EventCategory, Some(category_id))
+            .await
+    };
+    match state.service.save_category(request).await {
+        Ok(saved_category_id) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::EventCategory, Some(saved_category_id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: if category_id == 0 {
+                        AuditAction::Create
+                    } else {
+                        AuditAction::Update
+                    },
+                    entity_type: AuditEntityType::EventCategory,
+                    entity_id: Some(saved_category_id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(CategoryManagementError::CategoryNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(CategoryManagementError::CategoryAlreadyExists) => (
+            StatusCode::CONFLICT,
+            "a category with this name already exists",
+        )
+            .into_response(),
+        Err(CategoryManagementError::CategoryInUse)
+        | Err(CategoryManagementError::UnableToUpdateCategories) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::services::category_management_service::MockCategoryManagementService;
+
+    #[tokio::test]
+    async fn test_upsert_category() {
+        struct TestCase {
+            description: &'static str,
+            expected_service_response: Result<i32, CategoryManagementError>,
+            expected_status: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When another category already has the name, I should get a conflict",
+                expected_service_response: Err(CategoryManagementError::CategoryAlreadyExists),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When the category being updated does not exist, I should get a not found",
+                expected_service_response: Err(CategoryManagementError::CategoryNotFound),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When saving the category fails, I should get an internal server error",
+                expected_service_response: Err(CategoryManagementError::UnableToUpdateCategories),
+                expected_status: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When saving the category succeeds, I should get an ok response",
+                expected_service_response: Ok(1),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockCategoryManagementService::new();
+            mock_service
+                .expect_save_category()
+                .return_once(move |_| test_case.expected_service_response);
+            let app_state = AuditedServiceAppState::<Arc<dyn CategoryManagementService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let request = EventCategory {
+                id: 0,
+                name: "Youth".to_owned(),
+            };
+            let actual_response = upsert_category(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                ValidatedJsonRequest(request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::errors::event_exception_error::EventExceptionError;
+use crate::features::events::services::event_exception_service::EventExceptionService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use std::sync::Arc;
+
+pub async fn upsert_event_exception(
+    State(state): State<AuditedServiceAppState<Arc<dyn EventExceptionService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Json(request): Json<EventExceptionDTO>,
+) -> Response {
+    if request.event_id == 0 {
+        return (StatusCode::BAD_REQUEST, "event ids cannot be 0").into_response();
+    }
+
+    // Exceptions are audited together with the rest of the event's exceptions, as they have no
+    // identity of their own
+    let event_id = request.event_id;
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::EventExceptions, Some(event_id))
+        .await;
+    match state.service.save_event_exception(request).await {
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::EventExceptions, Some(event_id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::EventExceptions,
+                    entity_id: Some(event_id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(EventExceptionError::EventNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(EventExceptionError::NotAnOccurrence) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "occurrence date is not a scheduled occurrence of the event",
+        )
+            .into_response(),
+        Err(EventExceptionError::RoomConflict(conflict)) => {
+            (StatusCode::CONFLICT, Json(conflict)).into_response()
+        }
+        Err(EventExceptionError::EventExceptionNotFound)
+        | Err(EventExceptionError::UnableToCheckRoomAvailability)
+        | Err(EventExceptionError::UnableToUpdateEventExceptions) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::models::booking_conflict::BookingConflict;
+    use crate::features::events::services::event_exception_service::MockEventExceptionService;
+
+    #[tokio::test]
+    async fn test_upsert_event_exception() {
+        struct TestCase {
+            description: &'static str,
+            event_id: i32,
+            expected_service_response: Option<Result<(), EventExceptionError>>,
+            expected_status: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When I use an invalid event ID, I should get a bad request",
+                event_id: 0,
+                expected_service_response: None,
+                expected_status: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the event does not exist, I should get a not found",
+                event_id: 1,
+                expected_service_response: Some(Err(EventExceptionError::EventNotFound)),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When the date is not an occurrence of the event, I should get an unprocessable entity",
+                event_id: 1,
+                expected_service_response: Some(Err(EventExceptionError::NotAnOccurrence)),
+                expected_status: StatusCode::UNPROCESSABLE_ENTITY,
+            },
+            TestCase {
+                description: "When the occurrence is moved to when its room is already booked, I should get a conflict",
+                event_id: 1,
+                expected_service_response: Some(Err(EventExceptionError::RoomConflict(
+                    BookingConflict {
+                        event_id: 2,
+                        title: "Evening talk".to_owned(),
+                        room_id: 1,
+                        starts_at: "2025-01-14T19:00:00Z".parse().unwrap(),
+                        ends_at: "2025-01-14T20:00:00Z".parse().unwrap(),
+                    },
+                ))),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When saving the exception fails, I should get an internal server error",
+                event_id: 1,
+                expected_service_response: Some(Err(
+                    EventExceptionError::UnableToUpdateEventExceptions,
+                )),
+                expected_status: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When saving the exception succeeds, I should get an ok response",
+                event_id: 1,
+                expected_service_response: Some(Ok(())),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventExceptionService::new();
+            if let Some(mock_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_save_event_exception()
+                    .return_once(move |_| mock_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn EventExceptionService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let request = EventExceptionDTO {
+                event_id: test_case.event_id,
+                occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+                replacement_date: None,
+            };
+            let actual_response = upsert_event_exception(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                Json(request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+    }
+}
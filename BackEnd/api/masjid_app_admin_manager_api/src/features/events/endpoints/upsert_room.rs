@@ -0,0 +1,125 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::errors::room_management_error::RoomManagementError;
+use crate::features::events::services::room_management_service::RoomManagementService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::features::events::models::room::Room;
+use masjid_app_api_library::shared::extractors::request_validator::json::ValidatedJsonRequest;
+use std::sync::Arc;
+
+pub async fn upsert_room(
+    State(state): State<AuditedServiceAppState<Arc<dyn RoomManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    ValidatedJsonRequest(request): ValidatedJsonRequest<Room>,
+) -> Response {
+    let room_id = request.id;
+    let before = if room_id == 0 {
+        None
+    } else {
+        state
+            .audit_log
+            .take_snapshot(AuditEntityType::Room, Some(room_id))
+            .await
+    };
+    match state.service.save_room(request).await {
+        Ok(saved_room_id) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::Room, Some(saved_room_id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: if room_id == 0 {
+                        AuditAction::Create
+                    } else {
+                        AuditAction::Update
+                    },
+                    entity_type: AuditEntityType::Room,
+                    entity_id: Some(saved_room_id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(RoomManagementError::RoomNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(RoomManagementError::RoomAlreadyExists) => {
+            (StatusCode::CONFLICT, "a room with this name already exists").into_response()
+        }
+        Err(RoomManagementError::RoomInUse) | Err(RoomManagementError::UnableToUpdateRooms) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::services::room_management_service::MockRoomManagementService;
+
+    #[tokio::test]
+    async fn test_upsert_room() {
+        struct TestCase {
+            description: &'static str,
+            expected_service_response: Result<i32, RoomManagementError>,
+            expected_status: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When another room already has the name, I should get a conflict",
+                expected_service_response: Err(RoomManagementError::RoomAlreadyExists),
+                expected_status: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When the room being updated does not exist, I should get a not found",
+                expected_service_response: Err(RoomManagementError::RoomNotFound),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When saving the room fails, I should get an internal server error",
+                expected_service_response: Err(RoomManagementError::UnableToUpdateRooms),
+                expected_status: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When saving the room succeeds, I should get an ok response",
+                expected_service_response: Ok(1),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockRoomManagementService::new();
+            mock_service
+                .expect_save_room()
+                .return_once(move |_| test_case.expected_service_response);
+            let app_state = AuditedServiceAppState::<Arc<dyn RoomManagementService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let request = Room {
+                id: 0,
+                name: "Main hall".to_owned(),
+                capacity: Some(300),
+            };
+            let actual_response = upsert_room(
+                State(app_state),
+                Claims::default(),
+                RequestId::default(),
+                ValidatedJsonRequest(request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+    }
+}
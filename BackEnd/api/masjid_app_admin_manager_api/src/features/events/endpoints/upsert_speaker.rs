@@ -0,0 +1,239 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::events::services::errors::speaker_management_error::SpeakerManagementError;
+use crate::features::events::services::speaker_management_service::SpeakerManagementService;
+use crate::shared::audited_app_state::AuditedFileHandlingServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::features::events::models::speaker::Speaker;
+use masjid_app_api_library::shared::extractors::file_handler::FileHandler;
+use masjid_app_api_library::shared::extractors::file_handler::image_uploader::ImageUploadError;
+use masjid_app_api_library::shared::extractors::request_validator::multipart::ValidatedMultipartRequest;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn upsert_speaker(
+    State(state): State<AuditedFileHandlingServiceAppState<Arc<dyn SpeakerManagementService>>>,
+    file_handler: FileHandler,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    mut request: ValidatedMultipartRequest<Speaker>,
+) -> Response {
+    if request.json.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    let mut uploaded_photo_url: Option<String> = None;
+    if let (Some(file_data), Some(filename)) = (&request.file_data, &request.filename)
+        && !filename.is_empty()
+    {
+        match file_handler.save_image(file_data).await {
+            Ok(url) => {
+                request.json.photo_url = Some(url.clone());
+                uploaded_photo_url = Some(url);
+            }
+            Err(err) => {
+                return match err {
+                    ImageUploadError::EmptyFile | ImageUploadError::UndecodableImage => {
+                        (StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response()
+                    }
+                    ImageUploadError::UnsupportedImageFormat => {
+                        (StatusCode::UNSUPPORTED_MEDIA_TYPE, err.to_string()).into_response()
+                    }
+                    ImageUploadError::FileTooLarge => {
+                        (StatusCode::PAYLOAD_TOO_LARGE, err.to_string()).into_response()
+                    }
+                    ImageUploadError::StorageQuotaExceeded => {
+                        (StatusCode::INSUFFICIENT_STORAGE, err.to_string()).into_response()
+                    }
+                    ImageUploadError::SystemIOError => {
+                        StatusCode::INTERNAL_SERVER_ERROR.into_response()
+                    }
+                };
+            }
+        }
+    }
+
+    let speaker_id = request.json.id;
+    let before = if speaker_id == 0 {
+        None
+    } else {
+        state
+            .audit_log
+            .take_snapshot(AuditEntityType::Speaker, Some(speaker_id))
+            .await
+    };
+    let save_result = state.service.save_speaker(request.json).await;
+    // Whichever photo the speaker no longer references is now orphaned
+    let orphaned_photo_url = match &save_result {
+        Ok(saved_speaker) => saved_speaker.replaced_photo_url.clone(),
+        Err(_) => uploaded_photo_url,
+    };
+    if let Some(orphaned_photo_url) = orphaned_photo_url
+        && !state.service.is_image_referenced(&orphaned_photo_url).await
+        && let Err(err) = file_handler.delete_image_by_url(&orphaned_photo_url).await
+    {
+        tracing::error!(error = ?err, url = %orphaned_photo_url, "unable to delete orphaned speaker photo");
+    }
+
+    match save_result {
+        Ok(saved_speaker) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::Speaker, Some(saved_speaker.id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: if speaker_id == 0 {
+                        AuditAction::Create
+                    } else {
+                        AuditAction::Update
+                    },
+                    entity_type: AuditEntityType::Speaker,
+                    entity_id: Some(saved_speaker.id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(SpeakerManagementError::SpeakerNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(SpeakerManagementError::SpeakerInUse)
+        | Err(SpeakerManagementError::UnableToUpdateSpeakers) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::events::models::saved_speaker::SavedSpeaker;
+    use crate::features::events::services::speaker_management_service::MockSpeakerManagementService;
+    use axum::body::Bytes;
+    use masjid_app_api_library::shared::extractors::file_handler::UPLOADS_ENDPOINT;
+    use masjid_app_api_library::shared::services::file_storage::providers::FileStorageProvider;
+    use masjid_app_api_library::shared::services::file_storage::settings::UploadLimits;
+    use masjid_app_api_library::shared::services::file_storage::r#trait::new_file_storage;
+    fn get_upsert_request(
+        full_name: &str,
+        file_data: &'static [u8],
+    ) -> ValidatedMultipartRequest<Speaker> {
+        ValidatedMultipartRequest {
+            json: Speaker {
+                id: 0,
+                full_name: full_name.to_owned(),
+                title: Some("Shaykh".to_owned()),
+                bio: None,
+                photo_url: None,
+                languages: vec!["English".to_owned()],
+            },
+            file_data: Some(Bytes::from_static(file_data)),
+            filename: Some(
+                if file_data.is_empty() {
+                    ""
+                } else {
+                    "photo.png"
+                }
+                .to_owned(),
+            ),
+        }
+    }
+    fn get_test_upload_directory() -> std::path::PathBuf {
+        std::env::temp_dir().join("upsert_speaker_tests")
+    }
+    fn get_test_file_handler() -> FileHandler {
+        FileHandler::new(
+            new_file_storage(FileStorageProvider::Local {
+                directory: get_test_upload_directory(),
+            }),
+            UploadLimits::default(),
+            UPLOADS_ENDPOINT.to_owned(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_upsert_speaker() {
+        struct TestCase {
+            description: &'static str,
+            request: ValidatedMultipartRequest<Speaker>,
+            expected_service_response: Option<Result<SavedSpeaker, SpeakerManagementError>>,
+            expected_status: StatusCode,
+        }
+        let replaced_photo_path = get_test_upload_directory().join("replaced.png");
+        std::fs::create_dir_all(get_test_upload_directory()).unwrap();
+        std::fs::write(&replaced_photo_path, "test data").unwrap();
+        let test_cases = [
+            TestCase {
+                description: "Given the speaker has no name, I should get a bad request",
+                request: get_upsert_request("", &[]),
+                expected_service_response: None,
+                expected_status: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "Given the uploaded file is not an image, I should get an unsupported media type",
+                request: get_upsert_request("Yusuf Ahmed", b"name,age"),
+                expected_service_response: None,
+                expected_status: StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            },
+            TestCase {
+                description: "Given the speaker being updated does not exist, I should get a not found",
+                request: get_upsert_request("Yusuf Ahmed", &[]),
+                expected_service_response: Some(Err(SpeakerManagementError::SpeakerNotFound)),
+                expected_status: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "Given saving the speaker fails, I should get an internal server error",
+                request: get_upsert_request("Yusuf Ahmed", &[]),
+                expected_service_response: Some(Err(
+                    SpeakerManagementError::UnableToUpdateSpeakers,
+                )),
+                expected_status: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "Given the speaker's photo is replaced, I should get an ok response",
+                request: get_upsert_request("Yusuf Ahmed", &[]),
+                expected_service_response: Some(Ok(SavedSpeaker {
+                    id: 1,
+                    replaced_photo_url: Some("/uploads/replaced.png".to_owned()),
+                })),
+                expected_status: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockSpeakerManagementService::new();
+            if let Some(mock_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_save_speaker()
+                    .return_once(move |_| mock_response);
+            }
+            mock_service
+                .expect_is_image_referenced()
+                .returning(|_| false);
+            let app_state = AuditedFileHandlingServiceAppState::<Arc<dyn SpeakerManagementService>> {
+                service: Arc::new(mock_service),
+                file_handler: get_test_file_handler(),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = upsert_speaker(
+                State(app_state),
+                get_test_file_handler(),
+                Claims::default(),
+                RequestId::default(),
+                test_case.request,
+            )
+            .await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+        }
+        assert!(!replaced_photo_path.exists());
+    }
+}
@@ -0,0 +1,416 @@
+use crate::shared::csv::{ParseCsvError, escape_csv_field, parse_csv, unescape_csv_field};
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use serde_json::{Map, Value};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum EventCsvColumnKind {
+    Text,
+    Number,
+    /// Either true or false
+    Boolean,
+    /// Numbers separated by semicolons, so the list does not need quoting
+    NumberList,
+    /// Text separated by semicolons, such as tags, which cannot contain semicolons themselves
+    TextList,
+}
+
+/// A column of the CSV along with where its value lives in the JSON representation of an event,
+/// so that CSV rows are validated exactly like JSON events
+struct EventCsvColumn {
+    header: &'static str,
+    pointer: &'static str,
+    kind: EventCsvColumnKind,
+}
+
+const fn column(
+    header: &'static str,
+    pointer: &'static str,
+    kind: EventCsvColumnKind,
+) -> EventCsvColumn {
+    EventCsvColumn {
+        header,
+        pointer,
+        kind,
+    }
+}
+
+const EVENT_CSV_COLUMNS: [EventCsvColumn; 28] = [
+    column("id", "/id", EventCsvColumnKind::Number),
+    column("title", "/title", EventCsvColumnKind::Text),
+    column("description", "/description", EventCsvColumnKind::Text),
+    column("date", "/date", EventCsvColumnKind::Text),
+    column(
+        "eventType",
+        "/eventDetails/eventType",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "eventRecurrence",
+        "/eventDetails/eventRecurrence",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "recurrenceEndDate",
+        "/eventDetails/recurrenceEnd/endDate",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "recurrenceCount",
+        "/eventDetails/recurrenceEnd/occurrenceCount",
+        EventCsvColumnKind::Number,
+    ),
+    column(
+        "eventStatus",
+        "/eventDetails/eventStatus",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "publishAt",
+        "/eventDetails/publishAt",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "minimumAge",
+        "/eventDetails/ageRange/minimumAge",
+        EventCsvColumnKind::Number,
+    ),
+    column(
+        "maximumAge",
+        "/eventDetails/ageRange/maximumAge",
+        EventCsvColumnKind::Number,
+    ),
+    column(
+        "capacity",
+        "/eventDetails/capacity",
+        EventCsvColumnKind::Number,
+    ),
+    column("roomId", "/eventDetails/roomId", EventCsvColumnKind::Number),
+    column(
+        "durationMinutes",
+        "/eventDetails/durationMinutes",
+        EventCsvColumnKind::Number,
+    ),
+    column(
+        "speakerIds",
+        "/eventDetails/speakerIds",
+        EventCsvColumnKind::NumberList,
+    ),
+    column(
+        "categoryId",
+        "/eventDetails/categoryId",
+        EventCsvColumnKind::Number,
+    ),
+    column("tags", "/eventDetails/tags", EventCsvColumnKind::TextList),
+    column(
+        "imageUrl",
+        "/eventDetails/imageUrl",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "contactFullName",
+        "/eventDetails/contactDetails/fullName",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "contactTitle",
+        "/eventDetails/contactDetails/title",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "contactPhoneNumber",
+        "/eventDetails/contactDetails/phoneNumber",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "contactEmail",
+        "/eventDetails/contactDetails/email",
+        EventCsvColumnKind::Text,
+    ),
+    column(
+        "contactShowFullName",
+        "/eventDetails/contactDetails/visibility/fullName",
+        EventCsvColumnKind::Boolean,
+    ),
+    column(
+        "contactShowPhoneNumber",
+        "/eventDetails/contactDetails/visibility/phoneNumber",
+        EventCsvColumnKind::Boolean,
+    ),
+    column(
+        "contactShowEmail",
+        "/eventDetails/contactDetails/visibility/email",
+        EventCsvColumnKind::Boolean,
+    ),
+    column(
+        "contactFormEnabled",
+        "/eventDetails/contactDetails/visibility/contactForm",
+        EventCsvColumnKind::Boolean,
+    ),
+    column("version", "/version", EventCsvColumnKind::Number),
+];
+
+fn csv_value(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => escape_csv_field(text),
+        Some(Value::Array(values)) => values
+            .iter()
+            .map(|value| csv_value(Some(value)))
+            .collect::<Vec<String>>()
+            .join(";"),
+        Some(value) => value.to_string(),
+    }
+}
+
+pub fn events_to_csv(events: &[EventDTO]) -> String {
+    let mut csv = EVENT_CSV_COLUMNS
+        .iter()
+        .map(|column| column.header)
+        .collect::<Vec<&str>>()
+        .join(",");
+    csv.push_str("\r\n");
+    for event in events {
+        let event = serde_json::to_value(event).unwrap_or_default();
+        let fields: Vec<String> = EVENT_CSV_COLUMNS
+            .iter()
+            .map(|column| csv_value(event.pointer(column.pointer)))
+            .collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+fn parse_number(column: &EventCsvColumn, text: &str) -> Result<Value, String> {
+    text.trim()
+        .parse::<i64>()
+        .map(Value::from)
+        .map_err(|_| format!("{} must be a whole number", column.header))
+}
+
+fn parse_boolean(column: &EventCsvColumn, text: &str) -> Result<Value, String> {
+    match text.trim().to_ascii_lowercase().as_str() {
+        "true" => Ok(Value::Bool(true)),
+        "false" => Ok(Value::Bool(false)),
+        _ => Err(format!("{} must be true or false", column.header)),
+    }
+}
+
+fn insert_at_pointer(event: &mut Map<String, Value>, pointer: &str, value: Value) {
+    let mut keys = pointer.trim_start_matches('/').split('/').peekable();
+    let mut object = event;
+    while let Some(key) = keys.next() {
+        if keys.peek().is_none() {
+            object.insert(key.to_owned(), value);
+            return;
+        }
+        let child = object
+            .entry(key.to_owned())
+            .or_insert_with(|| Value::Object(Map::new()));
+        match child {
+            Value::Object(child) => object = child,
+            _ => return,
+        }
+    }
+}
+
+/// Converts a CSV row into an event, collecting every problem with the row rather than stopping
+/// at the first
+fn event_from_csv_record(
+    columns: &[&EventCsvColumn],
+    record: Vec<String>,
+) -> Result<EventDTO, Vec<String>> {
+    let mut event = Map::new();
+    event.insert("id".to_owned(), Value::from(0));
+    insert_at_pointer(
+        &mut event,
+        "/eventDetails/contactDetails",
+        Value::Object(Map::new()),
+    );
+    let mut errors: Vec<String> = Vec::new();
+    for (column, field) in columns.iter().zip(record) {
+        let field = unescape_csv_field(field);
+        if field.trim().is_empty() {
+            continue;
+        }
+        let value = match column.kind {
+            EventCsvColumnKind::Text => Ok(Value::String(field)),
+            EventCsvColumnKind::Number => parse_number(column, &field),
+            EventCsvColumnKind::Boolean => parse_boolean(column, &field),
+            EventCsvColumnKind::NumberList => field
+                .split(';')
+                .filter(|item| !item.trim().is_empty())
+                .map(|item| parse_number(column, item))
+                .collect::<Result<Vec<Value>, String>>()
+                .map(Value::Array),
+            // Each item is neutralised against formulas on export, not only the first
+            EventCsvColumnKind::TextList => Ok(Value::Array(
+                field
+                    .split(';')
+                    .filter(|item| !item.trim().is_empty())
+                    .map(|item| Value::String(unescape_csv_field(item.trim().to_owned())))
+                    .collect(),
+            )),
+        };
+        match value {
+            Ok(value) => insert_at_pointer(&mut event, column.pointer, value),
+            Err(error) => errors.push(error),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    serde_json::from_value(Value::Object(event)).map_err(|err| vec![err.to_string()])
+}
+
+/// Reads events from CSV with a header row naming the columns, which may be in any order. The
+/// outer error is for files which cannot be read at all, whilst each row has its own result
+pub fn events_from_csv(csv: &str) -> Result<Vec<Result<EventDTO, Vec<String>>>, String> {
+    let mut records = parse_csv(csv)
+        .map_err(|ParseCsvError::UnterminatedQuotedField { line }| {
+            format!("the quoted field starting on line {} is never closed", line)
+        })?
+        .into_iter();
+    let header = records.next().ok_or("the file is empty")?;
+    let columns = header
+        .iter()
+        .map(|name| {
+            EVENT_CSV_COLUMNS
+                .iter()
+                .find(|column| column.header.eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| format!("{} is not a recognised column", name))
+        })
+        .collect::<Result<Vec<&EventCsvColumn>, String>>()?;
+    Ok(records
+        .map(|record| event_from_csv_record(&columns, record))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::models::recurrence_end::RecurrenceEnd;
+    use masjid_app_api_library::shared::types::age_range::AgeRange;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+
+    fn event() -> EventDTO {
+        EventDTO {
+            id: 3,
+            title: "Tajweed class".to_owned(),
+            description: Some("Beginners, ages 8-12".to_owned()),
+            date: Utc.with_ymd_and_hms(2026, 1, 5, 17, 30, 0).unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::Weekly,
+                recurrence_end: Some(RecurrenceEnd::OccurrenceCount(12)),
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: Some(AgeRange {
+                    minimum_age: 8,
+                    maximum_age: 12,
+                }),
+                capacity: Some(20),
+                room_id: Some(2),
+                duration_minutes: Some(60),
+                speaker_ids: vec![1, 4],
+                category_id: Some(1),
+                tags: vec!["tajweed".to_owned(), "youth".to_owned()],
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "John Smith".to_owned(),
+                    title: None,
+                    phone_number: "+447123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 2,
+        }
+    }
+
+    #[test]
+    fn test_events_to_csv() {
+        assert_eq!(
+            "id,title,description,date,eventType,eventRecurrence,recurrenceEndDate,recurrenceCount,eventStatus,publishAt,minimumAge,maximumAge,capacity,roomId,durationMinutes,speakerIds,categoryId,tags,imageUrl,contactFullName,contactTitle,contactPhoneNumber,contactEmail,contactShowFullName,contactShowPhoneNumber,contactShowEmail,contactFormEnabled,version\r\n\
+            3,Tajweed class,\"Beginners, ages 8-12\",2026-01-05T17:30:00Z,class,weekly,,12,confirmed,,8,12,20,2,60,1;4,1,tajweed;youth,,John Smith,,'+447123456789,,true,false,false,false,2\r\n",
+            events_to_csv(&[event()])
+        );
+    }
+
+    #[test]
+    fn test_events_from_csv() {
+        struct TestCase {
+            description: &'static str,
+            csv: String,
+            expected_result: Result<Vec<Result<EventDTO, Vec<String>>>, String>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the file was exported, every event should be imported unchanged",
+                csv: events_to_csv(&[event()]),
+                expected_result: Ok(vec![Ok(event())]),
+            },
+            TestCase {
+                description: "When columns are left out, they should be treated as empty and new events should get an id and version of 0",
+                csv: "Title,date,eventType,eventRecurrence,eventStatus,contactFullName,contactPhoneNumber\n\
+                    Tajweed class,2026-01-05T17:30:00Z,class,weekly,confirmed,John Smith,+447123456789"
+                    .to_owned(),
+                expected_result: Ok(vec![Ok(EventDTO {
+                    id: 0,
+                    description: None,
+                    event_details: EventDetails {
+                        recurrence_end: None,
+                        age_range: None,
+                        capacity: None,
+                        room_id: None,
+                        duration_minutes: None,
+                        speaker_ids: Vec::new(),
+                        category_id: None,
+                        tags: Vec::new(),
+                        ..event().event_details
+                    },
+                    version: 0,
+                    ..event()
+                })]),
+            },
+            TestCase {
+                description: "When a row has invalid numbers or booleans, each of them should be reported",
+                csv: "title,capacity,speakerIds,contactShowEmail\nTajweed class,twenty,1;x,yes"
+                    .to_owned(),
+                expected_result: Ok(vec![Err(vec![
+                    "capacity must be a whole number".to_owned(),
+                    "speakerIds must be a whole number".to_owned(),
+                    "contactShowEmail must be true or false".to_owned(),
+                ])]),
+            },
+            TestCase {
+                description: "When a row is missing a required value, it should be reported",
+                csv: "title,eventType,eventRecurrence,eventStatus,contactFullName,contactPhoneNumber\n\
+                    Tajweed class,class,weekly,confirmed,John Smith,07123456789"
+                    .to_owned(),
+                expected_result: Ok(vec![Err(vec!["missing field `date`".to_owned()])]),
+            },
+            TestCase {
+                description: "When the header has an unknown column, the file should be rejected",
+                csv: "title,venue\nTajweed class,Main hall".to_owned(),
+                expected_result: Err("venue is not a recognised column".to_owned()),
+            },
+            TestCase {
+                description: "When the file is empty, it should be rejected",
+                csv: String::new(),
+                expected_result: Err("the file is empty".to_owned()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(test_case.expected_result, events_from_csv(&test_case.csv));
+        }
+    }
+}
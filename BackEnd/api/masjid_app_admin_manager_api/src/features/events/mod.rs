@@ -1,3 +1,5 @@
 pub mod endpoints;
+pub mod events_csv;
+pub mod models;
 pub mod repositories;
 pub mod services;
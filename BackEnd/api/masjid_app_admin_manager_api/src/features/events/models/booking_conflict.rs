@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// An occurrence of another event which already has the room booked
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookingConflict {
+    pub event_id: i32,
+    pub title: String,
+    pub room_id: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
@@ -0,0 +1,46 @@
+pub const EVENT_DUPLICATE_DATE_WINDOW_DAYS: &'static str = "EVENT_DUPLICATE_DATE_WINDOW_DAYS";
+pub const EVENT_DUPLICATE_MINIMUM_TITLE_SIMILARITY: &'static str =
+    "EVENT_DUPLICATE_MINIMUM_TITLE_SIMILARITY";
+
+/// Occurrences are looked for on either side of the event's date, and the whole window must stay
+/// within what can be expanded at once
+const MAXIMUM_DATE_WINDOW_DAYS: u16 = 183;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DuplicateDetectionSettings {
+    /// How many days either side of the event's date another occurrence can fall to be similar
+    pub date_window_days: u16,
+    /// Between 0 and 1, where 1 only matches titles which are the same once case and spacing are
+    /// ignored
+    pub minimum_title_similarity: f64,
+}
+
+impl Default for DuplicateDetectionSettings {
+    fn default() -> Self {
+        Self {
+            date_window_days: 7,
+            minimum_title_similarity: 0.8,
+        }
+    }
+}
+
+impl DuplicateDetectionSettings {
+    /// Reads each setting from the environment, falling back to the default for any that are
+    /// not set
+    pub fn from_environment() -> Self {
+        let defaults = Self::default();
+        Self {
+            date_window_days: std::env::var(EVENT_DUPLICATE_DATE_WINDOW_DAYS)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .map(|days: u16| days.min(MAXIMUM_DATE_WINDOW_DAYS))
+                .unwrap_or(defaults.date_window_days),
+            minimum_title_similarity: std::env::var(EVENT_DUPLICATE_MINIMUM_TITLE_SIMILARITY)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .filter(|similarity: &f64| similarity.is_finite())
+                .map(|similarity| similarity.clamp(0.0, 1.0))
+                .unwrap_or(defaults.minimum_title_similarity),
+        }
+    }
+}
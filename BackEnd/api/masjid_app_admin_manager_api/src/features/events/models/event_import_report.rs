@@ -0,0 +1,16 @@
+use crate::features::events::models::event_import_row_error::EventImportRowError;
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventImportReport {
+    pub dry_run: bool,
+
+    /// The number of events saved, which is always 0 for dry runs and imports with errors
+    pub imported: usize,
+
+    /// The ids the events were saved with, in the order they appear in the file
+    pub event_ids: Vec<i32>,
+
+    pub errors: Vec<EventImportRowError>,
+}
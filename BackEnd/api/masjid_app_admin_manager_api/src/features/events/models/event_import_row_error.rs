@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct EventImportRowError {
+    /// The position of the event in the file, starting from 1 and not counting the CSV header
+    pub row: usize,
+    pub errors: Vec<String>,
+}
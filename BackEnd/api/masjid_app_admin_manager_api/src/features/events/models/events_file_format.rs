@@ -0,0 +1,74 @@
+use serde::Deserialize;
+
+/// The formats events can be bulk imported from and exported to
+#[derive(Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EventsFileFormat {
+    Csv,
+    #[default]
+    Json,
+}
+impl EventsFileFormat {
+    /// Picks the format from the media type of a request body, ignoring any parameters such as
+    /// the charset
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type.split(';').next()?.trim();
+        if media_type.eq_ignore_ascii_case("text/csv") {
+            Some(EventsFileFormat::Csv)
+        } else if media_type.eq_ignore_ascii_case("application/json") {
+            Some(EventsFileFormat::Json)
+        } else {
+            None
+        }
+    }
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            EventsFileFormat::Csv => "text/csv; charset=utf-8",
+            EventsFileFormat::Json => "application/json",
+        }
+    }
+    pub fn extension(&self) -> &'static str {
+        match self {
+            EventsFileFormat::Csv => "csv",
+            EventsFileFormat::Json => "json",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_file_format_from_content_type() {
+        struct TestCase {
+            description: &'static str,
+            content_type: &'static str,
+            expected_format: Option<EventsFileFormat>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the body is CSV with a charset, it should be read as CSV",
+                content_type: "text/csv; charset=utf-8",
+                expected_format: Some(EventsFileFormat::Csv),
+            },
+            TestCase {
+                description: "When the body is JSON, it should be read as JSON",
+                content_type: "Application/JSON",
+                expected_format: Some(EventsFileFormat::Json),
+            },
+            TestCase {
+                description: "When the body is any other type, it should not be read",
+                content_type: "multipart/form-data; boundary=test",
+                expected_format: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_format,
+                EventsFileFormat::from_content_type(test_case.content_type)
+            );
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::features::events::models::events_file_format::EventsFileFormat;
+use serde::Deserialize;
+
+#[derive(Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExportEventsRequest {
+    #[serde(default)]
+    pub format: EventsFileFormat,
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Describes an event an administrator is about to publish
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FindDuplicateEventsRequest {
+    /// The event being edited, which is never reported as a duplicate of itself. 0 for a new
+    /// event
+    #[serde(default)]
+    pub id: i32,
+    pub title: String,
+    pub date: DateTime<Utc>,
+    pub room_id: Option<i32>,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportEventsRequest {
+    /// Validates the events and reports any errors without saving anything
+    #[serde(default, rename = "dryRun")]
+    pub dry_run: bool,
+}
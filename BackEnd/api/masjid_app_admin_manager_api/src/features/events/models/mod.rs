@@ -0,0 +1,12 @@
+pub mod booking_conflict;
+pub mod duplicate_detection_settings;
+pub mod event_import_report;
+pub mod event_import_row_error;
+pub mod events_file_format;
+pub mod export_events_request;
+pub mod find_duplicate_events_request;
+pub mod import_events_request;
+pub mod published_event;
+pub mod saved_event;
+pub mod saved_speaker;
+pub mod similar_event;
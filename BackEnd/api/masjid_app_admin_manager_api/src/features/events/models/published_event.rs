@@ -0,0 +1,9 @@
+use crate::features::events::models::saved_event::SavedEvent;
+
+/// What changed when an event was published
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublishedEvent {
+    pub saved_event: SavedEvent,
+    /// The image the event used before, if it has since been replaced or removed
+    pub replaced_image_url: Option<String>,
+}
@@ -0,0 +1,7 @@
+/// The id and version the database saved an event with, which are all that can change when it is
+/// saved
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SavedEvent {
+    pub id: i32,
+    pub version: u32,
+}
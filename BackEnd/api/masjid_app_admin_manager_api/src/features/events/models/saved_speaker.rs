@@ -0,0 +1,7 @@
+/// What changed when a speaker was saved
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavedSpeaker {
+    pub id: i32,
+    /// The photo the speaker used before, if it has since been replaced or removed
+    pub replaced_photo_url: Option<String>,
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// An existing event which may be the same as the one about to be published
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarEvent {
+    pub event_id: i32,
+    pub title: String,
+    pub room_id: Option<i32>,
+    /// The occurrence closest to the requested date
+    pub occurrence_date: DateTime<Utc>,
+    /// Between 0 and 1, where 1 is the same title once case and spacing are ignored
+    pub title_similarity: f64,
+    /// Whether publishing would be refused, as the event has the same title, start date and room
+    pub is_duplicate: bool,
+}
@@ -0,0 +1,5 @@
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DeleteEventExceptionError {
+    EventExceptionNotFound,
+    UnableToDeleteEventException,
+}
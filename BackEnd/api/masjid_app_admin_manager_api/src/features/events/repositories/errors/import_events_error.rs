@@ -0,0 +1,11 @@
+use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+
+#[derive(Debug)]
+pub enum ImportEventsError {
+    /// The event at this index could not be saved, so none of the events were imported
+    EventRejected {
+        index: usize,
+        error: UpsertEventError,
+    },
+    UnableToImportEvents,
+}
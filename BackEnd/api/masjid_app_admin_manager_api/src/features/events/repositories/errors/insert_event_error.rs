@@ -1,5 +1,4 @@
 #[derive(Debug)]
 pub enum InsertEventError {
-    EventAlreadyExists,
     UnableToInsertEvent,
 }
@@ -1,4 +1,13 @@
+pub mod delete_category_error;
 pub mod delete_event_error;
+pub mod delete_event_exception_error;
+pub mod delete_room_error;
+pub mod delete_speaker_error;
+pub mod import_events_error;
 pub mod insert_event_error;
 pub mod update_event_error;
+pub mod upsert_category_error;
 pub mod upsert_event_error;
+pub mod upsert_event_exception_error;
+pub mod upsert_room_error;
+pub mod upsert_speaker_error;
@@ -1,5 +1,7 @@
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum UpdateEventError {
     EventNotFound,
+    /// The event has been changed since the version the update was made against
+    VersionMismatch,
     UnableToUpdateEvent,
 }
@@ -1,3 +1,4 @@
+use crate::features::events::models::booking_conflict::BookingConflict;
 use crate::features::events::repositories::errors::insert_event_error::InsertEventError;
 use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
 
@@ -5,5 +6,13 @@ use crate::features::events::repositories::errors::update_event_error::UpdateEve
 pub enum UpsertEventError {
     InsertError(InsertEventError),
     UpdateError(UpdateEventError),
+    /// Another event already has the same title, start date and room
+    EventAlreadyExists,
+    RoomNotFound,
+    CategoryNotFound,
+    SpeakerNotFound,
+    /// The room is already booked by another event at the same time
+    RoomConflict(BookingConflict),
+    UnableToCheckRoomAvailability,
     UnableToUpsertEvent,
 }
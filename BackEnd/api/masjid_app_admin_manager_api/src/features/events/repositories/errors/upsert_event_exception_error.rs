@@ -0,0 +1,10 @@
+use crate::features::events::models::booking_conflict::BookingConflict;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum UpsertEventExceptionError {
+    EventNotFound,
+    /// The moved occurrence overlaps another booking of the event's room
+    RoomConflict(BookingConflict),
+    UnableToCheckRoomAvailability,
+    UnableToUpsertEventException,
+}
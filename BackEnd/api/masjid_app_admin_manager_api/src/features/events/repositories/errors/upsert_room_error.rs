@@ -0,0 +1,6 @@
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum UpsertRoomError {
+    RoomNotFound,
+    RoomAlreadyExists,
+    UnableToUpsertRoom,
+}
@@ -1,8 +1,26 @@
+use crate::features::events::models::saved_event::SavedEvent;
+use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
 use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
 use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use masjid_app_api_library::features::events::models::event::Event;
+use masjid_app_api_library::features::events::models::event_category::EventCategory;
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use masjid_app_api_library::features::events::models::event_status::EventStatus;
+use masjid_app_api_library::features::events::models::room::Room;
+use masjid_app_api_library::features::events::models::speaker::Speaker;
 use masjid_app_api_library::features::events::repositories::EventsRepository;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
 use masjid_app_api_library::new_repository;
 use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
 use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
@@ -17,8 +35,59 @@ mod mysql_impl;
 mod redis_impl;
 #[async_trait]
 pub trait EventsAdminRepository: EventsRepository {
-    async fn upsert_event(&self, event: &Event) -> Result<(), UpsertEventError>;
+    /// Returns the id and version the event was saved with
+    async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+    /// Upserts every event in a single transaction, so either all of them are saved or none are.
+    /// Returns the id and version of each event, in the order they were given
+    async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
     async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+    async fn get_image_url_by_event_id(
+        &self,
+        event_id: &i32,
+    ) -> Result<Option<String>, GetEventsRepositoryError>;
+    async fn get_event_status_by_id(
+        &self,
+        event_id: &i32,
+    ) -> Result<EventStatus, GetEventsRepositoryError>;
+    /// `version` is the version the change was made against. Returns the version the event was
+    /// saved with
+    async fn update_event_status(
+        &self,
+        event_id: &i32,
+        status: &EventStatus,
+        version: &u32,
+    ) -> Result<u32, UpdateEventError>;
+    async fn upsert_event_exception(
+        &self,
+        exception: &EventExceptionDTO,
+    ) -> Result<(), UpsertEventExceptionError>;
+    async fn delete_event_exception(
+        &self,
+        event_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<(), DeleteEventExceptionError>;
+    /// Returns the id the room was saved with
+    async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+    async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+    /// Returns the id the speaker was saved with
+    async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+    async fn get_photo_url_by_speaker_id(
+        &self,
+        speaker_id: &i32,
+    ) -> Result<Option<String>, GetEventsRepositoryError>;
+    /// Whether any event or speaker still uses the uploaded image
+    async fn is_image_url_referenced(
+        &self,
+        image_url: &str,
+    ) -> Result<bool, GetEventsRepositoryError>;
+    /// Returns the photo url of the deleted speaker, if it is not shared with another record
+    async fn delete_speaker_by_id(
+        &self,
+        speaker_id: &i32,
+    ) -> Result<Option<String>, DeleteSpeakerError>;
+    /// Returns the id the category was saved with
+    async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+    async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
 }
 
 pub async fn new_events_admin_repository(
@@ -1,49 +1,257 @@
+use crate::features::events::models::saved_event::SavedEvent;
 use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
 use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
 use crate::features::events::repositories::errors::insert_event_error::InsertEventError;
 use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
 use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
+use crate::features::events::services::booking_conflicts::find_booking_conflict;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use masjid_app_api_library::features::events::models::event::Event;
+use masjid_app_api_library::features::events::models::event_category::EventCategory;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use masjid_app_api_library::features::events::models::event_status::EventStatus;
+use masjid_app_api_library::features::events::models::room::Room;
+use masjid_app_api_library::features::events::models::speaker::Speaker;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use masjid_app_api_library::features::events::repositories::mysql_impl::map_event_row;
 use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
-use sqlx::Row;
+use sqlx::mysql::{MySql, MySqlArguments, MySqlRow};
+use sqlx::query::Query;
+use sqlx::{Row, Transaction};
+
+fn upsert_event_query(event: &Event) -> Query<'_, MySql, MySqlArguments> {
+    sqlx::query(
+        "CALL upsert_event(?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+        .bind(&event.id)
+        .bind(&event.title)
+        .bind(&event.description)
+        .bind(&event.date)
+        .bind(&event.r#type)
+        .bind(&event.recurrence)
+        .bind(&event.recurrence_end_date)
+        .bind(&event.recurrence_count)
+        .bind(&event.status)
+        .bind(&event.publish_at)
+        .bind(&event.minimum_age)
+        .bind(&event.maximum_age)
+        .bind(&event.capacity)
+        .bind(&event.room_id)
+        .bind(&event.duration_minutes)
+        .bind(&event.speaker_ids)
+        .bind(&event.category_id)
+        .bind(&event.tags)
+        .bind(&event.image_url)
+        .bind(&event.full_name)
+        .bind(&event.phone_number)
+        .bind(&event.email)
+        .bind(&event.show_full_name)
+        .bind(&event.show_phone_number)
+        .bind(&event.show_email)
+        .bind(&event.contact_form_enabled)
+        .bind(&event.version)
+}
+
+fn map_upsert_event_error(err: sqlx::Error) -> UpsertEventError {
+    if let sqlx::Error::Database(ref database_error) = err {
+        match database_error.message() {
+            "Event already exists" => return UpsertEventError::EventAlreadyExists,
+            "Version mismatch" => {
+                return UpsertEventError::UpdateError(UpdateEventError::VersionMismatch);
+            }
+            "Room not found" => return UpsertEventError::RoomNotFound,
+            "Speaker not found" => return UpsertEventError::SpeakerNotFound,
+            "Category not found" => return UpsertEventError::CategoryNotFound,
+            _ => {}
+        }
+    }
+    tracing::error!("Unable to upsert event due to the following error: {}", err);
+    UpsertEventError::InsertError(InsertEventError::UnableToInsertEvent)
+}
+
+/// Holds the room until the transaction ends, returning whether it exists
+async fn lock_room(
+    transaction: &mut Transaction<'_, MySql>,
+    room_id: i32,
+) -> Result<bool, sqlx::Error> {
+    let room = sqlx::query("CALL lock_room_by_id(?)")
+        .bind(room_id)
+        .fetch_optional(&mut **transaction)
+        .await?;
+    Ok(room.is_some())
+}
+
+/// Every event, along with the exceptions to their occurrences, that a booking is compared with
+async fn get_bookings(
+    transaction: &mut Transaction<'_, MySql>,
+) -> Result<(Vec<EventDTO>, Vec<EventExceptionDTO>), sqlx::Error> {
+    let events = sqlx::query("CALL get_events(?);")
+        .bind(false)
+        .map(map_event_row)
+        .fetch_all(&mut **transaction)
+        .await?
+        .into_iter()
+        .map(EventDTO::from)
+        .collect();
+    let exceptions = sqlx::query("CALL get_event_exceptions();")
+        .map(|row: MySqlRow| EventExceptionDTO {
+            event_id: row.get(0),
+            occurrence_date: row.get(1),
+            replacement_date: row.get(2),
+        })
+        .fetch_all(&mut **transaction)
+        .await?;
+    Ok((events, exceptions))
+}
+
+/// Locks the room the event is booked into, then rejects the event when one of its occurrences
+/// overlaps another booking of the room
+async fn check_room_availability(
+    transaction: &mut Transaction<'_, MySql>,
+    event: &Event,
+) -> Result<(), UpsertEventError> {
+    let Some(room_id) = event.room_id else {
+        return Ok(());
+    };
+    let map_database_error = |err: sqlx::Error| {
+        tracing::error!(error = err.to_string(), "unable to check room availability");
+        UpsertEventError::UnableToCheckRoomAvailability
+    };
+    if !lock_room(transaction, room_id)
+        .await
+        .map_err(map_database_error)?
+    {
+        return Err(UpsertEventError::RoomNotFound);
+    }
+    let (events, exceptions) = get_bookings(transaction)
+        .await
+        .map_err(map_database_error)?;
+    match find_booking_conflict(
+        &EventDTO::from(event.clone()),
+        &events,
+        &exceptions,
+        Utc::now(),
+    ) {
+        Some(conflict) => Err(UpsertEventError::RoomConflict(conflict)),
+        None => Ok(()),
+    }
+}
+
+fn find_event(events: &[EventDTO], event_id: i32) -> Result<&EventDTO, UpsertEventExceptionError> {
+    events
+        .iter()
+        .find(|event| event.id == event_id)
+        .ok_or(UpsertEventExceptionError::EventNotFound)
+}
+
+/// Locks the room of the event whose occurrence is moved, then rejects the move when the event,
+/// with the occurrence at its new date, overlaps another booking of the room
+async fn check_moved_occurrence_availability(
+    transaction: &mut Transaction<'_, MySql>,
+    exception: &EventExceptionDTO,
+) -> Result<(), UpsertEventExceptionError> {
+    let Some(replacement_date) = exception.replacement_date else {
+        return Ok(());
+    };
+    let map_database_error = |err: sqlx::Error| {
+        tracing::error!(error = err.to_string(), "unable to check room availability");
+        UpsertEventExceptionError::UnableToCheckRoomAvailability
+    };
+    let (events, _) = get_bookings(transaction)
+        .await
+        .map_err(map_database_error)?;
+    let Some(room_id) = find_event(&events, exception.event_id)?
+        .event_details
+        .room_id
+    else {
+        return Ok(());
+    };
+    lock_room(transaction, room_id)
+        .await
+        .map_err(map_database_error)?;
+    // The bookings are read again now that the room is locked, so that none can change before
+    // the exception is saved
+    let (events, mut exceptions) = get_bookings(transaction)
+        .await
+        .map_err(map_database_error)?;
+    exceptions.retain(|existing| {
+        existing.event_id != exception.event_id
+            || existing.occurrence_date != exception.occurrence_date
+    });
+    exceptions.push(exception.clone());
+    let event = find_event(&events, exception.event_id)?;
+    // Compared from the new date, so that occurrences moved beyond the booking horizon are
+    // checked too
+    match find_booking_conflict(event, &events, &exceptions, replacement_date) {
+        Some(conflict) => Err(UpsertEventExceptionError::RoomConflict(conflict)),
+        None => Ok(()),
+    }
+}
+
+/// Saves the event once its room is known to be free. The room stays locked until the transaction
+/// ends, so the availability check cannot be passed by two overlapping bookings at once
+async fn save_event(
+    transaction: &mut Transaction<'_, MySql>,
+    event: &Event,
+) -> Result<SavedEvent, UpsertEventError> {
+    check_room_availability(transaction, event).await?;
+    let saved_event = upsert_event_query(event)
+        .map(|row: MySqlRow| SavedEvent {
+            id: row.get(0),
+            version: row.get(1),
+        })
+        .fetch_optional(&mut **transaction)
+        .await
+        .map_err(map_upsert_event_error)?;
+    saved_event.ok_or_else(|| {
+        tracing::debug!(id = &event.id, "record not found");
+        UpsertEventError::UpdateError(UpdateEventError::EventNotFound)
+    })
+}
 
 #[async_trait]
 impl EventsAdminRepository for MySqlRepository {
-    async fn upsert_event(&self, event: &Event) -> Result<(), UpsertEventError> {
-        let db_connection = self.db_connection.clone();
-        let result = sqlx::query("CALL upsert_event(?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
-            .bind(&event.id)
-            .bind(&event.title)
-            .bind(&event.description)
-            .bind(&event.date)
-            .bind(&event.r#type)
-            .bind(&event.recurrence)
-            .bind(&event.status)
-            .bind(&event.minimum_age)
-            .bind(&event.maximum_age)
-            .bind(&event.image_url)
-            .bind(&event.full_name)
-            .bind(&event.phone_number)
-            .bind(&event.email)
-            .execute(&*db_connection)
-            .await
-            .map_err(|err| {
-                if let sqlx::Error::Database(ref database_error) = err {
-                    if database_error.message() == "Event already exists" {
-                        return UpsertEventError::InsertError(InsertEventError::EventAlreadyExists);
-                    }
-                }
-                tracing::error!("Unable to upsert event due to the following error: {}", err);
-                UpsertEventError::InsertError(InsertEventError::UnableToInsertEvent)
-            })?;
-        if result.rows_affected() == 0 {
-            tracing::debug!(id = &event.id, "record not found");
-            return Err(UpsertEventError::UpdateError(
-                UpdateEventError::EventNotFound,
-            ));
+    async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError> {
+        let db_connection = self.db_connection.clone();
+        let map_database_error = |err: sqlx::Error| {
+            tracing::error!(error = err.to_string(), "unable to upsert event");
+            UpsertEventError::UnableToUpsertEvent
+        };
+        let mut transaction = db_connection.begin().await.map_err(map_database_error)?;
+        let saved_event = save_event(&mut transaction, event).await?;
+        transaction.commit().await.map_err(map_database_error)?;
+        Ok(saved_event)
+    }
+
+    async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError> {
+        let db_connection = self.db_connection.clone();
+        let map_database_error = |err: sqlx::Error| {
+            tracing::error!(error = err.to_string(), "unable to import events");
+            ImportEventsError::UnableToImportEvents
+        };
+        // Dropping the transaction before it is committed rolls back every event saved so far
+        let mut transaction = db_connection.begin().await.map_err(map_database_error)?;
+        let mut saved_events = Vec::with_capacity(events.len());
+        for (index, event) in events.iter().enumerate() {
+            let saved_event = save_event(&mut transaction, event)
+                .await
+                .map_err(|error| ImportEventsError::EventRejected { index, error })?;
+            saved_events.push(saved_event);
         }
-        Ok(())
+        transaction.commit().await.map_err(map_database_error)?;
+        tracing::info!(count = events.len(), "imported events");
+        Ok(saved_events)
     }
 
     async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError> {
@@ -79,4 +287,354 @@ impl EventsAdminRepository for MySqlRepository {
         }
         Ok(image_url)
     }
+
+    async fn get_image_url_by_event_id(
+        &self,
+        event_id: &i32,
+    ) -> Result<Option<String>, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL retrieve_image_url_by_event_id(?)")
+            .bind(&event_id)
+            .fetch_optional(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    "unable to retrieve image url for event id {}, due to the following error: {}",
+                    event_id,
+                    err
+                );
+                GetEventsRepositoryError::UnableToGetEvents
+            })?
+            .map(|row| row.get(0))
+            .ok_or(GetEventsRepositoryError::EventsNotFound)
+    }
+
+    async fn get_event_status_by_id(
+        &self,
+        event_id: &i32,
+    ) -> Result<EventStatus, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_event_status_by_id(?)")
+            .bind(&event_id)
+            .fetch_optional(&*db_connection)
+            .await
+            .and_then(|row| row.map(|row| row.try_get(0)).transpose())
+            .map_err(|err| {
+                tracing::error!(
+                    "unable to retrieve status for event id {}, due to the following error: {}",
+                    event_id,
+                    err
+                );
+                GetEventsRepositoryError::UnableToGetEvents
+            })?
+            .ok_or(GetEventsRepositoryError::EventsNotFound)
+    }
+
+    async fn update_event_status(
+        &self,
+        event_id: &i32,
+        status: &EventStatus,
+        version: &u32,
+    ) -> Result<u32, UpdateEventError> {
+        let db_connection = self.db_connection.clone();
+        let saved_version = sqlx::query("CALL update_event_status(?, ?, ?)")
+            .bind(&event_id)
+            .bind(status)
+            .bind(&version)
+            .map(|row: MySqlRow| row.get::<u32, _>(0))
+            .fetch_optional(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err
+                    && database_error.message() == "Version mismatch"
+                {
+                    return UpdateEventError::VersionMismatch;
+                }
+                tracing::error!(
+                    "failed to update event status due to the following error: {}",
+                    err
+                );
+                UpdateEventError::UnableToUpdateEvent
+            })?;
+        saved_version.ok_or_else(|| {
+            tracing::debug!("event id {} not found in the database", event_id);
+            UpdateEventError::EventNotFound
+        })
+    }
+
+    async fn upsert_event_exception(
+        &self,
+        exception: &EventExceptionDTO,
+    ) -> Result<(), UpsertEventExceptionError> {
+        let db_connection = self.db_connection.clone();
+        let map_database_error = |err: sqlx::Error| {
+            tracing::error!(error = err.to_string(), "unable to upsert event exception");
+            UpsertEventExceptionError::UnableToUpsertEventException
+        };
+        let mut transaction = db_connection.begin().await.map_err(map_database_error)?;
+        check_moved_occurrence_availability(&mut transaction, exception).await?;
+        sqlx::query("CALL upsert_event_exception(?, ?, ?)")
+            .bind(&exception.event_id)
+            .bind(&exception.occurrence_date)
+            .bind(&exception.replacement_date)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Event not found" {
+                        return UpsertEventExceptionError::EventNotFound;
+                    }
+                }
+                tracing::error!(
+                    "Unable to upsert event exception due to the following error: {}",
+                    err
+                );
+                UpsertEventExceptionError::UnableToUpsertEventException
+            })?;
+        transaction.commit().await.map_err(map_database_error)?;
+        Ok(())
+    }
+
+    async fn delete_event_exception(
+        &self,
+        event_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<(), DeleteEventExceptionError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL delete_event_exception(?, ?)")
+            .bind(&event_id)
+            .bind(&occurrence_date)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    "failed to delete event exception due to the following error: {}",
+                    err
+                );
+                DeleteEventExceptionError::UnableToDeleteEventException
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(
+                "exception for event id {} on {} not found in the database",
+                event_id,
+                occurrence_date
+            );
+            return Err(DeleteEventExceptionError::EventExceptionNotFound);
+        }
+        Ok(())
+    }
+
+    async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL upsert_room(?, ?, ?)")
+            .bind(&room.id)
+            .bind(&room.name)
+            .bind(&room.capacity)
+            .map(|row: MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    match database_error.message() {
+                        "Room not found" => return UpsertRoomError::RoomNotFound,
+                        "Room already exists" => return UpsertRoomError::RoomAlreadyExists,
+                        _ => {}
+                    }
+                }
+                tracing::error!("Unable to upsert room due to the following error: {}", err);
+                UpsertRoomError::UnableToUpsertRoom
+            })
+    }
+
+    async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL delete_room_by_id(?)")
+            .bind(&room_id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Room in use" {
+                        return DeleteRoomError::RoomInUse;
+                    }
+                }
+                tracing::error!("failed to delete room due to the following error: {}", err);
+                DeleteRoomError::UnableToDeleteRoom
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!("room id {} not found in the database", room_id);
+            return Err(DeleteRoomError::RoomNotFound);
+        }
+        Ok(())
+    }
+
+    async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError> {
+        let db_connection = self.db_connection.clone();
+        let languages = serde_json::to_string(&speaker.languages).map_err(|err| {
+            tracing::error!("Unable to serialise speaker languages: {}", err);
+            UpsertSpeakerError::UnableToUpsertSpeaker
+        })?;
+        sqlx::query("CALL upsert_speaker(?, ?, ?, ?, ?, ?)")
+            .bind(&speaker.id)
+            .bind(&speaker.full_name)
+            .bind(&speaker.title)
+            .bind(&speaker.bio)
+            .bind(&speaker.photo_url)
+            .bind(&languages)
+            .map(|row: MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Speaker not found" {
+                        return UpsertSpeakerError::SpeakerNotFound;
+                    }
+                }
+                tracing::error!(
+                    "Unable to upsert speaker due to the following error: {}",
+                    err
+                );
+                UpsertSpeakerError::UnableToUpsertSpeaker
+            })
+    }
+
+    async fn get_photo_url_by_speaker_id(
+        &self,
+        speaker_id: &i32,
+    ) -> Result<Option<String>, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL retrieve_photo_url_by_speaker_id(?)")
+            .bind(&speaker_id)
+            .fetch_optional(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    "unable to retrieve photo url for speaker id {}, due to the following error: {}",
+                    speaker_id,
+                    err
+                );
+                GetEventsRepositoryError::UnableToGetEvents
+            })?
+            .map(|row| row.get(0))
+            .ok_or(GetEventsRepositoryError::EventsNotFound)
+    }
+
+    async fn is_image_url_referenced(
+        &self,
+        image_url: &str,
+    ) -> Result<bool, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL is_image_url_referenced(?)")
+            .bind(image_url)
+            .map(|row: sqlx::mysql::MySqlRow| row.get::<i64, _>(0) != 0)
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    "unable to check whether image url {} is referenced, due to the following error: {}",
+                    image_url,
+                    err
+                );
+                GetEventsRepositoryError::UnableToGetEvents
+            })
+    }
+
+    async fn delete_speaker_by_id(
+        &self,
+        speaker_id: &i32,
+    ) -> Result<Option<String>, DeleteSpeakerError> {
+        let db_connection = self.db_connection.clone();
+        let mut photo_url: Option<String> = None;
+
+        match sqlx::query("CALL retrieve_photo_url_by_speaker_id(?)")
+            .bind(&speaker_id)
+            .fetch_optional(&*db_connection)
+            .await
+        {
+            Ok(url) => photo_url = url.and_then(|row| row.get(0)),
+            Err(err) => {
+                tracing::error!(
+                    "unable to retrieve photo url for speaker id {}, due to the following error: {}",
+                    speaker_id,
+                    err
+                )
+            }
+        }
+
+        let query_result = sqlx::query("CALL delete_speaker_by_id(?)")
+            .bind(&speaker_id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Speaker in use" {
+                        return DeleteSpeakerError::SpeakerInUse;
+                    }
+                }
+                tracing::error!(
+                    "failed to delete speaker due to the following error: {}",
+                    err
+                );
+                DeleteSpeakerError::UnableToDeleteSpeaker
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!("speaker id {} not found in the database", speaker_id);
+            return Err(DeleteSpeakerError::SpeakerNotFound);
+        }
+        Ok(photo_url)
+    }
+
+    async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL upsert_event_category(?, ?)")
+            .bind(&category.id)
+            .bind(&category.name)
+            .map(|row: MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    match database_error.message() {
+                        "Category not found" => return UpsertCategoryError::CategoryNotFound,
+                        "Category already exists" => {
+                            return UpsertCategoryError::CategoryAlreadyExists;
+                        }
+                        _ => {}
+                    }
+                }
+                tracing::error!(
+                    "Unable to upsert event category due to the following error: {}",
+                    err
+                );
+                UpsertCategoryError::UnableToUpsertCategory
+            })
+    }
+
+    async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL delete_event_category_by_id(?)")
+            .bind(&category_id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Category in use" {
+                        return DeleteCategoryError::CategoryInUse;
+                    }
+                }
+                tracing::error!(
+                    "failed to delete event category due to the following error: {}",
+                    err
+                );
+                DeleteCategoryError::UnableToDeleteCategory
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(
+                "event category id {} not found in the database",
+                category_id
+            );
+            return Err(DeleteCategoryError::CategoryNotFound);
+        }
+        Ok(())
+    }
 }
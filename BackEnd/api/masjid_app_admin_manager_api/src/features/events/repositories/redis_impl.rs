@@ -1,19 +1,157 @@
+use crate::features::events::models::saved_event::SavedEvent;
 use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
 use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
 use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use masjid_app_api_library::features::events::models::event::Event;
+use masjid_app_api_library::features::events::models::event_category::EventCategory;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use masjid_app_api_library::features::events::models::event_status::EventStatus;
+use masjid_app_api_library::features::events::models::room::Room;
+use masjid_app_api_library::features::events::models::speaker::Speaker;
+use masjid_app_api_library::features::events::repositories::EventsCacheRepository;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
 use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
 
 #[async_trait]
 impl EventsAdminRepository for InMemoryRepository {
-    async fn upsert_event(&self, event: &Event) -> Result<(), UpsertEventError> {
-        tracing::warn!("in-memory database for upserting event not implemented");
-        Err(UpsertEventError::UnableToUpsertEvent)
+    async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError> {
+        if event.id == 0 {
+            tracing::debug!("new events are only cached once the database has given them an id");
+            return Err(UpsertEventError::UnableToUpsertEvent);
+        }
+        self.cache_events(&[EventDTO::from(event.clone())])
+            .await
+            .map_err(|_| UpsertEventError::UnableToUpsertEvent)?;
+        Ok(SavedEvent {
+            id: event.id,
+            version: event.version,
+        })
+    }
+
+    async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError> {
+        let saved_events = events
+            .iter()
+            .map(|event| SavedEvent {
+                id: event.id,
+                version: event.version,
+            })
+            .collect();
+        let events: Vec<EventDTO> = events.iter().cloned().map(EventDTO::from).collect();
+        self.cache_events(&events)
+            .await
+            .map_err(|_| ImportEventsError::UnableToImportEvents)?;
+        Ok(saved_events)
     }
 
     async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError> {
-        tracing::warn!("in-memory database for deleting event not implemented");
-        Err(DeleteEventError::UnableToDeleteEvent)
+        self.evict_event(event_id)
+            .await
+            .map_err(|_| DeleteEventError::UnableToDeleteEvent)?;
+        Ok(None)
+    }
+
+    async fn get_image_url_by_event_id(
+        &self,
+        event_id: &i32,
+    ) -> Result<Option<String>, GetEventsRepositoryError> {
+        tracing::warn!("in-memory database for retrieving event image url not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn get_event_status_by_id(
+        &self,
+        event_id: &i32,
+    ) -> Result<EventStatus, GetEventsRepositoryError> {
+        tracing::warn!("in-memory database for retrieving event status not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn update_event_status(
+        &self,
+        event_id: &i32,
+        status: &EventStatus,
+        version: &u32,
+    ) -> Result<u32, UpdateEventError> {
+        tracing::warn!("in-memory database for updating event status not implemented");
+        Err(UpdateEventError::UnableToUpdateEvent)
+    }
+
+    async fn upsert_event_exception(
+        &self,
+        exception: &EventExceptionDTO,
+    ) -> Result<(), UpsertEventExceptionError> {
+        tracing::warn!("in-memory database for upserting event exception not implemented");
+        Err(UpsertEventExceptionError::UnableToUpsertEventException)
+    }
+
+    async fn delete_event_exception(
+        &self,
+        event_id: &i32,
+        occurrence_date: &DateTime<Utc>,
+    ) -> Result<(), DeleteEventExceptionError> {
+        tracing::warn!("in-memory database for deleting event exception not implemented");
+        Err(DeleteEventExceptionError::UnableToDeleteEventException)
+    }
+
+    async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError> {
+        tracing::warn!("in-memory database for upserting room not implemented");
+        Err(UpsertRoomError::UnableToUpsertRoom)
+    }
+
+    async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError> {
+        tracing::warn!("in-memory database for deleting room not implemented");
+        Err(DeleteRoomError::UnableToDeleteRoom)
+    }
+
+    async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError> {
+        tracing::warn!("in-memory database for upserting speaker not implemented");
+        Err(UpsertSpeakerError::UnableToUpsertSpeaker)
+    }
+
+    async fn get_photo_url_by_speaker_id(
+        &self,
+        speaker_id: &i32,
+    ) -> Result<Option<String>, GetEventsRepositoryError> {
+        tracing::warn!("in-memory database for retrieving speaker photo url not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn is_image_url_referenced(
+        &self,
+        image_url: &str,
+    ) -> Result<bool, GetEventsRepositoryError> {
+        tracing::warn!("in-memory database for checking image references not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn delete_speaker_by_id(
+        &self,
+        speaker_id: &i32,
+    ) -> Result<Option<String>, DeleteSpeakerError> {
+        tracing::warn!("in-memory database for deleting speaker not implemented");
+        Err(DeleteSpeakerError::UnableToDeleteSpeaker)
+    }
+
+    async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError> {
+        tracing::warn!("in-memory database for upserting event category not implemented");
+        Err(UpsertCategoryError::UnableToUpsertCategory)
+    }
+
+    async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError> {
+        tracing::warn!("in-memory database for deleting event category not implemented");
+        Err(DeleteCategoryError::UnableToDeleteCategory)
     }
 }
@@ -0,0 +1,288 @@
+use crate::features::events::models::booking_conflict::BookingConflict;
+use chrono::{DateTime, TimeDelta, Utc};
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use masjid_app_api_library::features::events::models::event_status::EventStatus;
+use masjid_app_api_library::features::events::services::occurrence_expansion::expand_occurrences;
+
+/// How far ahead of `from` occurrences are compared, which bounds series that repeat
+/// indefinitely
+const BOOKING_HORIZON_IN_DAYS: i64 = 366;
+
+/// The room an event holds and for how long each occurrence holds it. Drafts are not booked yet,
+/// and postponed, cancelled or archived events no longer need the room
+fn room_booking(event: &EventDTO) -> Option<(i32, TimeDelta)> {
+    let details = &event.event_details;
+    if !matches!(
+        details.event_status,
+        EventStatus::Scheduled | EventStatus::Confirmed
+    ) {
+        return None;
+    }
+    let room_id = details.room_id?;
+    let duration = TimeDelta::minutes(details.duration_minutes? as i64);
+    Some((room_id, duration))
+}
+
+/// Finds the earliest occurrence of another event which overlaps an occurrence of `event` in the
+/// same room, looking at occurrences which end after `from`
+pub fn find_booking_conflict(
+    event: &EventDTO,
+    events: &[EventDTO],
+    exceptions: &[EventExceptionDTO],
+    from: DateTime<Utc>,
+) -> Option<BookingConflict> {
+    let (room_id, duration) = room_booking(event)?;
+    let window_start = from.max(event.date);
+    let window_end = window_start + TimeDelta::days(BOOKING_HORIZON_IN_DAYS);
+    let occurrences = expand_occurrences(event, exceptions, window_start - duration, window_end);
+
+    events
+        .iter()
+        .filter(|other| other.id != event.id)
+        .filter_map(|other| {
+            let (other_room_id, other_duration) = room_booking(other)?;
+            if other_room_id != room_id {
+                return None;
+            }
+            let other_occurrences = expand_occurrences(
+                other,
+                exceptions,
+                window_start - other_duration,
+                window_end + duration,
+            );
+            occurrences.iter().find_map(|occurrence| {
+                let (starts_at, ends_at) = (
+                    occurrence.occurrence_date,
+                    occurrence.occurrence_date + duration,
+                );
+                other_occurrences
+                    .iter()
+                    .map(|other_occurrence| {
+                        (
+                            other_occurrence.occurrence_date,
+                            other_occurrence.occurrence_date + other_duration,
+                        )
+                    })
+                    .find(|(other_starts_at, other_ends_at)| {
+                        *other_starts_at < ends_at && starts_at < *other_ends_at
+                    })
+                    .map(|(other_starts_at, other_ends_at)| BookingConflict {
+                        event_id: other.id,
+                        title: other.title.clone(),
+                        room_id,
+                        starts_at: other_starts_at,
+                        ends_at: other_ends_at,
+                    })
+            })
+        })
+        .min_by_key(|conflict| conflict.starts_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::models::recurrence_end::RecurrenceEnd;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+
+    fn create_event(
+        id: i32,
+        date: &str,
+        event_recurrence: EventRecurrence,
+        event_status: EventStatus,
+        room_id: Option<i32>,
+        duration_minutes: u16,
+    ) -> EventDTO {
+        EventDTO {
+            id,
+            title: format!("event {}", id),
+            description: None,
+            date: date.parse().unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence,
+                recurrence_end: None,
+                event_status,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id,
+                duration_minutes: Some(duration_minutes),
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_find_booking_conflict() {
+        struct TestCase {
+            description: &'static str,
+            event: EventDTO,
+            events: Vec<EventDTO>,
+            exceptions: Vec<EventExceptionDTO>,
+            expected_conflict_start: Option<&'static str>,
+        }
+        let weekly_class = create_event(
+            1,
+            "2025-01-06T19:00:00Z",
+            EventRecurrence::Weekly,
+            EventStatus::Confirmed,
+            Some(1),
+            90,
+        );
+        let test_cases = [
+            TestCase {
+                description: "When the event is not held in a room, there should be no conflict",
+                event: create_event(
+                    2,
+                    "2025-01-13T19:30:00Z",
+                    EventRecurrence::OneOff,
+                    EventStatus::Confirmed,
+                    None,
+                    60,
+                ),
+                events: vec![weekly_class.clone()],
+                exceptions: Vec::new(),
+                expected_conflict_start: None,
+            },
+            TestCase {
+                description: "When a one-off event overlaps an occurrence of a weekly series, I should get that occurrence",
+                event: create_event(
+                    2,
+                    "2025-01-13T20:00:00Z",
+                    EventRecurrence::OneOff,
+                    EventStatus::Confirmed,
+                    Some(1),
+                    60,
+                ),
+                events: vec![weekly_class.clone()],
+                exceptions: Vec::new(),
+                expected_conflict_start: Some("2025-01-13T19:00:00Z"),
+            },
+            TestCase {
+                description: "When the event starts as another finishes, there should be no conflict",
+                event: create_event(
+                    2,
+                    "2025-01-13T20:30:00Z",
+                    EventRecurrence::OneOff,
+                    EventStatus::Confirmed,
+                    Some(1),
+                    60,
+                ),
+                events: vec![weekly_class.clone()],
+                exceptions: Vec::new(),
+                expected_conflict_start: None,
+            },
+            TestCase {
+                description: "When the event is in a different room, there should be no conflict",
+                event: create_event(
+                    2,
+                    "2025-01-13T20:00:00Z",
+                    EventRecurrence::OneOff,
+                    EventStatus::Confirmed,
+                    Some(2),
+                    60,
+                ),
+                events: vec![weekly_class.clone()],
+                exceptions: Vec::new(),
+                expected_conflict_start: None,
+            },
+            TestCase {
+                description: "When the clashing occurrence has been cancelled, there should be no conflict",
+                event: create_event(
+                    2,
+                    "2025-01-13T20:00:00Z",
+                    EventRecurrence::OneOff,
+                    EventStatus::Confirmed,
+                    Some(1),
+                    60,
+                ),
+                events: vec![weekly_class.clone()],
+                exceptions: vec![EventExceptionDTO {
+                    event_id: 1,
+                    occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+                    replacement_date: None,
+                }],
+                expected_conflict_start: None,
+            },
+            TestCase {
+                description: "When a monthly series first clashes with a weekly series months later, I should get the first clash",
+                event: create_event(
+                    2,
+                    "2025-01-01T19:00:00Z",
+                    EventRecurrence::Monthly,
+                    EventStatus::Confirmed,
+                    Some(1),
+                    60,
+                ),
+                events: vec![weekly_class.clone()],
+                exceptions: Vec::new(),
+                expected_conflict_start: Some("2025-09-01T19:00:00Z"),
+            },
+            TestCase {
+                description: "When the other event is only a draft, there should be no conflict",
+                event: create_event(
+                    2,
+                    "2025-01-13T20:00:00Z",
+                    EventRecurrence::OneOff,
+                    EventStatus::Confirmed,
+                    Some(1),
+                    60,
+                ),
+                events: vec![EventDTO {
+                    event_details: EventDetails {
+                        event_status: EventStatus::Draft,
+                        ..weekly_class.event_details.clone()
+                    },
+                    ..weekly_class.clone()
+                }],
+                exceptions: Vec::new(),
+                expected_conflict_start: None,
+            },
+            TestCase {
+                description: "When the event is compared with its own saved version, there should be no conflict",
+                event: EventDTO {
+                    event_details: EventDetails {
+                        recurrence_end: Some(RecurrenceEnd::OccurrenceCount(4)),
+                        ..weekly_class.event_details.clone()
+                    },
+                    ..weekly_class.clone()
+                },
+                events: vec![weekly_class],
+                exceptions: Vec::new(),
+                expected_conflict_start: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let actual_result = find_booking_conflict(
+                &test_case.event,
+                &test_case.events,
+                &test_case.exceptions,
+                "2025-01-01T00:00:00Z".parse().unwrap(),
+            );
+            assert_eq!(
+                test_case
+                    .expected_conflict_start
+                    .map(|date| date.parse::<DateTime<Utc>>().unwrap()),
+                actual_result.map(|conflict| conflict.starts_at)
+            );
+        }
+    }
+}
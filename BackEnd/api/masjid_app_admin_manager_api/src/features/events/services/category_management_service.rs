@@ -0,0 +1,219 @@
+use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::services::errors::category_management_error::CategoryManagementError;
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::models::event_category::EventCategory;
+use masjid_app_api_library::features::events::services::event_service_impl::EventServiceImpl;
+use masjid_app_api_library::new_event_service;
+use masjid_app_api_library::shared::common_service_impl::CommonServiceImpl;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait CategoryManagementService: Send + Sync {
+    /// Creates a category when its id is 0, otherwise renames the existing category. Returns the
+    /// id the category was saved with
+    async fn save_category(&self, category: EventCategory) -> Result<i32, CategoryManagementError>;
+    /// Removes a category, which is refused while any event is still listed under it
+    async fn remove_category(&self, category_id: i32) -> Result<(), CategoryManagementError>;
+}
+
+new_event_service!(
+    new_category_management_service,
+    CategoryManagementService,
+    EventsAdminRepository
+);
+
+#[async_trait]
+impl CategoryManagementService for EventServiceImpl<dyn EventsAdminRepository> {
+    async fn save_category(&self, category: EventCategory) -> Result<i32, CategoryManagementError> {
+        if let Err(upsert_error) = self
+            .common
+            .in_memory_repository
+            .upsert_category(&category)
+            .await
+        {
+            tracing::warn!(in_memory_upsertion_error = ?upsert_error, "upserting category into in-memory repository failed");
+        }
+        self.common
+            .repository
+            .upsert_category(&category)
+            .await
+            .map_err(CategoryManagementError::from)
+    }
+
+    async fn remove_category(&self, category_id: i32) -> Result<(), CategoryManagementError> {
+        if let Err(delete_error) = self
+            .common
+            .in_memory_repository
+            .delete_category_by_id(&category_id)
+            .await
+        {
+            tracing::warn!(in_memory_repository_error = ?delete_error, "failure to delete category from in-memory repository");
+        }
+        self.common
+            .repository
+            .delete_category_by_id(&category_id)
+            .await
+            .map_err(CategoryManagementError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::saved_event::SavedEvent;
+    use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
+    use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+    use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+    use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+    use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+    use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+    use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+    use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
+    use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+    use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+    use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+    use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
+    use chrono::{DateTime, Utc};
+    use masjid_app_api_library::features::events::models::event::Event;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+    use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+    use masjid_app_api_library::features::events::models::events_page::EventsPage;
+    use masjid_app_api_library::features::events::models::room::Room;
+    use masjid_app_api_library::features::events::models::speaker::Speaker;
+    use masjid_app_api_library::features::events::repositories::EventsRepository;
+    use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use mockall::mock;
+
+    mock!(
+        pub EventsAdminRepository {}
+
+        #[async_trait]
+        impl EventsRepository for EventsAdminRepository {
+            async fn get_events(&self, visibility: EventVisibility) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_filtered_events(&self, filter: &EventsFilter) -> Result<EventsPage, GetEventsRepositoryError>;
+            async fn get_event_exceptions(&self) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+            async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+            async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+            async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+            async fn get_tags(&self, visibility: EventVisibility) -> Result<Vec<String>, GetEventsRepositoryError>;
+        }
+        #[async_trait]
+        impl EventsAdminRepository for EventsAdminRepository {
+            async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+            async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
+            async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+            async fn get_image_url_by_event_id(&self, event_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn get_event_status_by_id(&self, event_id: &i32) -> Result<EventStatus, GetEventsRepositoryError>;
+            async fn update_event_status(&self, event_id: &i32, status: &EventStatus, version: &u32) -> Result<u32, UpdateEventError>;
+            async fn upsert_event_exception(&self, exception: &EventExceptionDTO) -> Result<(), UpsertEventExceptionError>;
+            async fn delete_event_exception(&self, event_id: &i32, occurrence_date: &DateTime<Utc>) -> Result<(), DeleteEventExceptionError>;
+            async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+            async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+            async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+            async fn get_photo_url_by_speaker_id(&self, speaker_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn is_image_url_referenced(&self, image_url: &str) -> Result<bool, GetEventsRepositoryError>;
+            async fn delete_speaker_by_id(&self, speaker_id: &i32) -> Result<Option<String>, DeleteSpeakerError>;
+            async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+            async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
+        }
+    );
+
+    #[tokio::test]
+    async fn test_category_management_service_save_category() {
+        struct TestCase {
+            description: &'static str,
+            expected_db_response: Result<i32, UpsertCategoryError>,
+            expected_result: Result<i32, CategoryManagementError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When another category already has the name, I should receive a category already exists error",
+                expected_db_response: Err(UpsertCategoryError::CategoryAlreadyExists),
+                expected_result: Err(CategoryManagementError::CategoryAlreadyExists),
+            },
+            TestCase {
+                description: "When the category being updated does not exist, I should receive a category not found error",
+                expected_db_response: Err(UpsertCategoryError::CategoryNotFound),
+                expected_result: Err(CategoryManagementError::CategoryNotFound),
+            },
+            TestCase {
+                description: "When saving the category succeeds, I should receive the id it was saved with",
+                expected_db_response: Ok(1),
+                expected_result: Ok(1),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            let mut mock_repository = MockEventsAdminRepository::new();
+
+            mock_in_memory_repository
+                .expect_upsert_category()
+                .return_once(|_| Err(UpsertCategoryError::UnableToUpsertCategory));
+            mock_repository
+                .expect_upsert_category()
+                .return_once(move |_| test_case.expected_db_response);
+
+            let service = new_category_management_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            let category = EventCategory {
+                id: 0,
+                name: "Youth".to_owned(),
+            };
+            let actual_result = service.save_category(category).await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_category_management_service_remove_category() {
+        struct TestCase {
+            description: &'static str,
+            expected_db_response: Result<(), DeleteCategoryError>,
+            expected_result: Result<(), CategoryManagementError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When an event is still listed under the category, I should receive a category in use error",
+                expected_db_response: Err(DeleteCategoryError::CategoryInUse),
+                expected_result: Err(CategoryManagementError::CategoryInUse),
+            },
+            TestCase {
+                description: "When the category does not exist, I should receive a category not found error",
+                expected_db_response: Err(DeleteCategoryError::CategoryNotFound),
+                expected_result: Err(CategoryManagementError::CategoryNotFound),
+            },
+            TestCase {
+                description: "When deleting the category succeeds, I should receive no error",
+                expected_db_response: Ok(()),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            let mut mock_repository = MockEventsAdminRepository::new();
+
+            mock_in_memory_repository
+                .expect_delete_category_by_id()
+                .return_once(|_| Err(DeleteCategoryError::UnableToDeleteCategory));
+            mock_repository
+                .expect_delete_category_by_id()
+                .return_once(move |_| test_case.expected_db_response);
+
+            let service = new_category_management_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            let actual_result = service.remove_category(1).await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+}
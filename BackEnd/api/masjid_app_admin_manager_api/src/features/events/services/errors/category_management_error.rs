@@ -0,0 +1,30 @@
+use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
+use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
+
+#[derive(Debug, PartialEq)]
+pub enum CategoryManagementError {
+    CategoryNotFound,
+    CategoryAlreadyExists,
+    CategoryInUse,
+    UnableToUpdateCategories,
+}
+impl From<UpsertCategoryError> for CategoryManagementError {
+    #[inline]
+    fn from(value: UpsertCategoryError) -> Self {
+        match value {
+            UpsertCategoryError::CategoryNotFound => Self::CategoryNotFound,
+            UpsertCategoryError::CategoryAlreadyExists => Self::CategoryAlreadyExists,
+            UpsertCategoryError::UnableToUpsertCategory => Self::UnableToUpdateCategories,
+        }
+    }
+}
+impl From<DeleteCategoryError> for CategoryManagementError {
+    #[inline]
+    fn from(value: DeleteCategoryError) -> Self {
+        match value {
+            DeleteCategoryError::CategoryNotFound => Self::CategoryNotFound,
+            DeleteCategoryError::CategoryInUse => Self::CategoryInUse,
+            DeleteCategoryError::UnableToDeleteCategory => Self::UnableToUpdateCategories,
+        }
+    }
+}
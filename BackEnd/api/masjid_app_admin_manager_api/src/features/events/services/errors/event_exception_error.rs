@@ -0,0 +1,50 @@
+use crate::features::events::models::booking_conflict::BookingConflict;
+use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+
+#[derive(Debug)]
+pub enum EventExceptionError {
+    EventNotFound,
+    NotAnOccurrence,
+    EventExceptionNotFound,
+    /// The moved occurrence overlaps another booking of the event's room
+    RoomConflict(BookingConflict),
+    UnableToCheckRoomAvailability,
+    UnableToUpdateEventExceptions,
+}
+impl From<GetEventsRepositoryError> for EventExceptionError {
+    #[inline]
+    fn from(value: GetEventsRepositoryError) -> Self {
+        match value {
+            GetEventsRepositoryError::EventsNotFound => Self::EventNotFound,
+            GetEventsRepositoryError::UnableToGetEvents => Self::UnableToUpdateEventExceptions,
+        }
+    }
+}
+impl From<UpsertEventExceptionError> for EventExceptionError {
+    #[inline]
+    fn from(value: UpsertEventExceptionError) -> Self {
+        match value {
+            UpsertEventExceptionError::EventNotFound => Self::EventNotFound,
+            UpsertEventExceptionError::RoomConflict(conflict) => Self::RoomConflict(conflict),
+            UpsertEventExceptionError::UnableToCheckRoomAvailability => {
+                Self::UnableToCheckRoomAvailability
+            }
+            UpsertEventExceptionError::UnableToUpsertEventException => {
+                Self::UnableToUpdateEventExceptions
+            }
+        }
+    }
+}
+impl From<DeleteEventExceptionError> for EventExceptionError {
+    #[inline]
+    fn from(value: DeleteEventExceptionError) -> Self {
+        match value {
+            DeleteEventExceptionError::EventExceptionNotFound => Self::EventExceptionNotFound,
+            DeleteEventExceptionError::UnableToDeleteEventException => {
+                Self::UnableToUpdateEventExceptions
+            }
+        }
+    }
+}
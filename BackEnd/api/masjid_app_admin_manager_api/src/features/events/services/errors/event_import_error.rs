@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq, Eq)]
+pub enum EventImportError {
+    /// The file could not be read at all, so none of its events could be checked
+    InvalidFile(String),
+    UnableToValidateEvents,
+    UnableToImportEvents,
+}
@@ -0,0 +1,12 @@
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+
+#[derive(Debug, PartialEq)]
+pub enum EventLifecycleError {
+    RepositoryError(GetEventsRepositoryError),
+}
+impl From<GetEventsRepositoryError> for EventLifecycleError {
+    #[inline]
+    fn from(value: GetEventsRepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
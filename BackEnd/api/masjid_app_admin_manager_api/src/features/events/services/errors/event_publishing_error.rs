@@ -1,12 +1,22 @@
+use crate::features::events::models::booking_conflict::BookingConflict;
 use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
 
 #[derive(Debug)]
 pub enum EventPublishingError {
     UnableToSaveImage,
+    /// The event cannot be created with, or moved to, the requested status
+    InvalidStatusTransition,
+    /// The room is already booked by another event at the same time
+    RoomConflict(BookingConflict),
+    UnableToCheckRoomAvailability,
     RepositoryError(UpsertEventError),
 }
 impl From<UpsertEventError> for EventPublishingError {
     fn from(value: UpsertEventError) -> Self {
-        Self::RepositoryError(value)
+        match value {
+            UpsertEventError::RoomConflict(conflict) => Self::RoomConflict(conflict),
+            UpsertEventError::UnableToCheckRoomAvailability => Self::UnableToCheckRoomAvailability,
+            err => Self::RepositoryError(err),
+        }
     }
 }
@@ -1,2 +1,9 @@
+pub mod category_management_error;
 pub mod event_deletion_error;
+pub mod event_duplicate_detection_error;
+pub mod event_exception_error;
+pub mod event_import_error;
+pub mod event_lifecycle_error;
 pub mod event_publishing_error;
+pub mod room_management_error;
+pub mod speaker_management_error;
@@ -0,0 +1,30 @@
+use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+
+#[derive(Debug, PartialEq)]
+pub enum RoomManagementError {
+    RoomNotFound,
+    RoomAlreadyExists,
+    RoomInUse,
+    UnableToUpdateRooms,
+}
+impl From<UpsertRoomError> for RoomManagementError {
+    #[inline]
+    fn from(value: UpsertRoomError) -> Self {
+        match value {
+            UpsertRoomError::RoomNotFound => Self::RoomNotFound,
+            UpsertRoomError::RoomAlreadyExists => Self::RoomAlreadyExists,
+            UpsertRoomError::UnableToUpsertRoom => Self::UnableToUpdateRooms,
+        }
+    }
+}
+impl From<DeleteRoomError> for RoomManagementError {
+    #[inline]
+    fn from(value: DeleteRoomError) -> Self {
+        match value {
+            DeleteRoomError::RoomNotFound => Self::RoomNotFound,
+            DeleteRoomError::RoomInUse => Self::RoomInUse,
+            DeleteRoomError::UnableToDeleteRoom => Self::UnableToUpdateRooms,
+        }
+    }
+}
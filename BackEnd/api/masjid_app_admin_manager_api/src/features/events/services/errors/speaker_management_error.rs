@@ -0,0 +1,28 @@
+use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
+
+#[derive(Debug, PartialEq)]
+pub enum SpeakerManagementError {
+    SpeakerNotFound,
+    SpeakerInUse,
+    UnableToUpdateSpeakers,
+}
+impl From<UpsertSpeakerError> for SpeakerManagementError {
+    #[inline]
+    fn from(value: UpsertSpeakerError) -> Self {
+        match value {
+            UpsertSpeakerError::SpeakerNotFound => Self::SpeakerNotFound,
+            UpsertSpeakerError::UnableToUpsertSpeaker => Self::UnableToUpdateSpeakers,
+        }
+    }
+}
+impl From<DeleteSpeakerError> for SpeakerManagementError {
+    #[inline]
+    fn from(value: DeleteSpeakerError) -> Self {
+        match value {
+            DeleteSpeakerError::SpeakerNotFound => Self::SpeakerNotFound,
+            DeleteSpeakerError::SpeakerInUse => Self::SpeakerInUse,
+            DeleteSpeakerError::UnableToDeleteSpeaker => Self::UnableToUpdateSpeakers,
+        }
+    }
+}
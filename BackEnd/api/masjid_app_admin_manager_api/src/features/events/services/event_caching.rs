@@ -0,0 +1,25 @@
+use crate::features::events::models::saved_event::SavedEvent;
+use crate::features::events::repositories::EventsAdminRepository;
+use masjid_app_api_library::features::events::models::event::Event;
+
+/// Caches the events as the database saved them, with the ids it gave new events and the
+/// versions it bumped. `saved_events` are in the same order as `events`. The database remains the
+/// source of truth, so a failure only leaves the cache stale and is logged rather than returned
+pub async fn cache_saved_events(
+    in_memory_repository: &dyn EventsAdminRepository,
+    events: &[Event],
+    saved_events: &[SavedEvent],
+) {
+    let saved_events: Vec<Event> = events
+        .iter()
+        .zip(saved_events)
+        .map(|(event, saved_event)| Event {
+            id: saved_event.id,
+            version: saved_event.version,
+            ..event.clone()
+        })
+        .collect();
+    if let Err(in_memory_import_error) = in_memory_repository.import_events(&saved_events).await {
+        tracing::warn!(in_memory_import_error = ?in_memory_import_error, "caching saved events in in-memory repository failed");
+    }
+}
@@ -10,7 +10,8 @@ use std::sync::Arc;
 #[automock]
 #[async_trait]
 pub trait EventDeletionService: Send + Sync {
-    async fn delete_event(&self, id: i32) -> Result<(), EventDeletionError>;
+    /// Returns the url of the image the deleted event used, so that it can be cleaned up
+    async fn delete_event(&self, id: i32) -> Result<Option<String>, EventDeletionError>;
 }
 new_event_service!(
     new_event_deletion_service,
@@ -19,37 +20,57 @@ new_event_service!(
 );
 #[async_trait]
 impl EventDeletionService for EventServiceImpl<dyn EventsAdminRepository> {
-    async fn delete_event(&self, id: i32) -> Result<(), EventDeletionError> {
-        let delete_event_in_memory_repository_result = self
+    async fn delete_event(&self, id: i32) -> Result<Option<String>, EventDeletionError> {
+        let image_url = self
+            .common
+            .repository
+            .delete_event_by_id(&id)
+            .await
+            .map_err(EventDeletionError::from)?;
+        // Only evicted once the event is gone from the database, so that a failed deletion does
+        // not hide an event which still exists
+        if let Err(in_memory_repository_error) = self
             .common
             .in_memory_repository
             .delete_event_by_id(&id)
             .await
-            .map_err(EventDeletionError::from);
-        if let Err(in_memory_repository_error) = delete_event_in_memory_repository_result {
+        {
             tracing::warn!(in_memory_repository_error = ?in_memory_repository_error, "failure to delete event from in-memory repository");
         }
-        self.common
-            .repository
-            .delete_event_by_id(&id)
-            .await
-            .map(move |_image_url| {
-                //TODO - Add file IO implementation for storing/removing images from the web server
-            })
-            .map_err(EventDeletionError::from)
+        Ok(image_url)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::events::models::saved_event::SavedEvent;
     use crate::features::events::repositories::EventsAdminRepository;
+    use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
     use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+    use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+    use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+    use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+    use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+    use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+    use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
     use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+    use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+    use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+    use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
     use crate::features::events::services::errors::event_deletion_error::EventDeletionError;
     use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
     use masjid_app_api_library::features::events::models::event::Event;
+    use masjid_app_api_library::features::events::models::event_category::EventCategory;
     use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+    use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+    use masjid_app_api_library::features::events::models::events_page::EventsPage;
+    use masjid_app_api_library::features::events::models::room::Room;
+    use masjid_app_api_library::features::events::models::speaker::Speaker;
     use masjid_app_api_library::features::events::repositories::EventsRepository;
     use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
     use mockall::mock;
@@ -59,12 +80,32 @@ mod tests {
 
         #[async_trait]
         impl EventsRepository for EventsAdminRepository {
-            async fn get_events(&self) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_events(&self, visibility: EventVisibility) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_filtered_events(&self, filter: &EventsFilter) -> Result<EventsPage, GetEventsRepositoryError>;
+            async fn get_event_exceptions(&self) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+            async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+            async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+            async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+            async fn get_tags(&self, visibility: EventVisibility) -> Result<Vec<String>, GetEventsRepositoryError>;
         }
         #[async_trait]
         impl EventsAdminRepository for EventsAdminRepository {
-            async fn upsert_event(&self, event: &Event) -> Result<(), UpsertEventError>;
+            async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+            async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
             async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+            async fn get_image_url_by_event_id(&self, event_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn get_event_status_by_id(&self, event_id: &i32) -> Result<EventStatus, GetEventsRepositoryError>;
+            async fn update_event_status(&self, event_id: &i32, status: &EventStatus, version: &u32) -> Result<u32, UpdateEventError>;
+            async fn upsert_event_exception(&self, exception: &EventExceptionDTO) -> Result<(), UpsertEventExceptionError>;
+            async fn delete_event_exception(&self, event_id: &i32, occurrence_date: &DateTime<Utc>) -> Result<(), DeleteEventExceptionError>;
+            async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+            async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+            async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+            async fn get_photo_url_by_speaker_id(&self, speaker_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn is_image_url_referenced(&self, image_url: &str) -> Result<bool, GetEventsRepositoryError>;
+            async fn delete_speaker_by_id(&self, speaker_id: &i32) -> Result<Option<String>, DeleteSpeakerError>;
+            async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+            async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
         }
     );
 
@@ -74,7 +115,7 @@ mod tests {
             description: &'static str,
             id: i32,
             expected_db_response: Result<Option<String>, DeleteEventError>,
-            expected_result: Result<(), EventDeletionError>,
+            expected_result: Result<Option<String>, EventDeletionError>,
         }
 
         let test_cases = [
@@ -98,7 +139,13 @@ mod tests {
                 description: "When deletion successful, I should get no error",
                 id: 0,
                 expected_db_response: Ok(None),
-                expected_result: Ok(()),
+                expected_result: Ok(None),
+            },
+            TestCase {
+                description: "When an event with an image is deleted, I should get the url of the image",
+                id: 0,
+                expected_db_response: Ok(Some("/uploads/poster.png".to_owned())),
+                expected_result: Ok(Some("/uploads/poster.png".to_owned())),
             },
         ];
         for test_case in test_cases {
@@ -108,7 +155,8 @@ mod tests {
 
             mock_in_memory_repository
                 .expect_delete_event_by_id()
-                .return_once(move |_| Ok(None));
+                .times(usize::from(test_case.expected_db_response.is_ok()))
+                .returning(|_| Ok(None));
             mock_repository
                 .expect_delete_event_by_id()
                 .return_once(move |_| test_case.expected_db_response);
@@ -116,8 +164,9 @@ mod tests {
                 Arc::new(mock_repository),
                 Arc::new(mock_in_memory_repository),
             );
-            let actual_result = service.delete_event(test_case.id);
-            assert!(matches!(test_case.expected_result, actual_result));
+            let actual_result = service.delete_event(test_case.id).await;
+            assert_eq!(test_case.expected_result.is_ok(), actual_result.is_ok());
+            assert_eq!(test_case.expected_result.ok(), actual_result.ok());
         }
     }
 }
@@ -0,0 +1,409 @@
+use crate::features::events::models::duplicate_detection_settings::DuplicateDetectionSettings;
+use crate::features::events::models::find_duplicate_events_request::FindDuplicateEventsRequest;
+use crate::features::events::models::similar_event::SimilarEvent;
+use crate::features::events::services::errors::event_duplicate_detection_error::EventDuplicateDetectionError;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
+use masjid_app_api_library::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use masjid_app_api_library::features::events::services::event_retrieval_service::EventRetrievalService;
+use mockall::automock;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventDuplicateDetectionService: Send + Sync {
+    /// Existing events which may be the same as the one described, with those that would be
+    /// refused as duplicates first, followed by the most similar titles
+    async fn find_similar_events(
+        &self,
+        request: FindDuplicateEventsRequest,
+    ) -> Result<Vec<SimilarEvent>, EventDuplicateDetectionError>;
+}
+
+struct EventDuplicateDetectionServiceImpl {
+    event_retrieval_service: Arc<dyn EventRetrievalService>,
+    settings: DuplicateDetectionSettings,
+}
+
+pub fn new_event_duplicate_detection_service(
+    event_retrieval_service: Arc<dyn EventRetrievalService>,
+    settings: DuplicateDetectionSettings,
+) -> Arc<dyn EventDuplicateDetectionService> {
+    Arc::new(EventDuplicateDetectionServiceImpl {
+        event_retrieval_service,
+        settings,
+    })
+}
+
+/// Lowercases the title and collapses its spacing, so that only the words are compared
+fn normalise_title(title: &str) -> Vec<char> {
+    title
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+        .chars()
+        .collect()
+}
+
+/// One minus the edit distance between the titles, relative to the longer of the two
+fn title_similarity(title: &str, other_title: &str) -> f64 {
+    let (title, other_title) = (normalise_title(title), normalise_title(other_title));
+    let longest = title.len().max(other_title.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let mut previous_row: Vec<usize> = (0..=other_title.len()).collect();
+    for (index, character) in title.iter().enumerate() {
+        let mut current_row = vec![index + 1];
+        for (other_index, other_character) in other_title.iter().enumerate() {
+            let substitution_cost = usize::from(character != other_character);
+            current_row.push(
+                (previous_row[other_index] + substitution_cost)
+                    .min(previous_row[other_index + 1] + 1)
+                    .min(current_row[other_index] + 1),
+            );
+        }
+        previous_row = current_row;
+    }
+    1.0 - previous_row[other_title.len()] as f64 / longest as f64
+}
+
+/// Mirrors the check made by the database when an event is saved
+fn is_duplicate(request: &FindDuplicateEventsRequest, occurrence: &EventOccurrenceDTO) -> bool {
+    let event = &occurrence.event;
+    event.title.to_lowercase() == request.title.to_lowercase()
+        && event.date.date_naive() == request.date.date_naive()
+        && event.event_details.room_id == request.room_id
+}
+
+fn distance_from(date: DateTime<Utc>, occurrence: &EventOccurrenceDTO) -> TimeDelta {
+    (occurrence.occurrence_date - date).abs()
+}
+
+#[async_trait]
+impl EventDuplicateDetectionService for EventDuplicateDetectionServiceImpl {
+    async fn find_similar_events(
+        &self,
+        request: FindDuplicateEventsRequest,
+    ) -> Result<Vec<SimilarEvent>, EventDuplicateDetectionError> {
+        // The window covers whole days, so that a window of 0 still finds events on the same day
+        let requested_day = request.date.date_naive().and_time(NaiveTime::MIN).and_utc();
+        let window = TimeDelta::days(self.settings.date_window_days as i64);
+        let occurrences = self
+            .event_retrieval_service
+            .get_event_occurrences(
+                requested_day - window,
+                requested_day + window + TimeDelta::days(1),
+            )
+            .await
+            .map_err(|_| EventDuplicateDetectionError::UnableToGetEvents)?;
+
+        let mut nearest_occurrences: HashMap<i32, EventOccurrenceDTO> = HashMap::new();
+        for occurrence in occurrences {
+            if request.id != 0 && occurrence.event.id == request.id {
+                continue;
+            }
+            let is_nearer = nearest_occurrences
+                .get(&occurrence.event.id)
+                .is_none_or(|nearest| {
+                    distance_from(request.date, &occurrence) < distance_from(request.date, nearest)
+                });
+            if is_nearer {
+                nearest_occurrences.insert(occurrence.event.id, occurrence);
+            }
+        }
+
+        let mut similar_events: Vec<SimilarEvent> = nearest_occurrences
+            .into_values()
+            .filter_map(|occurrence| {
+                let similarity = title_similarity(&request.title, &occurrence.event.title);
+                let is_duplicate = is_duplicate(&request, &occurrence);
+                if similarity < self.settings.minimum_title_similarity && !is_duplicate {
+                    return None;
+                }
+                Some(SimilarEvent {
+                    event_id: occurrence.event.id,
+                    title: occurrence.event.title,
+                    room_id: occurrence.event.event_details.room_id,
+                    occurrence_date: occurrence.occurrence_date,
+                    title_similarity: similarity,
+                    is_duplicate,
+                })
+            })
+            .collect();
+        similar_events.sort_by(|similar_event, other| {
+            other
+                .is_duplicate
+                .cmp(&similar_event.is_duplicate)
+                .then(
+                    other
+                        .title_similarity
+                        .total_cmp(&similar_event.title_similarity),
+                )
+                .then(similar_event.occurrence_date.cmp(&other.occurrence_date))
+        });
+        Ok(similar_events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use masjid_app_api_library::features::events::services::errors::get_event_occurrences_service_error::GetEventOccurrencesServiceError;
+    use masjid_app_api_library::features::events::services::event_retrieval_service::MockEventRetrievalService;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+
+    fn occurrence(
+        id: i32,
+        title: &str,
+        date: &str,
+        occurrence_date: &str,
+        room_id: Option<i32>,
+    ) -> EventOccurrenceDTO {
+        EventOccurrenceDTO {
+            occurrence_date: occurrence_date.parse().unwrap(),
+            original_date: None,
+            event: EventDTO {
+                id,
+                title: title.to_owned(),
+                description: None,
+                date: date.parse().unwrap(),
+                event_details: EventDetails {
+                    event_type: EventType::Talk,
+                    event_recurrence: EventRecurrence::Weekly,
+                    recurrence_end: None,
+                    event_status: EventStatus::Confirmed,
+                    publish_at: None,
+                    age_range: None,
+                    capacity: None,
+                    room_id,
+                    duration_minutes: None,
+                    speaker_ids: Vec::new(),
+                    category_id: None,
+                    tags: Vec::new(),
+                    image_url: None,
+                    image_variants: None,
+                    contact_details: ContactDetails {
+                        full_name: "Zayd McArdle".to_owned(),
+                        title: None,
+                        phone_number: "07123456789".to_owned(),
+                        email: None,
+                        visibility: ContactVisibility::default(),
+                    },
+                },
+                version: 1,
+            },
+        }
+    }
+
+    fn summarise(similar_events: Vec<SimilarEvent>) -> Vec<(i32, DateTime<Utc>, bool)> {
+        similar_events
+            .into_iter()
+            .map(|similar_event| {
+                (
+                    similar_event.event_id,
+                    similar_event.occurrence_date,
+                    similar_event.is_duplicate,
+                )
+            })
+            .collect()
+    }
+
+    fn request(id: i32, title: &str) -> FindDuplicateEventsRequest {
+        FindDuplicateEventsRequest {
+            id,
+            title: title.to_owned(),
+            date: "2026-03-04T19:00:00Z".parse().unwrap(),
+            room_id: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        struct TestCase {
+            description: &'static str,
+            title: &'static str,
+            other_title: &'static str,
+            expected_similarity: f64,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the titles only differ in case and spacing, they should be the same",
+                title: "Weekly  Tafsir Circle",
+                other_title: "weekly tafsir circle ",
+                expected_similarity: 1.0,
+            },
+            TestCase {
+                description: "When one character differs, the similarity should reflect the longer title",
+                title: "Tafsir circle",
+                other_title: "Tafsir circles",
+                expected_similarity: 1.0 - 1.0 / 14.0,
+            },
+            TestCase {
+                description: "When the titles share nothing, they should not be similar at all",
+                title: "abc",
+                other_title: "xyz",
+                expected_similarity: 0.0,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let similarity = title_similarity(test_case.title, test_case.other_title);
+            assert!((test_case.expected_similarity - similarity).abs() < f64::EPSILON);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_similar_events() {
+        struct TestCase {
+            description: &'static str,
+            request: FindDuplicateEventsRequest,
+            occurrences: Result<Vec<EventOccurrenceDTO>, GetEventsRepositoryError>,
+            expected_result: Result<Vec<(i32, &'static str, bool)>, EventDuplicateDetectionError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the events cannot be retrieved, I should get an error",
+                request: request(0, "Weekly Tafsir Circle"),
+                occurrences: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_result: Err(EventDuplicateDetectionError::UnableToGetEvents),
+            },
+            TestCase {
+                description: "When an event shares the title, start date and room, it should be reported as a duplicate",
+                request: request(0, "Weekly Tafsir Circle"),
+                occurrences: Ok(vec![occurrence(
+                    2,
+                    "Weekly Tafsir Circle",
+                    "2026-03-04T18:00:00Z",
+                    "2026-03-04T18:00:00Z",
+                    Some(1),
+                )]),
+                expected_result: Ok(vec![(2, "2026-03-04T18:00:00Z", true)]),
+            },
+            TestCase {
+                description: "When an earlier event in the series has the same title, it should be similar but not a duplicate",
+                request: request(0, "Weekly Tafsir Circle"),
+                occurrences: Ok(vec![
+                    occurrence(
+                        2,
+                        "Weekly tafsir circle",
+                        "2025-09-03T19:00:00Z",
+                        "2026-02-25T19:00:00Z",
+                        Some(1),
+                    ),
+                    occurrence(
+                        2,
+                        "Weekly tafsir circle",
+                        "2025-09-03T19:00:00Z",
+                        "2026-03-04T19:00:00Z",
+                        Some(1),
+                    ),
+                    occurrence(
+                        2,
+                        "Weekly tafsir circle",
+                        "2025-09-03T19:00:00Z",
+                        "2026-03-11T19:00:00Z",
+                        Some(1),
+                    ),
+                ]),
+                expected_result: Ok(vec![(2, "2026-03-04T19:00:00Z", false)]),
+            },
+            TestCase {
+                description: "When an event has a different title, it should not be reported",
+                request: request(0, "Weekly Tafsir Circle"),
+                occurrences: Ok(vec![occurrence(
+                    2,
+                    "Sisters' halaqa",
+                    "2026-03-04T19:00:00Z",
+                    "2026-03-04T19:00:00Z",
+                    Some(1),
+                )]),
+                expected_result: Ok(Vec::new()),
+            },
+            TestCase {
+                description: "When the event being edited is the only match, it should not be reported",
+                request: request(2, "Weekly Tafsir Circle"),
+                occurrences: Ok(vec![occurrence(
+                    2,
+                    "Weekly Tafsir Circle",
+                    "2026-03-04T19:00:00Z",
+                    "2026-03-04T19:00:00Z",
+                    Some(1),
+                )]),
+                expected_result: Ok(Vec::new()),
+            },
+            TestCase {
+                description: "When there are several matches, duplicates should come first followed by the most similar titles",
+                request: request(0, "Weekly Tafsir Circle"),
+                occurrences: Ok(vec![
+                    occurrence(
+                        3,
+                        "Weekly Tafsir Circles",
+                        "2026-03-02T19:00:00Z",
+                        "2026-03-02T19:00:00Z",
+                        Some(1),
+                    ),
+                    occurrence(
+                        4,
+                        "Weekly Tafsir Circle",
+                        "2026-03-04T19:00:00Z",
+                        "2026-03-04T19:00:00Z",
+                        Some(2),
+                    ),
+                    occurrence(
+                        5,
+                        "Weekly Tafsir Circle",
+                        "2026-03-04T20:00:00Z",
+                        "2026-03-04T20:00:00Z",
+                        Some(1),
+                    ),
+                ]),
+                expected_result: Ok(vec![
+                    (5, "2026-03-04T20:00:00Z", true),
+                    (4, "2026-03-04T19:00:00Z", false),
+                    (3, "2026-03-02T19:00:00Z", false),
+                ]),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_event_retrieval_service = MockEventRetrievalService::new();
+            let occurrences = test_case
+                .occurrences
+                .map_err(GetEventOccurrencesServiceError::UnableToGetEventsFromRepository);
+            mock_event_retrieval_service
+                .expect_get_event_occurrences()
+                .withf(|from, to| {
+                    *from == "2026-02-25T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                        && *to == "2026-03-12T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                })
+                .return_once(move |_, _| occurrences);
+            let service = new_event_duplicate_detection_service(
+                Arc::new(mock_event_retrieval_service),
+                DuplicateDetectionSettings::default(),
+            );
+            let actual_result = service
+                .find_similar_events(test_case.request)
+                .await
+                .map(summarise);
+            let expected_result = test_case.expected_result.map(|similar_events| {
+                similar_events
+                    .into_iter()
+                    .map(|(event_id, occurrence_date, is_duplicate)| {
+                        (event_id, occurrence_date.parse().unwrap(), is_duplicate)
+                    })
+                    .collect::<Vec<_>>()
+            });
+            assert_eq!(expected_result, actual_result);
+        }
+    }
+}
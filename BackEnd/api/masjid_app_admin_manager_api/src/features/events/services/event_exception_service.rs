@@ -0,0 +1,357 @@
+use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::services::errors::event_exception_error::EventExceptionError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use masjid_app_api_library::features::events::repositories::EventsRepository;
+use masjid_app_api_library::features::events::services::event_service_impl::EventServiceImpl;
+use masjid_app_api_library::features::events::services::occurrence_expansion::is_scheduled_occurrence;
+use masjid_app_api_library::new_event_service;
+use masjid_app_api_library::shared::common_service_impl::CommonServiceImpl;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventExceptionService: Send + Sync {
+    /// Cancels or moves a single occurrence of an event, replacing any existing exception for it
+    async fn save_event_exception(
+        &self,
+        exception: EventExceptionDTO,
+    ) -> Result<(), EventExceptionError>;
+    async fn remove_event_exception(
+        &self,
+        event_id: i32,
+        occurrence_date: DateTime<Utc>,
+    ) -> Result<(), EventExceptionError>;
+}
+
+new_event_service!(
+    new_event_exception_service,
+    EventExceptionService,
+    EventsAdminRepository
+);
+
+#[async_trait]
+impl EventExceptionService for EventServiceImpl<dyn EventsAdminRepository> {
+    async fn save_event_exception(
+        &self,
+        exception: EventExceptionDTO,
+    ) -> Result<(), EventExceptionError> {
+        let events = self.common.repository.get_events(self.visibility).await?;
+        let event = events
+            .iter()
+            .find(|event| event.id == exception.event_id)
+            .ok_or(EventExceptionError::EventNotFound)?;
+        if !is_scheduled_occurrence(event, exception.occurrence_date) {
+            return Err(EventExceptionError::NotAnOccurrence);
+        }
+        // Saved to the database first, as moving the occurrence is rejected there when it clashes
+        // with another booking of the room
+        self.common
+            .repository
+            .upsert_event_exception(&exception)
+            .await?;
+        if let Err(upsert_error) = self
+            .common
+            .in_memory_repository
+            .upsert_event_exception(&exception)
+            .await
+        {
+            tracing::warn!(in_memory_upsertion_error = ?upsert_error, "upserting event exception into in-memory repository failed");
+        }
+        Ok(())
+    }
+
+    async fn remove_event_exception(
+        &self,
+        event_id: i32,
+        occurrence_date: DateTime<Utc>,
+    ) -> Result<(), EventExceptionError> {
+        if let Err(delete_error) = self
+            .common
+            .in_memory_repository
+            .delete_event_exception(&event_id, &occurrence_date)
+            .await
+        {
+            tracing::warn!(in_memory_repository_error = ?delete_error, "failure to delete event exception from in-memory repository");
+        }
+        self.common
+            .repository
+            .delete_event_exception(&event_id, &occurrence_date)
+            .await
+            .map_err(EventExceptionError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::booking_conflict::BookingConflict;
+    use crate::features::events::models::saved_event::SavedEvent;
+    use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
+    use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+    use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+    use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+    use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+    use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+    use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+    use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
+    use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+    use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+    use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+    use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
+    use masjid_app_api_library::features::events::models::event::Event;
+    use masjid_app_api_library::features::events::models::event_category::EventCategory;
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+    use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+    use masjid_app_api_library::features::events::models::events_page::EventsPage;
+    use masjid_app_api_library::features::events::models::room::Room;
+    use masjid_app_api_library::features::events::models::speaker::Speaker;
+    use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+    use mockall::mock;
+
+    mock!(
+        pub EventsAdminRepository {}
+
+        #[async_trait]
+        impl EventsRepository for EventsAdminRepository {
+            async fn get_events(&self, visibility: EventVisibility) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_filtered_events(&self, filter: &EventsFilter) -> Result<EventsPage, GetEventsRepositoryError>;
+            async fn get_event_exceptions(&self) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+            async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+            async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+            async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+            async fn get_tags(&self, visibility: EventVisibility) -> Result<Vec<String>, GetEventsRepositoryError>;
+        }
+        #[async_trait]
+        impl EventsAdminRepository for EventsAdminRepository {
+            async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+            async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
+            async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+            async fn get_image_url_by_event_id(&self, event_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn get_event_status_by_id(&self, event_id: &i32) -> Result<EventStatus, GetEventsRepositoryError>;
+            async fn update_event_status(&self, event_id: &i32, status: &EventStatus, version: &u32) -> Result<u32, UpdateEventError>;
+            async fn upsert_event_exception(&self, exception: &EventExceptionDTO) -> Result<(), UpsertEventExceptionError>;
+            async fn delete_event_exception(&self, event_id: &i32, occurrence_date: &DateTime<Utc>) -> Result<(), DeleteEventExceptionError>;
+            async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+            async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+            async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+            async fn get_photo_url_by_speaker_id(&self, speaker_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn is_image_url_referenced(&self, image_url: &str) -> Result<bool, GetEventsRepositoryError>;
+            async fn delete_speaker_by_id(&self, speaker_id: &i32) -> Result<Option<String>, DeleteSpeakerError>;
+            async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+            async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
+        }
+    );
+
+    #[tokio::test]
+    async fn test_event_exception_service_save_event_exception() {
+        struct TestCase {
+            description: &'static str,
+            exception: EventExceptionDTO,
+            expected_get_events_response: Result<Vec<EventDTO>, GetEventsRepositoryError>,
+            expected_db_response: Option<Result<(), UpsertEventExceptionError>>,
+            expected_result: Result<(), EventExceptionError>,
+        }
+        let events = vec![EventDTO {
+            id: 1,
+            title: "weekly halaqa".to_owned(),
+            description: None,
+            date: "2025-01-06T19:00:00Z".parse().unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::Weekly,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }];
+        let cancellation = EventExceptionDTO {
+            event_id: 1,
+            occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+            replacement_date: None,
+        };
+        let booking_conflict = BookingConflict {
+            event_id: 2,
+            title: "Evening talk".to_owned(),
+            room_id: 1,
+            starts_at: "2025-01-14T19:00:00Z".parse().unwrap(),
+            ends_at: "2025-01-14T20:00:00Z".parse().unwrap(),
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the event does not exist, I should receive an event not found error",
+                exception: EventExceptionDTO {
+                    event_id: 2,
+                    ..cancellation.clone()
+                },
+                expected_get_events_response: Ok(events.clone()),
+                expected_db_response: None,
+                expected_result: Err(EventExceptionError::EventNotFound),
+            },
+            TestCase {
+                description: "When the date is not an occurrence of the event, I should receive a not an occurrence error",
+                exception: EventExceptionDTO {
+                    occurrence_date: "2025-01-14T19:00:00Z".parse().unwrap(),
+                    ..cancellation.clone()
+                },
+                expected_get_events_response: Ok(events.clone()),
+                expected_db_response: None,
+                expected_result: Err(EventExceptionError::NotAnOccurrence),
+            },
+            TestCase {
+                description: "When the occurrence is moved to when its room is already booked, I should receive a room conflict error",
+                exception: EventExceptionDTO {
+                    replacement_date: Some("2025-01-14T19:00:00Z".parse().unwrap()),
+                    ..cancellation.clone()
+                },
+                expected_get_events_response: Ok(events.clone()),
+                expected_db_response: Some(Err(UpsertEventExceptionError::RoomConflict(
+                    booking_conflict.clone(),
+                ))),
+                expected_result: Err(EventExceptionError::RoomConflict(booking_conflict)),
+            },
+            TestCase {
+                description: "When saving the exception fails, I should receive an error",
+                exception: cancellation.clone(),
+                expected_get_events_response: Ok(events.clone()),
+                expected_db_response: Some(Err(
+                    UpsertEventExceptionError::UnableToUpsertEventException,
+                )),
+                expected_result: Err(EventExceptionError::UnableToUpdateEventExceptions),
+            },
+            TestCase {
+                description: "When saving the exception succeeds, I should receive no error",
+                exception: cancellation,
+                expected_get_events_response: Ok(events),
+                expected_db_response: Some(Ok(())),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            let mut mock_repository = MockEventsAdminRepository::new();
+
+            mock_repository
+                .expect_get_events()
+                .return_once(move |_| test_case.expected_get_events_response);
+            if let Some(expected_db_response) = test_case.expected_db_response {
+                mock_in_memory_repository
+                    .expect_upsert_event_exception()
+                    .return_once(|_| Ok(()));
+                mock_repository
+                    .expect_upsert_event_exception()
+                    .return_once(move |_| expected_db_response);
+            }
+
+            let service = new_event_exception_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            let actual_result = service.save_event_exception(test_case.exception).await;
+            if let Err(EventExceptionError::RoomConflict(expected_conflict)) =
+                &test_case.expected_result
+            {
+                assert!(matches!(
+                    &actual_result,
+                    Err(EventExceptionError::RoomConflict(actual_conflict)) if actual_conflict == expected_conflict
+                ));
+            }
+            assert!(matches!(
+                (test_case.expected_result, actual_result),
+                (Ok(()), Ok(()))
+                    | (
+                        Err(EventExceptionError::EventNotFound),
+                        Err(EventExceptionError::EventNotFound)
+                    )
+                    | (
+                        Err(EventExceptionError::NotAnOccurrence),
+                        Err(EventExceptionError::NotAnOccurrence)
+                    )
+                    | (
+                        Err(EventExceptionError::UnableToUpdateEventExceptions),
+                        Err(EventExceptionError::UnableToUpdateEventExceptions)
+                    )
+                    | (
+                        Err(EventExceptionError::RoomConflict(_)),
+                        Err(EventExceptionError::RoomConflict(_))
+                    )
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_exception_service_remove_event_exception() {
+        struct TestCase {
+            description: &'static str,
+            expected_db_response: Result<(), DeleteEventExceptionError>,
+            expected_result: Result<(), EventExceptionError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the exception does not exist, I should receive a not found error",
+                expected_db_response: Err(DeleteEventExceptionError::EventExceptionNotFound),
+                expected_result: Err(EventExceptionError::EventExceptionNotFound),
+            },
+            TestCase {
+                description: "When removing the exception succeeds, I should receive no error",
+                expected_db_response: Ok(()),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            let mut mock_repository = MockEventsAdminRepository::new();
+
+            mock_in_memory_repository
+                .expect_delete_event_exception()
+                .return_once(|_, _| Ok(()));
+            mock_repository
+                .expect_delete_event_exception()
+                .return_once(move |_, _| test_case.expected_db_response);
+
+            let service = new_event_exception_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            let actual_result = service
+                .remove_event_exception(1, "2025-01-13T19:00:00Z".parse().unwrap())
+                .await;
+            assert!(matches!(
+                (test_case.expected_result, actual_result),
+                (Ok(()), Ok(()))
+                    | (
+                        Err(EventExceptionError::EventExceptionNotFound),
+                        Err(EventExceptionError::EventExceptionNotFound)
+                    )
+            ));
+        }
+    }
+}
@@ -0,0 +1,685 @@
+use crate::features::events::events_csv::events_from_csv;
+use crate::features::events::models::event_import_report::EventImportReport;
+use crate::features::events::models::event_import_row_error::EventImportRowError;
+use crate::features::events::models::events_file_format::EventsFileFormat;
+use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+use crate::features::events::repositories::errors::insert_event_error::InsertEventError;
+use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+use crate::features::events::services::booking_conflicts::find_booking_conflict;
+use crate::features::events::services::errors::event_import_error::EventImportError;
+use crate::features::events::services::event_caching::cache_saved_events;
+use async_trait::async_trait;
+use chrono::Utc;
+use masjid_app_api_library::features::events::models::event::Event;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+use masjid_app_api_library::features::events::repositories::EventsRepository;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use masjid_app_api_library::features::events::services::event_service_impl::EventServiceImpl;
+use masjid_app_api_library::new_event_service;
+use masjid_app_api_library::shared::common_service_impl::CommonServiceImpl;
+use mockall::automock;
+use std::collections::HashSet;
+use std::sync::Arc;
+use validator::Validate;
+
+#[automock]
+#[async_trait]
+pub trait EventImportService: Send + Sync {
+    /// Checks every event in the file, then saves all of them together unless this is a dry run
+    /// or any of them have errors
+    async fn import_events(
+        &self,
+        format: EventsFileFormat,
+        file: String,
+        dry_run: bool,
+    ) -> Result<EventImportReport, EventImportError>;
+    /// The ids of the existing events the file updates, which is empty when the file cannot be
+    /// read
+    async fn find_updated_event_ids(&self, format: EventsFileFormat, file: &str) -> Vec<i32>;
+}
+
+new_event_service!(
+    new_event_import_service,
+    EventImportService,
+    EventsAdminRepository
+);
+
+#[async_trait]
+impl EventImportService for EventServiceImpl<dyn EventsAdminRepository> {
+    async fn import_events(
+        &self,
+        format: EventsFileFormat,
+        file: String,
+        dry_run: bool,
+    ) -> Result<EventImportReport, EventImportError> {
+        let rows = events_from_file(format, &file).map_err(EventImportError::InvalidFile)?;
+
+        let mut errors: Vec<EventImportRowError> = Vec::new();
+        let mut events: Vec<(usize, EventDTO)> = Vec::new();
+        for (index, row) in rows.into_iter().enumerate() {
+            match row {
+                Ok(event) => events.push((index + 1, event)),
+                Err(row_errors) => errors.push(EventImportRowError {
+                    row: index + 1,
+                    errors: row_errors,
+                }),
+            }
+        }
+        let check_errors =
+            check_events(self.common.repository.as_ref(), self.visibility, &events).await?;
+        errors.extend(check_errors);
+        errors.sort_by_key(|error| error.row);
+        if dry_run || !errors.is_empty() || events.is_empty() {
+            return Ok(EventImportReport {
+                dry_run,
+                imported: 0,
+                event_ids: Vec::new(),
+                errors,
+            });
+        }
+
+        let (rows, events): (Vec<usize>, Vec<Event>) = events
+            .into_iter()
+            .map(|(row, event)| (row, event.into()))
+            .unzip();
+        match self.common.repository.import_events(&events).await {
+            Ok(saved_events) => {
+                cache_saved_events(
+                    self.common.in_memory_repository.as_ref(),
+                    &events,
+                    &saved_events,
+                )
+                .await;
+                Ok(EventImportReport {
+                    dry_run,
+                    imported: events.len(),
+                    event_ids: saved_events
+                        .iter()
+                        .map(|saved_event| saved_event.id)
+                        .collect(),
+                    errors,
+                })
+            }
+            Err(ImportEventsError::EventRejected { index, error }) => {
+                let message = rejection_message(&events[index], &error)
+                    .ok_or(EventImportError::UnableToImportEvents)?;
+                Ok(EventImportReport {
+                    dry_run,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: vec![EventImportRowError {
+                        row: rows[index],
+                        errors: vec![message],
+                    }],
+                })
+            }
+            Err(ImportEventsError::UnableToImportEvents) => {
+                Err(EventImportError::UnableToImportEvents)
+            }
+        }
+    }
+
+    async fn find_updated_event_ids(&self, format: EventsFileFormat, file: &str) -> Vec<i32> {
+        events_from_file(format, file)
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .map(|event| event.id)
+            .filter(|event_id| *event_id != 0)
+            .collect()
+    }
+}
+
+fn events_from_file(
+    format: EventsFileFormat,
+    file: &str,
+) -> Result<Vec<Result<EventDTO, Vec<String>>>, String> {
+    match format {
+        EventsFileFormat::Csv => events_from_csv(file),
+        EventsFileFormat::Json => events_from_json(file),
+    }
+}
+
+/// Reads events from a JSON array, so that one malformed event does not hide problems with the
+/// rest of the file
+fn events_from_json(json: &str) -> Result<Vec<Result<EventDTO, Vec<String>>>, String> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(json)
+        .map_err(|err| format!("the file is not a JSON array of events: {}", err))?;
+    Ok(values
+        .into_iter()
+        .map(|value| serde_json::from_value(value).map_err(|err| vec![err.to_string()]))
+        .collect())
+}
+
+/// Applies the same rules as publishing events one at a time, with events earlier in the file
+/// counting as room bookings for the events after them
+async fn check_events(
+    repository: &dyn EventsAdminRepository,
+    visibility: EventVisibility,
+    events: &[(usize, EventDTO)],
+) -> Result<Vec<EventImportRowError>, EventImportError> {
+    let imported_ids: HashSet<i32> = events
+        .iter()
+        .map(|(_, event)| event.id)
+        .filter(|id| *id != 0)
+        .collect();
+    let (mut bookings, exceptions) = if events
+        .iter()
+        .any(|(_, event)| event.event_details.room_id.is_some())
+    {
+        let bookings = match repository.get_events(visibility).await {
+            Ok(events) => events,
+            Err(GetEventsRepositoryError::EventsNotFound) => Vec::new(),
+            Err(err) => {
+                tracing::error!(error = ?err, "unable to retrieve events to check room availability");
+                return Err(EventImportError::UnableToValidateEvents);
+            }
+        };
+        let exceptions = repository.get_event_exceptions().await.map_err(|err| {
+            tracing::error!(error = ?err, "unable to retrieve event exceptions to check room availability");
+            EventImportError::UnableToValidateEvents
+        })?;
+        (bookings, exceptions)
+    } else {
+        (Vec::new(), Vec::<EventExceptionDTO>::new())
+    };
+    // Events being replaced by the file no longer hold their old bookings
+    bookings.retain(|booking| !imported_ids.contains(&booking.id));
+
+    let mut errors: Vec<EventImportRowError> = Vec::new();
+    let mut seen_ids: HashSet<i32> = HashSet::new();
+    for (row, event) in events {
+        let mut row_errors: Vec<String> = Vec::new();
+        if let Err(validation_errors) = event.validate() {
+            row_errors.push(validation_errors.to_string());
+        }
+        let next_status = &event.event_details.event_status;
+        if event.id == 0 {
+            if !next_status.is_valid_initial_status() {
+                row_errors.push(format!(
+                    "events cannot be created as {}",
+                    next_status.to_string()
+                ));
+            }
+        } else if !seen_ids.insert(event.id) {
+            row_errors.push(format!("event {} appears more than once", event.id));
+        } else if event.version == 0 {
+            row_errors.push(format!(
+                "event {} is missing the version it was exported at",
+                event.id
+            ));
+        } else {
+            match repository.get_event_status_by_id(&event.id).await {
+                Ok(current_status) if !current_status.can_transition_to(next_status) => {
+                    row_errors.push(format!(
+                        "events cannot be moved from {} to {}",
+                        current_status.to_string(),
+                        next_status.to_string()
+                    ));
+                }
+                Ok(_) => {}
+                Err(GetEventsRepositoryError::EventsNotFound) => {
+                    row_errors.push(format!("event {} does not exist", event.id));
+                }
+                Err(GetEventsRepositoryError::UnableToGetEvents) => {
+                    return Err(EventImportError::UnableToValidateEvents);
+                }
+            }
+        }
+        // New events all have an id of 0, which would stop them being compared with each other
+        let booking = EventDTO {
+            id: if event.id == 0 {
+                -(*row as i32)
+            } else {
+                event.id
+            },
+            ..event.clone()
+        };
+        if let Some(conflict) = find_booking_conflict(&booking, &bookings, &exceptions, Utc::now())
+        {
+            row_errors.push(if conflict.event_id < 0 {
+                format!(
+                    "the room is already booked by row {} at {}",
+                    -conflict.event_id,
+                    conflict.starts_at.to_rfc3339()
+                )
+            } else {
+                format!(
+                    "the room is already booked by {} (event {}) at {}",
+                    conflict.title,
+                    conflict.event_id,
+                    conflict.starts_at.to_rfc3339()
+                )
+            });
+        }
+        if row_errors.is_empty() {
+            bookings.push(booking);
+        } else {
+            errors.push(EventImportRowError {
+                row: *row,
+                errors: row_errors,
+            });
+        }
+    }
+    Ok(errors)
+}
+
+/// Explains why the database rejected an event, when the problem is with the event itself
+/// rather than the database
+fn rejection_message(event: &Event, error: &UpsertEventError) -> Option<String> {
+    match error {
+        UpsertEventError::EventAlreadyExists => Some(
+            "an event with the same title already starts on that day in the same room".to_owned(),
+        ),
+        UpsertEventError::UpdateError(UpdateEventError::EventNotFound) => {
+            Some(format!("event {} does not exist", event.id))
+        }
+        UpsertEventError::UpdateError(UpdateEventError::VersionMismatch) => Some(format!(
+            "event {} has been changed since version {} was exported",
+            event.id, event.version
+        )),
+        UpsertEventError::RoomNotFound => Some("the room does not exist".to_owned()),
+        UpsertEventError::CategoryNotFound => Some("the category does not exist".to_owned()),
+        UpsertEventError::SpeakerNotFound => Some("one of the speakers does not exist".to_owned()),
+        UpsertEventError::RoomConflict(conflict) => Some(format!(
+            "the room is already booked by {} (event {}) at {}",
+            conflict.title,
+            conflict.event_id,
+            conflict.starts_at.to_rfc3339()
+        )),
+        UpsertEventError::InsertError(InsertEventError::UnableToInsertEvent)
+        | UpsertEventError::UpdateError(UpdateEventError::UnableToUpdateEvent)
+        | UpsertEventError::UnableToUpsertEvent
+        | UpsertEventError::UnableToCheckRoomAvailability => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::saved_event::SavedEvent;
+    use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
+    use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+    use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+    use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+    use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+    use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
+    use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+    use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+    use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
+    use chrono::{DateTime, TimeZone};
+    use masjid_app_api_library::features::events::models::event_category::EventCategory;
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+    use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+    use masjid_app_api_library::features::events::models::events_page::EventsPage;
+    use masjid_app_api_library::features::events::models::room::Room;
+    use masjid_app_api_library::features::events::models::speaker::Speaker;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+    use mockall::mock;
+
+    mock!(
+        pub EventsAdminRepository {}
+
+        #[async_trait]
+        impl EventsRepository for EventsAdminRepository {
+            async fn get_events(&self, visibility: EventVisibility) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_filtered_events(&self, filter: &EventsFilter) -> Result<EventsPage, GetEventsRepositoryError>;
+            async fn get_event_exceptions(&self) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+            async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+            async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+            async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+            async fn get_tags(&self, visibility: EventVisibility) -> Result<Vec<String>, GetEventsRepositoryError>;
+        }
+        #[async_trait]
+        impl EventsAdminRepository for EventsAdminRepository {
+            async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+            async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
+            async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+            async fn get_image_url_by_event_id(&self, event_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn get_event_status_by_id(&self, event_id: &i32) -> Result<EventStatus, GetEventsRepositoryError>;
+            async fn update_event_status(&self, event_id: &i32, status: &EventStatus, version: &u32) -> Result<u32, UpdateEventError>;
+            async fn upsert_event_exception(&self, exception: &EventExceptionDTO) -> Result<(), UpsertEventExceptionError>;
+            async fn delete_event_exception(&self, event_id: &i32, occurrence_date: &DateTime<Utc>) -> Result<(), DeleteEventExceptionError>;
+            async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+            async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+            async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+            async fn get_photo_url_by_speaker_id(&self, speaker_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn is_image_url_referenced(&self, image_url: &str) -> Result<bool, GetEventsRepositoryError>;
+            async fn delete_speaker_by_id(&self, speaker_id: &i32) -> Result<Option<String>, DeleteSpeakerError>;
+            async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+            async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
+        }
+    );
+
+    fn event(id: i32, room_id: Option<i32>) -> EventDTO {
+        EventDTO {
+            id,
+            title: "Tajweed class".to_owned(),
+            description: None,
+            date: Utc.with_ymd_and_hms(2099, 1, 5, 17, 30, 0).unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id,
+                duration_minutes: room_id.map(|_| 60),
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "John Smith".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }
+    }
+
+    fn json(events: &[EventDTO]) -> String {
+        serde_json::to_string(events).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_event_import_service_import_events() {
+        struct TestCase {
+            description: &'static str,
+            format: EventsFileFormat,
+            file: String,
+            dry_run: bool,
+            expected_status_response: Option<Result<EventStatus, GetEventsRepositoryError>>,
+            expected_booked_events: Option<Vec<EventDTO>>,
+            expected_import_response: Option<Result<Vec<SavedEvent>, ImportEventsError>>,
+            expected_result: Result<EventImportReport, EventImportError>,
+        }
+        let archived_event = EventDTO {
+            event_details: EventDetails {
+                event_status: EventStatus::Archived,
+                ..event(0, None).event_details
+            },
+            ..event(0, None)
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the file is not JSON, I should get an invalid file error",
+                format: EventsFileFormat::Json,
+                file: String::new(),
+                dry_run: false,
+                expected_status_response: None,
+                expected_booked_events: None,
+                expected_import_response: None,
+                expected_result: Err(EventImportError::InvalidFile(
+                    "the file is not a JSON array of events: EOF while parsing a value at line 1 column 0"
+                        .to_owned(),
+                )),
+            },
+            TestCase {
+                description: "When some events are invalid, every error should be reported and nothing should be imported",
+                format: EventsFileFormat::Json,
+                file: format!(
+                    "[{}, {{\"title\": \"Iftar\"}}, {}]",
+                    serde_json::to_string(&event(0, None)).unwrap(),
+                    serde_json::to_string(&archived_event).unwrap()
+                ),
+                dry_run: false,
+                expected_status_response: None,
+                expected_booked_events: None,
+                expected_import_response: None,
+                expected_result: Ok(EventImportReport {
+                    dry_run: false,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: vec![
+                        EventImportRowError {
+                            row: 2,
+                            errors: vec!["missing field `id`".to_owned()],
+                        },
+                        EventImportRowError {
+                            row: 3,
+                            errors: vec!["events cannot be created as archived".to_owned()],
+                        },
+                    ],
+                }),
+            },
+            TestCase {
+                description: "When an existing event in the file does not exist, it should be reported",
+                format: EventsFileFormat::Json,
+                file: json(&[event(7, None)]),
+                dry_run: false,
+                expected_status_response: Some(Err(GetEventsRepositoryError::EventsNotFound)),
+                expected_booked_events: None,
+                expected_import_response: None,
+                expected_result: Ok(EventImportReport {
+                    dry_run: false,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: vec![EventImportRowError {
+                        row: 1,
+                        errors: vec!["event 7 does not exist".to_owned()],
+                    }],
+                }),
+            },
+            TestCase {
+                description: "When an existing event in the file has no version, it should be reported",
+                format: EventsFileFormat::Json,
+                file: json(&[EventDTO {
+                    version: 0,
+                    ..event(7, None)
+                }]),
+                dry_run: false,
+                expected_status_response: None,
+                expected_booked_events: None,
+                expected_import_response: None,
+                expected_result: Ok(EventImportReport {
+                    dry_run: false,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: vec![EventImportRowError {
+                        row: 1,
+                        errors: vec!["event 7 is missing the version it was exported at".to_owned()],
+                    }],
+                }),
+            },
+            TestCase {
+                description: "When two new events in the file book the same room at the same time, the later one should be reported",
+                format: EventsFileFormat::Json,
+                file: json(&[event(0, Some(1)), event(0, Some(1))]),
+                dry_run: false,
+                expected_status_response: None,
+                expected_booked_events: Some(Vec::new()),
+                expected_import_response: None,
+                expected_result: Ok(EventImportReport {
+                    dry_run: false,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: vec![EventImportRowError {
+                        row: 2,
+                        errors: vec![
+                            "the room is already booked by row 1 at 2099-01-05T17:30:00+00:00"
+                                .to_owned(),
+                        ],
+                    }],
+                }),
+            },
+            TestCase {
+                description: "When the file is valid but this is a dry run, nothing should be imported",
+                format: EventsFileFormat::Json,
+                file: json(&[event(0, Some(1))]),
+                dry_run: true,
+                expected_status_response: None,
+                expected_booked_events: Some(vec![event(3, Some(2))]),
+                expected_import_response: None,
+                expected_result: Ok(EventImportReport {
+                    dry_run: true,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: Vec::new(),
+                }),
+            },
+            TestCase {
+                description: "When the database rejects one of the events, it should be reported against its row",
+                format: EventsFileFormat::Json,
+                file: json(&[event(0, None), event(0, None)]),
+                dry_run: false,
+                expected_status_response: None,
+                expected_booked_events: None,
+                expected_import_response: Some(Err(ImportEventsError::EventRejected {
+                    index: 1,
+                    error: UpsertEventError::SpeakerNotFound,
+                })),
+                expected_result: Ok(EventImportReport {
+                    dry_run: false,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: vec![EventImportRowError {
+                        row: 2,
+                        errors: vec!["one of the speakers does not exist".to_owned()],
+                    }],
+                }),
+            },
+            TestCase {
+                description: "When an existing event has been changed since it was exported, it should be reported against its row",
+                format: EventsFileFormat::Json,
+                file: json(&[event(7, None)]),
+                dry_run: false,
+                expected_status_response: Some(Ok(EventStatus::Confirmed)),
+                expected_booked_events: None,
+                expected_import_response: Some(Err(ImportEventsError::EventRejected {
+                    index: 0,
+                    error: UpsertEventError::UpdateError(UpdateEventError::VersionMismatch),
+                })),
+                expected_result: Ok(EventImportReport {
+                    dry_run: false,
+                    imported: 0,
+                    event_ids: Vec::new(),
+                    errors: vec![EventImportRowError {
+                        row: 1,
+                        errors: vec![
+                            "event 7 has been changed since version 1 was exported".to_owned(),
+                        ],
+                    }],
+                }),
+            },
+            TestCase {
+                description: "When saving the events fails, I should get an unable to import events error",
+                format: EventsFileFormat::Json,
+                file: json(&[event(0, None)]),
+                dry_run: false,
+                expected_status_response: None,
+                expected_booked_events: None,
+                expected_import_response: Some(Err(ImportEventsError::UnableToImportEvents)),
+                expected_result: Err(EventImportError::UnableToImportEvents),
+            },
+            TestCase {
+                description: "When every event is valid, they should all be imported",
+                format: EventsFileFormat::Csv,
+                file: "title,date,eventType,eventRecurrence,eventStatus,contactFullName,contactPhoneNumber\n\
+                    Tajweed class,2099-01-05T17:30:00Z,class,oneoff,confirmed,John Smith,07123456789\n\
+                    Tajweed class,2099-01-12T17:30:00Z,class,oneoff,draft,John Smith,07123456789\n"
+                    .to_owned(),
+                dry_run: false,
+                expected_status_response: None,
+                expected_booked_events: None,
+                expected_import_response: Some(Ok(vec![
+                    SavedEvent { id: 1, version: 1 },
+                    SavedEvent { id: 2, version: 1 },
+                ])),
+                expected_result: Ok(EventImportReport {
+                    dry_run: false,
+                    imported: 2,
+                    event_ids: vec![1, 2],
+                    errors: Vec::new(),
+                }),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventsAdminRepository::new();
+            if let Some(expected_status_response) = test_case.expected_status_response {
+                mock_repository
+                    .expect_get_event_status_by_id()
+                    .return_once(move |_| expected_status_response);
+            }
+            // Events are read to check rooms are free
+            let expected_booked_events = test_case.expected_booked_events;
+            mock_repository.expect_get_events().returning(move |_| {
+                expected_booked_events
+                    .clone()
+                    .ok_or(GetEventsRepositoryError::EventsNotFound)
+            });
+            mock_repository
+                .expect_get_event_exceptions()
+                .returning(|| Ok(Vec::new()));
+            // Imported events are cached with the ids and versions they were saved with
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            if let Some(Ok(saved_events)) = &test_case.expected_import_response {
+                let saved_events = saved_events.clone();
+                mock_in_memory_repository
+                    .expect_import_events()
+                    .withf(move |events| {
+                        events
+                            .iter()
+                            .map(|event| SavedEvent {
+                                id: event.id,
+                                version: event.version,
+                            })
+                            .eq(saved_events.iter().copied())
+                    })
+                    .returning(|_| Ok(Vec::new()));
+            }
+            if let Some(expected_import_response) = test_case.expected_import_response {
+                mock_repository
+                    .expect_import_events()
+                    .return_once(move |_| expected_import_response);
+            }
+            let service = new_event_import_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            assert_eq!(
+                test_case.expected_result,
+                service
+                    .import_events(test_case.format, test_case.file, test_case.dry_run)
+                    .await
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_import_service_find_updated_event_ids() {
+        let service = new_event_import_service(
+            Arc::new(MockEventsAdminRepository::new()),
+            Arc::new(MockEventsAdminRepository::new()),
+        );
+        let file = json(&[event(0, None), event(4, None)]);
+        assert_eq!(
+            vec![4],
+            service
+                .find_updated_event_ids(EventsFileFormat::Json, &file)
+                .await
+        );
+        assert_eq!(
+            Vec::<i32>::new(),
+            service
+                .find_updated_event_ids(EventsFileFormat::Json, "not an array")
+                .await
+        );
+    }
+}
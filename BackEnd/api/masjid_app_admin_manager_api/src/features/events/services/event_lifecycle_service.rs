@@ -0,0 +1,339 @@
+use crate::features::events::models::saved_event::SavedEvent;
+use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::services::errors::event_lifecycle_error::EventLifecycleError;
+use crate::features::events::services::event_caching::cache_saved_events;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use masjid_app_api_library::features::events::models::event::Event;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+use masjid_app_api_library::features::events::models::event_status::EventStatus;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use masjid_app_api_library::features::events::services::event_service_impl::EventServiceImpl;
+use masjid_app_api_library::features::events::services::occurrence_expansion::last_occurrence;
+use masjid_app_api_library::new_event_service;
+use masjid_app_api_library::shared::common_service_impl::CommonServiceImpl;
+use mockall::automock;
+use std::sync::Arc;
+
+/// How long an event stays visible after its final occurrence before it is archived
+const ARCHIVE_GRACE_PERIOD_DAYS: i64 = 7;
+
+#[automock]
+#[async_trait]
+pub trait EventLifecycleService: Send + Sync {
+    /// Publishes scheduled events whose publish time has been reached and archives events which
+    /// finished longer ago than the grace period, returning how many events were updated
+    async fn apply_status_transitions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, EventLifecycleError>;
+}
+
+new_event_service!(
+    new_event_lifecycle_service,
+    EventLifecycleService,
+    EventsAdminRepository
+);
+
+fn next_status(
+    event: &EventDTO,
+    exceptions: &[EventExceptionDTO],
+    now: DateTime<Utc>,
+) -> Option<EventStatus> {
+    match event.event_details.event_status {
+        EventStatus::Scheduled => event
+            .event_details
+            .publish_at
+            .is_some_and(|publish_at| publish_at <= now)
+            .then_some(EventStatus::Confirmed),
+        // Postponed events are left alone, as their original dates no longer apply
+        EventStatus::Confirmed | EventStatus::Cancelled => last_occurrence(event, exceptions)
+            .is_some_and(|last_occurrence| {
+                last_occurrence + TimeDelta::days(ARCHIVE_GRACE_PERIOD_DAYS) < now
+            })
+            .then_some(EventStatus::Archived),
+        EventStatus::Draft | EventStatus::Postponed | EventStatus::Archived => None,
+    }
+}
+
+#[async_trait]
+impl EventLifecycleService for EventServiceImpl<dyn EventsAdminRepository> {
+    async fn apply_status_transitions(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<usize, EventLifecycleError> {
+        let events = match self.common.repository.get_events(self.visibility).await {
+            Ok(events) => events,
+            Err(GetEventsRepositoryError::EventsNotFound) => return Ok(0),
+            Err(err) => return Err(err.into()),
+        };
+        let exceptions = self.common.repository.get_event_exceptions().await?;
+
+        let mut updated_events = 0;
+        for event in &events {
+            let Some(status) = next_status(event, &exceptions, now) else {
+                continue;
+            };
+            // A failure is retried on the next run, so it does not stop the remaining events
+            match self
+                .common
+                .repository
+                .update_event_status(&event.id, &status, &event.version)
+                .await
+            {
+                Ok(version) => {
+                    tracing::info!(
+                        event_id = event.id,
+                        status = status.to_string(),
+                        "event status updated"
+                    );
+                    updated_events += 1;
+                    // Only cached once the database has accepted the change, so that the cache
+                    // never holds a status the database does not
+                    let mut updated_event = event.clone();
+                    updated_event.event_details.event_status = status;
+                    cache_saved_events(
+                        self.common.in_memory_repository.as_ref(),
+                        &[Event::from(updated_event)],
+                        &[SavedEvent {
+                            id: event.id,
+                            version,
+                        }],
+                    )
+                    .await;
+                }
+                Err(err) => {
+                    tracing::error!(error = ?err, event_id = event.id, "unable to update event status")
+                }
+            }
+        }
+        Ok(updated_events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::saved_event::SavedEvent;
+    use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
+    use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+    use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+    use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+    use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+    use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+    use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+    use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
+    use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+    use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+    use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+    use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
+    use masjid_app_api_library::features::events::models::event::Event;
+    use masjid_app_api_library::features::events::models::event_category::EventCategory;
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+    use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+    use masjid_app_api_library::features::events::models::events_page::EventsPage;
+    use masjid_app_api_library::features::events::models::room::Room;
+    use masjid_app_api_library::features::events::models::speaker::Speaker;
+    use masjid_app_api_library::features::events::repositories::EventsRepository;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+    use mockall::mock;
+
+    mock!(
+        pub EventsAdminRepository {}
+
+        #[async_trait]
+        impl EventsRepository for EventsAdminRepository {
+            async fn get_events(&self, visibility: EventVisibility) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_filtered_events(&self, filter: &EventsFilter) -> Result<EventsPage, GetEventsRepositoryError>;
+            async fn get_event_exceptions(&self) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+            async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+            async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+            async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+            async fn get_tags(&self, visibility: EventVisibility) -> Result<Vec<String>, GetEventsRepositoryError>;
+        }
+        #[async_trait]
+        impl EventsAdminRepository for EventsAdminRepository {
+            async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+            async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
+            async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+            async fn get_image_url_by_event_id(&self, event_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn get_event_status_by_id(&self, event_id: &i32) -> Result<EventStatus, GetEventsRepositoryError>;
+            async fn update_event_status(&self, event_id: &i32, status: &EventStatus, version: &u32) -> Result<u32, UpdateEventError>;
+            async fn upsert_event_exception(&self, exception: &EventExceptionDTO) -> Result<(), UpsertEventExceptionError>;
+            async fn delete_event_exception(&self, event_id: &i32, occurrence_date: &DateTime<Utc>) -> Result<(), DeleteEventExceptionError>;
+            async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+            async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+            async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+            async fn get_photo_url_by_speaker_id(&self, speaker_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn is_image_url_referenced(&self, image_url: &str) -> Result<bool, GetEventsRepositoryError>;
+            async fn delete_speaker_by_id(&self, speaker_id: &i32) -> Result<Option<String>, DeleteSpeakerError>;
+            async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+            async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
+        }
+    );
+
+    fn create_event(id: i32, event_status: EventStatus, publish_at: Option<&str>) -> EventDTO {
+        EventDTO {
+            id,
+            title: format!("event {}", id),
+            description: None,
+            date: "2025-01-06T19:00:00Z".parse().unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Talk,
+                event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
+                event_status,
+                publish_at: publish_at.map(|publish_at| publish_at.parse().unwrap()),
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_lifecycle_service_apply_status_transitions() {
+        struct TestCase {
+            description: &'static str,
+            now: &'static str,
+            expected_get_events_response: Result<Vec<EventDTO>, GetEventsRepositoryError>,
+            expected_updates: Vec<(i32, EventStatus)>,
+            expected_db_response: Result<u32, UpdateEventError>,
+            expected_result: Result<usize, EventLifecycleError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When there are no events, nothing should be updated",
+                now: "2025-01-01T00:00:00Z",
+                expected_get_events_response: Err(GetEventsRepositoryError::EventsNotFound),
+                expected_updates: Vec::new(),
+                expected_db_response: Ok(2),
+                expected_result: Ok(0),
+            },
+            TestCase {
+                description: "When the events cannot be retrieved, I should receive an error",
+                now: "2025-01-01T00:00:00Z",
+                expected_get_events_response: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_updates: Vec::new(),
+                expected_db_response: Ok(2),
+                expected_result: Err(EventLifecycleError::RepositoryError(
+                    GetEventsRepositoryError::UnableToGetEvents,
+                )),
+            },
+            TestCase {
+                description: "When a scheduled event's publish time has been reached, it should be confirmed",
+                now: "2025-01-01T00:00:00Z",
+                expected_get_events_response: Ok(vec![
+                    create_event(1, EventStatus::Scheduled, Some("2024-12-31T12:00:00Z")),
+                    create_event(2, EventStatus::Scheduled, Some("2025-01-02T12:00:00Z")),
+                    create_event(3, EventStatus::Draft, None),
+                ]),
+                expected_updates: vec![(1, EventStatus::Confirmed)],
+                expected_db_response: Ok(2),
+                expected_result: Ok(1),
+            },
+            TestCase {
+                description: "When an event finished longer ago than the grace period, it should be archived",
+                now: "2025-01-14T00:00:00Z",
+                expected_get_events_response: Ok(vec![
+                    create_event(1, EventStatus::Confirmed, None),
+                    create_event(2, EventStatus::Cancelled, None),
+                    create_event(3, EventStatus::Postponed, None),
+                ]),
+                expected_updates: vec![(1, EventStatus::Archived), (2, EventStatus::Archived)],
+                expected_db_response: Ok(2),
+                expected_result: Ok(2),
+            },
+            TestCase {
+                description: "When the new status cannot be saved, the event should not be counted or cached",
+                now: "2025-01-01T00:00:00Z",
+                expected_get_events_response: Ok(vec![create_event(
+                    1,
+                    EventStatus::Scheduled,
+                    Some("2024-12-31T12:00:00Z"),
+                )]),
+                expected_updates: vec![(1, EventStatus::Confirmed)],
+                expected_db_response: Err(UpdateEventError::VersionMismatch),
+                expected_result: Ok(0),
+            },
+            TestCase {
+                description: "When an event finished within the grace period, it should be left alone",
+                now: "2025-01-10T00:00:00Z",
+                expected_get_events_response: Ok(vec![create_event(
+                    1,
+                    EventStatus::Confirmed,
+                    None,
+                )]),
+                expected_updates: Vec::new(),
+                expected_db_response: Ok(2),
+                expected_result: Ok(0),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            let mut mock_repository = MockEventsAdminRepository::new();
+
+            mock_repository
+                .expect_get_events()
+                .withf(|visibility| *visibility == EventVisibility::All)
+                .return_once(move |_| test_case.expected_get_events_response);
+            mock_repository
+                .expect_get_event_exceptions()
+                .return_once(|| Ok(Vec::new()));
+            let expected_updates = test_case.expected_updates;
+            let expected_cached_updates = if test_case.expected_db_response.is_ok() {
+                expected_updates.clone()
+            } else {
+                Vec::new()
+            };
+            mock_in_memory_repository
+                .expect_import_events()
+                .times(expected_cached_updates.len())
+                .withf(move |events| {
+                    events.iter().all(|event| {
+                        event.version == 2
+                            && expected_cached_updates.iter().any(|(event_id, status)| {
+                                event.id == *event_id && event.status == status.to_string()
+                            })
+                    })
+                })
+                .returning(|_| Ok(Vec::new()));
+            mock_repository
+                .expect_update_event_status()
+                .times(expected_updates.len())
+                .withf(move |event_id, status, version| {
+                    *version == 1 && expected_updates.contains(&(*event_id, status.clone()))
+                })
+                .returning(move |_, _, _| test_case.expected_db_response.clone());
+
+            let service = new_event_lifecycle_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            let actual_result = service
+                .apply_status_transitions(test_case.now.parse().unwrap())
+                .await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+}
@@ -1,8 +1,15 @@
+use crate::features::events::models::published_event::PublishedEvent;
 use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
 use crate::features::events::services::errors::event_publishing_error::EventPublishingError;
+use crate::features::events::services::event_caching::cache_saved_events;
+use crate::features::events::services::image_references::is_image_referenced;
 use async_trait::async_trait;
 use masjid_app_api_library::features::events::models::event::Event;
 use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::repositories::EventsRepository;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
 use masjid_app_api_library::features::events::services::event_service_impl::EventServiceImpl;
 use masjid_app_api_library::new_event_service;
 use masjid_app_api_library::shared::common_service_impl::CommonServiceImpl;
@@ -12,7 +19,11 @@ use std::sync::Arc;
 #[automock]
 #[async_trait]
 pub trait EventPublishingService: Send + Sync {
-    async fn publish_event(&self, event: EventDTO) -> Result<(), EventPublishingError>;
+    /// Returns the id and version the event was saved with, along with the url of any image it
+    /// used before that has since been replaced or removed, so that it can be cleaned up
+    async fn publish_event(&self, event: EventDTO) -> Result<PublishedEvent, EventPublishingError>;
+    /// Whether an uploaded image is still used by any event or speaker, so must not be deleted
+    async fn is_image_referenced(&self, image_url: &str) -> bool;
 }
 
 new_event_service!(
@@ -23,33 +34,101 @@ new_event_service!(
 
 #[async_trait]
 impl EventPublishingService for EventServiceImpl<dyn EventsAdminRepository> {
-    async fn publish_event(&self, event: EventDTO) -> Result<(), EventPublishingError> {
-        let event: Event = event.into();
-        if let Err(upsert_error) = self.common.in_memory_repository.upsert_event(&event).await {
-            tracing::warn!(in_memory_upsertion_error = ?upsert_error, "upserting event into in-memory repository failed");
+    async fn publish_event(&self, event: EventDTO) -> Result<PublishedEvent, EventPublishingError> {
+        let next_status = &event.event_details.event_status;
+        if event.id == 0 {
+            if !next_status.is_valid_initial_status() {
+                return Err(EventPublishingError::InvalidStatusTransition);
+            }
+        } else {
+            let current_status = self
+                .common
+                .repository
+                .get_event_status_by_id(&event.id)
+                .await
+                .map_err(|err| match err {
+                    GetEventsRepositoryError::EventsNotFound => {
+                        EventPublishingError::RepositoryError(UpsertEventError::UpdateError(
+                            UpdateEventError::EventNotFound,
+                        ))
+                    }
+                    GetEventsRepositoryError::UnableToGetEvents => {
+                        EventPublishingError::RepositoryError(UpsertEventError::UnableToUpsertEvent)
+                    }
+                })?;
+            if !current_status.can_transition_to(next_status) {
+                return Err(EventPublishingError::InvalidStatusTransition);
+            }
         }
-        self.common
+        let event: Event = event.into();
+        let previous_image_url = if event.id == 0 {
+            None
+        } else {
+            self.common
+                .repository
+                .get_image_url_by_event_id(&event.id)
+                .await
+                .unwrap_or_else(|err| {
+                    tracing::warn!(error = ?err, "unable to retrieve the current image url of the event");
+                    None
+                })
+        };
+        let saved_event = self
+            .common
             .repository
             .upsert_event(&event)
             .await
-            .map_err(EventPublishingError::from)
+            .map_err(EventPublishingError::from)?;
+        cache_saved_events(
+            self.common.in_memory_repository.as_ref(),
+            std::slice::from_ref(&event),
+            &[saved_event],
+        )
+        .await;
+        Ok(PublishedEvent {
+            saved_event,
+            replaced_image_url: previous_image_url
+                .filter(|previous_image_url| event.image_url.as_ref() != Some(previous_image_url)),
+        })
+    }
+
+    async fn is_image_referenced(&self, image_url: &str) -> bool {
+        is_image_referenced(self.common.repository.as_ref(), image_url).await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::events::models::booking_conflict::BookingConflict;
+    use crate::features::events::models::saved_event::SavedEvent;
+    use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
     use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+    use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+    use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+    use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+    use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+    use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
     use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+    use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+    use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+    use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
     use async_trait::async_trait;
-    use chrono::DateTime;
+    use chrono::{DateTime, Utc};
+    use masjid_app_api_library::features::events::models::event_category::EventCategory;
     use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
     use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
     use masjid_app_api_library::features::events::models::event_status::EventStatus;
     use masjid_app_api_library::features::events::models::event_type::EventType;
-    use masjid_app_api_library::features::events::repositories::EventsRepository;
+    use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+    use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+    use masjid_app_api_library::features::events::models::events_page::EventsPage;
+    use masjid_app_api_library::features::events::models::room::Room;
+    use masjid_app_api_library::features::events::models::speaker::Speaker;
     use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
     use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
     use mockall::mock;
 
     mock!(
@@ -57,12 +136,32 @@ mod tests {
 
         #[async_trait]
         impl EventsRepository for EventsAdminRepository {
-            async fn get_events(&self) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_events(&self, visibility: EventVisibility) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_filtered_events(&self, filter: &EventsFilter) -> Result<EventsPage, GetEventsRepositoryError>;
+            async fn get_event_exceptions(&self) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+            async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+            async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+            async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+            async fn get_tags(&self, visibility: EventVisibility) -> Result<Vec<String>, GetEventsRepositoryError>;
         }
         #[async_trait]
         impl EventsAdminRepository for EventsAdminRepository {
-            async fn upsert_event(&self, event: &Event) -> Result<(), UpsertEventError>;
+            async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+            async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
             async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+            async fn get_image_url_by_event_id(&self, event_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn get_event_status_by_id(&self, event_id: &i32) -> Result<EventStatus, GetEventsRepositoryError>;
+            async fn update_event_status(&self, event_id: &i32, status: &EventStatus, version: &u32) -> Result<u32, UpdateEventError>;
+            async fn upsert_event_exception(&self, exception: &EventExceptionDTO) -> Result<(), UpsertEventExceptionError>;
+            async fn delete_event_exception(&self, event_id: &i32, occurrence_date: &DateTime<Utc>) -> Result<(), DeleteEventExceptionError>;
+            async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+            async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+            async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+            async fn get_photo_url_by_speaker_id(&self, speaker_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn is_image_url_referenced(&self, image_url: &str) -> Result<bool, GetEventsRepositoryError>;
+            async fn delete_speaker_by_id(&self, speaker_id: &i32) -> Result<Option<String>, DeleteSpeakerError>;
+            async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+            async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
         }
     );
 
@@ -71,8 +170,11 @@ mod tests {
         struct TestCase {
             description: &'static str,
             event_dto: EventDTO,
-            expected_db_response: Result<(), UpsertEventError>,
-            expected_result: Result<(), EventPublishingError>,
+            expected_status_response: Option<Result<EventStatus, GetEventsRepositoryError>>,
+            expected_image_url_response: Option<Result<Option<String>, GetEventsRepositoryError>>,
+            expected_db_response: Result<SavedEvent, UpsertEventError>,
+            expected_cached_events: Vec<SavedEvent>,
+            expected_result: Result<PublishedEvent, EventPublishingError>,
         }
         let event_dto = EventDTO {
             id: 0,
@@ -82,31 +184,152 @@ mod tests {
             event_details: EventDetails {
                 event_type: EventType::Talk,
                 event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
                 event_status: EventStatus::Confirmed,
+                publish_at: None,
                 age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
                 image_url: None,
+                image_variants: None,
                 contact_details: ContactDetails {
                     full_name: "Zayd McArdle".to_owned(),
                     title: None,
                     phone_number: "07123456789".to_owned(),
                     email: None,
+                    visibility: ContactVisibility::default(),
                 },
             },
+            version: 1,
+        };
+        let mut existing_event_dto = event_dto.clone();
+        existing_event_dto.id = 1;
+        existing_event_dto.event_details.image_url = Some("/uploads/new.png".to_owned());
+        let saved_event = SavedEvent { id: 3, version: 1 };
+        let saved_existing_event = SavedEvent { id: 1, version: 2 };
+        let mut archived_event_dto = event_dto.clone();
+        archived_event_dto.event_details.event_status = EventStatus::Archived;
+        let mut booked_event_dto = event_dto.clone();
+        booked_event_dto.date = "2100-01-04T19:00:00Z".parse().unwrap();
+        booked_event_dto.event_details.room_id = Some(1);
+        booked_event_dto.event_details.duration_minutes = Some(60);
+        let booking_conflict = BookingConflict {
+            event_id: 2,
+            title: "weekly class".to_owned(),
+            room_id: 1,
+            starts_at: "2100-01-04T19:30:00Z".parse().unwrap(),
+            ends_at: "2100-01-04T20:30:00Z".parse().unwrap(),
         };
         let test_cases = [
+            TestCase {
+                description: "When a new event is created with a status it cannot start in, I should receive an invalid status transition error",
+                event_dto: archived_event_dto,
+                expected_status_response: None,
+                expected_image_url_response: None,
+                expected_db_response: Ok(saved_event),
+                expected_cached_events: Vec::new(),
+                expected_result: Err(EventPublishingError::InvalidStatusTransition),
+            },
+            TestCase {
+                description: "When an existing event cannot move to the requested status, I should receive an invalid status transition error",
+                event_dto: existing_event_dto.clone(),
+                expected_status_response: Some(Ok(EventStatus::Archived)),
+                expected_image_url_response: None,
+                expected_db_response: Ok(saved_event),
+                expected_cached_events: Vec::new(),
+                expected_result: Err(EventPublishingError::InvalidStatusTransition),
+            },
+            TestCase {
+                description: "When the existing event cannot be found, I should receive an event not found error",
+                event_dto: existing_event_dto.clone(),
+                expected_status_response: Some(Err(GetEventsRepositoryError::EventsNotFound)),
+                expected_image_url_response: None,
+                expected_db_response: Ok(saved_event),
+                expected_cached_events: Vec::new(),
+                expected_result: Err(EventPublishingError::RepositoryError(
+                    UpsertEventError::UpdateError(UpdateEventError::EventNotFound),
+                )),
+            },
+            TestCase {
+                description: "When the room is already booked at the same time, I should receive a room conflict error",
+                event_dto: booked_event_dto.clone(),
+                expected_status_response: None,
+                expected_image_url_response: None,
+                expected_db_response: Err(UpsertEventError::RoomConflict(booking_conflict.clone())),
+                expected_cached_events: Vec::new(),
+                expected_result: Err(EventPublishingError::RoomConflict(booking_conflict)),
+            },
+            TestCase {
+                description: "When the room availability cannot be checked, I should receive an error",
+                event_dto: booked_event_dto,
+                expected_status_response: None,
+                expected_image_url_response: None,
+                expected_db_response: Err(UpsertEventError::UnableToCheckRoomAvailability),
+                expected_cached_events: Vec::new(),
+                expected_result: Err(EventPublishingError::UnableToCheckRoomAvailability),
+            },
             TestCase {
                 description: "When upsertion fails, I should receive an error",
                 event_dto: event_dto.clone(),
+                expected_status_response: None,
+                expected_image_url_response: None,
                 expected_db_response: Err(UpsertEventError::UnableToUpsertEvent),
+                expected_cached_events: Vec::new(),
                 expected_result: Err(EventPublishingError::RepositoryError(
                     UpsertEventError::UnableToUpsertEvent,
                 )),
             },
             TestCase {
-                description: "When upsertion succeeds, I should receive no error",
+                description: "When upsertion succeeds, I should receive its new id and version and the event should be cached with them",
                 event_dto,
-                expected_db_response: Ok(()),
-                expected_result: Ok(()),
+                expected_status_response: None,
+                expected_image_url_response: None,
+                expected_db_response: Ok(saved_event),
+                expected_cached_events: vec![saved_event],
+                expected_result: Ok(PublishedEvent {
+                    saved_event,
+                    replaced_image_url: None,
+                }),
+            },
+            TestCase {
+                description: "When an existing event has its image replaced, I should receive the url of the old image",
+                event_dto: existing_event_dto.clone(),
+                expected_status_response: Some(Ok(EventStatus::Confirmed)),
+                expected_image_url_response: Some(Ok(Some("/uploads/old.png".to_owned()))),
+                expected_db_response: Ok(saved_existing_event),
+                expected_cached_events: vec![saved_existing_event],
+                expected_result: Ok(PublishedEvent {
+                    saved_event: saved_existing_event,
+                    replaced_image_url: Some("/uploads/old.png".to_owned()),
+                }),
+            },
+            TestCase {
+                description: "When an existing event keeps its image, I should receive no image url",
+                event_dto: existing_event_dto.clone(),
+                expected_status_response: Some(Ok(EventStatus::Confirmed)),
+                expected_image_url_response: Some(Ok(Some("/uploads/new.png".to_owned()))),
+                expected_db_response: Ok(saved_existing_event),
+                expected_cached_events: vec![saved_existing_event],
+                expected_result: Ok(PublishedEvent {
+                    saved_event: saved_existing_event,
+                    replaced_image_url: None,
+                }),
+            },
+            TestCase {
+                description: "When the current image of an existing event cannot be retrieved, publishing should still succeed",
+                event_dto: existing_event_dto,
+                expected_status_response: Some(Ok(EventStatus::Confirmed)),
+                expected_image_url_response: Some(Err(GetEventsRepositoryError::UnableToGetEvents)),
+                expected_db_response: Ok(saved_existing_event),
+                expected_cached_events: vec![saved_existing_event],
+                expected_result: Ok(PublishedEvent {
+                    saved_event: saved_existing_event,
+                    replaced_image_url: None,
+                }),
             },
         ];
         for test_case in test_cases {
@@ -114,9 +337,38 @@ mod tests {
             let mut mock_in_memory_repository = MockEventsAdminRepository::new();
             let mut mock_repository = MockEventsAdminRepository::new();
 
+            let expected_cached_events = test_case.expected_cached_events;
             mock_in_memory_repository
-                .expect_upsert_event()
-                .return_once(move |_| Ok(()));
+                .expect_import_events()
+                .times(usize::from(!expected_cached_events.is_empty()))
+                .withf(move |events| {
+                    events
+                        .iter()
+                        .map(|event| SavedEvent {
+                            id: event.id,
+                            version: event.version,
+                        })
+                        .eq(expected_cached_events.iter().copied())
+                })
+                .returning(|events| {
+                    Ok(events
+                        .iter()
+                        .map(|event| SavedEvent {
+                            id: event.id,
+                            version: event.version,
+                        })
+                        .collect())
+                });
+            if let Some(status_response) = test_case.expected_status_response {
+                mock_repository
+                    .expect_get_event_status_by_id()
+                    .return_once(move |_| status_response);
+            }
+            if let Some(image_url_response) = test_case.expected_image_url_response {
+                mock_repository
+                    .expect_get_image_url_by_event_id()
+                    .return_once(move |_| image_url_response);
+            }
             mock_repository
                 .expect_upsert_event()
                 .return_once(move |_| test_case.expected_db_response);
@@ -127,7 +379,16 @@ mod tests {
             );
 
             let actual_result = service.publish_event(test_case.event_dto).await;
-            assert!(matches!(test_case.expected_result, actual_result));
+            if let Err(EventPublishingError::RoomConflict(expected_conflict)) =
+                &test_case.expected_result
+            {
+                assert!(matches!(
+                    &actual_result,
+                    Err(EventPublishingError::RoomConflict(actual_conflict)) if actual_conflict == expected_conflict
+                ));
+            }
+            assert_eq!(test_case.expected_result.is_ok(), actual_result.is_ok());
+            assert_eq!(test_case.expected_result.ok(), actual_result.ok());
         }
     }
 }
@@ -0,0 +1,17 @@
+use crate::features::events::repositories::EventsAdminRepository;
+
+/// Uploads are content addressed, so an image left behind by one event or speaker may still be
+/// used by another. Images are assumed to be in use when this cannot be checked, since deleting a
+/// shared image cannot be undone
+pub(crate) async fn is_image_referenced(
+    repository: &dyn EventsAdminRepository,
+    image_url: &str,
+) -> bool {
+    repository
+        .is_image_url_referenced(image_url)
+        .await
+        .unwrap_or_else(|err| {
+            tracing::warn!(error = ?err, url = %image_url, "unable to check whether image is still referenced");
+            true
+        })
+}
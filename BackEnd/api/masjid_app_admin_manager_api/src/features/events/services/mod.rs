@@ -1,3 +1,13 @@
+pub mod booking_conflicts;
+pub mod category_management_service;
 pub mod errors;
+pub mod event_caching;
 pub mod event_deletion_service;
+pub mod event_duplicate_detection_service;
+pub mod event_exception_service;
+pub mod event_import_service;
+pub mod event_lifecycle_service;
 pub mod event_publishing_service;
+pub mod image_references;
+pub mod room_management_service;
+pub mod speaker_management_service;
@@ -0,0 +1,286 @@
+use crate::features::events::models::saved_speaker::SavedSpeaker;
+use crate::features::events::repositories::EventsAdminRepository;
+use crate::features::events::services::errors::speaker_management_error::SpeakerManagementError;
+use crate::features::events::services::image_references::is_image_referenced;
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::models::speaker::Speaker;
+use masjid_app_api_library::features::events::services::event_service_impl::EventServiceImpl;
+use masjid_app_api_library::new_event_service;
+use masjid_app_api_library::shared::common_service_impl::CommonServiceImpl;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait SpeakerManagementService: Send + Sync {
+    /// Creates a speaker when its id is 0, otherwise updates the existing profile
+    async fn save_speaker(&self, speaker: Speaker) -> Result<SavedSpeaker, SpeakerManagementError>;
+    /// Removes a speaker, which is refused while any event still lists them. Returns the url of
+    /// the photo the deleted speaker used, so that it can be cleaned up
+    async fn remove_speaker(
+        &self,
+        speaker_id: i32,
+    ) -> Result<Option<String>, SpeakerManagementError>;
+    /// Whether an uploaded image is still used by any event or speaker, so must not be deleted
+    async fn is_image_referenced(&self, image_url: &str) -> bool;
+}
+
+new_event_service!(
+    new_speaker_management_service,
+    SpeakerManagementService,
+    EventsAdminRepository
+);
+
+#[async_trait]
+impl SpeakerManagementService for EventServiceImpl<dyn EventsAdminRepository> {
+    async fn save_speaker(&self, speaker: Speaker) -> Result<SavedSpeaker, SpeakerManagementError> {
+        let previous_photo_url = if speaker.id == 0 {
+            None
+        } else {
+            self.common
+                .repository
+                .get_photo_url_by_speaker_id(&speaker.id)
+                .await
+                .unwrap_or_else(|err| {
+                    tracing::warn!(error = ?err, "unable to retrieve the current photo url of the speaker");
+                    None
+                })
+        };
+        if let Err(upsert_error) = self
+            .common
+            .in_memory_repository
+            .upsert_speaker(&speaker)
+            .await
+        {
+            tracing::warn!(in_memory_upsertion_error = ?upsert_error, "upserting speaker into in-memory repository failed");
+        }
+        let id = self
+            .common
+            .repository
+            .upsert_speaker(&speaker)
+            .await
+            .map_err(SpeakerManagementError::from)?;
+        Ok(SavedSpeaker {
+            id,
+            replaced_photo_url: previous_photo_url.filter(|previous_photo_url| {
+                speaker.photo_url.as_ref() != Some(previous_photo_url)
+            }),
+        })
+    }
+
+    async fn remove_speaker(
+        &self,
+        speaker_id: i32,
+    ) -> Result<Option<String>, SpeakerManagementError> {
+        if let Err(delete_error) = self
+            .common
+            .in_memory_repository
+            .delete_speaker_by_id(&speaker_id)
+            .await
+        {
+            tracing::warn!(in_memory_repository_error = ?delete_error, "failure to delete speaker from in-memory repository");
+        }
+        self.common
+            .repository
+            .delete_speaker_by_id(&speaker_id)
+            .await
+            .map_err(SpeakerManagementError::from)
+    }
+
+    async fn is_image_referenced(&self, image_url: &str) -> bool {
+        is_image_referenced(self.common.repository.as_ref(), image_url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::saved_event::SavedEvent;
+    use crate::features::events::repositories::errors::delete_category_error::DeleteCategoryError;
+    use crate::features::events::repositories::errors::delete_event_error::DeleteEventError;
+    use crate::features::events::repositories::errors::delete_event_exception_error::DeleteEventExceptionError;
+    use crate::features::events::repositories::errors::delete_room_error::DeleteRoomError;
+    use crate::features::events::repositories::errors::delete_speaker_error::DeleteSpeakerError;
+    use crate::features::events::repositories::errors::import_events_error::ImportEventsError;
+    use crate::features::events::repositories::errors::update_event_error::UpdateEventError;
+    use crate::features::events::repositories::errors::upsert_category_error::UpsertCategoryError;
+    use crate::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+    use crate::features::events::repositories::errors::upsert_event_exception_error::UpsertEventExceptionError;
+    use crate::features::events::repositories::errors::upsert_room_error::UpsertRoomError;
+    use crate::features::events::repositories::errors::upsert_speaker_error::UpsertSpeakerError;
+    use chrono::{DateTime, Utc};
+    use masjid_app_api_library::features::events::models::event::Event;
+    use masjid_app_api_library::features::events::models::event_category::EventCategory;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_exception_dto::EventExceptionDTO;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+    use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+    use masjid_app_api_library::features::events::models::events_page::EventsPage;
+    use masjid_app_api_library::features::events::models::room::Room;
+    use masjid_app_api_library::features::events::repositories::EventsRepository;
+    use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use mockall::mock;
+
+    mock!(
+        pub EventsAdminRepository {}
+
+        #[async_trait]
+        impl EventsRepository for EventsAdminRepository {
+            async fn get_events(&self, visibility: EventVisibility) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+            async fn get_filtered_events(&self, filter: &EventsFilter) -> Result<EventsPage, GetEventsRepositoryError>;
+            async fn get_event_exceptions(&self) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+            async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+            async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+            async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+            async fn get_tags(&self, visibility: EventVisibility) -> Result<Vec<String>, GetEventsRepositoryError>;
+        }
+        #[async_trait]
+        impl EventsAdminRepository for EventsAdminRepository {
+            async fn upsert_event(&self, event: &Event) -> Result<SavedEvent, UpsertEventError>;
+            async fn import_events(&self, events: &[Event]) -> Result<Vec<SavedEvent>, ImportEventsError>;
+            async fn delete_event_by_id(&self, event_id: &i32) -> Result<Option<String>, DeleteEventError>;
+            async fn get_image_url_by_event_id(&self, event_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn get_event_status_by_id(&self, event_id: &i32) -> Result<EventStatus, GetEventsRepositoryError>;
+            async fn update_event_status(&self, event_id: &i32, status: &EventStatus, version: &u32) -> Result<u32, UpdateEventError>;
+            async fn upsert_event_exception(&self, exception: &EventExceptionDTO) -> Result<(), UpsertEventExceptionError>;
+            async fn delete_event_exception(&self, event_id: &i32, occurrence_date: &DateTime<Utc>) -> Result<(), DeleteEventExceptionError>;
+            async fn upsert_room(&self, room: &Room) -> Result<i32, UpsertRoomError>;
+            async fn delete_room_by_id(&self, room_id: &i32) -> Result<(), DeleteRoomError>;
+            async fn upsert_speaker(&self, speaker: &Speaker) -> Result<i32, UpsertSpeakerError>;
+            async fn get_photo_url_by_speaker_id(&self, speaker_id: &i32) -> Result<Option<String>, GetEventsRepositoryError>;
+            async fn is_image_url_referenced(&self, image_url: &str) -> Result<bool, GetEventsRepositoryError>;
+            async fn delete_speaker_by_id(&self, speaker_id: &i32) -> Result<Option<String>, DeleteSpeakerError>;
+            async fn upsert_category(&self, category: &EventCategory) -> Result<i32, UpsertCategoryError>;
+            async fn delete_category_by_id(&self, category_id: &i32) -> Result<(), DeleteCategoryError>;
+        }
+    );
+
+    fn get_test_speaker(id: i32, photo_url: Option<&str>) -> Speaker {
+        Speaker {
+            id,
+            full_name: "Yusuf Ahmed".to_owned(),
+            title: Some("Imam".to_owned()),
+            bio: None,
+            photo_url: photo_url.map(str::to_owned),
+            languages: vec!["English".to_owned(), "Arabic".to_owned()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_speaker_management_service_save_speaker() {
+        struct TestCase {
+            description: &'static str,
+            speaker: Speaker,
+            expected_previous_photo_url: Option<String>,
+            expected_db_response: Result<i32, UpsertSpeakerError>,
+            expected_result: Result<SavedSpeaker, SpeakerManagementError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the speaker being updated does not exist, I should receive a speaker not found error",
+                speaker: get_test_speaker(1, None),
+                expected_previous_photo_url: None,
+                expected_db_response: Err(UpsertSpeakerError::SpeakerNotFound),
+                expected_result: Err(SpeakerManagementError::SpeakerNotFound),
+            },
+            TestCase {
+                description: "When the speaker keeps their photo, I should receive no photo to clean up",
+                speaker: get_test_speaker(1, Some("/uploads/speaker.png")),
+                expected_previous_photo_url: Some("/uploads/speaker.png".to_owned()),
+                expected_db_response: Ok(1),
+                expected_result: Ok(SavedSpeaker {
+                    id: 1,
+                    replaced_photo_url: None,
+                }),
+            },
+            TestCase {
+                description: "When the speaker's photo is replaced, I should receive the previous photo url",
+                speaker: get_test_speaker(1, Some("/uploads/new.png")),
+                expected_previous_photo_url: Some("/uploads/speaker.png".to_owned()),
+                expected_db_response: Ok(1),
+                expected_result: Ok(SavedSpeaker {
+                    id: 1,
+                    replaced_photo_url: Some("/uploads/speaker.png".to_owned()),
+                }),
+            },
+            TestCase {
+                description: "When a new speaker is saved, I should receive its id and no photo to clean up",
+                speaker: get_test_speaker(0, Some("/uploads/new.png")),
+                expected_previous_photo_url: None,
+                expected_db_response: Ok(2),
+                expected_result: Ok(SavedSpeaker {
+                    id: 2,
+                    replaced_photo_url: None,
+                }),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            let mut mock_repository = MockEventsAdminRepository::new();
+
+            mock_in_memory_repository
+                .expect_upsert_speaker()
+                .return_once(|_| Err(UpsertSpeakerError::UnableToUpsertSpeaker));
+            mock_repository
+                .expect_get_photo_url_by_speaker_id()
+                .return_once(move |_| Ok(test_case.expected_previous_photo_url));
+            mock_repository
+                .expect_upsert_speaker()
+                .return_once(move |_| test_case.expected_db_response);
+
+            let service = new_speaker_management_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            let actual_result = service.save_speaker(test_case.speaker).await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_speaker_management_service_remove_speaker() {
+        struct TestCase {
+            description: &'static str,
+            expected_db_response: Result<Option<String>, DeleteSpeakerError>,
+            expected_result: Result<Option<String>, SpeakerManagementError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When an event still lists the speaker, I should receive a speaker in use error",
+                expected_db_response: Err(DeleteSpeakerError::SpeakerInUse),
+                expected_result: Err(SpeakerManagementError::SpeakerInUse),
+            },
+            TestCase {
+                description: "When the speaker does not exist, I should receive a speaker not found error",
+                expected_db_response: Err(DeleteSpeakerError::SpeakerNotFound),
+                expected_result: Err(SpeakerManagementError::SpeakerNotFound),
+            },
+            TestCase {
+                description: "When deleting the speaker succeeds, I should receive their photo url",
+                expected_db_response: Ok(Some("/uploads/speaker.png".to_owned())),
+                expected_result: Ok(Some("/uploads/speaker.png".to_owned())),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_in_memory_repository = MockEventsAdminRepository::new();
+            let mut mock_repository = MockEventsAdminRepository::new();
+
+            mock_in_memory_repository
+                .expect_delete_speaker_by_id()
+                .return_once(|_| Err(DeleteSpeakerError::UnableToDeleteSpeaker));
+            mock_repository
+                .expect_delete_speaker_by_id()
+                .return_once(move |_| test_case.expected_db_response);
+
+            let service = new_speaker_management_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+            );
+            let actual_result = service.remove_speaker(1).await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+}
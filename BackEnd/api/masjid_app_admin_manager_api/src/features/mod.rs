@@ -1,4 +1,8 @@
 pub mod ask_imam;
+pub mod audit_log;
+pub mod event_registrations;
+pub mod event_reminders;
 pub mod events;
 pub mod prayer_times;
 pub mod user_authentication;
+pub mod user_management;
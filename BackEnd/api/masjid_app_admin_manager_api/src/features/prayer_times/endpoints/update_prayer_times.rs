@@ -1,21 +1,26 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
 use crate::features::prayer_times::errors::update_prayer_times_repository_error::UpdatePrayerTimesRepositoryError;
 use crate::features::prayer_times::models::update_prayer_times_request::UpdatePrayerTimesRequest;
 use crate::features::prayer_times::services::errors::update_prayer_times_service_error::UpdatePrayerTimesServiceError;
 use crate::features::prayer_times::services::prayer_times_update_service::PrayerTimesUpdateService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
 use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
 use axum::Json;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use masjid_app_api_library::features::prayer_times::models::prayer_times_dto::PrayerTimesDTO;
-use masjid_app_api_library::shared::types::app_state::ServiceAppState;
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
 use validator::Validate;
 
 pub async fn update_prayer_times(
-    State(state): State<ServiceAppState<Arc<dyn PrayerTimesUpdateService>>>,
+    State(state): State<AuditedServiceAppState<Arc<dyn PrayerTimesUpdateService>>>,
     claims: Claims,
+    RequestId(request_id): RequestId,
     Json(request): Json<UpdatePrayerTimesRequest>,
 ) -> Response {
     if request.validate().is_err() {
@@ -33,8 +38,30 @@ pub async fn update_prayer_times(
         data: Some(request.prayer_times_data),
         hash: request.hash,
     };
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::PrayerTimes, None)
+        .await;
     match state.service.update_prayer_times(prayer_times).await {
-        Ok(()) => StatusCode::OK.into_response(),
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::PrayerTimes, None)
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::PrayerTimes,
+                    entity_id: None,
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
         Err(UpdatePrayerTimesServiceError::RepositoryError(
             UpdatePrayerTimesRepositoryError::UnableToUpdatePrayerTimes,
         )) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
@@ -44,6 +71,7 @@ pub async fn update_prayer_times(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
     use crate::features::prayer_times::services::prayer_times_update_service::MockPrayerTimesUpdateService;
 
     #[tokio::test]
@@ -89,12 +117,14 @@ mod tests {
                     .expect_update_prayer_times()
                     .return_once(move |data| expected_service_response);
             }
-            let app_state = ServiceAppState::<Arc<dyn PrayerTimesUpdateService>> {
+            let app_state = AuditedServiceAppState::<Arc<dyn PrayerTimesUpdateService>> {
                 service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
             };
             let actual_response = update_prayer_times(
                 State(app_state),
                 test_case.claims,
+                RequestId::default(),
                 Json::from(UpdatePrayerTimesRequest {
                     prayer_times_data: test_case.prayer_times_data.clone(),
                     hash: "a13132143143134242".to_owned(),
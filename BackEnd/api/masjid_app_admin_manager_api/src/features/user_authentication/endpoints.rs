@@ -1,14 +1,37 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::user_authentication::models::forgotten_password_request::ForgottenPasswordRequest;
+use crate::features::user_authentication::models::login_outcome::LoginOutcome;
 use crate::features::user_authentication::models::login_request::LoginRequest;
+use crate::features::user_authentication::models::mfa_challenge_response::MfaChallengeResponse;
+use crate::features::user_authentication::models::mfa_code_request::MfaCodeRequest;
+use crate::features::user_authentication::models::mfa_enrolment_confirmation_response::MfaEnrolmentConfirmationResponse;
 use crate::features::user_authentication::models::registration_request::RegistrationRequest;
 use crate::features::user_authentication::models::reset_user_password_request::ResetUserPasswordRequest;
+use crate::features::user_authentication::models::token_password_reset_request::TokenPasswordResetRequest;
+use crate::features::user_authentication::models::update_mfa_policy_request::UpdateMfaPolicyRequest;
 use crate::features::user_authentication::models::user_account_dto::UserAccountDTO;
 use crate::features::user_authentication::services::errors::login_error::LoginError;
+use crate::features::user_authentication::services::errors::mfa_enrolment_error::MfaEnrolmentError;
+use crate::features::user_authentication::services::errors::mfa_policy_error::MfaPolicyError;
+use crate::features::user_authentication::services::errors::mfa_verification_error::MfaVerificationError;
+use crate::features::user_authentication::services::errors::password_reset_request_error::PasswordResetRequestError;
 use crate::features::user_authentication::services::errors::reset_password_error::ResetPasswordError;
+use crate::features::user_authentication::services::errors::token_password_reset_error::TokenPasswordResetError;
 use crate::features::user_authentication::services::errors::user_registration_error::UserRegistrationError;
+use crate::features::user_authentication::services::forgotten_password_service::ForgottenPasswordService;
 use crate::features::user_authentication::services::login_service::LoginService;
+use crate::features::user_authentication::services::mfa_service::MfaService;
 use crate::features::user_authentication::services::reset_password_service::ResetPasswordService;
 use crate::features::user_authentication::services::user_registration_service::UserRegistrationService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
 use crate::shared::jwt;
+use crate::shared::jwt::{
+    Claims, MFA_CHALLENGE_AUDIENCE, MFA_ENROLMENT_AUDIENCE, MfaChallengeClaims,
+    MfaEnrolmentPrincipal,
+};
+use crate::shared::request_id::RequestId;
 use axum::Json;
 use axum::extract::State;
 use axum::http::StatusCode;
@@ -34,7 +57,7 @@ pub(crate) async fn login(
         .login(&request.username, &request.password)
         .await;
     match login_result {
-        Ok(role) => {
+        Ok(LoginOutcome::Authenticated { role }) => {
             let claims = jwt::Claims::generate(&request.username, &role);
             let token_generation_result = jwt::generate_token(&claims);
             if let Ok(token) = token_generation_result {
@@ -42,17 +65,191 @@ pub(crate) async fn login(
             }
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
         }
+        Ok(LoginOutcome::MfaRequired { role }) => {
+            issue_mfa_challenge(&request.username, &role, MFA_CHALLENGE_AUDIENCE)
+        }
+        Ok(LoginOutcome::MfaEnrolmentRequired { role }) => {
+            issue_mfa_challenge(&request.username, &role, MFA_ENROLMENT_AUDIENCE)
+        }
         Err(LoginError::InvalidCredentials) => StatusCode::UNAUTHORIZED.into_response(),
+        Err(LoginError::AccountDisabled) => StatusCode::FORBIDDEN.into_response(),
         Err(LoginError::UnableToLogin) | Err(LoginError::UnableToVerifyPasswordHash) => {
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
         }
     }
 }
 
+fn issue_mfa_challenge(username: &str, role: &str, audience: &str) -> Response {
+    let claims = jwt::MfaChallengeClaims::generate(username, role, audience);
+    match jwt::generate_token(&claims) {
+        Ok(challenge_token) => (
+            StatusCode::ACCEPTED,
+            Json(MfaChallengeResponse {
+                challenge_token,
+                is_enrolment_required: audience == MFA_ENROLMENT_AUDIENCE,
+            }),
+        )
+            .into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+pub(crate) async fn verify_mfa(
+    State(state): State<ServiceAppState<Arc<dyn MfaService>>>,
+    challenge: MfaChallengeClaims,
+    Json(request): Json<MfaCodeRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.service.verify(&challenge.sub, &request.code).await {
+        Ok(()) => {
+            let claims = jwt::Claims::generate(&challenge.sub, &challenge.role);
+            match jwt::generate_token(&claims) {
+                Ok(token) => (StatusCode::OK, Json(token)).into_response(),
+                Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+            }
+        }
+        Err(MfaVerificationError::InvalidCode) | Err(MfaVerificationError::NotEnrolled) => {
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+        Err(MfaVerificationError::TooManyAttempts) => StatusCode::TOO_MANY_REQUESTS.into_response(),
+        Err(MfaVerificationError::UnableToVerify) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+pub(crate) async fn begin_mfa_enrolment(
+    State(state): State<ServiceAppState<Arc<dyn MfaService>>>,
+    principal: MfaEnrolmentPrincipal,
+) -> Response {
+    match state.service.begin_enrolment(principal.username()).await {
+        Ok(enrolment) => (StatusCode::OK, Json(enrolment)).into_response(),
+        Err(MfaEnrolmentError::UserDoesNotExist) => StatusCode::NOT_FOUND.into_response(),
+        Err(MfaEnrolmentError::AlreadyEnrolled) => StatusCode::CONFLICT.into_response(),
+        Err(MfaEnrolmentError::EnrolmentNotStarted)
+        | Err(MfaEnrolmentError::InvalidCode)
+        | Err(MfaEnrolmentError::UnableToEnrol) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+pub(crate) async fn confirm_mfa_enrolment(
+    State(state): State<ServiceAppState<Arc<dyn MfaService>>>,
+    principal: MfaEnrolmentPrincipal,
+    Json(request): Json<MfaCodeRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state
+        .service
+        .confirm_enrolment(principal.username(), &request.code)
+        .await
+    {
+        Ok(recovery_codes) => {
+            // Users forced to enrol whilst logging in are issued their token once enrolment is complete
+            let mut token = None;
+            if let MfaEnrolmentPrincipal::PendingEnrolment(_) = principal {
+                let claims = jwt::Claims::generate(principal.username(), principal.role());
+                match jwt::generate_token(&claims) {
+                    Ok(generated_token) => token = Some(generated_token),
+                    Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+                }
+            }
+            (
+                StatusCode::OK,
+                Json(MfaEnrolmentConfirmationResponse {
+                    recovery_codes,
+                    token,
+                }),
+            )
+                .into_response()
+        }
+        Err(MfaEnrolmentError::InvalidCode) => StatusCode::UNAUTHORIZED.into_response(),
+        Err(MfaEnrolmentError::EnrolmentNotStarted) => StatusCode::BAD_REQUEST.into_response(),
+        Err(MfaEnrolmentError::UserDoesNotExist) => StatusCode::NOT_FOUND.into_response(),
+        Err(MfaEnrolmentError::AlreadyEnrolled) => StatusCode::CONFLICT.into_response(),
+        Err(MfaEnrolmentError::UnableToEnrol) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+pub(crate) async fn disable_mfa(
+    State(state): State<ServiceAppState<Arc<dyn MfaService>>>,
+    claims: Claims,
+    Json(request): Json<MfaCodeRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.service.disable(&claims.sub, &request.code).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(MfaVerificationError::InvalidCode) => StatusCode::UNAUTHORIZED.into_response(),
+        Err(MfaVerificationError::NotEnrolled) => StatusCode::NOT_FOUND.into_response(),
+        Err(MfaVerificationError::TooManyAttempts) => StatusCode::TOO_MANY_REQUESTS.into_response(),
+        Err(MfaVerificationError::UnableToVerify) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+pub(crate) async fn update_mfa_policy(
+    State(state): State<AuditedServiceAppState<Arc<dyn MfaService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Json(request): Json<UpdateMfaPolicyRequest>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::MfaPolicy, None)
+        .await;
+    match state
+        .service
+        .set_mfa_requirement(&request.role, request.is_required)
+        .await
+    {
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::MfaPolicy, None)
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::MfaPolicy,
+                    entity_id: None,
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(MfaPolicyError::UnableToUpdatePolicy) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 pub(crate) async fn register_user(
     State(state): State<ServiceAppState<Arc<dyn UserRegistrationService>>>,
+    claims: Claims,
     Json(request): Json<RegistrationRequest>,
 ) -> Response {
+    // Accounts can grant the Admin role, so only an existing Admin may create them
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
     if let Err(_) = request.validate() {
         return StatusCode::BAD_REQUEST.into_response();
     }
@@ -66,6 +263,9 @@ pub(crate) async fn register_user(
     match state.service.register_user(new_user).await {
         Ok(()) => StatusCode::CREATED.into_response(),
         Err(UserRegistrationError::UserAlreadyRegistered) => StatusCode::CONFLICT.into_response(),
+        Err(UserRegistrationError::PasswordPolicyViolation(violation)) => {
+            (StatusCode::BAD_REQUEST, violation.to_string()).into_response()
+        }
         Err(UserRegistrationError::UnableToRegisterToRepository)
         | Err(UserRegistrationError::UnableToHashPassword(_)) => {
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
@@ -74,20 +274,62 @@ pub(crate) async fn register_user(
 }
 
 pub(crate) async fn reset_user_password(
-    State(state): State<ServiceAppState<Arc<dyn ResetPasswordService>>>,
+    State(state): State<AuditedServiceAppState<Arc<dyn ResetPasswordService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
     Json(request): Json<ResetUserPasswordRequest>,
 ) -> Response {
+    // Users who have forgotten their password must go through the emailed reset token flow
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
     if let Err(_) = request.validate() {
         return StatusCode::BAD_REQUEST.into_response();
     }
 
+    let user_id = state.audit_log.find_user_id(&request.username).await;
+    let before = match user_id {
+        Some(user_id) => {
+            state
+                .audit_log
+                .take_snapshot(AuditEntityType::User, Some(user_id))
+                .await
+        }
+        None => None,
+    };
     match state
         .service
         .reset_password(&request.username, &request.replacement_password)
         .await
     {
-        Ok(()) => StatusCode::OK.into_response(),
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::User, user_id)
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::User,
+                    entity_id: user_id,
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
         Err(ResetPasswordError::UserDoesNotExist) => StatusCode::NOT_FOUND.into_response(),
+        Err(ResetPasswordError::PasswordPolicyViolation(violation)) => {
+            (StatusCode::BAD_REQUEST, violation.to_string()).into_response()
+        }
+        Err(ResetPasswordError::PasswordRecentlyUsed) => (
+            StatusCode::BAD_REQUEST,
+            "The password has been used recently. Please choose a different password",
+        )
+            .into_response(),
         Err(ResetPasswordError::UnableToResetPassword)
         | Err(ResetPasswordError::UnableToHashPassword(_)) => {
             StatusCode::INTERNAL_SERVER_ERROR.into_response()
@@ -95,12 +337,100 @@ pub(crate) async fn reset_user_password(
     }
 }
 
+pub(crate) async fn request_password_reset(
+    State(state): State<ServiceAppState<Arc<dyn ForgottenPasswordService>>>,
+    Json(request): Json<ForgottenPasswordRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.service.request_password_reset(&request.email).await {
+        Ok(()) => StatusCode::ACCEPTED.into_response(),
+        Err(PasswordResetRequestError::UnableToRequestReset)
+        | Err(PasswordResetRequestError::UnableToSendEmail) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+pub(crate) async fn reset_password_with_token(
+    State(state): State<AuditedServiceAppState<Arc<dyn ForgottenPasswordService>>>,
+    RequestId(request_id): RequestId,
+    Json(request): Json<TokenPasswordResetRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    // The token is redeemed by the reset, so its user has to be found beforehand
+    let username = state
+        .service
+        .find_reset_token_username(&request.token)
+        .await;
+    let user_id = match &username {
+        Some(username) => state.audit_log.find_user_id(username).await,
+        None => None,
+    };
+    let before = match user_id {
+        Some(user_id) => {
+            state
+                .audit_log
+                .take_snapshot(AuditEntityType::User, Some(user_id))
+                .await
+        }
+        None => None,
+    };
+    match state
+        .service
+        .reset_password_with_token(&request.token, &request.replacement_password)
+        .await
+    {
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::User, user_id)
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: username.unwrap_or_default(),
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::User,
+                    entity_id: user_id,
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(TokenPasswordResetError::InvalidOrExpiredToken) => {
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+        Err(TokenPasswordResetError::PasswordPolicyViolation(violation)) => {
+            (StatusCode::BAD_REQUEST, violation.to_string()).into_response()
+        }
+        Err(TokenPasswordResetError::PasswordRecentlyUsed) => (
+            StatusCode::BAD_REQUEST,
+            "The password has been used recently. Please choose a different password",
+        )
+            .into_response(),
+        Err(TokenPasswordResetError::UnableToResetPassword)
+        | Err(TokenPasswordResetError::UnableToHashPassword(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::user_authentication::services::forgotten_password_service::MockForgottenPasswordService;
     use crate::features::user_authentication::services::login_service::MockLoginService;
+    use crate::features::user_authentication::services::mfa_service::MockMfaService;
     use crate::features::user_authentication::services::reset_password_service::MockResetPasswordService;
     use crate::features::user_authentication::services::user_registration_service::MockUserRegistrationService;
+    use masjid_app_api_library::shared::services::password_policy::errors::PasswordPolicyViolation;
 
     #[derive(Clone)]
     struct TestCase<TRequest, TOk, TErr> {
@@ -115,8 +445,15 @@ mod tests {
         Reset,
     }
 
+    fn set_jwt_secret() {
+        unsafe {
+            std::env::set_var("JWT_SECRET", "unit test secret");
+        }
+    }
+
     #[tokio::test]
     async fn test_login() {
+        set_jwt_secret();
         let valid_request = LoginRequest {
             username: "Zayd McArdle".to_owned(),
             password: "Password".to_owned(),
@@ -146,15 +483,35 @@ mod tests {
             TestCase {
                 description: "Given the request body is valid and when database successfully validates credentials, I should get an OK response",
                 request: valid_request.clone(),
-                expected_service_response: Some(Ok("Admin".to_owned())),
+                expected_service_response: Some(Ok(LoginOutcome::Authenticated {
+                    role: "Admin".to_owned(),
+                })),
                 expected_status_code: StatusCode::OK,
             },
             TestCase {
                 description: "Given the request body is valid and when database successfully validates credentials, I should get an OK response",
                 request: valid_request.clone(),
-                expected_service_response: Some(Ok("Imam".to_owned())),
+                expected_service_response: Some(Ok(LoginOutcome::Authenticated {
+                    role: "Imam".to_owned(),
+                })),
                 expected_status_code: StatusCode::OK,
             },
+            TestCase {
+                description: "Given the credentials are valid but the user has enrolled in mfa, I should get an ACCEPTED response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(LoginOutcome::MfaRequired {
+                    role: "Admin".to_owned(),
+                })),
+                expected_status_code: StatusCode::ACCEPTED,
+            },
+            TestCase {
+                description: "Given the credentials are valid but mfa enrolment is required, I should get an ACCEPTED response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(LoginOutcome::MfaEnrolmentRequired {
+                    role: "Admin".to_owned(),
+                })),
+                expected_status_code: StatusCode::ACCEPTED,
+            },
         ];
         for test_case in test_cases {
             eprintln!("{}", test_case.description);
@@ -209,6 +566,16 @@ mod tests {
                 expected_service_response: Some(Err(UserRegistrationError::UserAlreadyRegistered)),
                 expected_status_code: StatusCode::CONFLICT,
             },
+            TestCase {
+                description: "Given the request body is valid but the password is too weak, I should get a BAD_REQUEST response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(
+                    UserRegistrationError::PasswordPolicyViolation(
+                        PasswordPolicyViolation::TooWeak,
+                    ),
+                )),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
             TestCase {
                 description: "Given the request body is valid and registration succeeds, I should get a CREATED response",
                 request: valid_request.clone(),
@@ -228,11 +595,36 @@ mod tests {
             let app_state = ServiceAppState {
                 service: arc_service,
             };
-            let actual_response = register_user(State(app_state), Json(test_case.request)).await;
+            let actual_response = register_user(
+                State(app_state),
+                Claims::generate("admin", "Admin"),
+                Json(test_case.request),
+            )
+            .await;
             assert_eq!(test_case.expected_status_code, actual_response.status());
         }
     }
 
+    #[tokio::test]
+    async fn test_register_user_requires_admin() {
+        let app_state = ServiceAppState::<Arc<dyn UserRegistrationService>> {
+            service: Arc::new(MockUserRegistrationService::new()),
+        };
+        let actual_response = register_user(
+            State(app_state),
+            Claims::generate("imam", "Imam"),
+            Json(RegistrationRequest {
+                full_name: "Zayd McArdle".to_string(),
+                email: "zaydmcardle@example.com".to_string(),
+                role: "Admin".to_string(),
+                username: "ZaydMcArdle".to_string(),
+                password: "ThisIsMyPasswordForMyUnitTest".to_string(),
+            }),
+        )
+        .await;
+        assert_eq!(StatusCode::FORBIDDEN, actual_response.status());
+    }
+
     #[tokio::test]
     async fn test_reset_user_password() {
         let valid_request = ResetUserPasswordRequest {
@@ -261,6 +653,12 @@ mod tests {
                 expected_service_response: Some(Err(ResetPasswordError::UserDoesNotExist)),
                 expected_status_code: StatusCode::NOT_FOUND,
             },
+            TestCase {
+                description: "Given the request body is valid but the password has recently been used, I should get a BAD_REQUEST response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(ResetPasswordError::PasswordRecentlyUsed)),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
             TestCase {
                 description: "Given the request body is valid and password reset succeeds, I should get an OK response",
                 request: valid_request.clone(),
@@ -277,12 +675,342 @@ mod tests {
                     .return_once(move |_, _| expected_service_response);
             }
             let arc_service: Arc<dyn ResetPasswordService> = Arc::new(mock_service);
-            let app_state = ServiceAppState {
+            let app_state = AuditedServiceAppState {
                 service: arc_service,
+                audit_log: new_test_audit_log_service(),
             };
-            let actual_response =
-                reset_user_password(State(app_state), Json(test_case.request)).await;
+            let actual_response = reset_user_password(
+                State(app_state),
+                Claims::generate("admin", "Admin"),
+                RequestId::default(),
+                Json(test_case.request),
+            )
+            .await;
             assert!(matches!(test_case.expected_status_code, actual_resposne));
         }
     }
+
+    #[tokio::test]
+    async fn test_reset_user_password_requires_admin() {
+        let app_state = AuditedServiceAppState::<Arc<dyn ResetPasswordService>> {
+            service: Arc::new(MockResetPasswordService::new()),
+            audit_log: new_test_audit_log_service(),
+        };
+        let actual_response = reset_user_password(
+            State(app_state),
+            Claims::generate("imam", "Imam"),
+            RequestId::default(),
+            Json(ResetUserPasswordRequest {
+                username: "Zayd-McArdle".to_string(),
+                replacement_password: "MyReplacementPassword".to_string(),
+            }),
+        )
+        .await;
+        assert_eq!(StatusCode::FORBIDDEN, actual_response.status());
+    }
+
+    #[tokio::test]
+    async fn test_request_password_reset() {
+        let valid_request = ForgottenPasswordRequest {
+            email: "zaydmcardle@example.com".to_owned(),
+        };
+        let test_cases = vec![
+            TestCase {
+                description: "Given the email address is invalid, I should receive a BAD_REQUEST",
+                request: ForgottenPasswordRequest {
+                    email: "zaydmcardle".to_owned(),
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "Given the reset email cannot be sent, I should receive an INTERNAL_SERVER_ERROR",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(PasswordResetRequestError::UnableToSendEmail)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "Given the reset has been requested, I should receive an ACCEPTED response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(())),
+                expected_status_code: StatusCode::ACCEPTED,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockForgottenPasswordService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_request_password_reset()
+                    .return_once(move |_| expected_service_response);
+            }
+            let app_state = ServiceAppState::<Arc<dyn ForgottenPasswordService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response =
+                request_password_reset(State(app_state), Json(test_case.request)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_with_token() {
+        let valid_request = TokenPasswordResetRequest {
+            token: "a".repeat(64),
+            replacement_password: "MyReplacementPassword".to_owned(),
+        };
+        let test_cases = vec![
+            TestCase {
+                description: "Given the token is malformed, I should receive a BAD_REQUEST",
+                request: TokenPasswordResetRequest {
+                    token: "token".to_owned(),
+                    replacement_password: "MyReplacementPassword".to_owned(),
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "Given the token is invalid or has expired, I should receive an UNAUTHORIZED response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(
+                    TokenPasswordResetError::InvalidOrExpiredToken,
+                )),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            TestCase {
+                description: "Given the password contains personal information, I should receive a BAD_REQUEST",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(
+                    TokenPasswordResetError::PasswordPolicyViolation(
+                        PasswordPolicyViolation::ContainsPersonalInformation,
+                    ),
+                )),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "Given the password cannot be reset, I should receive an INTERNAL_SERVER_ERROR",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(
+                    TokenPasswordResetError::UnableToResetPassword,
+                )),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "Given the token is valid, I should receive an OK response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockForgottenPasswordService::new();
+            mock_service
+                .expect_find_reset_token_username()
+                .returning(|_| Some("Zayd-McArdle".to_owned()));
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_reset_password_with_token()
+                    .return_once(move |_, _| expected_service_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn ForgottenPasswordService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = reset_password_with_token(
+                State(app_state),
+                RequestId::default(),
+                Json(test_case.request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_mfa() {
+        set_jwt_secret();
+        let valid_request = MfaCodeRequest {
+            code: "123456".to_owned(),
+        };
+        let test_cases = vec![
+            TestCase {
+                description: "Given the code is too short, I should receive a BAD_REQUEST",
+                request: MfaCodeRequest {
+                    code: "123".to_owned(),
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "Given the code is invalid, I should receive an UNAUTHORIZED response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(MfaVerificationError::InvalidCode)),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            TestCase {
+                description: "Given too many incorrect codes have been entered, I should receive a TOO_MANY_REQUESTS response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(MfaVerificationError::TooManyAttempts)),
+                expected_status_code: StatusCode::TOO_MANY_REQUESTS,
+            },
+            TestCase {
+                description: "Given the code cannot be verified, I should receive an INTERNAL_SERVER_ERROR",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(MfaVerificationError::UnableToVerify)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "Given the code is valid, I should receive an OK response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockMfaService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_verify()
+                    .return_once(move |_, _| expected_service_response);
+            }
+            let app_state = ServiceAppState::<Arc<dyn MfaService>> {
+                service: Arc::new(mock_service),
+            };
+            let challenge = MfaChallengeClaims::generate("user", "Admin", MFA_CHALLENGE_AUDIENCE);
+            let actual_response =
+                verify_mfa(State(app_state), challenge, Json(test_case.request)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_confirm_mfa_enrolment() {
+        set_jwt_secret();
+        let valid_request = MfaCodeRequest {
+            code: "123456".to_owned(),
+        };
+        let test_cases = vec![
+            TestCase {
+                description: "Given the code is empty, I should receive a BAD_REQUEST",
+                request: MfaCodeRequest {
+                    code: "".to_owned(),
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "Given enrolment has not been started, I should receive a BAD_REQUEST",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(MfaEnrolmentError::EnrolmentNotStarted)),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "Given the code is invalid, I should receive an UNAUTHORIZED response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(MfaEnrolmentError::InvalidCode)),
+                expected_status_code: StatusCode::UNAUTHORIZED,
+            },
+            TestCase {
+                description: "Given the user has already enrolled, I should receive a CONFLICT response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(MfaEnrolmentError::AlreadyEnrolled)),
+                expected_status_code: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "Given the code is valid, I should receive an OK response",
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(vec!["ABCDE-FGHJK".to_owned()])),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockMfaService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_confirm_enrolment()
+                    .return_once(move |_, _| expected_service_response);
+            }
+            let app_state = ServiceAppState::<Arc<dyn MfaService>> {
+                service: Arc::new(mock_service),
+            };
+            let principal = MfaEnrolmentPrincipal::PendingEnrolment(
+                MfaChallengeClaims::generate("user", "Admin", MFA_ENROLMENT_AUDIENCE),
+            );
+            let actual_response =
+                confirm_mfa_enrolment(State(app_state), principal, Json(test_case.request)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_mfa_policy() {
+        struct PolicyTestCase {
+            description: &'static str,
+            claims: Claims,
+            request: UpdateMfaPolicyRequest,
+            expected_service_response: Option<Result<(), MfaPolicyError>>,
+            expected_status_code: StatusCode,
+        }
+        let valid_request = UpdateMfaPolicyRequest {
+            role: "Admin".to_owned(),
+            is_required: true,
+        };
+        let test_cases = vec![
+            PolicyTestCase {
+                description: "Given the user is not an admin, I should receive a FORBIDDEN response",
+                claims: Claims::generate("user", "Imam"),
+                request: valid_request.clone(),
+                expected_service_response: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            PolicyTestCase {
+                description: "Given the role is invalid, I should receive a BAD_REQUEST",
+                claims: Claims::generate("user", "Admin"),
+                request: UpdateMfaPolicyRequest {
+                    role: "Guest".to_owned(),
+                    is_required: true,
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            PolicyTestCase {
+                description: "Given the policy cannot be updated, I should receive an INTERNAL_SERVER_ERROR",
+                claims: Claims::generate("user", "Admin"),
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(MfaPolicyError::UnableToUpdatePolicy)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            PolicyTestCase {
+                description: "Given the policy is updated, I should receive an OK response",
+                claims: Claims::generate("user", "Admin"),
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockMfaService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_set_mfa_requirement()
+                    .return_once(move |_, _| expected_service_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn MfaService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = update_mfa_policy(
+                State(app_state),
+                test_case.claims,
+                RequestId::default(),
+                Json(test_case.request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
 }
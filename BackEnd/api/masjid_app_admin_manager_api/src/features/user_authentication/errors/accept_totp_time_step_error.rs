@@ -0,0 +1,5 @@
+#[derive(Clone, PartialEq, Debug)]
+pub enum AcceptTotpTimeStepError {
+    TimeStepAlreadyUsed,
+    DatabaseError,
+}
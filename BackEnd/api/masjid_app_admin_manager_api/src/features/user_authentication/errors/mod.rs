@@ -1,3 +1,9 @@
+pub mod accept_totp_time_step_error;
+pub mod consume_recovery_code_error;
 pub mod get_user_error;
 pub mod insert_new_user_error;
+pub mod insert_password_reset_token_error;
+pub mod redeem_password_reset_token_error;
+pub mod update_mfa_policy_error;
+pub mod update_user_mfa_error;
 pub mod update_user_password_error;
@@ -0,0 +1,5 @@
+#[derive(Clone, PartialEq, Debug)]
+pub enum RedeemPasswordResetTokenError {
+    InvalidOrExpiredToken,
+    DatabaseError,
+}
@@ -0,0 +1,4 @@
+#[derive(Clone, PartialEq, Debug)]
+pub enum UpdateMfaPolicyError {
+    DatabaseError,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone)]
+pub struct ForgottenPasswordRequest {
+    #[validate(email)]
+    pub email: String,
+}
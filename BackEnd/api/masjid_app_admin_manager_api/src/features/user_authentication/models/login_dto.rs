@@ -3,4 +3,7 @@ pub struct LoginDTO {
     pub username: String,
     pub password: String,
     pub role: String,
+    pub mfa_enabled: bool,
+    pub mfa_required: bool,
+    pub is_disabled: bool,
 }
@@ -0,0 +1,8 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoginOutcome {
+    Authenticated { role: String },
+    // The user has enrolled in 2FA and must provide a TOTP or recovery code
+    MfaRequired { role: String },
+    // 2FA is mandatory for the user's role, but they have yet to enrol
+    MfaEnrolmentRequired { role: String },
+}
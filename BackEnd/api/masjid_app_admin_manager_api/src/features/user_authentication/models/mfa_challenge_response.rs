@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct MfaChallengeResponse {
+    #[serde(rename(serialize = "challengeToken"))]
+    pub challenge_token: String,
+    #[serde(rename(serialize = "isEnrolmentRequired"))]
+    pub is_enrolment_required: bool,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone)]
+pub struct MfaCodeRequest {
+    // Either a 6 digit TOTP code or a recovery code
+    #[validate(length(min = 6, max = 16))]
+    pub code: String,
+}
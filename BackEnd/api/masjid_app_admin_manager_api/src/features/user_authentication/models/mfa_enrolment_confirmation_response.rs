@@ -0,0 +1,10 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct MfaEnrolmentConfirmationResponse {
+    #[serde(rename(serialize = "recoveryCodes"))]
+    pub recovery_codes: Vec<String>,
+    // Only issued when enrolment was completed as part of logging in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct MfaEnrolmentDTO {
+    pub secret: String,
+    #[serde(rename(serialize = "provisioningUri"))]
+    pub provisioning_uri: String,
+}
@@ -1,5 +1,14 @@
+pub mod forgotten_password_request;
 pub mod login_dto;
+pub mod login_outcome;
 pub mod login_request;
+pub mod mfa_challenge_response;
+pub mod mfa_code_request;
+pub mod mfa_enrolment_confirmation_response;
+pub mod mfa_enrolment_dto;
 pub mod registration_request;
 pub mod reset_user_password_request;
+pub mod token_password_reset_request;
+pub mod update_mfa_policy_request;
 pub mod user_account_dto;
+pub mod user_mfa_details;
@@ -18,7 +18,7 @@ pub struct RegistrationRequest {
     pub password: String,
 }
 #[inline]
-fn validate_role(role: &str) -> Result<(), ValidationError> {
+pub(crate) fn validate_role(role: &str) -> Result<(), ValidationError> {
     if role == "Admin" || role == "Imam" {
         return Ok(());
     }
@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone)]
+pub struct TokenPasswordResetRequest {
+    #[validate(length(equal = 64))]
+    pub token: String,
+    #[validate(length(
+        min = 16,
+        message = "Password length must be a minimum of 16 characters"
+    ))]
+    #[serde(rename(deserialize = "replacementPassword"))]
+    pub replacement_password: String,
+}
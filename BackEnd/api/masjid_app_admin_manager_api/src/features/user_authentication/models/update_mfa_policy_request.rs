@@ -0,0 +1,11 @@
+use crate::features::user_authentication::models::registration_request::validate_role;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone)]
+pub struct UpdateMfaPolicyRequest {
+    #[validate(custom(function = "validate_role"))]
+    pub role: String,
+    #[serde(rename(deserialize = "isRequired"))]
+    pub is_required: bool,
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, sqlx::FromRow, Clone, PartialEq)]
+pub struct UserMfaDetails {
+    /// Encrypted with the server-side key
+    pub secret: Option<String>,
+    pub is_enabled: bool,
+    /// The time step of the last TOTP code accepted, which no later code may be at or before
+    pub last_time_step: Option<u64>,
+    /// Set once too many incorrect codes have been entered
+    pub locked_until: Option<DateTime<Utc>>,
+}
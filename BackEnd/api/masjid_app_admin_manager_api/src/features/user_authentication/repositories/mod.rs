@@ -1,9 +1,17 @@
+use crate::features::user_authentication::errors::accept_totp_time_step_error::AcceptTotpTimeStepError;
+use crate::features::user_authentication::errors::consume_recovery_code_error::ConsumeRecoveryCodeError;
 use crate::features::user_authentication::errors::get_user_error::GetUserError;
 use crate::features::user_authentication::errors::insert_new_user_error::InsertNewUserError;
+use crate::features::user_authentication::errors::insert_password_reset_token_error::InsertPasswordResetTokenError;
+use crate::features::user_authentication::errors::redeem_password_reset_token_error::RedeemPasswordResetTokenError;
+use crate::features::user_authentication::errors::update_mfa_policy_error::UpdateMfaPolicyError;
+use crate::features::user_authentication::errors::update_user_mfa_error::UpdateUserMfaError;
 use crate::features::user_authentication::errors::update_user_password_error::UpdateUserPasswordError;
 use crate::features::user_authentication::models::login_dto::LoginDTO;
 use crate::features::user_authentication::models::user_account_dto::UserAccountDTO;
+use crate::features::user_authentication::models::user_mfa_details::UserMfaDetails;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
 use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
 use mockall::automock;
@@ -25,6 +33,78 @@ pub trait UserRepository: Send + Sync {
         username: &str,
         new_password: &str,
     ) -> Result<(), UpdateUserPasswordError>;
+    /// Replaces the stored hash of the user's current password without recording it in their
+    /// password history, for when the password is rehashed rather than changed
+    async fn update_user_password_hash(
+        &self,
+        username: &str,
+        new_password_hash: &str,
+    ) -> Result<(), UpdateUserPasswordError>;
+    /// Returns the most recent password hashes of the user, including their current password
+    async fn get_password_history(
+        &self,
+        username: &str,
+        limit: u32,
+    ) -> Result<Vec<String>, GetUserError>;
+    async fn get_username_by_email(&self, email: &str) -> Result<String, GetUserError>;
+    async fn get_user_email(&self, username: &str) -> Result<String, GetUserError>;
+    async fn insert_password_reset_token(
+        &self,
+        username: &str,
+        token_hash: &str,
+        expiry_date: DateTime<Utc>,
+    ) -> Result<(), InsertPasswordResetTokenError>;
+    async fn get_password_reset_token_username(
+        &self,
+        token_hash: &str,
+    ) -> Result<String, RedeemPasswordResetTokenError>;
+    /// Consumes the reset token and replaces the password of the user it was issued to,
+    /// returning their username
+    async fn reset_user_password_with_token(
+        &self,
+        token_hash: &str,
+        new_password: &str,
+    ) -> Result<String, RedeemPasswordResetTokenError>;
+    async fn get_user_mfa_details(&self, username: &str) -> Result<UserMfaDetails, GetUserError>;
+    async fn set_user_mfa_secret(
+        &self,
+        username: &str,
+        secret: &str,
+    ) -> Result<(), UpdateUserMfaError>;
+    /// Enables 2FA for the user and replaces any previously issued recovery codes. The time step
+    /// of the code which confirmed enrolment is recorded as the last one accepted
+    async fn enable_user_mfa(
+        &self,
+        username: &str,
+        recovery_code_hashes: Vec<String>,
+        time_step: u64,
+    ) -> Result<(), UpdateUserMfaError>;
+    async fn disable_user_mfa(&self, username: &str) -> Result<(), UpdateUserMfaError>;
+    /// Records the time step of an accepted TOTP code and clears any failed attempts, failing
+    /// when a code from the same or a later time step has already been accepted
+    async fn accept_totp_time_step(
+        &self,
+        username: &str,
+        time_step: u64,
+    ) -> Result<(), AcceptTotpTimeStepError>;
+    /// Counts an incorrect code, locking the user out of 2FA for the lockout period once the
+    /// maximum number of attempts is reached
+    async fn record_failed_mfa_attempt(
+        &self,
+        username: &str,
+        max_attempts: u32,
+        lockout_minutes: u32,
+    ) -> Result<(), UpdateUserMfaError>;
+    async fn consume_recovery_code(
+        &self,
+        username: &str,
+        code_hash: &str,
+    ) -> Result<(), ConsumeRecoveryCodeError>;
+    async fn upsert_mfa_policy(
+        &self,
+        role: &str,
+        is_mfa_required: bool,
+    ) -> Result<(), UpdateMfaPolicyError>;
 }
 pub async fn new_user_repository() -> Arc<dyn UserRepository> {
     Arc::new(MySqlRepository::new(RepositoryType::Authentication).await)
@@ -1,10 +1,18 @@
+use crate::features::user_authentication::errors::accept_totp_time_step_error::AcceptTotpTimeStepError;
+use crate::features::user_authentication::errors::consume_recovery_code_error::ConsumeRecoveryCodeError;
 use crate::features::user_authentication::errors::get_user_error::GetUserError;
 use crate::features::user_authentication::errors::insert_new_user_error::InsertNewUserError;
+use crate::features::user_authentication::errors::insert_password_reset_token_error::InsertPasswordResetTokenError;
+use crate::features::user_authentication::errors::redeem_password_reset_token_error::RedeemPasswordResetTokenError;
+use crate::features::user_authentication::errors::update_mfa_policy_error::UpdateMfaPolicyError;
+use crate::features::user_authentication::errors::update_user_mfa_error::UpdateUserMfaError;
 use crate::features::user_authentication::errors::update_user_password_error::UpdateUserPasswordError;
 use crate::features::user_authentication::models::login_dto::LoginDTO;
 use crate::features::user_authentication::models::user_account_dto::UserAccountDTO;
+use crate::features::user_authentication::models::user_mfa_details::UserMfaDetails;
 use crate::features::user_authentication::repositories::UserRepository;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
 use sqlx::{Error, Row};
 
@@ -22,6 +30,9 @@ impl UserRepository for MySqlRepository {
                 username: row.get(0),
                 password: row.get(1),
                 role: row.get(2),
+                mfa_enabled: row.get(3),
+                mfa_required: row.get(4),
+                is_disabled: row.get(5),
             })
             .fetch_one(&*db_connection)
             .await
@@ -83,4 +94,365 @@ impl UserRepository for MySqlRepository {
         tracing::debug!(username = username, "successfully reset password");
         Ok(())
     }
+    async fn update_user_password_hash(
+        &self,
+        username: &str,
+        new_password_hash: &str,
+    ) -> Result<(), UpdateUserPasswordError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL update_user_password_hash(?, ?);")
+            .bind(username)
+            .bind(new_password_hash)
+            .execute(&*db_connection)
+            .await
+            .map_err(|_| UpdateUserPasswordError::DatabaseError)?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(username = username, "user does not exist");
+            return Err(UpdateUserPasswordError::UserDoesNotExist);
+        }
+        Ok(())
+    }
+    async fn get_password_history(
+        &self,
+        username: &str,
+        limit: u32,
+    ) -> Result<Vec<String>, GetUserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_password_history(?, ?)")
+            .bind(username)
+            .bind(limit)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst retrieving password history",
+                );
+                GetUserError::DatabaseError
+            })
+    }
+    async fn get_username_by_email(&self, email: &str) -> Result<String, GetUserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_username_by_email(?)")
+            .bind(email)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if matches!(err, Error::RowNotFound) {
+                    return GetUserError::NotFound;
+                }
+                tracing::error!(
+                    error = err.to_string(),
+                    "an error occurred whilst retrieving username by email",
+                );
+                GetUserError::DatabaseError
+            })
+    }
+    async fn get_user_email(&self, username: &str) -> Result<String, GetUserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_user_email(?)")
+            .bind(username)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if matches!(err, Error::RowNotFound) {
+                    return GetUserError::NotFound;
+                }
+                tracing::error!(
+                    error = err.to_string(),
+                    "an error occurred whilst retrieving the email address of a user",
+                );
+                GetUserError::DatabaseError
+            })
+    }
+    async fn insert_password_reset_token(
+        &self,
+        username: &str,
+        token_hash: &str,
+        expiry_date: DateTime<Utc>,
+    ) -> Result<(), InsertPasswordResetTokenError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL insert_password_reset_token(?, ?, ?);")
+            .bind(username)
+            .bind(token_hash)
+            .bind(expiry_date)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst inserting password reset token",
+                );
+                InsertPasswordResetTokenError::DatabaseError
+            })?;
+        tracing::debug!(username = username, "password reset token issued");
+        Ok(())
+    }
+    async fn get_password_reset_token_username(
+        &self,
+        token_hash: &str,
+    ) -> Result<String, RedeemPasswordResetTokenError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_password_reset_token_username(?)")
+            .bind(token_hash)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if matches!(err, Error::RowNotFound) {
+                    tracing::debug!("password reset token is invalid or has expired");
+                    return RedeemPasswordResetTokenError::InvalidOrExpiredToken;
+                }
+                tracing::error!(
+                    error = err.to_string(),
+                    "an error occurred whilst retrieving password reset token",
+                );
+                RedeemPasswordResetTokenError::DatabaseError
+            })
+    }
+    async fn reset_user_password_with_token(
+        &self,
+        token_hash: &str,
+        new_password: &str,
+    ) -> Result<String, RedeemPasswordResetTokenError> {
+        let db_connection = self.db_connection.clone();
+        let username: String = sqlx::query("CALL reset_user_password_with_token(?, ?);")
+            .bind(token_hash)
+            .bind(new_password)
+            .map(|row: sqlx::mysql::MySqlRow| row.get(0))
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let Error::Database(ref database_error) = err
+                    && database_error.message() == "Invalid or expired token"
+                {
+                    tracing::debug!("password reset token is invalid or has expired");
+                    return RedeemPasswordResetTokenError::InvalidOrExpiredToken;
+                }
+                tracing::error!(
+                    error = err.to_string(),
+                    "an error occurred whilst resetting password with token",
+                );
+                RedeemPasswordResetTokenError::DatabaseError
+            })?;
+        tracing::info!(username = username, "password reset using reset token");
+        Ok(username)
+    }
+    async fn get_user_mfa_details(&self, username: &str) -> Result<UserMfaDetails, GetUserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_user_mfa_details(?)")
+            .bind(username)
+            .map(|row: sqlx::mysql::MySqlRow| UserMfaDetails {
+                secret: row.get(0),
+                is_enabled: row.get(1),
+                last_time_step: row.get(2),
+                locked_until: row.get(3),
+            })
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if matches!(err, Error::RowNotFound) {
+                    return GetUserError::NotFound;
+                }
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst retrieving user mfa details",
+                );
+                GetUserError::DatabaseError
+            })
+    }
+    async fn set_user_mfa_secret(
+        &self,
+        username: &str,
+        secret: &str,
+    ) -> Result<(), UpdateUserMfaError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL set_user_mfa_secret(?, ?);")
+            .bind(username)
+            .bind(secret)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst setting user mfa secret",
+                );
+                UpdateUserMfaError::DatabaseError
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(username = username, "user does not exist");
+            return Err(UpdateUserMfaError::UserDoesNotExist);
+        }
+        Ok(())
+    }
+    async fn enable_user_mfa(
+        &self,
+        username: &str,
+        recovery_code_hashes: Vec<String>,
+        time_step: u64,
+    ) -> Result<(), UpdateUserMfaError> {
+        let db_connection = self.db_connection.clone();
+        let map_database_error = |err: Error| {
+            tracing::error!(
+                username = username,
+                error = err.to_string(),
+                "an error occurred whilst enabling user mfa",
+            );
+            UpdateUserMfaError::DatabaseError
+        };
+        let mut transaction = db_connection.begin().await.map_err(map_database_error)?;
+        let query_result = sqlx::query("CALL enable_user_mfa(?, ?);")
+            .bind(username)
+            .bind(time_step)
+            .execute(&mut *transaction)
+            .await
+            .map_err(map_database_error)?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(username = username, "user does not exist");
+            return Err(UpdateUserMfaError::UserDoesNotExist);
+        }
+        for code_hash in &recovery_code_hashes {
+            sqlx::query("CALL insert_user_recovery_code(?, ?);")
+                .bind(username)
+                .bind(code_hash)
+                .execute(&mut *transaction)
+                .await
+                .map_err(map_database_error)?;
+        }
+        transaction.commit().await.map_err(map_database_error)?;
+        tracing::info!(username = username, "successfully enabled mfa");
+        Ok(())
+    }
+    async fn disable_user_mfa(&self, username: &str) -> Result<(), UpdateUserMfaError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL disable_user_mfa(?);")
+            .bind(username)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst disabling user mfa",
+                );
+                UpdateUserMfaError::DatabaseError
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(username = username, "user does not exist");
+            return Err(UpdateUserMfaError::UserDoesNotExist);
+        }
+        tracing::info!(username = username, "successfully disabled mfa");
+        Ok(())
+    }
+    async fn accept_totp_time_step(
+        &self,
+        username: &str,
+        time_step: u64,
+    ) -> Result<(), AcceptTotpTimeStepError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL accept_totp_time_step(?, ?);")
+            .bind(username)
+            .bind(time_step)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst accepting totp time step",
+                );
+                AcceptTotpTimeStepError::DatabaseError
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::warn!(username = username, "totp code has already been used");
+            return Err(AcceptTotpTimeStepError::TimeStepAlreadyUsed);
+        }
+        Ok(())
+    }
+    async fn record_failed_mfa_attempt(
+        &self,
+        username: &str,
+        max_attempts: u32,
+        lockout_minutes: u32,
+    ) -> Result<(), UpdateUserMfaError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL record_failed_mfa_attempt(?, ?, ?);")
+            .bind(username)
+            .bind(max_attempts)
+            .bind(lockout_minutes)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst recording failed mfa attempt",
+                );
+                UpdateUserMfaError::DatabaseError
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(username = username, "user does not exist");
+            return Err(UpdateUserMfaError::UserDoesNotExist);
+        }
+        Ok(())
+    }
+    async fn consume_recovery_code(
+        &self,
+        username: &str,
+        code_hash: &str,
+    ) -> Result<(), ConsumeRecoveryCodeError> {
+        let db_connection = self.db_connection.clone();
+        let query_result = sqlx::query("CALL consume_user_recovery_code(?, ?);")
+            .bind(username)
+            .bind(code_hash)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    username = username,
+                    error = err.to_string(),
+                    "an error occurred whilst consuming recovery code",
+                );
+                ConsumeRecoveryCodeError::DatabaseError
+            })?;
+        if query_result.rows_affected() == 0 {
+            tracing::debug!(username = username, "recovery code not found or already used");
+            return Err(ConsumeRecoveryCodeError::CodeNotFound);
+        }
+        tracing::info!(username = username, "recovery code consumed");
+        Ok(())
+    }
+    async fn upsert_mfa_policy(
+        &self,
+        role: &str,
+        is_mfa_required: bool,
+    ) -> Result<(), UpdateMfaPolicyError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL upsert_mfa_policy(?, ?);")
+            .bind(role)
+            .bind(is_mfa_required)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    role = role,
+                    error = err.to_string(),
+                    "an error occurred whilst updating mfa policy",
+                );
+                UpdateMfaPolicyError::DatabaseError
+            })?;
+        tracing::info!(
+            role = role,
+            is_mfa_required = is_mfa_required,
+            "mfa policy updated"
+        );
+        Ok(())
+    }
 }
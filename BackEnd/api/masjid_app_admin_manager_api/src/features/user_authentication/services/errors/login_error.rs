@@ -3,6 +3,7 @@ use crate::features::user_authentication::errors::get_user_error::GetUserError;
 #[derive(Clone, Debug, PartialEq)]
 pub enum LoginError {
     InvalidCredentials,
+    AccountDisabled,
     UnableToVerifyPasswordHash,
     UnableToLogin,
 }
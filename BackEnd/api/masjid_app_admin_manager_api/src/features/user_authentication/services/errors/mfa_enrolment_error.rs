@@ -0,0 +1,31 @@
+use crate::features::user_authentication::errors::get_user_error::GetUserError;
+use crate::features::user_authentication::errors::update_user_mfa_error::UpdateUserMfaError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MfaEnrolmentError {
+    UserDoesNotExist,
+    AlreadyEnrolled,
+    EnrolmentNotStarted,
+    InvalidCode,
+    UnableToEnrol,
+}
+
+impl From<GetUserError> for MfaEnrolmentError {
+    #[inline]
+    fn from(value: GetUserError) -> Self {
+        match value {
+            GetUserError::NotFound => Self::UserDoesNotExist,
+            GetUserError::DatabaseError => Self::UnableToEnrol,
+        }
+    }
+}
+
+impl From<UpdateUserMfaError> for MfaEnrolmentError {
+    #[inline]
+    fn from(value: UpdateUserMfaError) -> Self {
+        match value {
+            UpdateUserMfaError::UserDoesNotExist => Self::UserDoesNotExist,
+            UpdateUserMfaError::DatabaseError => Self::UnableToEnrol,
+        }
+    }
+}
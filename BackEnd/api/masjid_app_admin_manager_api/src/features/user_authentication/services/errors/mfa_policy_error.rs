@@ -0,0 +1,15 @@
+use crate::features::user_authentication::errors::update_mfa_policy_error::UpdateMfaPolicyError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MfaPolicyError {
+    UnableToUpdatePolicy,
+}
+
+impl From<UpdateMfaPolicyError> for MfaPolicyError {
+    #[inline]
+    fn from(value: UpdateMfaPolicyError) -> Self {
+        match value {
+            UpdateMfaPolicyError::DatabaseError => Self::UnableToUpdatePolicy,
+        }
+    }
+}
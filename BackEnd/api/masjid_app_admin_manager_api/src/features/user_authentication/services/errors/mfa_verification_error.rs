@@ -0,0 +1,53 @@
+use crate::features::user_authentication::errors::accept_totp_time_step_error::AcceptTotpTimeStepError;
+use crate::features::user_authentication::errors::consume_recovery_code_error::ConsumeRecoveryCodeError;
+use crate::features::user_authentication::errors::get_user_error::GetUserError;
+use crate::features::user_authentication::errors::update_user_mfa_error::UpdateUserMfaError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MfaVerificationError {
+    NotEnrolled,
+    InvalidCode,
+    /// Too many incorrect codes have been entered, so no code is accepted for a while
+    TooManyAttempts,
+    UnableToVerify,
+}
+
+impl From<GetUserError> for MfaVerificationError {
+    #[inline]
+    fn from(value: GetUserError) -> Self {
+        match value {
+            GetUserError::NotFound => Self::NotEnrolled,
+            GetUserError::DatabaseError => Self::UnableToVerify,
+        }
+    }
+}
+
+impl From<ConsumeRecoveryCodeError> for MfaVerificationError {
+    #[inline]
+    fn from(value: ConsumeRecoveryCodeError) -> Self {
+        match value {
+            ConsumeRecoveryCodeError::CodeNotFound => Self::InvalidCode,
+            ConsumeRecoveryCodeError::DatabaseError => Self::UnableToVerify,
+        }
+    }
+}
+
+impl From<AcceptTotpTimeStepError> for MfaVerificationError {
+    #[inline]
+    fn from(value: AcceptTotpTimeStepError) -> Self {
+        match value {
+            AcceptTotpTimeStepError::TimeStepAlreadyUsed => Self::InvalidCode,
+            AcceptTotpTimeStepError::DatabaseError => Self::UnableToVerify,
+        }
+    }
+}
+
+impl From<UpdateUserMfaError> for MfaVerificationError {
+    #[inline]
+    fn from(value: UpdateUserMfaError) -> Self {
+        match value {
+            UpdateUserMfaError::UserDoesNotExist => Self::NotEnrolled,
+            UpdateUserMfaError::DatabaseError => Self::UnableToVerify,
+        }
+    }
+}
@@ -1,3 +1,8 @@
 pub mod login_error;
+pub mod mfa_enrolment_error;
+pub mod mfa_policy_error;
+pub mod mfa_verification_error;
+pub mod password_reset_request_error;
 pub mod reset_password_error;
+pub mod token_password_reset_error;
 pub mod user_registration_error;
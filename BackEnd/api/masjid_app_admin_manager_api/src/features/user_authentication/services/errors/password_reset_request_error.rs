@@ -0,0 +1,32 @@
+use crate::features::user_authentication::errors::get_user_error::GetUserError;
+use crate::features::user_authentication::errors::insert_password_reset_token_error::InsertPasswordResetTokenError;
+use masjid_app_api_library::shared::services::mail::errors::SendMailError;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PasswordResetRequestError {
+    UnableToRequestReset,
+    UnableToSendEmail,
+}
+
+impl From<GetUserError> for PasswordResetRequestError {
+    #[inline]
+    fn from(_: GetUserError) -> Self {
+        Self::UnableToRequestReset
+    }
+}
+
+impl From<InsertPasswordResetTokenError> for PasswordResetRequestError {
+    #[inline]
+    fn from(value: InsertPasswordResetTokenError) -> Self {
+        match value {
+            InsertPasswordResetTokenError::DatabaseError => Self::UnableToRequestReset,
+        }
+    }
+}
+
+impl From<SendMailError> for PasswordResetRequestError {
+    #[inline]
+    fn from(_: SendMailError) -> Self {
+        Self::UnableToSendEmail
+    }
+}
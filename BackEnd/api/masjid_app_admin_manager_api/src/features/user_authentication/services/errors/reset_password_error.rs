@@ -1,9 +1,13 @@
+use crate::features::user_authentication::errors::get_user_error::GetUserError;
 use crate::features::user_authentication::errors::update_user_password_error::UpdateUserPasswordError;
 use masjid_app_api_library::shared::services::hashing::errors::HashError;
+use masjid_app_api_library::shared::services::password_policy::errors::PasswordPolicyViolation;
 
 pub enum ResetPasswordError {
     UserDoesNotExist,
     UnableToHashPassword(HashError),
+    PasswordPolicyViolation(PasswordPolicyViolation),
+    PasswordRecentlyUsed,
     UnableToResetPassword,
 }
 
@@ -23,3 +27,20 @@ impl From<HashError> for ResetPasswordError {
         Self::UnableToHashPassword(value)
     }
 }
+
+impl From<PasswordPolicyViolation> for ResetPasswordError {
+    #[inline]
+    fn from(value: PasswordPolicyViolation) -> Self {
+        Self::PasswordPolicyViolation(value)
+    }
+}
+
+impl From<GetUserError> for ResetPasswordError {
+    #[inline]
+    fn from(value: GetUserError) -> Self {
+        match value {
+            GetUserError::NotFound => Self::UserDoesNotExist,
+            GetUserError::DatabaseError => Self::UnableToResetPassword,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::features::user_authentication::errors::get_user_error::GetUserError;
+use crate::features::user_authentication::errors::redeem_password_reset_token_error::RedeemPasswordResetTokenError;
+use masjid_app_api_library::shared::services::hashing::errors::HashError;
+use masjid_app_api_library::shared::services::password_policy::errors::PasswordPolicyViolation;
+
+#[derive(Debug)]
+pub enum TokenPasswordResetError {
+    InvalidOrExpiredToken,
+    UnableToHashPassword(HashError),
+    PasswordPolicyViolation(PasswordPolicyViolation),
+    PasswordRecentlyUsed,
+    UnableToResetPassword,
+}
+
+impl From<RedeemPasswordResetTokenError> for TokenPasswordResetError {
+    #[inline]
+    fn from(value: RedeemPasswordResetTokenError) -> Self {
+        match value {
+            RedeemPasswordResetTokenError::InvalidOrExpiredToken => Self::InvalidOrExpiredToken,
+            RedeemPasswordResetTokenError::DatabaseError => Self::UnableToResetPassword,
+        }
+    }
+}
+
+impl From<HashError> for TokenPasswordResetError {
+    #[inline]
+    fn from(value: HashError) -> Self {
+        Self::UnableToHashPassword(value)
+    }
+}
+
+impl From<PasswordPolicyViolation> for TokenPasswordResetError {
+    #[inline]
+    fn from(value: PasswordPolicyViolation) -> Self {
+        Self::PasswordPolicyViolation(value)
+    }
+}
+
+impl From<GetUserError> for TokenPasswordResetError {
+    #[inline]
+    fn from(_: GetUserError) -> Self {
+        Self::UnableToResetPassword
+    }
+}
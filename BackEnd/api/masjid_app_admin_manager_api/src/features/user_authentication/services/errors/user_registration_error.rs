@@ -1,9 +1,11 @@
 use crate::features::user_authentication::errors::insert_new_user_error::InsertNewUserError;
 use masjid_app_api_library::shared::services::hashing::errors::HashError;
+use masjid_app_api_library::shared::services::password_policy::errors::PasswordPolicyViolation;
 
 #[derive(Debug)]
 pub enum UserRegistrationError {
     UnableToHashPassword(HashError),
+    PasswordPolicyViolation(PasswordPolicyViolation),
     UserAlreadyRegistered,
     UnableToRegisterToRepository,
 }
@@ -23,3 +25,10 @@ impl From<InsertNewUserError> for UserRegistrationError {
         }
     }
 }
+
+impl From<PasswordPolicyViolation> for UserRegistrationError {
+    #[inline]
+    fn from(value: PasswordPolicyViolation) -> Self {
+        Self::PasswordPolicyViolation(value)
+    }
+}
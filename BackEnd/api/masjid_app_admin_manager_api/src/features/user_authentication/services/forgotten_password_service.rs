@@ -0,0 +1,374 @@
+use crate::features::user_authentication::errors::get_user_error::GetUserError;
+use crate::features::user_authentication::repositories::UserRepository;
+use crate::features::user_authentication::services::errors::password_reset_request_error::PasswordResetRequestError;
+use crate::features::user_authentication::services::errors::token_password_reset_error::TokenPasswordResetError;
+use crate::features::user_authentication::services::forgotten_password_service_impl::ForgottenPasswordServiceImpl;
+use crate::features::user_authentication::services::password_history::is_recently_used_password;
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use masjid_app_api_library::shared::services::hashing::r#trait::HashingService;
+use masjid_app_api_library::shared::services::mail::mail_message::MailMessage;
+use masjid_app_api_library::shared::services::mail::r#trait::MailSender;
+use masjid_app_api_library::shared::services::password_policy::r#trait::PasswordPolicy;
+use mockall::automock;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+const RESET_TOKEN_LIFETIME_IN_MINUTES: i64 = 30;
+
+#[automock]
+#[async_trait]
+pub trait ForgottenPasswordService: Send + Sync {
+    /// Emails a reset link to the account registered with the email address. No error is
+    /// returned when the email address is not registered, so that accounts cannot be enumerated
+    async fn request_password_reset(&self, email: &str) -> Result<(), PasswordResetRequestError>;
+    /// Finds the user a reset token was issued to, which is `None` when the token is invalid or
+    /// has expired
+    async fn find_reset_token_username(&self, token: &str) -> Option<String>;
+    async fn reset_password_with_token(
+        &self,
+        token: &str,
+        new_password: &str,
+    ) -> Result<(), TokenPasswordResetError>;
+}
+
+pub fn new_forgotten_password_service(
+    hashing_service: Arc<dyn HashingService>,
+    user_repository: Arc<dyn UserRepository>,
+    mail_sender: Arc<dyn MailSender>,
+    password_policy: Arc<dyn PasswordPolicy>,
+    reset_password_url: String,
+) -> Arc<dyn ForgottenPasswordService> {
+    Arc::new(ForgottenPasswordServiceImpl {
+        hashing_service,
+        user_repository,
+        mail_sender,
+        password_policy,
+        reset_password_url,
+    })
+}
+
+fn generate_reset_token() -> String {
+    let token_bytes: [u8; 32] = rand::rng().random();
+    token_bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+// Only the hash is stored, so that a leaked database cannot be used to reset passwords
+fn hash_reset_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+#[async_trait]
+impl ForgottenPasswordService for ForgottenPasswordServiceImpl {
+    async fn request_password_reset(&self, email: &str) -> Result<(), PasswordResetRequestError> {
+        let username = match self.user_repository.get_username_by_email(email).await {
+            Ok(username) => username,
+            Err(GetUserError::NotFound) => {
+                tracing::debug!("password reset requested for an unregistered email address");
+                return Ok(());
+            }
+            Err(err) => return Err(PasswordResetRequestError::from(err)),
+        };
+        let token = generate_reset_token();
+        let expiry_date = Utc::now() + TimeDelta::minutes(RESET_TOKEN_LIFETIME_IN_MINUTES);
+        self.user_repository
+            .insert_password_reset_token(&username, &hash_reset_token(&token), expiry_date)
+            .await?;
+        let message = MailMessage {
+            to: email.to_owned(),
+            subject: "Reset your MasjidApp password".to_owned(),
+            body: format!(
+                "Assalamu alaikum {username},\n\n\
+                A password reset has been requested for your MasjidApp account. \
+                Use the link below to choose a new password. \
+                The link can only be used once and expires in {RESET_TOKEN_LIFETIME_IN_MINUTES} minutes.\n\n\
+                {}?token={token}\n\n\
+                If you did not request a password reset, you can safely ignore this email.",
+                self.reset_password_url
+            ),
+            reply_to: None,
+        };
+        self.mail_sender.send(message).await?;
+        tracing::info!(username = username, "password reset email sent");
+        Ok(())
+    }
+
+    async fn find_reset_token_username(&self, token: &str) -> Option<String> {
+        self.user_repository
+            .get_password_reset_token_username(&hash_reset_token(token))
+            .await
+            .ok()
+    }
+
+    async fn reset_password_with_token(
+        &self,
+        token: &str,
+        new_password: &str,
+    ) -> Result<(), TokenPasswordResetError> {
+        let token_hash = hash_reset_token(token);
+        let username = self
+            .user_repository
+            .get_password_reset_token_username(&token_hash)
+            .await?;
+        let email = self.user_repository.get_user_email(&username).await?;
+        self.password_policy
+            .validate(new_password, &[username.clone(), email])?;
+        if is_recently_used_password(
+            &*self.hashing_service,
+            &*self.user_repository,
+            &username,
+            new_password,
+        )
+        .await?
+        {
+            return Err(TokenPasswordResetError::PasswordRecentlyUsed);
+        }
+        let hashed_password = self.hashing_service.hash(new_password.as_bytes())?;
+        self.user_repository
+            .reset_user_password_with_token(&token_hash, &hashed_password)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::user_authentication::errors::insert_password_reset_token_error::InsertPasswordResetTokenError;
+    use crate::features::user_authentication::errors::redeem_password_reset_token_error::RedeemPasswordResetTokenError;
+    use crate::features::user_authentication::repositories::MockUserRepository;
+    use masjid_app_api_library::shared::services::hashing::r#trait::MockHashingService;
+    use masjid_app_api_library::shared::services::mail::errors::SendMailError;
+    use masjid_app_api_library::shared::services::mail::r#trait::MockMailSender;
+    use masjid_app_api_library::shared::services::password_policy::errors::PasswordPolicyViolation;
+    use masjid_app_api_library::shared::services::password_policy::r#trait::MockPasswordPolicy;
+
+    #[test]
+    fn test_generate_reset_token() {
+        let token = generate_reset_token();
+        assert_eq!(64, token.len());
+        assert_ne!(token, generate_reset_token());
+        assert_ne!(token, hash_reset_token(&token));
+    }
+
+    #[tokio::test]
+    async fn test_forgotten_password_service_request_password_reset() {
+        struct TestCase {
+            description: &'static str,
+            mock_get_username_result: Result<String, GetUserError>,
+            mock_insert_token_result: Option<Result<(), InsertPasswordResetTokenError>>,
+            mock_send_mail_result: Option<Result<(), SendMailError>>,
+            expected_result: Result<(), PasswordResetRequestError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the email address is not registered, I should receive no error and no email should be sent",
+                mock_get_username_result: Err(GetUserError::NotFound),
+                mock_insert_token_result: None,
+                mock_send_mail_result: None,
+                expected_result: Ok(()),
+            },
+            TestCase {
+                description: "When the user cannot be retrieved, I should receive an unable to request reset error",
+                mock_get_username_result: Err(GetUserError::DatabaseError),
+                mock_insert_token_result: None,
+                mock_send_mail_result: None,
+                expected_result: Err(PasswordResetRequestError::UnableToRequestReset),
+            },
+            TestCase {
+                description: "When the token cannot be stored, I should receive an unable to request reset error",
+                mock_get_username_result: Ok("user".to_owned()),
+                mock_insert_token_result: Some(Err(InsertPasswordResetTokenError::DatabaseError)),
+                mock_send_mail_result: None,
+                expected_result: Err(PasswordResetRequestError::UnableToRequestReset),
+            },
+            TestCase {
+                description: "When the email cannot be sent, I should receive an unable to send email error",
+                mock_get_username_result: Ok("user".to_owned()),
+                mock_insert_token_result: Some(Ok(())),
+                mock_send_mail_result: Some(Err(SendMailError::UnableToSend)),
+                expected_result: Err(PasswordResetRequestError::UnableToSendEmail),
+            },
+            TestCase {
+                description: "When the token is stored and the email is sent, I should receive no error",
+                mock_get_username_result: Ok("user".to_owned()),
+                mock_insert_token_result: Some(Ok(())),
+                mock_send_mail_result: Some(Ok(())),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockUserRepository::new();
+            let mut mock_mail_sender = MockMailSender::new();
+            mock_repository
+                .expect_get_username_by_email()
+                .return_once(|_| test_case.mock_get_username_result);
+            if let Some(mock_insert_token_result) = test_case.mock_insert_token_result {
+                mock_repository
+                    .expect_insert_password_reset_token()
+                    .withf(|_, token_hash, expiry_date| {
+                        token_hash.len() == 64 && *expiry_date > Utc::now()
+                    })
+                    .return_once(|_, _, _| mock_insert_token_result);
+            }
+            if let Some(mock_send_mail_result) = test_case.mock_send_mail_result {
+                mock_mail_sender
+                    .expect_send()
+                    .withf(|message| {
+                        message.to == "user@example.com"
+                            && message
+                                .body
+                                .contains("https://masjidapp.com/reset-password?token=")
+                    })
+                    .return_once(|_| mock_send_mail_result);
+            }
+            let service = new_forgotten_password_service(
+                Arc::new(MockHashingService::new()),
+                Arc::new(mock_repository),
+                Arc::new(mock_mail_sender),
+                Arc::new(MockPasswordPolicy::new()),
+                "https://masjidapp.com/reset-password".to_owned(),
+            );
+            let actual_result = service.request_password_reset("user@example.com").await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_forgotten_password_service_reset_password_with_token() {
+        struct TestCase {
+            description: &'static str,
+            mock_token_username_result: Result<String, RedeemPasswordResetTokenError>,
+            mock_password_policy_result: Option<Result<(), PasswordPolicyViolation>>,
+            mock_password_history: Option<Vec<String>>,
+            mock_repository_result: Option<Result<String, RedeemPasswordResetTokenError>>,
+            expected_result: Result<(), TokenPasswordResetError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the token is invalid or has expired, I should receive an invalid or expired token error",
+                mock_token_username_result: Err(RedeemPasswordResetTokenError::InvalidOrExpiredToken),
+                mock_password_policy_result: None,
+                mock_password_history: None,
+                mock_repository_result: None,
+                expected_result: Err(TokenPasswordResetError::InvalidOrExpiredToken),
+            },
+            TestCase {
+                description: "When the password does not meet the password policy, I should receive a password policy error",
+                mock_token_username_result: Ok("user".to_owned()),
+                mock_password_policy_result: Some(Err(PasswordPolicyViolation::TooWeak)),
+                mock_password_history: None,
+                mock_repository_result: None,
+                expected_result: Err(TokenPasswordResetError::PasswordPolicyViolation(
+                    PasswordPolicyViolation::TooWeak,
+                )),
+            },
+            TestCase {
+                description: "When the password has recently been used, I should receive a recently used error",
+                mock_token_username_result: Ok("user".to_owned()),
+                mock_password_policy_result: Some(Ok(())),
+                mock_password_history: Some(vec!["hashed password".to_owned()]),
+                mock_repository_result: None,
+                expected_result: Err(TokenPasswordResetError::PasswordRecentlyUsed),
+            },
+            TestCase {
+                description: "When the token is redeemed by another request first, I should receive an invalid or expired token error",
+                mock_token_username_result: Ok("user".to_owned()),
+                mock_password_policy_result: Some(Ok(())),
+                mock_password_history: Some(Vec::new()),
+                mock_repository_result: Some(Err(RedeemPasswordResetTokenError::InvalidOrExpiredToken)),
+                expected_result: Err(TokenPasswordResetError::InvalidOrExpiredToken),
+            },
+            TestCase {
+                description: "When the repository fails to reset the password, I should receive an unable to reset password error",
+                mock_token_username_result: Ok("user".to_owned()),
+                mock_password_policy_result: Some(Ok(())),
+                mock_password_history: Some(Vec::new()),
+                mock_repository_result: Some(Err(RedeemPasswordResetTokenError::DatabaseError)),
+                expected_result: Err(TokenPasswordResetError::UnableToResetPassword),
+            },
+            TestCase {
+                description: "When the token is valid, I should receive no error",
+                mock_token_username_result: Ok("user".to_owned()),
+                mock_password_policy_result: Some(Ok(())),
+                mock_password_history: Some(Vec::new()),
+                mock_repository_result: Some(Ok("user".to_owned())),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_hashing_service = MockHashingService::new();
+            let mut mock_repository = MockUserRepository::new();
+            let mut mock_password_policy = MockPasswordPolicy::new();
+            mock_repository
+                .expect_get_password_reset_token_username()
+                .withf(|token_hash| *token_hash == hash_reset_token("token"))
+                .return_once(|_| test_case.mock_token_username_result);
+            if let Some(mock_password_policy_result) = test_case.mock_password_policy_result {
+                mock_repository
+                    .expect_get_user_email()
+                    .return_once(|_| Ok("user@example.com".to_owned()));
+                mock_password_policy
+                    .expect_validate()
+                    .withf(|_, personal_information| {
+                        *personal_information == ["user".to_owned(), "user@example.com".to_owned()]
+                    })
+                    .return_once(|_, _| mock_password_policy_result);
+            }
+            if let Some(mock_password_history) = test_case.mock_password_history {
+                mock_repository
+                    .expect_get_password_history()
+                    .return_once(|_, _| Ok(mock_password_history));
+            }
+            // Only the previous password in the history matches the replacement password
+            mock_hashing_service
+                .expect_verify_hash()
+                .returning(|_, previous_hash| Ok(previous_hash == "hashed password"));
+            if let Some(mock_repository_result) = test_case.mock_repository_result {
+                mock_hashing_service
+                    .expect_hash()
+                    .return_once(|_| Ok("hashed password".to_owned()));
+                mock_repository
+                    .expect_reset_user_password_with_token()
+                    .withf(|token_hash, new_password| {
+                        *token_hash == hash_reset_token("token")
+                            && new_password == "hashed password"
+                    })
+                    .return_once(|_, _| mock_repository_result);
+            }
+            let service = new_forgotten_password_service(
+                Arc::new(mock_hashing_service),
+                Arc::new(mock_repository),
+                Arc::new(MockMailSender::new()),
+                Arc::new(mock_password_policy),
+                "https://masjidapp.com/reset-password".to_owned(),
+            );
+            let actual_result = service
+                .reset_password_with_token("token", "MyReplacementPassword")
+                .await;
+            assert!(matches!(
+                (test_case.expected_result, actual_result),
+                (Ok(()), Ok(()))
+                    | (
+                        Err(TokenPasswordResetError::InvalidOrExpiredToken),
+                        Err(TokenPasswordResetError::InvalidOrExpiredToken)
+                    )
+                    | (
+                        Err(TokenPasswordResetError::PasswordPolicyViolation(_)),
+                        Err(TokenPasswordResetError::PasswordPolicyViolation(_))
+                    )
+                    | (
+                        Err(TokenPasswordResetError::PasswordRecentlyUsed),
+                        Err(TokenPasswordResetError::PasswordRecentlyUsed)
+                    )
+                    | (
+                        Err(TokenPasswordResetError::UnableToResetPassword),
+                        Err(TokenPasswordResetError::UnableToResetPassword)
+                    )
+            ));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::features::user_authentication::repositories::UserRepository;
+use masjid_app_api_library::shared::services::hashing::r#trait::HashingService;
+use masjid_app_api_library::shared::services::mail::r#trait::MailSender;
+use masjid_app_api_library::shared::services::password_policy::r#trait::PasswordPolicy;
+use std::sync::Arc;
+
+pub(super) struct ForgottenPasswordServiceImpl {
+    pub(super) hashing_service: Arc<dyn HashingService>,
+    pub(super) user_repository: Arc<dyn UserRepository>,
+    pub(super) mail_sender: Arc<dyn MailSender>,
+    pub(super) password_policy: Arc<dyn PasswordPolicy>,
+    // Page of the front end which accepts the reset token
+    pub(super) reset_password_url: String,
+}
@@ -1,3 +1,4 @@
+use crate::features::user_authentication::models::login_outcome::LoginOutcome;
 use crate::features::user_authentication::repositories::UserRepository;
 use crate::features::user_authentication::services::authentication_service_impl::AuthenticationServiceImpl;
 use crate::features::user_authentication::services::errors::login_error::LoginError;
@@ -9,11 +10,11 @@ use std::sync::Arc;
 #[automock]
 #[async_trait]
 pub trait LoginService: Send + Sync {
-    async fn login(&self, username: &str, password: &str) -> Result<String, LoginError>;
+    async fn login(&self, username: &str, password: &str) -> Result<LoginOutcome, LoginError>;
 }
 #[async_trait]
 impl LoginService for AuthenticationServiceImpl {
-    async fn login(&self, username: &str, password: &str) -> Result<String, LoginError> {
+    async fn login(&self, username: &str, password: &str) -> Result<LoginOutcome, LoginError> {
         let user = self
             .user_repository
             .get_user_by_credentials(username, password)
@@ -23,12 +24,45 @@ impl LoginService for AuthenticationServiceImpl {
             .hashing_service
             .verify_hash(password.as_bytes(), &user.password)
             .map_err(|_| LoginError::UnableToVerifyPasswordHash)?;
-        if hash_verified {
-            tracing::info!(username = username, "logged in");
-            return Ok(user.role);
+        if !hash_verified {
+            return Err(LoginError::InvalidCredentials);
         }
+        if user.is_disabled {
+            tracing::warn!(username = username, "login attempted on a disabled account");
+            return Err(LoginError::AccountDisabled);
+        }
+        if self.hashing_service.needs_rehash(&user.password) {
+            self.upgrade_password_hash(username, password).await;
+        }
+        if user.mfa_enabled {
+            tracing::info!(username = username, "password verified, awaiting mfa code");
+            return Ok(LoginOutcome::MfaRequired { role: user.role });
+        }
+        if user.mfa_required {
+            tracing::info!(username = username, "password verified, awaiting mfa enrolment");
+            return Ok(LoginOutcome::MfaEnrolmentRequired { role: user.role });
+        }
+        tracing::info!(username = username, "logged in");
+        Ok(LoginOutcome::Authenticated { role: user.role })
+    }
+}
 
-        Err(LoginError::InvalidCredentials)
+impl AuthenticationServiceImpl {
+    /// Rehashes the password with the current algorithm and parameters. Failures are only logged,
+    /// as the user has already been authenticated and the upgrade will be retried on the next login
+    async fn upgrade_password_hash(&self, username: &str, password: &str) {
+        let Ok(hashed_password) = self.hashing_service.hash(password.as_bytes()) else {
+            tracing::warn!(username = username, "unable to rehash password");
+            return;
+        };
+        match self
+            .user_repository
+            .update_user_password_hash(username, &hashed_password)
+            .await
+        {
+            Ok(()) => tracing::info!(username = username, "password hash upgraded"),
+            Err(_) => tracing::warn!(username = username, "unable to store rehashed password"),
+        }
     }
 }
 
@@ -46,6 +80,7 @@ pub fn new_login_service(
 mod tests {
     use super::*;
     use crate::features::user_authentication::errors::get_user_error::GetUserError;
+    use crate::features::user_authentication::errors::update_user_password_error::UpdateUserPasswordError;
     use crate::features::user_authentication::models::login_dto::LoginDTO;
     use crate::features::user_authentication::repositories::MockUserRepository;
     use crate::features::user_authentication::services::errors::login_error::LoginError;
@@ -59,43 +94,106 @@ mod tests {
             description: &'static str,
             mock_repository_result: Result<LoginDTO, GetUserError>,
             mock_hashing_service_result: Option<Result<bool, VerifyHashError>>,
-            expected_result: Result<String, LoginError>,
+            mock_rehash_result: Option<Result<(), UpdateUserPasswordError>>,
+            expected_result: Result<LoginOutcome, LoginError>,
         }
         let mock_dto = LoginDTO {
             username: "user".to_owned(),
             password: "123".to_owned(),
             role: "admin".to_owned(),
+            mfa_enabled: false,
+            mfa_required: false,
+            is_disabled: false,
         };
         let test_cases = [
             TestCase {
                 description: "When repository receive a database error, an unable to login error should occur",
                 mock_repository_result: Err(GetUserError::DatabaseError),
                 mock_hashing_service_result: None,
+                mock_rehash_result: None,
                 expected_result: Err(LoginError::UnableToLogin),
             },
             TestCase {
                 description: "When repository cannot find user, an invalid credentials error should occur",
                 mock_repository_result: Err(GetUserError::NotFound),
                 mock_hashing_service_result: None,
+                mock_rehash_result: None,
                 expected_result: Err(LoginError::InvalidCredentials),
             },
             TestCase {
                 description: "When repository finds a user but the stored hash is malformed, a hashing error should occur",
                 mock_repository_result: Ok(mock_dto.clone()),
                 mock_hashing_service_result: Some(Err(VerifyHashError::HashMalformed)),
+                mock_rehash_result: None,
                 expected_result: Err(LoginError::UnableToVerifyPasswordHash),
             },
             TestCase {
                 description: "When repository finds a user but password does not match stored hash, an invalid credentials error should occur",
                 mock_repository_result: Ok(mock_dto.clone()),
                 mock_hashing_service_result: Some(Ok(false)),
+                mock_rehash_result: None,
                 expected_result: Err(LoginError::InvalidCredentials),
             },
             TestCase {
                 description: "When repository finds a user, the specific user role should be returned",
                 mock_repository_result: Ok(mock_dto.clone()),
                 mock_hashing_service_result: Some(Ok(true)),
-                expected_result: Ok("admin".to_owned()),
+                mock_rehash_result: None,
+                expected_result: Ok(LoginOutcome::Authenticated {
+                    role: "admin".to_owned(),
+                }),
+            },
+            TestCase {
+                description: "When the user's account has been disabled, an account disabled error should occur",
+                mock_repository_result: Ok(LoginDTO {
+                    is_disabled: true,
+                    ..mock_dto.clone()
+                }),
+                mock_hashing_service_result: Some(Ok(true)),
+                mock_rehash_result: None,
+                expected_result: Err(LoginError::AccountDisabled),
+            },
+            TestCase {
+                description: "When the user has enrolled in mfa, an mfa code should be required",
+                mock_repository_result: Ok(LoginDTO {
+                    mfa_enabled: true,
+                    ..mock_dto.clone()
+                }),
+                mock_hashing_service_result: Some(Ok(true)),
+                mock_rehash_result: None,
+                expected_result: Ok(LoginOutcome::MfaRequired {
+                    role: "admin".to_owned(),
+                }),
+            },
+            TestCase {
+                description: "When mfa is required for the user's role but they have not enrolled, mfa enrolment should be required",
+                mock_repository_result: Ok(LoginDTO {
+                    mfa_required: true,
+                    ..mock_dto.clone()
+                }),
+                mock_hashing_service_result: Some(Ok(true)),
+                mock_rehash_result: None,
+                expected_result: Ok(LoginOutcome::MfaEnrolmentRequired {
+                    role: "admin".to_owned(),
+                }),
+            },
+            TestCase {
+                description: "When the stored hash is outdated, it should be upgraded and the user should be logged in",
+                mock_repository_result: Ok(mock_dto.clone()),
+                mock_hashing_service_result: Some(Ok(true)),
+                mock_rehash_result: Some(Ok(())),
+                expected_result: Ok(LoginOutcome::Authenticated {
+                    role: "admin".to_owned(),
+                }),
+            },
+            TestCase {
+                description: "When the upgraded hash cannot be stored, the user should still be logged in",
+                mock_repository_result: Ok(mock_dto.clone()),
+                mock_hashing_service_result: Some(Ok(true)),
+                mock_rehash_result: Some(Err(UpdateUserPasswordError::DatabaseError)),
+                expected_result: Ok(LoginOutcome::Authenticated {
+                    role: "admin".to_owned(),
+                }),
             },
         ];
         for test_case in test_cases {
@@ -108,6 +206,24 @@ mod tests {
                     .expect_verify_hash()
                     .return_once(|_, _| mock_hashing_service_result);
             }
+            if let Some(mock_rehash_result) = test_case.mock_rehash_result {
+                mock_hashing_service
+                    .expect_needs_rehash()
+                    .return_const(true);
+                mock_hashing_service
+                    .expect_hash()
+                    .return_once(|_| Ok("rehashed password".to_owned()));
+                mock_repository
+                    .expect_update_user_password_hash()
+                    .withf(|username, password| {
+                        username == "admin" && password == "rehashed password"
+                    })
+                    .return_once(|_, _| mock_rehash_result);
+            } else {
+                mock_hashing_service
+                    .expect_needs_rehash()
+                    .return_const(false);
+            }
 
             mock_repository
                 .expect_get_user_by_credentials()
@@ -0,0 +1,592 @@
+use crate::features::user_authentication::models::mfa_enrolment_dto::MfaEnrolmentDTO;
+use crate::features::user_authentication::repositories::UserRepository;
+use crate::features::user_authentication::services::errors::mfa_enrolment_error::MfaEnrolmentError;
+use crate::features::user_authentication::services::errors::mfa_policy_error::MfaPolicyError;
+use crate::features::user_authentication::services::errors::mfa_verification_error::MfaVerificationError;
+use crate::features::user_authentication::services::mfa_service_impl::MfaServiceImpl;
+use async_trait::async_trait;
+use chrono::Utc;
+use masjid_app_api_library::shared::services::secret_encryption::r#trait::SecretEncryptor;
+use masjid_app_api_library::shared::services::totp::r#trait::TotpService;
+use mockall::automock;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_LENGTH: usize = 10;
+// Omits characters which are easily confused with one another, such as 0/O and 1/I
+const RECOVERY_CODE_CHARSET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+// A six digit code cannot be brute forced within this many attempts per lockout period
+const MAX_FAILED_MFA_ATTEMPTS: u32 = 5;
+const MFA_LOCKOUT_MINUTES: u32 = 15;
+
+#[automock]
+#[async_trait]
+pub trait MfaService: Send + Sync {
+    async fn begin_enrolment(&self, username: &str) -> Result<MfaEnrolmentDTO, MfaEnrolmentError>;
+    /// Returns the plaintext recovery codes, which are only ever shown to the user once
+    async fn confirm_enrolment(
+        &self,
+        username: &str,
+        code: &str,
+    ) -> Result<Vec<String>, MfaEnrolmentError>;
+    /// Accepts either a TOTP code or an unused recovery code
+    async fn verify(&self, username: &str, code: &str) -> Result<(), MfaVerificationError>;
+    async fn disable(&self, username: &str, code: &str) -> Result<(), MfaVerificationError>;
+    async fn set_mfa_requirement(&self, role: &str, is_required: bool)
+    -> Result<(), MfaPolicyError>;
+}
+
+pub fn new_mfa_service(
+    totp_service: Arc<dyn TotpService>,
+    user_repository: Arc<dyn UserRepository>,
+    secret_encryptor: Arc<dyn SecretEncryptor>,
+) -> Arc<dyn MfaService> {
+    Arc::new(MfaServiceImpl {
+        totp_service,
+        user_repository,
+        secret_encryptor,
+    })
+}
+
+fn generate_recovery_codes() -> Vec<String> {
+    let mut rng = rand::rng();
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let code: String = (0..RECOVERY_CODE_LENGTH)
+                .map(|_| RECOVERY_CODE_CHARSET[rng.random_range(0..RECOVERY_CODE_CHARSET.len())])
+                .map(char::from)
+                .collect();
+            format!(
+                "{}-{}",
+                &code[..RECOVERY_CODE_LENGTH / 2],
+                &code[RECOVERY_CODE_LENGTH / 2..]
+            )
+        })
+        .collect()
+}
+
+// Recovery codes carry enough entropy that a fast hash is sufficient, which also allows them to
+// be looked up directly rather than verified one at a time
+fn hash_recovery_code(code: &str) -> String {
+    let normalised_code: String = code
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|character| character.to_ascii_uppercase())
+        .collect();
+    format!("{:x}", Sha256::digest(normalised_code.as_bytes()))
+}
+
+#[async_trait]
+impl MfaService for MfaServiceImpl {
+    async fn begin_enrolment(&self, username: &str) -> Result<MfaEnrolmentDTO, MfaEnrolmentError> {
+        let mfa_details = self.user_repository.get_user_mfa_details(username).await?;
+        if mfa_details.is_enabled {
+            return Err(MfaEnrolmentError::AlreadyEnrolled);
+        }
+        let secret = self.totp_service.generate_secret();
+        let provisioning_uri = self
+            .totp_service
+            .provisioning_uri(&secret, username)
+            .map_err(|err| {
+                tracing::error!(username = username, error = ?err, "unable to build provisioning uri");
+                MfaEnrolmentError::UnableToEnrol
+            })?;
+        let encrypted_secret = self.secret_encryptor.encrypt(&secret).map_err(|err| {
+            tracing::error!(username = username, error = ?err, "unable to encrypt mfa secret");
+            MfaEnrolmentError::UnableToEnrol
+        })?;
+        self.user_repository
+            .set_user_mfa_secret(username, &encrypted_secret)
+            .await?;
+        tracing::info!(username = username, "mfa enrolment started");
+        Ok(MfaEnrolmentDTO {
+            secret,
+            provisioning_uri,
+        })
+    }
+
+    async fn confirm_enrolment(
+        &self,
+        username: &str,
+        code: &str,
+    ) -> Result<Vec<String>, MfaEnrolmentError> {
+        let mfa_details = self.user_repository.get_user_mfa_details(username).await?;
+        if mfa_details.is_enabled {
+            return Err(MfaEnrolmentError::AlreadyEnrolled);
+        }
+        let Some(encrypted_secret) = mfa_details.secret else {
+            return Err(MfaEnrolmentError::EnrolmentNotStarted);
+        };
+        let secret = self
+            .secret_encryptor
+            .decrypt(&encrypted_secret)
+            .map_err(|err| {
+                tracing::error!(username = username, error = ?err, "unable to decrypt mfa secret");
+                MfaEnrolmentError::UnableToEnrol
+            })?;
+        let time_step = self
+            .totp_service
+            .verify_code(&secret, code)
+            .map_err(|err| {
+                tracing::error!(username = username, error = ?err, "unable to verify totp code");
+                MfaEnrolmentError::UnableToEnrol
+            })?
+            .ok_or(MfaEnrolmentError::InvalidCode)?;
+        let recovery_codes = generate_recovery_codes();
+        let recovery_code_hashes = recovery_codes
+            .iter()
+            .map(|recovery_code| hash_recovery_code(recovery_code))
+            .collect();
+        self.user_repository
+            .enable_user_mfa(username, recovery_code_hashes, time_step)
+            .await?;
+        Ok(recovery_codes)
+    }
+
+    async fn verify(&self, username: &str, code: &str) -> Result<(), MfaVerificationError> {
+        let mfa_details = self.user_repository.get_user_mfa_details(username).await?;
+        let encrypted_secret = match mfa_details.secret {
+            Some(secret) if mfa_details.is_enabled => secret,
+            _ => return Err(MfaVerificationError::NotEnrolled),
+        };
+        if mfa_details
+            .locked_until
+            .is_some_and(|locked_until| locked_until > Utc::now())
+        {
+            tracing::warn!(username = username, "mfa attempted whilst locked out");
+            return Err(MfaVerificationError::TooManyAttempts);
+        }
+        let secret = self
+            .secret_encryptor
+            .decrypt(&encrypted_secret)
+            .map_err(|err| {
+                tracing::error!(username = username, error = ?err, "unable to decrypt mfa secret");
+                MfaVerificationError::UnableToVerify
+            })?;
+        let time_step = self
+            .totp_service
+            .verify_code(&secret, code)
+            .map_err(|err| {
+                tracing::error!(username = username, error = ?err, "unable to verify totp code");
+                MfaVerificationError::UnableToVerify
+            })?;
+        let verification_result = match time_step {
+            Some(time_step)
+                if mfa_details
+                    .last_time_step
+                    .is_some_and(|last_time_step| time_step <= last_time_step) =>
+            {
+                tracing::warn!(username = username, "totp code has already been used");
+                Err(MfaVerificationError::InvalidCode)
+            }
+            Some(time_step) => self
+                .user_repository
+                .accept_totp_time_step(username, time_step)
+                .await
+                .map_err(MfaVerificationError::from),
+            None => self
+                .user_repository
+                .consume_recovery_code(username, &hash_recovery_code(code))
+                .await
+                .map_err(MfaVerificationError::from),
+        };
+        if verification_result == Err(MfaVerificationError::InvalidCode)
+            && let Err(err) = self
+                .user_repository
+                .record_failed_mfa_attempt(username, MAX_FAILED_MFA_ATTEMPTS, MFA_LOCKOUT_MINUTES)
+                .await
+        {
+            tracing::error!(username = username, error = ?err, "unable to record failed mfa attempt");
+        }
+        verification_result
+    }
+
+    async fn disable(&self, username: &str, code: &str) -> Result<(), MfaVerificationError> {
+        self.verify(username, code).await?;
+        self.user_repository
+            .disable_user_mfa(username)
+            .await
+            .map_err(MfaVerificationError::from)
+    }
+
+    async fn set_mfa_requirement(
+        &self,
+        role: &str,
+        is_required: bool,
+    ) -> Result<(), MfaPolicyError> {
+        self.user_repository
+            .upsert_mfa_policy(role, is_required)
+            .await
+            .map_err(MfaPolicyError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::user_authentication::errors::accept_totp_time_step_error::AcceptTotpTimeStepError;
+    use crate::features::user_authentication::errors::consume_recovery_code_error::ConsumeRecoveryCodeError;
+    use crate::features::user_authentication::errors::get_user_error::GetUserError;
+    use crate::features::user_authentication::errors::update_user_mfa_error::UpdateUserMfaError;
+    use crate::features::user_authentication::models::user_mfa_details::UserMfaDetails;
+    use crate::features::user_authentication::repositories::MockUserRepository;
+    use chrono::TimeDelta;
+    use masjid_app_api_library::shared::services::secret_encryption::r#trait::MockSecretEncryptor;
+    use masjid_app_api_library::shared::services::totp::errors::TotpError;
+    use masjid_app_api_library::shared::services::totp::r#trait::MockTotpService;
+
+    fn new_test_secret_encryptor() -> Arc<dyn SecretEncryptor> {
+        let mut mock_secret_encryptor = MockSecretEncryptor::new();
+        mock_secret_encryptor
+            .expect_encrypt()
+            .returning(|secret| Ok(format!("encrypted {secret}")));
+        mock_secret_encryptor
+            .expect_decrypt()
+            .returning(|secret| Ok(secret.trim_start_matches("encrypted ").to_owned()));
+        Arc::new(mock_secret_encryptor)
+    }
+
+    #[test]
+    fn test_generate_recovery_codes() {
+        let recovery_codes = generate_recovery_codes();
+        assert_eq!(RECOVERY_CODE_COUNT, recovery_codes.len());
+        for recovery_code in recovery_codes {
+            assert_eq!(RECOVERY_CODE_LENGTH + 1, recovery_code.len());
+            assert_eq!(Some(RECOVERY_CODE_LENGTH / 2), recovery_code.find('-'));
+        }
+    }
+
+    #[test]
+    fn test_hash_recovery_code_ignores_formatting() {
+        assert_eq!(
+            hash_recovery_code("ABCDE-FGHJK"),
+            hash_recovery_code(" abcdefghjk ")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mfa_service_begin_enrolment() {
+        struct TestCase {
+            description: &'static str,
+            mock_get_details_result: Result<UserMfaDetails, GetUserError>,
+            mock_set_secret_result: Option<Result<(), UpdateUserMfaError>>,
+            expected_result: Result<MfaEnrolmentDTO, MfaEnrolmentError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the user does not exist, I should receive a user does not exist error",
+                mock_get_details_result: Err(GetUserError::NotFound),
+                mock_set_secret_result: None,
+                expected_result: Err(MfaEnrolmentError::UserDoesNotExist),
+            },
+            TestCase {
+                description: "When the user has already enrolled, I should receive an already enrolled error",
+                mock_get_details_result: Ok(UserMfaDetails {
+                    secret: Some("SECRET".to_owned()),
+                    is_enabled: true,
+                    last_time_step: None,
+                    locked_until: None,
+                }),
+                mock_set_secret_result: None,
+                expected_result: Err(MfaEnrolmentError::AlreadyEnrolled),
+            },
+            TestCase {
+                description: "When the secret cannot be stored, I should receive an unable to enrol error",
+                mock_get_details_result: Ok(UserMfaDetails {
+                    secret: None,
+                    is_enabled: false,
+                    last_time_step: None,
+                    locked_until: None,
+                }),
+                mock_set_secret_result: Some(Err(UpdateUserMfaError::DatabaseError)),
+                expected_result: Err(MfaEnrolmentError::UnableToEnrol),
+            },
+            TestCase {
+                description: "When the secret is stored, I should receive the secret and provisioning uri",
+                mock_get_details_result: Ok(UserMfaDetails {
+                    secret: None,
+                    is_enabled: false,
+                    last_time_step: None,
+                    locked_until: None,
+                }),
+                mock_set_secret_result: Some(Ok(())),
+                expected_result: Ok(MfaEnrolmentDTO {
+                    secret: "SECRET".to_owned(),
+                    provisioning_uri: "otpauth://totp/MasjidApp:user?secret=SECRET".to_owned(),
+                }),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockUserRepository::new();
+            let mut mock_totp_service = MockTotpService::new();
+            mock_repository
+                .expect_get_user_mfa_details()
+                .return_once(|_| test_case.mock_get_details_result);
+            if let Some(mock_set_secret_result) = test_case.mock_set_secret_result {
+                mock_totp_service
+                    .expect_generate_secret()
+                    .return_once(|| "SECRET".to_owned());
+                mock_totp_service
+                    .expect_provisioning_uri()
+                    .return_once(|_, _| {
+                        Ok("otpauth://totp/MasjidApp:user?secret=SECRET".to_owned())
+                    });
+                mock_repository
+                    .expect_set_user_mfa_secret()
+                    .withf(|_, secret| secret == "encrypted SECRET")
+                    .return_once(|_, _| mock_set_secret_result);
+            }
+            let service = new_mfa_service(
+                Arc::new(mock_totp_service),
+                Arc::new(mock_repository),
+                new_test_secret_encryptor(),
+            );
+            let actual_result = service.begin_enrolment("user").await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mfa_service_confirm_enrolment() {
+        struct TestCase {
+            description: &'static str,
+            mock_get_details_result: Result<UserMfaDetails, GetUserError>,
+            mock_verify_code_result: Option<Result<Option<u64>, TotpError>>,
+            mock_enable_result: Option<Result<(), UpdateUserMfaError>>,
+            expected_result: Result<usize, MfaEnrolmentError>,
+        }
+        let pending_enrolment = UserMfaDetails {
+            secret: Some("encrypted SECRET".to_owned()),
+            is_enabled: false,
+            last_time_step: None,
+            locked_until: None,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When enrolment has not been started, I should receive an enrolment not started error",
+                mock_get_details_result: Ok(UserMfaDetails {
+                    secret: None,
+                    is_enabled: false,
+                    last_time_step: None,
+                    locked_until: None,
+                }),
+                mock_verify_code_result: None,
+                mock_enable_result: None,
+                expected_result: Err(MfaEnrolmentError::EnrolmentNotStarted),
+            },
+            TestCase {
+                description: "When the code does not match the pending secret, I should receive an invalid code error",
+                mock_get_details_result: Ok(pending_enrolment.clone()),
+                mock_verify_code_result: Some(Ok(None)),
+                mock_enable_result: None,
+                expected_result: Err(MfaEnrolmentError::InvalidCode),
+            },
+            TestCase {
+                description: "When the code cannot be verified, I should receive an unable to enrol error",
+                mock_get_details_result: Ok(pending_enrolment.clone()),
+                mock_verify_code_result: Some(Err(TotpError::SecretMalformed)),
+                mock_enable_result: None,
+                expected_result: Err(MfaEnrolmentError::UnableToEnrol),
+            },
+            TestCase {
+                description: "When the code is valid and mfa is enabled, I should receive the recovery codes",
+                mock_get_details_result: Ok(pending_enrolment.clone()),
+                mock_verify_code_result: Some(Ok(Some(1))),
+                mock_enable_result: Some(Ok(())),
+                expected_result: Ok(RECOVERY_CODE_COUNT),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockUserRepository::new();
+            let mut mock_totp_service = MockTotpService::new();
+            mock_repository
+                .expect_get_user_mfa_details()
+                .return_once(|_| test_case.mock_get_details_result);
+            if let Some(mock_verify_code_result) = test_case.mock_verify_code_result {
+                mock_totp_service
+                    .expect_verify_code()
+                    .withf(|secret, _| secret == "SECRET")
+                    .return_once(|_, _| mock_verify_code_result);
+            }
+            if let Some(mock_enable_result) = test_case.mock_enable_result {
+                mock_repository
+                    .expect_enable_user_mfa()
+                    .withf(|_, recovery_code_hashes, time_step| {
+                        recovery_code_hashes.len() == RECOVERY_CODE_COUNT && *time_step == 1
+                    })
+                    .return_once(|_, _, _| mock_enable_result);
+            }
+            let service = new_mfa_service(
+                Arc::new(mock_totp_service),
+                Arc::new(mock_repository),
+                new_test_secret_encryptor(),
+            );
+            let actual_result = service
+                .confirm_enrolment("user", "123456")
+                .await
+                .map(|recovery_codes| recovery_codes.len());
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mfa_service_verify() {
+        struct TestCase {
+            description: &'static str,
+            mock_get_details_result: Result<UserMfaDetails, GetUserError>,
+            mock_verify_code_result: Option<Result<Option<u64>, TotpError>>,
+            mock_accept_time_step_result: Option<Result<(), AcceptTotpTimeStepError>>,
+            mock_consume_recovery_code_result: Option<Result<(), ConsumeRecoveryCodeError>>,
+            expect_failed_attempt: bool,
+            expected_result: Result<(), MfaVerificationError>,
+        }
+        let enrolled = UserMfaDetails {
+            secret: Some("encrypted SECRET".to_owned()),
+            is_enabled: true,
+            last_time_step: Some(1),
+            locked_until: None,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the user has not enrolled, I should receive a not enrolled error",
+                mock_get_details_result: Ok(UserMfaDetails {
+                    is_enabled: false,
+                    ..enrolled.clone()
+                }),
+                mock_verify_code_result: None,
+                mock_accept_time_step_result: None,
+                mock_consume_recovery_code_result: None,
+                expect_failed_attempt: false,
+                expected_result: Err(MfaVerificationError::NotEnrolled),
+            },
+            TestCase {
+                description: "When the repository fails, I should receive an unable to verify error",
+                mock_get_details_result: Err(GetUserError::DatabaseError),
+                mock_verify_code_result: None,
+                mock_accept_time_step_result: None,
+                mock_consume_recovery_code_result: None,
+                expect_failed_attempt: false,
+                expected_result: Err(MfaVerificationError::UnableToVerify),
+            },
+            TestCase {
+                description: "When the user has been locked out, I should receive a too many attempts error without the code being checked",
+                mock_get_details_result: Ok(UserMfaDetails {
+                    locked_until: Some(Utc::now() + TimeDelta::minutes(MFA_LOCKOUT_MINUTES as i64)),
+                    ..enrolled.clone()
+                }),
+                mock_verify_code_result: None,
+                mock_accept_time_step_result: None,
+                mock_consume_recovery_code_result: None,
+                expect_failed_attempt: false,
+                expected_result: Err(MfaVerificationError::TooManyAttempts),
+            },
+            TestCase {
+                description: "When the lockout has passed and the totp code is valid, I should receive no error",
+                mock_get_details_result: Ok(UserMfaDetails {
+                    locked_until: Some(Utc::now() - TimeDelta::minutes(1)),
+                    ..enrolled.clone()
+                }),
+                mock_verify_code_result: Some(Ok(Some(2))),
+                mock_accept_time_step_result: Some(Ok(())),
+                mock_consume_recovery_code_result: None,
+                expect_failed_attempt: false,
+                expected_result: Ok(()),
+            },
+            TestCase {
+                description: "When the totp code is valid, I should receive no error",
+                mock_get_details_result: Ok(enrolled.clone()),
+                mock_verify_code_result: Some(Ok(Some(2))),
+                mock_accept_time_step_result: Some(Ok(())),
+                mock_consume_recovery_code_result: None,
+                expect_failed_attempt: false,
+                expected_result: Ok(()),
+            },
+            TestCase {
+                description: "When the totp code is from the time step last accepted, I should receive an invalid code error",
+                mock_get_details_result: Ok(enrolled.clone()),
+                mock_verify_code_result: Some(Ok(Some(1))),
+                mock_accept_time_step_result: None,
+                mock_consume_recovery_code_result: None,
+                expect_failed_attempt: true,
+                expected_result: Err(MfaVerificationError::InvalidCode),
+            },
+            TestCase {
+                description: "When the totp code is accepted by another request first, I should receive an invalid code error",
+                mock_get_details_result: Ok(enrolled.clone()),
+                mock_verify_code_result: Some(Ok(Some(2))),
+                mock_accept_time_step_result: Some(Err(
+                    AcceptTotpTimeStepError::TimeStepAlreadyUsed,
+                )),
+                mock_consume_recovery_code_result: None,
+                expect_failed_attempt: true,
+                expected_result: Err(MfaVerificationError::InvalidCode),
+            },
+            TestCase {
+                description: "When the code is neither a valid totp code nor an unused recovery code, I should receive an invalid code error",
+                mock_get_details_result: Ok(enrolled.clone()),
+                mock_verify_code_result: Some(Ok(None)),
+                mock_accept_time_step_result: None,
+                mock_consume_recovery_code_result: Some(Err(
+                    ConsumeRecoveryCodeError::CodeNotFound,
+                )),
+                expect_failed_attempt: true,
+                expected_result: Err(MfaVerificationError::InvalidCode),
+            },
+            TestCase {
+                description: "When the code is an unused recovery code, I should receive no error",
+                mock_get_details_result: Ok(enrolled.clone()),
+                mock_verify_code_result: Some(Ok(None)),
+                mock_accept_time_step_result: None,
+                mock_consume_recovery_code_result: Some(Ok(())),
+                expect_failed_attempt: false,
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockUserRepository::new();
+            let mut mock_totp_service = MockTotpService::new();
+            mock_repository
+                .expect_get_user_mfa_details()
+                .return_once(|_| test_case.mock_get_details_result);
+            if let Some(mock_verify_code_result) = test_case.mock_verify_code_result {
+                mock_totp_service
+                    .expect_verify_code()
+                    .withf(|secret, _| secret == "SECRET")
+                    .return_once(|_, _| mock_verify_code_result);
+            }
+            if let Some(mock_accept_time_step_result) = test_case.mock_accept_time_step_result {
+                mock_repository
+                    .expect_accept_totp_time_step()
+                    .withf(|_, time_step| *time_step == 2)
+                    .return_once(|_, _| mock_accept_time_step_result);
+            }
+            if let Some(mock_consume_recovery_code_result) =
+                test_case.mock_consume_recovery_code_result
+            {
+                mock_repository
+                    .expect_consume_recovery_code()
+                    .return_once(|_, _| mock_consume_recovery_code_result);
+            }
+            mock_repository
+                .expect_record_failed_mfa_attempt()
+                .withf(|_, max_attempts, lockout_minutes| {
+                    *max_attempts == MAX_FAILED_MFA_ATTEMPTS
+                        && *lockout_minutes == MFA_LOCKOUT_MINUTES
+                })
+                .times(usize::from(test_case.expect_failed_attempt))
+                .returning(|_, _, _| Ok(()));
+            let service = new_mfa_service(
+                Arc::new(mock_totp_service),
+                Arc::new(mock_repository),
+                new_test_secret_encryptor(),
+            );
+            let actual_result = service.verify("user", "123456").await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::features::user_authentication::repositories::UserRepository;
+use masjid_app_api_library::shared::services::secret_encryption::r#trait::SecretEncryptor;
+use masjid_app_api_library::shared::services::totp::r#trait::TotpService;
+use std::sync::Arc;
+
+pub(super) struct MfaServiceImpl {
+    pub(super) totp_service: Arc<dyn TotpService>,
+    pub(super) user_repository: Arc<dyn UserRepository>,
+    pub(super) secret_encryptor: Arc<dyn SecretEncryptor>,
+}
@@ -1,6 +1,12 @@
 mod authentication_service_impl;
 pub mod errors;
+pub mod forgotten_password_service;
+mod forgotten_password_service_impl;
 pub mod login_service;
+pub mod mfa_service;
+mod mfa_service_impl;
+mod password_change_service_impl;
+mod password_history;
 pub mod reset_password_service;
 mod service_factory;
 pub mod user_registration_service;
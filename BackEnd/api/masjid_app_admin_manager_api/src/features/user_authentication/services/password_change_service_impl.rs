@@ -0,0 +1,10 @@
+use crate::features::user_authentication::repositories::UserRepository;
+use masjid_app_api_library::shared::services::hashing::r#trait::HashingService;
+use masjid_app_api_library::shared::services::password_policy::r#trait::PasswordPolicy;
+use std::sync::Arc;
+
+pub(super) struct PasswordChangeServiceImpl {
+    pub(super) hashing_service: Arc<dyn HashingService>,
+    pub(super) user_repository: Arc<dyn UserRepository>,
+    pub(super) password_policy: Arc<dyn PasswordPolicy>,
+}
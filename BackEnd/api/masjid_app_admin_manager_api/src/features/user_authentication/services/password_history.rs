@@ -0,0 +1,25 @@
+use crate::features::user_authentication::errors::get_user_error::GetUserError;
+use crate::features::user_authentication::repositories::UserRepository;
+use masjid_app_api_library::shared::services::hashing::r#trait::HashingService;
+
+/// Number of previous passwords, including the current one, which cannot be reused
+pub(super) const PASSWORD_HISTORY_LENGTH: u32 = 5;
+
+pub(super) async fn is_recently_used_password(
+    hashing_service: &dyn HashingService,
+    user_repository: &dyn UserRepository,
+    username: &str,
+    password: &str,
+) -> Result<bool, GetUserError> {
+    let password_history = user_repository
+        .get_password_history(username, PASSWORD_HISTORY_LENGTH)
+        .await?;
+    Ok(password_history.iter().any(|previous_hash| {
+        hashing_service
+            .verify_hash(password.as_bytes(), previous_hash)
+            .unwrap_or_else(|_| {
+                tracing::warn!(username = username, "unable to verify previous password hash");
+                false
+            })
+    }))
+}
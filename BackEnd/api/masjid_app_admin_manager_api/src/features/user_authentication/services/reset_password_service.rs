@@ -1,9 +1,11 @@
 use crate::features::user_authentication::repositories::UserRepository;
-use crate::features::user_authentication::services::authentication_service_impl::AuthenticationServiceImpl;
 use crate::features::user_authentication::services::errors::reset_password_error::ResetPasswordError;
-use crate::new_authentication_service;
+use crate::features::user_authentication::services::password_change_service_impl::PasswordChangeServiceImpl;
+use crate::features::user_authentication::services::password_history::is_recently_used_password;
+use crate::new_password_change_service;
 use async_trait::async_trait;
 use masjid_app_api_library::shared::services::hashing::r#trait::HashingService;
+use masjid_app_api_library::shared::services::password_policy::r#trait::PasswordPolicy;
 use mockall::automock;
 use std::sync::Arc;
 
@@ -16,15 +18,28 @@ pub trait ResetPasswordService: Send + Sync {
         new_password: &str,
     ) -> Result<(), ResetPasswordError>;
 }
-new_authentication_service!(new_reset_password_service, ResetPasswordService);
+new_password_change_service!(new_reset_password_service, ResetPasswordService);
 
 #[async_trait]
-impl ResetPasswordService for AuthenticationServiceImpl {
+impl ResetPasswordService for PasswordChangeServiceImpl {
     async fn reset_password(
         &self,
         username: &str,
         new_password: &str,
     ) -> Result<(), ResetPasswordError> {
+        let email = self.user_repository.get_user_email(username).await?;
+        self.password_policy
+            .validate(new_password, &[username.to_owned(), email])?;
+        if is_recently_used_password(
+            &*self.hashing_service,
+            &*self.user_repository,
+            username,
+            new_password,
+        )
+        .await?
+        {
+            return Err(ResetPasswordError::PasswordRecentlyUsed);
+        }
         let hashed_password = self.hashing_service.hash(new_password.as_bytes())?;
         self.user_repository
             .update_user_password(username, &hashed_password)
@@ -36,47 +51,126 @@ impl ResetPasswordService for AuthenticationServiceImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::features::user_authentication::errors::get_user_error::GetUserError;
     use crate::features::user_authentication::errors::update_user_password_error::UpdateUserPasswordError;
     use crate::features::user_authentication::repositories::MockUserRepository;
     use masjid_app_api_library::shared::services::hashing::providers::HashingProvider;
     use masjid_app_api_library::shared::services::hashing::r#trait::new_hashing_service;
+    use masjid_app_api_library::shared::services::password_policy::errors::PasswordPolicyViolation;
+    use masjid_app_api_library::shared::services::password_policy::r#trait::MockPasswordPolicy;
 
     #[tokio::test]
     async fn test_reset_password_service_reset_password() {
         struct TestCase {
             description: &'static str,
-            mock_repository_result: Result<(), UpdateUserPasswordError>,
+            mock_email_result: Result<String, GetUserError>,
+            mock_password_policy_result: Result<(), PasswordPolicyViolation>,
+            mock_password_history: Vec<String>,
+            mock_repository_result: Option<Result<(), UpdateUserPasswordError>>,
             expected_result: Result<(), ResetPasswordError>,
         }
+        let previous_hash = bcrypt::hash("MyReplacementPassword", 4).unwrap();
         let test_cases = [
+            TestCase {
+                description: "When the user does not exist, I should get an error",
+                mock_email_result: Err(GetUserError::NotFound),
+                mock_password_policy_result: Ok(()),
+                mock_password_history: Vec::new(),
+                mock_repository_result: None,
+                expected_result: Err(ResetPasswordError::UserDoesNotExist),
+            },
+            TestCase {
+                description: "When the password does not meet the password policy, I should get an error",
+                mock_email_result: Ok("user@example.com".to_owned()),
+                mock_password_policy_result: Err(PasswordPolicyViolation::CommonlyBreached),
+                mock_password_history: Vec::new(),
+                mock_repository_result: None,
+                expected_result: Err(ResetPasswordError::PasswordPolicyViolation(
+                    PasswordPolicyViolation::CommonlyBreached,
+                )),
+            },
+            TestCase {
+                description: "When the password has recently been used, I should get an error",
+                mock_email_result: Ok("user@example.com".to_owned()),
+                mock_password_policy_result: Ok(()),
+                mock_password_history: vec![previous_hash.clone()],
+                mock_repository_result: None,
+                expected_result: Err(ResetPasswordError::PasswordRecentlyUsed),
+            },
             TestCase {
                 description: "When the repository fails to update the password, I should get an error",
-                mock_repository_result: Err(UpdateUserPasswordError::DatabaseError),
+                mock_email_result: Ok("user@example.com".to_owned()),
+                mock_password_policy_result: Ok(()),
+                mock_password_history: Vec::new(),
+                mock_repository_result: Some(Err(UpdateUserPasswordError::DatabaseError)),
                 expected_result: Err(ResetPasswordError::UnableToResetPassword),
             },
             TestCase {
                 description: "When resetting a password for a user that does not exist, I should get an error",
-                mock_repository_result: Err(UpdateUserPasswordError::UserDoesNotExist),
+                mock_email_result: Ok("user@example.com".to_owned()),
+                mock_password_policy_result: Ok(()),
+                mock_password_history: Vec::new(),
+                mock_repository_result: Some(Err(UpdateUserPasswordError::UserDoesNotExist)),
                 expected_result: Err(ResetPasswordError::UserDoesNotExist),
             },
             TestCase {
                 description: "When the repository successfully updates the user password, I should get no error",
-                mock_repository_result: Ok(()),
+                mock_email_result: Ok("user@example.com".to_owned()),
+                mock_password_policy_result: Ok(()),
+                mock_password_history: Vec::new(),
+                mock_repository_result: Some(Ok(())),
                 expected_result: Ok(()),
             },
         ];
         for test_case in test_cases {
             eprintln!("{}", test_case.description);
             let mut mock_repository = MockUserRepository::new();
+            let mut mock_password_policy = MockPasswordPolicy::new();
+            mock_repository
+                .expect_get_user_email()
+                .return_once(|_| test_case.mock_email_result);
+            mock_password_policy
+                .expect_validate()
+                .withf(|_, personal_information| {
+                    *personal_information == ["user".to_owned(), "user@example.com".to_owned()]
+                })
+                .return_once(|_, _| test_case.mock_password_policy_result);
             mock_repository
-                .expect_update_user_password()
-                .return_once(|_, _| test_case.mock_repository_result);
+                .expect_get_password_history()
+                .return_once(|_, _| Ok(test_case.mock_password_history));
+            if let Some(mock_repository_result) = test_case.mock_repository_result {
+                mock_repository
+                    .expect_update_user_password()
+                    .return_once(|_, _| mock_repository_result);
+            }
             let reset_password_service = new_reset_password_service(
                 new_hashing_service(HashingProvider::Bcrypt),
                 Arc::new(mock_repository),
+                Arc::new(mock_password_policy),
             );
-            let actual_result = reset_password_service.reset_password("", "");
-            assert!(matches!(test_case.expected_result, actual_result));
+            let actual_result = reset_password_service
+                .reset_password("user", "MyReplacementPassword")
+                .await;
+            assert!(matches!(
+                (test_case.expected_result, actual_result),
+                (Ok(()), Ok(()))
+                    | (
+                        Err(ResetPasswordError::PasswordPolicyViolation(_)),
+                        Err(ResetPasswordError::PasswordPolicyViolation(_))
+                    )
+                    | (
+                        Err(ResetPasswordError::PasswordRecentlyUsed),
+                        Err(ResetPasswordError::PasswordRecentlyUsed)
+                    )
+                    | (
+                        Err(ResetPasswordError::UnableToResetPassword),
+                        Err(ResetPasswordError::UnableToResetPassword)
+                    )
+                    | (
+                        Err(ResetPasswordError::UserDoesNotExist),
+                        Err(ResetPasswordError::UserDoesNotExist)
+                    )
+            ));
         }
     }
 }
@@ -1,13 +1,15 @@
 #[macro_export]
-macro_rules! new_authentication_service {
+macro_rules! new_password_change_service {
     ($service_name: ident, $trait_name: ident) => {
         pub fn $service_name(
             hashing_service: Arc<dyn HashingService>,
             user_repository: Arc<dyn UserRepository>,
+            password_policy: Arc<dyn PasswordPolicy>,
         ) -> Arc<dyn $trait_name> {
-            Arc::new(AuthenticationServiceImpl {
+            Arc::new(PasswordChangeServiceImpl {
                 hashing_service,
                 user_repository,
+                password_policy,
             })
         }
     };
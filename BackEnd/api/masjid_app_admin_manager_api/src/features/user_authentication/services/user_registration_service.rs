@@ -1,10 +1,11 @@
 use crate::features::user_authentication::models::user_account_dto::UserAccountDTO;
 use crate::features::user_authentication::repositories::UserRepository;
-use crate::features::user_authentication::services::authentication_service_impl::AuthenticationServiceImpl;
 use crate::features::user_authentication::services::errors::user_registration_error::UserRegistrationError;
-use crate::new_authentication_service;
+use crate::features::user_authentication::services::password_change_service_impl::PasswordChangeServiceImpl;
+use crate::new_password_change_service;
 use async_trait::async_trait;
 use masjid_app_api_library::shared::services::hashing::r#trait::HashingService;
+use masjid_app_api_library::shared::services::password_policy::r#trait::PasswordPolicy;
 use mockall::automock;
 use std::sync::Arc;
 
@@ -14,14 +15,18 @@ pub trait UserRegistrationService: Send + Sync {
     async fn register_user(&self, new_user: UserAccountDTO) -> Result<(), UserRegistrationError>;
 }
 
-new_authentication_service!(new_user_registration_service, UserRegistrationService);
+new_password_change_service!(new_user_registration_service, UserRegistrationService);
 
 #[async_trait]
-impl UserRegistrationService for AuthenticationServiceImpl {
+impl UserRegistrationService for PasswordChangeServiceImpl {
     async fn register_user(
         &self,
         mut new_user: UserAccountDTO,
     ) -> Result<(), UserRegistrationError> {
+        self.password_policy.validate(
+            &new_user.password,
+            &[new_user.username.clone(), new_user.email.clone()],
+        )?;
         new_user.password = self.hashing_service.hash(&new_user.password.as_bytes())?;
         self.user_repository
             .insert_new_user(new_user)
@@ -37,16 +42,34 @@ mod tests {
     use crate::features::user_authentication::repositories::MockUserRepository;
     use masjid_app_api_library::shared::services::hashing::providers::HashingProvider;
     use masjid_app_api_library::shared::services::hashing::r#trait::new_hashing_service;
+    use masjid_app_api_library::shared::services::password_policy::errors::PasswordPolicyViolation;
+    use masjid_app_api_library::shared::services::password_policy::r#trait::MockPasswordPolicy;
 
     #[tokio::test]
     async fn test_user_registration_service_register_user() {
         struct TestCase {
             description: &'static str,
             new_user: UserAccountDTO,
-            mock_repository_result: Result<(), InsertNewUserError>,
+            mock_password_policy_result: Result<(), PasswordPolicyViolation>,
+            mock_repository_result: Option<Result<(), InsertNewUserError>>,
             expected_result: Result<(), UserRegistrationError>,
         }
         let test_cases = [
+            TestCase {
+                description: "When the password does not meet the password policy, I should receive a password policy error",
+                new_user: UserAccountDTO {
+                    full_name: "".to_owned(),
+                    email: "".to_owned(),
+                    role: "".to_owned(),
+                    username: "".to_owned(),
+                    password: "".to_owned(),
+                },
+                mock_password_policy_result: Err(PasswordPolicyViolation::TooWeak),
+                mock_repository_result: None,
+                expected_result: Err(UserRegistrationError::PasswordPolicyViolation(
+                    PasswordPolicyViolation::TooWeak,
+                )),
+            },
             TestCase {
                 description: "When registration fails, I should receive a database error",
                 new_user: UserAccountDTO {
@@ -56,7 +79,8 @@ mod tests {
                     username: "".to_owned(),
                     password: "".to_owned(),
                 },
-                mock_repository_result: Err(InsertNewUserError::DatabaseError),
+                mock_password_policy_result: Ok(()),
+                mock_repository_result: Some(Err(InsertNewUserError::DatabaseError)),
                 expected_result: Err(UserRegistrationError::UnableToRegisterToRepository),
             },
             TestCase {
@@ -69,7 +93,8 @@ mod tests {
                     username: "".to_owned(),
                     password: "".to_owned(),
                 },
-                mock_repository_result: Err(InsertNewUserError::UserExists),
+                mock_password_policy_result: Ok(()),
+                mock_repository_result: Some(Err(InsertNewUserError::UserExists)),
                 expected_result: Err(UserRegistrationError::UserAlreadyRegistered),
             },
             TestCase {
@@ -81,19 +106,27 @@ mod tests {
                     username: "".to_owned(),
                     password: "".to_owned(),
                 },
-                mock_repository_result: Ok(()),
+                mock_password_policy_result: Ok(()),
+                mock_repository_result: Some(Ok(())),
                 expected_result: Ok(()),
             },
         ];
         for test_case in test_cases {
             let mut mock_repository = MockUserRepository::new();
+            let mut mock_password_policy = MockPasswordPolicy::new();
             eprintln!("{}", test_case.description);
-            mock_repository
-                .expect_insert_new_user()
-                .return_once(|_| test_case.mock_repository_result);
+            mock_password_policy
+                .expect_validate()
+                .return_once(|_, _| test_case.mock_password_policy_result);
+            if let Some(mock_repository_result) = test_case.mock_repository_result {
+                mock_repository
+                    .expect_insert_new_user()
+                    .return_once(|_| mock_repository_result);
+            }
             let registration_service = new_user_registration_service(
                 new_hashing_service(HashingProvider::Bcrypt),
                 Arc::new(mock_repository),
+                Arc::new(mock_password_policy),
             );
             let actual_result = registration_service.register_user(test_case.new_user).await;
             assert!(matches!(test_case.expected_result, actual_result));
@@ -0,0 +1,139 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::user_management::errors::delete_user_error::DeleteUserError;
+use crate::features::user_management::services::UserManagementService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+pub async fn delete_user(
+    State(state): State<AuditedServiceAppState<Arc<dyn UserManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path(user_id): Path<i32>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if user_id <= 0 {
+        return (StatusCode::BAD_REQUEST, "user ids must be positive").into_response();
+    }
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::User, Some(user_id))
+        .await;
+    match state.service.delete_user(user_id).await {
+        Ok(()) => {
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Delete,
+                    entity_type: AuditEntityType::User,
+                    entity_id: Some(user_id),
+                    before,
+                    after: None,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(DeleteUserError::UserNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(DeleteUserError::LastAdmin) => (
+            StatusCode::CONFLICT,
+            "at least one enabled Admin account must remain",
+        )
+            .into_response(),
+        Err(DeleteUserError::UnableToDeleteUser) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::user_management::services::MockUserManagementService;
+
+    #[tokio::test]
+    async fn test_delete_user() {
+        struct TestCase {
+            description: &'static str,
+            claims: Claims,
+            user_id: i32,
+            expected_service_response: Option<Result<(), DeleteUserError>>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the user is not an Admin, I should get a FORBIDDEN response",
+                claims: Claims::generate("imam", "Imam"),
+                user_id: 1,
+                expected_service_response: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            TestCase {
+                description: "When the user id is invalid, I should get a BAD_REQUEST response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 0,
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When deleting a non-existent user, I should get a NOT_FOUND response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 1,
+                expected_service_response: Some(Err(DeleteUserError::UserNotFound)),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When deleting the last Admin, I should get a CONFLICT response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 1,
+                expected_service_response: Some(Err(DeleteUserError::LastAdmin)),
+                expected_status_code: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When deletion fails, I should get an INTERNAL_SERVER_ERROR response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 1,
+                expected_service_response: Some(Err(DeleteUserError::UnableToDeleteUser)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When deletion succeeds, I should get an OK response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 1,
+                expected_service_response: Some(Ok(())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockUserManagementService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_delete_user()
+                    .return_once(move |_| expected_service_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn UserManagementService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = delete_user(
+                State(app_state),
+                test_case.claims,
+                RequestId::default(),
+                Path(test_case.user_id),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::features::user_management::errors::get_users_error::GetUsersError;
+use crate::features::user_management::services::UserManagementService;
+use crate::shared::jwt::Claims;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn get_user(
+    State(state): State<ServiceAppState<Arc<dyn UserManagementService>>>,
+    claims: Claims,
+    Path(user_id): Path<i32>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if user_id <= 0 {
+        return (StatusCode::BAD_REQUEST, "user ids must be positive").into_response();
+    }
+    match state.service.get_user(user_id).await {
+        Ok(user) => (StatusCode::OK, Json(user)).into_response(),
+        Err(GetUsersError::UserNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetUsersError::UnableToGetUsers) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
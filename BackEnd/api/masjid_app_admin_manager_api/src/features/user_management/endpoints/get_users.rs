@@ -0,0 +1,99 @@
+use crate::features::user_management::errors::get_users_error::GetUsersError;
+use crate::features::user_management::models::get_users_request::GetUsersRequest;
+use crate::features::user_management::services::UserManagementService;
+use crate::shared::jwt::Claims;
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn get_users(
+    State(state): State<ServiceAppState<Arc<dyn UserManagementService>>>,
+    claims: Claims,
+    Query(request): Query<GetUsersRequest>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.service.get_users(request.role).await {
+        Ok(users) => (StatusCode::OK, Json(users)).into_response(),
+        Err(GetUsersError::UserNotFound) | Err(GetUsersError::UnableToGetUsers) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::user_management::models::user_details_dto::UserDetailsDTO;
+    use crate::features::user_management::services::MockUserManagementService;
+
+    #[tokio::test]
+    async fn test_get_users() {
+        struct TestCase {
+            description: &'static str,
+            claims: Claims,
+            role: Option<String>,
+            expected_service_response: Option<Result<Vec<UserDetailsDTO>, GetUsersError>>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the user is not an Admin, I should get a FORBIDDEN response",
+                claims: Claims::generate("imam", "Imam"),
+                role: None,
+                expected_service_response: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            TestCase {
+                description: "When the role filter is invalid, I should get a BAD_REQUEST response",
+                claims: Claims::generate("admin", "Admin"),
+                role: Some("Caretaker".to_owned()),
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the users cannot be retrieved, I should get an INTERNAL_SERVER_ERROR response",
+                claims: Claims::generate("admin", "Admin"),
+                role: None,
+                expected_service_response: Some(Err(GetUsersError::UnableToGetUsers)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When the users are retrieved, I should get an OK response",
+                claims: Claims::generate("admin", "Admin"),
+                role: Some("Imam".to_owned()),
+                expected_service_response: Some(Ok(Vec::new())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockUserManagementService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_get_users()
+                    .return_once(move |_| expected_service_response);
+            }
+            let app_state = ServiceAppState::<Arc<dyn UserManagementService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response = get_users(
+                State(app_state),
+                test_case.claims,
+                Query(GetUsersRequest {
+                    role: test_case.role,
+                }),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod delete_user;
+pub(crate) mod get_user;
+pub(crate) mod get_users;
+pub(crate) mod update_user;
+pub(crate) mod update_user_status;
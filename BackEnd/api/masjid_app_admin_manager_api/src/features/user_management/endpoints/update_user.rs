@@ -0,0 +1,149 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::user_management::errors::update_user_error::UpdateUserError;
+use crate::features::user_management::models::update_user_request::UpdateUserRequest;
+use crate::features::user_management::services::UserManagementService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn update_user(
+    State(state): State<AuditedServiceAppState<Arc<dyn UserManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path(user_id): Path<i32>,
+    Json(request): Json<UpdateUserRequest>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if user_id <= 0 || request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::User, Some(user_id))
+        .await;
+    match state.service.update_user(user_id, request).await {
+        Ok(()) => {
+            let after = state
+                .audit_log
+                .take_snapshot(AuditEntityType::User, Some(user_id))
+                .await;
+            state
+                .audit_log
+                .record_change(AuditedChange {
+                    actor: claims.sub,
+                    action: AuditAction::Update,
+                    entity_type: AuditEntityType::User,
+                    entity_id: Some(user_id),
+                    before,
+                    after,
+                    request_id,
+                })
+                .await;
+            StatusCode::OK.into_response()
+        }
+        Err(UpdateUserError::UserNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpdateUserError::LastAdmin) => (
+            StatusCode::CONFLICT,
+            "at least one enabled Admin account must remain",
+        )
+            .into_response(),
+        Err(UpdateUserError::UnableToUpdateUser) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::user_management::services::MockUserManagementService;
+
+    #[tokio::test]
+    async fn test_update_user() {
+        struct TestCase {
+            description: &'static str,
+            claims: Claims,
+            request: UpdateUserRequest,
+            expected_service_response: Option<Result<(), UpdateUserError>>,
+            expected_status_code: StatusCode,
+        }
+        let valid_request = UpdateUserRequest {
+            full_name: "Zayd McArdle".to_owned(),
+            email: "zaydmcardle@example.com".to_owned(),
+            role: "Imam".to_owned(),
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the user is not an Admin, I should get a FORBIDDEN response",
+                claims: Claims::generate("imam", "Imam"),
+                request: valid_request.clone(),
+                expected_service_response: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            TestCase {
+                description: "When the role is invalid, I should get a BAD_REQUEST response",
+                claims: Claims::generate("admin", "Admin"),
+                request: UpdateUserRequest {
+                    role: "Caretaker".to_owned(),
+                    ..valid_request.clone()
+                },
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the user does not exist, I should get a NOT_FOUND response",
+                claims: Claims::generate("admin", "Admin"),
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(UpdateUserError::UserNotFound)),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When the update would demote the last Admin, I should get a CONFLICT response",
+                claims: Claims::generate("admin", "Admin"),
+                request: valid_request.clone(),
+                expected_service_response: Some(Err(UpdateUserError::LastAdmin)),
+                expected_status_code: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When the update succeeds, I should get an OK response",
+                claims: Claims::generate("admin", "Admin"),
+                request: valid_request.clone(),
+                expected_service_response: Some(Ok(())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockUserManagementService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_update_user()
+                    .return_once(move |_, _| expected_service_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn UserManagementService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = update_user(
+                State(app_state),
+                test_case.claims,
+                RequestId::default(),
+                Path(1),
+                Json(test_case.request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::features::audit_log::models::audit_action::AuditAction;
+use crate::features::audit_log::models::audit_entity_type::AuditEntityType;
+use crate::features::audit_log::models::audited_change::AuditedChange;
+use crate::features::user_management::errors::update_user_error::UpdateUserError;
+use crate::features::user_management::services::UserManagementService;
+use crate::shared::audited_app_state::AuditedServiceAppState;
+use crate::shared::jwt::Claims;
+use crate::shared::request_id::RequestId;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+pub async fn disable_user(
+    State(state): State<AuditedServiceAppState<Arc<dyn UserManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path(user_id): Path<i32>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if user_id <= 0 {
+        return (StatusCode::BAD_REQUEST, "user ids must be positive").into_response();
+    }
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::User, Some(user_id))
+        .await;
+    let update_result = state.service.disable_user(user_id).await;
+    if update_result.is_ok() {
+        let after = state
+            .audit_log
+            .take_snapshot(AuditEntityType::User, Some(user_id))
+            .await;
+        state
+            .audit_log
+            .record_change(AuditedChange {
+                actor: claims.sub,
+                action: AuditAction::Update,
+                entity_type: AuditEntityType::User,
+                entity_id: Some(user_id),
+                before,
+                after,
+                request_id,
+            })
+            .await;
+    }
+    send_response_for_status_update(update_result)
+}
+
+pub async fn enable_user(
+    State(state): State<AuditedServiceAppState<Arc<dyn UserManagementService>>>,
+    claims: Claims,
+    RequestId(request_id): RequestId,
+    Path(user_id): Path<i32>,
+) -> Response {
+    if claims.aud != "Admin" {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if user_id <= 0 {
+        return (StatusCode::BAD_REQUEST, "user ids must be positive").into_response();
+    }
+    let before = state
+        .audit_log
+        .take_snapshot(AuditEntityType::User, Some(user_id))
+        .await;
+    let update_result = state.service.enable_user(user_id).await;
+    if update_result.is_ok() {
+        let after = state
+            .audit_log
+            .take_snapshot(AuditEntityType::User, Some(user_id))
+            .await;
+        state
+            .audit_log
+            .record_change(AuditedChange {
+                actor: claims.sub,
+                action: AuditAction::Update,
+                entity_type: AuditEntityType::User,
+                entity_id: Some(user_id),
+                before,
+                after,
+                request_id,
+            })
+            .await;
+    }
+    send_response_for_status_update(update_result)
+}
+
+fn send_response_for_status_update(update_result: Result<(), UpdateUserError>) -> Response {
+    match update_result {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(UpdateUserError::UserNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(UpdateUserError::LastAdmin) => (
+            StatusCode::CONFLICT,
+            "at least one enabled Admin account must remain",
+        )
+            .into_response(),
+        Err(UpdateUserError::UnableToUpdateUser) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::audit_log::services::new_test_audit_log_service;
+    use crate::features::user_management::services::MockUserManagementService;
+
+    #[tokio::test]
+    async fn test_disable_user() {
+        struct TestCase {
+            description: &'static str,
+            claims: Claims,
+            user_id: i32,
+            expected_service_response: Option<Result<(), UpdateUserError>>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the user is not an Admin, I should get a FORBIDDEN response",
+                claims: Claims::generate("imam", "Imam"),
+                user_id: 1,
+                expected_service_response: None,
+                expected_status_code: StatusCode::FORBIDDEN,
+            },
+            TestCase {
+                description: "When the user id is invalid, I should get a BAD_REQUEST response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 0,
+                expected_service_response: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When disabling the last Admin, I should get a CONFLICT response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 1,
+                expected_service_response: Some(Err(UpdateUserError::LastAdmin)),
+                expected_status_code: StatusCode::CONFLICT,
+            },
+            TestCase {
+                description: "When the user is disabled, I should get an OK response",
+                claims: Claims::generate("admin", "Admin"),
+                user_id: 1,
+                expected_service_response: Some(Ok(())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockUserManagementService::new();
+            if let Some(expected_service_response) = test_case.expected_service_response {
+                mock_service
+                    .expect_disable_user()
+                    .return_once(move |_| expected_service_response);
+            }
+            let app_state = AuditedServiceAppState::<Arc<dyn UserManagementService>> {
+                service: Arc::new(mock_service),
+                audit_log: new_test_audit_log_service(),
+            };
+            let actual_response = disable_user(
+                State(app_state),
+                test_case.claims,
+                RequestId::default(),
+                Path(test_case.user_id),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeleteUserError {
+    UserNotFound,
+    /// The deletion would leave no enabled Admin accounts
+    LastAdmin,
+    UnableToDeleteUser,
+}
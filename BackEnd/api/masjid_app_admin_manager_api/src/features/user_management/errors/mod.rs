@@ -0,0 +1,3 @@
+pub mod delete_user_error;
+pub mod get_users_error;
+pub mod update_user_error;
@@ -0,0 +1,7 @@
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpdateUserError {
+    UserNotFound,
+    /// The change would leave no enabled Admin accounts
+    LastAdmin,
+    UnableToUpdateUser,
+}
@@ -0,0 +1,9 @@
+use crate::features::user_authentication::models::registration_request::validate_role;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate)]
+pub struct GetUsersRequest {
+    #[validate(custom(function = "validate_role"))]
+    pub role: Option<String>,
+}
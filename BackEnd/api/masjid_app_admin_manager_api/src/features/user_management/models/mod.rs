@@ -0,0 +1,3 @@
+pub mod get_users_request;
+pub mod update_user_request;
+pub mod user_details_dto;
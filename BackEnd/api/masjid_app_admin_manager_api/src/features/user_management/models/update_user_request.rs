@@ -0,0 +1,14 @@
+use crate::features::user_authentication::models::registration_request::validate_role;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone)]
+pub struct UpdateUserRequest {
+    #[validate(length(min = 2, message = "First name cannot be empty"))]
+    #[serde(rename(deserialize = "fullName"))]
+    pub full_name: String,
+    #[validate(email)]
+    pub email: String,
+    #[validate(custom(function = "validate_role"))]
+    pub role: String,
+}
@@ -0,0 +1,15 @@
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq)]
+pub struct UserDetailsDTO {
+    pub id: i32,
+    #[serde(rename(serialize = "fullName"))]
+    pub full_name: String,
+    pub email: String,
+    pub username: String,
+    pub role: String,
+    #[serde(rename(serialize = "isDisabled"))]
+    pub is_disabled: bool,
+    #[serde(rename(serialize = "isMfaEnabled"))]
+    pub is_mfa_enabled: bool,
+}
@@ -0,0 +1,30 @@
+use crate::features::user_management::errors::delete_user_error::DeleteUserError;
+use crate::features::user_management::errors::get_users_error::GetUsersError;
+use crate::features::user_management::errors::update_user_error::UpdateUserError;
+use crate::features::user_management::models::update_user_request::UpdateUserRequest;
+use crate::features::user_management::models::user_details_dto::UserDetailsDTO;
+use async_trait::async_trait;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use std::sync::Arc;
+
+mod mysql_impl;
+
+#[automock]
+#[async_trait]
+pub trait UserManagementRepository: Send + Sync {
+    async fn get_users(&self, role: Option<String>) -> Result<Vec<UserDetailsDTO>, GetUsersError>;
+    async fn get_user_by_id(&self, id: i32) -> Result<UserDetailsDTO, GetUsersError>;
+    async fn update_user_details(
+        &self,
+        id: i32,
+        user_details: UpdateUserRequest,
+    ) -> Result<(), UpdateUserError>;
+    async fn set_user_disabled(&self, id: i32, is_disabled: bool) -> Result<(), UpdateUserError>;
+    async fn delete_user(&self, id: i32) -> Result<(), DeleteUserError>;
+}
+
+pub async fn new_user_management_repository() -> Arc<dyn UserManagementRepository> {
+    Arc::new(MySqlRepository::new(RepositoryType::Authentication).await)
+}
@@ -0,0 +1,150 @@
+use crate::features::user_management::errors::delete_user_error::DeleteUserError;
+use crate::features::user_management::errors::get_users_error::GetUsersError;
+use crate::features::user_management::errors::update_user_error::UpdateUserError;
+use crate::features::user_management::models::update_user_request::UpdateUserRequest;
+use crate::features::user_management::models::user_details_dto::UserDetailsDTO;
+use crate::features::user_management::repositories::UserManagementRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use sqlx::{Error, Row};
+
+const USER_NOT_FOUND_MESSAGE: &str = "User not found";
+const LAST_ADMIN_MESSAGE: &str = "Cannot remove the last Admin";
+
+fn map_user_details(row: sqlx::mysql::MySqlRow) -> UserDetailsDTO {
+    UserDetailsDTO {
+        id: row.get(0),
+        full_name: row.get(1),
+        email: row.get(2),
+        username: row.get(3),
+        role: row.get(4),
+        is_disabled: row.get(5),
+        is_mfa_enabled: row.get(6),
+    }
+}
+
+// The stored procedures signal these conditions so that the checks happen within their transaction
+fn signalled_message(err: &Error) -> Option<&'static str> {
+    if let Error::Database(database_error) = err {
+        return match database_error.message() {
+            USER_NOT_FOUND_MESSAGE => Some(USER_NOT_FOUND_MESSAGE),
+            LAST_ADMIN_MESSAGE => Some(LAST_ADMIN_MESSAGE),
+            _ => None,
+        };
+    }
+    None
+}
+
+#[async_trait]
+impl UserManagementRepository for MySqlRepository {
+    async fn get_users(&self, role: Option<String>) -> Result<Vec<UserDetailsDTO>, GetUsersError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_users(?);")
+            .bind(&role)
+            .map(map_user_details)
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    role = ?role,
+                    error = err.to_string(),
+                    "an error occurred whilst retrieving users",
+                );
+                GetUsersError::UnableToGetUsers
+            })
+    }
+
+    async fn get_user_by_id(&self, id: i32) -> Result<UserDetailsDTO, GetUsersError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_user_by_id(?);")
+            .bind(id)
+            .map(map_user_details)
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if matches!(err, Error::RowNotFound) {
+                    return GetUsersError::UserNotFound;
+                }
+                tracing::error!(
+                    user_id = id,
+                    error = err.to_string(),
+                    "an error occurred whilst retrieving user",
+                );
+                GetUsersError::UnableToGetUsers
+            })
+    }
+
+    async fn update_user_details(
+        &self,
+        id: i32,
+        user_details: UpdateUserRequest,
+    ) -> Result<(), UpdateUserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL update_user_details(?, ?, ?, ?);")
+            .bind(id)
+            .bind(&user_details.full_name)
+            .bind(&user_details.email)
+            .bind(&user_details.role)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| match signalled_message(&err) {
+                Some(USER_NOT_FOUND_MESSAGE) => UpdateUserError::UserNotFound,
+                Some(LAST_ADMIN_MESSAGE) => UpdateUserError::LastAdmin,
+                _ => {
+                    tracing::error!(
+                        user_id = id,
+                        error = err.to_string(),
+                        "an error occurred whilst updating user details",
+                    );
+                    UpdateUserError::UnableToUpdateUser
+                }
+            })?;
+        tracing::info!(user_id = id, "user details updated");
+        Ok(())
+    }
+
+    async fn set_user_disabled(&self, id: i32, is_disabled: bool) -> Result<(), UpdateUserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL set_user_disabled(?, ?);")
+            .bind(id)
+            .bind(is_disabled)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| match signalled_message(&err) {
+                Some(USER_NOT_FOUND_MESSAGE) => UpdateUserError::UserNotFound,
+                Some(LAST_ADMIN_MESSAGE) => UpdateUserError::LastAdmin,
+                _ => {
+                    tracing::error!(
+                        user_id = id,
+                        error = err.to_string(),
+                        "an error occurred whilst updating user status",
+                    );
+                    UpdateUserError::UnableToUpdateUser
+                }
+            })?;
+        tracing::info!(user_id = id, is_disabled = is_disabled, "user status updated");
+        Ok(())
+    }
+
+    async fn delete_user(&self, id: i32) -> Result<(), DeleteUserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL delete_user(?);")
+            .bind(id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| match signalled_message(&err) {
+                Some(USER_NOT_FOUND_MESSAGE) => DeleteUserError::UserNotFound,
+                Some(LAST_ADMIN_MESSAGE) => DeleteUserError::LastAdmin,
+                _ => {
+                    tracing::error!(
+                        user_id = id,
+                        error = err.to_string(),
+                        "an error occurred whilst deleting user",
+                    );
+                    DeleteUserError::UnableToDeleteUser
+                }
+            })?;
+        tracing::info!(user_id = id, "user deleted");
+        Ok(())
+    }
+}
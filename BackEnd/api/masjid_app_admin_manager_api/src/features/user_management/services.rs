@@ -0,0 +1,65 @@
+use crate::features::user_management::errors::delete_user_error::DeleteUserError;
+use crate::features::user_management::errors::get_users_error::GetUsersError;
+use crate::features::user_management::errors::update_user_error::UpdateUserError;
+use crate::features::user_management::models::update_user_request::UpdateUserRequest;
+use crate::features::user_management::models::user_details_dto::UserDetailsDTO;
+use crate::features::user_management::repositories::UserManagementRepository;
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait UserManagementService: Send + Sync {
+    async fn get_users(&self, role: Option<String>) -> Result<Vec<UserDetailsDTO>, GetUsersError>;
+    async fn get_user(&self, id: i32) -> Result<UserDetailsDTO, GetUsersError>;
+    async fn update_user(
+        &self,
+        id: i32,
+        user_details: UpdateUserRequest,
+    ) -> Result<(), UpdateUserError>;
+    async fn disable_user(&self, id: i32) -> Result<(), UpdateUserError>;
+    async fn enable_user(&self, id: i32) -> Result<(), UpdateUserError>;
+    async fn delete_user(&self, id: i32) -> Result<(), DeleteUserError>;
+}
+
+struct UserManagementServiceImpl {
+    repository: Arc<dyn UserManagementRepository>,
+}
+
+pub fn new_user_management_service(
+    repository: Arc<dyn UserManagementRepository>,
+) -> Arc<dyn UserManagementService> {
+    Arc::new(UserManagementServiceImpl { repository })
+}
+
+#[async_trait]
+impl UserManagementService for UserManagementServiceImpl {
+    async fn get_users(&self, role: Option<String>) -> Result<Vec<UserDetailsDTO>, GetUsersError> {
+        self.repository.get_users(role).await
+    }
+
+    async fn get_user(&self, id: i32) -> Result<UserDetailsDTO, GetUsersError> {
+        self.repository.get_user_by_id(id).await
+    }
+
+    async fn update_user(
+        &self,
+        id: i32,
+        user_details: UpdateUserRequest,
+    ) -> Result<(), UpdateUserError> {
+        self.repository.update_user_details(id, user_details).await
+    }
+
+    async fn disable_user(&self, id: i32) -> Result<(), UpdateUserError> {
+        self.repository.set_user_disabled(id, true).await
+    }
+
+    async fn enable_user(&self, id: i32) -> Result<(), UpdateUserError> {
+        self.repository.set_user_disabled(id, false).await
+    }
+
+    async fn delete_user(&self, id: i32) -> Result<(), DeleteUserError> {
+        self.repository.delete_user(id).await
+    }
+}
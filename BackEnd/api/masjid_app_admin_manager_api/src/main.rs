@@ -2,10 +2,14 @@ mod features;
 mod shared;
 
 use crate::features::ask_imam::repositories::new_imam_questions_admin_repository;
+use crate::features::audit_log::repositories::new_audit_log_repository;
+use crate::features::event_registrations::repositories::new_event_registrations_admin_repository;
+use crate::features::event_reminders::repositories::new_event_reminders_admin_repository;
 use crate::features::events::repositories::new_events_admin_repository;
 use crate::features::prayer_times::repositories::new_prayer_times_admin_repository;
 use crate::features::user_authentication;
 use crate::features::user_authentication::repositories::new_user_repository;
+use crate::features::user_management::repositories::new_user_management_repository;
 
 use crate::features::ask_imam::endpoints::delete_imam_question::delete_imam_question;
 use crate::features::ask_imam::endpoints::get_imam_questions::get_imam_questions;
@@ -14,57 +18,179 @@ use crate::features::ask_imam::endpoints::{
     delete_imam_question, get_imam_questions, provide_answer_for_imam_question,
 };
 use crate::features::ask_imam::services::{AskImamAdminService, new_ask_imam_admin_service};
+use crate::features::audit_log::endpoints::get_audit_log::get_audit_log;
+use crate::features::audit_log::services::{AuditLogService, new_audit_log_service};
+use crate::features::event_registrations::endpoints::check_in_attendee::check_in_attendee;
+use crate::features::event_registrations::endpoints::export_event_registrations::export_event_registrations;
+use crate::features::event_registrations::endpoints::get_event_attendance::get_event_attendance;
+use crate::features::event_registrations::endpoints::get_event_registrations::get_event_registrations;
+use crate::features::event_registrations::services::{
+    EventRegistrationAdminService, new_event_registration_admin_service,
+};
+use crate::features::event_reminders::services::new_event_reminder_scheduler;
+use crate::features::events::endpoints::delete_category::delete_category;
 use crate::features::events::endpoints::delete_event::delete_event;
+use crate::features::events::endpoints::delete_event_exception::delete_event_exception;
+use crate::features::events::endpoints::delete_room::delete_room;
+use crate::features::events::endpoints::delete_speaker::delete_speaker;
+use crate::features::events::endpoints::export_events::export_events;
+use crate::features::events::endpoints::find_duplicate_events::find_duplicate_events;
+use crate::features::events::endpoints::get_categories::get_categories;
+use crate::features::events::endpoints::get_event::get_event;
+use crate::features::events::endpoints::get_event_occurrences::get_event_occurrences;
 use crate::features::events::endpoints::get_events::get_events;
+use crate::features::events::endpoints::get_rooms::get_rooms;
+use crate::features::events::endpoints::get_speakers::get_speakers;
+use crate::features::events::endpoints::get_tags::get_tags;
+use crate::features::events::endpoints::import_events::import_events;
 use crate::features::events::endpoints::publish_event::upsert_events;
+use crate::features::events::endpoints::upsert_category::upsert_category;
+use crate::features::events::endpoints::upsert_event_exception::upsert_event_exception;
+use crate::features::events::endpoints::upsert_room::upsert_room;
+use crate::features::events::endpoints::upsert_speaker::upsert_speaker;
+use crate::features::events::models::duplicate_detection_settings::DuplicateDetectionSettings;
+use crate::features::events::services::category_management_service::{
+    CategoryManagementService, new_category_management_service,
+};
 use crate::features::events::services::event_deletion_service::{
     EventDeletionService, new_event_deletion_service,
 };
+use crate::features::events::services::event_duplicate_detection_service::{
+    EventDuplicateDetectionService, new_event_duplicate_detection_service,
+};
+use crate::features::events::services::event_exception_service::{
+    EventExceptionService, new_event_exception_service,
+};
+use crate::features::events::services::event_import_service::{
+    EventImportService, new_event_import_service,
+};
+use crate::features::events::services::event_lifecycle_service::new_event_lifecycle_service;
 use crate::features::events::services::event_publishing_service::{
     EventPublishingService, new_event_publishing_service,
 };
+use crate::features::events::services::room_management_service::{
+    RoomManagementService, new_room_management_service,
+};
+use crate::features::events::services::speaker_management_service::{
+    SpeakerManagementService, new_speaker_management_service,
+};
 use crate::features::prayer_times::endpoints::get_prayer_times::get_prayer_times;
 use crate::features::prayer_times::endpoints::update_prayer_times::update_prayer_times;
 use crate::features::prayer_times::services::prayer_times_update_service::PrayerTimesUpdateService;
 use crate::features::prayer_times::services::prayer_times_update_service::new_prayer_times_update_service;
+use crate::features::user_authentication::services::forgotten_password_service::new_forgotten_password_service;
 use crate::features::user_authentication::services::login_service::new_login_service;
+use crate::features::user_authentication::services::mfa_service::new_mfa_service;
 use crate::features::user_authentication::services::reset_password_service::new_reset_password_service;
 use crate::features::user_authentication::services::user_registration_service::new_user_registration_service;
+use crate::features::user_management::endpoints::delete_user::delete_user;
+use crate::features::user_management::endpoints::get_user::get_user;
+use crate::features::user_management::endpoints::get_users::get_users;
+use crate::features::user_management::endpoints::update_user::update_user;
+use crate::features::user_management::endpoints::update_user_status::{disable_user, enable_user};
+use crate::features::user_management::services::{
+    UserManagementService, new_user_management_service,
+};
+use crate::shared::audited_app_state::{
+    AuditedFileHandlingServiceAppState, AuditedServiceAppState,
+};
 use axum::Router;
 use axum::routing::{delete, get, patch, post, put};
+use chrono::Utc;
+use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+use masjid_app_api_library::features::events::repositories::new_events_cache_repository;
 use masjid_app_api_library::features::events::services::event_retrieval_service::{
     EventRetrievalService, new_event_retrieval_service,
 };
+use masjid_app_api_library::features::events::services::events_cache_warming_service::new_events_cache_warming_service;
 use masjid_app_api_library::features::prayer_times::services::prayer_times_retrieval_service::{
     PrayerTimesRetrievalService, new_prayer_times_retrieval_service,
 };
 use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
 use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
 use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::extractors::file_handler::FileHandler;
 use masjid_app_api_library::shared::logging::logging;
-use masjid_app_api_library::shared::services::hashing::providers::HashingProvider;
+use masjid_app_api_library::shared::services::hashing::providers::{
+    Argon2Parameters, HashingProvider,
+};
 use masjid_app_api_library::shared::services::hashing::r#trait::new_hashing_service;
+use masjid_app_api_library::shared::services::mail::providers::MailProvider;
+use masjid_app_api_library::shared::services::mail::r#trait::new_mail_sender;
+use masjid_app_api_library::shared::services::notifications::providers::NotificationProvider;
+use masjid_app_api_library::shared::services::notifications::r#trait::new_notifier;
+use masjid_app_api_library::shared::services::password_policy::settings::PasswordPolicySettings;
+use masjid_app_api_library::shared::services::password_policy::r#trait::new_password_policy;
+use masjid_app_api_library::shared::services::secret_encryption::r#trait::new_secret_encryptor;
+use masjid_app_api_library::shared::services::token_signing::r#trait::new_token_signer;
+use masjid_app_api_library::shared::services::totp::r#trait::new_totp_service;
 use masjid_app_api_library::shared::types::app_state::ServiceAppState;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// How often scheduled events are published and finished events are archived
+const EVENT_LIFECYCLE_INTERVAL: Duration = Duration::from_secs(60);
+/// How often due event reminders are sent
+const EVENT_REMINDER_INTERVAL: Duration = Duration::from_secs(60);
 
 async fn map_user_authentication() -> Router {
+    let audit_log = new_audit_log_service(new_audit_log_repository().await);
     let login_app_state = ServiceAppState {
         service: new_login_service(
-            new_hashing_service(HashingProvider::Bcrypt),
+            new_hashing_service(HashingProvider::Argon2id(
+                Argon2Parameters::from_environment(),
+            )),
             new_user_repository().await,
         ),
     };
     let user_registration_app_state = ServiceAppState {
         service: new_user_registration_service(
-            new_hashing_service(HashingProvider::Bcrypt),
+            new_hashing_service(HashingProvider::Argon2id(
+                Argon2Parameters::from_environment(),
+            )),
             new_user_repository().await,
+            new_password_policy(PasswordPolicySettings::from_environment()),
         ),
     };
-    let reset_password_app_state = ServiceAppState {
+    let reset_password_app_state = AuditedServiceAppState {
         service: new_reset_password_service(
-            new_hashing_service(HashingProvider::Bcrypt),
+            new_hashing_service(HashingProvider::Argon2id(
+                Argon2Parameters::from_environment(),
+            )),
             new_user_repository().await,
+            new_password_policy(PasswordPolicySettings::from_environment()),
         ),
+        audit_log: audit_log.clone(),
+    };
+    let mfa_app_state = ServiceAppState {
+        service: new_mfa_service(
+            new_totp_service("MasjidApp"),
+            new_user_repository().await,
+            new_secret_encryptor(
+                std::env::var("MFA_SECRET_ENCRYPTION_KEY")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        ),
+    };
+    let mfa_policy_app_state = AuditedServiceAppState {
+        service: mfa_app_state.service.clone(),
+        audit_log: audit_log.clone(),
+    };
+    let forgotten_password_app_state = ServiceAppState {
+        service: new_forgotten_password_service(
+            new_hashing_service(HashingProvider::Argon2id(
+                Argon2Parameters::from_environment(),
+            )),
+            new_user_repository().await,
+            new_mail_sender(MailProvider::from_environment()),
+            new_password_policy(PasswordPolicySettings::from_environment()),
+            std::env::var("PASSWORD_RESET_URL").unwrap(),
+        ),
+    };
+    let token_password_reset_app_state = AuditedServiceAppState {
+        service: forgotten_password_app_state.service.clone(),
+        audit_log,
     };
 
     Router::new()
@@ -80,6 +206,38 @@ async fn map_user_authentication() -> Router {
             patch(user_authentication::endpoints::reset_user_password),
         )
         .with_state(reset_password_app_state)
+        .route(
+            "/mfa/verify",
+            post(user_authentication::endpoints::verify_mfa),
+        )
+        .route(
+            "/mfa/enrolment",
+            post(user_authentication::endpoints::begin_mfa_enrolment),
+        )
+        .route(
+            "/mfa/enrolment/confirm",
+            post(user_authentication::endpoints::confirm_mfa_enrolment),
+        )
+        .route(
+            "/mfa/disable",
+            post(user_authentication::endpoints::disable_mfa),
+        )
+        .with_state(mfa_app_state)
+        .route(
+            "/mfa/policy",
+            put(user_authentication::endpoints::update_mfa_policy),
+        )
+        .with_state(mfa_policy_app_state)
+        .route(
+            "/forgotten-password",
+            post(user_authentication::endpoints::request_password_reset),
+        )
+        .with_state(forgotten_password_app_state)
+        .route(
+            "/forgotten-password/reset",
+            post(user_authentication::endpoints::reset_password_with_token),
+        )
+        .with_state(token_password_reset_app_state)
 }
 async fn map_prayer_times() -> Router {
     let get_prayer_times_app_state = ServiceAppState::<Arc<dyn PrayerTimesRetrievalService>> {
@@ -90,13 +248,14 @@ async fn map_prayer_times() -> Router {
                 .await,
         ),
     };
-    let update_prayer_times_app_state = ServiceAppState::<Arc<dyn PrayerTimesUpdateService>> {
+    let update_prayer_times_app_state = AuditedServiceAppState::<Arc<dyn PrayerTimesUpdateService>> {
         service: new_prayer_times_update_service(
             new_prayer_times_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql))
                 .await,
             new_prayer_times_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis))
                 .await,
         ),
+        audit_log: new_audit_log_service(new_audit_log_repository().await),
     };
     Router::new()
         .route("/", get(get_prayer_times))
@@ -112,29 +271,145 @@ async fn map_events() -> Router {
         service: new_event_retrieval_service(
             new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
             new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+            EventVisibility::All,
+        ),
+    };
+    let duplicate_detection_app_state = ServiceAppState::<Arc<dyn EventDuplicateDetectionService>> {
+        service: new_event_duplicate_detection_service(
+            get_events_app_state.service.clone(),
+            DuplicateDetectionSettings::from_environment(),
         ),
     };
 
-    let upsert_events_app_state = ServiceAppState::<Arc<dyn EventPublishingService>> {
+    let file_handler = FileHandler::from_environment();
+    let audit_log = new_audit_log_service(new_audit_log_repository().await);
+    let upsert_events_app_state = AuditedFileHandlingServiceAppState::<
+        Arc<dyn EventPublishingService>,
+    > {
         service: new_event_publishing_service(
             new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
             new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
         ),
+        file_handler: file_handler.clone(),
+        audit_log: audit_log.clone(),
     };
 
-    let delete_event_app_state = ServiceAppState::<Arc<dyn EventDeletionService>> {
+    let delete_event_app_state = AuditedFileHandlingServiceAppState::<
+        Arc<dyn EventDeletionService>,
+    > {
         service: new_event_deletion_service(
             new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
             new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
         ),
+        file_handler: file_handler.clone(),
+        audit_log: audit_log.clone(),
+    };
+    let event_exception_app_state = AuditedServiceAppState::<Arc<dyn EventExceptionService>> {
+        service: new_event_exception_service(
+            new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+            new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+        ),
+        audit_log: audit_log.clone(),
+    };
+    let event_import_app_state = AuditedServiceAppState::<Arc<dyn EventImportService>> {
+        service: new_event_import_service(
+            new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+            new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+        ),
+        audit_log: audit_log.clone(),
+    };
+    let room_management_app_state = AuditedServiceAppState::<Arc<dyn RoomManagementService>> {
+        service: new_room_management_service(
+            new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+            new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+        ),
+        audit_log: audit_log.clone(),
+    };
+    let category_management_app_state = AuditedServiceAppState::<
+        Arc<dyn CategoryManagementService>,
+    > {
+        service: new_category_management_service(
+            new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+            new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+        ),
+        audit_log: audit_log.clone(),
+    };
+    let speaker_management_app_state = AuditedFileHandlingServiceAppState::<
+        Arc<dyn SpeakerManagementService>,
+    > {
+        service: new_speaker_management_service(
+            new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+            new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+        ),
+        file_handler,
+        audit_log: audit_log.clone(),
+    };
+    let event_registrations_app_state = ServiceAppState::<Arc<dyn EventRegistrationAdminService>> {
+        service: new_event_registration_admin_service(
+            new_event_registrations_admin_repository(RepositoryMode::Normal(
+                NormalDbProvider::MySql,
+            ))
+            .await,
+            new_event_retrieval_service(
+                new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+                new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis))
+                    .await,
+                EventVisibility::All,
+            ),
+            new_token_signer(
+                std::env::var("REGISTRATION_TOKEN_SECRET")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        ),
+    };
+    // Check-ins are recorded in the audit log, whereas reading registrations and attendance is not
+    let check_in_app_state = AuditedServiceAppState {
+        service: event_registrations_app_state.service.clone(),
+        audit_log,
     };
     Router::new()
         .route("/", get(get_events))
+        .route("/occurrences", get(get_event_occurrences))
+        .route("/rooms", get(get_rooms))
+        .route("/speakers", get(get_speakers))
+        .route("/categories", get(get_categories))
+        .route("/tags", get(get_tags))
+        .route("/export", get(export_events))
+        .route("/{id}", get(get_event))
         .with_state(get_events_app_state)
+        .route("/duplicates", get(find_duplicate_events))
+        .with_state(duplicate_detection_app_state)
         .route("/", put(upsert_events))
         .with_state(upsert_events_app_state)
         .route("/{id}", delete(delete_event))
         .with_state(delete_event_app_state)
+        .route("/exceptions", put(upsert_event_exception))
+        .route(
+            "/{id}/exceptions/{occurrence_date}",
+            delete(delete_event_exception),
+        )
+        .with_state(event_exception_app_state)
+        .route("/import", post(import_events))
+        .with_state(event_import_app_state)
+        .route("/rooms", put(upsert_room))
+        .route("/rooms/{id}", delete(delete_room))
+        .with_state(room_management_app_state)
+        .route("/categories", put(upsert_category))
+        .route("/categories/{id}", delete(delete_category))
+        .with_state(category_management_app_state)
+        .route("/speakers", put(upsert_speaker))
+        .route("/speakers/{id}", delete(delete_speaker))
+        .with_state(speaker_management_app_state)
+        .route("/{id}/registrations", get(get_event_registrations))
+        .route(
+            "/{id}/registrations/export",
+            get(export_event_registrations),
+        )
+        .route("/{id}/attendance", get(get_event_attendance))
+        .with_state(event_registrations_app_state)
+        .route("/check-ins", post(check_in_attendee))
+        .with_state(check_in_app_state)
 }
 async fn map_ask_imam() -> Router {
     let state = ServiceAppState::<Arc<dyn AskImamAdminService>> {
@@ -147,10 +422,42 @@ async fn map_ask_imam() -> Router {
             .await,
         ),
     };
+    // Answers and deletions are recorded in the audit log, whereas reading the questions is not
+    let audited_state = AuditedServiceAppState {
+        service: state.service.clone(),
+        audit_log: new_audit_log_service(new_audit_log_repository().await),
+    };
     Router::new()
         .route("/", get(get_imam_questions))
+        .with_state(state)
         .route("/", put(provide_answer_for_imam_question))
         .route("/{question_id}", delete(delete_imam_question))
+        .with_state(audited_state)
+}
+async fn map_user_management() -> Router {
+    let state = ServiceAppState::<Arc<dyn UserManagementService>> {
+        service: new_user_management_service(new_user_management_repository().await),
+    };
+    let audited_state = AuditedServiceAppState {
+        service: state.service.clone(),
+        audit_log: new_audit_log_service(new_audit_log_repository().await),
+    };
+    Router::new()
+        .route("/", get(get_users))
+        .route("/{id}", get(get_user))
+        .with_state(state)
+        .route("/{id}", put(update_user))
+        .route("/{id}", delete(delete_user))
+        .route("/{id}/disable", post(disable_user))
+        .route("/{id}/enable", post(enable_user))
+        .with_state(audited_state)
+}
+async fn map_audit_log() -> Router {
+    let state = ServiceAppState::<Arc<dyn AuditLogService>> {
+        service: new_audit_log_service(new_audit_log_repository().await),
+    };
+    Router::new()
+        .route("/", get(get_audit_log))
         .with_state(state)
 }
 async fn map_endpoints() -> Router {
@@ -162,19 +469,89 @@ async fn map_endpoints() -> Router {
     tracing::info!("Mapped Events Routes");
     let ask_imam_routes = map_ask_imam().await;
     tracing::info!("Mapped Ask Imam Routes");
+    let user_management_routes = map_user_management().await;
+    tracing::info!("Mapped User Management Routes");
+    let audit_log_routes = map_audit_log().await;
+    tracing::info!("Mapped Audit Log Routes");
     let router = Router::new();
     router
         .nest("/authentication", authentication_routes)
         .nest("/prayer-times", prayer_times_routes)
         .nest("/events", events_routes)
         .nest("/ask-imam", ask_imam_routes)
+        .nest("/users", user_management_routes)
+        .nest("/audit-log", audit_log_routes)
+}
+
+async fn spawn_event_lifecycle_task() {
+    let service = new_event_lifecycle_service(
+        new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+        new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EVENT_LIFECYCLE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = service.apply_status_transitions(Utc::now()).await {
+                tracing::error!(error = ?err, "unable to apply event status transitions");
+            }
+        }
+    });
+}
+
+async fn spawn_event_reminder_task() {
+    let scheduler = new_event_reminder_scheduler(
+        new_event_reminders_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+        new_event_retrieval_service(
+            new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+            new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+            EventVisibility::Published,
+        ),
+        new_notifier(NotificationProvider::push_from_environment()),
+        new_notifier(NotificationProvider::Email {
+            mail_provider: MailProvider::from_environment(),
+        }),
+        // Signed with the secret the public API verifies unsubscribe tokens with
+        new_token_signer(
+            std::env::var("REGISTRATION_TOKEN_SECRET")
+                .unwrap()
+                .as_bytes(),
+        ),
+        std::env::var("REMINDER_UNSUBSCRIBE_URL").unwrap(),
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EVENT_REMINDER_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = scheduler.send_due_reminders(Utc::now()).await {
+                tracing::error!(error = ?err, "unable to send event reminders");
+            }
+        }
+    });
+}
+
+async fn warm_events_cache() {
+    let service = new_events_cache_warming_service(
+        new_events_admin_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+        new_events_cache_repository().await,
+    );
+    match service.warm_events_cache().await {
+        Ok(count) => tracing::info!(count, "Warmed Events Cache"),
+        // Events are read from the database until the cache has been warmed
+        Err(err) => tracing::error!(error = ?err, "unable to warm events cache"),
+    }
 }
 
 #[tokio::main]
 async fn main() {
     logging::setup();
     tracing::info!("MasjidApp Admin Manager API starting up");
+    warm_events_cache().await;
     let app = map_endpoints().await;
+    spawn_event_lifecycle_task().await;
+    tracing::info!("Started Event Lifecycle Task");
+    spawn_event_reminder_task().await;
+    tracing::info!("Started Event Reminder Task");
     let listener = tokio::net::TcpListener::bind(&"0.0.0.0:3000")
         .await
         .unwrap();
@@ -0,0 +1,25 @@
+use crate::features::audit_log::services::AuditLogService;
+use axum::extract::FromRef;
+use masjid_app_api_library::shared::extractors::file_handler::FileHandler;
+use std::sync::Arc;
+
+/// App state for endpoints whose changes are recorded in the audit log
+#[derive(Clone)]
+pub struct AuditedServiceAppState<T> {
+    pub service: T,
+    pub audit_log: Arc<dyn AuditLogService>,
+}
+
+/// App state for endpoints which read or write uploaded files and whose changes are recorded in
+/// the audit log
+#[derive(Clone)]
+pub struct AuditedFileHandlingServiceAppState<T> {
+    pub service: T,
+    pub file_handler: FileHandler,
+    pub audit_log: Arc<dyn AuditLogService>,
+}
+impl<T> FromRef<AuditedFileHandlingServiceAppState<T>> for FileHandler {
+    fn from_ref(state: &AuditedFileHandlingServiceAppState<T>) -> Self {
+        state.file_handler.clone()
+    }
+}
@@ -0,0 +1,168 @@
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseCsvError {
+    /// A quoted field was still open at the end of the file. The line is where the field began
+    UnterminatedQuotedField { line: usize },
+}
+
+/// Quotes a field when it contains a delimiter, and neutralises values a spreadsheet would
+/// otherwise evaluate as a formula, since most of what is exported was supplied by the public
+pub fn escape_csv_field(field: &str) -> String {
+    let field = if field.starts_with(FORMULA_PREFIXES) {
+        format!("'{}", field)
+    } else {
+        field.to_owned()
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+/// Reverses the formula neutralisation of `escape_csv_field`, so exported files can be imported
+/// again unchanged
+pub fn unescape_csv_field(field: String) -> String {
+    match field.strip_prefix('\'') {
+        Some(value) if value.starts_with(FORMULA_PREFIXES) => value.to_owned(),
+        _ => field,
+    }
+}
+
+/// Splits RFC 4180 CSV into its records, accepting either line ending and skipping blank lines
+pub fn parse_csv(csv: &str) -> Result<Vec<Vec<String>>, ParseCsvError> {
+    let mut records: Vec<Vec<String>> = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut is_quoted = false;
+    let mut line = 1;
+    let mut quoted_field_line = line;
+    let mut characters = csv.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(character) = characters.next() {
+        if is_quoted {
+            match character {
+                '"' if characters.peek() == Some(&'"') => {
+                    characters.next();
+                    field.push('"');
+                }
+                '"' => is_quoted = false,
+                _ => {
+                    if character == '\n' {
+                        line += 1;
+                    }
+                    field.push(character);
+                }
+            }
+            continue;
+        }
+        match character {
+            '"' if field.is_empty() => {
+                is_quoted = true;
+                quoted_field_line = line;
+            }
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if characters.peek() == Some(&'\n') => {}
+            '\n' => {
+                line += 1;
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|value| !value.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            _ => field.push(character),
+        }
+    }
+    if is_quoted {
+        return Err(ParseCsvError::UnterminatedQuotedField {
+            line: quoted_field_line,
+        });
+    }
+    record.push(field);
+    if record.iter().any(|value| !value.is_empty()) {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv() {
+        struct TestCase {
+            description: &'static str,
+            csv: &'static str,
+            expected_result: Result<Vec<Vec<&'static str>>, ParseCsvError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the fields are plain, each line should be a record",
+                csv: "title,date\r\nQuran class,2026-01-05\r\n",
+                expected_result: Ok(vec![
+                    vec!["title", "date"],
+                    vec!["Quran class", "2026-01-05"],
+                ]),
+            },
+            TestCase {
+                description: "When fields are quoted, they may contain delimiters, line breaks and escaped quotes",
+                csv: "title,description\n\"Smith, \"\"John\"\"\",\"Line one\nLine two\"",
+                expected_result: Ok(vec![
+                    vec!["title", "description"],
+                    vec!["Smith, \"John\"", "Line one\nLine two"],
+                ]),
+            },
+            TestCase {
+                description: "When the file starts with a byte order mark or has blank lines, they should be ignored",
+                csv: "\u{feff}title,\n\n,\nIftar,\n",
+                expected_result: Ok(vec![vec!["title", ""], vec!["Iftar", ""]]),
+            },
+            TestCase {
+                description: "When a quoted field is never closed, I should be told the line it started on",
+                csv: "title\nIftar\n\"Youth circle\n",
+                expected_result: Err(ParseCsvError::UnterminatedQuotedField { line: 3 }),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let expected_result = test_case.expected_result.map(|records| {
+                records
+                    .into_iter()
+                    .map(|record| record.into_iter().map(str::to_owned).collect())
+                    .collect::<Vec<Vec<String>>>()
+            });
+            assert_eq!(expected_result, parse_csv(test_case.csv));
+        }
+    }
+
+    #[test]
+    fn test_unescape_csv_field() {
+        struct TestCase {
+            description: &'static str,
+            field: &'static str,
+            expected_field: &'static str,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the field was neutralised on export, the prefix should be removed",
+                field: "'+447123456789",
+                expected_field: "+447123456789",
+            },
+            TestCase {
+                description: "When the field genuinely starts with an apostrophe, it should be kept",
+                field: "'Asr' prayer",
+                expected_field: "'Asr' prayer",
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_field,
+                unescape_csv_field(test_case.field.to_owned())
+            );
+            assert_eq!(test_case.field, escape_csv_field(test_case.expected_field));
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::{StatusCode, header};
+
+/// The version a change was made against, taken from the `If-Match` header. Versions are sent to
+/// clients as the `ETag` of what they retrieve
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct IfMatch(pub Option<u32>);
+
+pub fn entity_tag(version: u32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Only a single strong entity tag is accepted, since a change can only be made against one
+/// version
+fn parse_entity_tag(value: &str) -> Option<u32> {
+    value
+        .trim()
+        .strip_prefix('"')?
+        .strip_suffix('"')?
+        .parse()
+        .ok()
+}
+
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(header::IF_MATCH) else {
+            return Ok(Self(None));
+        };
+        value
+            .to_str()
+            .ok()
+            .and_then(parse_entity_tag)
+            .map(|version| Self(Some(version)))
+            .ok_or(StatusCode::BAD_REQUEST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entity_tag() {
+        struct TestCase {
+            description: &'static str,
+            value: &'static str,
+            expected_version: Option<u32>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the entity tag is quoted, I should get its version",
+                value: "\"3\"",
+                expected_version: Some(3),
+            },
+            TestCase {
+                description: "When the entity tag is not quoted, it should be rejected",
+                value: "3",
+                expected_version: None,
+            },
+            TestCase {
+                description: "When the entity tag is weak, it should be rejected",
+                value: "W/\"3\"",
+                expected_version: None,
+            },
+            TestCase {
+                description: "When any version is allowed, it should be rejected",
+                value: "*",
+                expected_version: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_version,
+                parse_entity_tag(test_case.value)
+            );
+            if let Some(version) = test_case.expected_version {
+                assert_eq!(test_case.value, entity_tag(version));
+            }
+        }
+    }
+}
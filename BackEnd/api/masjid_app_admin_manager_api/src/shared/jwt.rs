@@ -9,6 +9,7 @@ use axum_extra::{
 };
 use jsonwebtoken::errors::ErrorKind;
 use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::hash::Hash;
@@ -19,6 +20,9 @@ pub struct AuthenticatedUser {
     pub role: String,
 }
 
+pub const MFA_CHALLENGE_AUDIENCE: &str = "MfaChallenge";
+pub const MFA_ENROLMENT_AUDIENCE: &str = "MfaEnrolment";
+
 pub static KEYS: LazyLock<ApiKeys> =
     LazyLock::new(|| ApiKeys::new(std::env::var("JWT_SECRET").unwrap().as_bytes()));
 
@@ -77,6 +81,67 @@ impl Claims {
     }
 }
 
+/// Short-lived claims issued once a user's password has been verified, but before they have
+/// completed the second authentication factor
+#[derive(Clone, Serialize, Deserialize, Default)]
+pub struct MfaChallengeClaims {
+    // Issuer
+    pub iss: String,
+    // Subscriber
+    pub sub: String,
+    // Audience, which determines whether the user must verify or enrol
+    pub aud: String,
+    // Role granted to the user once the challenge has been completed
+    pub role: String,
+    // Issued At
+    pub iat: usize,
+    // Expiration Time
+    pub exp: usize,
+}
+
+impl MfaChallengeClaims {
+    fn is_valid(&self) -> bool {
+        !self.sub.is_empty() && !self.role.is_empty() && self.iat < self.exp
+    }
+    pub fn generate(sub: &str, role: &str, aud: &str) -> Self {
+        let issued_at = chrono::Utc::now().timestamp() as usize;
+
+        //The challenge expires after 5 minutes of being issued
+        let expiration_date = issued_at + (5 * 60);
+
+        Self {
+            iss: "MasjidApp".to_owned(),
+            sub: sub.to_owned(),
+            aud: aud.to_owned(),
+            role: role.to_owned(),
+            iat: issued_at,
+            exp: expiration_date,
+        }
+    }
+}
+
+/// The user enrolling in 2FA, who is either fully authenticated or has been forced to enrol
+/// whilst logging in
+pub enum MfaEnrolmentPrincipal {
+    Authenticated(Claims),
+    PendingEnrolment(MfaChallengeClaims),
+}
+
+impl MfaEnrolmentPrincipal {
+    pub fn username(&self) -> &str {
+        match self {
+            MfaEnrolmentPrincipal::Authenticated(claims) => &claims.sub,
+            MfaEnrolmentPrincipal::PendingEnrolment(claims) => &claims.sub,
+        }
+    }
+    pub fn role(&self) -> &str {
+        match self {
+            MfaEnrolmentPrincipal::Authenticated(claims) => &claims.aud,
+            MfaEnrolmentPrincipal::PendingEnrolment(claims) => &claims.role,
+        }
+    }
+}
+
 pub struct ApiKeys {
     pub encoding: EncodingKey,
     pub decoding: DecodingKey,
@@ -91,7 +156,7 @@ impl ApiKeys {
     }
 }
 
-pub fn generate_token(claims: &Claims) -> Result<String, ClaimsError> {
+pub fn generate_token<T: Serialize>(claims: &T) -> Result<String, ClaimsError> {
     let encoded_token_result = jsonwebtoken::encode(&Header::default(), claims, &KEYS.encoding);
 
     match encoded_token_result {
@@ -102,6 +167,44 @@ pub fn generate_token(claims: &Claims) -> Result<String, ClaimsError> {
         }
     }
 }
+async fn decode_bearer_token<T: DeserializeOwned>(
+    parts: &mut Parts,
+    audiences: &[&str],
+) -> Result<T, AuthorisationError> {
+    // Extract the token from the authorization header
+    let TypedHeader(Authorization(bearer)) = parts
+        .extract::<TypedHeader<Authorization<Bearer>>>()
+        .await
+        .map_err(|_| AuthorisationError::InvalidToken)?;
+
+    // Configure validation
+    let mut validation = Validation::default();
+    // HashSet of valid issuers
+    validation.iss = Some(["MasjidApp".into()].into_iter().collect());
+    // HashSet of valid audiences
+    validation.aud = Some(audiences.iter().map(|aud| aud.to_string()).collect());
+    // Only allow HS256
+    validation.algorithms = vec![Algorithm::HS256];
+    // Check expiration
+    validation.validate_exp = true;
+
+    // Decode and validate token
+    match decode::<T>(bearer.token().trim(), &KEYS.decoding, &validation) {
+        Ok(token_data) => Ok(token_data.claims),
+        Err(error) => match error.kind() {
+            ErrorKind::InvalidAudience
+            | ErrorKind::InvalidToken
+            | ErrorKind::InvalidIssuer
+            | ErrorKind::InvalidSignature => Err(AuthorisationError::InvalidToken),
+            ErrorKind::ExpiredSignature => Err(AuthorisationError::ExpiredToken),
+            _ => {
+                tracing::error!("unexpected error has occurred: {}", error);
+                Err(AuthorisationError::UnknownError)
+            }
+        },
+    }
+}
+
 impl<S> FromRequestParts<S> for Claims
 where
     S: Send + Sync,
@@ -109,45 +212,46 @@ where
     type Rejection = AuthorisationError;
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        // Extract the token from the authorization header
-        let TypedHeader(Authorization(bearer)) = parts
-            .extract::<TypedHeader<Authorization<Bearer>>>()
-            .await
-            .map_err(|_| Self::Rejection::InvalidToken)?;
-
-        // Configure validation
-        let mut validation = Validation::default();
-        // HashSet of valid issuers
-        validation.iss = Some(["MasjidApp".into()].into_iter().collect());
-        // HashSet of valid audiences
-        validation.aud = Some(["Admin".into(), "Imam".into()].into_iter().collect());
-        // Only allow HS256
-        validation.algorithms = vec![Algorithm::HS256];
-        // Check expiration
-        validation.validate_exp = true;
-
-        // Decode and validate token
-        let token_data_result =
-            decode::<Claims>(bearer.token().trim(), &KEYS.decoding, &validation);
-        match token_data_result {
-            Ok(token_data) => {
-                if !token_data.claims.is_valid() {
-                    return Err(Self::Rejection::InvalidToken);
-                }
-                Ok(token_data.claims)
-            }
-            Err(error) => match error.kind() {
-                ErrorKind::InvalidAudience
-                | ErrorKind::InvalidToken
-                | ErrorKind::InvalidIssuer
-                | ErrorKind::InvalidSignature => Err(Self::Rejection::InvalidToken),
-                ErrorKind::ExpiredSignature => Err(Self::Rejection::ExpiredToken),
-                _ => {
-                    tracing::error!("unexpected error has occurred: {}", error);
-                    Err(Self::Rejection::UnknownError)
-                }
-            },
+        let claims = decode_bearer_token::<Claims>(parts, &["Admin", "Imam"]).await?;
+        if !claims.is_valid() {
+            return Err(Self::Rejection::InvalidToken);
+        }
+        Ok(claims)
+    }
+}
+
+impl<S> FromRequestParts<S> for MfaChallengeClaims
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthorisationError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let claims =
+            decode_bearer_token::<MfaChallengeClaims>(parts, &[MFA_CHALLENGE_AUDIENCE]).await?;
+        if !claims.is_valid() {
+            return Err(Self::Rejection::InvalidToken);
+        }
+        Ok(claims)
+    }
+}
+
+impl<S> FromRequestParts<S> for MfaEnrolmentPrincipal
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthorisationError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Ok(claims) = Claims::from_request_parts(parts, state).await {
+            return Ok(Self::Authenticated(claims));
+        }
+        let claims =
+            decode_bearer_token::<MfaChallengeClaims>(parts, &[MFA_ENROLMENT_AUDIENCE]).await?;
+        if !claims.is_valid() {
+            return Err(Self::Rejection::InvalidToken);
         }
+        Ok(Self::PendingEnrolment(claims))
     }
 }
 impl IntoResponse for AuthorisationError {
@@ -1 +1,5 @@
+pub mod audited_app_state;
+pub mod csv;
+pub mod if_match;
 pub mod jwt;
+pub mod request_id;
@@ -0,0 +1,79 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use std::convert::Infallible;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+const MAXIMUM_REQUEST_ID_LENGTH: usize = 64;
+
+/// Identifies the request a change was made in, taken from the `X-Request-Id` header so that it
+/// can be matched with the logs of a proxy in front of the API. Requests without a usable id are
+/// given a random one
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RequestId(pub String);
+
+fn parse_request_id(value: &str) -> Option<String> {
+    let is_valid = !value.is_empty()
+        && value.len() <= MAXIMUM_REQUEST_ID_LENGTH
+        && value.chars().all(|c| c.is_ascii_graphic());
+    is_valid.then(|| value.to_owned())
+}
+
+impl<S> FromRequestParts<S> for RequestId
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let request_id = parts
+            .headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_request_id)
+            .unwrap_or_else(|| format!("{:032x}", rand::random::<u128>()));
+        Ok(Self(request_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_request_id() {
+        struct TestCase {
+            description: &'static str,
+            value: String,
+            expected_request_id: Option<String>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the request id is valid, I should get it unaltered",
+                value: "6f1c2b9e-3d4a-4e5f-8a7b-1c2d3e4f5a6b".to_owned(),
+                expected_request_id: Some("6f1c2b9e-3d4a-4e5f-8a7b-1c2d3e4f5a6b".to_owned()),
+            },
+            TestCase {
+                description: "When the request id is empty, it should be rejected",
+                value: String::new(),
+                expected_request_id: None,
+            },
+            TestCase {
+                description: "When the request id is too long, it should be rejected",
+                value: "a".repeat(MAXIMUM_REQUEST_ID_LENGTH + 1),
+                expected_request_id: None,
+            },
+            TestCase {
+                description: "When the request id contains whitespace, it should be rejected",
+                value: "request id".to_owned(),
+                expected_request_id: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_request_id,
+                parse_request_id(&test_case.value)
+            );
+        }
+    }
+}
@@ -1 +1,26 @@
+use testcontainers::core::{IntoContainerPort, WaitFor};
+use testcontainers::runners::AsyncRunner;
+use testcontainers::{ContainerAsync, GenericImage};
 
+/// Starts an empty Redis, pointing the connection string held in `environment_variable` at it
+pub async fn setup_container(environment_variable: &str) -> ContainerAsync<GenericImage> {
+    tracing::info!("Starting Redis");
+    let container = GenericImage::new("redis", "7-alpine")
+        .with_exposed_port(6379.tcp())
+        .with_wait_for(WaitFor::message_on_stdout("Ready to accept connections"))
+        .start()
+        .await
+        .unwrap();
+    tracing::info!("Redis started successfully");
+    let port = container.get_host_port_ipv4(6379).await.unwrap();
+    let connection_string = format!("redis://127.0.0.1:{port}/0");
+    tracing::debug!(
+        "Setting environment variable {} value to {}",
+        environment_variable,
+        &connection_string
+    );
+    unsafe {
+        std::env::set_var(environment_variable, connection_string);
+    }
+    container
+}
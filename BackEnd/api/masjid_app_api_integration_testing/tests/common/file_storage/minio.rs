@@ -0,0 +1,55 @@
+use masjid_app_api_library::shared::services::file_storage::providers::S3Settings;
+use s3::Region;
+use s3::bucket::Bucket;
+use s3::bucket_ops::BucketConfiguration;
+use s3::creds::Credentials;
+use testcontainers::core::{IntoContainerPort, WaitFor};
+use testcontainers::runners::AsyncRunner;
+use testcontainers::{ContainerAsync, GenericImage, ImageExt};
+
+const ACCESS_KEY_ID: &'static str = "minioadmin";
+const SECRET_ACCESS_KEY: &'static str = "minioadmin";
+const REGION: &'static str = "us-east-1";
+
+/// Starts MinIO as a stand in for S3, creating an empty bucket to store uploads in
+pub async fn setup_container(bucket: &str) -> (ContainerAsync<GenericImage>, S3Settings) {
+    tracing::info!("Starting MinIO");
+    let container = GenericImage::new("minio/minio", "latest")
+        .with_exposed_port(9000.tcp())
+        .with_wait_for(WaitFor::message_on_stdout("API:"))
+        .with_env_var("MINIO_ROOT_USER", ACCESS_KEY_ID)
+        .with_env_var("MINIO_ROOT_PASSWORD", SECRET_ACCESS_KEY)
+        .with_cmd(["server", "/data"])
+        .start()
+        .await
+        .unwrap();
+    tracing::info!("MinIO started successfully");
+    let port = container.get_host_port_ipv4(9000).await.unwrap();
+    let settings = S3Settings {
+        bucket: bucket.to_owned(),
+        region: REGION.to_owned(),
+        endpoint: format!("http://127.0.0.1:{port}"),
+        access_key_id: ACCESS_KEY_ID.to_owned(),
+        secret_access_key: SECRET_ACCESS_KEY.to_owned(),
+    };
+    Bucket::create_with_path_style(
+        bucket,
+        Region::Custom {
+            region: settings.region.clone(),
+            endpoint: settings.endpoint.clone(),
+        },
+        Credentials::new(
+            Some(ACCESS_KEY_ID),
+            Some(SECRET_ACCESS_KEY),
+            None,
+            None,
+            None,
+        )
+        .unwrap(),
+        BucketConfiguration::default(),
+    )
+    .await
+    .unwrap();
+    tracing::debug!("Created bucket {}", bucket);
+    (container, settings)
+}
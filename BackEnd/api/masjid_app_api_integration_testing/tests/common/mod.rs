@@ -1,5 +1,6 @@
 pub mod admin_manager_api;
 pub mod data_access_layer;
+pub mod file_storage;
 pub mod logging;
 pub mod mock_data;
 pub mod public_api;
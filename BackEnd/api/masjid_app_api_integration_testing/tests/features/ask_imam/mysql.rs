@@ -2,6 +2,7 @@ use crate::common::data_access_layer;
 use crate::common::data_access_layer::DatabaseCredentials;
 use crate::common::logging::setup_logging;
 use masjid_app_admin_manager_api::features::ask_imam::errors::delete_question_error::DeleteQuestionError;
+use masjid_app_admin_manager_api::features::ask_imam::errors::upsert_answer_to_question_error::UpsertAnswerToQuestionError;
 use masjid_app_admin_manager_api::features::ask_imam::repositories::new_imam_questions_admin_repository;
 use masjid_app_api_library::features::ask_imam::errors::get_questions_error::GetQuestionsError;
 use masjid_app_api_library::features::ask_imam::models::answer::Answer;
@@ -66,15 +67,26 @@ async fn test_ask_imam() {
         date_answered: date_question_answered,
     };
     let mut upsert_answer_to_question_result = admin_repository
-        .upsert_imam_answer_to_question(&1, &answer)
+        .upsert_imam_answer_to_question(&1, &1, &answer)
         .await;
     assert!(upsert_answer_to_question_result.is_ok());
 
     upsert_answer_to_question_result = admin_repository
-        .upsert_imam_answer_to_question(&7, &answer)
+        .upsert_imam_answer_to_question(&7, &1, &answer)
         .await;
     assert!(upsert_answer_to_question_result.is_ok());
 
+    eprintln!(
+        "When answering a question from a version that has since changed, I should receive an error"
+    );
+    upsert_answer_to_question_result = admin_repository
+        .upsert_imam_answer_to_question(&1, &1, &answer)
+        .await;
+    assert_eq!(
+        upsert_answer_to_question_result,
+        Err(UpsertAnswerToQuestionError::VersionMismatch)
+    );
+
     eprintln!(
         "When attempting to retrieve all questions for imam from database, I should get all questions without error"
     );
@@ -227,6 +239,7 @@ fn get_valid_questions() -> Vec<ImamQuestion> {
             imam_name: Some("Zayd".to_owned()),
             answer: Some("This is an answer".to_owned()),
             date_answered: Some(date_question_answered.clone()),
+            version: 1,
         },
         ImamQuestion {
             id: 2,
@@ -238,6 +251,7 @@ fn get_valid_questions() -> Vec<ImamQuestion> {
             imam_name: None,
             answer: None,
             date_answered: None,
+            version: 1,
         },
         ImamQuestion {
             id: 3,
@@ -249,6 +263,7 @@ fn get_valid_questions() -> Vec<ImamQuestion> {
             imam_name: None,
             answer: None,
             date_answered: None,
+            version: 1,
         },
         ImamQuestion {
             id: 4,
@@ -260,6 +275,7 @@ fn get_valid_questions() -> Vec<ImamQuestion> {
             imam_name: None,
             answer: None,
             date_answered: None,
+            version: 1,
         },
         ImamQuestion {
             id: 5,
@@ -271,6 +287,7 @@ fn get_valid_questions() -> Vec<ImamQuestion> {
             imam_name: None,
             answer: None,
             date_answered: None,
+            version: 1,
         },
         ImamQuestion {
             id: 6,
@@ -282,6 +299,7 @@ fn get_valid_questions() -> Vec<ImamQuestion> {
             imam_name: None,
             answer: None,
             date_answered: None,
+            version: 1,
         },
         ImamQuestion {
             id: 7,
@@ -293,6 +311,7 @@ fn get_valid_questions() -> Vec<ImamQuestion> {
             imam_name: Some("Zayd".to_owned()),
             answer: Some("This is an answer".to_owned()),
             date_answered: Some(date_question_answered),
+            version: 1,
         },
     ]
 }
@@ -0,0 +1,143 @@
+use crate::common::data_access_layer::{DatabaseCredentials, mysql};
+use crate::common::logging::setup_logging;
+use chrono::{Duration, Utc};
+use masjid_app_admin_manager_api::features::audit_log::models::audit_action::AuditAction;
+use masjid_app_admin_manager_api::features::audit_log::models::audit_entity_type::AuditEntityType;
+use masjid_app_admin_manager_api::features::audit_log::models::audit_log_filter::AuditLogFilter;
+use masjid_app_admin_manager_api::features::audit_log::models::audited_change::AuditedChange;
+use masjid_app_admin_manager_api::features::audit_log::repositories::new_audit_log_repository;
+use serde_json::json;
+
+fn unfiltered(page: u32, page_size: u32) -> AuditLogFilter {
+    AuditLogFilter {
+        actor: None,
+        action: None,
+        entity_type: None,
+        entity_id: None,
+        from: None,
+        to: None,
+        page,
+        page_size,
+    }
+}
+
+#[tokio::test]
+async fn test_audit_log() {
+    setup_logging();
+    let container = mysql::setup_container(DatabaseCredentials {
+        username: "auditlogadmin".to_owned(),
+        password: "changeme".to_owned(),
+        environment_variable: "AUDIT_LOG_CONNECTION".to_owned(),
+    })
+    .await;
+    let repository = new_audit_log_repository().await;
+
+    //Given the entity does not exist, I should get no snapshot
+    let snapshot = repository
+        .get_snapshot(AuditEntityType::Event, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(snapshot, None);
+    let snapshot = repository
+        .get_snapshot(AuditEntityType::User, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(snapshot, None);
+
+    //Given an event has no exceptions, I should get an empty snapshot rather than none
+    let snapshot = repository
+        .get_snapshot(AuditEntityType::EventExceptions, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(snapshot, Some(json!([])));
+    let snapshot = repository
+        .get_snapshot(AuditEntityType::MfaPolicy, None)
+        .await
+        .unwrap();
+    assert_eq!(snapshot, Some(json!([])));
+
+    //Given the user does not exist, I should get no user id
+    let user_id = repository.get_user_id("JohnSmith").await.unwrap();
+    assert_eq!(user_id, None);
+
+    //Given changes have been recorded, I should be able to page through them newest first
+    let started_at = Utc::now() - Duration::minutes(10);
+    let changes = [
+        AuditedChange {
+            actor: "JohnSmith".to_owned(),
+            action: AuditAction::Create,
+            entity_type: AuditEntityType::Event,
+            entity_id: None,
+            before: None,
+            after: Some(json!({"title": "Weekly halaqa"})),
+            request_id: "request-1".to_owned(),
+        },
+        AuditedChange {
+            actor: "JohnSmith".to_owned(),
+            action: AuditAction::Update,
+            entity_type: AuditEntityType::Event,
+            entity_id: Some(1),
+            before: Some(json!({"id": 1, "version": 1})),
+            after: Some(json!({"id": 1, "version": 2})),
+            request_id: "request-2".to_owned(),
+        },
+        AuditedChange {
+            actor: "JaneSmith".to_owned(),
+            action: AuditAction::Delete,
+            entity_type: AuditEntityType::ImamQuestion,
+            entity_id: Some(3),
+            before: Some(json!({"id": 3})),
+            after: None,
+            request_id: "request-3".to_owned(),
+        },
+    ];
+    for (minutes, change) in changes.iter().enumerate() {
+        let insert_result = repository
+            .insert_entry(
+                change.clone(),
+                started_at + Duration::minutes(minutes as i64),
+            )
+            .await;
+        assert!(insert_result.is_ok());
+    }
+    let first_page = repository.get_entries(unfiltered(1, 2)).await.unwrap();
+    assert_eq!(first_page.total_count, 3);
+    assert_eq!(first_page.entries.len(), 2);
+    assert_eq!(first_page.entries[0].request_id, "request-3");
+    assert_eq!(first_page.entries[0].before, Some(json!({"id": 3})));
+    assert_eq!(first_page.entries[0].after, None);
+    assert_eq!(first_page.entries[1].request_id, "request-2");
+    let second_page = repository.get_entries(unfiltered(2, 2)).await.unwrap();
+    assert_eq!(second_page.entries.len(), 1);
+    assert_eq!(second_page.entries[0].action, AuditAction::Create);
+    assert_eq!(second_page.entries[0].entity_id, None);
+
+    //Given a filter, I should only get the changes matching it
+    let filtered_page = repository
+        .get_entries(AuditLogFilter {
+            actor: Some("JohnSmith".to_owned()),
+            entity_type: Some(AuditEntityType::Event),
+            entity_id: Some(1),
+            ..unfiltered(1, 25)
+        })
+        .await
+        .unwrap();
+    assert_eq!(filtered_page.total_count, 1);
+    assert_eq!(filtered_page.entries[0].action, AuditAction::Update);
+    assert_eq!(
+        filtered_page.entries[0].after,
+        Some(json!({"id": 1, "version": 2}))
+    );
+    let filtered_page = repository
+        .get_entries(AuditLogFilter {
+            from: Some(started_at + Duration::seconds(30)),
+            to: Some(started_at + Duration::minutes(2)),
+            ..unfiltered(1, 25)
+        })
+        .await
+        .unwrap();
+    assert_eq!(filtered_page.total_count, 1);
+    assert_eq!(filtered_page.entries[0].request_id, "request-2");
+
+    container.stop().await.unwrap();
+}
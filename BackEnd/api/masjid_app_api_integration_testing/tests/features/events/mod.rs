@@ -1 +1,2 @@
 mod mysql;
+mod redis;
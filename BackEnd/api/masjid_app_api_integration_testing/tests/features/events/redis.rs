@@ -0,0 +1,245 @@
+use crate::common::data_access_layer;
+use crate::common::logging::setup_logging;
+use masjid_app_admin_manager_api::features::events::models::saved_event::SavedEvent;
+use masjid_app_admin_manager_api::features::events::repositories::errors::delete_event_error::DeleteEventError;
+use masjid_app_admin_manager_api::features::events::repositories::errors::upsert_event_error::UpsertEventError;
+use masjid_app_admin_manager_api::features::events::repositories::new_events_admin_repository;
+use masjid_app_api_library::features::events::models::event::Event;
+use masjid_app_api_library::features::events::models::event_details::EventDetails;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+use masjid_app_api_library::features::events::models::event_status::EventStatus;
+use masjid_app_api_library::features::events::models::event_type::EventType;
+use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+use masjid_app_api_library::features::events::models::events_filter::EventsFilter;
+use masjid_app_api_library::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use masjid_app_api_library::features::events::repositories::new_events_cache_repository;
+use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+use masjid_app_api_library::shared::types::image_variants::ImageVariants;
+use masjid_app_public_api::features::events::events_public_repository::new_events_public_repository;
+
+fn event(
+    id: i32,
+    date: &str,
+    event_recurrence: EventRecurrence,
+    event_status: EventStatus,
+) -> EventDTO {
+    EventDTO {
+        id,
+        title: format!("event {}", id),
+        description: None,
+        date: date.parse().unwrap(),
+        event_details: EventDetails {
+            event_type: EventType::Class,
+            event_recurrence,
+            recurrence_end: None,
+            event_status,
+            publish_at: None,
+            age_range: None,
+            capacity: None,
+            room_id: None,
+            duration_minutes: None,
+            speaker_ids: Vec::new(),
+            category_id: None,
+            tags: Vec::new(),
+            image_url: None,
+            image_variants: None,
+            contact_details: ContactDetails {
+                full_name: "John Smith".to_owned(),
+                title: None,
+                phone_number: "07123456789".to_owned(),
+                email: None,
+                visibility: ContactVisibility::default(),
+            },
+        },
+        version: 1,
+    }
+}
+
+#[tokio::test]
+async fn test_events_cache() {
+    setup_logging();
+    let container = data_access_layer::redis::setup_container("EVENTS_REDIS_CONNECTION").await;
+
+    let cache_repository = new_events_cache_repository().await;
+    let public_repository =
+        new_events_public_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await;
+    let admin_repository =
+        new_events_admin_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await;
+
+    // Given the cache has not been warmed, I should receive an error so that events are read from
+    // the database instead
+    let get_events_result = public_repository
+        .get_events(EventVisibility::Published)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        get_events_result,
+        GetEventsRepositoryError::UnableToGetEvents
+    );
+
+    // When the cache is warmed without any events, I should receive a not found error
+    cache_repository.replace_events(&[]).await.unwrap();
+    let get_events_result = public_repository
+        .get_events(EventVisibility::Published)
+        .await
+        .unwrap_err();
+    assert_eq!(get_events_result, GetEventsRepositoryError::EventsNotFound);
+
+    // When the cache is warmed with events, I should only receive the published ones, ordered by
+    // date
+    let mut poster_event = event(
+        1,
+        "2099-01-05T19:00:00Z",
+        EventRecurrence::OneOff,
+        EventStatus::Confirmed,
+    );
+    poster_event.event_details.image_url = Some("/uploads/poster.png".to_owned());
+    let draft_event = event(
+        2,
+        "2099-01-12T19:00:00Z",
+        EventRecurrence::OneOff,
+        EventStatus::Draft,
+    );
+    let weekly_event = event(
+        10,
+        "2098-12-01T19:00:00Z",
+        EventRecurrence::Weekly,
+        EventStatus::Confirmed,
+    );
+    let past_event = event(
+        11,
+        "2098-11-01T19:00:00Z",
+        EventRecurrence::OneOff,
+        EventStatus::Confirmed,
+    );
+    cache_repository
+        .replace_events(&[
+            poster_event.clone(),
+            draft_event.clone(),
+            weekly_event.clone(),
+            past_event.clone(),
+        ])
+        .await
+        .unwrap();
+    let events = public_repository
+        .get_events(EventVisibility::Published)
+        .await
+        .unwrap();
+    poster_event.event_details.image_variants =
+        Some(ImageVariants::from_image_url("/uploads/poster.png"));
+    assert_eq!(
+        events,
+        vec![
+            past_event.clone(),
+            weekly_event.clone(),
+            poster_event.clone()
+        ]
+    );
+
+    // When I filter by a range of dates, I should receive the events in that range along with the
+    // recurring series still running during it
+    let filter = EventsFilter {
+        from: Some("2099-01-01T00:00:00Z".parse().unwrap()),
+        to: Some("2099-02-01T00:00:00Z".parse().unwrap()),
+        event_type: None,
+        event_status: None,
+        minimum_age: None,
+        maximum_age: None,
+        category_id: None,
+        tag: None,
+        page: 1,
+        page_size: 2,
+        visibility: EventVisibility::All,
+    };
+    let events_page = public_repository
+        .get_filtered_events(&filter)
+        .await
+        .unwrap();
+    assert_eq!(events_page.total_count, 3);
+    assert_eq!(
+        events_page.events,
+        vec![weekly_event.clone(), poster_event.clone()]
+    );
+    let events_page = public_repository
+        .get_filtered_events(&EventsFilter { page: 2, ..filter })
+        .await
+        .unwrap();
+    assert_eq!(events_page.events, vec![draft_event.clone()]);
+
+    // When an event is cached with the status and version the database saved it with, I should
+    // receive it with both
+    let mut confirmed_event = draft_event.clone();
+    confirmed_event.event_details.event_status = EventStatus::Confirmed;
+    confirmed_event.version = 2;
+    let upsert_event_result = admin_repository
+        .upsert_event(&Event::from(confirmed_event.clone()))
+        .await;
+    assert!(matches!(
+        upsert_event_result,
+        Ok(SavedEvent { id: 2, version: 2 })
+    ));
+    let events = public_repository
+        .get_events(EventVisibility::Published)
+        .await
+        .unwrap();
+    assert!(events.contains(&confirmed_event));
+
+    // When a saved event is cached, I should receive it as it was saved
+    let mut renamed_event = weekly_event.clone();
+    renamed_event.title = "weekly halaqa".to_owned();
+    renamed_event.version = 2;
+    admin_repository
+        .upsert_event(&Event::from(renamed_event.clone()))
+        .await
+        .unwrap();
+    let events = public_repository
+        .get_events(EventVisibility::Published)
+        .await
+        .unwrap();
+    assert!(events.contains(&renamed_event));
+
+    // When a new event is cached before it has an id, I should receive an error
+    let upsert_event_result = admin_repository
+        .upsert_event(&Event::from(event(
+            0,
+            "2099-03-01T19:00:00Z",
+            EventRecurrence::OneOff,
+            EventStatus::Confirmed,
+        )))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        upsert_event_result,
+        UpsertEventError::UnableToUpsertEvent
+    ));
+
+    // When an event is evicted, I should no longer receive it
+    let delete_event_result = admin_repository.delete_event_by_id(&2).await.unwrap();
+    assert_eq!(delete_event_result, None);
+    let events = public_repository
+        .get_events(EventVisibility::All)
+        .await
+        .unwrap();
+    assert!(events.iter().all(|event| event.id != 2));
+
+    // When the cache is warmed again, events which are no longer in the database should be
+    // removed
+    cache_repository
+        .replace_events(std::slice::from_ref(&past_event))
+        .await
+        .unwrap();
+    let events = public_repository
+        .get_events(EventVisibility::All)
+        .await
+        .unwrap();
+    assert_eq!(events, vec![past_event]);
+
+    let delete_event_result: Result<Option<String>, DeleteEventError> =
+        admin_repository.delete_event_by_id(&11).await;
+    assert_eq!(delete_event_result, Ok(None));
+    container.stop().await.unwrap();
+}
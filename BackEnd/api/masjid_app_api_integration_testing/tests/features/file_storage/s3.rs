@@ -0,0 +1,42 @@
+use crate::common::file_storage::minio;
+use crate::common::logging::setup_logging;
+use masjid_app_api_library::shared::services::file_storage::errors::FileStorageError;
+use masjid_app_api_library::shared::services::file_storage::providers::FileStorageProvider;
+use masjid_app_api_library::shared::services::file_storage::r#trait::new_file_storage;
+
+#[tokio::test]
+async fn test_s3_file_storage() {
+    setup_logging();
+    let (container, settings) = minio::setup_container("masjidapp-uploads").await;
+    let storage = new_file_storage(FileStorageProvider::S3 { settings });
+
+    // Given nothing has been uploaded, the total size should be zero
+    assert_eq!(storage.total_size().await, Ok(0));
+
+    // When I retrieve an object that does not exist, I should get an object not found error
+    let get_object_result = storage.get_object("poster.png").await;
+    assert_eq!(get_object_result, Err(FileStorageError::ObjectNotFound));
+
+    // When the key could be interpreted as a path, I should get an invalid key error
+    let put_object_result = storage
+        .put_object("../poster.png", vec![1], "image/png")
+        .await;
+    assert_eq!(put_object_result, Err(FileStorageError::InvalidKey));
+
+    // When I store an object, I should be able to retrieve it
+    let put_object_result = storage
+        .put_object("poster.png", vec![1, 2, 3], "image/png")
+        .await;
+    assert_eq!(put_object_result, Ok(()));
+    let get_object_result = storage.get_object("poster.png").await;
+    assert_eq!(get_object_result, Ok(vec![1, 2, 3]));
+    assert_eq!(storage.total_size().await, Ok(3));
+
+    // When I delete an object, it should no longer be retrievable
+    let delete_object_result = storage.delete_object("poster.png").await;
+    assert_eq!(delete_object_result, Ok(()));
+    let get_object_result = storage.get_object("poster.png").await;
+    assert_eq!(get_object_result, Err(FileStorageError::ObjectNotFound));
+    assert_eq!(storage.total_size().await, Ok(0));
+    container.stop().await.unwrap();
+}
@@ -1,4 +1,7 @@
 mod ask_imam;
+mod audit_log;
 mod events;
+mod file_storage;
 mod prayer_times;
 mod user_authentication;
+mod user_management;
@@ -1,10 +1,15 @@
 use crate::common::data_access_layer::{DatabaseCredentials, mysql};
 use crate::common::logging::setup_logging;
+use masjid_app_admin_manager_api::features::user_authentication::errors::accept_totp_time_step_error::AcceptTotpTimeStepError;
+use masjid_app_admin_manager_api::features::user_authentication::errors::consume_recovery_code_error::ConsumeRecoveryCodeError;
 use masjid_app_admin_manager_api::features::user_authentication::errors::get_user_error::GetUserError;
+use masjid_app_admin_manager_api::features::user_authentication::errors::redeem_password_reset_token_error::RedeemPasswordResetTokenError;
 use masjid_app_admin_manager_api::features::user_authentication::errors::update_user_password_error::UpdateUserPasswordError;
 use masjid_app_admin_manager_api::features::user_authentication::models::login_dto::LoginDTO;
 use masjid_app_admin_manager_api::features::user_authentication::models::user_account_dto::UserAccountDTO;
+use masjid_app_admin_manager_api::features::user_authentication::models::user_mfa_details::UserMfaDetails;
 use masjid_app_admin_manager_api::features::user_authentication::repositories::new_user_repository;
+use chrono::{TimeDelta, Utc};
 #[tokio::test]
 async fn test_user_authentication() {
     setup_logging();
@@ -44,6 +49,12 @@ async fn test_user_authentication() {
     let register_result = repository.insert_new_user(new_user).await;
     assert!(register_result.is_ok());
 
+    //Given a user has been created, I should be able to retrieve their email address
+    let email_result = repository.get_user_email("JohnSmith").await;
+    assert_eq!(email_result, Ok("JohnSmith@masjidapp.com".to_owned()));
+    let email_result = repository.get_user_email("JaneSmith").await;
+    assert_eq!(email_result, Err(GetUserError::NotFound));
+
     //Given a new user has been created, I should be able to successfully log in
     let mut actual_login_result = repository
         .get_user_by_credentials("JohnSmith", "password")
@@ -52,6 +63,9 @@ async fn test_user_authentication() {
         username: "JohnSmith".to_owned(),
         password: "password".to_owned(),
         role: "Admin".to_owned(),
+        mfa_enabled: false,
+        mfa_required: false,
+        is_disabled: false,
     });
     assert!(matches!(expected_login_result.clone(), actual_login_result));
 
@@ -69,9 +83,199 @@ async fn test_user_authentication() {
         username: "JohnSmith".to_owned(),
         password: "new_password".to_owned(),
         role: "Admin".to_owned(),
+        mfa_enabled: false,
+        mfa_required: false,
+        is_disabled: false,
     });
     assert!(matches!(expected_login_result, actual_login_result));
 
+    //Given a user exists, I should be able to start enrolling them in mfa
+    let set_mfa_secret_result = repository
+        .set_user_mfa_secret("JohnSmith", "JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP")
+        .await;
+    assert!(set_mfa_secret_result.is_ok());
+    let mfa_details = repository.get_user_mfa_details("JohnSmith").await;
+    assert_eq!(
+        mfa_details,
+        Ok(UserMfaDetails {
+            secret: Some("JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP".to_owned()),
+            is_enabled: false,
+            last_time_step: None,
+            locked_until: None,
+        })
+    );
+
+    //Given mfa enrolment has started, I should be able to enable mfa with recovery codes
+    let recovery_code_hash = "a".repeat(64);
+    let enable_mfa_result = repository
+        .enable_user_mfa("JohnSmith", vec![recovery_code_hash.clone()], 100)
+        .await;
+    assert!(enable_mfa_result.is_ok());
+
+    //Given a totp time step has been accepted, it and any earlier time step should be rejected
+    let accept_result = repository.accept_totp_time_step("JohnSmith", 100).await;
+    assert_eq!(
+        accept_result,
+        Err(AcceptTotpTimeStepError::TimeStepAlreadyUsed)
+    );
+    let accept_result = repository.accept_totp_time_step("JohnSmith", 101).await;
+    assert!(accept_result.is_ok());
+    let accept_result = repository.accept_totp_time_step("JohnSmith", 99).await;
+    assert_eq!(
+        accept_result,
+        Err(AcceptTotpTimeStepError::TimeStepAlreadyUsed)
+    );
+    let mfa_details = repository.get_user_mfa_details("JohnSmith").await.unwrap();
+    assert_eq!(mfa_details.last_time_step, Some(101));
+
+    //Given too many incorrect codes have been entered, the user should be locked out
+    for _ in 0..4 {
+        let record_result = repository
+            .record_failed_mfa_attempt("JohnSmith", 5, 15)
+            .await;
+        assert!(record_result.is_ok());
+    }
+    let mfa_details = repository.get_user_mfa_details("JohnSmith").await.unwrap();
+    assert_eq!(mfa_details.locked_until, None);
+    let record_result = repository
+        .record_failed_mfa_attempt("JohnSmith", 5, 15)
+        .await;
+    assert!(record_result.is_ok());
+    let mfa_details = repository.get_user_mfa_details("JohnSmith").await.unwrap();
+    assert!(
+        mfa_details
+            .locked_until
+            .is_some_and(|locked_until| locked_until > Utc::now())
+    );
+
+    //Given a recovery code has been issued, it should only be usable once
+    let consume_result = repository
+        .consume_recovery_code("JohnSmith", &recovery_code_hash)
+        .await;
+    assert!(consume_result.is_ok());
+    let consume_result = repository
+        .consume_recovery_code("JohnSmith", &recovery_code_hash)
+        .await;
+    assert_eq!(consume_result, Err(ConsumeRecoveryCodeError::CodeNotFound));
+
+    //Given mfa is required for admins, the login details should reflect the user's mfa status
+    let update_policy_result = repository.upsert_mfa_policy("Admin", true).await;
+    assert!(update_policy_result.is_ok());
+    let login_details = repository
+        .get_user_by_credentials("JohnSmith", "new_password")
+        .await
+        .unwrap();
+    assert!(login_details.mfa_enabled);
+    assert!(login_details.mfa_required);
+
+    //Given mfa is enabled, I should be able to disable it
+    let disable_mfa_result = repository.disable_user_mfa("JohnSmith").await;
+    assert!(disable_mfa_result.is_ok());
+    let mfa_details = repository.get_user_mfa_details("JohnSmith").await;
+    assert_eq!(
+        mfa_details,
+        Ok(UserMfaDetails {
+            secret: None,
+            is_enabled: false,
+            last_time_step: None,
+            locked_until: None,
+        })
+    );
+
+    //Given a user is registered with an email address, I should be able to find their username
+    let get_username_result = repository
+        .get_username_by_email("JohnSmith@masjidapp.com")
+        .await;
+    assert_eq!(get_username_result, Ok("JohnSmith".to_owned()));
+    let get_username_result = repository
+        .get_username_by_email("JaneSmith@masjidapp.com")
+        .await;
+    assert_eq!(get_username_result, Err(GetUserError::NotFound));
+
+    //Given a password reset token has been issued, it should only be redeemable once
+    let reset_token_hash = "b".repeat(64);
+    let insert_token_result = repository
+        .insert_password_reset_token(
+            "JohnSmith",
+            &reset_token_hash,
+            Utc::now() + TimeDelta::minutes(30),
+        )
+        .await;
+    assert!(insert_token_result.is_ok());
+    let token_username_result = repository
+        .get_password_reset_token_username(&reset_token_hash)
+        .await;
+    assert_eq!(token_username_result, Ok("JohnSmith".to_owned()));
+    let redeem_token_result = repository
+        .reset_user_password_with_token(&reset_token_hash, "token_password")
+        .await;
+    assert_eq!(redeem_token_result, Ok("JohnSmith".to_owned()));
+    let login_details = repository
+        .get_user_by_credentials("JohnSmith", "token_password")
+        .await;
+    assert!(login_details.is_ok());
+    let redeem_token_result = repository
+        .reset_user_password_with_token(&reset_token_hash, "another_password")
+        .await;
+    assert_eq!(
+        redeem_token_result,
+        Err(RedeemPasswordResetTokenError::InvalidOrExpiredToken)
+    );
+    let token_username_result = repository
+        .get_password_reset_token_username(&reset_token_hash)
+        .await;
+    assert_eq!(
+        token_username_result,
+        Err(RedeemPasswordResetTokenError::InvalidOrExpiredToken)
+    );
+
+    //Given a user has changed their password, their previous passwords should be kept as history
+    let password_history_result = repository.get_password_history("JohnSmith", 2).await;
+    assert_eq!(
+        password_history_result,
+        Ok(vec!["token_password".to_owned(), "new_password".to_owned()])
+    );
+
+    //Given a user's password has been rehashed, their password history should be unchanged
+    let rehash_result = repository
+        .update_user_password_hash("JohnSmith", "rehashed_token_password")
+        .await;
+    assert!(rehash_result.is_ok());
+    let login_details = repository
+        .get_user_by_credentials("JohnSmith", "rehashed_token_password")
+        .await;
+    assert!(login_details.is_ok());
+    let password_history_result = repository.get_password_history("JohnSmith", 2).await;
+    assert_eq!(
+        password_history_result,
+        Ok(vec!["token_password".to_owned(), "new_password".to_owned()])
+    );
+    let rehash_result = repository
+        .update_user_password_hash("JaneSmith", "rehashed_password")
+        .await;
+    assert_eq!(
+        rehash_result,
+        Err(UpdateUserPasswordError::UserDoesNotExist)
+    );
+
+    //Given a password reset token has expired, it should not be redeemable
+    let expired_token_hash = "c".repeat(64);
+    let insert_token_result = repository
+        .insert_password_reset_token(
+            "JohnSmith",
+            &expired_token_hash,
+            Utc::now() - TimeDelta::minutes(1),
+        )
+        .await;
+    assert!(insert_token_result.is_ok());
+    let redeem_token_result = repository
+        .reset_user_password_with_token(&expired_token_hash, "another_password")
+        .await;
+    assert_eq!(
+        redeem_token_result,
+        Err(RedeemPasswordResetTokenError::InvalidOrExpiredToken)
+    );
+
     main_database_container
         .stop()
         .await
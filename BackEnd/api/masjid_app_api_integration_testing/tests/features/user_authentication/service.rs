@@ -2,11 +2,14 @@ use crate::common::data_access_layer::{DatabaseCredentials, mysql};
 use crate::common::logging::setup_logging;
 use masjid_app_admin_manager_api::features::user_authentication::models::user_account_dto::UserAccountDTO;
 use masjid_app_admin_manager_api::features::user_authentication::repositories::new_user_repository;
+use masjid_app_admin_manager_api::features::user_authentication::services::errors::reset_password_error::ResetPasswordError;
 use masjid_app_admin_manager_api::features::user_authentication::services::login_service::new_login_service;
 use masjid_app_admin_manager_api::features::user_authentication::services::reset_password_service::new_reset_password_service;
 use masjid_app_admin_manager_api::features::user_authentication::services::user_registration_service::new_user_registration_service;
 use masjid_app_api_library::shared::services::hashing::providers::HashingProvider;
 use masjid_app_api_library::shared::services::hashing::r#trait::new_hashing_service;
+use masjid_app_api_library::shared::services::password_policy::settings::PasswordPolicySettings;
+use masjid_app_api_library::shared::services::password_policy::r#trait::new_password_policy;
 
 #[tokio::test]
 async fn test_user_authentication_service() {
@@ -18,32 +21,54 @@ async fn test_user_authentication_service() {
     })
     .await;
     let hashing_service = new_hashing_service(HashingProvider::Bcrypt);
+    let password_policy = new_password_policy(PasswordPolicySettings::default());
 
-    let user_registration_service =
-        new_user_registration_service(hashing_service.clone(), new_user_repository().await);
+    let user_registration_service = new_user_registration_service(
+        hashing_service.clone(),
+        new_user_repository().await,
+        password_policy.clone(),
+    );
     let registration_result = user_registration_service
         .register_user(UserAccountDTO {
             full_name: "zayd mcardle".to_string(),
             email: "zaydmcardle@masjidapp.com".to_string(),
             role: "admin".to_string(),
             username: "zayd".to_string(),
-            password: "1234".to_string(),
+            password: "Quartz-Lantern-Orbit-47".to_string(),
         })
         .await;
     assert!(registration_result.is_ok());
 
     let login_service = new_login_service(hashing_service.clone(), new_user_repository().await);
-    let mut login_result = login_service.login("zayd", "1234").await;
+    let mut login_result = login_service.login("zayd", "Quartz-Lantern-Orbit-47").await;
     assert!(login_result.is_ok());
 
-    let password_reset_service =
-        new_reset_password_service(hashing_service, new_user_repository().await);
-    let password_reset_result = password_reset_service
-        .reset_password("zayd", "1234321")
+    let password_reset_service = new_reset_password_service(
+        hashing_service,
+        new_user_repository().await,
+        password_policy,
+    );
+    let mut password_reset_result = password_reset_service
+        .reset_password("zayd", "Velvet!Harbour-Canyon-92")
         .await;
     assert!(password_reset_result.is_ok());
 
-    login_result = login_service.login("zayd", "1234321").await;
+    login_result = login_service.login("zayd", "Velvet!Harbour-Canyon-92").await;
+    assert!(login_result.is_ok());
+
+    password_reset_result = password_reset_service
+        .reset_password("zayd", "Quartz-Lantern-Orbit-47")
+        .await;
+    assert!(matches!(
+        password_reset_result,
+        Err(ResetPasswordError::PasswordRecentlyUsed)
+    ));
+
+    password_reset_result = password_reset_service.reset_password("zayd", "1234321").await;
+    assert!(matches!(
+        password_reset_result,
+        Err(ResetPasswordError::PasswordPolicyViolation(_))
+    ));
     assert!(login_result.is_ok());
 
     main_database_container
@@ -0,0 +1,108 @@
+use crate::common::data_access_layer::{DatabaseCredentials, mysql};
+use crate::common::logging::setup_logging;
+use masjid_app_admin_manager_api::features::user_authentication::models::user_account_dto::UserAccountDTO;
+use masjid_app_admin_manager_api::features::user_authentication::repositories::new_user_repository;
+use masjid_app_admin_manager_api::features::user_management::errors::delete_user_error::DeleteUserError;
+use masjid_app_admin_manager_api::features::user_management::errors::get_users_error::GetUsersError;
+use masjid_app_admin_manager_api::features::user_management::errors::update_user_error::UpdateUserError;
+use masjid_app_admin_manager_api::features::user_management::models::update_user_request::UpdateUserRequest;
+use masjid_app_admin_manager_api::features::user_management::repositories::new_user_management_repository;
+
+#[tokio::test]
+async fn test_user_management() {
+    setup_logging();
+    let main_database_container = mysql::setup_container(DatabaseCredentials {
+        username: "authenticationuser".to_owned(),
+        password: "BL6FxKu!237GvPS9".to_owned(),
+        environment_variable: "AUTHENTICATION_CONNECTION".to_string(),
+    })
+    .await;
+    let user_repository = new_user_repository().await;
+    let repository = new_user_management_repository().await;
+
+    //Given no user exists with the id, I should get a not found error
+    let get_user_result = repository.get_user_by_id(1).await;
+    assert_eq!(get_user_result, Err(GetUsersError::UserNotFound));
+
+    //Given an Admin and an Imam have registered, I should be able to list them by role
+    for (username, role) in [("JohnSmith", "Admin"), ("JaneSmith", "Imam")] {
+        let register_result = user_repository
+            .insert_new_user(UserAccountDTO {
+                full_name: username.to_owned(),
+                email: format!("{username}@masjidapp.com"),
+                role: role.to_owned(),
+                username: username.to_owned(),
+                password: "password".to_owned(),
+            })
+            .await;
+        assert!(register_result.is_ok());
+    }
+    let all_users = repository.get_users(None).await.unwrap();
+    assert_eq!(all_users.len(), 2);
+    let imams = repository.get_users(Some("Imam".to_owned())).await.unwrap();
+    assert_eq!(imams.len(), 1);
+    assert_eq!(imams[0].username, "JaneSmith");
+    let admin_id = all_users
+        .iter()
+        .find(|user| user.role == "Admin")
+        .unwrap()
+        .id;
+    let imam_id = imams[0].id;
+
+    //Given a user exists, I should be able to update their details
+    let update_result = repository
+        .update_user_details(
+            imam_id,
+            UpdateUserRequest {
+                full_name: "Jane Smith".to_owned(),
+                email: "jane@masjidapp.com".to_owned(),
+                role: "Imam".to_owned(),
+            },
+        )
+        .await;
+    assert!(update_result.is_ok());
+    let imam = repository.get_user_by_id(imam_id).await.unwrap();
+    assert_eq!(imam.full_name, "Jane Smith");
+    assert_eq!(imam.email, "jane@masjidapp.com");
+
+    //Given only one Admin exists, I should not be able to demote, disable or delete them
+    let update_result = repository
+        .update_user_details(
+            admin_id,
+            UpdateUserRequest {
+                full_name: "John Smith".to_owned(),
+                email: "JohnSmith@masjidapp.com".to_owned(),
+                role: "Imam".to_owned(),
+            },
+        )
+        .await;
+    assert_eq!(update_result, Err(UpdateUserError::LastAdmin));
+    let disable_result = repository.set_user_disabled(admin_id, true).await;
+    assert_eq!(disable_result, Err(UpdateUserError::LastAdmin));
+    let delete_result = repository.delete_user(admin_id).await;
+    assert_eq!(delete_result, Err(DeleteUserError::LastAdmin));
+
+    //Given a user has been disabled, they should be flagged as disabled at login
+    let disable_result = repository.set_user_disabled(imam_id, true).await;
+    assert!(disable_result.is_ok());
+    let login_details = user_repository
+        .get_user_by_credentials("JaneSmith", "password")
+        .await
+        .unwrap();
+    assert!(login_details.is_disabled);
+    let enable_result = repository.set_user_disabled(imam_id, false).await;
+    assert!(enable_result.is_ok());
+
+    //Given a user exists, I should be able to delete them only once
+    let delete_result = repository.delete_user(imam_id).await;
+    assert!(delete_result.is_ok());
+    let delete_result = repository.delete_user(imam_id).await;
+    assert_eq!(delete_result, Err(DeleteUserError::UserNotFound));
+    let disable_result = repository.set_user_disabled(imam_id, true).await;
+    assert_eq!(disable_result, Err(UpdateUserError::UserNotFound));
+
+    main_database_container
+        .stop()
+        .await
+        .expect("Container failed to stop");
+}
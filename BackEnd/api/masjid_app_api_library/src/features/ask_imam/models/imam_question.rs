@@ -19,6 +19,8 @@ pub struct ImamQuestion {
     pub answer: Option<String>,
 
     pub date_answered: Option<chrono::DateTime<chrono::Utc>>,
+
+    pub version: u32,
 }
 
 impl From<ImamQuestionDTO> for ImamQuestion {
@@ -43,6 +45,7 @@ impl From<ImamQuestionDTO> for ImamQuestion {
             imam_name,
             answer: imam_answer,
             date_answered,
+            version: dto.version,
         }
     }
 }
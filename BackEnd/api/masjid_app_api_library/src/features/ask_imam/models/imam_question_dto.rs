@@ -22,6 +22,11 @@ pub struct ImamQuestionDTO {
     pub date_of_question: chrono::DateTime<chrono::Utc>,
 
     pub answer: Option<Answer>,
+
+    /// Sent as the ETag of the question. Answering requires the version the answer was written
+    /// against
+    #[serde(default)]
+    pub version: u32,
 }
 
 impl From<ImamQuestion> for ImamQuestionDTO {
@@ -47,6 +52,7 @@ impl From<ImamQuestion> for ImamQuestionDTO {
             description: imam_question.description,
             date_of_question: imam_question.date_of_question,
             answer: answer,
+            version: imam_question.version,
         }
     }
 }
@@ -9,7 +9,7 @@ use sqlx::mysql::MySqlRow;
 use sqlx::{MySqlPool, Row};
 use std::sync::Arc;
 
-fn imam_question_from_my_sql_row(row: MySqlRow) -> ImamQuestion {
+pub fn imam_question_from_my_sql_row(row: MySqlRow) -> ImamQuestion {
     ImamQuestion {
         id: row.get(0),
         title: row.get(1),
@@ -20,6 +20,7 @@ fn imam_question_from_my_sql_row(row: MySqlRow) -> ImamQuestion {
         imam_name: row.get(6),
         answer: row.get(7),
         date_answered: row.get(8),
+        version: row.get(9),
     }
 }
 
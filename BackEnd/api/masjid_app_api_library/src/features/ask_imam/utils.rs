@@ -35,6 +35,7 @@ mod test {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 2,
@@ -48,6 +49,7 @@ mod test {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 3,
@@ -61,6 +63,7 @@ mod test {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 4,
@@ -74,6 +77,7 @@ mod test {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
         ]
     }
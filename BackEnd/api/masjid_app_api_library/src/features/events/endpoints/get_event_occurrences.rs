@@ -0,0 +1,103 @@
+use crate::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use crate::features::events::models::get_event_occurrences_request::GetEventOccurrencesRequest;
+use crate::features::events::services::errors::get_event_occurrences_service_error::GetEventOccurrencesServiceError;
+use crate::features::events::services::event_retrieval_service::EventRetrievalService;
+use crate::shared::types::app_state::ServiceAppState;
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Occurrences are served as `O`, so that the public API can serve them without the organiser's
+/// hidden contact details
+#[inline]
+pub async fn get_event_occurrences_common<
+    R: EventRetrievalService + ?Sized,
+    O: From<EventOccurrenceDTO> + Serialize,
+>(
+    State(state): State<ServiceAppState<Arc<R>>>,
+    Query(request): Query<GetEventOccurrencesRequest>,
+) -> Response {
+    match state
+        .service
+        .get_event_occurrences(request.from, request.to)
+        .await
+    {
+        Ok(occurrences) => (
+            StatusCode::OK,
+            Json(occurrences.into_iter().map(O::from).collect::<Vec<O>>()),
+        )
+            .into_response(),
+        Err(GetEventOccurrencesServiceError::InvalidWindow) => (
+            StatusCode::BAD_REQUEST,
+            "the window must end after it starts and cannot be longer than a year",
+        )
+            .into_response(),
+        Err(GetEventOccurrencesServiceError::UnableToGetEventsFromRepository(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use crate::features::events::services::event_retrieval_service::MockEventRetrievalService;
+
+    #[tokio::test]
+    async fn test_get_event_occurrences_common() {
+        struct TestCase {
+            description: &'static str,
+            expected_service_response:
+                Result<Vec<EventOccurrenceDTO>, GetEventOccurrencesServiceError>,
+            expected_response_code: StatusCode,
+        }
+        let test_cases = vec![
+            TestCase {
+                description: "When the window is invalid",
+                expected_service_response: Err(GetEventOccurrencesServiceError::InvalidWindow),
+                expected_response_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When retrieval of events fails",
+                expected_service_response: Err(
+                    GetEventOccurrencesServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                ),
+                expected_response_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When occurrences are found",
+                expected_service_response: Ok(Vec::new()),
+                expected_response_code: StatusCode::OK,
+            },
+        ];
+
+        for case in test_cases {
+            eprintln!("{}", case.description);
+            let mut mock_service = MockEventRetrievalService::new();
+
+            mock_service
+                .expect_get_event_occurrences()
+                .return_once(move |_, _| case.expected_service_response);
+
+            let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
+                service: Arc::new(mock_service),
+            };
+            let request = GetEventOccurrencesRequest {
+                from: "2025-01-01T00:00:00Z".parse().unwrap(),
+                to: "2025-02-01T00:00:00Z".parse().unwrap(),
+            };
+
+            let actual_response = get_event_occurrences_common::<_, EventOccurrenceDTO>(
+                State(app_state),
+                Query(request),
+            )
+            .await;
+            assert_eq!(actual_response.status(), case.expected_response_code)
+        }
+    }
+}
@@ -1,19 +1,51 @@
+use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::events_filter::EventsFilter;
+use crate::features::events::models::get_events_request::GetEventsRequest;
 use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
 use crate::features::events::services::errors::get_events_service_error::GetEventsServiceError;
 use crate::features::events::services::event_retrieval_service::EventRetrievalService;
 use crate::shared::types::app_state::ServiceAppState;
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
+use serde::Serialize;
 use std::sync::Arc;
+use validator::Validate;
 
+pub const TOTAL_COUNT_HEADER: &'static str = "X-Total-Count";
+pub const PAGE_HEADER: &'static str = "X-Page";
+pub const PAGE_SIZE_HEADER: &'static str = "X-Page-Size";
+
+/// Events are served as `E`, so that the public API can serve them without the organiser's hidden
+/// contact details
 #[inline]
-pub async fn get_events_common<R: EventRetrievalService + ?Sized>(
+pub async fn get_events_common<R: EventRetrievalService + ?Sized, E: From<EventDTO> + Serialize>(
     State(state): State<ServiceAppState<Arc<R>>>,
+    Query(request): Query<GetEventsRequest>,
 ) -> Response {
-    match state.service.get_events().await {
-        Ok(events) => (StatusCode::OK, Json(events)).into_response(),
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    let filter = EventsFilter::from(request);
+    let (page, page_size) = (filter.page, filter.page_size);
+    match state.service.get_events_page(filter).await {
+        Ok(events_page) => (
+            StatusCode::OK,
+            [
+                (TOTAL_COUNT_HEADER, events_page.total_count.to_string()),
+                (PAGE_HEADER, page.to_string()),
+                (PAGE_SIZE_HEADER, page_size.to_string()),
+            ],
+            Json(
+                events_page
+                    .events
+                    .into_iter()
+                    .map(E::from)
+                    .collect::<Vec<E>>(),
+            ),
+        )
+            .into_response(),
 
         Err(GetEventsServiceError::UnableToGetEventsFromRepository(
             GetEventsRepositoryError::EventsNotFound,
@@ -27,13 +59,14 @@ pub async fn get_events_common<R: EventRetrievalService + ?Sized>(
 mod test {
     use super::*;
     use crate::features::events::models::event_details::EventDetails;
-    use crate::features::events::models::event_dto::EventDTO;
     use crate::features::events::models::event_recurrence::EventRecurrence;
     use crate::features::events::models::event_status::EventStatus;
     use crate::features::events::models::event_type::EventType;
+    use crate::features::events::models::events_page::EventsPage;
     use crate::features::events::services::event_retrieval_service::MockEventRetrievalService;
     use crate::shared::types::age_range::AgeRange;
     use crate::shared::types::contact_details::ContactDetails;
+    use crate::shared::types::contact_visibility::ContactVisibility;
 
     #[tokio::test]
     async fn test_get_events_common() {
@@ -45,47 +78,88 @@ mod test {
             event_details: EventDetails {
                 event_type: EventType::Talk,
                 event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
                 event_status: EventStatus::Confirmed,
+                publish_at: None,
                 age_range: Some(AgeRange {
                     minimum_age: 16,
                     maximum_age: 18,
                 }),
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
                 image_url: None,
+                image_variants: None,
                 contact_details: ContactDetails {
                     full_name: "John Smith".to_owned(),
                     title: None,
                     phone_number: "07127665431".to_owned(),
                     email: Some("johns.smith@masjidapp.com".to_owned()),
+                    visibility: ContactVisibility::default(),
                 },
             },
+            version: 1,
         }];
         struct TestCase {
             description: &'static str,
-            expected_service_response: Result<Vec<EventDTO>, GetEventsServiceError>,
+            request: GetEventsRequest,
+            expected_service_response: Option<Result<EventsPage, GetEventsServiceError>>,
             expected_response_code: StatusCode,
         }
         let test_cases = vec![
+            TestCase {
+                description: "When the page size is larger than the maximum",
+                request: GetEventsRequest {
+                    page_size: Some(1000),
+                    ..Default::default()
+                },
+                expected_service_response: None,
+                expected_response_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the minimum age is greater than the maximum age",
+                request: GetEventsRequest {
+                    age_min: Some(18),
+                    age_max: Some(16),
+                    ..Default::default()
+                },
+                expected_service_response: None,
+                expected_response_code: StatusCode::BAD_REQUEST,
+            },
             TestCase {
                 description: "When retrieval of events fails",
-                expected_service_response: Err(
+                request: GetEventsRequest::default(),
+                expected_service_response: Some(Err(
                     GetEventsServiceError::UnableToGetEventsFromRepository(
                         GetEventsRepositoryError::UnableToGetEvents,
                     ),
-                ),
+                )),
                 expected_response_code: StatusCode::INTERNAL_SERVER_ERROR,
             },
             TestCase {
                 description: "When no events found",
-                expected_service_response: Err(
+                request: GetEventsRequest::default(),
+                expected_service_response: Some(Err(
                     GetEventsServiceError::UnableToGetEventsFromRepository(
                         GetEventsRepositoryError::EventsNotFound,
                     ),
-                ),
+                )),
                 expected_response_code: StatusCode::NOT_FOUND,
             },
             TestCase {
                 description: "When events found",
-                expected_service_response: Ok(events),
+                request: GetEventsRequest {
+                    r#type: Some(EventType::Talk),
+                    page: Some(2),
+                    ..Default::default()
+                },
+                expected_service_response: Some(Ok(EventsPage {
+                    events,
+                    total_count: 26,
+                })),
                 expected_response_code: StatusCode::OK,
             },
         ];
@@ -94,16 +168,23 @@ mod test {
             eprintln!("{}", case.description);
             let mut mock_service = MockEventRetrievalService::new();
 
-            mock_service
-                .expect_get_events()
-                .return_once(move || case.expected_service_response);
+            if let Some(expected_service_response) = case.expected_service_response {
+                mock_service
+                    .expect_get_events_page()
+                    .return_once(move |_| expected_service_response);
+            }
 
             let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
                 service: Arc::new(mock_service),
             };
 
-            let actual_response = get_events_common(State(app_state)).await;
-            assert_eq!(actual_response.status(), case.expected_response_code)
+            let actual_response =
+                get_events_common::<_, EventDTO>(State(app_state), Query(case.request)).await;
+            assert_eq!(actual_response.status(), case.expected_response_code);
+            if actual_response.status() == StatusCode::OK {
+                assert_eq!(actual_response.headers()[TOTAL_COUNT_HEADER], "26");
+                assert_eq!(actual_response.headers()[PAGE_HEADER], "2");
+            }
         }
     }
 }
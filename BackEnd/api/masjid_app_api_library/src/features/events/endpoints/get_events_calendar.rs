@@ -0,0 +1,114 @@
+use crate::features::events::icalendar_export::events_to_icalendar;
+use crate::features::events::models::get_events_calendar_request::GetEventsCalendarRequest;
+use crate::features::events::services::errors::get_events_service_error::GetEventsServiceError;
+use crate::features::events::services::event_retrieval_service::EventRetrievalService;
+use crate::shared::types::app_state::ServiceAppState;
+use axum::extract::{Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use std::sync::Arc;
+use validator::Validate;
+
+/// Serves events as an iCalendar feed which calendar apps can subscribe to, optionally narrowed
+/// to a single event type or age group
+#[inline]
+pub async fn get_events_calendar_common<R: EventRetrievalService + ?Sized>(
+    State(state): State<ServiceAppState<Arc<R>>>,
+    Query(request): Query<GetEventsCalendarRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+    match state.service.get_events_calendar(request).await {
+        Ok(calendar) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+                (
+                    header::CONTENT_DISPOSITION,
+                    "inline; filename=\"events.ics\"",
+                ),
+            ],
+            events_to_icalendar(&calendar, Utc::now()),
+        )
+            .into_response(),
+        Err(GetEventsServiceError::UnableToGetEventsFromRepository(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::events::models::event_type::EventType;
+    use crate::features::events::models::events_calendar::EventsCalendar;
+    use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use crate::features::events::services::event_retrieval_service::MockEventRetrievalService;
+
+    #[tokio::test]
+    async fn test_get_events_calendar_common() {
+        struct TestCase {
+            description: &'static str,
+            request: GetEventsCalendarRequest,
+            expected_service_response: Option<Result<EventsCalendar, GetEventsServiceError>>,
+            expected_response_code: StatusCode,
+        }
+        let test_cases = vec![
+            TestCase {
+                description: "When the age range is reversed",
+                request: GetEventsCalendarRequest {
+                    r#type: None,
+                    age_min: Some(16),
+                    age_max: Some(7),
+                },
+                expected_service_response: None,
+                expected_response_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When retrieval of events fails",
+                request: GetEventsCalendarRequest::default(),
+                expected_service_response: Some(Err(
+                    GetEventsServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                )),
+                expected_response_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When subscribing to a single event type",
+                request: GetEventsCalendarRequest {
+                    r#type: Some(EventType::Class),
+                    age_min: None,
+                    age_max: None,
+                },
+                expected_service_response: Some(Ok(EventsCalendar::default())),
+                expected_response_code: StatusCode::OK,
+            },
+        ];
+
+        for case in test_cases {
+            eprintln!("{}", case.description);
+            let mut mock_service = MockEventRetrievalService::new();
+            if let Some(expected_service_response) = case.expected_service_response {
+                mock_service
+                    .expect_get_events_calendar()
+                    .return_once(move |_| expected_service_response);
+            }
+
+            let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
+                service: Arc::new(mock_service),
+            };
+
+            let actual_response =
+                get_events_calendar_common(State(app_state), Query(case.request)).await;
+            assert_eq!(actual_response.status(), case.expected_response_code);
+            if actual_response.status() == StatusCode::OK {
+                assert_eq!(
+                    actual_response.headers().get(header::CONTENT_TYPE).unwrap(),
+                    "text/calendar; charset=utf-8"
+                );
+            }
+        }
+    }
+}
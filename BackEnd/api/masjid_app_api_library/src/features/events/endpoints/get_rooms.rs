@@ -0,0 +1,72 @@
+use crate::features::events::services::errors::get_events_service_error::GetEventsServiceError;
+use crate::features::events::services::event_retrieval_service::EventRetrievalService;
+use crate::shared::types::app_state::ServiceAppState;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+#[inline]
+pub async fn get_rooms_common<R: EventRetrievalService + ?Sized>(
+    State(state): State<ServiceAppState<Arc<R>>>,
+) -> Response {
+    match state.service.get_rooms().await {
+        Ok(rooms) => (StatusCode::OK, Json(rooms)).into_response(),
+        Err(GetEventsServiceError::UnableToGetEventsFromRepository(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::events::models::room::Room;
+    use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use crate::features::events::services::event_retrieval_service::MockEventRetrievalService;
+
+    #[tokio::test]
+    async fn test_get_rooms_common() {
+        struct TestCase {
+            description: &'static str,
+            expected_service_response: Result<Vec<Room>, GetEventsServiceError>,
+            expected_response_code: StatusCode,
+        }
+        let test_cases = vec![
+            TestCase {
+                description: "When retrieval of rooms fails",
+                expected_service_response: Err(
+                    GetEventsServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                ),
+                expected_response_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When rooms are found",
+                expected_service_response: Ok(vec![Room {
+                    id: 1,
+                    name: "Main hall".to_owned(),
+                    capacity: Some(300),
+                }]),
+                expected_response_code: StatusCode::OK,
+            },
+        ];
+
+        for case in test_cases {
+            eprintln!("{}", case.description);
+            let mut mock_service = MockEventRetrievalService::new();
+
+            mock_service
+                .expect_get_rooms()
+                .return_once(move || case.expected_service_response);
+
+            let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
+                service: Arc::new(mock_service),
+            };
+
+            let actual_response = get_rooms_common(State(app_state)).await;
+            assert_eq!(actual_response.status(), case.expected_response_code)
+        }
+    }
+}
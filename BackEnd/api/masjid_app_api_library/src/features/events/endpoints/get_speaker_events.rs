@@ -0,0 +1,93 @@
+use crate::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use crate::features::events::services::errors::get_speaker_events_service_error::GetSpeakerEventsServiceError;
+use crate::features::events::services::event_retrieval_service::EventRetrievalService;
+use crate::shared::types::app_state::ServiceAppState;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Occurrences are served as `O`, so that the public API can serve them without the organiser's
+/// hidden contact details
+#[inline]
+pub async fn get_speaker_events_common<
+    R: EventRetrievalService + ?Sized,
+    O: From<EventOccurrenceDTO> + Serialize,
+>(
+    State(state): State<ServiceAppState<Arc<R>>>,
+    Path(speaker_id): Path<i32>,
+) -> Response {
+    match state
+        .service
+        .get_speaker_events(speaker_id, Utc::now())
+        .await
+    {
+        Ok(occurrences) => (
+            StatusCode::OK,
+            Json(occurrences.into_iter().map(O::from).collect::<Vec<O>>()),
+        )
+            .into_response(),
+        Err(GetSpeakerEventsServiceError::SpeakerNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetSpeakerEventsServiceError::UnableToGetEventsFromRepository(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use crate::features::events::services::event_retrieval_service::MockEventRetrievalService;
+
+    #[tokio::test]
+    async fn test_get_speaker_events_common() {
+        struct TestCase {
+            description: &'static str,
+            expected_service_response:
+                Result<Vec<EventOccurrenceDTO>, GetSpeakerEventsServiceError>,
+            expected_response_code: StatusCode,
+        }
+        let test_cases = vec![
+            TestCase {
+                description: "When the speaker does not exist",
+                expected_service_response: Err(GetSpeakerEventsServiceError::SpeakerNotFound),
+                expected_response_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When retrieval of events fails",
+                expected_service_response: Err(
+                    GetSpeakerEventsServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                ),
+                expected_response_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When the speaker has no upcoming events",
+                expected_service_response: Ok(Vec::new()),
+                expected_response_code: StatusCode::OK,
+            },
+        ];
+
+        for case in test_cases {
+            eprintln!("{}", case.description);
+            let mut mock_service = MockEventRetrievalService::new();
+
+            mock_service
+                .expect_get_speaker_events()
+                .withf(|speaker_id, _| *speaker_id == 1)
+                .return_once(move |_, _| case.expected_service_response);
+
+            let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
+                service: Arc::new(mock_service),
+            };
+
+            let actual_response =
+                get_speaker_events_common::<_, EventOccurrenceDTO>(State(app_state), Path(1)).await;
+            assert_eq!(actual_response.status(), case.expected_response_code)
+        }
+    }
+}
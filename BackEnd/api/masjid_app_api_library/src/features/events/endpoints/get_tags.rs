@@ -0,0 +1,67 @@
+use crate::features::events::services::errors::get_events_service_error::GetEventsServiceError;
+use crate::features::events::services::event_retrieval_service::EventRetrievalService;
+use crate::shared::types::app_state::ServiceAppState;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+#[inline]
+pub async fn get_tags_common<R: EventRetrievalService + ?Sized>(
+    State(state): State<ServiceAppState<Arc<R>>>,
+) -> Response {
+    match state.service.get_tags().await {
+        Ok(tags) => (StatusCode::OK, Json(tags)).into_response(),
+        Err(GetEventsServiceError::UnableToGetEventsFromRepository(_)) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use crate::features::events::services::event_retrieval_service::MockEventRetrievalService;
+
+    #[tokio::test]
+    async fn test_get_tags_common() {
+        struct TestCase {
+            description: &'static str,
+            expected_service_response: Result<Vec<String>, GetEventsServiceError>,
+            expected_response_code: StatusCode,
+        }
+        let test_cases = vec![
+            TestCase {
+                description: "When retrieval of tags fails",
+                expected_service_response: Err(
+                    GetEventsServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                ),
+                expected_response_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When tags are found",
+                expected_service_response: Ok(vec!["youth".to_owned()]),
+                expected_response_code: StatusCode::OK,
+            },
+        ];
+
+        for case in test_cases {
+            eprintln!("{}", case.description);
+            let mut mock_service = MockEventRetrievalService::new();
+
+            mock_service
+                .expect_get_tags()
+                .return_once(move || case.expected_service_response);
+
+            let app_state = ServiceAppState::<Arc<dyn EventRetrievalService>> {
+                service: Arc::new(mock_service),
+            };
+
+            let actual_response = get_tags_common(State(app_state)).await;
+            assert_eq!(actual_response.status(), case.expected_response_code)
+        }
+    }
+}
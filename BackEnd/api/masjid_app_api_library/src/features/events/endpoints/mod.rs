@@ -1 +1,8 @@
+pub mod get_categories;
+pub mod get_event_occurrences;
 pub mod get_events;
+pub mod get_events_calendar;
+pub mod get_rooms;
+pub mod get_speaker_events;
+pub mod get_speakers;
+pub mod get_tags;
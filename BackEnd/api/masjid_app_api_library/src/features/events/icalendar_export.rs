@@ -0,0 +1,373 @@
+use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_exception_dto::EventExceptionDTO;
+use crate::features::events::models::event_recurrence::EventRecurrence;
+use crate::features::events::models::event_status::EventStatus;
+use crate::features::events::models::events_calendar::EventsCalendar;
+use crate::features::events::models::recurrence_end::RecurrenceEnd;
+use crate::features::events::services::occurrence_expansion::is_scheduled_occurrence;
+use chrono::{DateTime, Datelike, TimeDelta, Utc};
+
+const PRODUCT_ID: &str = "-//Masjid App//Events//EN";
+const CALENDAR_NAME: &str = "Masjid Events";
+const UID_DOMAIN: &str = "masjidapp.com";
+// Lines longer than this many octets are folded onto continuation lines
+const MAXIMUM_LINE_LENGTH: usize = 75;
+
+/// Renders events as an iCalendar feed. Each event keeps the same UID for as long as it exists,
+/// so calendar clients update their copy when the event is edited rather than duplicating it
+pub fn events_to_icalendar(calendar: &EventsCalendar, generated_at: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_owned(),
+        "VERSION:2.0".to_owned(),
+        format!("PRODID:{}", PRODUCT_ID),
+        "CALSCALE:GREGORIAN".to_owned(),
+        "METHOD:PUBLISH".to_owned(),
+        format!("X-WR-CALNAME:{}", CALENDAR_NAME),
+    ];
+    for event in &calendar.events {
+        let exceptions: Vec<&EventExceptionDTO> = calendar
+            .exceptions
+            .iter()
+            .filter(|exception| {
+                exception.event_id == event.id
+                    && is_scheduled_occurrence(event, exception.occurrence_date)
+            })
+            .collect();
+        lines.extend(event_to_vevent(event, &exceptions, generated_at));
+        // Moved occurrences override the series for their original date
+        for exception in &exceptions {
+            if let Some(replacement_date) = exception.replacement_date {
+                let mut moved_occurrence = event_properties(event, replacement_date, generated_at);
+                moved_occurrence.insert(
+                    2,
+                    format!("RECURRENCE-ID:{}", format_date(exception.occurrence_date)),
+                );
+                lines.push("BEGIN:VEVENT".to_owned());
+                lines.extend(moved_occurrence);
+                lines.push("END:VEVENT".to_owned());
+            }
+        }
+    }
+    lines.push("END:VCALENDAR".to_owned());
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+fn event_to_vevent(
+    event: &EventDTO,
+    exceptions: &[&EventExceptionDTO],
+    generated_at: DateTime<Utc>,
+) -> Vec<String> {
+    let mut lines = vec!["BEGIN:VEVENT".to_owned()];
+    lines.extend(event_properties(event, event.date, generated_at));
+    if let Some(recurrence_rule) = recurrence_rule(event) {
+        lines.push(format!("RRULE:{}", recurrence_rule));
+        // Both cancelled and moved occurrences are removed from the series, as moved occurrences
+        // are published separately
+        lines.extend(
+            exceptions
+                .iter()
+                .map(|exception| format!("EXDATE:{}", format_date(exception.occurrence_date))),
+        );
+    }
+    lines.push("END:VEVENT".to_owned());
+    lines
+}
+
+fn event_properties(
+    event: &EventDTO,
+    start: DateTime<Utc>,
+    generated_at: DateTime<Utc>,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("UID:event-{}@{}", event.id, UID_DOMAIN),
+        format!("DTSTAMP:{}", format_date(generated_at)),
+        format!("DTSTART:{}", format_date(start)),
+        // Calendar clients only replace their copy of an event when its sequence has increased
+        format!("SEQUENCE:{}", event.version),
+    ];
+    if let Some(duration_minutes) = event.event_details.duration_minutes {
+        let end = start + TimeDelta::minutes(duration_minutes as i64);
+        lines.push(format!("DTEND:{}", format_date(end)));
+    }
+    lines.push(format!("SUMMARY:{}", escape_text(&event.title)));
+    if let Some(description) = &event.description {
+        lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    lines.push(format!(
+        "CATEGORIES:{}",
+        event.event_details.event_type.to_string().to_uppercase()
+    ));
+    let status = match event.event_details.event_status {
+        EventStatus::Cancelled => "CANCELLED",
+        EventStatus::Postponed | EventStatus::Draft | EventStatus::Scheduled => "TENTATIVE",
+        EventStatus::Confirmed | EventStatus::Archived => "CONFIRMED",
+    };
+    lines.push(format!("STATUS:{}", status));
+    lines
+}
+
+fn recurrence_rule(event: &EventDTO) -> Option<String> {
+    let mut rule = match event.event_details.event_recurrence {
+        EventRecurrence::OneOff => return None,
+        EventRecurrence::Daily => "FREQ=DAILY".to_owned(),
+        EventRecurrence::Weekly => "FREQ=WEEKLY".to_owned(),
+        EventRecurrence::Fortnightly => "FREQ=WEEKLY;INTERVAL=2".to_owned(),
+        // Calendars skip months without the anchor's day, whereas occurrences fall on the last
+        // day of shorter months instead, so the latest valid day up to the anchor's is chosen
+        EventRecurrence::Monthly if event.date.day() > 28 => format!(
+            "FREQ=MONTHLY;BYMONTHDAY={};BYSETPOS=-1",
+            (28..=event.date.day())
+                .map(|day| day.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        ),
+        EventRecurrence::Monthly => "FREQ=MONTHLY".to_owned(),
+    };
+    match event.event_details.recurrence_end {
+        Some(RecurrenceEnd::EndDate(end_date)) => {
+            rule.push_str(&format!(";UNTIL={}", format_date(end_date)))
+        }
+        Some(RecurrenceEnd::OccurrenceCount(count)) => rule.push_str(&format!(";COUNT={}", count)),
+        None => {}
+    }
+    Some(rule)
+}
+
+#[inline]
+fn format_date(date: DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+/// Terminates the line, splitting it onto continuation lines without breaking up a character
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut line_length = 0;
+    for character in line.chars() {
+        if line_length + character.len_utf8() > MAXIMUM_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            // The leading space counts towards the continuation line's length
+            line_length = 1;
+        }
+        folded.push(character);
+        line_length += character.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::event_details::EventDetails;
+    use crate::features::events::models::event_type::EventType;
+    use crate::shared::types::contact_details::ContactDetails;
+    use crate::shared::types::contact_visibility::ContactVisibility;
+    use chrono::TimeZone;
+
+    fn get_test_event(
+        event_recurrence: EventRecurrence,
+        recurrence_end: Option<RecurrenceEnd>,
+        event_status: EventStatus,
+        date: DateTime<Utc>,
+    ) -> EventDTO {
+        EventDTO {
+            id: 7,
+            title: "Tafsir, part 1".to_owned(),
+            description: None,
+            date,
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence,
+                recurrence_end,
+                event_status,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: Some(90),
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "John Smith".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_events_to_icalendar() {
+        struct TestCase {
+            description: &'static str,
+            event: EventDTO,
+            exceptions: Vec<EventExceptionDTO>,
+            expected_lines: Vec<&'static str>,
+            unexpected_lines: Vec<&'static str>,
+        }
+        let anchor = Utc.with_ymd_and_hms(2025, 1, 6, 19, 0, 0).unwrap();
+        let test_cases = [
+            TestCase {
+                description: "When the event happens once, it should have no recurrence rule",
+                event: get_test_event(
+                    EventRecurrence::OneOff,
+                    None,
+                    EventStatus::Confirmed,
+                    anchor,
+                ),
+                exceptions: Vec::new(),
+                expected_lines: vec![
+                    "UID:event-7@masjidapp.com",
+                    "DTSTART:20250106T190000Z",
+                    "DTEND:20250106T203000Z",
+                    "SUMMARY:Tafsir\\, part 1",
+                    "CATEGORIES:CLASS",
+                    "STATUS:CONFIRMED",
+                    "SEQUENCE:1",
+                ],
+                unexpected_lines: vec!["RRULE"],
+            },
+            TestCase {
+                description: "When the event has been edited, its sequence should be its version so that calendars replace their copy",
+                event: EventDTO {
+                    version: 4,
+                    ..get_test_event(
+                        EventRecurrence::OneOff,
+                        None,
+                        EventStatus::Confirmed,
+                        anchor,
+                    )
+                },
+                exceptions: Vec::new(),
+                expected_lines: vec!["SEQUENCE:4"],
+                unexpected_lines: vec!["SEQUENCE:1"],
+            },
+            TestCase {
+                description: "When the event is fortnightly for a number of occurrences, the rule should repeat every other week with a count",
+                event: get_test_event(
+                    EventRecurrence::Fortnightly,
+                    Some(RecurrenceEnd::OccurrenceCount(6)),
+                    EventStatus::Confirmed,
+                    anchor,
+                ),
+                exceptions: Vec::new(),
+                expected_lines: vec!["RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=6"],
+                unexpected_lines: vec!["EXDATE"],
+            },
+            TestCase {
+                description: "When a monthly event falls on the 31st, the rule should fall back to the last day of shorter months",
+                event: get_test_event(
+                    EventRecurrence::Monthly,
+                    Some(RecurrenceEnd::EndDate(
+                        Utc.with_ymd_and_hms(2025, 12, 31, 19, 0, 0).unwrap(),
+                    )),
+                    EventStatus::Confirmed,
+                    Utc.with_ymd_and_hms(2025, 1, 31, 19, 0, 0).unwrap(),
+                ),
+                exceptions: Vec::new(),
+                expected_lines: vec![
+                    "RRULE:FREQ=MONTHLY;BYMONTHDAY=28,29,30,31;BYSETPOS=-1;UNTIL=20251231T190000Z",
+                ],
+                unexpected_lines: Vec::new(),
+            },
+            TestCase {
+                description: "When the event is cancelled, it should be marked as cancelled",
+                event: get_test_event(
+                    EventRecurrence::Weekly,
+                    None,
+                    EventStatus::Cancelled,
+                    anchor,
+                ),
+                exceptions: Vec::new(),
+                expected_lines: vec!["RRULE:FREQ=WEEKLY", "STATUS:CANCELLED"],
+                unexpected_lines: vec!["STATUS:CONFIRMED"],
+            },
+            TestCase {
+                description: "When occurrences are cancelled or moved, they should be excluded from the series and moved occurrences published separately",
+                event: get_test_event(
+                    EventRecurrence::Weekly,
+                    None,
+                    EventStatus::Confirmed,
+                    anchor,
+                ),
+                exceptions: vec![
+                    EventExceptionDTO {
+                        event_id: 7,
+                        occurrence_date: anchor + TimeDelta::weeks(1),
+                        replacement_date: None,
+                    },
+                    EventExceptionDTO {
+                        event_id: 7,
+                        occurrence_date: anchor + TimeDelta::weeks(2),
+                        replacement_date: Some(anchor + TimeDelta::weeks(2) + TimeDelta::days(1)),
+                    },
+                    EventExceptionDTO {
+                        event_id: 8,
+                        occurrence_date: anchor + TimeDelta::weeks(3),
+                        replacement_date: None,
+                    },
+                ],
+                expected_lines: vec![
+                    "EXDATE:20250113T190000Z",
+                    "EXDATE:20250120T190000Z",
+                    "RECURRENCE-ID:20250120T190000Z",
+                    "DTSTART:20250121T190000Z",
+                ],
+                unexpected_lines: vec!["EXDATE:20250127T190000Z"],
+            },
+        ];
+        let generated_at = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let calendar = EventsCalendar {
+                events: vec![test_case.event],
+                exceptions: test_case.exceptions,
+            };
+            // Folded lines are unfolded so that long properties can be compared whole
+            let actual_result = events_to_icalendar(&calendar, generated_at).replace("\r\n ", "");
+            let actual_lines: Vec<&str> = actual_result.split("\r\n").collect();
+            assert_eq!(Some(&"BEGIN:VCALENDAR"), actual_lines.first());
+            assert!(actual_lines.contains(&"DTSTAMP:20250101T120000Z"));
+            for expected_line in test_case.expected_lines {
+                assert!(actual_lines.contains(&expected_line), "{}", expected_line);
+            }
+            for unexpected_line in test_case.unexpected_lines {
+                assert!(
+                    !actual_lines
+                        .iter()
+                        .any(|line| line.starts_with(unexpected_line)),
+                    "{}",
+                    unexpected_line
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold_line() {
+        let line = format!("DESCRIPTION:{}", "é".repeat(40));
+        let folded = fold_line(&line);
+        for folded_line in folded.trim_end_matches("\r\n").split("\r\n") {
+            assert!(folded_line.len() <= MAXIMUM_LINE_LENGTH);
+        }
+        assert_eq!(line, folded.trim_end_matches("\r\n").replace("\r\n ", ""));
+    }
+}
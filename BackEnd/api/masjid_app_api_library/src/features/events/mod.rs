@@ -1,4 +1,5 @@
 pub mod endpoints;
+pub mod icalendar_export;
 pub mod models;
 pub mod repositories;
 pub mod services;
@@ -1,4 +1,5 @@
 use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::recurrence_end::RecurrenceEnd;
 use chrono::{DateTime, Utc};
 
 #[derive(sqlx::FromRow, Clone, Debug, PartialEq)]
@@ -10,14 +11,32 @@ pub struct Event {
     // Event Details
     pub r#type: String,
     pub recurrence: String,
+    pub recurrence_end_date: Option<DateTime<Utc>>,
+    pub recurrence_count: Option<u16>,
     pub status: String,
+    pub publish_at: Option<DateTime<Utc>>,
     pub minimum_age: Option<u8>,
     pub maximum_age: Option<u8>,
+    pub capacity: Option<u16>,
+    pub room_id: Option<i32>,
+    pub duration_minutes: Option<u16>,
+    // JSON array of speaker ids
+    pub speaker_ids: String,
+    pub category_id: Option<i32>,
+    // JSON array of lowercase tags
+    pub tags: String,
     pub image_url: Option<String>,
     // Organiser Contact Details
     pub full_name: String,
     pub phone_number: String,
     pub email: Option<String>,
+    // Which contact details are shown on the public API
+    pub show_full_name: bool,
+    pub show_phone_number: bool,
+    pub show_email: bool,
+    pub contact_form_enabled: bool,
+    // Incremented on every change. Updates are only applied when this matches the stored version
+    pub version: u32,
 }
 
 impl From<EventDTO> for Event {
@@ -27,6 +46,11 @@ impl From<EventDTO> for Event {
             None => (None, None),
             Some(age_range) => (Some(age_range.minimum_age), Some(age_range.maximum_age)),
         };
+        let (recurrence_end_date, recurrence_count) = match dto.event_details.recurrence_end {
+            None => (None, None),
+            Some(RecurrenceEnd::EndDate(end_date)) => (Some(end_date), None),
+            Some(RecurrenceEnd::OccurrenceCount(count)) => (None, Some(count)),
+        };
         Self {
             id: dto.id,
             title: dto.title,
@@ -34,13 +58,73 @@ impl From<EventDTO> for Event {
             date: dto.date,
             r#type: dto.event_details.event_type.to_string(),
             recurrence: dto.event_details.event_recurrence.to_string(),
+            recurrence_end_date,
+            recurrence_count,
             status: dto.event_details.event_status.to_string(),
+            publish_at: dto.event_details.publish_at,
             minimum_age,
             maximum_age,
+            capacity: dto.event_details.capacity,
+            room_id: dto.event_details.room_id,
+            duration_minutes: dto.event_details.duration_minutes,
+            speaker_ids: serde_json::to_string(&dto.event_details.speaker_ids)
+                .unwrap_or_else(|_| "[]".to_owned()),
+            category_id: dto.event_details.category_id,
+            tags: serde_json::to_string(&normalise_tags(dto.event_details.tags))
+                .unwrap_or_else(|_| "[]".to_owned()),
             image_url: dto.event_details.image_url,
             full_name: dto.event_details.contact_details.full_name,
             phone_number: dto.event_details.contact_details.phone_number,
             email: dto.event_details.contact_details.email,
+            show_full_name: dto.event_details.contact_details.visibility.full_name,
+            show_phone_number: dto.event_details.contact_details.visibility.phone_number,
+            show_email: dto.event_details.contact_details.visibility.email,
+            contact_form_enabled: dto.event_details.contact_details.visibility.contact_form,
+            version: dto.version,
+        }
+    }
+}
+
+/// Lowercases and trims tags, dropping any repeats, so that the same tag is never stored twice
+/// under different cases
+fn normalise_tags(tags: Vec<String>) -> Vec<String> {
+    let mut normalised_tags: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if !normalised_tags.contains(&tag) {
+            normalised_tags.push(tag);
+        }
+    }
+    normalised_tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise_tags() {
+        struct TestCase {
+            description: &'static str,
+            tags: Vec<&'static str>,
+            expected_tags: Vec<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When tags are in mixed case, they should be lowercased",
+                tags: vec!["Youth", "SISTERS"],
+                expected_tags: vec!["youth", "sisters"],
+            },
+            TestCase {
+                description: "When a tag is repeated in a different case, only the first should be kept",
+                tags: vec!["fundraiser", " Fundraiser ", "youth"],
+                expected_tags: vec!["fundraiser", "youth"],
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let tags = test_case.tags.into_iter().map(str::to_owned).collect();
+            assert_eq!(test_case.expected_tags, normalise_tags(tags));
         }
     }
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// A heading events are listed under, such as Youth or Sisters, which administrators manage
+/// alongside the fixed event types
+#[derive(sqlx::FromRow, Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Validate)]
+pub struct EventCategory {
+    pub id: i32,
+
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+}
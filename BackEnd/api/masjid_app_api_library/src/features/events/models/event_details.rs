@@ -1,12 +1,19 @@
 use crate::features::events::models::event_recurrence::EventRecurrence;
 use crate::features::events::models::event_status::EventStatus;
 use crate::features::events::models::event_type::EventType;
+use crate::features::events::models::recurrence_end::RecurrenceEnd;
 use crate::shared::types::age_range::AgeRange;
 use crate::shared::types::contact_details::ContactDetails;
+use crate::shared::types::image_variants::ImageVariants;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+pub const MAXIMUM_TAG_LENGTH: u64 = 30;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Validate)]
+#[validate(schema(function = "validate_publish_at"))]
+#[validate(schema(function = "validate_room_booking"))]
 pub struct EventDetails {
     #[serde(rename(serialize = "eventType", deserialize = "eventType"))]
     pub event_type: EventType,
@@ -14,18 +21,149 @@ pub struct EventDetails {
     #[serde(rename(serialize = "eventRecurrence", deserialize = "eventRecurrence"))]
     pub event_recurrence: EventRecurrence,
 
+    #[validate(nested)]
+    #[serde(rename(serialize = "recurrenceEnd", deserialize = "recurrenceEnd"))]
+    pub recurrence_end: Option<RecurrenceEnd>,
+
     #[serde(rename(serialize = "eventStatus", deserialize = "eventStatus"))]
     pub event_status: EventStatus,
 
+    /// When a scheduled event is published
+    #[serde(rename(serialize = "publishAt", deserialize = "publishAt"))]
+    pub publish_at: Option<DateTime<Utc>>,
+
     #[validate(nested)]
     #[serde(rename(serialize = "ageRange", deserialize = "ageRange"))]
     pub age_range: Option<AgeRange>,
 
-    #[validate(url)]
+    /// The number of places available, where registrations beyond this are waitlisted. Events
+    /// without a capacity accept every registration
+    #[validate(range(min = 1))]
+    pub capacity: Option<u16>,
+
+    /// The room the event is held in, which it books for `duration_minutes` from each occurrence
+    #[serde(rename(serialize = "roomId", deserialize = "roomId"))]
+    pub room_id: Option<i32>,
+
+    #[validate(range(min = 1))]
+    #[serde(rename(serialize = "durationMinutes", deserialize = "durationMinutes"))]
+    pub duration_minutes: Option<u16>,
+
+    /// The speakers or teachers leading the event, as opposed to its organiser
+    #[validate(length(max = 10))]
+    #[serde(default, rename(serialize = "speakerIds", deserialize = "speakerIds"))]
+    pub speaker_ids: Vec<i32>,
+
+    #[serde(rename(serialize = "categoryId", deserialize = "categoryId"))]
+    pub category_id: Option<i32>,
+
+    /// Free-form labels such as "fundraiser", which are stored in lowercase so that filtering by
+    /// them ignores case
+    #[validate(length(max = 10), custom(function = "validate_tags"))]
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    // Uploaded images are referenced by a path relative to the public API rather than a full url
+    #[validate(length(max = 2083))]
     #[serde(rename(serialize = "imageUrl", deserialize = "imageUrl"))]
     pub image_url: Option<String>,
 
+    /// Derived from `image_url`, so any value sent in a request is ignored
+    #[serde(
+        default,
+        skip_deserializing,
+        rename(serialize = "imageVariants", deserialize = "imageVariants")
+    )]
+    pub image_variants: Option<ImageVariants>,
+
     #[validate(nested)]
     #[serde(rename(serialize = "contactDetails", deserialize = "contactDetails"))]
     pub contact_details: ContactDetails,
 }
+
+fn validate_publish_at(event_details: &EventDetails) -> Result<(), ValidationError> {
+    if event_details.event_status == EventStatus::Scheduled && event_details.publish_at.is_none() {
+        return Err(ValidationError::new(
+            "scheduled events require a publishAt date",
+        ));
+    }
+    Ok(())
+}
+
+// Tags are kept to simple words so that they can be listed in a single CSV field
+fn validate_tags(tags: &[String]) -> Result<(), ValidationError> {
+    let is_valid_tag = |tag: &String| {
+        // Tags are trimmed when saved, so one made only of spaces would be saved empty
+        let tag = tag.trim();
+        (1..=MAXIMUM_TAG_LENGTH).contains(&(tag.chars().count() as u64))
+            && tag
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == ' ' || c == '-')
+    };
+    if !tags.iter().all(is_valid_tag) {
+        return Err(ValidationError::new(
+            "tags must be up to 30 letters, numbers, spaces or hyphens",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_room_booking(event_details: &EventDetails) -> Result<(), ValidationError> {
+    if event_details.room_id.is_some() && event_details.duration_minutes.is_none() {
+        return Err(ValidationError::new(
+            "events held in a room require a durationMinutes",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_tags() {
+        struct TestCase {
+            description: &'static str,
+            tags: Vec<&'static str>,
+            expected_is_valid: bool,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When tags are words separated by spaces or hyphens, they should be valid",
+                tags: vec!["youth", "new muslims", "fund-raiser"],
+                expected_is_valid: true,
+            },
+            TestCase {
+                description: "When a tag is surrounded by spaces, it should be valid",
+                tags: vec![" youth "],
+                expected_is_valid: true,
+            },
+            TestCase {
+                description: "When a tag is empty, it should be invalid",
+                tags: vec![""],
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When a tag is only spaces, it should be invalid",
+                tags: vec!["   "],
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When a tag has punctuation, it should be invalid",
+                tags: vec!["youth,sisters"],
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When a tag is longer than the maximum length, it should be invalid",
+                tags: vec!["a tag which is far too long to list"],
+                expected_is_valid: false,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let tags: Vec<String> = test_case.tags.into_iter().map(str::to_owned).collect();
+            assert_eq!(test_case.expected_is_valid, validate_tags(&tags).is_ok());
+        }
+    }
+}
@@ -3,8 +3,11 @@ use crate::features::events::models::event_details::EventDetails;
 use crate::features::events::models::event_recurrence::EventRecurrence;
 use crate::features::events::models::event_status::EventStatus;
 use crate::features::events::models::event_type::EventType;
+use crate::features::events::models::recurrence_end::RecurrenceEnd;
 use crate::shared::types::age_range::AgeRange;
 use crate::shared::types::contact_details::ContactDetails;
+use crate::shared::types::contact_visibility::ContactVisibility;
+use crate::shared::types::image_variants::ImageVariants;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -25,6 +28,10 @@ pub struct EventDTO {
     #[validate(nested)]
     #[serde(rename(serialize = "eventDetails", deserialize = "eventDetails"))]
     pub event_details: EventDetails,
+
+    /// Sent as the ETag of the event. New events start at version 1
+    #[serde(default)]
+    pub version: u32,
 }
 
 impl From<Event> for EventDTO {
@@ -36,6 +43,11 @@ impl From<Event> for EventDTO {
                 maximum_age: event.maximum_age.unwrap(),
             });
         }
+        let recurrence_end = match (event.recurrence_end_date, event.recurrence_count) {
+            (Some(end_date), _) => Some(RecurrenceEnd::EndDate(end_date)),
+            (None, Some(count)) => Some(RecurrenceEnd::OccurrenceCount(count)),
+            (None, None) => None,
+        };
         Self {
             id: event.id,
             title: event.title,
@@ -44,16 +56,35 @@ impl From<Event> for EventDTO {
             event_details: EventDetails {
                 event_type: EventType::from_str(&event.r#type).unwrap(),
                 event_recurrence: EventRecurrence::from_str(&event.recurrence).unwrap(),
+                recurrence_end,
                 event_status: EventStatus::from_str(&event.status).unwrap(),
+                publish_at: event.publish_at,
                 age_range,
+                capacity: event.capacity,
+                room_id: event.room_id,
+                duration_minutes: event.duration_minutes,
+                speaker_ids: serde_json::from_str(&event.speaker_ids).unwrap_or_default(),
+                category_id: event.category_id,
+                tags: serde_json::from_str(&event.tags).unwrap_or_default(),
+                image_variants: event
+                    .image_url
+                    .as_deref()
+                    .map(ImageVariants::from_image_url),
                 image_url: event.image_url,
                 contact_details: ContactDetails {
                     full_name: event.full_name,
                     title: None,
                     phone_number: event.phone_number,
                     email: event.email,
+                    visibility: ContactVisibility {
+                        full_name: event.show_full_name,
+                        phone_number: event.show_phone_number,
+                        email: event.show_email,
+                        contact_form: event.contact_form_enabled,
+                    },
                 },
             },
+            version: event.version,
         }
     }
 }
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Overrides a single occurrence of a recurring event. The occurrence is cancelled when no
+/// replacement date is given, otherwise it is moved to the replacement date
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EventExceptionDTO {
+    #[serde(rename(serialize = "eventId", deserialize = "eventId"))]
+    pub event_id: i32,
+
+    #[serde(rename(serialize = "occurrenceDate", deserialize = "occurrenceDate"))]
+    pub occurrence_date: DateTime<Utc>,
+
+    #[serde(rename(serialize = "replacementDate", deserialize = "replacementDate"))]
+    pub replacement_date: Option<DateTime<Utc>>,
+}
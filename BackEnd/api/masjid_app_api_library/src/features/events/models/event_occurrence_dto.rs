@@ -0,0 +1,15 @@
+use crate::features::events::models::event_dto::EventDTO;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct EventOccurrenceDTO {
+    #[serde(rename(serialize = "occurrenceDate"))]
+    pub occurrence_date: DateTime<Utc>,
+
+    /// Only set when the occurrence has been moved from its scheduled date
+    #[serde(rename(serialize = "originalDate"))]
+    pub original_date: Option<DateTime<Utc>>,
+
+    pub event: EventDTO,
+}
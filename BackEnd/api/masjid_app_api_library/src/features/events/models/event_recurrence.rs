@@ -30,7 +30,7 @@ impl FromStr for EventRecurrence {
             "one-off" => Ok(EventRecurrence::OneOff),
             "daily" => Ok(EventRecurrence::Daily),
             "weekly" => Ok(EventRecurrence::Weekly),
-            "fortnight" => Ok(EventRecurrence::Fortnightly),
+            "fortnightly" => Ok(EventRecurrence::Fortnightly),
             "monthly" => Ok(EventRecurrence::Monthly),
             _ => Err(()),
         }
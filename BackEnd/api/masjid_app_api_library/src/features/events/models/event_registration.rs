@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+#[derive(sqlx::FromRow, Clone, Debug, PartialEq)]
+pub struct EventRegistration {
+    pub id: i32,
+    pub event_id: i32,
+    pub full_name: String,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    pub party_size: u8,
+    pub status: String,
+    pub registered_at: DateTime<Utc>,
+}
@@ -0,0 +1,40 @@
+use crate::features::events::models::event_registration::EventRegistration;
+use crate::features::events::models::registration_status::RegistrationStatus;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::str::FromStr;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct EventRegistrationDTO {
+    pub id: i32,
+
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+
+    pub email: Option<String>,
+
+    #[serde(rename = "phoneNumber")]
+    pub phone_number: Option<String>,
+
+    #[serde(rename = "partySize")]
+    pub party_size: u8,
+
+    pub status: RegistrationStatus,
+
+    #[serde(rename = "registeredAt")]
+    pub registered_at: DateTime<Utc>,
+}
+
+impl From<EventRegistration> for EventRegistrationDTO {
+    fn from(registration: EventRegistration) -> Self {
+        Self {
+            id: registration.id,
+            full_name: registration.full_name,
+            email: registration.email,
+            phone_number: registration.phone_number,
+            party_size: registration.party_size,
+            status: RegistrationStatus::from_str(&registration.status).unwrap(),
+            registered_at: registration.registered_at,
+        }
+    }
+}
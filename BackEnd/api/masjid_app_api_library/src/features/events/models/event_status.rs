@@ -6,14 +6,71 @@ use std::str::FromStr;
 #[sqlx(rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum EventStatus {
+    /// Being prepared, and not yet visible to the public
+    Draft,
+    /// Published automatically once its `publish_at` time is reached
+    Scheduled,
     Confirmed,
+    Postponed,
     Cancelled,
+    /// Hidden from the public once the event is over
+    Archived,
+}
+impl EventStatus {
+    /// Whether events with this status are shown to the public
+    pub fn is_published(&self) -> bool {
+        matches!(
+            self,
+            EventStatus::Confirmed | EventStatus::Postponed | EventStatus::Cancelled
+        )
+    }
+
+    /// Whether a new event can be created with this status
+    pub fn is_valid_initial_status(&self) -> bool {
+        matches!(
+            self,
+            EventStatus::Draft | EventStatus::Scheduled | EventStatus::Confirmed
+        )
+    }
+
+    /// Whether an event with this status can be moved to `next`. Keeping the same status is
+    /// always allowed, so that the rest of the event can be edited
+    pub fn can_transition_to(&self, next: &EventStatus) -> bool {
+        if self == next {
+            return true;
+        }
+        match self {
+            EventStatus::Draft => matches!(
+                next,
+                EventStatus::Scheduled | EventStatus::Confirmed | EventStatus::Archived
+            ),
+            EventStatus::Scheduled => matches!(
+                next,
+                EventStatus::Draft | EventStatus::Confirmed | EventStatus::Archived
+            ),
+            EventStatus::Confirmed => matches!(
+                next,
+                EventStatus::Postponed | EventStatus::Cancelled | EventStatus::Archived
+            ),
+            EventStatus::Postponed => matches!(
+                next,
+                EventStatus::Confirmed | EventStatus::Cancelled | EventStatus::Archived
+            ),
+            EventStatus::Cancelled => matches!(next, EventStatus::Archived),
+            // Archived events are restored as drafts, so they are reviewed before being shown again
+            EventStatus::Archived => matches!(next, EventStatus::Draft),
+        }
+    }
 }
 impl ToString for EventStatus {
     fn to_string(&self) -> String {
         match self {
+            EventStatus::Draft => "draft".to_owned(),
+            EventStatus::Scheduled => "scheduled".to_owned(),
             EventStatus::Confirmed => "confirmed".to_owned(),
+            EventStatus::Postponed => "postponed".to_owned(),
             EventStatus::Cancelled => "cancelled".to_owned(),
+            EventStatus::Archived => "archived".to_owned(),
         }
     }
 }
@@ -21,9 +78,79 @@ impl FromStr for EventStatus {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "draft" => Ok(EventStatus::Draft),
+            "scheduled" => Ok(EventStatus::Scheduled),
             "confirmed" => Ok(EventStatus::Confirmed),
+            "postponed" => Ok(EventStatus::Postponed),
             "cancelled" => Ok(EventStatus::Cancelled),
+            "archived" => Ok(EventStatus::Archived),
             _ => Err(()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_status_can_transition_to() {
+        struct TestCase {
+            description: &'static str,
+            from: EventStatus,
+            to: EventStatus,
+            expected_result: bool,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the status is unchanged, the transition should be allowed",
+                from: EventStatus::Cancelled,
+                to: EventStatus::Cancelled,
+                expected_result: true,
+            },
+            TestCase {
+                description: "When a draft is scheduled, the transition should be allowed",
+                from: EventStatus::Draft,
+                to: EventStatus::Scheduled,
+                expected_result: true,
+            },
+            TestCase {
+                description: "When a published event is returned to draft, the transition should not be allowed",
+                from: EventStatus::Confirmed,
+                to: EventStatus::Draft,
+                expected_result: false,
+            },
+            TestCase {
+                description: "When a postponed event is confirmed again, the transition should be allowed",
+                from: EventStatus::Postponed,
+                to: EventStatus::Confirmed,
+                expected_result: true,
+            },
+            TestCase {
+                description: "When a cancelled event is confirmed again, the transition should not be allowed",
+                from: EventStatus::Cancelled,
+                to: EventStatus::Confirmed,
+                expected_result: false,
+            },
+            TestCase {
+                description: "When an archived event is published straight away, the transition should not be allowed",
+                from: EventStatus::Archived,
+                to: EventStatus::Confirmed,
+                expected_result: false,
+            },
+            TestCase {
+                description: "When an archived event is restored as a draft, the transition should be allowed",
+                from: EventStatus::Archived,
+                to: EventStatus::Draft,
+                expected_result: true,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_result,
+                test_case.from.can_transition_to(&test_case.to)
+            );
+        }
+    }
+}
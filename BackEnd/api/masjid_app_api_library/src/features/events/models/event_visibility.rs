@@ -0,0 +1,8 @@
+/// Which events a retrieval returns. Anything facing the public only ever sees published events
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum EventVisibility {
+    #[default]
+    Published,
+    /// Includes drafts, scheduled and archived events, for administrators
+    All,
+}
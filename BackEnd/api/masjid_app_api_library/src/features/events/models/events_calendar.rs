@@ -0,0 +1,10 @@
+use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_exception_dto::EventExceptionDTO;
+
+/// Everything needed to publish events as a calendar, as recurring events are exported as rules
+/// rather than expanded into their occurrences
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventsCalendar {
+    pub events: Vec<EventDTO>,
+    pub exceptions: Vec<EventExceptionDTO>,
+}
@@ -0,0 +1,262 @@
+use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_recurrence::EventRecurrence;
+use crate::features::events::models::event_status::EventStatus;
+use crate::features::events::models::event_type::EventType;
+use crate::features::events::models::event_visibility::EventVisibility;
+use crate::features::events::models::get_events_request::GetEventsRequest;
+use crate::features::events::models::recurrence_end::RecurrenceEnd;
+use chrono::{DateTime, Utc};
+
+pub const DEFAULT_PAGE_SIZE: u32 = 25;
+
+/// Events between `from` and `to` include recurring series which are still running during
+/// that period, not only events anchored within it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventsFilter {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub event_type: Option<EventType>,
+    pub event_status: Option<EventStatus>,
+    pub minimum_age: Option<u8>,
+    pub maximum_age: Option<u8>,
+    pub category_id: Option<i32>,
+    /// Always lowercase, as tags are stored
+    pub tag: Option<String>,
+    pub page: u32,
+    pub page_size: u32,
+    /// Set by the service rather than the request, so the public cannot ask for unpublished events
+    pub visibility: EventVisibility,
+}
+
+impl EventsFilter {
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        (self.page.saturating_sub(1) as u64) * self.page_size as u64
+    }
+
+    /// Applies the filter to an event which has already been read, matching the events the
+    /// database returns for the same filter
+    pub fn matches(&self, event: &EventDTO) -> bool {
+        let details = &event.event_details;
+        if self.to.is_some_and(|to| event.date >= to) {
+            return false;
+        }
+        // Recurring series anchored before the window are included while they are still running
+        if let Some(from) = self.from
+            && event.date < from
+        {
+            let still_running = match (&details.event_recurrence, details.recurrence_end) {
+                (EventRecurrence::OneOff, _) => false,
+                (_, Some(RecurrenceEnd::EndDate(end_date))) => end_date >= from,
+                (_, _) => true,
+            };
+            if !still_running {
+                return false;
+            }
+        }
+        // Events without an age range are suitable for everyone
+        let suits_ages = details.age_range.as_ref().is_none_or(|age_range| {
+            self.minimum_age
+                .is_none_or(|minimum_age| age_range.maximum_age >= minimum_age)
+                && self
+                    .maximum_age
+                    .is_none_or(|maximum_age| age_range.minimum_age <= maximum_age)
+        });
+        suits_ages
+            && self
+                .event_type
+                .as_ref()
+                .is_none_or(|event_type| details.event_type == *event_type)
+            && self
+                .event_status
+                .as_ref()
+                .is_none_or(|event_status| details.event_status == *event_status)
+            && self
+                .category_id
+                .is_none_or(|category_id| details.category_id == Some(category_id))
+            && self
+                .tag
+                .as_ref()
+                .is_none_or(|tag| details.tags.contains(tag))
+            && (self.visibility == EventVisibility::All || details.event_status.is_published())
+    }
+}
+
+impl From<GetEventsRequest> for EventsFilter {
+    fn from(request: GetEventsRequest) -> Self {
+        Self {
+            from: request.from,
+            to: request.to,
+            event_type: request.r#type,
+            event_status: request.status,
+            minimum_age: request.age_min,
+            maximum_age: request.age_max,
+            category_id: request.category_id,
+            tag: request.tag.map(|tag| tag.trim().to_lowercase()),
+            page: request.page.unwrap_or(1),
+            page_size: request.page_size.unwrap_or(DEFAULT_PAGE_SIZE),
+            visibility: EventVisibility::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::event_details::EventDetails;
+    use crate::shared::types::age_range::AgeRange;
+    use crate::shared::types::contact_details::ContactDetails;
+    use crate::shared::types::contact_visibility::ContactVisibility;
+
+    #[test]
+    fn test_events_filter_matches() {
+        struct TestCase {
+            description: &'static str,
+            filter: EventsFilter,
+            event: EventDTO,
+            expected_result: bool,
+        }
+        let filter = EventsFilter {
+            from: Some("2099-01-01T00:00:00Z".parse().unwrap()),
+            to: Some("2099-02-01T00:00:00Z".parse().unwrap()),
+            event_type: None,
+            event_status: None,
+            minimum_age: None,
+            maximum_age: None,
+            category_id: None,
+            tag: None,
+            page: 1,
+            page_size: DEFAULT_PAGE_SIZE,
+            visibility: EventVisibility::Published,
+        };
+        let event = EventDTO {
+            id: 1,
+            title: "weekly halaqa".to_owned(),
+            description: None,
+            date: "2099-01-05T19:00:00Z".parse().unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: Some(AgeRange {
+                    minimum_age: 12,
+                    maximum_age: 18,
+                }),
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: Some(1),
+                tags: vec!["youth".to_owned()],
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        };
+        let mut earlier_weekly_event = event.clone();
+        earlier_weekly_event.date = "2098-12-01T19:00:00Z".parse().unwrap();
+        earlier_weekly_event.event_details.event_recurrence = EventRecurrence::Weekly;
+        let mut finished_weekly_event = earlier_weekly_event.clone();
+        finished_weekly_event.event_details.recurrence_end = Some(RecurrenceEnd::EndDate(
+            "2098-12-22T19:00:00Z".parse().unwrap(),
+        ));
+        let mut draft_event = event.clone();
+        draft_event.event_details.event_status = EventStatus::Draft;
+        let test_cases = [
+            TestCase {
+                description: "When the event falls within the window, it should match",
+                filter: filter.clone(),
+                event: event.clone(),
+                expected_result: true,
+            },
+            TestCase {
+                description: "When the event starts at the end of the window, it should not match",
+                filter: EventsFilter {
+                    to: Some(event.date),
+                    ..filter.clone()
+                },
+                event: event.clone(),
+                expected_result: false,
+            },
+            TestCase {
+                description: "When a one-off event took place before the window, it should not match",
+                filter: EventsFilter {
+                    from: Some("2099-01-06T00:00:00Z".parse().unwrap()),
+                    ..filter.clone()
+                },
+                event: event.clone(),
+                expected_result: false,
+            },
+            TestCase {
+                description: "When a recurring event started before the window and is still running, it should match",
+                filter: filter.clone(),
+                event: earlier_weekly_event,
+                expected_result: true,
+            },
+            TestCase {
+                description: "When a recurring event ended before the window, it should not match",
+                filter: filter.clone(),
+                event: finished_weekly_event,
+                expected_result: false,
+            },
+            TestCase {
+                description: "When the event is for an older age group, it should not match",
+                filter: EventsFilter {
+                    maximum_age: Some(10),
+                    ..filter.clone()
+                },
+                event: event.clone(),
+                expected_result: false,
+            },
+            TestCase {
+                description: "When the event has the tag, it should match",
+                filter: EventsFilter {
+                    tag: Some("youth".to_owned()),
+                    ..filter.clone()
+                },
+                event: event.clone(),
+                expected_result: true,
+            },
+            TestCase {
+                description: "When the event is in another category, it should not match",
+                filter: EventsFilter {
+                    category_id: Some(2),
+                    ..filter.clone()
+                },
+                event: event.clone(),
+                expected_result: false,
+            },
+            TestCase {
+                description: "When a draft is filtered for the public, it should not match",
+                filter: filter.clone(),
+                event: draft_event.clone(),
+                expected_result: false,
+            },
+            TestCase {
+                description: "When a draft is filtered for administrators, it should match",
+                filter: EventsFilter {
+                    visibility: EventVisibility::All,
+                    ..filter
+                },
+                event: draft_event,
+                expected_result: true,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_result,
+                test_case.filter.matches(&test_case.event)
+            );
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::features::events::models::event_dto::EventDTO;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventsPage {
+    pub events: Vec<EventDTO>,
+    /// Number of events matching the filter across every page
+    pub total_count: u64,
+}
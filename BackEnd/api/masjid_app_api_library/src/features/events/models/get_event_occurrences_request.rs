@@ -0,0 +1,8 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct GetEventOccurrencesRequest {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
@@ -0,0 +1,45 @@
+use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_type::EventType;
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+/// Narrows a calendar feed, so that subscribers can follow a single category of events
+#[derive(Deserialize, Validate, Clone, Debug, Default, PartialEq, Eq)]
+#[validate(schema(function = "validate_age_range"))]
+pub struct GetEventsCalendarRequest {
+    pub r#type: Option<EventType>,
+
+    #[serde(rename = "ageMin")]
+    pub age_min: Option<u8>,
+
+    #[serde(rename = "ageMax")]
+    pub age_max: Option<u8>,
+}
+
+impl GetEventsCalendarRequest {
+    /// Events without an age range are suitable for everyone, so are always included
+    pub fn matches(&self, event: &EventDTO) -> bool {
+        if let Some(event_type) = &self.r#type
+            && event.event_details.event_type != *event_type
+        {
+            return false;
+        }
+        let Some(age_range) = &event.event_details.age_range else {
+            return true;
+        };
+        self.age_min
+            .is_none_or(|age_min| age_range.maximum_age >= age_min)
+            && self
+                .age_max
+                .is_none_or(|age_max| age_range.minimum_age <= age_max)
+    }
+}
+
+fn validate_age_range(request: &GetEventsCalendarRequest) -> Result<(), ValidationError> {
+    if let (Some(age_min), Some(age_max)) = (request.age_min, request.age_max) {
+        if age_min > age_max {
+            return Err(ValidationError::new("ageMin cannot be greater than ageMax"));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use crate::features::events::models::event_details::MAXIMUM_TAG_LENGTH;
+use crate::features::events::models::event_status::EventStatus;
+use crate::features::events::models::event_type::EventType;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+pub const MAXIMUM_PAGE_SIZE: u32 = 100;
+
+#[derive(Deserialize, Validate, Clone, Debug, Default)]
+#[validate(schema(function = "validate_ranges"))]
+pub struct GetEventsRequest {
+    pub from: Option<DateTime<Utc>>,
+
+    pub to: Option<DateTime<Utc>>,
+
+    pub r#type: Option<EventType>,
+
+    pub status: Option<EventStatus>,
+
+    #[serde(rename = "ageMin")]
+    pub age_min: Option<u8>,
+
+    #[serde(rename = "ageMax")]
+    pub age_max: Option<u8>,
+
+    #[serde(rename = "categoryId")]
+    pub category_id: Option<i32>,
+
+    #[validate(length(min = 1, max = MAXIMUM_TAG_LENGTH))]
+    pub tag: Option<String>,
+
+    #[validate(range(min = 1))]
+    pub page: Option<u32>,
+
+    #[validate(range(min = 1, max = MAXIMUM_PAGE_SIZE))]
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<u32>,
+}
+
+fn validate_ranges(request: &GetEventsRequest) -> Result<(), ValidationError> {
+    if let (Some(from), Some(to)) = (request.from, request.to) {
+        if from >= to {
+            return Err(ValidationError::new("from must be before to"));
+        }
+    }
+    if let (Some(age_min), Some(age_max)) = (request.age_min, request.age_max) {
+        if age_min > age_max {
+            return Err(ValidationError::new("ageMin cannot be greater than ageMax"));
+        }
+    }
+    Ok(())
+}
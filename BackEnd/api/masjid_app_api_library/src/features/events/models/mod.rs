@@ -1,6 +1,27 @@
 pub mod event;
+pub mod event_category;
 pub mod event_details;
 pub mod event_dto;
+pub mod event_exception_dto;
+pub mod event_occurrence_dto;
 pub mod event_recurrence;
+pub mod event_registration;
+pub mod event_registration_dto;
 pub mod event_status;
 pub mod event_type;
+pub mod event_visibility;
+pub mod events_calendar;
+pub mod events_filter;
+pub mod events_page;
+pub mod get_event_occurrences_request;
+pub mod get_events_calendar_request;
+pub mod get_events_request;
+pub mod public_event_details;
+pub mod public_event_dto;
+pub mod public_event_occurrence_dto;
+pub mod recurrence_end;
+pub mod registration_status;
+pub mod reminder_channel;
+pub mod reminder_subscription;
+pub mod room;
+pub mod speaker;
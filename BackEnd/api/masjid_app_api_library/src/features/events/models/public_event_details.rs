@@ -0,0 +1,80 @@
+use crate::features::events::models::event_details::EventDetails;
+use crate::features::events::models::event_recurrence::EventRecurrence;
+use crate::features::events::models::event_status::EventStatus;
+use crate::features::events::models::event_type::EventType;
+use crate::features::events::models::recurrence_end::RecurrenceEnd;
+use crate::shared::types::age_range::AgeRange;
+use crate::shared::types::image_variants::ImageVariants;
+use crate::shared::types::public_contact_details::PublicContactDetails;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// The details of an event as shown on the public API, where the organiser's contact details are
+/// redacted
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PublicEventDetails {
+    #[serde(rename(serialize = "eventType"))]
+    pub event_type: EventType,
+
+    #[serde(rename(serialize = "eventRecurrence"))]
+    pub event_recurrence: EventRecurrence,
+
+    #[serde(rename(serialize = "recurrenceEnd"))]
+    pub recurrence_end: Option<RecurrenceEnd>,
+
+    #[serde(rename(serialize = "eventStatus"))]
+    pub event_status: EventStatus,
+
+    #[serde(rename(serialize = "publishAt"))]
+    pub publish_at: Option<DateTime<Utc>>,
+
+    #[serde(rename(serialize = "ageRange"))]
+    pub age_range: Option<AgeRange>,
+
+    pub capacity: Option<u16>,
+
+    #[serde(rename(serialize = "roomId"))]
+    pub room_id: Option<i32>,
+
+    #[serde(rename(serialize = "durationMinutes"))]
+    pub duration_minutes: Option<u16>,
+
+    #[serde(rename(serialize = "speakerIds"))]
+    pub speaker_ids: Vec<i32>,
+
+    #[serde(rename(serialize = "categoryId"))]
+    pub category_id: Option<i32>,
+
+    pub tags: Vec<String>,
+
+    #[serde(rename(serialize = "imageUrl"))]
+    pub image_url: Option<String>,
+
+    #[serde(rename(serialize = "imageVariants"))]
+    pub image_variants: Option<ImageVariants>,
+
+    #[serde(rename(serialize = "contactDetails"))]
+    pub contact_details: PublicContactDetails,
+}
+
+impl From<EventDetails> for PublicEventDetails {
+    fn from(event_details: EventDetails) -> Self {
+        Self {
+            event_type: event_details.event_type,
+            event_recurrence: event_details.event_recurrence,
+            recurrence_end: event_details.recurrence_end,
+            event_status: event_details.event_status,
+            publish_at: event_details.publish_at,
+            age_range: event_details.age_range,
+            capacity: event_details.capacity,
+            room_id: event_details.room_id,
+            duration_minutes: event_details.duration_minutes,
+            speaker_ids: event_details.speaker_ids,
+            category_id: event_details.category_id,
+            tags: event_details.tags,
+            image_url: event_details.image_url,
+            image_variants: event_details.image_variants,
+            contact_details: PublicContactDetails::from(event_details.contact_details),
+        }
+    }
+}
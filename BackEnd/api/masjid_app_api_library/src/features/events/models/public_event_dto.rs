@@ -0,0 +1,35 @@
+use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::public_event_details::PublicEventDetails;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// An event as served by the public API, which differs from `EventDTO` only in leaving out the
+/// contact details the organiser has hidden
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PublicEventDTO {
+    pub id: i32,
+
+    pub title: String,
+
+    pub description: Option<String>,
+
+    pub date: DateTime<Utc>,
+
+    #[serde(rename(serialize = "eventDetails"))]
+    pub event_details: PublicEventDetails,
+
+    pub version: u32,
+}
+
+impl From<EventDTO> for PublicEventDTO {
+    fn from(event: EventDTO) -> Self {
+        Self {
+            id: event.id,
+            title: event.title,
+            description: event.description,
+            date: event.date,
+            event_details: PublicEventDetails::from(event.event_details),
+            version: event.version,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use crate::features::events::models::public_event_dto::PublicEventDTO;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PublicEventOccurrenceDTO {
+    #[serde(rename(serialize = "occurrenceDate"))]
+    pub occurrence_date: DateTime<Utc>,
+
+    /// Only set when the occurrence has been moved from its scheduled date
+    #[serde(rename(serialize = "originalDate"))]
+    pub original_date: Option<DateTime<Utc>>,
+
+    pub event: PublicEventDTO,
+}
+
+impl From<EventOccurrenceDTO> for PublicEventOccurrenceDTO {
+    fn from(occurrence: EventOccurrenceDTO) -> Self {
+        Self {
+            occurrence_date: occurrence.occurrence_date,
+            original_date: occurrence.original_date,
+            event: PublicEventDTO::from(occurrence.event),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationErrors};
+
+/// When a recurring event stops repeating. Series without one repeat indefinitely
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RecurrenceEnd {
+    EndDate(DateTime<Utc>),
+    OccurrenceCount(u16),
+}
+
+impl Validate for RecurrenceEnd {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        if let RecurrenceEnd::OccurrenceCount(0) = self {
+            return Err(ValidationErrors::new());
+        }
+        Ok(())
+    }
+}
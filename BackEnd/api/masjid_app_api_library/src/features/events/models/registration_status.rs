@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize, Debug, sqlx::Type, Clone, Copy, PartialEq, Eq)]
+#[sqlx(type_name = "varchar")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RegistrationStatus {
+    Confirmed,
+    Waitlisted,
+}
+impl ToString for RegistrationStatus {
+    fn to_string(&self) -> String {
+        match self {
+            RegistrationStatus::Confirmed => "confirmed".to_owned(),
+            RegistrationStatus::Waitlisted => "waitlisted".to_owned(),
+        }
+    }
+}
+impl FromStr for RegistrationStatus {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "confirmed" => Ok(RegistrationStatus::Confirmed),
+            "waitlisted" => Ok(RegistrationStatus::Waitlisted),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How a reminder is delivered
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReminderChannel {
+    Push,
+    Email,
+}
+impl ToString for ReminderChannel {
+    fn to_string(&self) -> String {
+        match self {
+            ReminderChannel::Push => "push".to_owned(),
+            ReminderChannel::Email => "email".to_owned(),
+        }
+    }
+}
+impl FromStr for ReminderChannel {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "push" => Ok(ReminderChannel::Push),
+            "email" => Ok(ReminderChannel::Email),
+            _ => Err(()),
+        }
+    }
+}
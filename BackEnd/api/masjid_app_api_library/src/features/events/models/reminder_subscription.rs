@@ -0,0 +1,15 @@
+use crate::features::events::models::event_type::EventType;
+use crate::features::events::models::reminder_channel::ReminderChannel;
+
+/// A congregant's request to be reminded ahead of each occurrence of an event, or of every event
+/// of a type. Exactly one of `event_id` and `event_type` is set
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReminderSubscription {
+    pub id: i32,
+    pub event_id: Option<i32>,
+    pub event_type: Option<EventType>,
+    pub channel: ReminderChannel,
+    /// The device token for push reminders, otherwise the email address
+    pub recipient: String,
+    pub hours_before: u16,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// A bookable space within the masjid, such as the main hall
+#[derive(sqlx::FromRow, Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Validate)]
+pub struct Room {
+    pub id: i32,
+
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+
+    /// How many people the room holds, if known
+    #[validate(range(min = 1))]
+    pub capacity: Option<u16>,
+}
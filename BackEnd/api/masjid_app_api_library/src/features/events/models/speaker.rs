@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// Somebody who gives talks or teaches classes at the masjid
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Validate)]
+pub struct Speaker {
+    pub id: i32,
+
+    #[validate(length(min = 3, max = 100))]
+    #[serde(rename(serialize = "fullName", deserialize = "fullName"))]
+    pub full_name: String,
+
+    /// How the speaker is addressed, such as Sheikh or Ustadh
+    #[validate(length(min = 1, max = 50))]
+    pub title: Option<String>,
+
+    #[validate(length(max = 1000))]
+    pub bio: Option<String>,
+
+    // Uploaded photos are referenced by a path relative to the public API, as with event images
+    #[validate(length(max = 2083))]
+    #[serde(rename(serialize = "photoUrl", deserialize = "photoUrl"))]
+    pub photo_url: Option<String>,
+
+    /// The languages the speaker teaches in
+    #[validate(length(max = 10))]
+    #[serde(default)]
+    pub languages: Vec<String>,
+}
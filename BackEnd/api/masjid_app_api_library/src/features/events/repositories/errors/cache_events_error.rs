@@ -0,0 +1,4 @@
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CacheEventsError {
+    UnableToCacheEvents,
+}
@@ -1 +1,2 @@
+pub mod cache_events_error;
 pub mod get_events_repository_error;
@@ -1,14 +1,66 @@
+use crate::features::events::models::event_category::EventCategory;
 use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_exception_dto::EventExceptionDTO;
+use crate::features::events::models::event_visibility::EventVisibility;
+use crate::features::events::models::events_filter::EventsFilter;
+use crate::features::events::models::events_page::EventsPage;
+use crate::features::events::models::room::Room;
+use crate::features::events::models::speaker::Speaker;
+use crate::features::events::repositories::errors::cache_events_error::CacheEventsError;
 use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use crate::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use crate::shared::data_access::repository_management::repository_type::RepositoryType;
 use async_trait::async_trait;
 use mockall::automock;
+use std::sync::Arc;
 
 pub mod errors;
-mod mysql_impl;
+pub mod mysql_impl;
 mod redis_impl;
 
 #[automock]
 #[async_trait]
 pub trait EventsRepository: Send + Sync {
-    async fn get_events(&self) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+    async fn get_events(
+        &self,
+        visibility: EventVisibility,
+    ) -> Result<Vec<EventDTO>, GetEventsRepositoryError>;
+    /// Ordered by date, then id, so that pages are stable
+    async fn get_filtered_events(
+        &self,
+        filter: &EventsFilter,
+    ) -> Result<EventsPage, GetEventsRepositoryError>;
+    async fn get_event_exceptions(&self)
+    -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>;
+    async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError>;
+    /// Ordered by name
+    async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError>;
+    /// Ordered by name
+    async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError>;
+    /// Every tag used by the visible events, ordered alphabetically
+    async fn get_tags(
+        &self,
+        visibility: EventVisibility,
+    ) -> Result<Vec<String>, GetEventsRepositoryError>;
+}
+
+/// Keeps the events cache in line with the database. Only the in-memory repository is a cache, so
+/// the database has no implementation of this
+#[automock]
+#[async_trait]
+pub trait EventsCacheRepository: Send + Sync {
+    /// Replaces every cached event at once. Reads are only served from the cache once this has
+    /// been done, so that a cache which is empty or partly filled is never mistaken for the
+    /// events in the database
+    async fn replace_events(&self, events: &[EventDTO]) -> Result<(), CacheEventsError>;
+    /// Events are cached as the database saved them, so they must already have their id and
+    /// version. Should caching fail, reads stop being served from the cache until it is replaced,
+    /// as it may no longer match the database
+    async fn cache_events(&self, events: &[EventDTO]) -> Result<(), CacheEventsError>;
+    /// Reads stop being served from the cache should this fail, as with caching events
+    async fn evict_event(&self, event_id: &i32) -> Result<(), CacheEventsError>;
+}
+
+pub async fn new_events_cache_repository() -> Arc<dyn EventsCacheRepository> {
+    Arc::new(InMemoryRepository::new(RepositoryType::Events).await)
 }
@@ -1,32 +1,30 @@
 use crate::features::events::models::event::Event;
+use crate::features::events::models::event_category::EventCategory;
 use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_exception_dto::EventExceptionDTO;
+use crate::features::events::models::event_visibility::EventVisibility;
+use crate::features::events::models::events_filter::EventsFilter;
+use crate::features::events::models::events_page::EventsPage;
+use crate::features::events::models::room::Room;
+use crate::features::events::models::speaker::Speaker;
 use crate::features::events::repositories::EventsRepository;
 use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
 use crate::shared::data_access::repository_management::mysql_repository::MySqlRepository;
 use async_trait::async_trait;
-use sqlx::mysql::MySqlRow;
-use sqlx::{Error, Row};
+use sqlx::mysql::{MySqlArguments, MySqlRow};
+use sqlx::query::Query;
+use sqlx::{Error, MySql, Row};
 
 #[async_trait]
 impl EventsRepository for MySqlRepository {
-    async fn get_events(&self) -> Result<Vec<EventDTO>, GetEventsRepositoryError> {
+    async fn get_events(
+        &self,
+        visibility: EventVisibility,
+    ) -> Result<Vec<EventDTO>, GetEventsRepositoryError> {
         let db_connection = self.db_connection.clone();
-        let events = sqlx::query("CALL get_events();")
-            .map(|row: MySqlRow| Event {
-                id: row.get(0),
-                title: row.get(1),
-                description: row.get(2),
-                date: row.get(3),
-                r#type: row.get(4),
-                recurrence: row.get(5),
-                status: row.get(6),
-                minimum_age: row.get(7),
-                maximum_age: row.get(8),
-                image_url: row.get(9),
-                full_name: row.get(10),
-                phone_number: row.get(11),
-                email: row.get(12),
-            })
+        let events = sqlx::query("CALL get_events(?);")
+            .bind(visibility == EventVisibility::Published)
+            .map(map_event_row)
             .fetch_all(&*db_connection)
             .await
             .map_err(|err| {
@@ -42,4 +40,163 @@ impl EventsRepository for MySqlRepository {
 
         Ok(events.into_iter().map(EventDTO::from).collect())
     }
+
+    async fn get_event_exceptions(
+        &self,
+    ) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_event_exceptions();")
+            .map(|row: MySqlRow| EventExceptionDTO {
+                event_id: row.get(0),
+                occurrence_date: row.get(1),
+                replacement_date: row.get(2),
+            })
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!("failed to fetch event exceptions from database: {}", err);
+                GetEventsRepositoryError::UnableToGetEvents
+            })
+    }
+
+    async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query_as::<_, Room>("CALL get_rooms();")
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!("failed to fetch rooms from database: {}", err);
+                GetEventsRepositoryError::UnableToGetEvents
+            })
+    }
+
+    async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_speakers();")
+            .map(|row: MySqlRow| Speaker {
+                id: row.get(0),
+                full_name: row.get(1),
+                title: row.get(2),
+                bio: row.get(3),
+                photo_url: row.get(4),
+                languages: serde_json::from_str(row.get::<&str, _>(5)).unwrap_or_default(),
+            })
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!("failed to fetch speakers from database: {}", err);
+                GetEventsRepositoryError::UnableToGetEvents
+            })
+    }
+
+    async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query_as::<_, EventCategory>("CALL get_event_categories();")
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!("failed to fetch event categories from database: {}", err);
+                GetEventsRepositoryError::UnableToGetEvents
+            })
+    }
+
+    async fn get_tags(
+        &self,
+        visibility: EventVisibility,
+    ) -> Result<Vec<String>, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_event_tags(?);")
+            .bind(visibility == EventVisibility::Published)
+            .map(|row: MySqlRow| row.get(0))
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!("failed to fetch event tags from database: {}", err);
+                GetEventsRepositoryError::UnableToGetEvents
+            })
+    }
+
+    async fn get_filtered_events(
+        &self,
+        filter: &EventsFilter,
+    ) -> Result<EventsPage, GetEventsRepositoryError> {
+        let db_connection = self.db_connection.clone();
+        let events = bind_filter(
+            sqlx::query("CALL get_filtered_events(?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?);"),
+            filter,
+        )
+        .bind(filter.page_size)
+        .bind(filter.offset())
+        .map(map_event_row)
+        .fetch_all(&*db_connection)
+        .await
+        .map_err(|err| {
+            tracing::error!("failed to fetch filtered events from database: {}", err);
+            GetEventsRepositoryError::UnableToGetEvents
+        })?;
+        let total_count: i64 = bind_filter(
+            sqlx::query("CALL count_filtered_events(?, ?, ?, ?, ?, ?, ?, ?, ?);"),
+            filter,
+        )
+        .map(|row: MySqlRow| row.get(0))
+        .fetch_one(&*db_connection)
+        .await
+        .map_err(|err| {
+            tracing::error!("failed to count filtered events in database: {}", err);
+            GetEventsRepositoryError::UnableToGetEvents
+        })?;
+
+        Ok(EventsPage {
+            events: events.into_iter().map(EventDTO::from).collect(),
+            total_count: total_count as u64,
+        })
+    }
+}
+
+pub fn map_event_row(row: MySqlRow) -> Event {
+    Event {
+        id: row.get(0),
+        title: row.get(1),
+        description: row.get(2),
+        date: row.get(3),
+        r#type: row.get(4),
+        recurrence: row.get(5),
+        recurrence_end_date: row.get(6),
+        recurrence_count: row.get(7),
+        status: row.get(8),
+        publish_at: row.get(16),
+        minimum_age: row.get(9),
+        maximum_age: row.get(10),
+        capacity: row.get(15),
+        room_id: row.get(17),
+        duration_minutes: row.get(18),
+        speaker_ids: row.get(19),
+        category_id: row.get(25),
+        tags: row.get(26),
+        image_url: row.get(11),
+        full_name: row.get(12),
+        phone_number: row.get(13),
+        email: row.get(14),
+        show_full_name: row.get(21),
+        show_phone_number: row.get(22),
+        show_email: row.get(23),
+        contact_form_enabled: row.get(24),
+        version: row.get(20),
+    }
+}
+
+fn bind_filter<'q>(
+    query: Query<'q, MySql, MySqlArguments>,
+    filter: &'q EventsFilter,
+) -> Query<'q, MySql, MySqlArguments> {
+    query
+        .bind(filter.from)
+        .bind(filter.to)
+        .bind(filter.event_type.as_ref().map(ToString::to_string))
+        .bind(filter.event_status.as_ref().map(ToString::to_string))
+        .bind(filter.minimum_age)
+        .bind(filter.maximum_age)
+        .bind(filter.category_id)
+        .bind(filter.tag.as_deref())
+        .bind(filter.visibility == EventVisibility::Published)
 }
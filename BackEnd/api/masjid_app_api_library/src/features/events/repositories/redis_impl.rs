@@ -1,13 +1,275 @@
+use crate::features::events::models::event_category::EventCategory;
 use crate::features::events::models::event_dto::EventDTO;
-use crate::features::events::repositories::EventsRepository;
+use crate::features::events::models::event_exception_dto::EventExceptionDTO;
+use crate::features::events::models::event_status::EventStatus;
+use crate::features::events::models::event_visibility::EventVisibility;
+use crate::features::events::models::events_filter::EventsFilter;
+use crate::features::events::models::events_page::EventsPage;
+use crate::features::events::models::room::Room;
+use crate::features::events::models::speaker::Speaker;
+use crate::features::events::repositories::errors::cache_events_error::CacheEventsError;
 use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use crate::features::events::repositories::{EventsCacheRepository, EventsRepository};
 use crate::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use crate::shared::types::image_variants::ImageVariants;
 use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, Pipeline, RedisError};
+use std::str::FromStr;
+
+// Events are held in a hash each, with their ids in a sorted set scored by their date, so that a
+// range of dates can be read without reading every event
+const EVENTS_BY_DATE_KEY: &str = "events:by_date";
+/// Only set once the cache holds every event
+const EVENTS_WARMED_KEY: &str = "events:warmed";
+/// Kept alongside the event, so that unpublished events can be skipped without deserialising them
+const STATUS_FIELD: &str = "status";
+const EVENT_FIELD: &str = "event";
 
 #[async_trait]
 impl EventsRepository for InMemoryRepository {
-    async fn get_events(&self) -> Result<Vec<EventDTO>, GetEventsRepositoryError> {
-        tracing::warn!("In-memory database for getting events not implemented");
+    async fn get_events(
+        &self,
+        visibility: EventVisibility,
+    ) -> Result<Vec<EventDTO>, GetEventsRepositoryError> {
+        let mut db_connection = self.db_connection.clone();
+        check_events_cache_warmed(&mut db_connection).await?;
+        let event_ids: Vec<i32> = db_connection
+            .zrange(EVENTS_BY_DATE_KEY, 0, -1)
+            .await
+            .map_err(map_get_events_error)?;
+        let events = read_events(&mut db_connection, &event_ids, visibility).await?;
+        if events.is_empty() {
+            return Err(GetEventsRepositoryError::EventsNotFound);
+        }
+        Ok(events)
+    }
+
+    async fn get_event_exceptions(
+        &self,
+    ) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError> {
+        tracing::warn!("In-memory database for getting event exceptions not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsRepositoryError> {
+        tracing::warn!("In-memory database for getting rooms not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsRepositoryError> {
+        tracing::warn!("In-memory database for getting speakers not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsRepositoryError> {
+        tracing::warn!("In-memory database for getting event categories not implemented");
         Err(GetEventsRepositoryError::UnableToGetEvents)
     }
+
+    async fn get_tags(
+        &self,
+        visibility: EventVisibility,
+    ) -> Result<Vec<String>, GetEventsRepositoryError> {
+        tracing::warn!("In-memory database for getting event tags not implemented");
+        Err(GetEventsRepositoryError::UnableToGetEvents)
+    }
+
+    async fn get_filtered_events(
+        &self,
+        filter: &EventsFilter,
+    ) -> Result<EventsPage, GetEventsRepositoryError> {
+        let mut db_connection = self.db_connection.clone();
+        check_events_cache_warmed(&mut db_connection).await?;
+        // Recurring series anchored before the window may still be running during it, so only the
+        // end of the window narrows the range which is read
+        let maximum_score = match filter.to {
+            Some(to) => format!("({}", to.timestamp_millis()),
+            None => "+inf".to_owned(),
+        };
+        let event_ids: Vec<i32> = db_connection
+            .zrangebyscore(EVENTS_BY_DATE_KEY, "-inf", maximum_score)
+            .await
+            .map_err(map_get_events_error)?;
+        let mut events: Vec<EventDTO> =
+            read_events(&mut db_connection, &event_ids, filter.visibility)
+                .await?
+                .into_iter()
+                .filter(|event| filter.matches(event))
+                .collect();
+        // Members sharing a score are ordered as text, so events on the same date are sorted again
+        // by their id as a number
+        events.sort_by_key(|event| (event.date, event.id));
+        let total_count = events.len() as u64;
+        Ok(EventsPage {
+            events: events
+                .into_iter()
+                .skip(filter.offset() as usize)
+                .take(filter.page_size as usize)
+                .collect(),
+            total_count,
+        })
+    }
+}
+
+#[async_trait]
+impl EventsCacheRepository for InMemoryRepository {
+    async fn replace_events(&self, events: &[EventDTO]) -> Result<(), CacheEventsError> {
+        let mut db_connection = self.db_connection.clone();
+        let cached_event_ids: Vec<i32> = db_connection
+            .zrange(EVENTS_BY_DATE_KEY, 0, -1)
+            .await
+            .map_err(map_cache_events_error)?;
+        // Applied as a single transaction, so reads see either the old events or the new ones
+        let mut pipeline = redis::pipe();
+        pipeline.atomic();
+        for event_id in cached_event_ids {
+            pipeline.del(event_key(event_id)).ignore();
+        }
+        pipeline.del(EVENTS_BY_DATE_KEY).ignore();
+        add_events(&mut pipeline, events)?;
+        pipeline.set(EVENTS_WARMED_KEY, 1).ignore();
+        pipeline
+            .query_async::<()>(&mut db_connection)
+            .await
+            .map_err(map_cache_events_error)
+    }
+
+    async fn cache_events(&self, events: &[EventDTO]) -> Result<(), CacheEventsError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let mut db_connection = self.db_connection.clone();
+        let mut pipeline = redis::pipe();
+        pipeline.atomic();
+        let result = match add_events(&mut pipeline, events) {
+            Ok(()) => pipeline
+                .query_async::<()>(&mut db_connection)
+                .await
+                .map_err(map_cache_events_error),
+            Err(err) => Err(err),
+        };
+        if result.is_err() {
+            invalidate_events_cache(&mut db_connection).await;
+        }
+        result
+    }
+
+    async fn evict_event(&self, event_id: &i32) -> Result<(), CacheEventsError> {
+        let mut db_connection = self.db_connection.clone();
+        let result = redis::pipe()
+            .atomic()
+            .del(event_key(*event_id))
+            .ignore()
+            .zrem(EVENTS_BY_DATE_KEY, *event_id)
+            .ignore()
+            .query_async::<()>(&mut db_connection)
+            .await
+            .map_err(map_cache_events_error);
+        if result.is_err() {
+            invalidate_events_cache(&mut db_connection).await;
+        }
+        result
+    }
+}
+
+#[inline]
+fn event_key(event_id: i32) -> String {
+    format!("events:{event_id}")
+}
+
+/// Stops reads being served from a cache which has missed a change, so that events are read from
+/// the database until the cache is warmed again
+async fn invalidate_events_cache(db_connection: &mut ConnectionManager) {
+    if let Err(err) = db_connection.del::<_, ()>(EVENTS_WARMED_KEY).await {
+        tracing::error!("failed to invalidate events cache: {}", err);
+    }
+}
+
+async fn check_events_cache_warmed(
+    db_connection: &mut ConnectionManager,
+) -> Result<(), GetEventsRepositoryError> {
+    let warmed: bool = db_connection
+        .exists(EVENTS_WARMED_KEY)
+        .await
+        .map_err(map_get_events_error)?;
+    if !warmed {
+        tracing::debug!("events cache has not been warmed");
+        return Err(GetEventsRepositoryError::UnableToGetEvents);
+    }
+    Ok(())
+}
+
+/// Keeps the order of `event_ids`, skipping events hidden by the visibility before they are
+/// deserialised
+async fn read_events(
+    db_connection: &mut ConnectionManager,
+    event_ids: &[i32],
+    visibility: EventVisibility,
+) -> Result<Vec<EventDTO>, GetEventsRepositoryError> {
+    if event_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut pipeline = redis::pipe();
+    for event_id in event_ids {
+        pipeline.hget(event_key(*event_id), &[STATUS_FIELD, EVENT_FIELD]);
+    }
+    let cached_events: Vec<(Option<String>, Option<String>)> = pipeline
+        .query_async(db_connection)
+        .await
+        .map_err(map_get_events_error)?;
+    let mut events = Vec::with_capacity(cached_events.len());
+    for (status, event) in cached_events {
+        let (Some(status), Some(event)) = (status, event) else {
+            continue;
+        };
+        if visibility == EventVisibility::Published
+            && !EventStatus::from_str(&status).is_ok_and(|status| status.is_published())
+        {
+            continue;
+        }
+        let mut event: EventDTO = serde_json::from_str(&event).map_err(|err| {
+            tracing::error!("failed to deserialise cached event: {}", err);
+            GetEventsRepositoryError::UnableToGetEvents
+        })?;
+        // Image variants are never deserialised, as they are derived from the image url
+        event.event_details.image_variants = event
+            .event_details
+            .image_url
+            .as_deref()
+            .map(ImageVariants::from_image_url);
+        events.push(event);
+    }
+    Ok(events)
+}
+
+fn add_events(pipeline: &mut Pipeline, events: &[EventDTO]) -> Result<(), CacheEventsError> {
+    for event in events {
+        let serialised_event = serde_json::to_string(event).map_err(|err| {
+            tracing::error!("failed to serialise event for caching: {}", err);
+            CacheEventsError::UnableToCacheEvents
+        })?;
+        pipeline
+            .hset_multiple(
+                event_key(event.id),
+                &[
+                    (STATUS_FIELD, event.event_details.event_status.to_string()),
+                    (EVENT_FIELD, serialised_event),
+                ],
+            )
+            .ignore()
+            .zadd(EVENTS_BY_DATE_KEY, event.id, event.date.timestamp_millis())
+            .ignore();
+    }
+    Ok(())
+}
+
+fn map_get_events_error(err: RedisError) -> GetEventsRepositoryError {
+    tracing::error!("failed to fetch events from in-memory database: {}", err);
+    GetEventsRepositoryError::UnableToGetEvents
+}
+
+fn map_cache_events_error(err: RedisError) -> CacheEventsError {
+    tracing::error!("failed to cache events in in-memory database: {}", err);
+    CacheEventsError::UnableToCacheEvents
 }
@@ -0,0 +1,13 @@
+use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+
+pub enum GetEventOccurrencesServiceError {
+    InvalidWindow,
+    UnableToGetEventsFromRepository(GetEventsRepositoryError),
+}
+
+impl From<GetEventsRepositoryError> for GetEventOccurrencesServiceError {
+    #[inline]
+    fn from(value: GetEventsRepositoryError) -> Self {
+        Self::UnableToGetEventsFromRepository(value)
+    }
+}
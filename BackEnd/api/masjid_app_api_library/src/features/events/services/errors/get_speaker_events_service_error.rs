@@ -0,0 +1,14 @@
+use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+
+#[derive(Debug, PartialEq)]
+pub enum GetSpeakerEventsServiceError {
+    SpeakerNotFound,
+    UnableToGetEventsFromRepository(GetEventsRepositoryError),
+}
+
+impl From<GetEventsRepositoryError> for GetSpeakerEventsServiceError {
+    #[inline]
+    fn from(value: GetEventsRepositoryError) -> Self {
+        Self::UnableToGetEventsFromRepository(value)
+    }
+}
@@ -1 +1,4 @@
+pub mod get_event_occurrences_service_error;
 pub mod get_events_service_error;
+pub mod get_speaker_events_service_error;
+pub mod warm_events_cache_error;
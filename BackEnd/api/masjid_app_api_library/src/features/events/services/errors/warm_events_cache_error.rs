@@ -0,0 +1,22 @@
+use crate::features::events::repositories::errors::cache_events_error::CacheEventsError;
+use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+
+#[derive(Debug, PartialEq)]
+pub enum WarmEventsCacheError {
+    UnableToGetEvents(GetEventsRepositoryError),
+    UnableToCacheEvents(CacheEventsError),
+}
+
+impl From<GetEventsRepositoryError> for WarmEventsCacheError {
+    #[inline]
+    fn from(value: GetEventsRepositoryError) -> Self {
+        Self::UnableToGetEvents(value)
+    }
+}
+
+impl From<CacheEventsError> for WarmEventsCacheError {
+    #[inline]
+    fn from(value: CacheEventsError) -> Self {
+        Self::UnableToCacheEvents(value)
+    }
+}
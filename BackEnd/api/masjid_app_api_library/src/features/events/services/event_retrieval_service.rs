@@ -1,38 +1,275 @@
+use crate::features::events::models::event_category::EventCategory;
 use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_exception_dto::EventExceptionDTO;
+use crate::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use crate::features::events::models::event_visibility::EventVisibility;
+use crate::features::events::models::events_calendar::EventsCalendar;
+use crate::features::events::models::events_filter::EventsFilter;
+use crate::features::events::models::events_page::EventsPage;
+use crate::features::events::models::get_events_calendar_request::GetEventsCalendarRequest;
+use crate::features::events::models::room::Room;
+use crate::features::events::models::speaker::Speaker;
 use crate::features::events::repositories::EventsRepository;
+use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use crate::features::events::services::errors::get_event_occurrences_service_error::GetEventOccurrencesServiceError;
 use crate::features::events::services::errors::get_events_service_error::GetEventsServiceError;
+use crate::features::events::services::errors::get_speaker_events_service_error::GetSpeakerEventsServiceError;
 use crate::features::events::services::event_service_impl::EventServiceImpl;
-use crate::new_event_service;
+use crate::features::events::services::occurrence_expansion::expand_occurrences;
 use crate::shared::common_service_impl::CommonServiceImpl;
 use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
 use mockall::automock;
 use std::sync::Arc;
 
+// Keeps the number of occurrences generated for daily events within reason
+const MAXIMUM_OCCURRENCE_WINDOW_IN_DAYS: i64 = 366;
+/// How far ahead a speaker's upcoming events are listed
+const SPEAKER_EVENTS_WINDOW_IN_DAYS: i64 = 90;
+
 #[automock]
 #[async_trait]
 pub trait EventRetrievalService: Send + Sync {
     async fn get_events(&self) -> Result<Vec<EventDTO>, GetEventsServiceError>;
+    async fn get_events_page(
+        &self,
+        filter: EventsFilter,
+    ) -> Result<EventsPage, GetEventsServiceError>;
+    /// Expands every event into the occurrences which fall between `from` (inclusive) and `to`
+    /// (exclusive), ordered by date
+    async fn get_event_occurrences(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<EventOccurrenceDTO>, GetEventOccurrencesServiceError>;
+    async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsServiceError>;
+    async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsServiceError>;
+    async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsServiceError>;
+    async fn get_tags(&self) -> Result<Vec<String>, GetEventsServiceError>;
+    /// The occurrences of events led by the speaker over the coming weeks from `from`, ordered by
+    /// date
+    async fn get_speaker_events(
+        &self,
+        speaker_id: i32,
+        from: DateTime<Utc>,
+    ) -> Result<Vec<EventOccurrenceDTO>, GetSpeakerEventsServiceError>;
+    /// The events matching the request along with their exceptions, so that they can be exported
+    /// with their recurrence intact. Having no events is not an error, as subscribers should
+    /// receive an empty calendar
+    async fn get_events_calendar(
+        &self,
+        request: GetEventsCalendarRequest,
+    ) -> Result<EventsCalendar, GetEventsServiceError>;
 }
 
-new_event_service!(
-    new_event_retrieval_service,
-    EventRetrievalService,
-    EventsRepository
-);
+/// The public API only ever retrieves published events, whereas administrators see them all
+pub fn new_event_retrieval_service(
+    repository: Arc<dyn EventsRepository>,
+    in_memory_repository: Arc<dyn EventsRepository>,
+    visibility: EventVisibility,
+) -> Arc<dyn EventRetrievalService> {
+    Arc::new(EventServiceImpl {
+        common: CommonServiceImpl::<dyn EventsRepository> {
+            repository,
+            in_memory_repository,
+        },
+        visibility,
+    })
+}
 
 #[async_trait]
 impl EventRetrievalService for EventServiceImpl<dyn EventsRepository> {
     async fn get_events(&self) -> Result<Vec<EventDTO>, GetEventsServiceError> {
-        if let Ok(events) = self.common.in_memory_repository.get_events().await {
+        if let Ok(events) = self
+            .common
+            .in_memory_repository
+            .get_events(self.visibility)
+            .await
+        {
             Ok(events)
         } else {
             self.common
                 .repository
-                .get_events()
+                .get_events(self.visibility)
+                .await
+                .map_err(GetEventsServiceError::from)
+        }
+    }
+
+    async fn get_events_page(
+        &self,
+        filter: EventsFilter,
+    ) -> Result<EventsPage, GetEventsServiceError> {
+        let filter = EventsFilter {
+            visibility: self.visibility,
+            ..filter
+        };
+        if let Ok(events_page) = self
+            .common
+            .in_memory_repository
+            .get_filtered_events(&filter)
+            .await
+        {
+            Ok(events_page)
+        } else {
+            self.common
+                .repository
+                .get_filtered_events(&filter)
+                .await
+                .map_err(GetEventsServiceError::from)
+        }
+    }
+
+    async fn get_event_occurrences(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<EventOccurrenceDTO>, GetEventOccurrencesServiceError> {
+        if to <= from || to - from > TimeDelta::days(MAXIMUM_OCCURRENCE_WINDOW_IN_DAYS) {
+            return Err(GetEventOccurrencesServiceError::InvalidWindow);
+        }
+        let events = match self.get_events().await {
+            Ok(events) => events,
+            Err(GetEventsServiceError::UnableToGetEventsFromRepository(
+                GetEventsRepositoryError::EventsNotFound,
+            )) => return Ok(Vec::new()),
+            Err(GetEventsServiceError::UnableToGetEventsFromRepository(err)) => {
+                return Err(GetEventOccurrencesServiceError::from(err));
+            }
+        };
+        let exceptions = self.get_event_exceptions().await?;
+        let mut occurrences: Vec<EventOccurrenceDTO> = events
+            .iter()
+            .flat_map(|event| expand_occurrences(event, &exceptions, from, to))
+            .collect();
+        occurrences.sort_by_key(|occurrence| occurrence.occurrence_date);
+        Ok(occurrences)
+    }
+
+    async fn get_rooms(&self) -> Result<Vec<Room>, GetEventsServiceError> {
+        if let Ok(rooms) = self.common.in_memory_repository.get_rooms().await {
+            Ok(rooms)
+        } else {
+            self.common
+                .repository
+                .get_rooms()
+                .await
+                .map_err(GetEventsServiceError::from)
+        }
+    }
+
+    async fn get_speakers(&self) -> Result<Vec<Speaker>, GetEventsServiceError> {
+        if let Ok(speakers) = self.common.in_memory_repository.get_speakers().await {
+            Ok(speakers)
+        } else {
+            self.common
+                .repository
+                .get_speakers()
                 .await
                 .map_err(GetEventsServiceError::from)
         }
     }
+
+    async fn get_categories(&self) -> Result<Vec<EventCategory>, GetEventsServiceError> {
+        if let Ok(categories) = self.common.in_memory_repository.get_categories().await {
+            Ok(categories)
+        } else {
+            self.common
+                .repository
+                .get_categories()
+                .await
+                .map_err(GetEventsServiceError::from)
+        }
+    }
+
+    async fn get_tags(&self) -> Result<Vec<String>, GetEventsServiceError> {
+        if let Ok(tags) = self
+            .common
+            .in_memory_repository
+            .get_tags(self.visibility)
+            .await
+        {
+            Ok(tags)
+        } else {
+            self.common
+                .repository
+                .get_tags(self.visibility)
+                .await
+                .map_err(GetEventsServiceError::from)
+        }
+    }
+
+    async fn get_speaker_events(
+        &self,
+        speaker_id: i32,
+        from: DateTime<Utc>,
+    ) -> Result<Vec<EventOccurrenceDTO>, GetSpeakerEventsServiceError> {
+        let speakers = self
+            .get_speakers()
+            .await
+            .map_err(|GetEventsServiceError::UnableToGetEventsFromRepository(err)| err)?;
+        if !speakers.iter().any(|speaker| speaker.id == speaker_id) {
+            return Err(GetSpeakerEventsServiceError::SpeakerNotFound);
+        }
+        let events = match self.get_events().await {
+            Ok(events) => events,
+            Err(GetEventsServiceError::UnableToGetEventsFromRepository(
+                GetEventsRepositoryError::EventsNotFound,
+            )) => return Ok(Vec::new()),
+            Err(GetEventsServiceError::UnableToGetEventsFromRepository(err)) => {
+                return Err(GetSpeakerEventsServiceError::from(err));
+            }
+        };
+        let exceptions = self.get_event_exceptions().await?;
+        let to = from + TimeDelta::days(SPEAKER_EVENTS_WINDOW_IN_DAYS);
+        let mut occurrences: Vec<EventOccurrenceDTO> = events
+            .iter()
+            .filter(|event| event.event_details.speaker_ids.contains(&speaker_id))
+            .flat_map(|event| expand_occurrences(event, &exceptions, from, to))
+            .collect();
+        occurrences.sort_by_key(|occurrence| occurrence.occurrence_date);
+        Ok(occurrences)
+    }
+
+    async fn get_events_calendar(
+        &self,
+        request: GetEventsCalendarRequest,
+    ) -> Result<EventsCalendar, GetEventsServiceError> {
+        let events: Vec<EventDTO> = match self.get_events().await {
+            Ok(events) => events
+                .into_iter()
+                .filter(|event| request.matches(event))
+                .collect(),
+            Err(GetEventsServiceError::UnableToGetEventsFromRepository(
+                GetEventsRepositoryError::EventsNotFound,
+            )) => return Ok(EventsCalendar::default()),
+            Err(err) => return Err(err),
+        };
+        let exceptions = self
+            .get_event_exceptions()
+            .await?
+            .into_iter()
+            .filter(|exception| events.iter().any(|event| event.id == exception.event_id))
+            .collect();
+        Ok(EventsCalendar { events, exceptions })
+    }
+}
+
+impl EventServiceImpl<dyn EventsRepository> {
+    async fn get_event_exceptions(
+        &self,
+    ) -> Result<Vec<EventExceptionDTO>, GetEventsRepositoryError> {
+        if let Ok(exceptions) = self
+            .common
+            .in_memory_repository
+            .get_event_exceptions()
+            .await
+        {
+            Ok(exceptions)
+        } else {
+            self.common.repository.get_event_exceptions().await
+        }
+    }
 }
 
 #[cfg(test)]
@@ -42,9 +279,11 @@ mod tests {
     use crate::features::events::models::event_recurrence::EventRecurrence;
     use crate::features::events::models::event_status::EventStatus;
     use crate::features::events::models::event_type::EventType;
+    use crate::features::events::models::get_events_request::GetEventsRequest;
     use crate::features::events::repositories::MockEventsRepository;
-    use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+    use crate::shared::types::age_range::AgeRange;
     use crate::shared::types::contact_details::ContactDetails;
+    use crate::shared::types::contact_visibility::ContactVisibility;
     use chrono::DateTime;
 
     #[tokio::test]
@@ -63,16 +302,27 @@ mod tests {
             event_details: EventDetails {
                 event_type: EventType::Talk,
                 event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
                 event_status: EventStatus::Confirmed,
+                publish_at: None,
                 age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
                 image_url: None,
+                image_variants: None,
                 contact_details: ContactDetails {
                     full_name: "Zayd McArdle".to_owned(),
                     title: None,
                     phone_number: "07123456789".to_string(),
                     email: None,
+                    visibility: ContactVisibility::default(),
                 },
             },
+            version: 1,
         }];
         let test_cases = [
             TestCase {
@@ -111,19 +361,484 @@ mod tests {
 
             mock_in_memory_repository
                 .expect_get_events()
-                .return_once(move || test_case.expected_in_memory_db_response);
+                .return_once(move |_| test_case.expected_in_memory_db_response);
             if let Some(expected_db_response) = test_case.expected_db_response {
                 mock_repository
                     .expect_get_events()
-                    .return_once(move || expected_db_response);
+                    .return_once(move |_| expected_db_response);
             }
 
             let service = new_event_retrieval_service(
                 Arc::new(mock_repository),
                 Arc::new(mock_in_memory_repository),
+                EventVisibility::Published,
             );
             let actual_result = service.get_events().await;
             assert!(matches!(test_case.expected_result, actual_result));
         }
     }
+
+    #[tokio::test]
+    async fn test_event_retrieval_service_get_events_page() {
+        struct TestCase {
+            description: &'static str,
+            expected_in_memory_db_response: Result<EventsPage, GetEventsRepositoryError>,
+            expected_db_response: Option<Result<EventsPage, GetEventsRepositoryError>>,
+            expected_result: Result<EventsPage, GetEventsRepositoryError>,
+        }
+        let events_page = EventsPage {
+            events: Vec::new(),
+            total_count: 30,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When retrieving events fails on in-memory repository and main repository, I should get an error",
+                expected_in_memory_db_response: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_db_response: Some(Err(GetEventsRepositoryError::UnableToGetEvents)),
+                expected_result: Err(GetEventsRepositoryError::UnableToGetEvents),
+            },
+            TestCase {
+                description: "When retrieving events fails on in-memory repository but succeeds on main repository, I should get the page",
+                expected_in_memory_db_response: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_db_response: Some(Ok(events_page.clone())),
+                expected_result: Ok(events_page.clone()),
+            },
+            TestCase {
+                description: "When the page is found in in-memory repository, I should get the page",
+                expected_in_memory_db_response: Ok(events_page.clone()),
+                expected_db_response: None,
+                expected_result: Ok(events_page),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventsRepository::new();
+            let mut mock_in_memory_repository = MockEventsRepository::new();
+
+            mock_in_memory_repository
+                .expect_get_filtered_events()
+                .return_once(move |_| test_case.expected_in_memory_db_response);
+            if let Some(expected_db_response) = test_case.expected_db_response {
+                mock_repository
+                    .expect_get_filtered_events()
+                    .withf(|filter| filter.visibility == EventVisibility::All)
+                    .return_once(move |_| expected_db_response);
+            }
+
+            // Requests default to published events, so this checks the service's own visibility wins
+            let service = new_event_retrieval_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+                EventVisibility::All,
+            );
+            let actual_result = service
+                .get_events_page(EventsFilter::from(GetEventsRequest::default()))
+                .await
+                .map_err(|GetEventsServiceError::UnableToGetEventsFromRepository(err)| err);
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_retrieval_service_get_event_occurrences() {
+        struct TestCase {
+            description: &'static str,
+            from: &'static str,
+            to: &'static str,
+            expected_db_response: Option<Result<Vec<EventDTO>, GetEventsRepositoryError>>,
+            expected_db_exceptions_response:
+                Option<Result<Vec<EventExceptionDTO>, GetEventsRepositoryError>>,
+            expected_result: Result<usize, GetEventOccurrencesServiceError>,
+        }
+        let events = vec![EventDTO {
+            id: 1,
+            title: "weekly halaqa".to_owned(),
+            description: None,
+            date: "2025-01-06T19:00:00Z".parse().unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::Weekly,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_string(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }];
+        let test_cases = [
+            TestCase {
+                description: "When the window ends before it starts, I should get an invalid window error",
+                from: "2025-02-01T00:00:00Z",
+                to: "2025-01-01T00:00:00Z",
+                expected_db_response: None,
+                expected_db_exceptions_response: None,
+                expected_result: Err(GetEventOccurrencesServiceError::InvalidWindow),
+            },
+            TestCase {
+                description: "When the window is longer than the maximum, I should get an invalid window error",
+                from: "2025-01-01T00:00:00Z",
+                to: "2027-01-01T00:00:00Z",
+                expected_db_response: None,
+                expected_db_exceptions_response: None,
+                expected_result: Err(GetEventOccurrencesServiceError::InvalidWindow),
+            },
+            TestCase {
+                description: "When retrieving events fails, I should get an error",
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_db_response: Some(Err(GetEventsRepositoryError::UnableToGetEvents)),
+                expected_db_exceptions_response: None,
+                expected_result: Err(
+                    GetEventOccurrencesServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                ),
+            },
+            TestCase {
+                description: "When no events exist, I should get no occurrences",
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_db_response: Some(Err(GetEventsRepositoryError::EventsNotFound)),
+                expected_db_exceptions_response: None,
+                expected_result: Ok(0),
+            },
+            TestCase {
+                description: "When retrieving exceptions fails, I should get an error",
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_db_response: Some(Ok(events.clone())),
+                expected_db_exceptions_response: Some(Err(
+                    GetEventsRepositoryError::UnableToGetEvents,
+                )),
+                expected_result: Err(
+                    GetEventOccurrencesServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                ),
+            },
+            TestCase {
+                description: "When a weekly event has a cancelled occurrence, I should get the remaining occurrences",
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_db_response: Some(Ok(events)),
+                expected_db_exceptions_response: Some(Ok(vec![EventExceptionDTO {
+                    event_id: 1,
+                    occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+                    replacement_date: None,
+                }])),
+                expected_result: Ok(3),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventsRepository::new();
+            let mut mock_in_memory_repository = MockEventsRepository::new();
+            if let Some(expected_db_response) = test_case.expected_db_response {
+                mock_in_memory_repository
+                    .expect_get_events()
+                    .return_once(|_| Err(GetEventsRepositoryError::UnableToGetEvents));
+                mock_repository
+                    .expect_get_events()
+                    .return_once(move |_| expected_db_response);
+            }
+            if let Some(expected_db_exceptions_response) = test_case.expected_db_exceptions_response
+            {
+                mock_in_memory_repository
+                    .expect_get_event_exceptions()
+                    .return_once(|| Err(GetEventsRepositoryError::UnableToGetEvents));
+                mock_repository
+                    .expect_get_event_exceptions()
+                    .return_once(move || expected_db_exceptions_response);
+            }
+
+            let service = new_event_retrieval_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+                EventVisibility::Published,
+            );
+            let actual_result = service
+                .get_event_occurrences(
+                    test_case.from.parse().unwrap(),
+                    test_case.to.parse().unwrap(),
+                )
+                .await
+                .map(|occurrences| occurrences.len());
+            match (test_case.expected_result, actual_result) {
+                (Ok(expected_count), Ok(actual_count)) => assert_eq!(expected_count, actual_count),
+                (
+                    Err(GetEventOccurrencesServiceError::InvalidWindow),
+                    Err(GetEventOccurrencesServiceError::InvalidWindow),
+                ) => {}
+                (
+                    Err(GetEventOccurrencesServiceError::UnableToGetEventsFromRepository(expected)),
+                    Err(GetEventOccurrencesServiceError::UnableToGetEventsFromRepository(actual)),
+                ) => assert_eq!(expected, actual),
+                _ => panic!("unexpected result"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_retrieval_service_get_speaker_events() {
+        struct TestCase {
+            description: &'static str,
+            speaker_id: i32,
+            expected_db_events_response: Option<Result<Vec<EventDTO>, GetEventsRepositoryError>>,
+            expected_result: Result<Vec<i32>, GetSpeakerEventsServiceError>,
+        }
+        let speakers = vec![Speaker {
+            id: 1,
+            full_name: "Zayd McArdle".to_owned(),
+            title: Some("Ustadh".to_owned()),
+            bio: None,
+            photo_url: None,
+            languages: vec!["English".to_owned(), "Arabic".to_owned()],
+        }];
+        let create_event = |id: i32, date: &str, speaker_ids: Vec<i32>| EventDTO {
+            id,
+            title: format!("event {}", id),
+            description: None,
+            date: date.parse().unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids,
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_string(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the speaker does not exist, I should get a speaker not found error",
+                speaker_id: 2,
+                expected_db_events_response: None,
+                expected_result: Err(GetSpeakerEventsServiceError::SpeakerNotFound),
+            },
+            TestCase {
+                description: "When no events exist, I should get no occurrences",
+                speaker_id: 1,
+                expected_db_events_response: Some(Err(GetEventsRepositoryError::EventsNotFound)),
+                expected_result: Ok(Vec::new()),
+            },
+            TestCase {
+                description: "When retrieving events fails, I should get an error",
+                speaker_id: 1,
+                expected_db_events_response: Some(Err(GetEventsRepositoryError::UnableToGetEvents)),
+                expected_result: Err(
+                    GetSpeakerEventsServiceError::UnableToGetEventsFromRepository(
+                        GetEventsRepositoryError::UnableToGetEvents,
+                    ),
+                ),
+            },
+            TestCase {
+                description: "When the speaker leads upcoming events, I should only get those events in date order",
+                speaker_id: 1,
+                expected_db_events_response: Some(Ok(vec![
+                    create_event(1, "2025-02-10T19:00:00Z", vec![1, 2]),
+                    create_event(2, "2025-01-20T19:00:00Z", vec![1]),
+                    create_event(3, "2025-01-21T19:00:00Z", vec![2]),
+                    create_event(4, "2024-12-01T19:00:00Z", vec![1]),
+                    create_event(5, "2025-06-01T19:00:00Z", vec![1]),
+                ])),
+                expected_result: Ok(vec![2, 1]),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventsRepository::new();
+            let mut mock_in_memory_repository = MockEventsRepository::new();
+            let speakers = speakers.clone();
+            mock_in_memory_repository
+                .expect_get_speakers()
+                .return_once(move || Ok(speakers));
+            if let Some(expected_db_events_response) = test_case.expected_db_events_response {
+                mock_in_memory_repository
+                    .expect_get_events()
+                    .return_once(|_| Err(GetEventsRepositoryError::UnableToGetEvents));
+                mock_repository
+                    .expect_get_events()
+                    .return_once(move |_| expected_db_events_response);
+                mock_in_memory_repository
+                    .expect_get_event_exceptions()
+                    .return_once(|| Ok(Vec::new()));
+            }
+
+            let service = new_event_retrieval_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+                EventVisibility::Published,
+            );
+            let actual_result = service
+                .get_speaker_events(
+                    test_case.speaker_id,
+                    "2025-01-01T00:00:00Z".parse().unwrap(),
+                )
+                .await
+                .map(|occurrences| {
+                    occurrences
+                        .into_iter()
+                        .map(|occurrence| occurrence.event.id)
+                        .collect::<Vec<i32>>()
+                });
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_retrieval_service_get_events_calendar() {
+        struct TestCase {
+            description: &'static str,
+            request: GetEventsCalendarRequest,
+            expected_db_events_response: Result<Vec<EventDTO>, GetEventsRepositoryError>,
+            expected_result: Result<(Vec<i32>, usize), GetEventsRepositoryError>,
+        }
+        let create_event = |id: i32, event_type: EventType, age_range: Option<AgeRange>| EventDTO {
+            id,
+            title: format!("event {}", id),
+            description: None,
+            date: "2025-01-06T19:00:00Z".parse().unwrap(),
+            event_details: EventDetails {
+                event_type,
+                event_recurrence: EventRecurrence::Weekly,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_string(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        };
+        let events = vec![
+            create_event(1, EventType::Class, None),
+            create_event(
+                2,
+                EventType::Class,
+                Some(AgeRange {
+                    minimum_age: 7,
+                    maximum_age: 11,
+                }),
+            ),
+            create_event(3, EventType::Talk, None),
+        ];
+        let test_cases = [
+            TestCase {
+                description: "When no events exist, I should get an empty calendar",
+                request: GetEventsCalendarRequest::default(),
+                expected_db_events_response: Err(GetEventsRepositoryError::EventsNotFound),
+                expected_result: Ok((Vec::new(), 0)),
+            },
+            TestCase {
+                description: "When retrieving events fails, I should get an error",
+                request: GetEventsCalendarRequest::default(),
+                expected_db_events_response: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_result: Err(GetEventsRepositoryError::UnableToGetEvents),
+            },
+            TestCase {
+                description: "When no filter is given, I should get every event and its exceptions",
+                request: GetEventsCalendarRequest::default(),
+                expected_db_events_response: Ok(events.clone()),
+                expected_result: Ok((vec![1, 2, 3], 2)),
+            },
+            TestCase {
+                description: "When filtering by type and age, I should only get matching events and their exceptions",
+                request: GetEventsCalendarRequest {
+                    r#type: Some(EventType::Class),
+                    age_min: Some(12),
+                    age_max: None,
+                },
+                expected_db_events_response: Ok(events),
+                expected_result: Ok((vec![1], 1)),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventsRepository::new();
+            let mut mock_in_memory_repository = MockEventsRepository::new();
+            mock_in_memory_repository
+                .expect_get_events()
+                .return_once(|_| Err(GetEventsRepositoryError::UnableToGetEvents));
+            mock_repository
+                .expect_get_events()
+                .return_once(move |_| test_case.expected_db_events_response);
+            mock_in_memory_repository
+                .expect_get_event_exceptions()
+                .return_once(|| {
+                    Ok([1, 2, 4]
+                        .into_iter()
+                        .map(|event_id| EventExceptionDTO {
+                            event_id,
+                            occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+                            replacement_date: None,
+                        })
+                        .collect())
+                });
+
+            let service = new_event_retrieval_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_in_memory_repository),
+                EventVisibility::Published,
+            );
+            let actual_result = service
+                .get_events_calendar(test_case.request)
+                .await
+                .map(|calendar| {
+                    (
+                        calendar
+                            .events
+                            .iter()
+                            .map(|event| event.id)
+                            .collect::<Vec<i32>>(),
+                        calendar.exceptions.len(),
+                    )
+                })
+                .map_err(|GetEventsServiceError::UnableToGetEventsFromRepository(err)| err);
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
 }
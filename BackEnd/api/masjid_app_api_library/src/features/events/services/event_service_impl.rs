@@ -1,6 +1,8 @@
+use crate::features::events::models::event_visibility::EventVisibility;
 use crate::features::events::repositories::EventsRepository;
 use crate::shared::common_service_impl::CommonServiceImpl;
 
 pub struct EventServiceImpl<R: EventsRepository + ?Sized> {
     pub common: CommonServiceImpl<R>,
+    pub visibility: EventVisibility,
 }
@@ -0,0 +1,160 @@
+use crate::features::events::models::event_visibility::EventVisibility;
+use crate::features::events::repositories::errors::get_events_repository_error::GetEventsRepositoryError;
+use crate::features::events::repositories::{EventsCacheRepository, EventsRepository};
+use crate::features::events::services::errors::warm_events_cache_error::WarmEventsCacheError;
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventsCacheWarmingService: Send + Sync {
+    /// Replaces the cached events with every event in the database, returning how many were
+    /// cached
+    async fn warm_events_cache(&self) -> Result<usize, WarmEventsCacheError>;
+}
+
+struct EventsCacheWarmingServiceImpl {
+    repository: Arc<dyn EventsRepository>,
+    cache_repository: Arc<dyn EventsCacheRepository>,
+}
+
+pub fn new_events_cache_warming_service(
+    repository: Arc<dyn EventsRepository>,
+    cache_repository: Arc<dyn EventsCacheRepository>,
+) -> Arc<dyn EventsCacheWarmingService> {
+    Arc::new(EventsCacheWarmingServiceImpl {
+        repository,
+        cache_repository,
+    })
+}
+
+#[async_trait]
+impl EventsCacheWarmingService for EventsCacheWarmingServiceImpl {
+    async fn warm_events_cache(&self) -> Result<usize, WarmEventsCacheError> {
+        // Unpublished events are cached too, as administrators read from the same cache
+        let events = match self.repository.get_events(EventVisibility::All).await {
+            Ok(events) => events,
+            Err(GetEventsRepositoryError::EventsNotFound) => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        self.cache_repository.replace_events(&events).await?;
+        Ok(events.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::event_details::EventDetails;
+    use crate::features::events::models::event_dto::EventDTO;
+    use crate::features::events::models::event_recurrence::EventRecurrence;
+    use crate::features::events::models::event_status::EventStatus;
+    use crate::features::events::models::event_type::EventType;
+    use crate::features::events::repositories::errors::cache_events_error::CacheEventsError;
+    use crate::features::events::repositories::{MockEventsCacheRepository, MockEventsRepository};
+    use crate::shared::types::contact_details::ContactDetails;
+    use crate::shared::types::contact_visibility::ContactVisibility;
+    use chrono::DateTime;
+
+    #[tokio::test]
+    async fn test_events_cache_warming_service_warm_events_cache() {
+        struct TestCase {
+            description: &'static str,
+            expected_db_response: Result<Vec<EventDTO>, GetEventsRepositoryError>,
+            expected_cached_events: Option<Vec<EventDTO>>,
+            expected_cache_response: Result<(), CacheEventsError>,
+            expected_result: Result<usize, WarmEventsCacheError>,
+        }
+        let events = vec![EventDTO {
+            id: 1,
+            title: "weekly halaqa".to_owned(),
+            description: None,
+            date: DateTime::default(),
+            event_details: EventDetails {
+                event_type: EventType::Talk,
+                event_recurrence: EventRecurrence::Weekly,
+                recurrence_end: None,
+                event_status: EventStatus::Draft,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }];
+        let test_cases = [
+            TestCase {
+                description: "When events cannot be retrieved from the database, I should get an error and nothing should be cached",
+                expected_db_response: Err(GetEventsRepositoryError::UnableToGetEvents),
+                expected_cached_events: None,
+                expected_cache_response: Ok(()),
+                expected_result: Err(WarmEventsCacheError::UnableToGetEvents(
+                    GetEventsRepositoryError::UnableToGetEvents,
+                )),
+            },
+            TestCase {
+                description: "When there are no events, the cache should be emptied",
+                expected_db_response: Err(GetEventsRepositoryError::EventsNotFound),
+                expected_cached_events: Some(Vec::new()),
+                expected_cache_response: Ok(()),
+                expected_result: Ok(0),
+            },
+            TestCase {
+                description: "When caching the events fails, I should get an error",
+                expected_db_response: Ok(events.clone()),
+                expected_cached_events: Some(events.clone()),
+                expected_cache_response: Err(CacheEventsError::UnableToCacheEvents),
+                expected_result: Err(WarmEventsCacheError::UnableToCacheEvents(
+                    CacheEventsError::UnableToCacheEvents,
+                )),
+            },
+            TestCase {
+                description: "When every event is cached, I should get how many were cached",
+                expected_db_response: Ok(events.clone()),
+                expected_cached_events: Some(events),
+                expected_cache_response: Ok(()),
+                expected_result: Ok(1),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventsRepository::new();
+            let mut mock_cache_repository = MockEventsCacheRepository::new();
+            mock_repository
+                .expect_get_events()
+                .withf(|visibility| *visibility == EventVisibility::All)
+                .return_once(move |_| test_case.expected_db_response);
+            match test_case.expected_cached_events {
+                Some(expected_cached_events) => {
+                    mock_cache_repository
+                        .expect_replace_events()
+                        .withf(move |events| events == expected_cached_events.as_slice())
+                        .times(1)
+                        .return_once(move |_| test_case.expected_cache_response);
+                }
+                None => {
+                    mock_cache_repository.expect_replace_events().never();
+                }
+            }
+            let service = new_events_cache_warming_service(
+                Arc::new(mock_repository),
+                Arc::new(mock_cache_repository),
+            );
+            assert_eq!(test_case.expected_result, service.warm_events_cache().await);
+        }
+    }
+}
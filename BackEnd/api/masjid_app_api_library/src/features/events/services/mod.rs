@@ -1,4 +1,8 @@
 pub mod errors;
 pub mod event_retrieval_service;
 pub mod event_service_impl;
+pub mod events_cache_warming_service;
+pub mod occurrence_expansion;
+pub mod registration_tokens;
+pub mod reminder_tokens;
 pub mod service_factory;
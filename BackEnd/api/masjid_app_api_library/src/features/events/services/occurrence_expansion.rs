@@ -0,0 +1,476 @@
+use crate::features::events::models::event_dto::EventDTO;
+use crate::features::events::models::event_exception_dto::EventExceptionDTO;
+use crate::features::events::models::event_occurrence_dto::EventOccurrenceDTO;
+use crate::features::events::models::event_recurrence::EventRecurrence;
+use crate::features::events::models::recurrence_end::RecurrenceEnd;
+use chrono::{DateTime, Datelike, Months, TimeDelta, Utc};
+
+/// Expands an event into its occurrences which fall between `from` (inclusive) and `to`
+/// (exclusive), applying any cancelled or moved occurrences
+pub fn expand_occurrences(
+    event: &EventDTO,
+    exceptions: &[EventExceptionDTO],
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<EventOccurrenceDTO> {
+    let event_exceptions: Vec<&EventExceptionDTO> = exceptions
+        .iter()
+        .filter(|exception| exception.event_id == event.id)
+        .collect();
+    let mut occurrences = Vec::new();
+    let mut index = first_occurrence_index_from(event, from);
+    while let Some(occurrence_date) = nth_occurrence(event, index) {
+        if occurrence_date >= to || !is_within_series(event, index, occurrence_date) {
+            break;
+        }
+        let has_exception = event_exceptions
+            .iter()
+            .any(|exception| exception.occurrence_date == occurrence_date);
+        if !has_exception {
+            occurrences.push(EventOccurrenceDTO {
+                occurrence_date,
+                original_date: None,
+                event: event.clone(),
+            });
+        }
+        index += 1;
+    }
+    // Moved occurrences are added separately, as their scheduled date may fall outside the window
+    for exception in event_exceptions {
+        let Some(replacement_date) = exception.replacement_date else {
+            continue;
+        };
+        if replacement_date >= from
+            && replacement_date < to
+            && is_scheduled_occurrence(event, exception.occurrence_date)
+        {
+            occurrences.push(EventOccurrenceDTO {
+                occurrence_date: replacement_date,
+                original_date: Some(exception.occurrence_date),
+                event: event.clone(),
+            });
+        }
+    }
+    occurrences.sort_by_key(|occurrence| occurrence.occurrence_date);
+    occurrences
+}
+
+/// Whether the date is one of the event's scheduled occurrences, before any exceptions
+pub fn is_scheduled_occurrence(event: &EventDTO, date: DateTime<Utc>) -> bool {
+    let index = first_occurrence_index_from(event, date);
+    nth_occurrence(event, index).is_some_and(|occurrence_date| {
+        occurrence_date == date && is_within_series(event, index, occurrence_date)
+    })
+}
+
+/// The date of the event's final occurrence, including any occurrence moved later than it, or
+/// `None` when the series repeats indefinitely
+pub fn last_occurrence(
+    event: &EventDTO,
+    exceptions: &[EventExceptionDTO],
+) -> Option<DateTime<Utc>> {
+    let last_index = match (
+        &event.event_details.event_recurrence,
+        event.event_details.recurrence_end,
+    ) {
+        (EventRecurrence::OneOff, _) => 0,
+        (_, None) => return None,
+        (_, Some(RecurrenceEnd::OccurrenceCount(count))) => (count as u32).checked_sub(1)?,
+        (_, Some(RecurrenceEnd::EndDate(end_date))) => {
+            let index = first_occurrence_index_from(event, end_date);
+            if nth_occurrence(event, index) == Some(end_date) {
+                index
+            } else {
+                index.checked_sub(1)?
+            }
+        }
+    };
+    let last_scheduled_date = nth_occurrence(event, last_index)?;
+    let last_replacement_date = exceptions
+        .iter()
+        .filter(|exception| {
+            exception.event_id == event.id
+                && is_scheduled_occurrence(event, exception.occurrence_date)
+        })
+        .filter_map(|exception| exception.replacement_date)
+        .max();
+    Some(
+        last_replacement_date.map_or(last_scheduled_date, |replacement_date| {
+            replacement_date.max(last_scheduled_date)
+        }),
+    )
+}
+
+fn nth_occurrence(event: &EventDTO, index: u32) -> Option<DateTime<Utc>> {
+    let anchor = event.date;
+    match event.event_details.event_recurrence {
+        EventRecurrence::OneOff => (index == 0).then_some(anchor),
+        EventRecurrence::Daily => anchor.checked_add_signed(TimeDelta::days(index as i64)),
+        EventRecurrence::Weekly => anchor.checked_add_signed(TimeDelta::weeks(index as i64)),
+        EventRecurrence::Fortnightly => {
+            anchor.checked_add_signed(TimeDelta::weeks(2 * index as i64))
+        }
+        // Always calculated from the anchor, so that an event on the 31st returns to the 31st
+        // after a shorter month
+        EventRecurrence::Monthly => anchor.checked_add_months(Months::new(index)),
+    }
+}
+
+/// Index of the first occurrence on or after the date
+fn first_occurrence_index_from(event: &EventDTO, date: DateTime<Utc>) -> u32 {
+    let anchor = event.date;
+    if date <= anchor {
+        return 0;
+    }
+    // Estimates an index no later than the answer, then steps forward to it
+    let estimate = match event.event_details.event_recurrence {
+        EventRecurrence::OneOff => 0,
+        EventRecurrence::Daily => (date - anchor).num_days(),
+        EventRecurrence::Weekly => (date - anchor).num_weeks(),
+        EventRecurrence::Fortnightly => (date - anchor).num_weeks() / 2,
+        EventRecurrence::Monthly => {
+            let months = (date.year() - anchor.year()) as i64 * 12 + date.month() as i64
+                - anchor.month() as i64;
+            months - 1
+        }
+    };
+    let mut index = u32::try_from(estimate.max(0)).unwrap_or(u32::MAX);
+    while nth_occurrence(event, index).is_some_and(|occurrence_date| occurrence_date < date) {
+        index += 1;
+    }
+    index
+}
+
+fn is_within_series(event: &EventDTO, index: u32, occurrence_date: DateTime<Utc>) -> bool {
+    match event.event_details.recurrence_end {
+        None => true,
+        Some(RecurrenceEnd::EndDate(end_date)) => occurrence_date <= end_date,
+        Some(RecurrenceEnd::OccurrenceCount(count)) => index < count as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::events::models::event_details::EventDetails;
+    use crate::features::events::models::event_status::EventStatus;
+    use crate::features::events::models::event_type::EventType;
+    use crate::shared::types::contact_details::ContactDetails;
+    use crate::shared::types::contact_visibility::ContactVisibility;
+
+    fn create_event(
+        date: &str,
+        event_recurrence: EventRecurrence,
+        recurrence_end: Option<RecurrenceEnd>,
+    ) -> EventDTO {
+        EventDTO {
+            id: 1,
+            title: "Weekly halaqa".to_owned(),
+            description: None,
+            date: date.parse().unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Class,
+                event_recurrence,
+                recurrence_end,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "Zayd McArdle".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }
+    }
+
+    fn parse_dates(dates: &[&str]) -> Vec<DateTime<Utc>> {
+        dates.iter().map(|date| date.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_expand_occurrences() {
+        struct TestCase {
+            description: &'static str,
+            event: EventDTO,
+            exceptions: Vec<EventExceptionDTO>,
+            from: &'static str,
+            to: &'static str,
+            expected_occurrence_dates: Vec<&'static str>,
+            expected_original_dates: Vec<Option<&'static str>>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When a one-off event falls within the window, I should get a single occurrence",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::OneOff, None),
+                exceptions: Vec::new(),
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_occurrence_dates: vec!["2025-01-06T19:00:00Z"],
+                expected_original_dates: vec![None],
+            },
+            TestCase {
+                description: "When a weekly event started before the window, I should only get the occurrences within it",
+                event: create_event("2024-09-02T19:00:00Z", EventRecurrence::Weekly, None),
+                exceptions: Vec::new(),
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-01-21T00:00:00Z",
+                expected_occurrence_dates: vec![
+                    "2025-01-06T19:00:00Z",
+                    "2025-01-13T19:00:00Z",
+                    "2025-01-20T19:00:00Z",
+                ],
+                expected_original_dates: vec![None, None, None],
+            },
+            TestCase {
+                description: "When a fortnightly event is expanded, I should get every other week",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Fortnightly, None),
+                exceptions: Vec::new(),
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_occurrence_dates: vec!["2025-01-06T19:00:00Z", "2025-01-20T19:00:00Z"],
+                expected_original_dates: vec![None, None],
+            },
+            TestCase {
+                description: "When a monthly event is on the 31st, it should fall on the last day of shorter months",
+                event: create_event("2025-01-31T19:00:00Z", EventRecurrence::Monthly, None),
+                exceptions: Vec::new(),
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-04-01T00:00:00Z",
+                expected_occurrence_dates: vec![
+                    "2025-01-31T19:00:00Z",
+                    "2025-02-28T19:00:00Z",
+                    "2025-03-31T19:00:00Z",
+                ],
+                expected_original_dates: vec![None, None, None],
+            },
+            TestCase {
+                description: "When a daily series has an end date, I should get no occurrences after it",
+                event: create_event(
+                    "2025-01-01T07:00:00Z",
+                    EventRecurrence::Daily,
+                    Some(RecurrenceEnd::EndDate(
+                        "2025-01-03T23:59:59Z".parse().unwrap(),
+                    )),
+                ),
+                exceptions: Vec::new(),
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_occurrence_dates: vec![
+                    "2025-01-01T07:00:00Z",
+                    "2025-01-02T07:00:00Z",
+                    "2025-01-03T07:00:00Z",
+                ],
+                expected_original_dates: vec![None, None, None],
+            },
+            TestCase {
+                description: "When a weekly series has an occurrence count, I should get no more than that many occurrences",
+                event: create_event(
+                    "2024-12-30T19:00:00Z",
+                    EventRecurrence::Weekly,
+                    Some(RecurrenceEnd::OccurrenceCount(2)),
+                ),
+                exceptions: Vec::new(),
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-02-01T00:00:00Z",
+                expected_occurrence_dates: vec!["2025-01-06T19:00:00Z"],
+                expected_original_dates: vec![None],
+            },
+            TestCase {
+                description: "When an occurrence is cancelled, it should not be returned",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Weekly, None),
+                exceptions: vec![EventExceptionDTO {
+                    event_id: 1,
+                    occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+                    replacement_date: None,
+                }],
+                from: "2025-01-01T00:00:00Z",
+                to: "2025-01-21T00:00:00Z",
+                expected_occurrence_dates: vec!["2025-01-06T19:00:00Z", "2025-01-20T19:00:00Z"],
+                expected_original_dates: vec![None, None],
+            },
+            TestCase {
+                description: "When an occurrence is moved into the window, it should be returned on its new date",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Weekly, None),
+                exceptions: vec![EventExceptionDTO {
+                    event_id: 1,
+                    occurrence_date: "2025-01-27T19:00:00Z".parse().unwrap(),
+                    replacement_date: Some("2025-01-14T19:00:00Z".parse().unwrap()),
+                }],
+                from: "2025-01-10T00:00:00Z",
+                to: "2025-01-21T00:00:00Z",
+                expected_occurrence_dates: vec![
+                    "2025-01-13T19:00:00Z",
+                    "2025-01-14T19:00:00Z",
+                    "2025-01-20T19:00:00Z",
+                ],
+                expected_original_dates: vec![None, Some("2025-01-27T19:00:00Z"), None],
+            },
+            TestCase {
+                description: "When an exception belongs to another event, it should be ignored",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Weekly, None),
+                exceptions: vec![EventExceptionDTO {
+                    event_id: 2,
+                    occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+                    replacement_date: None,
+                }],
+                from: "2025-01-10T00:00:00Z",
+                to: "2025-01-14T00:00:00Z",
+                expected_occurrence_dates: vec!["2025-01-13T19:00:00Z"],
+                expected_original_dates: vec![None],
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let actual_occurrences = expand_occurrences(
+                &test_case.event,
+                &test_case.exceptions,
+                test_case.from.parse().unwrap(),
+                test_case.to.parse().unwrap(),
+            );
+            let actual_occurrence_dates: Vec<DateTime<Utc>> = actual_occurrences
+                .iter()
+                .map(|occurrence| occurrence.occurrence_date)
+                .collect();
+            let actual_original_dates: Vec<Option<DateTime<Utc>>> = actual_occurrences
+                .iter()
+                .map(|occurrence| occurrence.original_date)
+                .collect();
+            let expected_original_dates: Vec<Option<DateTime<Utc>>> = test_case
+                .expected_original_dates
+                .iter()
+                .map(|date| date.map(|date| date.parse().unwrap()))
+                .collect();
+            assert_eq!(
+                parse_dates(&test_case.expected_occurrence_dates),
+                actual_occurrence_dates
+            );
+            assert_eq!(expected_original_dates, actual_original_dates);
+        }
+    }
+
+    #[test]
+    fn test_is_scheduled_occurrence() {
+        struct TestCase {
+            description: &'static str,
+            event: EventDTO,
+            date: &'static str,
+            expected_result: bool,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the date is an occurrence of the series, I should get true",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Weekly, None),
+                date: "2025-03-03T19:00:00Z",
+                expected_result: true,
+            },
+            TestCase {
+                description: "When the date is on the right day but at a different time, I should get false",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Weekly, None),
+                date: "2025-03-03T18:00:00Z",
+                expected_result: false,
+            },
+            TestCase {
+                description: "When the date is before the series starts, I should get false",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Weekly, None),
+                date: "2024-12-30T19:00:00Z",
+                expected_result: false,
+            },
+            TestCase {
+                description: "When the date is after the final occurrence, I should get false",
+                event: create_event(
+                    "2025-01-06T19:00:00Z",
+                    EventRecurrence::Weekly,
+                    Some(RecurrenceEnd::OccurrenceCount(4)),
+                ),
+                date: "2025-02-03T19:00:00Z",
+                expected_result: false,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let actual_result =
+                is_scheduled_occurrence(&test_case.event, test_case.date.parse().unwrap());
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[test]
+    fn test_last_occurrence() {
+        struct TestCase {
+            description: &'static str,
+            event: EventDTO,
+            exceptions: Vec<EventExceptionDTO>,
+            expected_result: Option<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the event is one-off, I should get its date",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::OneOff, None),
+                exceptions: Vec::new(),
+                expected_result: Some("2025-01-06T19:00:00Z"),
+            },
+            TestCase {
+                description: "When the series repeats indefinitely, I should get nothing",
+                event: create_event("2025-01-06T19:00:00Z", EventRecurrence::Weekly, None),
+                exceptions: Vec::new(),
+                expected_result: None,
+            },
+            TestCase {
+                description: "When the series ends after a number of occurrences, I should get the last of them",
+                event: create_event(
+                    "2025-01-06T19:00:00Z",
+                    EventRecurrence::Weekly,
+                    Some(RecurrenceEnd::OccurrenceCount(4)),
+                ),
+                exceptions: Vec::new(),
+                expected_result: Some("2025-01-27T19:00:00Z"),
+            },
+            TestCase {
+                description: "When the series ends between occurrences, I should get the one before the end date",
+                event: create_event(
+                    "2025-01-06T19:00:00Z",
+                    EventRecurrence::Weekly,
+                    Some(RecurrenceEnd::EndDate(
+                        "2025-01-25T00:00:00Z".parse().unwrap(),
+                    )),
+                ),
+                exceptions: Vec::new(),
+                expected_result: Some("2025-01-20T19:00:00Z"),
+            },
+            TestCase {
+                description: "When an occurrence has been moved past the end of the series, I should get its new date",
+                event: create_event(
+                    "2025-01-06T19:00:00Z",
+                    EventRecurrence::Weekly,
+                    Some(RecurrenceEnd::OccurrenceCount(2)),
+                ),
+                exceptions: vec![EventExceptionDTO {
+                    event_id: 1,
+                    occurrence_date: "2025-01-13T19:00:00Z".parse().unwrap(),
+                    replacement_date: Some("2025-02-01T19:00:00Z".parse().unwrap()),
+                }],
+                expected_result: Some("2025-02-01T19:00:00Z"),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let actual_result = last_occurrence(&test_case.event, &test_case.exceptions);
+            assert_eq!(
+                test_case.expected_result.map(|date| date.parse().unwrap()),
+                actual_result
+            );
+        }
+    }
+}
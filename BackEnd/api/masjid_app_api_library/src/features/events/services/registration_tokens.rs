@@ -0,0 +1,101 @@
+use crate::shared::services::token_signing::r#trait::TokenSigner;
+
+/// What a registration token entitles its holder to do. Each purpose signs a differently
+/// prefixed payload, so a check-in code can never be used to cancel a registration or vice versa
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RegistrationTokenPurpose {
+    Cancellation,
+    CheckIn,
+}
+impl RegistrationTokenPurpose {
+    fn payload_prefix(&self) -> &'static str {
+        match self {
+            RegistrationTokenPurpose::Cancellation => "event-registration-",
+            RegistrationTokenPurpose::CheckIn => "event-check-in-",
+        }
+    }
+}
+
+pub fn issue_registration_token(
+    token_signer: &dyn TokenSigner,
+    purpose: RegistrationTokenPurpose,
+    registration_id: i32,
+) -> String {
+    token_signer.sign(&format!("{}{}", purpose.payload_prefix(), registration_id))
+}
+
+/// Returns the id of the registration the token was issued for, provided it was signed with the
+/// same secret and for the same purpose
+pub fn registration_id_from_token(
+    token_signer: &dyn TokenSigner,
+    purpose: RegistrationTokenPurpose,
+    token: &str,
+) -> Option<i32> {
+    token_signer
+        .verify(token)
+        .ok()?
+        .strip_prefix(purpose.payload_prefix())?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::services::token_signing::r#trait::new_token_signer;
+
+    #[test]
+    fn test_registration_id_from_token() {
+        struct TestCase {
+            description: &'static str,
+            token: String,
+            expected_registration_id: Option<i32>,
+        }
+        let token_signer = new_token_signer(b"secret");
+        let test_cases = [
+            TestCase {
+                description: "When the token was issued for the same purpose, I should get the registration id",
+                token: issue_registration_token(
+                    &*token_signer,
+                    RegistrationTokenPurpose::CheckIn,
+                    7,
+                ),
+                expected_registration_id: Some(7),
+            },
+            TestCase {
+                description: "When the token was issued for a different purpose, I should get nothing",
+                token: issue_registration_token(
+                    &*token_signer,
+                    RegistrationTokenPurpose::Cancellation,
+                    7,
+                ),
+                expected_registration_id: None,
+            },
+            TestCase {
+                description: "When the token was signed with a different secret, I should get nothing",
+                token: issue_registration_token(
+                    &*new_token_signer(b"another secret"),
+                    RegistrationTokenPurpose::CheckIn,
+                    7,
+                ),
+                expected_registration_id: None,
+            },
+            TestCase {
+                description: "When the signed payload is not a registration, I should get nothing",
+                token: token_signer.sign("event-check-in-seven"),
+                expected_registration_id: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_registration_id,
+                registration_id_from_token(
+                    &*token_signer,
+                    RegistrationTokenPurpose::CheckIn,
+                    &test_case.token
+                )
+            );
+        }
+    }
+}
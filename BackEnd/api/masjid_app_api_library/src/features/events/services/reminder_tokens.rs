@@ -0,0 +1,94 @@
+use crate::shared::services::token_signing::r#trait::TokenSigner;
+
+/// What a reminder token entitles its holder to do. Each purpose signs a differently prefixed
+/// payload, so an unsubscribe link can never be used to confirm a subscription or vice versa
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ReminderTokenPurpose {
+    Unsubscribe,
+    Confirmation,
+}
+impl ReminderTokenPurpose {
+    fn payload_prefix(&self) -> &'static str {
+        match self {
+            ReminderTokenPurpose::Unsubscribe => "event-reminder-",
+            ReminderTokenPurpose::Confirmation => "event-reminder-confirmation-",
+        }
+    }
+}
+
+pub fn issue_reminder_token(
+    token_signer: &dyn TokenSigner,
+    purpose: ReminderTokenPurpose,
+    subscription_id: i32,
+) -> String {
+    token_signer.sign(&format!("{}{}", purpose.payload_prefix(), subscription_id))
+}
+
+/// Returns the id of the subscription the token was issued for, provided it was signed with the
+/// same secret and for the same purpose
+pub fn subscription_id_from_token(
+    token_signer: &dyn TokenSigner,
+    purpose: ReminderTokenPurpose,
+    token: &str,
+) -> Option<i32> {
+    token_signer
+        .verify(token)
+        .ok()?
+        .strip_prefix(purpose.payload_prefix())?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::services::token_signing::r#trait::new_token_signer;
+
+    #[test]
+    fn test_subscription_id_from_token() {
+        struct TestCase {
+            description: &'static str,
+            purpose: ReminderTokenPurpose,
+            token: String,
+            expected_subscription_id: Option<i32>,
+        }
+        let token_signer = new_token_signer(b"secret");
+        let test_cases = [
+            TestCase {
+                description: "When the token was issued for the same purpose, I should get the subscription id",
+                purpose: ReminderTokenPurpose::Unsubscribe,
+                token: issue_reminder_token(&*token_signer, ReminderTokenPurpose::Unsubscribe, 7),
+                expected_subscription_id: Some(7),
+            },
+            TestCase {
+                description: "When a confirmation token is used to unsubscribe, I should get nothing",
+                purpose: ReminderTokenPurpose::Unsubscribe,
+                token: issue_reminder_token(&*token_signer, ReminderTokenPurpose::Confirmation, 7),
+                expected_subscription_id: None,
+            },
+            TestCase {
+                description: "When an unsubscribe token is used to confirm, I should get nothing",
+                purpose: ReminderTokenPurpose::Confirmation,
+                token: issue_reminder_token(&*token_signer, ReminderTokenPurpose::Unsubscribe, 7),
+                expected_subscription_id: None,
+            },
+            TestCase {
+                description: "When the token was signed with a different secret, I should get nothing",
+                purpose: ReminderTokenPurpose::Confirmation,
+                token: issue_reminder_token(
+                    &*new_token_signer(b"another secret"),
+                    ReminderTokenPurpose::Confirmation,
+                    7,
+                ),
+                expected_subscription_id: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_subscription_id,
+                subscription_id_from_token(&*token_signer, test_case.purpose, &test_case.token)
+            );
+        }
+    }
+}
@@ -11,6 +11,8 @@ macro_rules! new_event_service {
                     repository,
                     in_memory_repository,
                 },
+                visibility:
+                    $crate::features::events::models::event_visibility::EventVisibility::All,
             })
         }
     };
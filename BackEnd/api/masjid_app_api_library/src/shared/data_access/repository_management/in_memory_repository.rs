@@ -1,9 +1,33 @@
+use crate::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use crate::shared::data_access::repository_management::repository_mode::RepositoryMode;
 use crate::shared::data_access::repository_management::repository_type::RepositoryType;
+use crate::shared::data_access::repository_management::utils::get_connection_string;
+use redis::aio::ConnectionManager;
 
-pub struct InMemoryRepository {}
+pub struct InMemoryRepository {
+    /// Reconnects by itself, so a cache which restarts does not need the API to be restarted too
+    pub db_connection: ConnectionManager,
+}
 
 impl InMemoryRepository {
     pub async fn new(repository_type: RepositoryType) -> Self {
-        InMemoryRepository {}
+        let connection_string = std::env::var(get_connection_string(
+            repository_type,
+            RepositoryMode::InMemory(InMemoryDbProvider::Redis),
+        ))
+        .unwrap();
+        let db_connection_result = match redis::Client::open(connection_string) {
+            Ok(client) => client.get_connection_manager().await,
+            Err(err) => Err(err),
+        };
+        match db_connection_result {
+            Ok(db_connection) => {
+                tracing::info!("in-memory database connection successfully established");
+                Self { db_connection }
+            }
+            Err(err) => {
+                panic!("Failed to connect to in-memory database: {err}");
+            }
+        }
     }
 }
@@ -4,13 +4,15 @@ use crate::shared::data_access::repository_management::repository_mode::Reposito
 use crate::shared::data_access::repository_management::repository_type::RepositoryType;
 
 pub const AUTHENTICATION_MYSQL_CONNECTION: &'static str = "AUTHENTICATION_CONNECTION";
-pub const AUTHENTICATION_REDIS_CONNECTION: &'static str = "AUTHENTICATION_CONNECTION";
+pub const AUTHENTICATION_REDIS_CONNECTION: &'static str = "AUTHENTICATION_REDIS_CONNECTION";
 pub const PRAYER_TIMES_MYSQL_CONNECTION: &'static str = "PRAYER_TIMES_CONNECTION";
-pub const PRAYER_TIMES_REDIS_CONNECTION: &'static str = "PRAYER_TIMES_CONNECTION";
+pub const PRAYER_TIMES_REDIS_CONNECTION: &'static str = "PRAYER_TIMES_REDIS_CONNECTION";
 pub const ASK_IMAM_MYSQL_CONNECTION: &'static str = "ASK_IMAM_CONNECTION";
-pub const ASK_IMAM_REDIS_CONNECTION: &'static str = "ASK_IMAM_CONNECTION";
+pub const ASK_IMAM_REDIS_CONNECTION: &'static str = "ASK_IMAM_REDIS_CONNECTION";
 pub const EVENTS_MYSQL_CONNECTION: &'static str = "EVENTS_CONNECTION";
-pub const EVENTS_REDIS_CONNECTION: &'static str = "EVENTS_CONNECTION";
+pub const EVENTS_REDIS_CONNECTION: &'static str = "EVENTS_REDIS_CONNECTION";
+pub const AUDIT_LOG_MYSQL_CONNECTION: &'static str = "AUDIT_LOG_CONNECTION";
+pub const AUDIT_LOG_REDIS_CONNECTION: &'static str = "AUDIT_LOG_REDIS_CONNECTION";
 
 #[inline]
 pub(super) fn get_connection_string(
@@ -42,5 +44,11 @@ pub(super) fn get_connection_string(
         (RepositoryType::Events, RepositoryMode::Normal(NormalDbProvider::MySql)) => {
             EVENTS_MYSQL_CONNECTION
         }
+        (RepositoryType::AuditLog, RepositoryMode::InMemory(InMemoryDbProvider::Redis)) => {
+            AUDIT_LOG_REDIS_CONNECTION
+        }
+        (RepositoryType::AuditLog, RepositoryMode::Normal(NormalDbProvider::MySql)) => {
+            AUDIT_LOG_MYSQL_CONNECTION
+        }
     }
 }
@@ -1,213 +1,104 @@
-use crate::shared::extractors::file_handler::{
-    FileHandler, FilePathSafetyError, file_path_is_safe,
-};
-use std::io::ErrorKind;
-use std::path::Path;
+use crate::shared::extractors::file_handler::FileHandler;
+use crate::shared::services::file_storage::errors::FileStorageError;
+use crate::shared::types::image_variants::ImageVariant;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum DeleteError {
-    EmptyPath,
-    PathIsTraversal,
+    UrlNotIssuedByFileHandler,
     FileNotFound,
-    DirectoryNotFound,
-    PermissionDenied,
-    DirectoryMistookForFile,
-    UnableToDeleteFileDueToReadOnlyAccess,
-    UnableToDeleteFileDueToBeingInUse,
-    IOError(std::io::Error),
+    UnableToDeleteFile,
 }
 impl FileHandler {
-    pub async fn delete_file(&self, file_path: impl AsRef<Path>) -> Result<(), DeleteError> {
-        let path = file_path.as_ref();
-        file_path_is_safe(&path).map_err(|err| match err {
-            FilePathSafetyError::EmptyPath => DeleteError::EmptyPath,
-            FilePathSafetyError::PathTraversalAttack => {
-                tracing::warn!("user attempted path traversal attack: {}", path.display());
-                DeleteError::PathIsTraversal
-            }
-        })?;
-
-        // Check if parent directory exists
-        if let Some(parent) = path.parent()
-            && !parent.exists()
-        {
-            return Err(DeleteError::DirectoryNotFound);
-        }
-
-        // Check if path exists and is a file
-        match tokio::fs::metadata(path).await {
-            Ok(metadata) => {
-                if !metadata.is_file() {
-                    return Err(DeleteError::DirectoryMistookForFile);
-                }
-            }
-            Err(e) => {
-                if e.kind() != ErrorKind::NotFound {
-                    return Err(DeleteError::FileNotFound);
-                }
-                tracing::error!("Failed to get metadata for {}: {}", path.display(), e);
-                return Err(DeleteError::IOError(e));
-            }
-        };
-
-        // Attempt to delete the file
-        tokio::fs::remove_file(path)
+    /// Deletes the file behind a url previously returned by `save_file`
+    pub async fn delete_file_by_url(&self, url: &str) -> Result<(), DeleteError> {
+        let key = self
+            .key_from_url(url)
+            .ok_or(DeleteError::UrlNotIssuedByFileHandler)?;
+        self.storage
+            .delete_object(key)
             .await
-            .map_err(|e| match e.kind() {
-                ErrorKind::PermissionDenied => DeleteError::PermissionDenied,
-                ErrorKind::ReadOnlyFilesystem => DeleteError::UnableToDeleteFileDueToReadOnlyAccess,
-                ErrorKind::ResourceBusy => DeleteError::UnableToDeleteFileDueToBeingInUse,
-                _ => {
-                    tracing::error!("Failed to delete file {}: {}", path.display(), e);
-                    return DeleteError::IOError(e);
-                }
+            .map_err(|err| match err {
+                FileStorageError::ObjectNotFound => DeleteError::FileNotFound,
+                _ => DeleteError::UnableToDeleteFile,
             })
     }
-} /*
-#[cfg(test)]
-mod tests {
-use super::*;
-use std::path::PathBuf;
-use tempdir::TempDir;
-
-// Test case struct to replace tuples
-struct TestCase {
-name: &'static str,
-path: PathBuf,
-path_exists: bool,
-expected: Result<(), DeleteError>,
-setup: Box<dyn Fn() -> (PathBuf, Result<(), DeleteError>)>,
+    /// Deletes an image previously returned by `save_image`, along with each of its variants
+    pub async fn delete_image_by_url(&self, url: &str) -> Result<(), DeleteError> {
+        self.delete_file_by_url(url).await?;
+        for variant in ImageVariant::ALL {
+            let variant_url = variant.file_name(url);
+            match self.delete_file_by_url(&variant_url).await {
+                // Images uploaded before variants were generated will not have any
+                Ok(()) | Err(DeleteError::FileNotFound) => {}
+                Err(err) => {
+                    tracing::error!(error = ?err, url = %variant_url, "unable to delete image variant")
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
-#[tokio::test]
-async fn test_delete_file() {
-let tests: Vec<TestCase> = vec![
-TestCase {
-name: "parent_directory_not_found",
-path: "non_existent_directory/file.txt".into(),
-path_exists: false,
-expected: Err(DeleteError::DirectoryNotFound),
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::extractors::file_handler::UPLOADS_ENDPOINT;
+    use crate::shared::services::file_storage::settings::UploadLimits;
+    use crate::shared::services::file_storage::r#trait::MockFileStorage;
+    use std::sync::Arc;
 
-TestCase {
-name: "parent_directory_not_found",
-setup: Box::new(|| {
-(
-"non_existent_directory/file.txt".into(),
-Err(DeleteError::DirectoryNotFound),
-)
-}),
-},
-TestCase {
-name: "directory_mistook_for_file",
-setup: Box::new(|| {
-(
-TempDir::new("I_am_a_directory").unwrap().path().to_owned(),
-Err(DeleteError::DirectoryMistookForFile),
-)
-}),
-},
-TestCase {
-name: "file_not_found",
-setup: Box::new(|| {
-/*let dir = tempdir().unwrap();
-                    let path = dir.path().join("nonexistent.txt");*/
-                    ("nonexistent.txt".into(), Err(DeleteError::FileNotFound))
-                }),
+    #[tokio::test]
+    async fn test_file_handler_delete_file_by_url() {
+        struct TestCase {
+            description: &'static str,
+            url: &'static str,
+            mock_response: Option<Result<(), FileStorageError>>,
+            expected_result: Result<(), DeleteError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the url was not issued by the file handler, I should get an error without touching storage",
+                url: "https://example.com/poster.png",
+                mock_response: None,
+                expected_result: Err(DeleteError::UrlNotIssuedByFileHandler),
             },
-            /*TestCase {
-                name: "delete_permission_denied",
-                setup: Box::new(|| {
-                    #[cfg(unix)]
-                    {
-                        use std::os::unix::fs::PermissionsExt;
-                        let dir = tempdir().unwrap();
-                        let path = dir.path().join("restricted.txt");
-
-                        // Create the file synchronously since we're in a sync closure
-                        std::fs::File::create(&path).unwrap();
-                        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o000))
-                            .unwrap();
-
-                        (path, Err(DeleteError::PermissionDenied))
-                    }
-                    #[cfg(not(unix))]
-                    {
-                        // Skip test on non-Unix systems
-                        let dir = tempdir().unwrap();
-                        let path = dir.path().join("dummy.txt");
-                        (path, Ok(()))
-                    }
-                }),
+            TestCase {
+                description: "When the file does not exist, I should get a file not found error",
+                url: "/uploads/poster.png",
+                mock_response: Some(Err(FileStorageError::ObjectNotFound)),
+                expected_result: Err(DeleteError::FileNotFound),
             },
             TestCase {
-                name: "success",
-                setup: Box::new(|| {
-                    let file = NamedTempFile::new().unwrap();
-                    let path = file.path().to_owned();
-                    // Keep the file handle to prevent automatic deletion
-                    (path, Ok(()))
-                }),
-            },*/
+                description: "When storage fails to delete the file, I should get an unable to delete file error",
+                url: "/uploads/poster.png",
+                mock_response: Some(Err(FileStorageError::UnableToDeleteObject)),
+                expected_result: Err(DeleteError::UnableToDeleteFile),
+            },
+            TestCase {
+                description: "When the file exists, it should be deleted",
+                url: "/uploads/poster.png",
+                mock_response: Some(Ok(())),
+                expected_result: Ok(()),
+            },
         ];
-
-        for test in tests {
-            if test.path_exists {
-                std:::
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_storage = MockFileStorage::new();
+            if let Some(mock_response) = test_case.mock_response {
+                mock_storage
+                    .expect_delete_object()
+                    .withf(|key| key == "poster.png")
+                    .return_once(move |_| mock_response);
             }
-            let handler = FileHandler::default();
-            let result = handler.delete_file(&path).await.map_err(|actual_err| {
-                let expected_err = expected.as_ref().unwrap_err();
-                match expected_err {
-                    DeleteError::EmptyPath => assert!(matches!(actual_err, DeleteError::EmptyPath)),
-                    DeleteError::PathIsTraversal => {
-                        assert!(matches!(actual_err, DeleteError::PathIsTraversal))
-                    }
-                    DeleteError::FileNotFound => {
-                        assert!(matches!(actual_err, DeleteError::FileNotFound))
-                    }
-                    DeleteError::DirectoryNotFound => {
-                        assert!(matches!(actual_err, DeleteError::DirectoryNotFound))
-                    }
-                    DeleteError::PermissionDenied => {
-                        assert!(matches!(actual_err, DeleteError::PermissionDenied))
-                    }
-                    DeleteError::DirectoryMistookForFile => {
-                        assert!(
-                            matches!(actual_err, DeleteError::DirectoryMistookForFile),
-                            "Test '{}' failed: {:?}",
-                            path.display(),
-                            actual_err
-                        );
-                    }
-                    DeleteError::UnableToDeleteFileDueToReadOnlyAccess => assert!(matches!(
-                        actual_err,
-                        DeleteError::UnableToDeleteFileDueToReadOnlyAccess
-                    )),
-                    DeleteError::UnableToDeleteFileDueToBeingInUse => assert!(matches!(
-                        actual_err,
-                        DeleteError::UnableToDeleteFileDueToBeingInUse
-                    )),
-                    DeleteError::IOError(_) => {
-                        assert!(matches!(actual_err, DeleteError::IOError(_)))
-                    }
-                }
-            });
-            if !expected.is_ok() {
-                continue;
-            }
-            assert!(result.is_ok(), "Test '{}' failed: {:?}", test.name, result)
+            let file_handler = FileHandler::new(
+                Arc::new(mock_storage),
+                UploadLimits::default(),
+                UPLOADS_ENDPOINT.to_owned(),
+            );
+            assert_eq!(
+                test_case.expected_result,
+                file_handler.delete_file_by_url(test_case.url).await
+            );
         }
-
-        /*// Test for IOError case (file not found during metadata check)
-        let dir = tempdir().unwrap();
-        let non_existent_path = dir.path().join("non_existent_file.txt");
-        let handler = FileHandler::default();
-        let result = handler.delete_file(&non_existent_path).await;
-
-        // This should return FileNotFound, not IOError, based on the function logic
-        assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), DeleteError::FileNotFound));*/
     }
 }
-*/
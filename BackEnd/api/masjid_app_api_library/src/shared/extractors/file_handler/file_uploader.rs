@@ -1,4 +1,6 @@
-use crate::shared::extractors::file_handler::FileHandler;
+use crate::shared::extractors::file_handler::{
+    FileHandler, content_addressed_key, content_type_from_file_name, file_path_is_safe,
+};
 use async_trait::async_trait;
 use axum::extract::FromRequestParts;
 use axum::http::StatusCode;
@@ -13,6 +15,8 @@ pub enum UploadError {
     NoFileName,
     InvalidFileName,
     UnsupportedFileType(String),
+    FileTooLarge,
+    StorageQuotaExceeded,
     SystemIOError,
 }
 const NO_FILE_EXTENSION: &'static str = "";
@@ -28,19 +32,16 @@ impl Display for UploadError {
                 }
                 format!("Unsupported type: {}", e)
             }
+            UploadError::FileTooLarge => "File too large".to_owned(),
+            UploadError::StorageQuotaExceeded => "Storage quota exceeded".to_owned(),
             UploadError::SystemIOError => "System IO error".to_owned(),
         };
         write!(f, "{}", str)
     }
 }
+// Images are uploaded through `save_image` instead, so that their contents are validated
 fn is_supported_file_extension(extension: &str) -> bool {
-    extension == "png"
-        || extension == "jpg"
-        || extension == "jpeg"
-        || extension == "gif"
-        || extension == "json"
-        || extension == "csv"
-        || extension == "json"
+    extension == "json" || extension == "csv"
 }
 impl FileHandler {
     pub async fn save_file(&self, data: &[u8], file_name: String) -> Result<String, UploadError> {
@@ -48,6 +49,12 @@ impl FileHandler {
             return Err(UploadError::NoFileName);
         } else if data.is_empty() {
             return Err(UploadError::EmptyFile);
+        } else if file_name.contains('/') || file_path_is_safe(&file_name).is_err() {
+            tracing::warn!(
+                "user attempted to upload outside of the upload directory: {}",
+                file_name
+            );
+            return Err(UploadError::InvalidFileName);
         } else if !file_name.contains('.') {
             return Err(UploadError::UnsupportedFileType(
                 NO_FILE_EXTENSION.to_owned(),
@@ -55,30 +62,49 @@ impl FileHandler {
         } else if file_name.chars().nth(file_name.len() - 1) == Some('.') {
             tracing::error!("invalid file name: {}", file_name);
             return Err(UploadError::InvalidFileName);
-        } else if let Some(extension) = file_name.split('.').last() {
-            if !is_supported_file_extension(extension) {
-                tracing::error!("invalid file extension: {}", file_name);
-                return Err(UploadError::UnsupportedFileType(extension.to_owned()));
-            }
         }
-        let path = self.upload_dir.join(&file_name);
-        tokio::fs::write(&path, data).await.map_err(|e| {
-            tracing::error!("unable to upload file: {}\nerror: {}", &file_name, &e);
-            UploadError::SystemIOError
-        })?;
-        Ok(format!("/{}/{}", &self.endpoint, file_name))
+        let extension = file_name.split('.').last().unwrap_or(NO_FILE_EXTENSION);
+        if !is_supported_file_extension(extension) {
+            tracing::error!("invalid file extension: {}", file_name);
+            return Err(UploadError::UnsupportedFileType(extension.to_owned()));
+        }
+        let size = data.len() as u64;
+        if size > self.limits.maximum_document_size {
+            return Err(UploadError::FileTooLarge);
+        }
+        let is_within_quota = self
+            .is_within_quota(size)
+            .await
+            .map_err(|_| UploadError::SystemIOError)?;
+        if !is_within_quota {
+            tracing::warn!("upload rejected as the storage quota has been reached");
+            return Err(UploadError::StorageQuotaExceeded);
+        }
+        let key = content_addressed_key(data, extension);
+        self.storage
+            .put_object(&key, data.to_vec(), content_type_from_file_name(&key))
+            .await
+            .map_err(|err| {
+                tracing::error!("unable to upload file: {}\nerror: {:?}", &file_name, err);
+                UploadError::SystemIOError
+            })?;
+        Ok(self.url_from_key(&key))
     }
 }
 
 mod test {
-    use crate::shared::extractors::file_handler::FileHandler;
     use crate::shared::extractors::file_handler::file_uploader::{NO_FILE_EXTENSION, UploadError};
+    use crate::shared::extractors::file_handler::{FileHandler, UPLOADS_ENDPOINT};
+    use crate::shared::services::file_storage::settings::UploadLimits;
+    use crate::shared::services::file_storage::r#trait::MockFileStorage;
+    use std::sync::Arc;
 
     #[tokio::test]
     async fn test_file_uploader_save_file() {
         struct TestCase {
             file_name: String,
             file_data: Vec<u8>,
+            stored_size: u64,
             expected_result: Result<String, UploadError>,
         }
         let test_cases = vec![
@@ -86,24 +112,35 @@ mod test {
             TestCase {
                 file_name: "filename".to_owned(),
                 file_data: vec![],
+                stored_size: 0,
                 expected_result: Err(UploadError::EmptyFile),
             },
             // When there is no file name, I should get a no file name error
             TestCase {
                 file_name: "".to_owned(),
                 file_data: vec![1],
+                stored_size: 0,
                 expected_result: Err(UploadError::NoFileName),
             },
             // When there is an invalid file name, I should get an invalid file name error
             TestCase {
                 file_name: ".".to_owned(),
                 file_data: vec![1],
+                stored_size: 0,
+                expected_result: Err(UploadError::InvalidFileName),
+            },
+            // When the file name tries to escape the upload directory, I should get an invalid file name error
+            TestCase {
+                file_name: "../filename.png".to_owned(),
+                file_data: vec![1],
+                stored_size: 0,
                 expected_result: Err(UploadError::InvalidFileName),
             },
             // When there is no file extension, I should get an unable to locate file extension error
             TestCase {
                 file_name: "filename".to_owned(),
                 file_data: vec![1],
+                stored_size: 0,
                 expected_result: Err(UploadError::UnsupportedFileType(
                     NO_FILE_EXTENSION.to_owned(),
                 )),
@@ -112,17 +149,58 @@ mod test {
             TestCase {
                 file_name: "filename.txt".to_owned(),
                 file_data: vec![1],
+                stored_size: 0,
                 expected_result: Err(UploadError::UnsupportedFileType(String::from("txt"))),
             },
-            // When I input a supported file, I should get no error
+            // When I input an image, I should get an unsupported file extension error as images have their own upload
+            TestCase {
+                file_name: "filename.png".to_owned(),
+                file_data: vec![1],
+                stored_size: 0,
+                expected_result: Err(UploadError::UnsupportedFileType(String::from("png"))),
+            },
+            // When the file is larger than the limit for its type, I should get a file too large error
+            TestCase {
+                file_name: "filename.json".to_owned(),
+                file_data: vec![1; 5],
+                stored_size: 0,
+                expected_result: Err(UploadError::FileTooLarge),
+            },
+            // When the file would exceed the storage quota, I should get a storage quota exceeded error
+            TestCase {
+                file_name: "filename.json".to_owned(),
+                file_data: vec![1],
+                stored_size: 100,
+                expected_result: Err(UploadError::StorageQuotaExceeded),
+            },
+            // When I input a supported file, it should be stored under the hash of its contents
             TestCase {
                 file_name: "filename.json".to_owned(),
                 file_data: vec![1],
-                expected_result: Ok("/uploads/filename.json".to_owned()),
+                stored_size: 0,
+                expected_result: Ok(
+                    "/uploads/4bf5122f344554c53bde2ebb8cd2b7e3d1600ad631c385a5d7cce23c7785459a.json"
+                        .to_owned(),
+                ),
             },
         ];
         for test_case in test_cases {
-            let file_uploader = FileHandler::new(std::env::temp_dir(), "uploads".to_owned());
+            let mut mock_storage = MockFileStorage::new();
+            mock_storage
+                .expect_total_size()
+                .return_once(move || Ok(test_case.stored_size));
+            mock_storage
+                .expect_put_object()
+                .return_once(|_, _, _| Ok(()));
+            let file_uploader = FileHandler::new(
+                Arc::new(mock_storage),
+                UploadLimits {
+                    maximum_document_size: 4,
+                    storage_quota: 100,
+                    ..UploadLimits::default()
+                },
+                UPLOADS_ENDPOINT.to_owned(),
+            );
             let actual_result = file_uploader
                 .save_file(&test_case.file_data, test_case.file_name)
                 .await;
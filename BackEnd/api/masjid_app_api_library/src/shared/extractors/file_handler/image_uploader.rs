@@ -0,0 +1,284 @@
+use crate::shared::extractors::file_handler::{
+    FileHandler, content_addressed_key, content_type_from_file_name,
+};
+use crate::shared::types::image_variants::ImageVariant;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat};
+use std::fmt::Display;
+use std::io::Cursor;
+
+/// Images larger than this in either dimension are scaled down to fit
+pub const MAXIMUM_IMAGE_DIMENSION: u32 = 2048;
+const JPEG_QUALITY: u8 = 85;
+
+#[derive(Debug, PartialEq)]
+pub enum ImageUploadError {
+    EmptyFile,
+    UnsupportedImageFormat,
+    UndecodableImage,
+    FileTooLarge,
+    StorageQuotaExceeded,
+    SystemIOError,
+}
+impl Display for ImageUploadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            ImageUploadError::EmptyFile => "Empty file",
+            ImageUploadError::UnsupportedImageFormat => {
+                "Unsupported image format, expected png, jpeg, gif or webp"
+            }
+            ImageUploadError::UndecodableImage => "Image could not be read",
+            ImageUploadError::FileTooLarge => "Image too large",
+            ImageUploadError::StorageQuotaExceeded => "Storage quota exceeded",
+            ImageUploadError::SystemIOError => "System IO error",
+        };
+        write!(f, "{}", str)
+    }
+}
+
+/// Identifies the format of an image from its leading bytes rather than trusting the file
+/// extension
+fn detect_image_format(data: &[u8]) -> Option<ImageFormat> {
+    if data.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        Some(ImageFormat::Png)
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some(ImageFormat::Jpeg)
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some(ImageFormat::Gif)
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        Some(ImageFormat::WebP)
+    } else {
+        None
+    }
+}
+
+/// Encodes the image from its pixels alone, which drops any EXIF or GPS metadata the upload
+/// carried
+fn encode_image(image: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, ImageUploadError> {
+    let mut buffer = Cursor::new(Vec::new());
+    let encode_result = match format {
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut buffer, JPEG_QUALITY)),
+        _ => image.write_to(&mut buffer, ImageFormat::Png),
+    };
+    encode_result.map_err(|err| {
+        tracing::error!("unable to encode image: {}", err);
+        ImageUploadError::SystemIOError
+    })?;
+    Ok(buffer.into_inner())
+}
+
+struct ProcessedImage {
+    extension: &'static str,
+    image: Vec<u8>,
+    variants: Vec<(ImageVariant, Vec<u8>)>,
+}
+
+fn process_image(data: &[u8]) -> Result<ProcessedImage, ImageUploadError> {
+    let format = detect_image_format(data).ok_or(ImageUploadError::UnsupportedImageFormat)?;
+    let mut image = image::load_from_memory_with_format(data, format).map_err(|err| {
+        tracing::warn!("unable to decode uploaded image: {}", err);
+        ImageUploadError::UndecodableImage
+    })?;
+    if image.width() > MAXIMUM_IMAGE_DIMENSION || image.height() > MAXIMUM_IMAGE_DIMENSION {
+        image = image.resize(
+            MAXIMUM_IMAGE_DIMENSION,
+            MAXIMUM_IMAGE_DIMENSION,
+            FilterType::Lanczos3,
+        );
+    }
+    // Photographs stay lossy, whereas everything else is stored losslessly
+    let (output_format, extension) = match format {
+        ImageFormat::Jpeg => (ImageFormat::Jpeg, "jpg"),
+        _ => (ImageFormat::Png, "png"),
+    };
+    let variants = ImageVariant::ALL
+        .iter()
+        .map(|variant| {
+            let (width, height) = variant.dimensions();
+            encode_image(
+                &image.resize_to_fill(width, height, FilterType::Lanczos3),
+                output_format,
+            )
+            .map(|encoded_variant| (*variant, encoded_variant))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(ProcessedImage {
+        extension,
+        image: encode_image(&image, output_format)?,
+        variants,
+    })
+}
+
+impl FileHandler {
+    /// Validates and re-encodes an uploaded image, storing it alongside each of its variants and
+    /// returning the url of the full sized image
+    pub async fn save_image(&self, data: &[u8]) -> Result<String, ImageUploadError> {
+        if data.is_empty() {
+            return Err(ImageUploadError::EmptyFile);
+        } else if data.len() as u64 > self.limits.maximum_image_size {
+            return Err(ImageUploadError::FileTooLarge);
+        }
+        let data = data.to_vec();
+        // Decoding and resizing is CPU bound, so it is kept off the async worker threads
+        let processed_image = tokio::task::spawn_blocking(move || process_image(&data))
+            .await
+            .map_err(|err| {
+                tracing::error!("image processing task failed: {}", err);
+                ImageUploadError::SystemIOError
+            })??;
+
+        let total_size = processed_image.image.len()
+            + processed_image
+                .variants
+                .iter()
+                .map(|(_variant, encoded_variant)| encoded_variant.len())
+                .sum::<usize>();
+        let is_within_quota = self
+            .is_within_quota(total_size as u64)
+            .await
+            .map_err(|_| ImageUploadError::SystemIOError)?;
+        if !is_within_quota {
+            tracing::warn!("image upload rejected as the storage quota has been reached");
+            return Err(ImageUploadError::StorageQuotaExceeded);
+        }
+
+        // Variants are named after the full sized image, so only its contents are hashed
+        let key = content_addressed_key(&processed_image.image, processed_image.extension);
+        for (variant, encoded_variant) in processed_image.variants {
+            self.store_image(&variant.file_name(&key), encoded_variant)
+                .await?;
+        }
+        self.store_image(&key, processed_image.image).await?;
+        Ok(self.url_from_key(&key))
+    }
+    async fn store_image(&self, key: &str, data: Vec<u8>) -> Result<(), ImageUploadError> {
+        self.storage
+            .put_object(key, data, content_type_from_file_name(key))
+            .await
+            .map_err(|err| {
+                tracing::error!("unable to upload image: {}\nerror: {:?}", key, err);
+                ImageUploadError::SystemIOError
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::extractors::file_handler::UPLOADS_ENDPOINT;
+    use crate::shared::services::file_storage::providers::FileStorageProvider;
+    use crate::shared::services::file_storage::settings::UploadLimits;
+    use crate::shared::services::file_storage::r#trait::new_file_storage;
+
+    fn encode_test_image(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+        let mut buffer = Cursor::new(Vec::new());
+        DynamicImage::new_rgb8(width, height)
+            .write_to(&mut buffer, format)
+            .unwrap();
+        buffer.into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_file_handler_save_image() {
+        struct TestCase {
+            description: &'static str,
+            file_data: Vec<u8>,
+            /// The extension the image should be stored with when it is accepted
+            expected_result: Result<&'static str, ImageUploadError>,
+            expected_dimensions: Option<(u32, u32)>,
+        }
+        let mut truncated_png = encode_test_image(10, 10, ImageFormat::Png);
+        truncated_png.truncate(20);
+        // An APP1 segment carrying EXIF data, placed straight after the JPEG start of image marker
+        let jpeg = encode_test_image(10, 10, ImageFormat::Jpeg);
+        let mut jpeg_with_exif = vec![0xFF, 0xD8, 0xFF, 0xE1, 0x00, 0x10];
+        jpeg_with_exif.extend_from_slice(b"Exif\0\0GPSDATA!");
+        jpeg_with_exif.extend_from_slice(&jpeg[2..]);
+
+        let test_cases = [
+            TestCase {
+                description: "When the file is empty, I should get an empty file error",
+                file_data: vec![],
+                expected_result: Err(ImageUploadError::EmptyFile),
+                expected_dimensions: None,
+            },
+            TestCase {
+                description: "When the file is not an image despite its extension, I should get an unsupported image format error",
+                file_data: b"name,age\nZayd,30".to_vec(),
+                expected_result: Err(ImageUploadError::UnsupportedImageFormat),
+                expected_dimensions: None,
+            },
+            TestCase {
+                description: "When the image is corrupt, I should get an undecodable image error",
+                file_data: truncated_png,
+                expected_result: Err(ImageUploadError::UndecodableImage),
+                expected_dimensions: None,
+            },
+            TestCase {
+                description: "When the upload exceeds the size limit for images, I should get a file too large error",
+                file_data: vec![0xFF; (1 << 16) + 1],
+                expected_result: Err(ImageUploadError::FileTooLarge),
+                expected_dimensions: None,
+            },
+            TestCase {
+                description: "When the image is too large, it should be scaled down to the maximum dimension",
+                file_data: encode_test_image(4096, 1024, ImageFormat::Png),
+                expected_result: Ok("png"),
+                expected_dimensions: Some((2048, 512)),
+            },
+            TestCase {
+                description: "When the image is a photograph with metadata, it should be stored as a jpeg without the metadata",
+                file_data: jpeg_with_exif,
+                expected_result: Ok("jpg"),
+                expected_dimensions: Some((10, 10)),
+            },
+        ];
+        let upload_directory = std::env::temp_dir().join("image_uploader_tests");
+        let file_handler = FileHandler::new(
+            new_file_storage(FileStorageProvider::Local {
+                directory: upload_directory.clone(),
+            }),
+            UploadLimits {
+                maximum_image_size: 1 << 16,
+                ..UploadLimits::default()
+            },
+            UPLOADS_ENDPOINT.to_owned(),
+        );
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let actual_result = file_handler.save_image(&test_case.file_data).await;
+            assert_eq!(
+                test_case.expected_result.as_deref(),
+                actual_result
+                    .as_ref()
+                    .map(|url| url.rsplit_once('.').unwrap().1)
+            );
+            let Some(expected_dimensions) = test_case.expected_dimensions else {
+                continue;
+            };
+            let stored_file_name = actual_result.unwrap().replace("/uploads/", "");
+            let stored_image = std::fs::read(upload_directory.join(&stored_file_name)).unwrap();
+            assert_eq!(
+                content_addressed_key(&stored_image, test_case.expected_result.unwrap()),
+                stored_file_name
+            );
+            assert!(!stored_image.windows(4).any(|window| window == b"Exif"));
+            let decoded_image = image::load_from_memory(&stored_image).unwrap();
+            assert_eq!(
+                expected_dimensions,
+                (decoded_image.width(), decoded_image.height())
+            );
+            for variant in ImageVariant::ALL {
+                let decoded_variant =
+                    image::open(upload_directory.join(variant.file_name(&stored_file_name)))
+                        .unwrap();
+                assert_eq!(
+                    variant.dimensions(),
+                    (decoded_variant.width(), decoded_variant.height())
+                );
+            }
+        }
+    }
+}
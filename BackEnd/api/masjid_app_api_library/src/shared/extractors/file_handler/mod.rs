@@ -1,10 +1,20 @@
-use axum::extract::FromRequestParts;
-use axum::http::StatusCode;
+use crate::shared::services::file_storage::errors::FileStorageError;
+use crate::shared::services::file_storage::providers::FileStorageProvider;
+use crate::shared::services::file_storage::settings::UploadLimits;
+use crate::shared::services::file_storage::r#trait::{FileStorage, new_file_storage};
+use axum::extract::{FromRef, FromRequestParts};
 use axum::http::request::Parts;
-use std::path::{Path, PathBuf};
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+use std::path::Path;
+use std::sync::Arc;
 
 pub mod file_deleter;
 pub mod file_uploader;
+pub mod image_uploader;
+
+/// The route uploaded files are served from, which prefixes every url returned by `save_file`
+pub const UPLOADS_ENDPOINT: &'static str = "uploads";
 #[derive(Debug, Eq, PartialEq)]
 enum FilePathSafetyError {
     EmptyPath,
@@ -22,38 +32,86 @@ fn file_path_is_safe(path: impl AsRef<Path>) -> Result<(), FilePathSafetyError>
     }
     Err(FilePathSafetyError::EmptyPath)
 }
-// FileHandler is responsible for uploading and deleting files from the web server
-#[derive(Clone, Default)]
+/// Names an object after a hash of its contents, so identical uploads share one object and
+/// different uploads can never overwrite each other
+fn content_addressed_key(data: &[u8], extension: &str) -> String {
+    format!("{:x}.{}", Sha256::digest(data), extension)
+}
+pub fn content_type_from_file_name(file_name: &str) -> &'static str {
+    match file_name
+        .rsplit_once('.')
+        .map(|(_stem, extension)| extension)
+    {
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("json") => "application/json",
+        Some("csv") => "text/csv",
+        _ => "application/octet-stream",
+    }
+}
+// FileHandler is responsible for uploading and deleting files from the configured file storage
+#[derive(Clone)]
 pub struct FileHandler {
-    upload_dir: PathBuf,
+    storage: Arc<dyn FileStorage>,
+    limits: UploadLimits,
     endpoint: String,
 }
 impl FileHandler {
-    pub fn new(upload_directory: impl AsRef<Path>, endpoint: String) -> Self {
+    pub fn new(storage: Arc<dyn FileStorage>, limits: UploadLimits, endpoint: String) -> Self {
         Self {
-            upload_dir: upload_directory.as_ref().to_path_buf(),
-            endpoint: endpoint,
+            storage,
+            limits,
+            endpoint,
         }
     }
+    pub fn from_environment() -> Self {
+        Self::new(
+            new_file_storage(FileStorageProvider::from_environment()),
+            UploadLimits::from_environment(),
+            UPLOADS_ENDPOINT.to_owned(),
+        )
+    }
+    pub async fn get_file(&self, file_name: &str) -> Result<Vec<u8>, FileStorageError> {
+        self.storage.get_object(file_name).await
+    }
+    fn url_from_key(&self, key: &str) -> String {
+        format!("/{}/{}", &self.endpoint, key)
+    }
+    /// Maps a url returned by `save_file` back to the key of the object it points to, returning
+    /// `None` for urls that were not issued by this handler
+    fn key_from_url<'a>(&self, url: &'a str) -> Option<&'a str> {
+        let key = url
+            .strip_prefix('/')?
+            .strip_prefix(self.endpoint.as_str())?
+            .strip_prefix('/')?;
+        if key.is_empty() || key.contains('/') {
+            return None;
+        }
+        Some(key)
+    }
+    /// Whether storing another `size` bytes would stay within the storage quota
+    async fn is_within_quota(&self, size: u64) -> Result<bool, FileStorageError> {
+        let total_size = self.storage.total_size().await?;
+        Ok(total_size.saturating_add(size) <= self.limits.storage_quota)
+    }
 }
 impl<S> FromRequestParts<S> for FileHandler
 where
+    FileHandler: FromRef<S>,
     S: Send + Sync,
 {
-    type Rejection = (StatusCode, String);
+    type Rejection = Infallible;
 
-    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        Ok(Self::default())
-        // TODO - Fix file handler implementation
-        /*parts.extensions.get::<FileHandler>().cloned().ok_or((
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "FileUploader not configured".into(),
-        ))*/
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::from_ref(state))
     }
 }
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::shared::services::file_storage::r#trait::MockFileStorage;
     use std::path::Path;
     #[test]
     fn test_file_is_safe() {
@@ -83,4 +141,94 @@ mod test {
             assert_eq!(test_case.expected_result, actual_result,)
         }
     }
+    #[test]
+    fn test_key_from_url() {
+        struct TestCase {
+            description: &'static str,
+            url: &'static str,
+            expected_result: Option<&'static str>,
+        }
+        let file_handler = FileHandler::new(
+            Arc::new(MockFileStorage::new()),
+            UploadLimits::default(),
+            UPLOADS_ENDPOINT.to_owned(),
+        );
+        let test_cases = [
+            TestCase {
+                description: "When the url was issued by the handler, I should get the key of the object",
+                url: "/uploads/poster.png",
+                expected_result: Some("poster.png"),
+            },
+            TestCase {
+                description: "When the url points to a different endpoint, I should get nothing",
+                url: "/images/poster.png",
+                expected_result: None,
+            },
+            TestCase {
+                description: "When the url points to a nested path, I should get nothing",
+                url: "/uploads/nested/poster.png",
+                expected_result: None,
+            },
+            TestCase {
+                description: "When the url has no file name, I should get nothing",
+                url: "/uploads/",
+                expected_result: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_result,
+                file_handler.key_from_url(test_case.url)
+            );
+        }
+    }
+    #[tokio::test]
+    async fn test_is_within_quota() {
+        struct TestCase {
+            description: &'static str,
+            stored_size: Result<u64, FileStorageError>,
+            upload_size: u64,
+            expected_result: Result<bool, FileStorageError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the upload fits within the quota, I should be allowed to store it",
+                stored_size: Ok(90),
+                upload_size: 10,
+                expected_result: Ok(true),
+            },
+            TestCase {
+                description: "When the upload exceeds the quota, I should not be allowed to store it",
+                stored_size: Ok(91),
+                upload_size: 10,
+                expected_result: Ok(false),
+            },
+            TestCase {
+                description: "When the stored size cannot be determined, I should get an error",
+                stored_size: Err(FileStorageError::UnableToRetrieveObject),
+                upload_size: 10,
+                expected_result: Err(FileStorageError::UnableToRetrieveObject),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_storage = MockFileStorage::new();
+            mock_storage
+                .expect_total_size()
+                .return_once(move || test_case.stored_size);
+            let file_handler = FileHandler::new(
+                Arc::new(mock_storage),
+                UploadLimits {
+                    storage_quota: 100,
+                    ..UploadLimits::default()
+                },
+                UPLOADS_ENDPOINT.to_owned(),
+            );
+            assert_eq!(
+                test_case.expected_result,
+                file_handler.is_within_quota(test_case.upload_size).await
+            );
+        }
+    }
 }
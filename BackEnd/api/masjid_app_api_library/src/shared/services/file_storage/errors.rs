@@ -0,0 +1,8 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileStorageError {
+    InvalidKey,
+    ObjectNotFound,
+    UnableToStoreObject,
+    UnableToRetrieveObject,
+    UnableToDeleteObject,
+}
@@ -0,0 +1,136 @@
+use crate::shared::services::file_storage::errors::FileStorageError;
+use crate::shared::services::file_storage::is_valid_object_key;
+use crate::shared::services::file_storage::r#trait::FileStorage;
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+pub(super) struct LocalFileStorage {
+    pub(super) directory: PathBuf,
+}
+
+impl LocalFileStorage {
+    fn object_path(&self, key: &str) -> Result<PathBuf, FileStorageError> {
+        if !is_valid_object_key(key) {
+            tracing::warn!(key = key, "rejected invalid object key");
+            return Err(FileStorageError::InvalidKey);
+        }
+        Ok(self.directory.join(key))
+    }
+}
+
+#[async_trait]
+impl FileStorage for LocalFileStorage {
+    async fn put_object(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        _content_type: &str,
+    ) -> Result<(), FileStorageError> {
+        let path = self.object_path(key)?;
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|err| {
+                tracing::error!(error = err.to_string(), "unable to create upload directory");
+                FileStorageError::UnableToStoreObject
+            })?;
+        tokio::fs::write(&path, data).await.map_err(|err| {
+            tracing::error!(error = err.to_string(), key = key, "unable to write object");
+            FileStorageError::UnableToStoreObject
+        })
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>, FileStorageError> {
+        let path = self.object_path(key)?;
+        tokio::fs::read(&path).await.map_err(|err| match err.kind() {
+            ErrorKind::NotFound => FileStorageError::ObjectNotFound,
+            _ => {
+                tracing::error!(error = err.to_string(), key = key, "unable to read object");
+                FileStorageError::UnableToRetrieveObject
+            }
+        })
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), FileStorageError> {
+        let path = self.object_path(key)?;
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(|err| match err.kind() {
+                ErrorKind::NotFound => FileStorageError::ObjectNotFound,
+                _ => {
+                    tracing::error!(error = err.to_string(), key = key, "unable to delete object");
+                    FileStorageError::UnableToDeleteObject
+                }
+            })
+    }
+
+    async fn total_size(&self) -> Result<u64, FileStorageError> {
+        let mut entries = match tokio::fs::read_dir(&self.directory).await {
+            Ok(entries) => entries,
+            // Nothing has been uploaded yet
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(err) => {
+                tracing::error!(error = err.to_string(), "unable to read upload directory");
+                return Err(FileStorageError::UnableToRetrieveObject);
+            }
+        };
+        let mut total_size = 0;
+        while let Some(entry) = entries.next_entry().await.map_err(|err| {
+            tracing::error!(error = err.to_string(), "unable to read upload directory");
+            FileStorageError::UnableToRetrieveObject
+        })? {
+            if let Ok(metadata) = entry.metadata().await
+                && metadata.is_file()
+            {
+                total_size += metadata.len();
+            }
+        }
+        Ok(total_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_file_storage() {
+        let directory = std::env::temp_dir().join("masjid_app_local_file_storage_test");
+        let _ = std::fs::remove_dir_all(&directory);
+        let storage = LocalFileStorage {
+            directory: directory.clone(),
+        };
+
+        // Given nothing has been uploaded, the total size should be zero
+        assert_eq!(Ok(0), storage.total_size().await);
+
+        // When the key could be interpreted as a path, I should get an invalid key error
+        assert_eq!(
+            Err(FileStorageError::InvalidKey),
+            storage
+                .put_object("../poster.png", vec![1], "image/png")
+                .await
+        );
+
+        // When I store an object, I should be able to retrieve it
+        assert_eq!(
+            Ok(()),
+            storage
+                .put_object("poster.png", vec![1, 2, 3], "image/png")
+                .await
+        );
+        assert_eq!(Ok(vec![1, 2, 3]), storage.get_object("poster.png").await);
+        assert_eq!(Ok(3), storage.total_size().await);
+
+        // When I delete an object, it should no longer be retrievable
+        assert_eq!(Ok(()), storage.delete_object("poster.png").await);
+        assert_eq!(
+            Err(FileStorageError::ObjectNotFound),
+            storage.get_object("poster.png").await
+        );
+        assert_eq!(
+            Err(FileStorageError::ObjectNotFound),
+            storage.delete_object("poster.png").await
+        );
+    }
+}
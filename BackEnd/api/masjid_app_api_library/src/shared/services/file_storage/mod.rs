@@ -0,0 +1,19 @@
+pub mod errors;
+mod local_impl;
+pub mod providers;
+mod s3_impl;
+pub mod settings;
+pub mod r#trait;
+
+/// Keys are used as file names by the local implementation, so anything that could be
+/// interpreted as a path is rejected
+fn is_valid_object_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.contains("..")
+        && key.chars().all(|character| {
+            character.is_ascii_alphanumeric()
+                || character == '.'
+                || character == '-'
+                || character == '_'
+        })
+}
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+pub const FILE_STORAGE_PROVIDER: &'static str = "FILE_STORAGE_PROVIDER";
+pub const UPLOAD_DIRECTORY: &'static str = "UPLOAD_DIRECTORY";
+pub const S3_BUCKET: &'static str = "S3_BUCKET";
+pub const S3_REGION: &'static str = "S3_REGION";
+pub const S3_ENDPOINT: &'static str = "S3_ENDPOINT";
+pub const S3_ACCESS_KEY_ID: &'static str = "S3_ACCESS_KEY_ID";
+pub const S3_SECRET_ACCESS_KEY: &'static str = "S3_SECRET_ACCESS_KEY";
+
+pub struct S3Settings {
+    pub bucket: String,
+    pub region: String,
+    /// Any S3 compatible endpoint, such as MinIO, may be used in place of AWS
+    pub endpoint: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+pub enum FileStorageProvider {
+    Local { directory: PathBuf },
+    S3 { settings: S3Settings },
+}
+
+impl FileStorageProvider {
+    /// Selects the storage provider from the environment, falling back to the local filesystem
+    /// when S3 has not been explicitly configured
+    pub fn from_environment() -> Self {
+        match std::env::var(FILE_STORAGE_PROVIDER).as_deref() {
+            Ok("S3") => Self::S3 {
+                settings: S3Settings {
+                    bucket: std::env::var(S3_BUCKET).unwrap(),
+                    region: std::env::var(S3_REGION).unwrap_or_else(|_| "us-east-1".to_owned()),
+                    endpoint: std::env::var(S3_ENDPOINT).unwrap(),
+                    access_key_id: std::env::var(S3_ACCESS_KEY_ID).unwrap(),
+                    secret_access_key: std::env::var(S3_SECRET_ACCESS_KEY).unwrap(),
+                },
+            },
+            _ => Self::Local {
+                directory: std::env::var(UPLOAD_DIRECTORY)
+                    .unwrap_or_else(|_| "./uploads".to_owned())
+                    .into(),
+            },
+        }
+    }
+}
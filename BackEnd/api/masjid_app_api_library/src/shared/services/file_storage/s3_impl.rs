@@ -0,0 +1,140 @@
+use crate::shared::services::file_storage::errors::FileStorageError;
+use crate::shared::services::file_storage::is_valid_object_key;
+use crate::shared::services::file_storage::providers::S3Settings;
+use crate::shared::services::file_storage::r#trait::FileStorage;
+use async_trait::async_trait;
+use s3::Region;
+use s3::bucket::Bucket;
+use s3::creds::Credentials;
+use s3::error::S3Error;
+
+const NOT_FOUND_STATUS_CODE: u16 = 404;
+
+pub(super) struct S3FileStorage {
+    bucket: Box<Bucket>,
+}
+
+impl S3FileStorage {
+    pub(super) fn new(settings: S3Settings) -> Self {
+        let region = Region::Custom {
+            region: settings.region,
+            endpoint: settings.endpoint,
+        };
+        let credentials = Credentials::new(
+            Some(&settings.access_key_id),
+            Some(&settings.secret_access_key),
+            None,
+            None,
+            None,
+        )
+        .expect("S3 credentials should be valid");
+        // Path style addressing is required by most S3 compatible stores, including MinIO
+        let bucket = Bucket::new(&settings.bucket, region, credentials)
+            .expect("S3 bucket settings should be valid")
+            .with_path_style();
+        Self { bucket }
+    }
+}
+
+fn is_success(status_code: u16) -> bool {
+    (200..300).contains(&status_code)
+}
+
+#[async_trait]
+impl FileStorage for S3FileStorage {
+    async fn put_object(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        content_type: &str,
+    ) -> Result<(), FileStorageError> {
+        if !is_valid_object_key(key) {
+            return Err(FileStorageError::InvalidKey);
+        }
+        match self
+            .bucket
+            .put_object_with_content_type(key, &data, content_type)
+            .await
+        {
+            Ok(response) if is_success(response.status_code()) => Ok(()),
+            Ok(response) => {
+                tracing::error!(
+                    status_code = response.status_code(),
+                    key = key,
+                    "unable to put object"
+                );
+                Err(FileStorageError::UnableToStoreObject)
+            }
+            Err(err) => {
+                tracing::error!(error = err.to_string(), key = key, "unable to put object");
+                Err(FileStorageError::UnableToStoreObject)
+            }
+        }
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>, FileStorageError> {
+        if !is_valid_object_key(key) {
+            return Err(FileStorageError::InvalidKey);
+        }
+        match self.bucket.get_object(key).await {
+            Ok(response) if is_success(response.status_code()) => Ok(response.bytes().to_vec()),
+            Ok(response) if response.status_code() == NOT_FOUND_STATUS_CODE => {
+                Err(FileStorageError::ObjectNotFound)
+            }
+            Err(S3Error::HttpFailWithBody(NOT_FOUND_STATUS_CODE, _)) => {
+                Err(FileStorageError::ObjectNotFound)
+            }
+            Ok(response) => {
+                tracing::error!(
+                    status_code = response.status_code(),
+                    key = key,
+                    "unable to get object"
+                );
+                Err(FileStorageError::UnableToRetrieveObject)
+            }
+            Err(err) => {
+                tracing::error!(error = err.to_string(), key = key, "unable to get object");
+                Err(FileStorageError::UnableToRetrieveObject)
+            }
+        }
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<(), FileStorageError> {
+        if !is_valid_object_key(key) {
+            return Err(FileStorageError::InvalidKey);
+        }
+        // S3 reports success when deleting a key that does not exist, so ObjectNotFound is never
+        // returned here
+        match self.bucket.delete_object(key).await {
+            Ok(response) if is_success(response.status_code()) => Ok(()),
+            Ok(response) => {
+                tracing::error!(
+                    status_code = response.status_code(),
+                    key = key,
+                    "unable to delete object"
+                );
+                Err(FileStorageError::UnableToDeleteObject)
+            }
+            Err(err) => {
+                tracing::error!(error = err.to_string(), key = key, "unable to delete object");
+                Err(FileStorageError::UnableToDeleteObject)
+            }
+        }
+    }
+
+    async fn total_size(&self) -> Result<u64, FileStorageError> {
+        let pages = self
+            .bucket
+            .list(String::new(), None)
+            .await
+            .map_err(|err| {
+                tracing::error!(error = err.to_string(), "unable to list objects");
+                FileStorageError::UnableToRetrieveObject
+            })?;
+        Ok(pages
+            .iter()
+            .flat_map(|page| page.contents.iter())
+            .map(|object| object.size)
+            .sum())
+    }
+}
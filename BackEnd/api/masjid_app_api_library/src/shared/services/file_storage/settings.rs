@@ -0,0 +1,44 @@
+pub const MAXIMUM_IMAGE_SIZE_IN_BYTES: &'static str = "MAXIMUM_IMAGE_SIZE_IN_BYTES";
+pub const MAXIMUM_DOCUMENT_SIZE_IN_BYTES: &'static str = "MAXIMUM_DOCUMENT_SIZE_IN_BYTES";
+pub const UPLOAD_QUOTA_IN_BYTES: &'static str = "UPLOAD_QUOTA_IN_BYTES";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UploadLimits {
+    /// Applies to the image as uploaded, before it is resized
+    pub maximum_image_size: u64,
+    pub maximum_document_size: u64,
+    /// The combined size of every stored object, including image variants
+    pub storage_quota: u64,
+}
+
+impl Default for UploadLimits {
+    fn default() -> Self {
+        Self {
+            maximum_image_size: 10 * 1024 * 1024,
+            maximum_document_size: 2 * 1024 * 1024,
+            storage_quota: 5 * 1024 * 1024 * 1024,
+        }
+    }
+}
+
+impl UploadLimits {
+    /// Reads each limit from the environment, falling back to the default for any that are not
+    /// set
+    pub fn from_environment() -> Self {
+        let defaults = Self::default();
+        let read_limit = |key: &str, default: u64| {
+            std::env::var(key)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default)
+        };
+        Self {
+            maximum_image_size: read_limit(MAXIMUM_IMAGE_SIZE_IN_BYTES, defaults.maximum_image_size),
+            maximum_document_size: read_limit(
+                MAXIMUM_DOCUMENT_SIZE_IN_BYTES,
+                defaults.maximum_document_size,
+            ),
+            storage_quota: read_limit(UPLOAD_QUOTA_IN_BYTES, defaults.storage_quota),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::shared::services::file_storage::errors::FileStorageError;
+use crate::shared::services::file_storage::local_impl::LocalFileStorage;
+use crate::shared::services::file_storage::providers::FileStorageProvider;
+use crate::shared::services::file_storage::s3_impl::S3FileStorage;
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait FileStorage: Send + Sync {
+    async fn put_object(
+        &self,
+        key: &str,
+        data: Vec<u8>,
+        content_type: &str,
+    ) -> Result<(), FileStorageError>;
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>, FileStorageError>;
+    async fn delete_object(&self, key: &str) -> Result<(), FileStorageError>;
+    /// The combined size in bytes of every stored object
+    async fn total_size(&self) -> Result<u64, FileStorageError>;
+}
+
+pub fn new_file_storage(file_storage_provider: FileStorageProvider) -> Arc<dyn FileStorage> {
+    match file_storage_provider {
+        FileStorageProvider::Local { directory } => Arc::new(LocalFileStorage { directory }),
+        FileStorageProvider::S3 { settings } => Arc::new(S3FileStorage::new(settings)),
+    }
+}
@@ -23,6 +23,20 @@ impl From<BcryptError> for HashError {
     }
 }
 
+impl From<argon2::password_hash::Error> for HashError {
+    #[inline]
+    fn from(_: argon2::password_hash::Error) -> Self {
+        Self::UnknownError
+    }
+}
+
+impl From<argon2::Error> for HashError {
+    #[inline]
+    fn from(_: argon2::Error) -> Self {
+        Self::UnknownError
+    }
+}
+
 impl From<BcryptError> for VerifyHashError {
     #[inline]
     fn from(value: BcryptError) -> Self {
@@ -33,3 +47,22 @@ impl From<BcryptError> for VerifyHashError {
         }
     }
 }
+
+impl From<argon2::password_hash::Error> for VerifyHashError {
+    #[inline]
+    fn from(value: argon2::password_hash::Error) -> Self {
+        match value {
+            argon2::password_hash::Error::Algorithm
+            | argon2::password_hash::Error::B64Encoding(_)
+            | argon2::password_hash::Error::ParamNameDuplicated
+            | argon2::password_hash::Error::ParamNameInvalid
+            | argon2::password_hash::Error::ParamValueInvalid(_)
+            | argon2::password_hash::Error::ParamsMaxExceeded
+            | argon2::password_hash::Error::PhcStringField
+            | argon2::password_hash::Error::PhcStringTrailingData
+            | argon2::password_hash::Error::SaltInvalid(_)
+            | argon2::password_hash::Error::Version => Self::HashMalformed,
+            _ => Self::UnknownError,
+        }
+    }
+}
@@ -1,20 +1,195 @@
 use crate::shared::services::hashing::errors::{HashError, VerifyHashError};
+use crate::shared::services::hashing::providers::{Argon2Parameters, HashingProvider};
 use crate::shared::services::hashing::r#trait::HashingService;
-use bcrypt::{BcryptError, BcryptResult};
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use bcrypt::{BcryptError, HashParts};
+
+const BCRYPT_COST: u32 = 12;
+
+pub(super) struct HashingServiceImpl {
+    pub(super) hashing_provider: HashingProvider,
+}
+
+fn is_argon2_hash(hash: &str) -> bool {
+    hash.starts_with("$argon2")
+}
+
+// Covers the $2a$, $2b$ and $2y$ bcrypt variants
+fn is_bcrypt_hash(hash: &str) -> bool {
+    hash.starts_with("$2")
+}
+
+fn new_argon2id(parameters: &Argon2Parameters) -> Result<Argon2<'static>, argon2::Error> {
+    let params = Params::new(
+        parameters.memory_cost,
+        parameters.iterations,
+        parameters.parallelism,
+        None,
+    )?;
+    Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+}
 
-pub(super) struct HashingServiceImpl;
 impl HashingService for HashingServiceImpl {
     fn hash(&self, input: &[u8]) -> Result<String, HashError> {
-        bcrypt::hash(input, 12).map_err(|err| {
-            if let BcryptError::Truncation(input_len) = err {
-                return HashError::InputTooLarge(input_len);
+        match &self.hashing_provider {
+            HashingProvider::Bcrypt => bcrypt::hash(input, BCRYPT_COST).map_err(|err| {
+                if let BcryptError::Truncation(input_len) = err {
+                    return HashError::InputTooLarge(input_len);
+                }
+                tracing::error!(error = ?err, "Hashing failed");
+                HashError::UnknownError
+            }),
+            HashingProvider::Argon2id(parameters) => {
+                let salt = SaltString::generate(&mut OsRng);
+                let hash = new_argon2id(parameters)
+                    .map_err(HashError::from)?
+                    .hash_password(input, &salt)
+                    .map_err(|err| {
+                        tracing::error!(error = ?err, "Hashing failed");
+                        HashError::from(err)
+                    })?;
+                Ok(hash.to_string())
             }
-            tracing::error!(error = ?err, "Hashing failed");
-            HashError::UnknownError
-        })
+        }
     }
 
     fn verify_hash(&self, input: &[u8], expected_hash: &str) -> Result<bool, VerifyHashError> {
-        bcrypt::verify(input, expected_hash).map_err(VerifyHashError::from)
+        // The algorithm is taken from the hash rather than the provider, so that hashes created
+        // before a change of provider can still be verified
+        if is_argon2_hash(expected_hash) {
+            let parsed_hash = PasswordHash::new(expected_hash)?;
+            return match Argon2::default().verify_password(input, &parsed_hash) {
+                Ok(()) => Ok(true),
+                Err(argon2::password_hash::Error::Password) => Ok(false),
+                Err(err) => Err(VerifyHashError::from(err)),
+            };
+        }
+        if is_bcrypt_hash(expected_hash) {
+            return bcrypt::verify(input, expected_hash).map_err(VerifyHashError::from);
+        }
+        Err(VerifyHashError::HashMalformed)
+    }
+
+    fn needs_rehash(&self, hash: &str) -> bool {
+        match &self.hashing_provider {
+            HashingProvider::Bcrypt => hash
+                .parse::<HashParts>()
+                .map_or(true, |hash_parts| hash_parts.get_cost() != BCRYPT_COST),
+            HashingProvider::Argon2id(parameters) => {
+                let Ok(parsed_hash) = PasswordHash::new(hash) else {
+                    return true;
+                };
+                let Ok(params) = Params::try_from(&parsed_hash) else {
+                    return true;
+                };
+                parsed_hash.algorithm != argon2::ARGON2ID_IDENT
+                    || parsed_hash.version != Some(Version::V0x13.into())
+                    || params.m_cost() != parameters.memory_cost
+                    || params.t_cost() != parameters.iterations
+                    || params.p_cost() != parameters.parallelism
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Kept small so that the tests run quickly
+    const TEST_ARGON2_PARAMETERS: Argon2Parameters = Argon2Parameters {
+        memory_cost: 1024,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    #[test]
+    fn test_argon2id_hash_and_verify() {
+        let service = HashingServiceImpl {
+            hashing_provider: HashingProvider::Argon2id(TEST_ARGON2_PARAMETERS),
+        };
+        let hash = service.hash(b"password").unwrap();
+        assert!(hash.starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+        assert!(matches!(service.verify_hash(b"password", &hash), Ok(true)));
+        assert!(matches!(service.verify_hash(b"wrong", &hash), Ok(false)));
+        assert!(!service.needs_rehash(&hash));
+    }
+
+    #[test]
+    fn test_verify_hash_accepts_both_algorithms() {
+        let bcrypt_hash = bcrypt::hash(b"password", 4).unwrap();
+        let argon2_service = HashingServiceImpl {
+            hashing_provider: HashingProvider::Argon2id(TEST_ARGON2_PARAMETERS),
+        };
+        let argon2_hash = argon2_service.hash(b"password").unwrap();
+        let bcrypt_service = HashingServiceImpl {
+            hashing_provider: HashingProvider::Bcrypt,
+        };
+        assert!(matches!(argon2_service.verify_hash(b"password", &bcrypt_hash), Ok(true)));
+        assert!(matches!(bcrypt_service.verify_hash(b"password", &argon2_hash), Ok(true)));
+        assert!(matches!(
+            argon2_service.verify_hash(b"password", "not a hash"),
+            Err(VerifyHashError::HashMalformed)
+        ));
+    }
+
+    #[test]
+    fn test_needs_rehash() {
+        struct TestCase {
+            description: &'static str,
+            hashing_provider: HashingProvider,
+            hash: String,
+            expected_result: bool,
+        }
+        let bcrypt_hash = bcrypt::hash(b"password", 4).unwrap();
+        let argon2_hash = HashingServiceImpl {
+            hashing_provider: HashingProvider::Argon2id(TEST_ARGON2_PARAMETERS),
+        }
+        .hash(b"password")
+        .unwrap();
+        let test_cases = [
+            TestCase {
+                description: "When the provider is argon2id and the hash is bcrypt, a rehash should be needed",
+                hashing_provider: HashingProvider::Argon2id(TEST_ARGON2_PARAMETERS),
+                hash: bcrypt_hash.clone(),
+                expected_result: true,
+            },
+            TestCase {
+                description: "When the argon2id parameters have changed, a rehash should be needed",
+                hashing_provider: HashingProvider::Argon2id(Argon2Parameters {
+                    iterations: 2,
+                    ..TEST_ARGON2_PARAMETERS
+                }),
+                hash: argon2_hash.clone(),
+                expected_result: true,
+            },
+            TestCase {
+                description: "When the argon2id parameters are unchanged, no rehash should be needed",
+                hashing_provider: HashingProvider::Argon2id(TEST_ARGON2_PARAMETERS),
+                hash: argon2_hash.clone(),
+                expected_result: false,
+            },
+            TestCase {
+                description: "When the provider is bcrypt and the cost has changed, a rehash should be needed",
+                hashing_provider: HashingProvider::Bcrypt,
+                hash: bcrypt_hash.clone(),
+                expected_result: true,
+            },
+            TestCase {
+                description: "When the provider is bcrypt and the hash is argon2id, a rehash should be needed",
+                hashing_provider: HashingProvider::Bcrypt,
+                hash: argon2_hash.clone(),
+                expected_result: true,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let service = HashingServiceImpl {
+                hashing_provider: test_case.hashing_provider,
+            };
+            assert_eq!(test_case.expected_result, service.needs_rehash(&test_case.hash));
+        }
     }
 }
@@ -1,3 +1,48 @@
+use argon2::Params;
+
+pub const ARGON2_MEMORY_COST: &'static str = "ARGON2_MEMORY_COST";
+pub const ARGON2_ITERATIONS: &'static str = "ARGON2_ITERATIONS";
+pub const ARGON2_PARALLELISM: &'static str = "ARGON2_PARALLELISM";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Argon2Parameters {
+    /// Memory size in KiB
+    pub memory_cost: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Parameters {
+    /// The OWASP recommended minimum for Argon2id
+    fn default() -> Self {
+        Self {
+            memory_cost: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl Argon2Parameters {
+    /// Reads each parameter from the environment, falling back to the default for any that are
+    /// not set
+    pub fn from_environment() -> Self {
+        let defaults = Self::default();
+        let read = |key: &str, default: u32| {
+            std::env::var(key)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default)
+        };
+        Self {
+            memory_cost: read(ARGON2_MEMORY_COST, defaults.memory_cost),
+            iterations: read(ARGON2_ITERATIONS, defaults.iterations),
+            parallelism: read(ARGON2_PARALLELISM, defaults.parallelism),
+        }
+    }
+}
+
 pub enum HashingProvider {
     Bcrypt,
+    Argon2id(Argon2Parameters),
 }
@@ -7,11 +7,12 @@ use std::sync::Arc;
 #[automock]
 pub trait HashingService: Send + Sync {
     fn hash(&self, input: &[u8]) -> Result<String, HashError>;
+    /// Accepts both bcrypt and argon2 PHC-formatted hashes, regardless of the configured provider
     fn verify_hash(&self, input: &[u8], expected_hash: &str) -> Result<bool, VerifyHashError>;
+    /// Returns true when the hash was not created with the configured algorithm and parameters
+    fn needs_rehash(&self, hash: &str) -> bool;
 }
 
 pub fn new_hashing_service(hashing_provider: HashingProvider) -> Arc<dyn HashingService> {
-    match hashing_provider {
-        HashingProvider::Bcrypt => Arc::new(HashingServiceImpl),
-    }
+    Arc::new(HashingServiceImpl { hashing_provider })
 }
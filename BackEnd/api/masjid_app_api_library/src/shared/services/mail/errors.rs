@@ -0,0 +1,23 @@
+use lettre::address::AddressError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SendMailError {
+    InvalidAddress,
+    UnableToBuildMessage,
+    UnableToSend,
+}
+
+impl From<AddressError> for SendMailError {
+    #[inline]
+    fn from(_: AddressError) -> Self {
+        Self::InvalidAddress
+    }
+}
+
+impl From<lettre::error::Error> for SendMailError {
+    #[inline]
+    fn from(value: lettre::error::Error) -> Self {
+        tracing::error!(error = ?value, "unable to build email message");
+        Self::UnableToBuildMessage
+    }
+}
@@ -0,0 +1,65 @@
+use crate::shared::services::mail::errors::SendMailError;
+use crate::shared::services::mail::mail_message::MailMessage;
+use crate::shared::services::mail::r#trait::MailSender;
+use async_trait::async_trait;
+use lettre::{AsyncFileTransport, AsyncTransport, Tokio1Executor};
+use std::path::PathBuf;
+
+pub(super) struct FileMailSender {
+    pub(super) directory: PathBuf,
+    pub(super) from_address: String,
+}
+
+#[async_trait]
+impl MailSender for FileMailSender {
+    async fn send(&self, message: MailMessage) -> Result<(), SendMailError> {
+        tracing::info!(
+            to = message.to,
+            subject = message.subject,
+            body = message.body,
+            "sending email"
+        );
+        let email = message.build(&self.from_address)?;
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|err| {
+                tracing::error!(error = err.to_string(), "unable to create mail directory");
+                SendMailError::UnableToSend
+            })?;
+        let email_id = AsyncFileTransport::<Tokio1Executor>::new(&self.directory)
+            .send(email)
+            .await
+            .map_err(|err| {
+                tracing::error!(error = err.to_string(), "unable to write email to file");
+                SendMailError::UnableToSend
+            })?;
+        tracing::debug!(email_id = email_id, "email written to file");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_send() {
+        let directory = std::env::temp_dir().join("masjid_app_file_mail_sender_test");
+        let sender = FileMailSender {
+            directory: directory.clone(),
+            from_address: "noreply@masjidapp.com".to_owned(),
+        };
+        let send_result = sender
+            .send(MailMessage {
+                to: "user@example.com".to_owned(),
+                subject: "Subject".to_owned(),
+                body: "Body".to_owned(),
+                reply_to: None,
+            })
+            .await;
+        assert!(send_result.is_ok());
+        let written_emails = std::fs::read_dir(&directory).unwrap().count();
+        assert!(written_emails > 0);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,93 @@
+use crate::shared::services::mail::errors::SendMailError;
+use lettre::Message;
+use lettre::message::header::ContentType;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+    /// Where replies go instead of the sender address, such as when forwarding a message on
+    /// somebody's behalf
+    pub reply_to: Option<String>,
+}
+
+impl MailMessage {
+    pub(super) fn build(self, from_address: &str) -> Result<Message, SendMailError> {
+        let mut builder = Message::builder()
+            .from(from_address.parse()?)
+            .to(self.to.parse()?);
+        if let Some(reply_to) = self.reply_to {
+            builder = builder.reply_to(reply_to.parse()?);
+        }
+        builder
+            .subject(self.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(self.body)
+            .map_err(SendMailError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        struct TestCase {
+            description: &'static str,
+            from_address: &'static str,
+            to: &'static str,
+            reply_to: Option<&'static str>,
+            expected_error: Option<SendMailError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the sender address is invalid, I should receive an invalid address error",
+                from_address: "masjidapp",
+                to: "user@example.com",
+                reply_to: None,
+                expected_error: Some(SendMailError::InvalidAddress),
+            },
+            TestCase {
+                description: "When the recipient address is invalid, I should receive an invalid address error",
+                from_address: "noreply@masjidapp.com",
+                to: "user",
+                reply_to: None,
+                expected_error: Some(SendMailError::InvalidAddress),
+            },
+            TestCase {
+                description: "When the reply to address is invalid, I should receive an invalid address error",
+                from_address: "noreply@masjidapp.com",
+                to: "user@example.com",
+                reply_to: Some("sender"),
+                expected_error: Some(SendMailError::InvalidAddress),
+            },
+            TestCase {
+                description: "When both addresses are valid, I should receive a message",
+                from_address: "noreply@masjidapp.com",
+                to: "user@example.com",
+                reply_to: None,
+                expected_error: None,
+            },
+            TestCase {
+                description: "When every address including the reply to address is valid, I should receive a message",
+                from_address: "noreply@masjidapp.com",
+                to: "user@example.com",
+                reply_to: Some("sender@example.com"),
+                expected_error: None,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let message = MailMessage {
+                to: test_case.to.to_owned(),
+                subject: "Subject".to_owned(),
+                body: "Body".to_owned(),
+                reply_to: test_case.reply_to.map(ToOwned::to_owned),
+            };
+            let actual_result = message.build(test_case.from_address);
+            assert_eq!(test_case.expected_error, actual_result.err());
+        }
+    }
+}
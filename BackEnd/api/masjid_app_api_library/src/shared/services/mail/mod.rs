@@ -0,0 +1,6 @@
+pub mod errors;
+mod file_impl;
+pub mod mail_message;
+pub mod providers;
+mod smtp_impl;
+pub mod r#trait;
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+pub const MAIL_PROVIDER: &'static str = "MAIL_PROVIDER";
+pub const MAIL_FROM_ADDRESS: &'static str = "MAIL_FROM_ADDRESS";
+pub const MAIL_DIRECTORY: &'static str = "MAIL_DIRECTORY";
+pub const SMTP_HOST: &'static str = "SMTP_HOST";
+pub const SMTP_PORT: &'static str = "SMTP_PORT";
+pub const SMTP_USERNAME: &'static str = "SMTP_USERNAME";
+pub const SMTP_PASSWORD: &'static str = "SMTP_PASSWORD";
+
+pub struct SmtpSettings {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+}
+
+pub enum MailProvider {
+    Smtp {
+        settings: SmtpSettings,
+        from_address: String,
+    },
+    /// Writes each email to the given directory and logs it, which is intended for local testing
+    File {
+        directory: PathBuf,
+        from_address: String,
+    },
+}
+
+impl MailProvider {
+    /// Selects the mail provider from the environment, falling back to the file provider so that
+    /// real emails are never sent unless SMTP has been explicitly configured
+    pub fn from_environment() -> Self {
+        let from_address = std::env::var(MAIL_FROM_ADDRESS)
+            .unwrap_or_else(|_| "noreply@masjidapp.com".to_owned());
+        match std::env::var(MAIL_PROVIDER).as_deref() {
+            Ok("SMTP") => Self::Smtp {
+                settings: SmtpSettings {
+                    host: std::env::var(SMTP_HOST).unwrap(),
+                    port: std::env::var(SMTP_PORT)
+                        .ok()
+                        .and_then(|port| port.parse().ok())
+                        .unwrap_or(587),
+                    username: std::env::var(SMTP_USERNAME).unwrap(),
+                    password: std::env::var(SMTP_PASSWORD).unwrap(),
+                },
+                from_address,
+            },
+            _ => Self::File {
+                directory: std::env::var(MAIL_DIRECTORY)
+                    .unwrap_or_else(|_| "./mail".to_owned())
+                    .into(),
+                from_address,
+            },
+        }
+    }
+}
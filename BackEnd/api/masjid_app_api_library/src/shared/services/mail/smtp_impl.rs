@@ -0,0 +1,39 @@
+use crate::shared::services::mail::errors::SendMailError;
+use crate::shared::services::mail::mail_message::MailMessage;
+use crate::shared::services::mail::providers::SmtpSettings;
+use crate::shared::services::mail::r#trait::MailSender;
+use async_trait::async_trait;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+
+pub(super) struct SmtpMailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from_address: String,
+}
+
+impl SmtpMailSender {
+    pub(super) fn new(settings: SmtpSettings, from_address: String) -> Self {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&settings.host)
+            .unwrap_or_else(|err| panic!("Failed to configure SMTP relay: {err}"))
+            .port(settings.port)
+            .credentials(Credentials::new(settings.username, settings.password))
+            .build();
+        Self {
+            transport,
+            from_address,
+        }
+    }
+}
+
+#[async_trait]
+impl MailSender for SmtpMailSender {
+    async fn send(&self, message: MailMessage) -> Result<(), SendMailError> {
+        let email = message.build(&self.from_address)?;
+        self.transport.send(email).await.map_err(|err| {
+            tracing::error!(error = err.to_string(), "unable to send email via smtp");
+            SendMailError::UnableToSend
+        })?;
+        tracing::debug!("email sent via smtp");
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::shared::services::mail::errors::SendMailError;
+use crate::shared::services::mail::file_impl::FileMailSender;
+use crate::shared::services::mail::mail_message::MailMessage;
+use crate::shared::services::mail::providers::MailProvider;
+use crate::shared::services::mail::smtp_impl::SmtpMailSender;
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait MailSender: Send + Sync {
+    async fn send(&self, message: MailMessage) -> Result<(), SendMailError>;
+}
+
+pub fn new_mail_sender(mail_provider: MailProvider) -> Arc<dyn MailSender> {
+    match mail_provider {
+        MailProvider::Smtp {
+            settings,
+            from_address,
+        } => Arc::new(SmtpMailSender::new(settings, from_address)),
+        MailProvider::File {
+            directory,
+            from_address,
+        } => Arc::new(FileMailSender {
+            directory,
+            from_address,
+        }),
+    }
+}
@@ -1 +1,8 @@
+pub mod file_storage;
 pub mod hashing;
+pub mod mail;
+pub mod notifications;
+pub mod password_policy;
+pub mod secret_encryption;
+pub mod token_signing;
+pub mod totp;
@@ -0,0 +1,92 @@
+use crate::shared::services::mail::errors::SendMailError;
+use crate::shared::services::mail::mail_message::MailMessage;
+use crate::shared::services::mail::r#trait::MailSender;
+use crate::shared::services::notifications::errors::NotifyError;
+use crate::shared::services::notifications::notification::Notification;
+use crate::shared::services::notifications::r#trait::Notifier;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub(super) struct EmailNotifier {
+    pub(super) mail_sender: Arc<dyn MailSender>,
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+    async fn notify(&self, notification: Notification) -> Result<(), NotifyError> {
+        self.mail_sender
+            .send(MailMessage {
+                to: notification.recipient,
+                subject: notification.title,
+                body: notification.body,
+                reply_to: None,
+            })
+            .await
+            .map_err(|err| match err {
+                SendMailError::InvalidAddress => NotifyError::InvalidRecipient,
+                SendMailError::UnableToBuildMessage | SendMailError::UnableToSend => {
+                    NotifyError::UnableToNotify
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::services::mail::r#trait::MockMailSender;
+
+    #[tokio::test]
+    async fn test_notify() {
+        struct TestCase {
+            description: &'static str,
+            send_result: Result<(), SendMailError>,
+            expected_result: Result<(), NotifyError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the email is sent, I should get no error",
+                send_result: Ok(()),
+                expected_result: Ok(()),
+            },
+            TestCase {
+                description: "When the address is invalid, I should get an invalid recipient error",
+                send_result: Err(SendMailError::InvalidAddress),
+                expected_result: Err(NotifyError::InvalidRecipient),
+            },
+            TestCase {
+                description: "When the email cannot be sent, I should get an unable to notify error",
+                send_result: Err(SendMailError::UnableToSend),
+                expected_result: Err(NotifyError::UnableToNotify),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_mail_sender = MockMailSender::new();
+            let send_result = test_case.send_result;
+            mock_mail_sender
+                .expect_send()
+                .withf(|message| {
+                    *message
+                        == MailMessage {
+                            to: "user@example.com".to_owned(),
+                            subject: "Reminder".to_owned(),
+                            body: "Starts soon".to_owned(),
+                            reply_to: None,
+                        }
+                })
+                .return_once(move |_| send_result);
+            let notifier = EmailNotifier {
+                mail_sender: Arc::new(mock_mail_sender),
+            };
+            let actual_result = notifier
+                .notify(Notification {
+                    recipient: "user@example.com".to_owned(),
+                    title: "Reminder".to_owned(),
+                    body: "Starts soon".to_owned(),
+                })
+                .await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotifyError {
+    /// The recipient can never be reached, such as a device which is no longer registered
+    InvalidRecipient,
+    UnableToNotify,
+}
@@ -0,0 +1,19 @@
+use crate::shared::services::notifications::errors::NotifyError;
+use crate::shared::services::notifications::notification::Notification;
+use crate::shared::services::notifications::r#trait::Notifier;
+use async_trait::async_trait;
+
+pub(super) struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, notification: Notification) -> Result<(), NotifyError> {
+        tracing::info!(
+            recipient = notification.recipient,
+            title = notification.title,
+            body = notification.body,
+            "sending notification"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+mod email_impl;
+pub mod errors;
+mod log_impl;
+pub mod notification;
+pub mod providers;
+mod push_impl;
+pub mod r#trait;
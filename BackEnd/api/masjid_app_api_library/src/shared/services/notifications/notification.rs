@@ -0,0 +1,8 @@
+/// A message for a single recipient, who is identified by a device token for push notifications
+/// and by an email address for emails
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub recipient: String,
+    pub title: String,
+    pub body: String,
+}
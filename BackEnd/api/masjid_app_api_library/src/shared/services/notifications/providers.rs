@@ -0,0 +1,41 @@
+use crate::shared::services::mail::providers::MailProvider;
+
+pub const PUSH_NOTIFICATION_PROVIDER: &'static str = "PUSH_NOTIFICATION_PROVIDER";
+pub const FCM_ENDPOINT: &'static str = "FCM_ENDPOINT";
+pub const FCM_ACCESS_TOKEN: &'static str = "FCM_ACCESS_TOKEN";
+
+pub struct FcmSettings {
+    /// The url messages are sent to, such as
+    /// `https://fcm.googleapis.com/v1/projects/{project_id}/messages:send`. Any server accepting
+    /// the same requests may be used in place of FCM
+    pub endpoint: String,
+    pub access_token: String,
+}
+
+pub enum NotificationProvider {
+    /// Sends push notifications through the FCM HTTP v1 API
+    Push {
+        settings: FcmSettings,
+    },
+    Email {
+        mail_provider: MailProvider,
+    },
+    /// Logs each notification, which is intended for local testing
+    Log,
+}
+
+impl NotificationProvider {
+    /// Selects how push notifications are sent from the environment, falling back to logging them
+    /// so that real notifications are never sent unless FCM has been explicitly configured
+    pub fn push_from_environment() -> Self {
+        match std::env::var(PUSH_NOTIFICATION_PROVIDER).as_deref() {
+            Ok("FCM") => Self::Push {
+                settings: FcmSettings {
+                    endpoint: std::env::var(FCM_ENDPOINT).unwrap(),
+                    access_token: std::env::var(FCM_ACCESS_TOKEN).unwrap(),
+                },
+            },
+            _ => Self::Log,
+        }
+    }
+}
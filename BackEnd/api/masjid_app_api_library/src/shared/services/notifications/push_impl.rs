@@ -0,0 +1,157 @@
+use crate::shared::services::notifications::errors::NotifyError;
+use crate::shared::services::notifications::notification::Notification;
+use crate::shared::services::notifications::providers::FcmSettings;
+use crate::shared::services::notifications::r#trait::Notifier;
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct FcmRequest<'a> {
+    message: FcmMessage<'a>,
+}
+
+#[derive(Serialize)]
+struct FcmMessage<'a> {
+    token: &'a str,
+    notification: FcmNotification<'a>,
+}
+
+#[derive(Serialize)]
+struct FcmNotification<'a> {
+    title: &'a str,
+    body: &'a str,
+}
+
+pub(super) struct PushNotifier {
+    client: reqwest::Client,
+    settings: FcmSettings,
+}
+
+impl PushNotifier {
+    pub(super) fn new(settings: FcmSettings) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            settings,
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for PushNotifier {
+    async fn notify(&self, notification: Notification) -> Result<(), NotifyError> {
+        let request = FcmRequest {
+            message: FcmMessage {
+                token: &notification.recipient,
+                notification: FcmNotification {
+                    title: &notification.title,
+                    body: &notification.body,
+                },
+            },
+        };
+        let response = self
+            .client
+            .post(&self.settings.endpoint)
+            .bearer_auth(&self.settings.access_token)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|err| {
+                tracing::error!(error = err.to_string(), "unable to send push notification");
+                NotifyError::UnableToNotify
+            })?;
+        match response.status() {
+            status if status.is_success() => {
+                tracing::debug!("push notification sent");
+                Ok(())
+            }
+            // FCM responds with not found once the app has been uninstalled from the device
+            StatusCode::NOT_FOUND => Err(NotifyError::InvalidRecipient),
+            status => {
+                tracing::error!(status = status.as_u16(), "push notification was rejected");
+                Err(NotifyError::UnableToNotify)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_notify() {
+        struct TestCase {
+            description: &'static str,
+            response_status: u16,
+            expected_result: Result<(), NotifyError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the server accepts the message, I should get no error",
+                response_status: 200,
+                expected_result: Ok(()),
+            },
+            TestCase {
+                description: "When the device is no longer registered, I should get an invalid recipient error",
+                response_status: 404,
+                expected_result: Err(NotifyError::InvalidRecipient),
+            },
+            TestCase {
+                description: "When the server fails, I should get an unable to notify error",
+                response_status: 503,
+                expected_result: Err(NotifyError::UnableToNotify),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/v1/projects/masjidapp/messages:send"))
+                .and(header("authorization", "Bearer access-token"))
+                .and(body_json(serde_json::json!({
+                    "message": {
+                        "token": "device-token",
+                        "notification": {
+                            "title": "Reminder",
+                            "body": "Starts soon"
+                        }
+                    }
+                })))
+                .respond_with(ResponseTemplate::new(test_case.response_status))
+                .expect(1)
+                .mount(&server)
+                .await;
+            let notifier = PushNotifier::new(FcmSettings {
+                endpoint: format!("{}/v1/projects/masjidapp/messages:send", server.uri()),
+                access_token: "access-token".to_owned(),
+            });
+            let actual_result = notifier
+                .notify(Notification {
+                    recipient: "device-token".to_owned(),
+                    title: "Reminder".to_owned(),
+                    body: "Starts soon".to_owned(),
+                })
+                .await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notify_when_server_is_unreachable() {
+        let notifier = PushNotifier::new(FcmSettings {
+            endpoint: "http://127.0.0.1:9/messages:send".to_owned(),
+            access_token: "access-token".to_owned(),
+        });
+        let actual_result = notifier
+            .notify(Notification {
+                recipient: "device-token".to_owned(),
+                title: "Reminder".to_owned(),
+                body: "Starts soon".to_owned(),
+            })
+            .await;
+        assert_eq!(Err(NotifyError::UnableToNotify), actual_result);
+    }
+}
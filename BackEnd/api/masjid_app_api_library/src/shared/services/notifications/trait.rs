@@ -0,0 +1,26 @@
+use crate::shared::services::mail::r#trait::new_mail_sender;
+use crate::shared::services::notifications::email_impl::EmailNotifier;
+use crate::shared::services::notifications::errors::NotifyError;
+use crate::shared::services::notifications::log_impl::LogNotifier;
+use crate::shared::services::notifications::notification::Notification;
+use crate::shared::services::notifications::providers::NotificationProvider;
+use crate::shared::services::notifications::push_impl::PushNotifier;
+use async_trait::async_trait;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, notification: Notification) -> Result<(), NotifyError>;
+}
+
+pub fn new_notifier(notification_provider: NotificationProvider) -> Arc<dyn Notifier> {
+    match notification_provider {
+        NotificationProvider::Push { settings } => Arc::new(PushNotifier::new(settings)),
+        NotificationProvider::Email { mail_provider } => Arc::new(EmailNotifier {
+            mail_sender: new_mail_sender(mail_provider),
+        }),
+        NotificationProvider::Log => Arc::new(LogNotifier),
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasswordPolicyViolation {
+    TooShort { minimum_length: usize },
+    TooWeak,
+    ContainsPersonalInformation,
+    CommonlyBreached,
+}
+
+impl Display for PasswordPolicyViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort { minimum_length } => write!(
+                f,
+                "Password length must be a minimum of {minimum_length} characters"
+            ),
+            Self::TooWeak => write!(
+                f,
+                "Password is too easy to guess. Avoid repeated characters, sequences and common words"
+            ),
+            Self::ContainsPersonalInformation => {
+                write!(f, "Password must not contain your username or email address")
+            }
+            Self::CommonlyBreached => write!(
+                f,
+                "Password appears in a list of commonly breached passwords"
+            ),
+        }
+    }
+}
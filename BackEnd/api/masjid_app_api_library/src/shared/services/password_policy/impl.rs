@@ -0,0 +1,184 @@
+use crate::shared::services::password_policy::errors::PasswordPolicyViolation;
+use crate::shared::services::password_policy::settings::PasswordPolicySettings;
+use crate::shared::services::password_policy::strength::{
+    estimate_strength_score, longest_common_password,
+};
+use crate::shared::services::password_policy::r#trait::PasswordPolicy;
+use std::collections::HashSet;
+
+// Bundled so that passwords are never sent to a third party breach checking service
+const COMMON_PASSWORDS: &str = include_str!("common_passwords.txt");
+// Shorter personal information, such as initials, is too likely to appear by chance
+const MINIMUM_PERSONAL_INFORMATION_LENGTH: usize = 3;
+
+pub(super) struct PasswordPolicyImpl {
+    settings: PasswordPolicySettings,
+    common_passwords: HashSet<String>,
+    longest_common_password: usize,
+}
+
+impl PasswordPolicyImpl {
+    pub(super) fn new(settings: PasswordPolicySettings) -> Self {
+        let mut common_passwords = parse_common_passwords(COMMON_PASSWORDS);
+        if let Some(path) = &settings.common_passwords_path {
+            match std::fs::read_to_string(path) {
+                Ok(file) => common_passwords.extend(parse_common_passwords(&file)),
+                // Falling back to the bundled list keeps the policy enforced rather than failing
+                // to start
+                Err(err) => tracing::error!(
+                    error = err.to_string(),
+                    path = path.as_str(),
+                    "unable to read the common passwords file, so only the bundled list is used"
+                ),
+            }
+        }
+        Self {
+            settings,
+            longest_common_password: longest_common_password(&common_passwords),
+            common_passwords,
+        }
+    }
+
+    fn contains_personal_information(
+        lowercase_password: &str,
+        personal_information: &[String],
+    ) -> bool {
+        personal_information.iter().any(|information| {
+            let information = information.trim().to_lowercase();
+            // The local part of an email address is often reused as a username elsewhere
+            let local_part = information.split('@').next().unwrap_or_default();
+            [information.as_str(), local_part].iter().any(|value| {
+                value.chars().count() >= MINIMUM_PERSONAL_INFORMATION_LENGTH
+                    && lowercase_password.contains(value)
+            })
+        })
+    }
+
+    fn is_commonly_breached(&self, lowercase_password: &str) -> bool {
+        // Catches the common habit of appending digits or symbols to a breached password
+        let without_suffix = lowercase_password.trim_end_matches(|c: char| !c.is_alphabetic());
+        self.common_passwords.contains(lowercase_password)
+            || (!without_suffix.is_empty() && self.common_passwords.contains(without_suffix))
+    }
+}
+
+fn parse_common_passwords(file: &str) -> HashSet<String> {
+    file.lines()
+        .map(|common_password| common_password.trim().to_lowercase())
+        .filter(|common_password| !common_password.is_empty())
+        .collect()
+}
+
+impl PasswordPolicy for PasswordPolicyImpl {
+    fn validate(
+        &self,
+        password: &str,
+        personal_information: &[String],
+    ) -> Result<(), PasswordPolicyViolation> {
+        if password.chars().count() < self.settings.minimum_length {
+            return Err(PasswordPolicyViolation::TooShort {
+                minimum_length: self.settings.minimum_length,
+            });
+        }
+        let lowercase_password = password.to_lowercase();
+        if Self::contains_personal_information(&lowercase_password, personal_information) {
+            return Err(PasswordPolicyViolation::ContainsPersonalInformation);
+        }
+        if self.is_commonly_breached(&lowercase_password) {
+            return Err(PasswordPolicyViolation::CommonlyBreached);
+        }
+        if estimate_strength_score(
+            password,
+            &self.common_passwords,
+            self.longest_common_password,
+        ) < self.settings.minimum_strength_score
+        {
+            return Err(PasswordPolicyViolation::TooWeak);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_password_policy_validate() {
+        struct TestCase {
+            description: &'static str,
+            password: &'static str,
+            expected_result: Result<(), PasswordPolicyViolation>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the password is shorter than the minimum length, I should receive a too short error",
+                password: "Tr0ub4dour&3",
+                expected_result: Err(PasswordPolicyViolation::TooShort { minimum_length: 16 }),
+            },
+            TestCase {
+                description: "When the password contains the username, I should receive a personal information error",
+                password: "Tr0ub4dour&3ZaydMcArdle",
+                expected_result: Err(PasswordPolicyViolation::ContainsPersonalInformation),
+            },
+            TestCase {
+                description: "When the password contains the local part of the email address, I should receive a personal information error",
+                password: "Tr0ub4dour&3zmcardle",
+                expected_result: Err(PasswordPolicyViolation::ContainsPersonalInformation),
+            },
+            TestCase {
+                description: "When the password is a common password, I should receive a breached error",
+                password: "PasswordPassword",
+                expected_result: Err(PasswordPolicyViolation::CommonlyBreached),
+            },
+            TestCase {
+                description: "When the password is a common password with a suffix, I should receive a breached error",
+                password: "correcthorsebatterystaple123!",
+                expected_result: Err(PasswordPolicyViolation::CommonlyBreached),
+            },
+            TestCase {
+                description: "When the password is easily guessed, I should receive a too weak error",
+                password: "zzzzzzzzzzzzzzzz",
+                expected_result: Err(PasswordPolicyViolation::TooWeak),
+            },
+            TestCase {
+                description: "When the password is strong, I should receive no error",
+                password: "Tr0ub4dour&3Horse!",
+                expected_result: Ok(()),
+            },
+        ];
+        let password_policy = PasswordPolicyImpl::new(PasswordPolicySettings::default());
+        let personal_information = ["ZaydMcArdle".to_owned(), "zmcardle@example.com".to_owned()];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_result,
+                password_policy.validate(test_case.password, &personal_information)
+            );
+        }
+    }
+
+    #[test]
+    fn test_password_policy_common_passwords_path() {
+        let path = std::env::temp_dir().join("masjid_app_common_passwords_test.txt");
+        std::fs::write(&path, "Tr0ub4dour&3Horse!\n").unwrap();
+        let password_policy = PasswordPolicyImpl::new(PasswordPolicySettings {
+            common_passwords_path: Some(path.to_string_lossy().into_owned()),
+            ..PasswordPolicySettings::default()
+        });
+        std::fs::remove_file(&path).unwrap();
+
+        eprintln!("When the password is in the configured list, I should receive a breached error");
+        assert_eq!(
+            Err(PasswordPolicyViolation::CommonlyBreached),
+            password_policy.validate("Tr0ub4dour&3Horse!", &[])
+        );
+        eprintln!(
+            "When the password is in the bundled list, I should still receive a breached error"
+        );
+        assert_eq!(
+            Err(PasswordPolicyViolation::CommonlyBreached),
+            password_policy.validate("PasswordPassword", &[])
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod errors;
+mod r#impl;
+pub mod settings;
+mod strength;
+pub mod r#trait;
@@ -0,0 +1,43 @@
+pub const PASSWORD_MINIMUM_LENGTH: &'static str = "PASSWORD_MINIMUM_LENGTH";
+pub const PASSWORD_MINIMUM_STRENGTH_SCORE: &'static str = "PASSWORD_MINIMUM_STRENGTH_SCORE";
+pub const PASSWORD_COMMON_PASSWORDS_PATH: &'static str = "PASSWORD_COMMON_PASSWORDS_PATH";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PasswordPolicySettings {
+    pub minimum_length: usize,
+    /// Between 0 and 4, following the same scale as zxcvbn
+    pub minimum_strength_score: u8,
+    /// A file of further breached passwords, one per line, checked alongside the bundled list.
+    /// This allows a full breach corpus to be deployed without rebuilding
+    pub common_passwords_path: Option<String>,
+}
+
+impl Default for PasswordPolicySettings {
+    fn default() -> Self {
+        Self {
+            minimum_length: 16,
+            minimum_strength_score: 3,
+            common_passwords_path: None,
+        }
+    }
+}
+
+impl PasswordPolicySettings {
+    /// Reads each setting from the environment, falling back to the default for any that are
+    /// not set
+    pub fn from_environment() -> Self {
+        let defaults = Self::default();
+        Self {
+            minimum_length: std::env::var(PASSWORD_MINIMUM_LENGTH)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(defaults.minimum_length),
+            minimum_strength_score: std::env::var(PASSWORD_MINIMUM_STRENGTH_SCORE)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .map(|score: u8| score.min(4))
+                .unwrap_or(defaults.minimum_strength_score),
+            common_passwords_path: std::env::var(PASSWORD_COMMON_PASSWORDS_PATH).ok(),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+// Dictionary words shorter than this are too likely to appear by chance within a strong password
+const MINIMUM_DICTIONARY_MATCH_LENGTH: usize = 4;
+// A repeated character, or the next character of a sequence such as "abc" or "321", only adds
+// around one bit of entropy
+const PATTERN_CHARACTER_LOG10_GUESSES: f64 = std::f64::consts::LOG10_2;
+
+/// Estimates how many guesses are needed to crack the password, in the style of zxcvbn, and maps
+/// it onto the zxcvbn scale of 0 (too guessable) to 4 (very unguessable)
+pub(super) fn estimate_strength_score(
+    password: &str,
+    common_passwords: &HashSet<String>,
+    longest_common_password: usize,
+) -> u8 {
+    let log10_guesses = estimate_log10_guesses(password, common_passwords, longest_common_password);
+    if log10_guesses < 3.0 {
+        0
+    } else if log10_guesses < 6.0 {
+        1
+    } else if log10_guesses < 8.0 {
+        2
+    } else if log10_guesses < 10.0 {
+        3
+    } else {
+        4
+    }
+}
+
+/// How many characters the longest common password has, which bounds how far ahead a password
+/// is searched for one
+pub(super) fn longest_common_password(common_passwords: &HashSet<String>) -> usize {
+    common_passwords
+        .iter()
+        .map(|common_password| common_password.chars().count())
+        .max()
+        .unwrap_or_default()
+}
+
+fn character_set_size(password: &str) -> usize {
+    let mut size = 0;
+    if password.chars().any(|c| c.is_ascii_lowercase()) {
+        size += 26;
+    }
+    if password.chars().any(|c| c.is_ascii_uppercase()) {
+        size += 26;
+    }
+    if password.chars().any(|c| c.is_ascii_digit()) {
+        size += 10;
+    }
+    if password
+        .chars()
+        .any(|c| c.is_ascii() && !c.is_ascii_alphanumeric())
+    {
+        size += 33;
+    }
+    if password.chars().any(|c| !c.is_ascii()) {
+        size += 100;
+    }
+    size.max(1)
+}
+
+fn estimate_log10_guesses(
+    password: &str,
+    common_passwords: &HashSet<String>,
+    longest_common_password: usize,
+) -> f64 {
+    let lowercase_password = password.to_ascii_lowercase();
+    let characters: Vec<char> = lowercase_password.chars().collect();
+    // Where each character starts, so that candidates can be sliced from the password rather
+    // than collected into new strings
+    let character_offsets: Vec<usize> = lowercase_password
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(lowercase_password.len()))
+        .collect();
+    let character_log10_guesses = (character_set_size(password) as f64).log10();
+    let dictionary_log10_guesses = (common_passwords.len().max(1) as f64).log10();
+
+    let mut log10_guesses = 0.0;
+    let mut start = 0;
+    while start < characters.len() {
+        // A common password within the password costs no more than guessing from the list
+        let longest_end = characters.len().min(start + longest_common_password);
+        let dictionary_match_length = (start + MINIMUM_DICTIONARY_MATCH_LENGTH..=longest_end)
+            .rev()
+            .find(|&end| {
+                common_passwords
+                    .contains(&lowercase_password[character_offsets[start]..character_offsets[end]])
+            })
+            .map(|end| end - start);
+        if let Some(match_length) = dictionary_match_length {
+            log10_guesses += dictionary_log10_guesses;
+            start += match_length;
+            continue;
+        }
+        let continues_pattern =
+            start > 0 && (characters[start] as i64 - characters[start - 1] as i64).abs() <= 1;
+        log10_guesses += if continues_pattern {
+            PATTERN_CHARACTER_LOG10_GUESSES
+        } else {
+            character_log10_guesses
+        };
+        start += 1;
+    }
+    log10_guesses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_strength_score() {
+        struct TestCase {
+            description: &'static str,
+            password: &'static str,
+            expected_score: u8,
+        }
+        let common_passwords =
+            HashSet::from(["password", "passwordpassword", "1234", "abcdefgh"].map(str::to_owned));
+        let test_cases = [
+            TestCase {
+                description: "When the password is a common password repeated, the score should be 0",
+                password: "passwordpassword",
+                expected_score: 0,
+            },
+            TestCase {
+                description: "When the password is a single repeated character, the score should be 1",
+                password: "zzzzzzzzzzzzzzzz",
+                expected_score: 1,
+            },
+            TestCase {
+                description: "When the password is a sequence, the score should be 1",
+                password: "abcdefghijklmnop",
+                expected_score: 1,
+            },
+            TestCase {
+                description: "When the password is made of common passwords, the score should be 0",
+                password: "password12341234",
+                expected_score: 0,
+            },
+            TestCase {
+                description: "When the password is a passphrase, the score should be 4",
+                password: "correct horse battery staple",
+                expected_score: 4,
+            },
+            TestCase {
+                description: "When the password is random characters, the score should be 4",
+                password: "Tr0ub4dour&3Horse!",
+                expected_score: 4,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_score,
+                estimate_strength_score(
+                    test_case.password,
+                    &common_passwords,
+                    longest_common_password(&common_passwords)
+                )
+            );
+        }
+    }
+}
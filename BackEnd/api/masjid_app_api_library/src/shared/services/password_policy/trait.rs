@@ -0,0 +1,20 @@
+use crate::shared::services::password_policy::errors::PasswordPolicyViolation;
+use crate::shared::services::password_policy::r#impl::PasswordPolicyImpl;
+use crate::shared::services::password_policy::settings::PasswordPolicySettings;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+pub trait PasswordPolicy: Send + Sync {
+    /// Checks the password against the policy. The personal information, such as the username
+    /// and email address, must not appear within the password
+    fn validate(
+        &self,
+        password: &str,
+        personal_information: &[String],
+    ) -> Result<(), PasswordPolicyViolation>;
+}
+
+pub fn new_password_policy(settings: PasswordPolicySettings) -> Arc<dyn PasswordPolicy> {
+    Arc::new(PasswordPolicyImpl::new(settings))
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretEncryptionError {
+    UnableToEncrypt,
+    Malformed,
+    UnableToDecrypt,
+}
@@ -0,0 +1,108 @@
+use crate::shared::services::secret_encryption::errors::SecretEncryptionError;
+use crate::shared::services::secret_encryption::r#trait::SecretEncryptor;
+use aes_gcm::aead::{Aead, AeadCore, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+
+const NONCE_LENGTH: usize = 12;
+
+pub(super) struct SecretEncryptorImpl {
+    pub(super) cipher: Aes256Gcm,
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+impl SecretEncryptor for SecretEncryptorImpl {
+    fn encrypt(&self, plaintext: &str) -> Result<String, SecretEncryptionError> {
+        // A fresh nonce for every secret, stored alongside the ciphertext
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| SecretEncryptionError::UnableToEncrypt)?;
+        Ok(nonce
+            .iter()
+            .chain(ciphertext.iter())
+            .map(|byte| format!("{byte:02x}"))
+            .collect())
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> Result<String, SecretEncryptionError> {
+        let bytes = decode_hex(ciphertext).ok_or(SecretEncryptionError::Malformed)?;
+        if bytes.len() <= NONCE_LENGTH {
+            return Err(SecretEncryptionError::Malformed);
+        }
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LENGTH);
+        // Decryption also authenticates the ciphertext, so a tampered secret is rejected
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| SecretEncryptionError::UnableToDecrypt)?;
+        String::from_utf8(plaintext).map_err(|_| SecretEncryptionError::Malformed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::services::secret_encryption::r#trait::new_secret_encryptor;
+
+    #[test]
+    fn test_decrypt() {
+        struct TestCase {
+            description: &'static str,
+            ciphertext: String,
+            expected_result: Result<String, SecretEncryptionError>,
+        }
+        let secret_encryptor = new_secret_encryptor(b"unit test key");
+        let other_secret_encryptor = new_secret_encryptor(b"another key");
+        let ciphertext = secret_encryptor.encrypt("JBSWY3DPEHPK3PXP").unwrap();
+        let mut tampered_ciphertext = ciphertext.clone();
+        let last_character = if tampered_ciphertext.pop() == Some('0') {
+            '1'
+        } else {
+            '0'
+        };
+        tampered_ciphertext.push(last_character);
+        let test_cases = [
+            TestCase {
+                description: "When the secret was encrypted with the same key, I should get the plaintext",
+                ciphertext: ciphertext.clone(),
+                expected_result: Ok("JBSWY3DPEHPK3PXP".to_owned()),
+            },
+            TestCase {
+                description: "When the ciphertext has been tampered with, I should get an unable to decrypt error",
+                ciphertext: tampered_ciphertext,
+                expected_result: Err(SecretEncryptionError::UnableToDecrypt),
+            },
+            TestCase {
+                description: "When the secret was encrypted with a different key, I should get an unable to decrypt error",
+                ciphertext: other_secret_encryptor.encrypt("JBSWY3DPEHPK3PXP").unwrap(),
+                expected_result: Err(SecretEncryptionError::UnableToDecrypt),
+            },
+            TestCase {
+                description: "When the ciphertext is not hexadecimal, I should get a malformed error",
+                ciphertext: "JBSWY3DPEHPK3PXP".to_owned(),
+                expected_result: Err(SecretEncryptionError::Malformed),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_result,
+                secret_encryptor.decrypt(&test_case.ciphertext)
+            );
+        }
+        assert_ne!(
+            ciphertext,
+            secret_encryptor.encrypt("JBSWY3DPEHPK3PXP").unwrap()
+        );
+    }
+}
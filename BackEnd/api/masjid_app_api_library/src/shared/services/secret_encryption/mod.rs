@@ -0,0 +1,3 @@
+pub mod errors;
+mod r#impl;
+pub mod r#trait;
@@ -0,0 +1,22 @@
+use crate::shared::services::secret_encryption::errors::SecretEncryptionError;
+use crate::shared::services::secret_encryption::r#impl::SecretEncryptorImpl;
+use aes_gcm::{Aes256Gcm, KeyInit};
+use mockall::automock;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// Encrypts secrets which have to be read back, such as TOTP secrets, so that they are of no use
+/// to anyone who obtains a copy of the database without the key
+#[automock]
+pub trait SecretEncryptor: Send + Sync {
+    fn encrypt(&self, plaintext: &str) -> Result<String, SecretEncryptionError>;
+    /// Returns the plaintext of a secret previously produced by `encrypt`
+    fn decrypt(&self, ciphertext: &str) -> Result<String, SecretEncryptionError>;
+}
+
+/// The key may be of any length, as AES-256 is keyed with its SHA-256 digest
+pub fn new_secret_encryptor(key: &[u8]) -> Arc<dyn SecretEncryptor> {
+    Arc::new(SecretEncryptorImpl {
+        cipher: Aes256Gcm::new(&Sha256::digest(key)),
+    })
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenVerificationError {
+    Malformed,
+    InvalidSignature,
+}
@@ -0,0 +1,105 @@
+use crate::shared::services::token_signing::errors::TokenVerificationError;
+use crate::shared::services::token_signing::r#trait::TokenSigner;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SIGNATURE_SEPARATOR: char = '.';
+
+pub(super) struct TokenSignerImpl {
+    pub(super) secret: Vec<u8>,
+}
+
+impl TokenSignerImpl {
+    fn mac(&self, payload: &str) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+impl TokenSigner for TokenSignerImpl {
+    fn sign(&self, payload: &str) -> String {
+        let signature = self.mac(payload).finalize().into_bytes();
+        format!("{payload}{SIGNATURE_SEPARATOR}{signature:x}")
+    }
+
+    fn verify(&self, token: &str) -> Result<String, TokenVerificationError> {
+        let (payload, signature) = token
+            .rsplit_once(SIGNATURE_SEPARATOR)
+            .ok_or(TokenVerificationError::Malformed)?;
+        let signature = decode_hex(signature).ok_or(TokenVerificationError::Malformed)?;
+        // The comparison is constant time, so the signature cannot be guessed byte by byte
+        self.mac(payload)
+            .verify_slice(&signature)
+            .map_err(|_| TokenVerificationError::InvalidSignature)?;
+        Ok(payload.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        struct TestCase {
+            description: &'static str,
+            token: String,
+            expected_result: Result<String, TokenVerificationError>,
+        }
+        let token_signer = TokenSignerImpl {
+            secret: b"unit test secret".to_vec(),
+        };
+        let other_token_signer = TokenSignerImpl {
+            secret: b"another secret".to_vec(),
+        };
+        let token = token_signer.sign("event-registration-1");
+        let test_cases = [
+            TestCase {
+                description: "When the token was signed with the same secret, I should get its payload",
+                token: token.clone(),
+                expected_result: Ok("event-registration-1".to_owned()),
+            },
+            TestCase {
+                description: "When the payload has been tampered with, I should get an invalid signature error",
+                token: token.replace("registration-1", "registration-2"),
+                expected_result: Err(TokenVerificationError::InvalidSignature),
+            },
+            TestCase {
+                description: "When the token was signed with a different secret, I should get an invalid signature error",
+                token: other_token_signer.sign("event-registration-1"),
+                expected_result: Err(TokenVerificationError::InvalidSignature),
+            },
+            TestCase {
+                description: "When the token has no signature, I should get a malformed error",
+                token: "event-registration-1".to_owned(),
+                expected_result: Err(TokenVerificationError::Malformed),
+            },
+            TestCase {
+                description: "When the signature is not hexadecimal, I should get a malformed error",
+                token: "event-registration-1.not-hex!".to_owned(),
+                expected_result: Err(TokenVerificationError::Malformed),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_result,
+                token_signer.verify(&test_case.token)
+            );
+        }
+    }
+}
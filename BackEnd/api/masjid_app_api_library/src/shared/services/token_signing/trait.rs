@@ -0,0 +1,19 @@
+use crate::shared::services::token_signing::errors::TokenVerificationError;
+use crate::shared::services::token_signing::r#impl::TokenSignerImpl;
+use mockall::automock;
+use std::sync::Arc;
+
+/// Produces tokens that can be handed to people without accounts and trusted when they are
+/// presented again, since they cannot be forged without the secret
+#[automock]
+pub trait TokenSigner: Send + Sync {
+    fn sign(&self, payload: &str) -> String;
+    /// Returns the payload of a token previously produced by `sign`
+    fn verify(&self, token: &str) -> Result<String, TokenVerificationError>;
+}
+
+pub fn new_token_signer(secret: &[u8]) -> Arc<dyn TokenSigner> {
+    Arc::new(TokenSignerImpl {
+        secret: secret.to_vec(),
+    })
+}
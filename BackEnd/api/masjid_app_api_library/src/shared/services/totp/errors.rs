@@ -0,0 +1,28 @@
+use totp_rs::{SecretParseError, TotpUrlError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TotpError {
+    SecretMalformed,
+    InvalidAccountName,
+    UnknownError,
+}
+
+impl From<SecretParseError> for TotpError {
+    #[inline]
+    fn from(_: SecretParseError) -> Self {
+        Self::SecretMalformed
+    }
+}
+
+impl From<TotpUrlError> for TotpError {
+    #[inline]
+    fn from(value: TotpUrlError) -> Self {
+        match value {
+            TotpUrlError::AccountName(_) | TotpUrlError::AccountNameDecoding(_) => {
+                Self::InvalidAccountName
+            }
+            TotpUrlError::SecretSize(_) | TotpUrlError::Secret(_) => Self::SecretMalformed,
+            _ => Self::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::shared::services::totp::errors::TotpError;
+use crate::shared::services::totp::r#trait::TotpService;
+use std::time::{SystemTime, UNIX_EPOCH};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+// RFC 6238 defaults, which is what the majority of authenticator apps expect
+const DIGITS: usize = 6;
+const SKEW: u8 = 1;
+const STEP_IN_SECONDS: u64 = 30;
+
+pub(super) struct TotpServiceImpl {
+    pub(super) issuer: String,
+}
+
+impl TotpServiceImpl {
+    fn build_totp(&self, secret: &str, account_name: &str) -> Result<TOTP, TotpError> {
+        let secret_bytes = Secret::Encoded(secret.to_owned()).to_bytes()?;
+        TOTP::new(
+            Algorithm::SHA1,
+            DIGITS,
+            // The skew is applied by verify_code instead, so that it knows which step matched
+            0,
+            STEP_IN_SECONDS,
+            secret_bytes,
+            Some(self.issuer.clone()),
+            account_name.to_owned(),
+        )
+        .map_err(TotpError::from)
+    }
+}
+
+impl TotpService for TotpServiceImpl {
+    fn generate_secret(&self) -> String {
+        Secret::generate_secret().to_encoded().to_string()
+    }
+
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, TotpError> {
+        Ok(self.build_totp(secret, account_name)?.get_url())
+    }
+
+    fn verify_code(&self, secret: &str, code: &str) -> Result<Option<u64>, TotpError> {
+        let totp = self.build_totp(secret, "")?;
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|err| {
+                tracing::error!(error = ?err, "system time is before the unix epoch");
+                TotpError::UnknownError
+            })?
+            .as_secs();
+        let current_time_step = current_time / STEP_IN_SECONDS;
+        let skew = u64::from(SKEW);
+        Ok(
+            (current_time_step.saturating_sub(skew)..=current_time_step + skew)
+                .find(|time_step| totp.check(code.trim(), time_step * STEP_IN_SECONDS)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_code() {
+        let service = TotpServiceImpl {
+            issuer: "MasjidApp".to_owned(),
+        };
+        let secret = service.generate_secret();
+        let totp = service.build_totp(&secret, "").unwrap();
+        let current_time_step = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            / STEP_IN_SECONDS;
+        let current_code = totp.generate(current_time_step * STEP_IN_SECONDS);
+        let expired_code = totp.generate((current_time_step - 3) * STEP_IN_SECONDS);
+        struct TestCase {
+            description: &'static str,
+            secret: String,
+            code: String,
+            expected_result: Result<Option<u64>, TotpError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the secret is not valid base32, I should receive a malformed secret error",
+                secret: "not-a-secret!".to_owned(),
+                code: current_code.clone(),
+                expected_result: Err(TotpError::SecretMalformed),
+            },
+            TestCase {
+                description: "When the code does not match the secret, I should receive no time step",
+                secret: secret.clone(),
+                code: "abcdef".to_owned(),
+                expected_result: Ok(None),
+            },
+            TestCase {
+                description: "When the code is from a time step too long ago, I should receive no time step",
+                secret: secret.clone(),
+                code: expired_code,
+                expected_result: Ok(None),
+            },
+            TestCase {
+                description: "When the code matches the secret, I should receive the time step it was generated for",
+                secret: secret.clone(),
+                code: current_code.clone(),
+                expected_result: Ok(Some(current_time_step)),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let actual_result = service.verify_code(&test_case.secret, &test_case.code);
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+
+    #[test]
+    fn test_provisioning_uri() {
+        let service = TotpServiceImpl {
+            issuer: "MasjidApp".to_owned(),
+        };
+        let secret = service.generate_secret();
+        let uri = service.provisioning_uri(&secret, "zayd").unwrap();
+        assert!(uri.starts_with("otpauth://totp/MasjidApp:zayd?"));
+        assert!(uri.contains(&format!("secret={secret}")));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::shared::services::totp::errors::TotpError;
+use crate::shared::services::totp::r#impl::TotpServiceImpl;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+pub trait TotpService: Send + Sync {
+    /// Generates a new base32 encoded shared secret
+    fn generate_secret(&self) -> String;
+    /// Builds the otpauth:// URI used by authenticator apps to enrol the secret via a QR code
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, TotpError>;
+    /// Returns the time step the code was generated for, so that callers can refuse to accept
+    /// the same code twice, or `None` when the code does not match the secret
+    fn verify_code(&self, secret: &str, code: &str) -> Result<Option<u64>, TotpError>;
+}
+
+pub fn new_totp_service(issuer: &str) -> Arc<dyn TotpService> {
+    Arc::new(TotpServiceImpl {
+        issuer: issuer.to_owned(),
+    })
+}
@@ -1,4 +1,20 @@
+use crate::shared::extractors::file_handler::FileHandler;
+use axum::extract::FromRef;
+
 #[derive(Clone)]
 pub struct ServiceAppState<T> {
     pub service: T,
 }
+
+/// App state for endpoints that read or write uploaded files, allowing `FileHandler` to be
+/// extracted from the router state
+#[derive(Clone)]
+pub struct FileHandlingServiceAppState<T> {
+    pub service: T,
+    pub file_handler: FileHandler,
+}
+impl<T> FromRef<FileHandlingServiceAppState<T>> for FileHandler {
+    fn from_ref(state: &FileHandlingServiceAppState<T>) -> Self {
+        state.file_handler.clone()
+    }
+}
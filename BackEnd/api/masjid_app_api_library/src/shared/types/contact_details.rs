@@ -1,3 +1,4 @@
+use crate::shared::types::contact_visibility::ContactVisibility;
 use crate::shared::types::personal_title::PersonalTitle;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -14,4 +15,7 @@ pub struct ContactDetails {
 
     #[validate(email)]
     pub email: Option<String>,
+
+    #[serde(default)]
+    pub visibility: ContactVisibility,
 }
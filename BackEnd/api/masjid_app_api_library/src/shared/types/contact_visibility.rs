@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Which of the organiser's contact details are shown on the public API. Details which are hidden
+/// are only ever seen by administrators
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default)]
+pub struct ContactVisibility {
+    #[serde(rename(serialize = "fullName", deserialize = "fullName"))]
+    pub full_name: bool,
+
+    #[serde(rename(serialize = "phoneNumber", deserialize = "phoneNumber"))]
+    pub phone_number: bool,
+
+    pub email: bool,
+
+    /// Lets the public message the organiser through a form which is forwarded by email, without
+    /// the email address being shown
+    #[serde(rename(serialize = "contactForm", deserialize = "contactForm"))]
+    pub contact_form: bool,
+}
+
+impl Default for ContactVisibility {
+    fn default() -> Self {
+        Self {
+            full_name: true,
+            phone_number: false,
+            email: false,
+            contact_form: false,
+        }
+    }
+}
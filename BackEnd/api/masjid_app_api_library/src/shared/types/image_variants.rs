@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+/// The resized copies generated alongside every uploaded image
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageVariant {
+    /// Shown on the cards of event listings
+    Card,
+    /// Shown at the top of an event's detail page
+    Hero,
+}
+impl ImageVariant {
+    pub const ALL: [ImageVariant; 2] = [ImageVariant::Card, ImageVariant::Hero];
+
+    /// The exact width and height the variant is cropped to
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            ImageVariant::Card => (480, 320),
+            ImageVariant::Hero => (1600, 600),
+        }
+    }
+    fn suffix(&self) -> &'static str {
+        match self {
+            ImageVariant::Card => "card",
+            ImageVariant::Hero => "hero",
+        }
+    }
+    /// Derives the name of the variant from the name of the original image, so that variants
+    /// never need to be stored separately
+    pub fn file_name(&self, image_file_name: &str) -> String {
+        match image_file_name.rsplit_once('.') {
+            Some((stem, extension)) => format!("{}-{}.{}", stem, self.suffix(), extension),
+            None => format!("{}-{}", image_file_name, self.suffix()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ImageVariants {
+    #[serde(rename(serialize = "cardUrl", deserialize = "cardUrl"))]
+    pub card_url: String,
+
+    #[serde(rename(serialize = "heroUrl", deserialize = "heroUrl"))]
+    pub hero_url: String,
+}
+impl ImageVariants {
+    pub fn from_image_url(image_url: &str) -> Self {
+        Self {
+            card_url: ImageVariant::Card.file_name(image_url),
+            hero_url: ImageVariant::Hero.file_name(image_url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_variants_from_image_url() {
+        struct TestCase {
+            description: &'static str,
+            image_url: &'static str,
+            expected_result: ImageVariants,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the image has an extension, the variant should be named before it",
+                image_url: "/uploads/poster.png",
+                expected_result: ImageVariants {
+                    card_url: "/uploads/poster-card.png".to_owned(),
+                    hero_url: "/uploads/poster-hero.png".to_owned(),
+                },
+            },
+            TestCase {
+                description: "When the image has no extension, the variant should be appended",
+                image_url: "/uploads/poster",
+                expected_result: ImageVariants {
+                    card_url: "/uploads/poster-card".to_owned(),
+                    hero_url: "/uploads/poster-hero".to_owned(),
+                },
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_result,
+                ImageVariants::from_image_url(test_case.image_url)
+            );
+        }
+    }
+}
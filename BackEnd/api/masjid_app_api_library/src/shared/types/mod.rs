@@ -1,4 +1,7 @@
 pub mod age_range;
 pub mod app_state;
 pub mod contact_details;
+pub mod contact_visibility;
+pub mod image_variants;
 pub mod personal_title;
+pub mod public_contact_details;
@@ -0,0 +1,138 @@
+use crate::shared::types::contact_details::ContactDetails;
+use crate::shared::types::personal_title::PersonalTitle;
+use serde::Serialize;
+
+/// The organiser's contact details with every field they have chosen to hide left out
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PublicContactDetails {
+    #[serde(
+        rename(serialize = "fullName"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub full_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<PersonalTitle>,
+
+    #[serde(
+        rename(serialize = "phoneNumber"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub phone_number: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Whether the organiser can be messaged through the contact form
+    #[serde(rename(serialize = "contactForm"))]
+    pub contact_form: bool,
+}
+
+impl From<ContactDetails> for PublicContactDetails {
+    fn from(contact_details: ContactDetails) -> Self {
+        let visibility = contact_details.visibility;
+        Self {
+            full_name: visibility.full_name.then_some(contact_details.full_name),
+            title: contact_details.title.filter(|_| visibility.full_name),
+            phone_number: visibility
+                .phone_number
+                .then_some(contact_details.phone_number),
+            // Messages are forwarded to the email address, so the form needs one
+            contact_form: visibility.contact_form && contact_details.email.is_some(),
+            email: contact_details.email.filter(|_| visibility.email),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::types::contact_visibility::ContactVisibility;
+
+    #[test]
+    fn test_public_contact_details_from_contact_details() {
+        struct TestCase {
+            description: &'static str,
+            email: Option<&'static str>,
+            visibility: ContactVisibility,
+            expected_public_contact_details: PublicContactDetails,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the default visibility is used, only the name should be shown",
+                email: Some("johnsmith@test.com"),
+                visibility: ContactVisibility::default(),
+                expected_public_contact_details: PublicContactDetails {
+                    full_name: Some("John Smith".to_owned()),
+                    title: Some(PersonalTitle::Mr),
+                    phone_number: None,
+                    email: None,
+                    contact_form: false,
+                },
+            },
+            TestCase {
+                description: "When every field is shown, the contact details should be unchanged",
+                email: Some("johnsmith@test.com"),
+                visibility: ContactVisibility {
+                    full_name: true,
+                    phone_number: true,
+                    email: true,
+                    contact_form: true,
+                },
+                expected_public_contact_details: PublicContactDetails {
+                    full_name: Some("John Smith".to_owned()),
+                    title: Some(PersonalTitle::Mr),
+                    phone_number: Some("07123456789".to_owned()),
+                    email: Some("johnsmith@test.com".to_owned()),
+                    contact_form: true,
+                },
+            },
+            TestCase {
+                description: "When every field is hidden but the contact form is enabled, only the contact form should be offered",
+                email: Some("johnsmith@test.com"),
+                visibility: ContactVisibility {
+                    full_name: false,
+                    phone_number: false,
+                    email: false,
+                    contact_form: true,
+                },
+                expected_public_contact_details: PublicContactDetails {
+                    full_name: None,
+                    title: None,
+                    phone_number: None,
+                    email: None,
+                    contact_form: true,
+                },
+            },
+            TestCase {
+                description: "When the organiser has no email address, the contact form should not be offered",
+                email: None,
+                visibility: ContactVisibility {
+                    contact_form: true,
+                    ..ContactVisibility::default()
+                },
+                expected_public_contact_details: PublicContactDetails {
+                    full_name: Some("John Smith".to_owned()),
+                    title: Some(PersonalTitle::Mr),
+                    phone_number: None,
+                    email: None,
+                    contact_form: false,
+                },
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let contact_details = ContactDetails {
+                full_name: "John Smith".to_owned(),
+                title: Some(PersonalTitle::Mr),
+                phone_number: "07123456789".to_owned(),
+                email: test_case.email.map(ToOwned::to_owned),
+                visibility: test_case.visibility,
+            };
+            assert_eq!(
+                test_case.expected_public_contact_details,
+                PublicContactDetails::from(contact_details)
+            );
+        }
+    }
+}
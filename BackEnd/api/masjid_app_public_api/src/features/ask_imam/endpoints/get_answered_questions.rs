@@ -55,6 +55,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 2,
@@ -68,6 +69,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 3,
@@ -81,6 +83,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 4,
@@ -94,6 +97,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
         ]
     }
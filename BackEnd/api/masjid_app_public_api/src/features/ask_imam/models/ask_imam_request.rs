@@ -32,6 +32,7 @@ impl Into<ImamQuestion> for AskImamRequest {
             imam_name: None,
             answer: None,
             date_answered: None,
+            version: 1,
         }
     }
 }
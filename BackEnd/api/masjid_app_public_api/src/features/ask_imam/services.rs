@@ -137,6 +137,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 2,
@@ -150,6 +151,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 3,
@@ -163,6 +165,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 4,
@@ -176,6 +179,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
             ImamQuestionDTO {
                 id: 5,
@@ -189,6 +193,7 @@ mod tests {
                     text: "This is an answer".to_string(),
                     date_answered: Default::default(),
                 }),
+                version: 1,
             },
         ]
     }
@@ -479,6 +484,7 @@ mod tests {
             imam_name: None,
             answer: None,
             date_answered: None,
+            version: 1,
         };
         let test_cases = [
             TestCase {
@@ -0,0 +1,76 @@
+use crate::features::event_registrations::errors::cancel_event_registration_error::CancelEventRegistrationError;
+use crate::features::event_registrations::services::EventRegistrationPublicService;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn cancel_event_registration(
+    State(state): State<ServiceAppState<Arc<dyn EventRegistrationPublicService>>>,
+    Path(cancellation_token): Path<String>,
+) -> Response {
+    match state.service.cancel_registration(&cancellation_token).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(CancelEventRegistrationError::InvalidCancellationToken) => {
+            StatusCode::BAD_REQUEST.into_response()
+        }
+        Err(CancelEventRegistrationError::RegistrationNotFound) => {
+            StatusCode::NOT_FOUND.into_response()
+        }
+        Err(CancelEventRegistrationError::UnableToCancelRegistration) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_registrations::services::MockEventRegistrationPublicService;
+
+    #[tokio::test]
+    async fn test_cancel_event_registration() {
+        struct TestCase {
+            description: &'static str,
+            service_result: Result<(), CancelEventRegistrationError>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the cancellation token is not valid, I should get a BAD_REQUEST response",
+                service_result: Err(CancelEventRegistrationError::InvalidCancellationToken),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the registration has already been cancelled, I should get a NOT_FOUND response",
+                service_result: Err(CancelEventRegistrationError::RegistrationNotFound),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When cancellation fails, I should get an INTERNAL_SERVER_ERROR response",
+                service_result: Err(CancelEventRegistrationError::UnableToCancelRegistration),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When cancellation succeeds, I should get an OK response",
+                service_result: Ok(()),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRegistrationPublicService::new();
+            let service_result = test_case.service_result;
+            mock_service
+                .expect_cancel_registration()
+                .return_once(move |_| service_result);
+            let app_state = ServiceAppState::<Arc<dyn EventRegistrationPublicService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response =
+                cancel_event_registration(State(app_state), Path("token".to_owned())).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
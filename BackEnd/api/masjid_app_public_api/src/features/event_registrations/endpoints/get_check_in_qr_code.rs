@@ -0,0 +1,104 @@
+use crate::features::event_registrations::errors::get_check_in_qr_code_error::GetCheckInQrCodeError;
+use crate::features::event_registrations::models::get_check_in_qr_code_request::GetCheckInQrCodeRequest;
+use crate::features::event_registrations::services::EventRegistrationPublicService;
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn get_check_in_qr_code(
+    State(state): State<ServiceAppState<Arc<dyn EventRegistrationPublicService>>>,
+    Path(check_in_code): Path<String>,
+    Query(request): Query<GetCheckInQrCodeRequest>,
+) -> Response {
+    match state
+        .service
+        .get_check_in_qr_code(&check_in_code, request.format)
+    {
+        Ok(qr_code) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, request.format.content_type()),
+                // The code identifies the attendee, so it must not be kept by shared caches
+                (header::CACHE_CONTROL, "private, max-age=86400"),
+            ],
+            qr_code,
+        )
+            .into_response(),
+        Err(GetCheckInQrCodeError::InvalidCheckInCode) => StatusCode::NOT_FOUND.into_response(),
+        Err(GetCheckInQrCodeError::UnableToRenderQrCode) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_registrations::models::qr_code_format::QrCodeFormat;
+    use crate::features::event_registrations::services::MockEventRegistrationPublicService;
+
+    #[tokio::test]
+    async fn test_get_check_in_qr_code() {
+        struct TestCase {
+            description: &'static str,
+            format: QrCodeFormat,
+            service_result: Result<Vec<u8>, GetCheckInQrCodeError>,
+            expected_status_code: StatusCode,
+            expected_content_type: Option<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the check-in code is not genuine, I should get a NOT_FOUND response",
+                format: QrCodeFormat::Png,
+                service_result: Err(GetCheckInQrCodeError::InvalidCheckInCode),
+                expected_status_code: StatusCode::NOT_FOUND,
+                expected_content_type: None,
+            },
+            TestCase {
+                description: "When rendering fails, I should get an INTERNAL_SERVER_ERROR response",
+                format: QrCodeFormat::Png,
+                service_result: Err(GetCheckInQrCodeError::UnableToRenderQrCode),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+                expected_content_type: None,
+            },
+            TestCase {
+                description: "When an svg is requested, I should get the QR code as an svg",
+                format: QrCodeFormat::Svg,
+                service_result: Ok(b"<svg></svg>".to_vec()),
+                expected_status_code: StatusCode::OK,
+                expected_content_type: Some("image/svg+xml"),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRegistrationPublicService::new();
+            let service_result = test_case.service_result;
+            let expected_format = test_case.format;
+            mock_service
+                .expect_get_check_in_qr_code()
+                .withf(move |_, format| *format == expected_format)
+                .return_once(move |_, _| service_result);
+            let app_state = ServiceAppState::<Arc<dyn EventRegistrationPublicService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response = get_check_in_qr_code(
+                State(app_state),
+                Path("code".to_owned()),
+                Query(GetCheckInQrCodeRequest {
+                    format: test_case.format,
+                }),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+            assert_eq!(
+                test_case.expected_content_type,
+                actual_response
+                    .headers()
+                    .get(header::CONTENT_TYPE)
+                    .map(|value| value.to_str().unwrap())
+            );
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod cancel_event_registration;
+pub mod get_check_in_qr_code;
+pub mod register_for_event;
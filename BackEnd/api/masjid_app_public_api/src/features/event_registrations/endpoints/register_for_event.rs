@@ -0,0 +1,115 @@
+use crate::features::event_registrations::errors::insert_event_registration_error::InsertEventRegistrationError;
+use crate::features::event_registrations::models::register_for_event_request::RegisterForEventRequest;
+use crate::features::event_registrations::services::EventRegistrationPublicService;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn register_for_event(
+    State(state): State<ServiceAppState<Arc<dyn EventRegistrationPublicService>>>,
+    Path(event_id): Path<i32>,
+    Json(request): Json<RegisterForEventRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    match state.service.register_for_event(event_id, request).await {
+        Ok(confirmation) => (StatusCode::CREATED, Json(confirmation)).into_response(),
+        Err(InsertEventRegistrationError::EventNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(InsertEventRegistrationError::PartySizeExceedsCapacity) => {
+            StatusCode::UNPROCESSABLE_ENTITY.into_response()
+        }
+        Err(InsertEventRegistrationError::UnableToInsertRegistration) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_registrations::models::registration_confirmation::RegistrationConfirmation;
+    use crate::features::event_registrations::services::MockEventRegistrationPublicService;
+    use masjid_app_api_library::features::events::models::registration_status::RegistrationStatus;
+
+    #[tokio::test]
+    async fn test_register_for_event() {
+        struct TestCase {
+            description: &'static str,
+            request: RegisterForEventRequest,
+            expected_service_result:
+                Option<Result<RegistrationConfirmation, InsertEventRegistrationError>>,
+            expected_status_code: StatusCode,
+        }
+        let valid_request = RegisterForEventRequest {
+            full_name: "John Smith".to_owned(),
+            email: Some("johnsmith@test.com".to_owned()),
+            phone_number: None,
+            party_size: 2,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the request is not valid, I should get a BAD_REQUEST response",
+                request: RegisterForEventRequest {
+                    email: None,
+                    ..valid_request.clone()
+                },
+                expected_service_result: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the event does not exist, I should get a NOT_FOUND response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(InsertEventRegistrationError::EventNotFound)),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When the party could never fit in the event, I should get an UNPROCESSABLE_ENTITY response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(
+                    InsertEventRegistrationError::PartySizeExceedsCapacity,
+                )),
+                expected_status_code: StatusCode::UNPROCESSABLE_ENTITY,
+            },
+            TestCase {
+                description: "When insertion fails, I should get an INTERNAL_SERVER_ERROR response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(
+                    InsertEventRegistrationError::UnableToInsertRegistration,
+                )),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When the registration is waitlisted, I should still get a CREATED response",
+                request: valid_request,
+                expected_service_result: Some(Ok(RegistrationConfirmation {
+                    status: RegistrationStatus::Waitlisted,
+                    cancellation_token: "event-registration-1.signature".to_owned(),
+                    check_in_code: "event-check-in-1.signature".to_owned(),
+                })),
+                expected_status_code: StatusCode::CREATED,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRegistrationPublicService::new();
+            if let Some(expected_service_result) = test_case.expected_service_result {
+                mock_service
+                    .expect_register_for_event()
+                    .withf(|event_id, _| *event_id == 1)
+                    .return_once(move |_, _| expected_service_result);
+            }
+            let app_state = ServiceAppState::<Arc<dyn EventRegistrationPublicService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response =
+                register_for_event(State(app_state), Path(1), Json(test_case.request)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
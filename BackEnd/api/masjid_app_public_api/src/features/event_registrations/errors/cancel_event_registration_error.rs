@@ -0,0 +1,6 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum CancelEventRegistrationError {
+    InvalidCancellationToken,
+    RegistrationNotFound,
+    UnableToCancelRegistration,
+}
@@ -0,0 +1,5 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GetCheckInQrCodeError {
+    InvalidCheckInCode,
+    UnableToRenderQrCode,
+}
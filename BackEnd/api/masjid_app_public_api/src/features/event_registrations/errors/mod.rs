@@ -0,0 +1,3 @@
+pub mod cancel_event_registration_error;
+pub mod get_check_in_qr_code_error;
+pub mod insert_event_registration_error;
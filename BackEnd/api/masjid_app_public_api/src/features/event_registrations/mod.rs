@@ -0,0 +1,6 @@
+pub mod endpoints;
+pub mod errors;
+pub mod models;
+mod qr_code;
+pub mod repositories;
+pub mod services;
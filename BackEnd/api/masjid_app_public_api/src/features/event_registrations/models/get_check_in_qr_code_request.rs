@@ -0,0 +1,8 @@
+use crate::features::event_registrations::models::qr_code_format::QrCodeFormat;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct GetCheckInQrCodeRequest {
+    #[serde(default)]
+    pub format: QrCodeFormat,
+}
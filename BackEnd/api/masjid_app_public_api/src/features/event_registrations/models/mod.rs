@@ -0,0 +1,4 @@
+pub mod get_check_in_qr_code_request;
+pub mod qr_code_format;
+pub mod register_for_event_request;
+pub mod registration_confirmation;
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum QrCodeFormat {
+    #[default]
+    Png,
+    Svg,
+}
+impl QrCodeFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            QrCodeFormat::Png => "image/png",
+            QrCodeFormat::Svg => "image/svg+xml",
+        }
+    }
+}
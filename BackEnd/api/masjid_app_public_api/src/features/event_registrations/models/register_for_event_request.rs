@@ -0,0 +1,101 @@
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+pub const MAXIMUM_PARTY_SIZE: u8 = 20;
+
+#[derive(Deserialize, Validate, Clone, Debug, PartialEq)]
+#[validate(schema(function = "validate_contact_details"))]
+pub struct RegisterForEventRequest {
+    #[validate(length(min = 3, max = 100))]
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+
+    #[validate(email, length(max = 50))]
+    pub email: Option<String>,
+
+    #[validate(length(min = 7, max = 15))]
+    #[serde(rename = "phoneNumber")]
+    pub phone_number: Option<String>,
+
+    #[validate(range(min = 1, max = MAXIMUM_PARTY_SIZE))]
+    #[serde(rename = "partySize")]
+    pub party_size: u8,
+}
+
+// Organisers need some way of reaching attendees if the event changes
+fn validate_contact_details(request: &RegisterForEventRequest) -> Result<(), ValidationError> {
+    if request.email.is_none() && request.phone_number.is_none() {
+        return Err(ValidationError::new(
+            "either an email or a phone number is required",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_for_event_request_validation() {
+        struct TestCase {
+            description: &'static str,
+            request: RegisterForEventRequest,
+            expected_is_valid: bool,
+        }
+        let valid_request = RegisterForEventRequest {
+            full_name: "John Smith".to_owned(),
+            email: Some("johnsmith@test.com".to_owned()),
+            phone_number: None,
+            party_size: 2,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the request has a name, contact details and party size, it should be valid",
+                request: valid_request.clone(),
+                expected_is_valid: true,
+            },
+            TestCase {
+                description: "When the request only has a phone number, it should be valid",
+                request: RegisterForEventRequest {
+                    email: None,
+                    phone_number: Some("07123456789".to_owned()),
+                    ..valid_request.clone()
+                },
+                expected_is_valid: true,
+            },
+            TestCase {
+                description: "When the request has no way of contacting the attendee, it should be invalid",
+                request: RegisterForEventRequest {
+                    email: None,
+                    phone_number: None,
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When the party is empty, it should be invalid",
+                request: RegisterForEventRequest {
+                    party_size: 0,
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When the party is larger than the maximum, it should be invalid",
+                request: RegisterForEventRequest {
+                    party_size: MAXIMUM_PARTY_SIZE + 1,
+                    ..valid_request
+                },
+                expected_is_valid: false,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_is_valid,
+                test_case.request.validate().is_ok()
+            );
+        }
+    }
+}
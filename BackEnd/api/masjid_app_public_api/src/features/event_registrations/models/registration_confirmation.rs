@@ -0,0 +1,16 @@
+use masjid_app_api_library::features::events::models::registration_status::RegistrationStatus;
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct RegistrationConfirmation {
+    pub status: RegistrationStatus,
+
+    /// Lets the registration be cancelled without an account, so it should only be shared with
+    /// the person who registered
+    #[serde(rename = "cancellationToken")]
+    pub cancellation_token: String,
+
+    /// Presented at the door, usually as a QR code, to check the party in
+    #[serde(rename = "checkInCode")]
+    pub check_in_code: String,
+}
@@ -0,0 +1,59 @@
+use crate::features::event_registrations::errors::get_check_in_qr_code_error::GetCheckInQrCodeError;
+use crate::features::event_registrations::models::qr_code_format::QrCodeFormat;
+use image::{ImageFormat, Luma};
+use qrcode::QrCode;
+use qrcode::render::svg;
+use std::io::Cursor;
+
+/// Large enough to scan from a phone screen held up to another phone
+const MINIMUM_QR_CODE_DIMENSION: u32 = 256;
+
+pub(super) fn render_qr_code(
+    data: &str,
+    format: QrCodeFormat,
+) -> Result<Vec<u8>, GetCheckInQrCodeError> {
+    let qr_code = QrCode::new(data.as_bytes()).map_err(|err| {
+        tracing::error!("unable to encode QR code: {}", err);
+        GetCheckInQrCodeError::UnableToRenderQrCode
+    })?;
+    match format {
+        QrCodeFormat::Png => {
+            let image = qr_code
+                .render::<Luma<u8>>()
+                .min_dimensions(MINIMUM_QR_CODE_DIMENSION, MINIMUM_QR_CODE_DIMENSION)
+                .build();
+            let mut buffer = Cursor::new(Vec::new());
+            image
+                .write_to(&mut buffer, ImageFormat::Png)
+                .map_err(|err| {
+                    tracing::error!("unable to encode QR code as png: {}", err);
+                    GetCheckInQrCodeError::UnableToRenderQrCode
+                })?;
+            Ok(buffer.into_inner())
+        }
+        QrCodeFormat::Svg => Ok(qr_code
+            .render::<svg::Color>()
+            .min_dimensions(MINIMUM_QR_CODE_DIMENSION, MINIMUM_QR_CODE_DIMENSION)
+            .build()
+            .into_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_qr_code() {
+        eprintln!(
+            "When rendered as a png, the QR code should decode as an image of at least the minimum size"
+        );
+        let png = render_qr_code("event-check-in-1.signature", QrCodeFormat::Png).unwrap();
+        let image = image::load_from_memory_with_format(&png, ImageFormat::Png).unwrap();
+        assert!(image.width() >= MINIMUM_QR_CODE_DIMENSION);
+
+        eprintln!("When rendered as an svg, the QR code should be an svg document");
+        let svg = render_qr_code("event-check-in-1.signature", QrCodeFormat::Svg).unwrap();
+        assert!(String::from_utf8(svg).unwrap().contains("<svg"));
+    }
+}
@@ -0,0 +1,40 @@
+mod mysql_impl;
+mod redis_impl;
+
+use crate::features::event_registrations::errors::cancel_event_registration_error::CancelEventRegistrationError;
+use crate::features::event_registrations::errors::insert_event_registration_error::InsertEventRegistrationError;
+use crate::features::event_registrations::models::register_for_event_request::RegisterForEventRequest;
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::models::registration_status::RegistrationStatus;
+use masjid_app_api_library::new_repository;
+use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventRegistrationsPublicRepository: Send + Sync {
+    /// Registers the party for the event, returning the id of the registration along with
+    /// whether it was confirmed or placed on the waitlist
+    async fn insert_event_registration(
+        &self,
+        event_id: &i32,
+        registration: &RegisterForEventRequest,
+    ) -> Result<(i32, RegistrationStatus), InsertEventRegistrationError>;
+    /// Removes the registration, promoting anybody on the waitlist who now fits
+    async fn cancel_event_registration(
+        &self,
+        registration_id: &i32,
+    ) -> Result<(), CancelEventRegistrationError>;
+}
+
+pub async fn new_event_registrations_public_repository(
+    repository_mode: RepositoryMode,
+) -> Arc<dyn EventRegistrationsPublicRepository> {
+    new_repository!(repository_mode, RepositoryType::Events)
+}
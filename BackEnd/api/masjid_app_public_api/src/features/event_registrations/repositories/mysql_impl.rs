@@ -0,0 +1,75 @@
+use crate::features::event_registrations::errors::cancel_event_registration_error::CancelEventRegistrationError;
+use crate::features::event_registrations::errors::insert_event_registration_error::InsertEventRegistrationError;
+use crate::features::event_registrations::models::register_for_event_request::RegisterForEventRequest;
+use crate::features::event_registrations::repositories::EventRegistrationsPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::models::registration_status::RegistrationStatus;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use sqlx::Row;
+use std::str::FromStr;
+
+#[async_trait]
+impl EventRegistrationsPublicRepository for MySqlRepository {
+    async fn insert_event_registration(
+        &self,
+        event_id: &i32,
+        registration: &RegisterForEventRequest,
+    ) -> Result<(i32, RegistrationStatus), InsertEventRegistrationError> {
+        let db_connection = self.db_connection.clone();
+        let row = sqlx::query("CALL insert_event_registration(?, ?, ?, ?, ?)")
+            .bind(&event_id)
+            .bind(&registration.full_name)
+            .bind(&registration.email)
+            .bind(&registration.phone_number)
+            .bind(&registration.party_size)
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    match database_error.message() {
+                        "Event not found" => return InsertEventRegistrationError::EventNotFound,
+                        "Party size exceeds capacity" => {
+                            return InsertEventRegistrationError::PartySizeExceedsCapacity;
+                        }
+                        _ => {}
+                    }
+                }
+                tracing::error!(
+                    "Unable to insert event registration due to the following error: {}",
+                    err
+                );
+                InsertEventRegistrationError::UnableToInsertRegistration
+            })?;
+        let registration_id: u64 = row.get(0);
+        let status: String = row.get(1);
+        let status = RegistrationStatus::from_str(&status).map_err(|_| {
+            tracing::error!(status = status, "unrecognised registration status");
+            InsertEventRegistrationError::UnableToInsertRegistration
+        })?;
+        Ok((registration_id as i32, status))
+    }
+
+    async fn cancel_event_registration(
+        &self,
+        registration_id: &i32,
+    ) -> Result<(), CancelEventRegistrationError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL cancel_event_registration(?)")
+            .bind(&registration_id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Registration not found" {
+                        return CancelEventRegistrationError::RegistrationNotFound;
+                    }
+                }
+                tracing::error!(
+                    "Unable to cancel event registration due to the following error: {}",
+                    err
+                );
+                CancelEventRegistrationError::UnableToCancelRegistration
+            })?;
+        Ok(())
+    }
+}
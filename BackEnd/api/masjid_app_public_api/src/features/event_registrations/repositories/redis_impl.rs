@@ -0,0 +1,27 @@
+use crate::features::event_registrations::errors::cancel_event_registration_error::CancelEventRegistrationError;
+use crate::features::event_registrations::errors::insert_event_registration_error::InsertEventRegistrationError;
+use crate::features::event_registrations::models::register_for_event_request::RegisterForEventRequest;
+use crate::features::event_registrations::repositories::EventRegistrationsPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::models::registration_status::RegistrationStatus;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+
+#[async_trait]
+impl EventRegistrationsPublicRepository for InMemoryRepository {
+    async fn insert_event_registration(
+        &self,
+        event_id: &i32,
+        registration: &RegisterForEventRequest,
+    ) -> Result<(i32, RegistrationStatus), InsertEventRegistrationError> {
+        tracing::warn!("in-memory database not implemented for insert_event_registration");
+        Err(InsertEventRegistrationError::UnableToInsertRegistration)
+    }
+
+    async fn cancel_event_registration(
+        &self,
+        registration_id: &i32,
+    ) -> Result<(), CancelEventRegistrationError> {
+        tracing::warn!("in-memory database not implemented for cancel_event_registration");
+        Err(CancelEventRegistrationError::UnableToCancelRegistration)
+    }
+}
@@ -0,0 +1,168 @@
+use crate::features::event_registrations::errors::cancel_event_registration_error::CancelEventRegistrationError;
+use crate::features::event_registrations::errors::get_check_in_qr_code_error::GetCheckInQrCodeError;
+use crate::features::event_registrations::errors::insert_event_registration_error::InsertEventRegistrationError;
+use crate::features::event_registrations::models::qr_code_format::QrCodeFormat;
+use crate::features::event_registrations::models::register_for_event_request::RegisterForEventRequest;
+use crate::features::event_registrations::models::registration_confirmation::RegistrationConfirmation;
+use crate::features::event_registrations::qr_code::render_qr_code;
+use crate::features::event_registrations::repositories::EventRegistrationsPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::services::registration_tokens::{
+    RegistrationTokenPurpose, issue_registration_token, registration_id_from_token,
+};
+use masjid_app_api_library::shared::services::token_signing::r#trait::TokenSigner;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventRegistrationPublicService: Send + Sync {
+    async fn register_for_event(
+        &self,
+        event_id: i32,
+        request: RegisterForEventRequest,
+    ) -> Result<RegistrationConfirmation, InsertEventRegistrationError>;
+    async fn cancel_registration(
+        &self,
+        cancellation_token: &str,
+    ) -> Result<(), CancelEventRegistrationError>;
+    /// Renders the check-in code as a QR code for volunteers to scan at the door
+    fn get_check_in_qr_code(
+        &self,
+        check_in_code: &str,
+        format: QrCodeFormat,
+    ) -> Result<Vec<u8>, GetCheckInQrCodeError>;
+}
+
+pub fn new_event_registration_public_service(
+    repository: Arc<dyn EventRegistrationsPublicRepository>,
+    token_signer: Arc<dyn TokenSigner>,
+) -> Arc<dyn EventRegistrationPublicService> {
+    Arc::new(EventRegistrationPublicServiceImpl {
+        repository,
+        token_signer,
+    })
+}
+
+struct EventRegistrationPublicServiceImpl {
+    repository: Arc<dyn EventRegistrationsPublicRepository>,
+    token_signer: Arc<dyn TokenSigner>,
+}
+
+#[async_trait]
+impl EventRegistrationPublicService for EventRegistrationPublicServiceImpl {
+    async fn register_for_event(
+        &self,
+        event_id: i32,
+        request: RegisterForEventRequest,
+    ) -> Result<RegistrationConfirmation, InsertEventRegistrationError> {
+        let (registration_id, status) = self
+            .repository
+            .insert_event_registration(&event_id, &request)
+            .await?;
+        Ok(RegistrationConfirmation {
+            status,
+            cancellation_token: issue_registration_token(
+                &*self.token_signer,
+                RegistrationTokenPurpose::Cancellation,
+                registration_id,
+            ),
+            check_in_code: issue_registration_token(
+                &*self.token_signer,
+                RegistrationTokenPurpose::CheckIn,
+                registration_id,
+            ),
+        })
+    }
+
+    async fn cancel_registration(
+        &self,
+        cancellation_token: &str,
+    ) -> Result<(), CancelEventRegistrationError> {
+        let registration_id = registration_id_from_token(
+            &*self.token_signer,
+            RegistrationTokenPurpose::Cancellation,
+            cancellation_token,
+        )
+        .ok_or(CancelEventRegistrationError::InvalidCancellationToken)?;
+        self.repository
+            .cancel_event_registration(&registration_id)
+            .await
+    }
+
+    fn get_check_in_qr_code(
+        &self,
+        check_in_code: &str,
+        format: QrCodeFormat,
+    ) -> Result<Vec<u8>, GetCheckInQrCodeError> {
+        // Only genuine codes are rendered, so the endpoint cannot be used as a general QR generator
+        registration_id_from_token(
+            &*self.token_signer,
+            RegistrationTokenPurpose::CheckIn,
+            check_in_code,
+        )
+        .ok_or(GetCheckInQrCodeError::InvalidCheckInCode)?;
+        render_qr_code(check_in_code, format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_registrations::repositories::MockEventRegistrationsPublicRepository;
+    use masjid_app_api_library::shared::services::token_signing::r#trait::new_token_signer;
+
+    const SECRET: &[u8] = b"secret";
+
+    #[tokio::test]
+    async fn test_cancel_registration() {
+        struct TestCase {
+            description: &'static str,
+            cancellation_token: String,
+            expected_registration_id: Option<i32>,
+            expected_result: Result<(), CancelEventRegistrationError>,
+        }
+        let token_signer = new_token_signer(SECRET);
+        let test_cases = [
+            TestCase {
+                description: "When the token was not signed by this service, I should get an invalid cancellation token error",
+                cancellation_token: new_token_signer(b"another secret")
+                    .sign("event-registration-1"),
+                expected_registration_id: None,
+                expected_result: Err(CancelEventRegistrationError::InvalidCancellationToken),
+            },
+            TestCase {
+                description: "When the token is a check-in code rather than a cancellation token, I should get an invalid cancellation token error",
+                cancellation_token: token_signer.sign("event-check-in-7"),
+                expected_registration_id: None,
+                expected_result: Err(CancelEventRegistrationError::InvalidCancellationToken),
+            },
+            TestCase {
+                description: "When the token is valid, the registration it was issued for should be cancelled",
+                cancellation_token: token_signer.sign("event-registration-7"),
+                expected_registration_id: Some(7),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventRegistrationsPublicRepository::new();
+            if let Some(expected_registration_id) = test_case.expected_registration_id {
+                mock_repository
+                    .expect_cancel_event_registration()
+                    .withf(move |registration_id| *registration_id == expected_registration_id)
+                    .return_once(|_| Ok(()));
+            }
+            let service = new_event_registration_public_service(
+                Arc::new(mock_repository),
+                new_token_signer(SECRET),
+            );
+            assert_eq!(
+                test_case.expected_result,
+                service
+                    .cancel_registration(&test_case.cancellation_token)
+                    .await
+            );
+        }
+    }
+}
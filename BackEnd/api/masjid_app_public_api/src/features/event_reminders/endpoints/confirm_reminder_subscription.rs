@@ -0,0 +1,76 @@
+use crate::features::event_reminders::errors::confirm_reminder_subscription_error::ConfirmReminderSubscriptionError;
+use crate::features::event_reminders::services::EventRemindersPublicService;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+
+pub async fn confirm_reminder_subscription(
+    State(state): State<ServiceAppState<Arc<dyn EventRemindersPublicService>>>,
+    Path(confirmation_token): Path<String>,
+) -> Response {
+    match state.service.confirm(&confirmation_token).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(ConfirmReminderSubscriptionError::InvalidConfirmationToken) => {
+            StatusCode::BAD_REQUEST.into_response()
+        }
+        Err(ConfirmReminderSubscriptionError::SubscriptionNotFound) => {
+            StatusCode::NOT_FOUND.into_response()
+        }
+        Err(ConfirmReminderSubscriptionError::UnableToConfirm) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_reminders::services::MockEventRemindersPublicService;
+
+    #[tokio::test]
+    async fn test_confirm_reminder_subscription() {
+        struct TestCase {
+            description: &'static str,
+            service_result: Result<(), ConfirmReminderSubscriptionError>,
+            expected_status_code: StatusCode,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the confirmation token is not valid, I should get a BAD_REQUEST response",
+                service_result: Err(ConfirmReminderSubscriptionError::InvalidConfirmationToken),
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the subscription has been removed, I should get a NOT_FOUND response",
+                service_result: Err(ConfirmReminderSubscriptionError::SubscriptionNotFound),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When confirming fails, I should get an INTERNAL_SERVER_ERROR response",
+                service_result: Err(ConfirmReminderSubscriptionError::UnableToConfirm),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When confirming succeeds, I should get an OK response",
+                service_result: Ok(()),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRemindersPublicService::new();
+            let service_result = test_case.service_result;
+            mock_service
+                .expect_confirm()
+                .return_once(move |_| service_result);
+            let app_state = ServiceAppState::<Arc<dyn EventRemindersPublicService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response =
+                confirm_reminder_subscription(State(app_state), Path("token".to_owned())).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
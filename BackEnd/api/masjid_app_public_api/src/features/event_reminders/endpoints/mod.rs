@@ -0,0 +1,3 @@
+pub mod confirm_reminder_subscription;
+pub mod subscribe_to_reminders;
+pub mod unsubscribe_from_reminders;
@@ -0,0 +1,109 @@
+use crate::features::event_reminders::errors::subscribe_to_reminders_error::SubscribeToRemindersError;
+use crate::features::event_reminders::models::subscribe_to_reminders_request::SubscribeToRemindersRequest;
+use crate::features::event_reminders::services::EventRemindersPublicService;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn subscribe_to_reminders(
+    State(state): State<ServiceAppState<Arc<dyn EventRemindersPublicService>>>,
+    Json(request): Json<SubscribeToRemindersRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    match state.service.subscribe(request).await {
+        Ok(confirmation) => (StatusCode::CREATED, Json(confirmation)).into_response(),
+        Err(SubscribeToRemindersError::EventNotFound) => StatusCode::NOT_FOUND.into_response(),
+        Err(
+            SubscribeToRemindersError::UnableToSubscribe
+            | SubscribeToRemindersError::UnableToSendConfirmation,
+        ) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_reminders::models::reminder_subscription_confirmation::ReminderSubscriptionConfirmation;
+    use crate::features::event_reminders::services::MockEventRemindersPublicService;
+    use masjid_app_api_library::features::events::models::reminder_channel::ReminderChannel;
+
+    #[tokio::test]
+    async fn test_subscribe_to_reminders() {
+        struct TestCase {
+            description: &'static str,
+            request: SubscribeToRemindersRequest,
+            expected_service_result:
+                Option<Result<ReminderSubscriptionConfirmation, SubscribeToRemindersError>>,
+            expected_status_code: StatusCode,
+        }
+        let valid_request = SubscribeToRemindersRequest {
+            event_id: Some(1),
+            event_type: None,
+            channel: ReminderChannel::Email,
+            recipient: "johnsmith@test.com".to_owned(),
+            hours_before: 24,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the request is not valid, I should get a BAD_REQUEST response",
+                request: SubscribeToRemindersRequest {
+                    event_id: None,
+                    ..valid_request.clone()
+                },
+                expected_service_result: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the event does not exist, I should get a NOT_FOUND response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(SubscribeToRemindersError::EventNotFound)),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When subscribing fails, I should get an INTERNAL_SERVER_ERROR response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(SubscribeToRemindersError::UnableToSubscribe)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When the confirmation email cannot be sent, I should get an INTERNAL_SERVER_ERROR response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(
+                    SubscribeToRemindersError::UnableToSendConfirmation,
+                )),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When subscribing succeeds, I should get a CREATED response",
+                request: valid_request,
+                expected_service_result: Some(Ok(ReminderSubscriptionConfirmation {
+                    unsubscribe_token: "event-reminder-1.signature".to_owned(),
+                    confirmation_required: true,
+                })),
+                expected_status_code: StatusCode::CREATED,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockEventRemindersPublicService::new();
+            if let Some(expected_service_result) = test_case.expected_service_result {
+                mock_service
+                    .expect_subscribe()
+                    .return_once(move |_| expected_service_result);
+            }
+            let app_state = ServiceAppState::<Arc<dyn EventRemindersPublicService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response =
+                subscribe_to_reminders(State(app_state), Json(test_case.request)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
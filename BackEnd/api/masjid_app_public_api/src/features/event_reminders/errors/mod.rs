@@ -0,0 +1,3 @@
+pub mod confirm_reminder_subscription_error;
+pub mod subscribe_to_reminders_error;
+pub mod unsubscribe_from_reminders_error;
@@ -0,0 +1,7 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SubscribeToRemindersError {
+    EventNotFound,
+    UnableToSubscribe,
+    /// The subscription was made, but the email asking the recipient to confirm it was not sent
+    UnableToSendConfirmation,
+}
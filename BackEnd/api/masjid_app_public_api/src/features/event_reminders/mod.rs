@@ -0,0 +1,5 @@
+pub mod endpoints;
+pub mod errors;
+pub mod models;
+pub mod repositories;
+pub mod services;
@@ -0,0 +1,2 @@
+pub mod reminder_subscription_confirmation;
+pub mod subscribe_to_reminders_request;
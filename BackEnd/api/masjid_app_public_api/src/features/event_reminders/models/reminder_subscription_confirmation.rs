@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReminderSubscriptionConfirmation {
+    /// Lets the reminders be stopped without an account, so it should only be shared with the
+    /// person who subscribed
+    #[serde(rename = "unsubscribeToken")]
+    pub unsubscribe_token: String,
+    /// Email reminders are only sent once the link emailed to the recipient has been followed
+    #[serde(rename = "confirmationRequired")]
+    pub confirmation_required: bool,
+}
@@ -0,0 +1,127 @@
+use masjid_app_api_library::features::events::models::event_type::EventType;
+use masjid_app_api_library::features::events::models::reminder_channel::ReminderChannel;
+use serde::Deserialize;
+use validator::{Validate, ValidateEmail, ValidationError};
+
+/// Reminders can be sent up to a week ahead of each occurrence
+pub const MAXIMUM_HOURS_BEFORE: u16 = 168;
+
+#[derive(Deserialize, Validate, Clone, Debug, PartialEq)]
+#[validate(schema(function = "validate_subscription"))]
+pub struct SubscribeToRemindersRequest {
+    #[serde(rename = "eventId")]
+    pub event_id: Option<i32>,
+
+    #[serde(rename = "eventType")]
+    pub event_type: Option<EventType>,
+
+    pub channel: ReminderChannel,
+
+    /// The device token for push reminders, otherwise the email address
+    #[validate(length(min = 1, max = 255))]
+    pub recipient: String,
+
+    #[validate(range(min = 1, max = MAXIMUM_HOURS_BEFORE))]
+    #[serde(rename = "hoursBefore")]
+    pub hours_before: u16,
+}
+
+fn validate_subscription(request: &SubscribeToRemindersRequest) -> Result<(), ValidationError> {
+    if request.event_id.is_some() == request.event_type.is_some() {
+        return Err(ValidationError::new(
+            "reminders are either for an event or for an event type",
+        ));
+    }
+    if request.channel == ReminderChannel::Email && !request.recipient.validate_email() {
+        return Err(ValidationError::new(
+            "email reminders require an email address",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe_to_reminders_request_validation() {
+        struct TestCase {
+            description: &'static str,
+            request: SubscribeToRemindersRequest,
+            expected_is_valid: bool,
+        }
+        let valid_request = SubscribeToRemindersRequest {
+            event_id: Some(1),
+            event_type: None,
+            channel: ReminderChannel::Email,
+            recipient: "johnsmith@test.com".to_owned(),
+            hours_before: 24,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the request is for an event by email, it should be valid",
+                request: valid_request.clone(),
+                expected_is_valid: true,
+            },
+            TestCase {
+                description: "When the request is for an event type by push notification, it should be valid",
+                request: SubscribeToRemindersRequest {
+                    event_id: None,
+                    event_type: Some(EventType::Class),
+                    channel: ReminderChannel::Push,
+                    recipient: "device-token".to_owned(),
+                    ..valid_request.clone()
+                },
+                expected_is_valid: true,
+            },
+            TestCase {
+                description: "When the request is for both an event and an event type, it should be invalid",
+                request: SubscribeToRemindersRequest {
+                    event_type: Some(EventType::Class),
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When the request is for neither an event nor an event type, it should be invalid",
+                request: SubscribeToRemindersRequest {
+                    event_id: None,
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When an email reminder is not sent to an email address, it should be invalid",
+                request: SubscribeToRemindersRequest {
+                    recipient: "device-token".to_owned(),
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When the reminder is sent at the start of the event, it should be invalid",
+                request: SubscribeToRemindersRequest {
+                    hours_before: 0,
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When the reminder is sent more than a week ahead, it should be invalid",
+                request: SubscribeToRemindersRequest {
+                    hours_before: MAXIMUM_HOURS_BEFORE + 1,
+                    ..valid_request
+                },
+                expected_is_valid: false,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_is_valid,
+                test_case.request.validate().is_ok()
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+mod mysql_impl;
+mod redis_impl;
+
+use crate::features::event_reminders::errors::confirm_reminder_subscription_error::ConfirmReminderSubscriptionError;
+use crate::features::event_reminders::errors::subscribe_to_reminders_error::SubscribeToRemindersError;
+use crate::features::event_reminders::errors::unsubscribe_from_reminders_error::UnsubscribeFromRemindersError;
+use crate::features::event_reminders::models::subscribe_to_reminders_request::SubscribeToRemindersRequest;
+use async_trait::async_trait;
+use masjid_app_api_library::new_repository;
+use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventRemindersPublicRepository: Send + Sync {
+    /// Subscribes the recipient to reminders, returning the id of the subscription. Email
+    /// subscriptions are not sent reminders until they have been confirmed
+    async fn insert_reminder_subscription(
+        &self,
+        subscription: &SubscribeToRemindersRequest,
+    ) -> Result<i32, SubscribeToRemindersError>;
+    async fn confirm_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), ConfirmReminderSubscriptionError>;
+    async fn delete_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), UnsubscribeFromRemindersError>;
+}
+
+pub async fn new_event_reminders_public_repository(
+    repository_mode: RepositoryMode,
+) -> Arc<dyn EventRemindersPublicRepository> {
+    new_repository!(repository_mode, RepositoryType::Events)
+}
@@ -0,0 +1,93 @@
+use crate::features::event_reminders::errors::confirm_reminder_subscription_error::ConfirmReminderSubscriptionError;
+use crate::features::event_reminders::errors::subscribe_to_reminders_error::SubscribeToRemindersError;
+use crate::features::event_reminders::errors::unsubscribe_from_reminders_error::UnsubscribeFromRemindersError;
+use crate::features::event_reminders::models::subscribe_to_reminders_request::SubscribeToRemindersRequest;
+use crate::features::event_reminders::repositories::EventRemindersPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use sqlx::Row;
+
+#[async_trait]
+impl EventRemindersPublicRepository for MySqlRepository {
+    async fn insert_reminder_subscription(
+        &self,
+        subscription: &SubscribeToRemindersRequest,
+    ) -> Result<i32, SubscribeToRemindersError> {
+        let db_connection = self.db_connection.clone();
+        let row = sqlx::query("CALL insert_event_reminder_subscription(?, ?, ?, ?, ?)")
+            .bind(&subscription.event_id)
+            .bind(
+                subscription
+                    .event_type
+                    .as_ref()
+                    .map(|event_type| event_type.to_string()),
+            )
+            .bind(subscription.channel.to_string())
+            .bind(&subscription.recipient)
+            .bind(&subscription.hours_before)
+            .fetch_one(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Event not found" {
+                        return SubscribeToRemindersError::EventNotFound;
+                    }
+                }
+                tracing::error!(
+                    "Unable to insert reminder subscription due to the following error: {}",
+                    err
+                );
+                SubscribeToRemindersError::UnableToSubscribe
+            })?;
+        let subscription_id: u64 = row.get(0);
+        Ok(subscription_id as i32)
+    }
+
+    async fn confirm_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), ConfirmReminderSubscriptionError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL confirm_event_reminder_subscription(?)")
+            .bind(&subscription_id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Subscription not found" {
+                        return ConfirmReminderSubscriptionError::SubscriptionNotFound;
+                    }
+                }
+                tracing::error!(
+                    "Unable to confirm reminder subscription due to the following error: {}",
+                    err
+                );
+                ConfirmReminderSubscriptionError::UnableToConfirm
+            })?;
+        Ok(())
+    }
+
+    async fn delete_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), UnsubscribeFromRemindersError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL delete_event_reminder_subscription(?)")
+            .bind(&subscription_id)
+            .execute(&*db_connection)
+            .await
+            .map_err(|err| {
+                if let sqlx::Error::Database(ref database_error) = err {
+                    if database_error.message() == "Subscription not found" {
+                        return UnsubscribeFromRemindersError::SubscriptionNotFound;
+                    }
+                }
+                tracing::error!(
+                    "Unable to delete reminder subscription due to the following error: {}",
+                    err
+                );
+                UnsubscribeFromRemindersError::UnableToUnsubscribe
+            })?;
+        Ok(())
+    }
+}
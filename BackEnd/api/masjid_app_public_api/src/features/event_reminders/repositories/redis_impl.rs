@@ -0,0 +1,34 @@
+use crate::features::event_reminders::errors::confirm_reminder_subscription_error::ConfirmReminderSubscriptionError;
+use crate::features::event_reminders::errors::subscribe_to_reminders_error::SubscribeToRemindersError;
+use crate::features::event_reminders::errors::unsubscribe_from_reminders_error::UnsubscribeFromRemindersError;
+use crate::features::event_reminders::models::subscribe_to_reminders_request::SubscribeToRemindersRequest;
+use crate::features::event_reminders::repositories::EventRemindersPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+
+#[async_trait]
+impl EventRemindersPublicRepository for InMemoryRepository {
+    async fn insert_reminder_subscription(
+        &self,
+        subscription: &SubscribeToRemindersRequest,
+    ) -> Result<i32, SubscribeToRemindersError> {
+        tracing::warn!("in-memory database not implemented for insert_reminder_subscription");
+        Err(SubscribeToRemindersError::UnableToSubscribe)
+    }
+
+    async fn confirm_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), ConfirmReminderSubscriptionError> {
+        tracing::warn!("in-memory database not implemented for confirm_reminder_subscription");
+        Err(ConfirmReminderSubscriptionError::UnableToConfirm)
+    }
+
+    async fn delete_reminder_subscription(
+        &self,
+        subscription_id: &i32,
+    ) -> Result<(), UnsubscribeFromRemindersError> {
+        tracing::warn!("in-memory database not implemented for delete_reminder_subscription");
+        Err(UnsubscribeFromRemindersError::UnableToUnsubscribe)
+    }
+}
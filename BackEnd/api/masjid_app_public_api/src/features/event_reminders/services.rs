@@ -0,0 +1,357 @@
+use crate::features::event_reminders::errors::confirm_reminder_subscription_error::ConfirmReminderSubscriptionError;
+use crate::features::event_reminders::errors::subscribe_to_reminders_error::SubscribeToRemindersError;
+use crate::features::event_reminders::errors::unsubscribe_from_reminders_error::UnsubscribeFromRemindersError;
+use crate::features::event_reminders::models::reminder_subscription_confirmation::ReminderSubscriptionConfirmation;
+use crate::features::event_reminders::models::subscribe_to_reminders_request::SubscribeToRemindersRequest;
+use crate::features::event_reminders::repositories::EventRemindersPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::models::reminder_channel::ReminderChannel;
+use masjid_app_api_library::features::events::services::reminder_tokens::{
+    ReminderTokenPurpose, issue_reminder_token, subscription_id_from_token,
+};
+use masjid_app_api_library::shared::services::mail::mail_message::MailMessage;
+use masjid_app_api_library::shared::services::mail::r#trait::MailSender;
+use masjid_app_api_library::shared::services::token_signing::r#trait::TokenSigner;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventRemindersPublicService: Send + Sync {
+    /// Subscribes the recipient to reminders. Email subscriptions are sent a confirmation link,
+    /// and are not sent reminders until it has been followed
+    async fn subscribe(
+        &self,
+        request: SubscribeToRemindersRequest,
+    ) -> Result<ReminderSubscriptionConfirmation, SubscribeToRemindersError>;
+    async fn confirm(
+        &self,
+        confirmation_token: &str,
+    ) -> Result<(), ConfirmReminderSubscriptionError>;
+    async fn unsubscribe(
+        &self,
+        unsubscribe_token: &str,
+    ) -> Result<(), UnsubscribeFromRemindersError>;
+}
+
+pub fn new_event_reminders_public_service(
+    repository: Arc<dyn EventRemindersPublicRepository>,
+    token_signer: Arc<dyn TokenSigner>,
+    mail_sender: Arc<dyn MailSender>,
+    confirmation_url: String,
+) -> Arc<dyn EventRemindersPublicService> {
+    Arc::new(EventRemindersPublicServiceImpl {
+        repository,
+        token_signer,
+        mail_sender,
+        confirmation_url,
+    })
+}
+
+struct EventRemindersPublicServiceImpl {
+    repository: Arc<dyn EventRemindersPublicRepository>,
+    token_signer: Arc<dyn TokenSigner>,
+    mail_sender: Arc<dyn MailSender>,
+    confirmation_url: String,
+}
+
+impl EventRemindersPublicServiceImpl {
+    async fn send_confirmation(
+        &self,
+        recipient: &str,
+        subscription_id: i32,
+    ) -> Result<(), SubscribeToRemindersError> {
+        let confirmation_token = issue_reminder_token(
+            &*self.token_signer,
+            ReminderTokenPurpose::Confirmation,
+            subscription_id,
+        );
+        self.mail_sender
+            .send(MailMessage {
+                to: recipient.to_owned(),
+                subject: "Confirm your MasjidApp event reminders".to_owned(),
+                body: format!(
+                    "Assalamu alaikum,\n\n\
+                    Event reminders have been requested for this email address. \
+                    Use the link below to start receiving them.\n\n\
+                    {}?token={confirmation_token}\n\n\
+                    If you did not ask for event reminders, you can safely ignore this email.",
+                    self.confirmation_url
+                ),
+                reply_to: None,
+            })
+            .await
+            .map_err(|err| {
+                tracing::error!(subscription_id = subscription_id, error = ?err, "unable to send reminder confirmation");
+                SubscribeToRemindersError::UnableToSendConfirmation
+            })
+    }
+}
+
+#[async_trait]
+impl EventRemindersPublicService for EventRemindersPublicServiceImpl {
+    async fn subscribe(
+        &self,
+        request: SubscribeToRemindersRequest,
+    ) -> Result<ReminderSubscriptionConfirmation, SubscribeToRemindersError> {
+        let subscription_id = self
+            .repository
+            .insert_reminder_subscription(&request)
+            .await?;
+        let confirmation_required = request.channel == ReminderChannel::Email;
+        if confirmation_required {
+            self.send_confirmation(&request.recipient, subscription_id)
+                .await?;
+        }
+        Ok(ReminderSubscriptionConfirmation {
+            unsubscribe_token: issue_reminder_token(
+                &*self.token_signer,
+                ReminderTokenPurpose::Unsubscribe,
+                subscription_id,
+            ),
+            confirmation_required,
+        })
+    }
+
+    async fn confirm(
+        &self,
+        confirmation_token: &str,
+    ) -> Result<(), ConfirmReminderSubscriptionError> {
+        let subscription_id = subscription_id_from_token(
+            &*self.token_signer,
+            ReminderTokenPurpose::Confirmation,
+            confirmation_token,
+        )
+        .ok_or(ConfirmReminderSubscriptionError::InvalidConfirmationToken)?;
+        self.repository
+            .confirm_reminder_subscription(&subscription_id)
+            .await
+    }
+
+    async fn unsubscribe(
+        &self,
+        unsubscribe_token: &str,
+    ) -> Result<(), UnsubscribeFromRemindersError> {
+        let subscription_id = subscription_id_from_token(
+            &*self.token_signer,
+            ReminderTokenPurpose::Unsubscribe,
+            unsubscribe_token,
+        )
+        .ok_or(UnsubscribeFromRemindersError::InvalidUnsubscribeToken)?;
+        self.repository
+            .delete_reminder_subscription(&subscription_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::event_reminders::repositories::MockEventRemindersPublicRepository;
+    use masjid_app_api_library::shared::services::mail::errors::SendMailError;
+    use masjid_app_api_library::shared::services::mail::r#trait::MockMailSender;
+    use masjid_app_api_library::shared::services::token_signing::r#trait::new_token_signer;
+
+    const SECRET: &[u8] = b"secret";
+    const CONFIRMATION_URL: &str = "https://masjidapp.com/reminders/confirm";
+
+    fn new_service(
+        mock_repository: MockEventRemindersPublicRepository,
+        mock_mail_sender: MockMailSender,
+    ) -> Arc<dyn EventRemindersPublicService> {
+        new_event_reminders_public_service(
+            Arc::new(mock_repository),
+            new_token_signer(SECRET),
+            Arc::new(mock_mail_sender),
+            CONFIRMATION_URL.to_owned(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_subscribe() {
+        struct TestCase {
+            description: &'static str,
+            channel: ReminderChannel,
+            recipient: &'static str,
+            send_result: Option<Result<(), SendMailError>>,
+            expected_confirmation_required: Result<bool, SubscribeToRemindersError>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When subscribing to push reminders, no confirmation should be required",
+                channel: ReminderChannel::Push,
+                recipient: "device-token",
+                send_result: None,
+                expected_confirmation_required: Ok(false),
+            },
+            TestCase {
+                description: "When subscribing to email reminders, a confirmation link should be emailed to the recipient",
+                channel: ReminderChannel::Email,
+                recipient: "johnsmith@test.com",
+                send_result: Some(Ok(())),
+                expected_confirmation_required: Ok(true),
+            },
+            TestCase {
+                description: "When the confirmation email cannot be sent, I should get an unable to send confirmation error",
+                channel: ReminderChannel::Email,
+                recipient: "johnsmith@test.com",
+                send_result: Some(Err(SendMailError::UnableToSend)),
+                expected_confirmation_required: Err(
+                    SubscribeToRemindersError::UnableToSendConfirmation,
+                ),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventRemindersPublicRepository::new();
+            mock_repository
+                .expect_insert_reminder_subscription()
+                .return_once(|_| Ok(7));
+            let mut mock_mail_sender = MockMailSender::new();
+            if let Some(send_result) = test_case.send_result {
+                let confirmation_token = issue_reminder_token(
+                    &*new_token_signer(SECRET),
+                    ReminderTokenPurpose::Confirmation,
+                    7,
+                );
+                mock_mail_sender
+                    .expect_send()
+                    .withf(move |message| {
+                        message.to == "johnsmith@test.com"
+                            && message
+                                .body
+                                .contains(&format!("{CONFIRMATION_URL}?token={confirmation_token}"))
+                    })
+                    .return_once(move |_| send_result);
+            }
+            let actual_result = new_service(mock_repository, mock_mail_sender)
+                .subscribe(SubscribeToRemindersRequest {
+                    event_id: Some(1),
+                    event_type: None,
+                    channel: test_case.channel,
+                    recipient: test_case.recipient.to_owned(),
+                    hours_before: 24,
+                })
+                .await;
+            assert_eq!(
+                test_case.expected_confirmation_required,
+                actual_result
+                    .as_ref()
+                    .map(|confirmation| confirmation.confirmation_required)
+                    .map_err(|err| *err)
+            );
+            if let Ok(confirmation) = actual_result {
+                assert_eq!(
+                    new_token_signer(SECRET).verify(&confirmation.unsubscribe_token),
+                    Ok("event-reminder-7".to_owned())
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_confirm() {
+        struct TestCase {
+            description: &'static str,
+            confirmation_token: String,
+            expected_subscription_id: Option<i32>,
+            expected_result: Result<(), ConfirmReminderSubscriptionError>,
+        }
+        let token_signer = new_token_signer(SECRET);
+        let test_cases = [
+            TestCase {
+                description: "When the token is an unsubscribe token, I should get an invalid confirmation token error",
+                confirmation_token: issue_reminder_token(
+                    &*token_signer,
+                    ReminderTokenPurpose::Unsubscribe,
+                    7,
+                ),
+                expected_subscription_id: None,
+                expected_result: Err(ConfirmReminderSubscriptionError::InvalidConfirmationToken),
+            },
+            TestCase {
+                description: "When the token is valid, the subscription it was issued for should be confirmed",
+                confirmation_token: issue_reminder_token(
+                    &*token_signer,
+                    ReminderTokenPurpose::Confirmation,
+                    7,
+                ),
+                expected_subscription_id: Some(7),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventRemindersPublicRepository::new();
+            if let Some(expected_subscription_id) = test_case.expected_subscription_id {
+                mock_repository
+                    .expect_confirm_reminder_subscription()
+                    .withf(move |subscription_id| *subscription_id == expected_subscription_id)
+                    .return_once(|_| Ok(()));
+            }
+            assert_eq!(
+                test_case.expected_result,
+                new_service(mock_repository, MockMailSender::new())
+                    .confirm(&test_case.confirmation_token)
+                    .await
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe() {
+        struct TestCase {
+            description: &'static str,
+            unsubscribe_token: String,
+            expected_subscription_id: Option<i32>,
+            expected_result: Result<(), UnsubscribeFromRemindersError>,
+        }
+        let token_signer = new_token_signer(SECRET);
+        let test_cases = [
+            TestCase {
+                description: "When the token was not signed by this service, I should get an invalid unsubscribe token error",
+                unsubscribe_token: new_token_signer(b"another secret").sign("event-reminder-7"),
+                expected_subscription_id: None,
+                expected_result: Err(UnsubscribeFromRemindersError::InvalidUnsubscribeToken),
+            },
+            TestCase {
+                description: "When the token is a registration cancellation token, I should get an invalid unsubscribe token error",
+                unsubscribe_token: token_signer.sign("event-registration-7"),
+                expected_subscription_id: None,
+                expected_result: Err(UnsubscribeFromRemindersError::InvalidUnsubscribeToken),
+            },
+            TestCase {
+                description: "When the token is a confirmation token, I should get an invalid unsubscribe token error",
+                unsubscribe_token: issue_reminder_token(
+                    &*token_signer,
+                    ReminderTokenPurpose::Confirmation,
+                    7,
+                ),
+                expected_subscription_id: None,
+                expected_result: Err(UnsubscribeFromRemindersError::InvalidUnsubscribeToken),
+            },
+            TestCase {
+                description: "When the token is valid, the subscription it was issued for should be removed",
+                unsubscribe_token: token_signer.sign("event-reminder-7"),
+                expected_subscription_id: Some(7),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockEventRemindersPublicRepository::new();
+            if let Some(expected_subscription_id) = test_case.expected_subscription_id {
+                mock_repository
+                    .expect_delete_reminder_subscription()
+                    .withf(move |subscription_id| *subscription_id == expected_subscription_id)
+                    .return_once(|_| Ok(()));
+            }
+            assert_eq!(
+                test_case.expected_result,
+                new_service(mock_repository, MockMailSender::new())
+                    .unsubscribe(&test_case.unsubscribe_token)
+                    .await
+            );
+        }
+    }
+}
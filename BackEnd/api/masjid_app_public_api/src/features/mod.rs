@@ -1,3 +1,8 @@
 pub mod ask_imam;
+pub mod event_registrations;
+pub mod event_reminders;
 pub mod events;
+pub mod organiser_messages;
 pub mod prayer_times;
+pub mod search;
+pub mod uploads;
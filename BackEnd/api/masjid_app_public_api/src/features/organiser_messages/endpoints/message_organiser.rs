@@ -0,0 +1,113 @@
+use crate::features::organiser_messages::errors::message_organiser_error::MessageOrganiserError;
+use crate::features::organiser_messages::models::message_organiser_request::MessageOrganiserRequest;
+use crate::features::organiser_messages::services::OrganiserMessagesPublicService;
+use axum::Json;
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn message_organiser(
+    State(state): State<ServiceAppState<Arc<dyn OrganiserMessagesPublicService>>>,
+    Path(event_id): Path<i32>,
+    ConnectInfo(sender_address): ConnectInfo<SocketAddr>,
+    Json(request): Json<MessageOrganiserRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    match state
+        .service
+        .message_organiser(event_id, sender_address.ip(), request)
+        .await
+    {
+        Ok(()) => StatusCode::ACCEPTED.into_response(),
+        Err(MessageOrganiserError::ContactFormUnavailable) => StatusCode::NOT_FOUND.into_response(),
+        Err(MessageOrganiserError::TooManyMessages) => {
+            StatusCode::TOO_MANY_REQUESTS.into_response()
+        }
+        Err(MessageOrganiserError::UnableToSendMessage) => {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::organiser_messages::services::MockOrganiserMessagesPublicService;
+
+    #[tokio::test]
+    async fn test_message_organiser() {
+        struct TestCase {
+            description: &'static str,
+            request: MessageOrganiserRequest,
+            expected_service_result: Option<Result<(), MessageOrganiserError>>,
+            expected_status_code: StatusCode,
+        }
+        let valid_request = MessageOrganiserRequest {
+            full_name: "John Smith".to_owned(),
+            email: "johnsmith@test.com".to_owned(),
+            message: "Is there parking nearby?".to_owned(),
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the request is not valid, I should get a BAD_REQUEST response",
+                request: MessageOrganiserRequest {
+                    email: "johnsmith".to_owned(),
+                    ..valid_request.clone()
+                },
+                expected_service_result: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the contact form is not available, I should get a NOT_FOUND response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(MessageOrganiserError::ContactFormUnavailable)),
+                expected_status_code: StatusCode::NOT_FOUND,
+            },
+            TestCase {
+                description: "When too many messages have been sent about the event, I should get a TOO_MANY_REQUESTS response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(MessageOrganiserError::TooManyMessages)),
+                expected_status_code: StatusCode::TOO_MANY_REQUESTS,
+            },
+            TestCase {
+                description: "When sending the message fails, I should get an INTERNAL_SERVER_ERROR response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(MessageOrganiserError::UnableToSendMessage)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When the message is sent, I should get an ACCEPTED response",
+                request: valid_request,
+                expected_service_result: Some(Ok(())),
+                expected_status_code: StatusCode::ACCEPTED,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockOrganiserMessagesPublicService::new();
+            if let Some(expected_service_result) = test_case.expected_service_result {
+                mock_service
+                    .expect_message_organiser()
+                    .return_once(move |_, _, _| expected_service_result);
+            }
+            let app_state = ServiceAppState::<Arc<dyn OrganiserMessagesPublicService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response = message_organiser(
+                State(app_state),
+                Path(1),
+                ConnectInfo(SocketAddr::from(([203, 0, 113, 7], 54321))),
+                Json(test_case.request),
+            )
+            .await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod message_organiser;
@@ -0,0 +1,8 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MessageOrganiserError {
+    /// The event does not exist, is not published or its organiser cannot be messaged
+    ContactFormUnavailable,
+    /// The sender has already sent as many messages as they are allowed to for now
+    TooManyMessages,
+    UnableToSendMessage,
+}
@@ -0,0 +1,73 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Deserialize, Validate, Clone, Debug, PartialEq)]
+pub struct MessageOrganiserRequest {
+    #[validate(length(min = 3, max = 100))]
+    #[serde(rename = "fullName")]
+    pub full_name: String,
+
+    /// Where the organiser's reply is sent
+    #[validate(email, length(max = 50))]
+    pub email: String,
+
+    #[validate(length(min = 1, max = 2000))]
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_organiser_request_validation() {
+        struct TestCase {
+            description: &'static str,
+            request: MessageOrganiserRequest,
+            expected_is_valid: bool,
+        }
+        let valid_request = MessageOrganiserRequest {
+            full_name: "John Smith".to_owned(),
+            email: "johnsmith@test.com".to_owned(),
+            message: "Is there parking nearby?".to_owned(),
+        };
+        let test_cases = [
+            TestCase {
+                description: "When every field is filled in, it should be valid",
+                request: valid_request.clone(),
+                expected_is_valid: true,
+            },
+            TestCase {
+                description: "When the email address is not valid, it should be invalid",
+                request: MessageOrganiserRequest {
+                    email: "johnsmith".to_owned(),
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When the message is empty, it should be invalid",
+                request: MessageOrganiserRequest {
+                    message: String::new(),
+                    ..valid_request.clone()
+                },
+                expected_is_valid: false,
+            },
+            TestCase {
+                description: "When the message is too long, it should be invalid",
+                request: MessageOrganiserRequest {
+                    message: "a".repeat(2001),
+                    ..valid_request
+                },
+                expected_is_valid: false,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_is_valid,
+                test_case.request.validate().is_ok()
+            );
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod message_organiser_request;
+pub mod organiser_contact;
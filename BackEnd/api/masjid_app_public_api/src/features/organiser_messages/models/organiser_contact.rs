@@ -0,0 +1,6 @@
+/// What is needed to forward a message to an event's organiser, which never leaves the API
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrganiserContact {
+    pub event_title: String,
+    pub email: String,
+}
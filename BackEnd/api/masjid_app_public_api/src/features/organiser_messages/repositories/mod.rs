@@ -0,0 +1,43 @@
+mod mysql_impl;
+mod redis_impl;
+
+use crate::features::organiser_messages::errors::message_organiser_error::MessageOrganiserError;
+use crate::features::organiser_messages::models::organiser_contact::OrganiserContact;
+use async_trait::async_trait;
+use masjid_app_api_library::new_repository;
+use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait OrganiserMessagesPublicRepository: Send + Sync {
+    /// Only returns the organiser of a published event which has the contact form enabled
+    async fn get_organiser_contact(
+        &self,
+        event_id: &i32,
+    ) -> Result<OrganiserContact, MessageOrganiserError>;
+    /// Records that the sender is sending a message about the event, unless within the last
+    /// `window_minutes` they have already sent `max_messages_per_sender` about it, or its
+    /// organiser has already been sent `max_messages_per_event`
+    async fn record_organiser_message(
+        &self,
+        event_id: &i32,
+        sender_ip: &IpAddr,
+        max_messages_per_sender: u32,
+        max_messages_per_event: u32,
+        window_minutes: u32,
+    ) -> Result<(), MessageOrganiserError>;
+}
+
+pub async fn new_organiser_messages_public_repository(
+    repository_mode: RepositoryMode,
+) -> Arc<dyn OrganiserMessagesPublicRepository> {
+    new_repository!(repository_mode, RepositoryType::Events)
+}
@@ -0,0 +1,74 @@
+use crate::features::organiser_messages::errors::message_organiser_error::MessageOrganiserError;
+use crate::features::organiser_messages::models::organiser_contact::OrganiserContact;
+use crate::features::organiser_messages::repositories::OrganiserMessagesPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use sqlx::Row;
+use std::net::IpAddr;
+
+#[async_trait]
+impl OrganiserMessagesPublicRepository for MySqlRepository {
+    async fn get_organiser_contact(
+        &self,
+        event_id: &i32,
+    ) -> Result<OrganiserContact, MessageOrganiserError> {
+        let db_connection = self.db_connection.clone();
+        sqlx::query("CALL get_event_organiser_contact(?)")
+            .bind(event_id)
+            .fetch_optional(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    event_id = event_id,
+                    error = err.to_string(),
+                    "unable to fetch event organiser contact"
+                );
+                MessageOrganiserError::UnableToSendMessage
+            })?
+            .map(|row| OrganiserContact {
+                event_title: row.get(0),
+                email: row.get(1),
+            })
+            .ok_or(MessageOrganiserError::ContactFormUnavailable)
+    }
+
+    async fn record_organiser_message(
+        &self,
+        event_id: &i32,
+        sender_ip: &IpAddr,
+        max_messages_per_sender: u32,
+        max_messages_per_event: u32,
+        window_minutes: u32,
+    ) -> Result<(), MessageOrganiserError> {
+        let db_connection = self.db_connection.clone();
+        let map_database_error = |err: sqlx::Error| {
+            tracing::error!(
+                event_id = event_id,
+                error = err.to_string(),
+                "unable to record organiser message"
+            );
+            MessageOrganiserError::UnableToSendMessage
+        };
+        // The event stays locked until the transaction ends, so the limits cannot be passed by
+        // messages sent at the same time
+        let mut transaction = db_connection.begin().await.map_err(map_database_error)?;
+        let query_result = sqlx::query("CALL record_organiser_message(?, ?, ?, ?, ?)")
+            .bind(event_id)
+            .bind(sender_ip.to_string())
+            .bind(max_messages_per_sender)
+            .bind(max_messages_per_event)
+            .bind(window_minutes)
+            .execute(&mut *transaction)
+            .await
+            .map_err(map_database_error)?;
+        transaction.commit().await.map_err(map_database_error)?;
+        if query_result.rows_affected() == 0 {
+            tracing::warn!(
+                event_id = event_id,
+                "too many messages have been sent to the event's organiser"
+            );
+            return Err(MessageOrganiserError::TooManyMessages);
+        }
+        Ok(())
+    }
+}
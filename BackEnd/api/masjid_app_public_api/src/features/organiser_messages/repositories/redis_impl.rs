@@ -0,0 +1,29 @@
+use crate::features::organiser_messages::errors::message_organiser_error::MessageOrganiserError;
+use crate::features::organiser_messages::models::organiser_contact::OrganiserContact;
+use crate::features::organiser_messages::repositories::OrganiserMessagesPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use std::net::IpAddr;
+
+#[async_trait]
+impl OrganiserMessagesPublicRepository for InMemoryRepository {
+    async fn get_organiser_contact(
+        &self,
+        event_id: &i32,
+    ) -> Result<OrganiserContact, MessageOrganiserError> {
+        tracing::warn!("in-memory database not implemented for get_organiser_contact");
+        Err(MessageOrganiserError::UnableToSendMessage)
+    }
+
+    async fn record_organiser_message(
+        &self,
+        event_id: &i32,
+        sender_ip: &IpAddr,
+        max_messages_per_sender: u32,
+        max_messages_per_event: u32,
+        window_minutes: u32,
+    ) -> Result<(), MessageOrganiserError> {
+        tracing::warn!("in-memory database not implemented for record_organiser_message");
+        Err(MessageOrganiserError::UnableToSendMessage)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::features::organiser_messages::errors::message_organiser_error::MessageOrganiserError;
+use crate::features::organiser_messages::models::message_organiser_request::MessageOrganiserRequest;
+use crate::features::organiser_messages::repositories::OrganiserMessagesPublicRepository;
+use async_trait::async_trait;
+use masjid_app_api_library::shared::services::mail::mail_message::MailMessage;
+use masjid_app_api_library::shared::services::mail::r#trait::MailSender;
+use mockall::automock;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+// Enough for a genuine sender to follow up on their questions, without the contact form being
+// usable to flood organisers
+const MAX_MESSAGES_PER_SENDER: u32 = 5;
+// Bounds how many messages an organiser receives, however many addresses they are sent from
+const MAX_MESSAGES_PER_EVENT: u32 = 30;
+const MESSAGE_WINDOW_MINUTES: u32 = 60;
+
+#[automock]
+#[async_trait]
+pub trait OrganiserMessagesPublicService: Send + Sync {
+    /// Forwards the message to the event's organiser without revealing their email address to
+    /// the sender, with replies going straight back to the sender
+    async fn message_organiser(
+        &self,
+        event_id: i32,
+        sender_ip: IpAddr,
+        request: MessageOrganiserRequest,
+    ) -> Result<(), MessageOrganiserError>;
+}
+
+pub fn new_organiser_messages_public_service(
+    repository: Arc<dyn OrganiserMessagesPublicRepository>,
+    mail_sender: Arc<dyn MailSender>,
+) -> Arc<dyn OrganiserMessagesPublicService> {
+    Arc::new(OrganiserMessagesPublicServiceImpl {
+        repository,
+        mail_sender,
+    })
+}
+
+struct OrganiserMessagesPublicServiceImpl {
+    repository: Arc<dyn OrganiserMessagesPublicRepository>,
+    mail_sender: Arc<dyn MailSender>,
+}
+
+#[async_trait]
+impl OrganiserMessagesPublicService for OrganiserMessagesPublicServiceImpl {
+    async fn message_organiser(
+        &self,
+        event_id: i32,
+        sender_ip: IpAddr,
+        request: MessageOrganiserRequest,
+    ) -> Result<(), MessageOrganiserError> {
+        let organiser_contact = self.repository.get_organiser_contact(&event_id).await?;
+        self.repository
+            .record_organiser_message(
+                &event_id,
+                &sender_ip,
+                MAX_MESSAGES_PER_SENDER,
+                MAX_MESSAGES_PER_EVENT,
+                MESSAGE_WINDOW_MINUTES,
+            )
+            .await?;
+        self.mail_sender
+            .send(MailMessage {
+                to: organiser_contact.email,
+                subject: format!("Message about {}", organiser_contact.event_title),
+                body: format!(
+                    "{} sent you a message about {}:\n\n{}",
+                    request.full_name, organiser_contact.event_title, request.message
+                ),
+                reply_to: Some(request.email),
+            })
+            .await
+            .map_err(|err| {
+                tracing::error!(event_id = event_id, error = ?err, "unable to message organiser");
+                MessageOrganiserError::UnableToSendMessage
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::organiser_messages::models::organiser_contact::OrganiserContact;
+    use crate::features::organiser_messages::repositories::MockOrganiserMessagesPublicRepository;
+    use masjid_app_api_library::shared::services::mail::errors::SendMailError;
+    use masjid_app_api_library::shared::services::mail::r#trait::MockMailSender;
+    use std::net::Ipv4Addr;
+
+    #[tokio::test]
+    async fn test_message_organiser() {
+        struct TestCase {
+            description: &'static str,
+            repository_result: Result<OrganiserContact, MessageOrganiserError>,
+            record_result: Option<Result<(), MessageOrganiserError>>,
+            send_result: Option<Result<(), SendMailError>>,
+            expected_result: Result<(), MessageOrganiserError>,
+        }
+        let organiser_contact = OrganiserContact {
+            event_title: "Tafsir Class".to_owned(),
+            email: "organiser@test.com".to_owned(),
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the contact form is not available for the event, I should get a ContactFormUnavailable error",
+                repository_result: Err(MessageOrganiserError::ContactFormUnavailable),
+                record_result: None,
+                send_result: None,
+                expected_result: Err(MessageOrganiserError::ContactFormUnavailable),
+            },
+            TestCase {
+                description: "When too many messages have been sent about the event, I should get a TooManyMessages error without the message being sent",
+                repository_result: Ok(organiser_contact.clone()),
+                record_result: Some(Err(MessageOrganiserError::TooManyMessages)),
+                send_result: None,
+                expected_result: Err(MessageOrganiserError::TooManyMessages),
+            },
+            TestCase {
+                description: "When the email cannot be sent, I should get an UnableToSendMessage error",
+                repository_result: Ok(organiser_contact.clone()),
+                record_result: Some(Ok(())),
+                send_result: Some(Err(SendMailError::UnableToSend)),
+                expected_result: Err(MessageOrganiserError::UnableToSendMessage),
+            },
+            TestCase {
+                description: "When the email is sent, the message should be forwarded to the organiser with replies going to the sender",
+                repository_result: Ok(organiser_contact),
+                record_result: Some(Ok(())),
+                send_result: Some(Ok(())),
+                expected_result: Ok(()),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_repository = MockOrganiserMessagesPublicRepository::new();
+            mock_repository
+                .expect_get_organiser_contact()
+                .withf(|event_id| *event_id == 1)
+                .return_once(move |_| test_case.repository_result);
+            if let Some(record_result) = test_case.record_result {
+                mock_repository
+                    .expect_record_organiser_message()
+                    .withf(
+                        |event_id,
+                         sender_ip,
+                         max_messages_per_sender,
+                         max_messages_per_event,
+                         window_minutes| {
+                            *event_id == 1
+                                && *sender_ip == IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7))
+                                && *max_messages_per_sender == MAX_MESSAGES_PER_SENDER
+                                && *max_messages_per_event == MAX_MESSAGES_PER_EVENT
+                                && *window_minutes == MESSAGE_WINDOW_MINUTES
+                        },
+                    )
+                    .return_once(move |_, _, _, _, _| record_result);
+            }
+            let mut mock_mail_sender = MockMailSender::new();
+            if let Some(send_result) = test_case.send_result {
+                mock_mail_sender
+                    .expect_send()
+                    .withf(|message| {
+                        *message
+                            == MailMessage {
+                                to: "organiser@test.com".to_owned(),
+                                subject: "Message about Tafsir Class".to_owned(),
+                                body: "John Smith sent you a message about Tafsir Class:\n\nIs there parking nearby?".to_owned(),
+                                reply_to: Some("johnsmith@test.com".to_owned()),
+                            }
+                    })
+                    .return_once(move |_| send_result);
+            }
+            let service = OrganiserMessagesPublicServiceImpl {
+                repository: Arc::new(mock_repository),
+                mail_sender: Arc::new(mock_mail_sender),
+            };
+            let actual_result = service
+                .message_organiser(
+                    1,
+                    IpAddr::V4(Ipv4Addr::new(203, 0, 113, 7)),
+                    MessageOrganiserRequest {
+                        full_name: "John Smith".to_owned(),
+                        email: "johnsmith@test.com".to_owned(),
+                        message: "Is there parking nearby?".to_owned(),
+                    },
+                )
+                .await;
+            assert_eq!(test_case.expected_result, actual_result);
+        }
+    }
+}
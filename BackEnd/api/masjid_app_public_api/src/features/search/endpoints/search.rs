@@ -0,0 +1,91 @@
+use crate::features::search::errors::search_error::SearchError;
+use crate::features::search::models::search_request::SearchRequest;
+use crate::features::search::services::SearchService;
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::sync::Arc;
+use validator::Validate;
+
+pub async fn search(
+    State(state): State<ServiceAppState<Arc<dyn SearchService>>>,
+    Query(request): Query<SearchRequest>,
+) -> Response {
+    if request.validate().is_err() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    match state.service.search(request).await {
+        Ok(results) => (StatusCode::OK, Json(results)).into_response(),
+        Err(SearchError::UnableToSearch) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::search::models::search_result::SearchResult;
+    use crate::features::search::services::MockSearchService;
+
+    #[tokio::test]
+    async fn test_search() {
+        struct TestCase {
+            description: &'static str,
+            request: SearchRequest,
+            expected_service_result: Option<Result<Vec<SearchResult>, SearchError>>,
+            expected_status_code: StatusCode,
+        }
+        let valid_request = SearchRequest {
+            q: "fasting".to_owned(),
+            limit: Some(10),
+        };
+        let test_cases = [
+            TestCase {
+                description: "When the query is too short, I should get a BAD_REQUEST response",
+                request: SearchRequest {
+                    q: "ab".to_owned(),
+                    ..valid_request.clone()
+                },
+                expected_service_result: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When the limit is more than the maximum, I should get a BAD_REQUEST response",
+                request: SearchRequest {
+                    limit: Some(51),
+                    ..valid_request.clone()
+                },
+                expected_service_result: None,
+                expected_status_code: StatusCode::BAD_REQUEST,
+            },
+            TestCase {
+                description: "When searching fails, I should get an INTERNAL_SERVER_ERROR response",
+                request: valid_request.clone(),
+                expected_service_result: Some(Err(SearchError::UnableToSearch)),
+                expected_status_code: StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            TestCase {
+                description: "When nothing matches, I should still get an OK response",
+                request: valid_request,
+                expected_service_result: Some(Ok(Vec::new())),
+                expected_status_code: StatusCode::OK,
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_service = MockSearchService::new();
+            if let Some(expected_service_result) = test_case.expected_service_result {
+                mock_service
+                    .expect_search()
+                    .return_once(move |_| expected_service_result);
+            }
+            let app_state = ServiceAppState::<Arc<dyn SearchService>> {
+                service: Arc::new(mock_service),
+            };
+            let actual_response = search(State(app_state), Query(test_case.request)).await;
+            assert_eq!(test_case.expected_status_code, actual_response.status());
+        }
+    }
+}
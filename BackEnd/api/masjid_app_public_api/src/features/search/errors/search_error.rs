@@ -0,0 +1,4 @@
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SearchError {
+    UnableToSearch,
+}
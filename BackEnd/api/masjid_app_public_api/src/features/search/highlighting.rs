@@ -0,0 +1,192 @@
+use crate::features::search::models::highlight_range::HighlightRange;
+use crate::features::search::models::search_highlight::SearchHighlight;
+
+// InnoDB does not index words shorter than this by default, so they never caused a match
+const MINIMUM_TERM_LENGTH: usize = 3;
+const SNIPPET_LENGTH: usize = 160;
+const SNIPPET_CONTEXT: usize = 40;
+const ELLIPSIS: char = '…';
+
+/// Splits the query into the lowercase words that are worth highlighting, longest first so
+/// that "prayers" is highlighted in full rather than as "prayer"
+pub fn search_terms(query: &str) -> Vec<Vec<char>> {
+    let mut terms: Vec<Vec<char>> = Vec::new();
+    for word in query.split(|c: char| !c.is_alphanumeric()) {
+        let term: Vec<char> = word.chars().map(lowercase).collect();
+        if term.len() >= MINIMUM_TERM_LENGTH && !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms.sort_by(|a, b| b.len().cmp(&a.len()));
+    terms
+}
+
+/// Finds the terms at the start of words in the text, returning a snippet around the first
+/// match along with where each match is in that snippet
+pub fn highlight(field: &'static str, text: &str, terms: &[Vec<char>]) -> Option<SearchHighlight> {
+    let characters: Vec<char> = text.chars().collect();
+    let lowercase_characters: Vec<char> = characters.iter().copied().map(lowercase).collect();
+    let mut matches: Vec<(usize, usize)> = Vec::new();
+    let mut index = 0;
+    while index < lowercase_characters.len() {
+        let is_word_start = index == 0 || !lowercase_characters[index - 1].is_alphanumeric();
+        let matched_term = terms
+            .iter()
+            .find(|term| is_word_start && lowercase_characters[index..].starts_with(term));
+        match matched_term {
+            Some(term) => {
+                matches.push((index, index + term.len()));
+                index += term.len();
+            }
+            None => index += 1,
+        }
+    }
+    let (first_match_start, _) = *matches.first()?;
+
+    let mut start = 0;
+    let mut end = characters.len();
+    if characters.len() > SNIPPET_LENGTH {
+        start = first_match_start.saturating_sub(SNIPPET_CONTEXT);
+        while start > 0 && !characters[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        end = (start + SNIPPET_LENGTH).min(characters.len());
+    }
+    let mut snippet = String::new();
+    let mut offset = start;
+    if start > 0 {
+        snippet.push(ELLIPSIS);
+        offset -= 1;
+    }
+    snippet.extend(&characters[start..end]);
+    if end < characters.len() {
+        snippet.push(ELLIPSIS);
+    }
+    Some(SearchHighlight {
+        field,
+        snippet,
+        matches: matches
+            .into_iter()
+            .filter(|(match_start, _)| *match_start < end)
+            .map(|(match_start, match_end)| HighlightRange {
+                start: match_start - offset,
+                end: match_end.min(end) - offset,
+            })
+            .collect(),
+    })
+}
+
+// Only characters that lowercase to a single character keep their position, anything else is
+// left as it is so offsets into the snippet stay correct
+fn lowercase(character: char) -> char {
+    let mut lowercase = character.to_lowercase();
+    match (lowercase.next(), lowercase.next()) {
+        (Some(lowercase_character), None) => lowercase_character,
+        _ => character,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_terms() {
+        struct TestCase {
+            description: &'static str,
+            query: &'static str,
+            expected_terms: Vec<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the query has several words, each should be a lowercase term, longest first",
+                query: "Quran Recitation",
+                expected_terms: vec!["recitation", "quran"],
+            },
+            TestCase {
+                description: "When the query has punctuation and short or repeated words, they should be ignored",
+                query: "zakat: is it due on gold? gold",
+                expected_terms: vec!["zakat", "gold", "due"],
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let expected_terms: Vec<Vec<char>> = test_case
+                .expected_terms
+                .iter()
+                .map(|term| term.chars().collect())
+                .collect();
+            assert_eq!(expected_terms, search_terms(test_case.query));
+        }
+    }
+
+    #[test]
+    fn test_highlight() {
+        struct TestCase {
+            description: &'static str,
+            text: String,
+            query: &'static str,
+            expected_highlight: Option<SearchHighlight>,
+        }
+        let long_text = format!(
+            "{} the youth circle meets weekly. {}",
+            "Every Friday after Isha ".repeat(5),
+            "Brothers and sisters welcome. ".repeat(5)
+        );
+        let test_cases = [
+            TestCase {
+                description: "When none of the terms are in the text, there should be no highlight",
+                text: "Community iftar".to_owned(),
+                query: "youth",
+                expected_highlight: None,
+            },
+            TestCase {
+                description: "When the terms are in a short text, every match should be highlighted regardless of case",
+                text: "Youth circle for the youth".to_owned(),
+                query: "YOUTH",
+                expected_highlight: Some(SearchHighlight {
+                    field: "title",
+                    snippet: "Youth circle for the youth".to_owned(),
+                    matches: vec![
+                        HighlightRange { start: 0, end: 5 },
+                        HighlightRange { start: 21, end: 26 },
+                    ],
+                }),
+            },
+            TestCase {
+                description: "When a term is only in the middle of a word, it should not be highlighted",
+                text: "Salah times".to_owned(),
+                query: "lah times",
+                expected_highlight: Some(SearchHighlight {
+                    field: "title",
+                    snippet: "Salah times".to_owned(),
+                    matches: vec![HighlightRange { start: 6, end: 11 }],
+                }),
+            },
+            TestCase {
+                description: "When the text is long, the snippet should start at a word near the first match and be shortened with ellipses",
+                text: long_text.clone(),
+                query: "youth",
+                expected_highlight: Some(SearchHighlight {
+                    field: "title",
+                    snippet: format!(
+                        "…{}…",
+                        long_text
+                            .chars()
+                            .skip(85)
+                            .take(SNIPPET_LENGTH)
+                            .collect::<String>()
+                    ),
+                    matches: vec![HighlightRange { start: 41, end: 46 }],
+                }),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            assert_eq!(
+                test_case.expected_highlight,
+                highlight("title", &test_case.text, &search_terms(test_case.query))
+            );
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod endpoints;
+pub mod errors;
+mod highlighting;
+pub mod models;
+pub mod repositories;
+pub mod services;
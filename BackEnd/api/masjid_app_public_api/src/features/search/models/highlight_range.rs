@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+/// Character offsets of a matched term within a snippet, with the end being exclusive
+#[derive(Serialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HighlightRange {
+    pub start: usize,
+    pub end: usize,
+}
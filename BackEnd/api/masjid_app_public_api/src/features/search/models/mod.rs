@@ -0,0 +1,6 @@
+pub mod highlight_range;
+pub mod search_highlight;
+pub mod search_match;
+pub mod search_request;
+pub mod search_result;
+pub mod search_result_item;
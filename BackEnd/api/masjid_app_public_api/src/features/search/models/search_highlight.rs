@@ -0,0 +1,9 @@
+use crate::features::search::models::highlight_range::HighlightRange;
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct SearchHighlight {
+    pub field: &'static str,
+    pub snippet: String,
+    pub matches: Vec<HighlightRange>,
+}
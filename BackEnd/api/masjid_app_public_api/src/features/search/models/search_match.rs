@@ -0,0 +1,6 @@
+/// An item found by the full-text index along with the relevance MySQL gave it
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchMatch<T> {
+    pub item: T,
+    pub relevance: f64,
+}
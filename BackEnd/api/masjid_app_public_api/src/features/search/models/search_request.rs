@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use validator::Validate;
+
+pub const DEFAULT_SEARCH_RESULTS: u32 = 20;
+pub const MAXIMUM_SEARCH_RESULTS: u32 = 50;
+
+#[derive(Deserialize, Validate, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchRequest {
+    #[validate(length(min = 3, max = 100))]
+    pub q: String,
+
+    #[validate(range(min = 1, max = MAXIMUM_SEARCH_RESULTS))]
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,15 @@
+use crate::features::search::models::search_highlight::SearchHighlight;
+use crate::features::search::models::search_result_item::SearchResultItem;
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct SearchResult {
+    /// Between 0 and 1, relative to the best match of the same type so events and questions
+    /// can be ranked together
+    pub score: f64,
+
+    pub highlights: Vec<SearchHighlight>,
+
+    #[serde(flatten)]
+    pub item: SearchResultItem,
+}
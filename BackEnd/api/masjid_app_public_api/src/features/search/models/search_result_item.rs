@@ -0,0 +1,10 @@
+use masjid_app_api_library::features::ask_imam::models::imam_question_dto::ImamQuestionDTO;
+use masjid_app_api_library::features::events::models::public_event_dto::PublicEventDTO;
+use serde::Serialize;
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", content = "item", rename_all = "lowercase")]
+pub enum SearchResultItem {
+    Event(PublicEventDTO),
+    Question(ImamQuestionDTO),
+}
@@ -0,0 +1,51 @@
+mod mysql_impl;
+mod redis_impl;
+
+use crate::features::search::errors::search_error::SearchError;
+use crate::features::search::models::search_match::SearchMatch;
+use async_trait::async_trait;
+use masjid_app_api_library::features::ask_imam::models::imam_question_dto::ImamQuestionDTO;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::new_repository;
+use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
+use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::data_access::repository_management::repository_type::RepositoryType;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait EventsSearchRepository: Send + Sync {
+    /// Searches the titles and descriptions of published events, most relevant first
+    async fn search_events(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SearchMatch<EventDTO>>, SearchError>;
+}
+
+#[automock]
+#[async_trait]
+pub trait ImamQuestionsSearchRepository: Send + Sync {
+    /// Searches the titles, descriptions and answers of answered questions, most relevant first
+    async fn search_answered_questions(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SearchMatch<ImamQuestionDTO>>, SearchError>;
+}
+
+pub async fn new_events_search_repository(
+    repository_mode: RepositoryMode,
+) -> Arc<dyn EventsSearchRepository> {
+    new_repository!(repository_mode, RepositoryType::Events)
+}
+
+pub async fn new_imam_questions_search_repository(
+    repository_mode: RepositoryMode,
+) -> Arc<dyn ImamQuestionsSearchRepository> {
+    new_repository!(repository_mode, RepositoryType::AskImam)
+}
@@ -0,0 +1,75 @@
+use crate::features::search::errors::search_error::SearchError;
+use crate::features::search::models::search_match::SearchMatch;
+use crate::features::search::repositories::{
+    EventsSearchRepository, ImamQuestionsSearchRepository,
+};
+use async_trait::async_trait;
+use masjid_app_api_library::features::ask_imam::models::imam_question_dto::ImamQuestionDTO;
+use masjid_app_api_library::features::ask_imam::repositories::mysql_impl::imam_question_from_my_sql_row;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::features::events::repositories::mysql_impl::map_event_row;
+use masjid_app_api_library::shared::data_access::repository_management::mysql_repository::MySqlRepository;
+use sqlx::Row;
+
+#[async_trait]
+impl EventsSearchRepository for MySqlRepository {
+    async fn search_events(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SearchMatch<EventDTO>>, SearchError> {
+        let db_connection = self.db_connection.clone();
+        let rows = sqlx::query("CALL search_events(?, ?)")
+            .bind(query)
+            .bind(limit)
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(error = err.to_string(), "unable to search events");
+                SearchError::UnableToSearch
+            })?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let relevance: f64 = row.get(27);
+                SearchMatch {
+                    item: EventDTO::from(map_event_row(row)),
+                    relevance,
+                }
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ImamQuestionsSearchRepository for MySqlRepository {
+    async fn search_answered_questions(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SearchMatch<ImamQuestionDTO>>, SearchError> {
+        let db_connection = self.db_connection.clone();
+        let rows = sqlx::query("CALL search_answered_imam_questions(?, ?)")
+            .bind(query)
+            .bind(limit)
+            .fetch_all(&*db_connection)
+            .await
+            .map_err(|err| {
+                tracing::error!(
+                    error = err.to_string(),
+                    "unable to search answered questions"
+                );
+                SearchError::UnableToSearch
+            })?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let relevance: f64 = row.get(10);
+                SearchMatch {
+                    item: ImamQuestionDTO::from(imam_question_from_my_sql_row(row)),
+                    relevance,
+                }
+            })
+            .collect())
+    }
+}
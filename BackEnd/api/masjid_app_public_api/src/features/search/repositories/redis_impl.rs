@@ -0,0 +1,33 @@
+use crate::features::search::errors::search_error::SearchError;
+use crate::features::search::models::search_match::SearchMatch;
+use crate::features::search::repositories::{
+    EventsSearchRepository, ImamQuestionsSearchRepository,
+};
+use async_trait::async_trait;
+use masjid_app_api_library::features::ask_imam::models::imam_question_dto::ImamQuestionDTO;
+use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+use masjid_app_api_library::shared::data_access::repository_management::in_memory_repository::InMemoryRepository;
+
+#[async_trait]
+impl EventsSearchRepository for InMemoryRepository {
+    async fn search_events(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SearchMatch<EventDTO>>, SearchError> {
+        tracing::warn!("in-memory database not implemented for search_events");
+        Err(SearchError::UnableToSearch)
+    }
+}
+
+#[async_trait]
+impl ImamQuestionsSearchRepository for InMemoryRepository {
+    async fn search_answered_questions(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SearchMatch<ImamQuestionDTO>>, SearchError> {
+        tracing::warn!("in-memory database not implemented for search_answered_questions");
+        Err(SearchError::UnableToSearch)
+    }
+}
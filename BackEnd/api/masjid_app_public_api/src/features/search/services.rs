@@ -0,0 +1,303 @@
+use crate::features::search::errors::search_error::SearchError;
+use crate::features::search::highlighting::{highlight, search_terms};
+use crate::features::search::models::search_match::SearchMatch;
+use crate::features::search::models::search_request::{DEFAULT_SEARCH_RESULTS, SearchRequest};
+use crate::features::search::models::search_result::SearchResult;
+use crate::features::search::models::search_result_item::SearchResultItem;
+use crate::features::search::repositories::{
+    EventsSearchRepository, ImamQuestionsSearchRepository,
+};
+use async_trait::async_trait;
+use masjid_app_api_library::features::events::models::public_event_dto::PublicEventDTO;
+use mockall::automock;
+use std::sync::Arc;
+
+#[automock]
+#[async_trait]
+pub trait SearchService: Send + Sync {
+    /// Searches published events and answered questions together, most relevant first
+    async fn search(&self, request: SearchRequest) -> Result<Vec<SearchResult>, SearchError>;
+}
+
+pub fn new_search_service(
+    events_repository: Arc<dyn EventsSearchRepository>,
+    imam_questions_repository: Arc<dyn ImamQuestionsSearchRepository>,
+) -> Arc<dyn SearchService> {
+    Arc::new(SearchServiceImpl {
+        events_repository,
+        imam_questions_repository,
+    })
+}
+
+struct SearchServiceImpl {
+    events_repository: Arc<dyn EventsSearchRepository>,
+    imam_questions_repository: Arc<dyn ImamQuestionsSearchRepository>,
+}
+
+#[async_trait]
+impl SearchService for SearchServiceImpl {
+    async fn search(&self, request: SearchRequest) -> Result<Vec<SearchResult>, SearchError> {
+        let limit = request.limit.unwrap_or(DEFAULT_SEARCH_RESULTS);
+        let (events, questions) = tokio::join!(
+            self.events_repository.search_events(&request.q, limit),
+            self.imam_questions_repository
+                .search_answered_questions(&request.q, limit)
+        );
+        let terms = search_terms(&request.q);
+        let events = relative_scores(events?).map(|(event, score)| SearchResult {
+            score,
+            highlights: [
+                highlight("title", &event.title, &terms),
+                event
+                    .description
+                    .as_deref()
+                    .and_then(|description| highlight("description", description, &terms)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            item: SearchResultItem::Event(PublicEventDTO::from(event)),
+        });
+        let questions = relative_scores(questions?).map(|(question, score)| SearchResult {
+            score,
+            highlights: [
+                highlight("title", &question.title, &terms),
+                highlight("description", &question.description, &terms),
+                question
+                    .answer
+                    .as_ref()
+                    .and_then(|answer| highlight("answer", &answer.text, &terms)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            item: SearchResultItem::Question(question),
+        });
+
+        let mut results: Vec<SearchResult> = events.chain(questions).collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results.truncate(limit as usize);
+        Ok(results)
+    }
+}
+
+// MySQL relevance values depend on the size of the table searched, so events and questions are
+// each scored against their own best match before being ranked together
+fn relative_scores<T>(matches: Vec<SearchMatch<T>>) -> impl Iterator<Item = (T, f64)> {
+    let best_relevance = matches
+        .iter()
+        .map(|search_match| search_match.relevance)
+        .fold(0.0, f64::max);
+    matches.into_iter().map(move |search_match| {
+        let score = if best_relevance > 0.0 {
+            search_match.relevance / best_relevance
+        } else {
+            0.0
+        };
+        (search_match.item, score)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::search::models::highlight_range::HighlightRange;
+    use crate::features::search::models::search_highlight::SearchHighlight;
+    use crate::features::search::repositories::{
+        MockEventsSearchRepository, MockImamQuestionsSearchRepository,
+    };
+    use masjid_app_api_library::features::ask_imam::models::answer::Answer;
+    use masjid_app_api_library::features::ask_imam::models::imam_question_dto::ImamQuestionDTO;
+    use masjid_app_api_library::features::events::models::event_details::EventDetails;
+    use masjid_app_api_library::features::events::models::event_dto::EventDTO;
+    use masjid_app_api_library::features::events::models::event_recurrence::EventRecurrence;
+    use masjid_app_api_library::features::events::models::event_status::EventStatus;
+    use masjid_app_api_library::features::events::models::event_type::EventType;
+    use masjid_app_api_library::shared::types::contact_details::ContactDetails;
+    use masjid_app_api_library::shared::types::contact_visibility::ContactVisibility;
+    use sqlx::types::chrono::{TimeZone, Utc};
+
+    fn event(id: i32, title: &str) -> EventDTO {
+        EventDTO {
+            id,
+            title: title.to_owned(),
+            description: None,
+            date: Utc.with_ymd_and_hms(2026, 3, 6, 19, 0, 0).unwrap(),
+            event_details: EventDetails {
+                event_type: EventType::Talk,
+                event_recurrence: EventRecurrence::OneOff,
+                recurrence_end: None,
+                event_status: EventStatus::Confirmed,
+                publish_at: None,
+                age_range: None,
+                capacity: None,
+                room_id: None,
+                duration_minutes: None,
+                speaker_ids: Vec::new(),
+                category_id: None,
+                tags: Vec::new(),
+                image_url: None,
+                image_variants: None,
+                contact_details: ContactDetails {
+                    full_name: "John Smith".to_owned(),
+                    title: None,
+                    phone_number: "07123456789".to_owned(),
+                    email: None,
+                    visibility: ContactVisibility::default(),
+                },
+            },
+            version: 1,
+        }
+    }
+
+    fn question(id: i32, title: &str, answer: &str) -> ImamQuestionDTO {
+        ImamQuestionDTO {
+            id,
+            title: title.to_owned(),
+            topic: "Fasting".to_owned(),
+            school_of_thought: None,
+            description: "Please advise".to_owned(),
+            date_of_question: Utc.with_ymd_and_hms(2026, 2, 1, 12, 0, 0).unwrap(),
+            answer: Some(Answer {
+                imam_name: "Imam Ali".to_owned(),
+                text: answer.to_owned(),
+                date_answered: Utc.with_ymd_and_hms(2026, 2, 2, 12, 0, 0).unwrap(),
+            }),
+            version: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search() {
+        struct TestCase {
+            description: &'static str,
+            request: SearchRequest,
+            events_repository_result: Result<Vec<SearchMatch<EventDTO>>, SearchError>,
+            questions_repository_result: Result<Vec<SearchMatch<ImamQuestionDTO>>, SearchError>,
+            expected_result: Result<Vec<SearchResult>, SearchError>,
+        }
+        let request = SearchRequest {
+            q: "fasting".to_owned(),
+            limit: None,
+        };
+        let test_cases = [
+            TestCase {
+                description: "When searching events fails, I should get an unable to search error",
+                request: request.clone(),
+                events_repository_result: Err(SearchError::UnableToSearch),
+                questions_repository_result: Ok(Vec::new()),
+                expected_result: Err(SearchError::UnableToSearch),
+            },
+            TestCase {
+                description: "When searching questions fails, I should get an unable to search error",
+                request: request.clone(),
+                events_repository_result: Ok(Vec::new()),
+                questions_repository_result: Err(SearchError::UnableToSearch),
+                expected_result: Err(SearchError::UnableToSearch),
+            },
+            TestCase {
+                description: "When events and questions match, they should be ranked together by their relevance to the best match of their own type, with matches highlighted",
+                request: request.clone(),
+                events_repository_result: Ok(vec![
+                    SearchMatch {
+                        item: event(1, "Fasting in Shawwal"),
+                        relevance: 4.0,
+                    },
+                    SearchMatch {
+                        item: event(2, "Sunnah fasting"),
+                        relevance: 1.0,
+                    },
+                ]),
+                questions_repository_result: Ok(vec![SearchMatch {
+                    item: question(3, "Missed fasts", "Fasting can be made up later"),
+                    relevance: 0.5,
+                }]),
+                expected_result: Ok(vec![
+                    SearchResult {
+                        score: 1.0,
+                        highlights: vec![SearchHighlight {
+                            field: "title",
+                            snippet: "Fasting in Shawwal".to_owned(),
+                            matches: vec![HighlightRange { start: 0, end: 7 }],
+                        }],
+                        item: SearchResultItem::Event(PublicEventDTO::from(event(
+                            1,
+                            "Fasting in Shawwal",
+                        ))),
+                    },
+                    SearchResult {
+                        score: 1.0,
+                        highlights: vec![SearchHighlight {
+                            field: "answer",
+                            snippet: "Fasting can be made up later".to_owned(),
+                            matches: vec![HighlightRange { start: 0, end: 7 }],
+                        }],
+                        item: SearchResultItem::Question(question(
+                            3,
+                            "Missed fasts",
+                            "Fasting can be made up later",
+                        )),
+                    },
+                    SearchResult {
+                        score: 0.25,
+                        highlights: vec![SearchHighlight {
+                            field: "title",
+                            snippet: "Sunnah fasting".to_owned(),
+                            matches: vec![HighlightRange { start: 7, end: 14 }],
+                        }],
+                        item: SearchResultItem::Event(PublicEventDTO::from(event(
+                            2,
+                            "Sunnah fasting",
+                        ))),
+                    },
+                ]),
+            },
+            TestCase {
+                description: "When there are more matches than the limit, only the most relevant should be returned",
+                request: SearchRequest {
+                    limit: Some(1),
+                    ..request
+                },
+                events_repository_result: Ok(vec![SearchMatch {
+                    item: event(2, "Sunnah fasting"),
+                    relevance: 1.0,
+                }]),
+                questions_repository_result: Ok(vec![SearchMatch {
+                    item: question(3, "Missed fasts", "They can be made up later"),
+                    relevance: 0.0,
+                }]),
+                expected_result: Ok(vec![SearchResult {
+                    score: 1.0,
+                    highlights: vec![SearchHighlight {
+                        field: "title",
+                        snippet: "Sunnah fasting".to_owned(),
+                        matches: vec![HighlightRange { start: 7, end: 14 }],
+                    }],
+                    item: SearchResultItem::Event(PublicEventDTO::from(event(2, "Sunnah fasting"))),
+                }]),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let expected_limit = test_case.request.limit.unwrap_or(DEFAULT_SEARCH_RESULTS);
+            let mut mock_events_repository = MockEventsSearchRepository::new();
+            mock_events_repository
+                .expect_search_events()
+                .withf(move |query, limit| query == "fasting" && *limit == expected_limit)
+                .return_once(move |_, _| test_case.events_repository_result);
+            let mut mock_questions_repository = MockImamQuestionsSearchRepository::new();
+            mock_questions_repository
+                .expect_search_answered_questions()
+                .withf(move |query, limit| query == "fasting" && *limit == expected_limit)
+                .return_once(move |_, _| test_case.questions_repository_result);
+            let service = new_search_service(
+                Arc::new(mock_events_repository),
+                Arc::new(mock_questions_repository),
+            );
+            assert_eq!(
+                test_case.expected_result,
+                service.search(test_case.request).await
+            );
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use masjid_app_api_library::shared::extractors::file_handler::{
+    FileHandler, content_type_from_file_name,
+};
+use masjid_app_api_library::shared::services::file_storage::errors::FileStorageError;
+
+// Uploads are named after a hash of their contents, so a url always refers to the same bytes
+const IMMUTABLE_CACHE_CONTROL: &'static str = "public, max-age=31536000, immutable";
+
+pub async fn get_upload(
+    State(file_handler): State<FileHandler>,
+    Path(file_name): Path<String>,
+) -> Response {
+    match file_handler.get_file(&file_name).await {
+        Ok(data) => (
+            [
+                (
+                    header::CONTENT_TYPE,
+                    content_type_from_file_name(&file_name),
+                ),
+                (header::CACHE_CONTROL, IMMUTABLE_CACHE_CONTROL),
+            ],
+            data,
+        )
+            .into_response(),
+        Err(FileStorageError::ObjectNotFound) | Err(FileStorageError::InvalidKey) => {
+            StatusCode::NOT_FOUND.into_response()
+        }
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use masjid_app_api_library::shared::extractors::file_handler::UPLOADS_ENDPOINT;
+    use masjid_app_api_library::shared::services::file_storage::settings::UploadLimits;
+    use masjid_app_api_library::shared::services::file_storage::r#trait::MockFileStorage;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_get_upload() {
+        struct TestCase {
+            description: &'static str,
+            file_name: &'static str,
+            mock_response: Result<Vec<u8>, FileStorageError>,
+            expected_status: StatusCode,
+            expected_content_type: Option<&'static str>,
+        }
+        let test_cases = [
+            TestCase {
+                description: "When the file does not exist, I should get a not found",
+                file_name: "poster.png",
+                mock_response: Err(FileStorageError::ObjectNotFound),
+                expected_status: StatusCode::NOT_FOUND,
+                expected_content_type: None,
+            },
+            TestCase {
+                description: "When the file name is not a valid key, I should get a not found",
+                file_name: "..poster.png",
+                mock_response: Err(FileStorageError::InvalidKey),
+                expected_status: StatusCode::NOT_FOUND,
+                expected_content_type: None,
+            },
+            TestCase {
+                description: "When storage fails to retrieve the file, I should get an internal server error",
+                file_name: "poster.png",
+                mock_response: Err(FileStorageError::UnableToRetrieveObject),
+                expected_status: StatusCode::INTERNAL_SERVER_ERROR,
+                expected_content_type: None,
+            },
+            TestCase {
+                description: "When the file exists, I should get it along with its content type",
+                file_name: "poster.png",
+                mock_response: Ok(vec![1, 2, 3]),
+                expected_status: StatusCode::OK,
+                expected_content_type: Some("image/png"),
+            },
+        ];
+        for test_case in test_cases {
+            eprintln!("{}", test_case.description);
+            let mut mock_storage = MockFileStorage::new();
+            mock_storage
+                .expect_get_object()
+                .return_once(move |_| test_case.mock_response);
+            let file_handler = FileHandler::new(
+                Arc::new(mock_storage),
+                UploadLimits::default(),
+                UPLOADS_ENDPOINT.to_owned(),
+            );
+            let actual_response =
+                get_upload(State(file_handler), Path(test_case.file_name.to_owned())).await;
+            assert_eq!(test_case.expected_status, actual_response.status());
+            assert_eq!(
+                test_case.expected_content_type,
+                actual_response
+                    .headers()
+                    .get(header::CONTENT_TYPE)
+                    .and_then(|content_type| content_type.to_str().ok())
+            );
+        }
+    }
+}
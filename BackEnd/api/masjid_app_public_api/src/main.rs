@@ -5,29 +5,65 @@ use crate::features::ask_imam::endpoints::ask_question_for_imam::ask_question_fo
 use crate::features::ask_imam::endpoints::get_answered_questions::get_answered_questions;
 use crate::features::ask_imam::repositories::new_imam_questions_public_repository;
 use crate::features::ask_imam::services::new_ask_imam_public_service;
+use crate::features::event_registrations::endpoints::cancel_event_registration::cancel_event_registration;
+use crate::features::event_registrations::endpoints::get_check_in_qr_code::get_check_in_qr_code;
+use crate::features::event_registrations::endpoints::register_for_event::register_for_event;
+use crate::features::event_registrations::repositories::new_event_registrations_public_repository;
+use crate::features::event_registrations::services::new_event_registration_public_service;
+use crate::features::event_reminders::endpoints::confirm_reminder_subscription::confirm_reminder_subscription;
+use crate::features::event_reminders::endpoints::subscribe_to_reminders::subscribe_to_reminders;
+use crate::features::event_reminders::endpoints::unsubscribe_from_reminders::unsubscribe_from_reminders;
+use crate::features::event_reminders::repositories::new_event_reminders_public_repository;
+use crate::features::event_reminders::services::new_event_reminders_public_service;
 use crate::features::events::events_public_repository::new_events_public_repository;
+use crate::features::organiser_messages::endpoints::message_organiser::message_organiser;
+use crate::features::organiser_messages::repositories::new_organiser_messages_public_repository;
+use crate::features::organiser_messages::services::new_organiser_messages_public_service;
 use crate::features::prayer_times::endpoints::get_prayer_times::get_prayer_times;
 use crate::features::prayer_times::endpoints::get_updated_prayer_times::get_updated_prayer_times;
 use crate::features::prayer_times::services::prayer_times_update_checking_service::{
     PrayerTimesUpdateCheckingService, new_prayer_times_update_checking_service,
 };
+use crate::features::search::endpoints::search::search;
+use crate::features::search::repositories::{
+    new_events_search_repository, new_imam_questions_search_repository,
+};
+use crate::features::search::services::new_search_service;
+use crate::features::uploads::endpoints::get_upload::get_upload;
 use crate::features::{ask_imam, events};
 use axum::Router;
-use axum::routing::{get, post};
+use axum::routing::{delete, get, post};
 use features::prayer_times;
 use features::prayer_times::repositories::new_prayer_times_public_repository;
+use masjid_app_api_library::features::events::endpoints::get_categories::get_categories_common;
+use masjid_app_api_library::features::events::endpoints::get_event_occurrences::get_event_occurrences_common;
 use masjid_app_api_library::features::events::endpoints::get_events::get_events_common;
+use masjid_app_api_library::features::events::endpoints::get_events_calendar::get_events_calendar_common;
+use masjid_app_api_library::features::events::endpoints::get_rooms::get_rooms_common;
+use masjid_app_api_library::features::events::endpoints::get_speaker_events::get_speaker_events_common;
+use masjid_app_api_library::features::events::endpoints::get_speakers::get_speakers_common;
+use masjid_app_api_library::features::events::endpoints::get_tags::get_tags_common;
+use masjid_app_api_library::features::events::models::event_visibility::EventVisibility;
+use masjid_app_api_library::features::events::models::public_event_dto::PublicEventDTO;
+use masjid_app_api_library::features::events::models::public_event_occurrence_dto::PublicEventOccurrenceDTO;
+use masjid_app_api_library::features::events::repositories::new_events_cache_repository;
 use masjid_app_api_library::features::events::services::event_retrieval_service::{
     EventRetrievalService, new_event_retrieval_service,
 };
+use masjid_app_api_library::features::events::services::events_cache_warming_service::new_events_cache_warming_service;
 use masjid_app_api_library::features::prayer_times::services::prayer_times_retrieval_service::{
     PrayerTimesRetrievalService, new_prayer_times_retrieval_service,
 };
 use masjid_app_api_library::shared::data_access::db_providers::in_memory_db_provider::InMemoryDbProvider;
 use masjid_app_api_library::shared::data_access::db_providers::normal_db_provider::NormalDbProvider;
 use masjid_app_api_library::shared::data_access::repository_management::repository_mode::RepositoryMode;
+use masjid_app_api_library::shared::extractors::file_handler::{FileHandler, UPLOADS_ENDPOINT};
 use masjid_app_api_library::shared::logging::logging;
+use masjid_app_api_library::shared::services::mail::providers::MailProvider;
+use masjid_app_api_library::shared::services::mail::r#trait::new_mail_sender;
+use masjid_app_api_library::shared::services::token_signing::r#trait::new_token_signer;
 use masjid_app_api_library::shared::types::app_state::ServiceAppState;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 async fn map_prayer_times() -> Router {
@@ -63,11 +99,86 @@ async fn map_events() -> Router {
         service: new_event_retrieval_service(
             new_events_public_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
             new_events_public_repository(RepositoryMode::InMemory(InMemoryDbProvider::Redis)).await,
+            EventVisibility::Published,
+        ),
+    };
+    let registration_state = ServiceAppState {
+        service: new_event_registration_public_service(
+            new_event_registrations_public_repository(RepositoryMode::Normal(
+                NormalDbProvider::MySql,
+            ))
+            .await,
+            new_token_signer(
+                std::env::var("REGISTRATION_TOKEN_SECRET")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+        ),
+    };
+    let reminders_state = ServiceAppState {
+        service: new_event_reminders_public_service(
+            new_event_reminders_public_repository(RepositoryMode::Normal(NormalDbProvider::MySql))
+                .await,
+            // Unsubscribe tokens are signed with a different prefix to registration tokens, so the
+            // secret can be shared
+            new_token_signer(
+                std::env::var("REGISTRATION_TOKEN_SECRET")
+                    .unwrap()
+                    .as_bytes(),
+            ),
+            new_mail_sender(MailProvider::from_environment()),
+            std::env::var("REMINDER_CONFIRMATION_URL").unwrap(),
+        ),
+    };
+    let organiser_messages_state = ServiceAppState {
+        service: new_organiser_messages_public_service(
+            new_organiser_messages_public_repository(RepositoryMode::Normal(
+                NormalDbProvider::MySql,
+            ))
+            .await,
+            new_mail_sender(MailProvider::from_environment()),
         ),
     };
     Router::new()
-        .route("/", get(get_events_common))
+        .route(
+            "/",
+            get(get_events_common::<dyn EventRetrievalService, PublicEventDTO>),
+        )
+        .route(
+            "/occurrences",
+            get(
+                get_event_occurrences_common::<dyn EventRetrievalService, PublicEventOccurrenceDTO>,
+            ),
+        )
+        .route("/calendar.ics", get(get_events_calendar_common))
+        .route("/rooms", get(get_rooms_common))
+        .route("/speakers", get(get_speakers_common))
+        .route("/categories", get(get_categories_common))
+        .route("/tags", get(get_tags_common))
+        .route(
+            "/speakers/{id}/events",
+            get(get_speaker_events_common::<dyn EventRetrievalService, PublicEventOccurrenceDTO>),
+        )
         .with_state(state)
+        .route("/{event_id}/registrations", post(register_for_event))
+        .route(
+            "/registrations/{cancellation_token}",
+            delete(cancel_event_registration),
+        )
+        .route("/check-in-codes/{check_in_code}", get(get_check_in_qr_code))
+        .with_state(registration_state)
+        .route("/reminders", post(subscribe_to_reminders))
+        .route(
+            "/reminders/confirmations/{confirmation_token}",
+            post(confirm_reminder_subscription),
+        )
+        .route(
+            "/reminders/{unsubscribe_token}",
+            delete(unsubscribe_from_reminders),
+        )
+        .with_state(reminders_state)
+        .route("/{event_id}/messages", post(message_organiser))
+        .with_state(organiser_messages_state)
 }
 async fn map_ask_imam() -> Router {
     let state = ServiceAppState {
@@ -85,6 +196,21 @@ async fn map_ask_imam() -> Router {
         .route("/", post(ask_question_for_imam))
         .with_state(state)
 }
+async fn map_search() -> Router {
+    let state = ServiceAppState {
+        service: new_search_service(
+            new_events_search_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+            new_imam_questions_search_repository(RepositoryMode::Normal(NormalDbProvider::MySql))
+                .await,
+        ),
+    };
+    Router::new().route("/", get(search)).with_state(state)
+}
+async fn map_uploads() -> Router {
+    Router::new()
+        .route("/{file_name}", get(get_upload))
+        .with_state(FileHandler::from_environment())
+}
 
 async fn map_endpoints() -> Router {
     let prayer_times_routes = map_prayer_times().await;
@@ -93,12 +219,30 @@ async fn map_endpoints() -> Router {
     tracing::info!("Mapped Events Endpoints");
     let ask_imam_routes = map_ask_imam().await;
     tracing::info!("Mapped Ask Imam Endpoints");
+    let search_routes = map_search().await;
+    tracing::info!("Mapped Search Endpoints");
+    let upload_routes = map_uploads().await;
+    tracing::info!("Mapped Uploads Endpoints");
 
     let router = Router::new();
     router
         .nest("/prayer-times", prayer_times_routes)
         .nest("/events", event_routes)
         .nest("/ask-imam", ask_imam_routes)
+        .nest("/search", search_routes)
+        .nest(&format!("/{}", UPLOADS_ENDPOINT), upload_routes)
+}
+
+async fn warm_events_cache() {
+    let service = new_events_cache_warming_service(
+        new_events_public_repository(RepositoryMode::Normal(NormalDbProvider::MySql)).await,
+        new_events_cache_repository().await,
+    );
+    match service.warm_events_cache().await {
+        Ok(count) => tracing::info!(count, "Warmed Events Cache"),
+        // Events are read from the database until the cache has been warmed
+        Err(err) => tracing::error!(error = ?err, "unable to warm events cache"),
+    }
 }
 
 #[tokio::main]
@@ -106,9 +250,16 @@ async fn main() {
     logging::setup();
 
     tracing::info!("MasjidApp Public API initialised");
+    warm_events_cache().await;
     let app = map_endpoints().await;
     let listener = tokio::net::TcpListener::bind(&"0.0.0.0:3000")
         .await
         .unwrap();
-    axum::serve(listener, app).await.unwrap();
+    // The client's address is needed to limit how many messages each sender can send organisers
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }